@@ -0,0 +1,56 @@
+// Who a token belongs to, as reported by each platform's current-user endpoint
+
+use crate::retry::RateLimitState;
+use reqwest::header::HeaderMap;
+
+/// The account behind a platform's credentials
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuthenticatedUser {
+    pub username: String,
+    pub display_name: Option<String>,
+    /// Scopes granted to the token, None when the platform doesn't say
+    /// (e.g. GitHub fine-grained tokens)
+    pub scopes: Option<Vec<String>>,
+    /// Rate limit as of the lookup, None when the platform doesn't report it
+    pub rate_limit: Option<RateLimitState>,
+}
+
+/// Parse a comma-separated scopes header such as GitHub's `X-OAuth-Scopes`
+pub fn scopes_from_header(headers: &HeaderMap, name: &str) -> Option<Vec<String>> {
+    let value = headers.get(name)?.to_str().ok()?;
+    Some(
+        value
+            .split(',')
+            .map(str::trim)
+            .filter(|scope| !scope.is_empty())
+            .map(String::from)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_scopes_from_header() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(scopes_from_header(&headers, "x-oauth-scopes"), None);
+
+        headers.insert("x-oauth-scopes", HeaderValue::from_static(""));
+        assert_eq!(
+            scopes_from_header(&headers, "x-oauth-scopes"),
+            Some(Vec::new())
+        );
+
+        headers.insert(
+            "x-oauth-scopes",
+            HeaderValue::from_static("repo, notifications,read:org"),
+        );
+        assert_eq!(
+            scopes_from_header(&headers, "x-oauth-scopes").unwrap(),
+            ["repo", "notifications", "read:org"]
+        );
+    }
+}
@@ -0,0 +1,432 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use thiserror::Error;
+
+use crate::retry::{is_retryable_status, rate_limit_delay, with_retry, RetryConfig, RetryHint};
+use crate::search_terms;
+
+const AZURE_DEVOPS_BASE: &str = "https://dev.azure.com";
+const API_VERSION: &str = "7.1";
+
+#[derive(Error, Debug)]
+pub enum AzureDevOpsError {
+    #[error("API request failed: {0}")]
+    RequestFailed(String),
+
+    #[error("Rate limit exceeded")]
+    RateLimitExceeded { retry_after: Option<Duration> },
+
+    #[error("Repository not found: {0}")]
+    NotFound(String),
+
+    #[error("Authentication required (set AZURE_DEVOPS_TOKEN)")]
+    AuthRequired,
+
+    #[error(
+        "Azure DevOps has no global search, set an organization to search in \
+         (--azure-org or AZURE_DEVOPS_ORG)"
+    )]
+    OrganizationRequired,
+
+    #[error(
+        "Request timed out after {}s (raise [network] timeout_secs on slow connections)",
+        crate::http::timeout().as_secs()
+    )]
+    Timeout,
+
+    #[error("Network error: {0}")]
+    NetworkError(reqwest::Error),
+
+    #[error("Request rejected: {0}")]
+    Rejected(String),
+
+    #[error("JSON parsing failed: {0}")]
+    ParseError(String),
+}
+
+impl From<reqwest::Error> for AzureDevOpsError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            AzureDevOpsError::Timeout
+        } else if error.is_decode() {
+            // The server answered, but not with what we expected
+            AzureDevOpsError::ParseError(error.to_string())
+        } else {
+            AzureDevOpsError::NetworkError(error)
+        }
+    }
+}
+
+impl From<serde_json::Error> for AzureDevOpsError {
+    fn from(error: serde_json::Error) -> Self {
+        AzureDevOpsError::ParseError(error.to_string())
+    }
+}
+
+impl AzureDevOpsError {
+    /// Error for a status we didn't handle. Only 5xx, 408 and 429 are
+    /// worth retrying; any other client error comes back the same.
+    fn unexpected_status(status: reqwest::StatusCode, message: String) -> Self {
+        if is_retryable_status(status) {
+            AzureDevOpsError::RequestFailed(message)
+        } else {
+            AzureDevOpsError::Rejected(message)
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, AzureDevOpsError>;
+
+impl RetryHint for AzureDevOpsError {
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            AzureDevOpsError::RateLimitExceeded { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            AzureDevOpsError::RequestFailed(_)
+                | AzureDevOpsError::RateLimitExceeded { .. }
+                | AzureDevOpsError::Timeout
+                | AzureDevOpsError::NetworkError(_)
+        )
+    }
+}
+
+/// Client for the Azure DevOps (Azure Repos) REST API
+///
+/// Azure DevOps has no global repository search. Searches list the
+/// repositories of the configured organization, narrowed to one project when
+/// one is set, and match the query against repository and project names.
+/// Every call needs a personal access token.
+#[derive(Clone)]
+pub struct AzureDevOpsClient {
+    client: reqwest::Client,
+    token: Option<String>,
+    organization: Option<String>,
+    project: Option<String>,
+    base_url: String,
+    retry_config: RetryConfig,
+}
+
+impl AzureDevOpsClient {
+    pub fn new(
+        token: Option<String>,
+        organization: Option<String>,
+        project: Option<String>,
+    ) -> Self {
+        Self::with_base_url(token, organization, project, AZURE_DEVOPS_BASE.to_string())
+    }
+
+    /// For Azure DevOps Server instances or testing with a custom URL
+    pub fn with_base_url(
+        token: Option<String>,
+        organization: Option<String>,
+        project: Option<String>,
+        base_url: String,
+    ) -> Self {
+        Self {
+            client: crate::http::client(),
+            token,
+            organization,
+            project,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            retry_config: RetryConfig::default(),
+        }
+    }
+
+    /// Create client with custom retry configuration
+    pub fn with_retry_config(
+        token: Option<String>,
+        organization: Option<String>,
+        project: Option<String>,
+        retry_config: RetryConfig,
+    ) -> Self {
+        let mut client = Self::new(token, organization, project);
+        client.retry_config = retry_config;
+        client
+    }
+
+    /// Whether both a token and an organization are set
+    pub fn is_configured(&self) -> bool {
+        self.token.is_some() && self.organization.is_some()
+    }
+
+    /// GET a REST endpoint under the organization, e.g. "Project/_apis/git/repositories"
+    async fn get<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T> {
+        let organization = self
+            .organization
+            .as_ref()
+            .ok_or(AzureDevOpsError::OrganizationRequired)?;
+        let token = self.token.as_ref().ok_or(AzureDevOpsError::AuthRequired)?;
+        let url = format!(
+            "{}/{}/{}",
+            self.base_url,
+            urlencoding::encode(organization),
+            path
+        );
+
+        with_retry(&self.retry_config, || async {
+            let response = self
+                .client
+                .get(&url)
+                .basic_auth("", Some(token))
+                .query(&[("api-version", API_VERSION)])
+                .query(params)
+                .send()
+                .await?;
+
+            let status = response.status();
+
+            // An invalid PAT gets a 203 with the sign-in page instead of a 401
+            if status == 401 || status == 403 || status == 203 {
+                return Err(AzureDevOpsError::AuthRequired);
+            }
+
+            if status == 404 {
+                return Err(AzureDevOpsError::NotFound(path.to_string()));
+            }
+
+            if status == 429 {
+                return Err(AzureDevOpsError::RateLimitExceeded {
+                    retry_after: rate_limit_delay(response.headers(), Utc::now()),
+                });
+            }
+
+            if status.is_client_error() && !is_retryable_status(status) {
+                let body = response.text().await.unwrap_or_default();
+                return Err(AzureDevOpsError::Rejected(format!(
+                    "Status {}: {}",
+                    status, body
+                )));
+            }
+
+            if !status.is_success() {
+                return Err(AzureDevOpsError::unexpected_status(
+                    status,
+                    format!("Status {}", status),
+                ));
+            }
+
+            Ok(response.json().await?)
+        })
+        .await
+    }
+
+    /// Search repositories in the configured organization (and project)
+    ///
+    /// The API can only list repositories, so the query is matched locally:
+    /// every word has to appear in the repository or project name. GitHub-style
+    /// qualifiers (`language:rust`, `stars:>10`) are dropped. Without a token
+    /// or organization this returns no results rather than failing the search.
+    pub async fn search_repositories(
+        &self,
+        query: &str,
+        per_page: u32,
+    ) -> Result<Vec<AzureDevOpsRepository>> {
+        if !self.is_configured() {
+            return Ok(Vec::new());
+        }
+
+        let path = match &self.project {
+            Some(project) => format!("{}/_apis/git/repositories", urlencoding::encode(project)),
+            None => "_apis/git/repositories".to_string(),
+        };
+        let response: ListResponse<AzureDevOpsRepository> = self.get(&path, &[]).await?;
+
+        let terms = search_terms(query);
+        Ok(response
+            .value
+            .into_iter()
+            .filter(|repo| repo.matches(&terms))
+            .take(per_page as usize)
+            .collect())
+    }
+
+    /// Get detailed info about a specific repository
+    pub async fn get_repository(&self, project: &str, name: &str) -> Result<AzureDevOpsRepository> {
+        let path = format!(
+            "{}/_apis/git/repositories/{}",
+            urlencoding::encode(project),
+            urlencoding::encode(name)
+        );
+        self.get(&path, &[]).await.map_err(|e| match e {
+            AzureDevOpsError::NotFound(_) => {
+                AzureDevOpsError::NotFound(format!("{}/{}", project, name))
+            }
+            e => e,
+        })
+    }
+
+    /// Get repository README content
+    pub async fn get_readme(&self, project: &str, name: &str) -> Result<String> {
+        for readme_name in &["README.md", "README", "README.rst", "readme.md"] {
+            match self.get_file_content(project, name, readme_name).await {
+                Ok(content) => return Ok(content),
+                Err(AzureDevOpsError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(AzureDevOpsError::NotFound(format!(
+            "README not found for {}/{}",
+            project, name
+        )))
+    }
+
+    /// Get the changelog file (Azure Repos has no releases to fall back on)
+    pub async fn get_changelog(&self, project: &str, name: &str) -> Result<String> {
+        for file in crate::CHANGELOG_FILES {
+            match self.get_file_content(project, name, file).await {
+                Ok(content) => return Ok(content),
+                Err(AzureDevOpsError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(AzureDevOpsError::NotFound(format!(
+            "Changelog not found for {}/{}",
+            project, name
+        )))
+    }
+
+    /// Get file content from the default branch
+    pub async fn get_file_content(&self, project: &str, name: &str, path: &str) -> Result<String> {
+        let endpoint = format!(
+            "{}/_apis/git/repositories/{}/items",
+            urlencoding::encode(project),
+            urlencoding::encode(name)
+        );
+        let item: ItemResponse = self
+            .get(&endpoint, &[("path", path), ("includeContent", "true")])
+            .await
+            .map_err(|e| match e {
+                AzureDevOpsError::NotFound(_) => AzureDevOpsError::NotFound(format!(
+                    "{} not found in {}/{}",
+                    path, project, name
+                )),
+                e => e,
+            })?;
+        Ok(item.content.unwrap_or_default())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ListResponse<T> {
+    value: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ItemResponse {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// Azure Repos git repository representation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AzureDevOpsRepository {
+    pub id: String,
+    pub name: String,
+    pub project: AzureDevOpsProject,
+    /// Browser URL, e.g. https://dev.azure.com/org/project/_git/name
+    #[serde(rename = "webUrl")]
+    pub web_url: String,
+    /// Default branch reference, None for empty repositories
+    #[serde(rename = "defaultBranch", default)]
+    pub default_branch: Option<String>,
+    /// Repository size in bytes
+    #[serde(default)]
+    pub size: u64,
+    #[serde(rename = "isDisabled", default)]
+    pub is_disabled: bool,
+    #[serde(rename = "isFork", default)]
+    pub is_fork: bool,
+}
+
+impl AzureDevOpsRepository {
+    /// "project/name", unique within an organization
+    pub fn full_name(&self) -> String {
+        format!("{}/{}", self.project.name, self.name)
+    }
+
+    /// Default branch name without the refs/heads/ prefix
+    pub fn default_branch_name(&self) -> Option<&str> {
+        self.default_branch
+            .as_deref()
+            .map(|branch| branch.trim_start_matches("refs/heads/"))
+    }
+
+    /// Whether every search term appears in the repository or project name
+    fn matches(&self, terms: &[String]) -> bool {
+        let haystack = format!("{} {}", self.name, self.project.name).to_lowercase();
+        terms.iter().all(|term| haystack.contains(term.as_str()))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AzureDevOpsProject {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// "private" or "public"
+    #[serde(default)]
+    pub visibility: Option<String>,
+    #[serde(rename = "lastUpdateTime", default)]
+    pub last_update_time: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repository_deserialization_and_matching() {
+        let json = r#"{
+            "id": "5febef5a-833d-4e14-b9c0-14cb638f91e6",
+            "name": "Fabrikam-Fiber",
+            "url": "https://dev.azure.com/fabrikam/_apis/git/repositories/5febef5a",
+            "project": {
+                "id": "6ce954b1-ce1f-45d1-b94d-e6bf2464ba2c",
+                "name": "Web Apps",
+                "visibility": "private",
+                "lastUpdateTime": "2024-03-01T10:00:00Z"
+            },
+            "defaultBranch": "refs/heads/main",
+            "size": 2048,
+            "webUrl": "https://dev.azure.com/fabrikam/Web%20Apps/_git/Fabrikam-Fiber",
+            "isDisabled": false
+        }"#;
+
+        let repo: AzureDevOpsRepository = serde_json::from_str(json).unwrap();
+        assert_eq!(repo.full_name(), "Web Apps/Fabrikam-Fiber");
+        assert_eq!(repo.default_branch_name(), Some("main"));
+        assert!(!repo.is_fork);
+
+        assert!(repo.matches(&search_terms("fiber language:rust")));
+        assert!(repo.matches(&search_terms("web fabrikam")));
+        assert!(!repo.matches(&search_terms("fiber mobile")));
+    }
+
+    #[tokio::test]
+    async fn test_search_without_organization_returns_nothing() {
+        let client = AzureDevOpsClient::new(Some("pat".to_string()), None, None);
+        assert!(!client.is_configured());
+        assert!(client
+            .search_repositories("api", 30)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            client.get_repository("Web Apps", "api").await,
+            Err(AzureDevOpsError::OrganizationRequired)
+        ));
+    }
+}
@@ -1,8 +1,11 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 
-use crate::retry::{is_retryable_status, with_retry, RetryConfig};
+use crate::auth::{scopes_from_header, AuthenticatedUser};
+pub(crate) use crate::bitbucket_server::ServerOptions;
+use crate::retry::{is_retryable_status, rate_limit_delay, with_retry, RetryConfig, RetryHint};
 
 const BITBUCKET_API_BASE: &str = "https://api.bitbucket.org/2.0";
 
@@ -12,7 +15,7 @@ pub enum BitbucketError {
     RequestFailed(String),
 
     #[error("Rate limit exceeded")]
-    RateLimitExceeded,
+    RateLimitExceeded { retry_after: Option<Duration> },
 
     #[error("Repository not found: {0}")]
     NotFound(String),
@@ -20,21 +23,85 @@ pub enum BitbucketError {
     #[error("Authentication required")]
     AuthRequired,
 
+    #[error(
+        "Request timed out after {}s (raise [network] timeout_secs on slow connections)",
+        crate::http::timeout().as_secs()
+    )]
+    Timeout,
+
     #[error("Network error: {0}")]
-    NetworkError(#[from] reqwest::Error),
+    NetworkError(reqwest::Error),
+
+    #[error("Request rejected: {0}")]
+    Rejected(String),
 
     #[error("JSON parsing failed: {0}")]
-    ParseError(#[from] serde_json::Error),
+    ParseError(String),
+}
+
+impl From<reqwest::Error> for BitbucketError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            BitbucketError::Timeout
+        } else if error.is_decode() {
+            // The server answered, but not with what we expected
+            BitbucketError::ParseError(error.to_string())
+        } else {
+            BitbucketError::NetworkError(error)
+        }
+    }
+}
+
+impl From<serde_json::Error> for BitbucketError {
+    fn from(error: serde_json::Error) -> Self {
+        BitbucketError::ParseError(error.to_string())
+    }
+}
+
+impl BitbucketError {
+    /// Error for a status we didn't handle. Only 5xx, 408 and 429 are
+    /// worth retrying; any other client error comes back the same.
+    fn unexpected_status(status: reqwest::StatusCode, message: String) -> Self {
+        if is_retryable_status(status) {
+            BitbucketError::RequestFailed(message)
+        } else {
+            BitbucketError::Rejected(message)
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, BitbucketError>;
 
+impl RetryHint for BitbucketError {
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            BitbucketError::RateLimitExceeded { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            BitbucketError::RequestFailed(_)
+                | BitbucketError::RateLimitExceeded { .. }
+                | BitbucketError::Timeout
+                | BitbucketError::NetworkError(_)
+        )
+    }
+}
+
+/// Client for Bitbucket Cloud's API 2.0, or for a Bitbucket Data Center
+/// (Server) instance's REST API 1.0 when created with [`BitbucketClient::server`]
+#[derive(Clone)]
 pub struct BitbucketClient {
     client: reqwest::Client,
     username: Option<String>,
     app_password: Option<String>,
-    base_url: String,
-    retry_config: RetryConfig,
+    pub(crate) base_url: String,
+    pub(crate) retry_config: RetryConfig,
+    /// Set when talking to Data Center instead of Cloud
+    pub(crate) server: Option<ServerOptions>,
 }
 
 impl BitbucketClient {
@@ -48,30 +115,53 @@ impl BitbucketClient {
         app_password: Option<String>,
         base_url: String,
     ) -> Self {
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::USER_AGENT,
-            reqwest::header::HeaderValue::from_static("RepoScout/0.1.0"),
-        );
-        headers.insert(
-            reqwest::header::ACCEPT,
-            reqwest::header::HeaderValue::from_static("application/json"),
-        );
-
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .build()
-            .expect("Failed to build HTTP client");
-
         Self {
-            client,
+            client: crate::http::client(),
             username,
             app_password,
             base_url,
             retry_config: RetryConfig::default(),
+            server: None,
         }
     }
 
+    /// Talk to a Bitbucket Data Center instance at `url`, e.g.
+    /// https://bitbucket.example.com
+    ///
+    /// Data Center accepts an HTTP access token as a bearer token, which
+    /// wins over `username` and `password` (sent as Basic auth) when both
+    /// are given. Searches cover every repository the credentials can see,
+    /// or only those in `project` when a project key is set.
+    pub fn server(
+        url: &str,
+        username: Option<String>,
+        password: Option<String>,
+        token: Option<String>,
+        project: Option<String>,
+    ) -> Self {
+        let base_url = format!("{}/rest/api/1.0", url.trim_end_matches('/'));
+        let mut client = Self::with_base_url(username, password, base_url);
+        client.server = Some(ServerOptions { token, project });
+        client
+    }
+
+    /// Whether this client talks to Data Center rather than Cloud
+    pub fn is_server(&self) -> bool {
+        self.server.is_some()
+    }
+
+    /// Whether any credentials are set
+    pub fn has_credentials(&self) -> bool {
+        self.auth_header().is_some()
+    }
+
+    pub(crate) fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        self.client.get(url).header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static("application/json"),
+        )
+    }
+
     /// Create client with custom retry configuration
     pub fn with_retry_config(
         username: Option<String>,
@@ -83,6 +173,15 @@ impl BitbucketClient {
         client
     }
 
+    /// Authorization header value: a Data Center access token when one is
+    /// set, Basic auth otherwise
+    pub(crate) fn auth_header(&self) -> Option<String> {
+        match self.server.as_ref().and_then(|s| s.token.as_ref()) {
+            Some(token) => Some(format!("Bearer {}", token)),
+            None => self.basic_auth_header(),
+        }
+    }
+
     /// Create Basic Auth header value
     fn basic_auth_header(&self) -> Option<String> {
         match (&self.username, &self.app_password) {
@@ -103,14 +202,195 @@ impl BitbucketClient {
     /// Note: Bitbucket API has limitations - it doesn't support global public repository search
     /// like GitHub. This method will return an empty list for now. To search Bitbucket repositories,
     /// you need workspace-specific access or use the workspace search endpoint.
+    ///
+    /// Data Center can list the repositories the credentials see, so there
+    /// the query is matched against their names.
     pub async fn search_repositories(
         &self,
-        _query: &str,
-        _per_page: u32,
+        query: &str,
+        per_page: u32,
     ) -> Result<Vec<BitbucketRepository>> {
+        let (mut repos, _) = self.search_repositories_page(query, None).await?;
+        repos.truncate(per_page as usize);
+        Ok(repos)
+    }
+
+    /// One page of search results, starting at `cursor` (None for the
+    /// first), with the cursor of the page after it (None on the last one)
+    pub async fn search_repositories_page(
+        &self,
+        query: &str,
+        cursor: Option<&str>,
+    ) -> Result<(Vec<BitbucketRepository>, Option<String>)> {
+        if self.is_server() {
+            return self.server_search(query, cursor).await;
+        }
         // Bitbucket doesn't support global public repository search without workspace access
         // Return empty results to avoid errors while keeping the integration functional
-        Ok(Vec::new())
+        Ok((Vec::new(), None))
+    }
+
+    /// Public repositories updated since `updated_since`, most recent first
+    ///
+    /// Unlike search, the global `/repositories` listing accepts a BBQL filter,
+    /// which is what Bitbucket trending is built on.
+    pub async fn recently_updated_repositories(
+        &self,
+        updated_since: DateTime<Utc>,
+        language: Option<&str>,
+        per_page: u32,
+    ) -> Result<Vec<BitbucketRepository>> {
+        // Data Center has no BBQL, nor update times in its listings
+        if self.is_server() {
+            return Ok(Vec::new());
+        }
+        let url = format!("{}/repositories", self.base_url);
+        let auth_header = self.auth_header();
+        let filter = recently_updated_filter(updated_since, language);
+        let pagelen = per_page.min(100).to_string();
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.get(&url).query(&[
+                ("q", filter.as_str()),
+                ("sort", "-updated_on"),
+                ("pagelen", pagelen.as_str()),
+            ]);
+
+            if let Some(ref auth) = auth_header {
+                request = request.header(reqwest::header::AUTHORIZATION, auth);
+            }
+
+            let response = request.send().await?;
+
+            if response.status() == 401 {
+                return Err(BitbucketError::AuthRequired);
+            }
+
+            if response.status() == 429 {
+                return Err(BitbucketError::RateLimitExceeded {
+                    retry_after: rate_limit_delay(response.headers(), Utc::now()),
+                });
+            }
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(BitbucketError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
+            let listing: SearchResponse = response.json().await?;
+            Ok(listing.values)
+        })
+        .await
+    }
+
+    /// The account the app password belongs to, with the scopes it grants
+    ///
+    /// Bitbucket doesn't report rate limits in its headers.
+    pub async fn get_authenticated_user(&self) -> Result<AuthenticatedUser> {
+        if self.is_server() {
+            return self.server_authenticated_user().await;
+        }
+        let auth_header = self.auth_header().ok_or(BitbucketError::AuthRequired)?;
+        let url = format!("{}/user", self.base_url);
+
+        with_retry(&self.retry_config, || async {
+            let response = self
+                .get(&url)
+                .header(reqwest::header::AUTHORIZATION, &auth_header)
+                .send()
+                .await?;
+
+            if response.status() == 401 {
+                return Err(BitbucketError::AuthRequired);
+            }
+            if response.status() == 429 {
+                return Err(BitbucketError::RateLimitExceeded {
+                    retry_after: rate_limit_delay(response.headers(), Utc::now()),
+                });
+            }
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(BitbucketError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
+            let scopes = scopes_from_header(response.headers(), "x-oauth-scopes");
+            let user: BitbucketUser = response.json().await?;
+            Ok(AuthenticatedUser {
+                username: user.username,
+                display_name: user.display_name,
+                scopes,
+                rate_limit: None,
+            })
+        })
+        .await
+    }
+
+    /// Number of users watching a repository, Bitbucket's closest thing to stars
+    pub async fn get_watcher_count(&self, workspace: &str, repo_slug: &str) -> Result<u32> {
+        if self.is_server() {
+            return Ok(0);
+        }
+        let url = format!(
+            "{}/repositories/{}/{}/watchers",
+            self.base_url, workspace, repo_slug
+        );
+        let auth_header = self.auth_header();
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.get(&url).query(&[("pagelen", "1")]);
+
+            if let Some(ref auth) = auth_header {
+                request = request.header(reqwest::header::AUTHORIZATION, auth);
+            }
+
+            let response = request.send().await?;
+
+            if response.status() == 404 {
+                return Err(BitbucketError::NotFound(format!(
+                    "{}/{}",
+                    workspace, repo_slug
+                )));
+            }
+
+            if response.status() == 401 {
+                return Err(BitbucketError::AuthRequired);
+            }
+
+            if !response.status().is_success() {
+                return Err(BitbucketError::unexpected_status(
+                    response.status(),
+                    format!("Failed to fetch watchers: {}", response.status()),
+                ));
+            }
+
+            let page: CountResponse = response.json().await?;
+            Ok(page.size)
+        })
+        .await
+    }
+
+    /// When the repository last changed. Data Center listings carry no
+    /// dates, so there the default branch's latest commit is looked up;
+    /// None for a repository without commits.
+    pub async fn last_commit_date(
+        &self,
+        workspace: &str,
+        repo_slug: &str,
+    ) -> Result<Option<DateTime<Utc>>> {
+        if self.is_server() {
+            return self.server_last_commit_date(workspace, repo_slug).await;
+        }
+        Ok(Some(
+            self.get_repository(workspace, repo_slug).await?.updated_on,
+        ))
     }
 
     /// Get detailed info about a specific repository
@@ -119,12 +399,15 @@ impl BitbucketClient {
         workspace: &str,
         repo_slug: &str,
     ) -> Result<BitbucketRepository> {
+        if self.is_server() {
+            return self.server_get_repository(workspace, repo_slug).await;
+        }
         let url = format!("{}/repositories/{}/{}", self.base_url, workspace, repo_slug);
-        let auth_header = self.basic_auth_header();
+        let auth_header = self.auth_header();
         let full_name = format!("{}/{}", workspace, repo_slug);
 
         with_retry(&self.retry_config, || async {
-            let mut request = self.client.get(&url);
+            let mut request = self.get(&url);
 
             if let Some(ref auth) = auth_header {
                 request = request.header(reqwest::header::AUTHORIZATION, auth);
@@ -143,17 +426,17 @@ impl BitbucketClient {
             let status = response.status();
 
             if status.is_client_error() && !is_retryable_status(status) {
-                return Err(BitbucketError::RequestFailed(format!(
+                return Err(BitbucketError::Rejected(format!(
                     "Failed to fetch repo: {}",
                     status
                 )));
             }
 
             if !response.status().is_success() {
-                return Err(BitbucketError::RequestFailed(format!(
-                    "Failed to fetch repo: {}",
-                    status
-                )));
+                return Err(BitbucketError::unexpected_status(
+                    status,
+                    format!("Failed to fetch repo: {}", status),
+                ));
             }
 
             let repo: BitbucketRepository = response.json().await?;
@@ -172,49 +455,11 @@ impl BitbucketClient {
             "README",
             "README.rst",
         ] {
-            let url = format!(
-                "{}/repositories/{}/{}/src/HEAD/{}",
-                self.base_url, workspace, repo_slug, readme_name
-            );
-            let auth_header = self.basic_auth_header();
-
-            let result = with_retry(&self.retry_config, || async {
-                let mut request = self.client.get(&url);
-
-                if let Some(ref auth) = auth_header {
-                    request = request.header(reqwest::header::AUTHORIZATION, auth);
-                }
-
-                let response = request.send().await?;
-
-                if response.status() == 404 {
-                    return Err(BitbucketError::NotFound(format!(
-                        "{}/{}",
-                        workspace, repo_slug
-                    )));
-                }
-
-                if response.status() == 401 {
-                    return Err(BitbucketError::AuthRequired);
-                }
-
-                if !response.status().is_success() {
-                    let status = response.status();
-                    let body = response.text().await.unwrap_or_default();
-                    return Err(BitbucketError::RequestFailed(format!(
-                        "Status {}: {}",
-                        status, body
-                    )));
-                }
-
-                let readme_content = response.text().await?;
-                Ok(readme_content)
-            })
-            .await;
-
-            // If we found the README, return it
-            if result.is_ok() {
-                return result;
+            if let Ok(content) = self
+                .get_file_content(workspace, repo_slug, readme_name)
+                .await
+            {
+                return Ok(content);
             }
         }
 
@@ -232,14 +477,17 @@ impl BitbucketClient {
         repo_slug: &str,
         path: &str,
     ) -> Result<String> {
+        if self.is_server() {
+            return self.server_file_content(workspace, repo_slug, path).await;
+        }
         let url = format!(
             "{}/repositories/{}/{}/src/HEAD/{}",
             self.base_url, workspace, repo_slug, path
         );
-        let auth_header = self.basic_auth_header();
+        let auth_header = self.auth_header();
 
         with_retry(&self.retry_config, || async {
-            let mut request = self.client.get(&url);
+            let mut request = self.get(&url);
 
             if let Some(ref auth) = auth_header {
                 request = request.header(reqwest::header::AUTHORIZATION, auth);
@@ -261,10 +509,10 @@ impl BitbucketClient {
             if !response.status().is_success() {
                 let status = response.status();
                 let body = response.text().await.unwrap_or_default();
-                return Err(BitbucketError::RequestFailed(format!(
-                    "Status {}: {}",
-                    status, body
-                )));
+                return Err(BitbucketError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
             }
 
             let content = response.text().await?;
@@ -291,22 +539,54 @@ impl BitbucketClient {
             .await
     }
 
-    /// Search for code across Bitbucket repositories
-    /// Note: Bitbucket's code search API is limited compared to GitHub
+    /// Get the changelog file (Bitbucket has no releases API to fall back on)
+    pub async fn get_changelog(&self, workspace: &str, repo_slug: &str) -> Result<String> {
+        for file in crate::CHANGELOG_FILES {
+            match self.get_file_content(workspace, repo_slug, file).await {
+                Ok(content) => return Ok(content),
+                Err(BitbucketError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(BitbucketError::NotFound(format!(
+            "Changelog not found for {}/{}",
+            workspace, repo_slug
+        )))
+    }
+
+    /// Search for code within a single Bitbucket repository
+    ///
+    /// Bitbucket only exposes code search per workspace, so this narrows
+    /// the workspace search with a `repo:` qualifier.
     pub async fn search_code(
         &self,
         workspace: &str,
         repo_slug: &str,
         query: &str,
     ) -> Result<Vec<CodeSearchItem>> {
-        let url = format!(
-            "{}/repositories/{}/{}/search/code",
-            self.base_url, workspace, repo_slug
-        );
-        let auth_header = self.basic_auth_header();
+        let scoped = format!("{} repo:{}", query, repo_slug);
+        self.search_workspace_code(workspace, &scoped).await
+    }
+
+    /// Search for code across all repositories in a workspace
+    /// Note: Bitbucket's code search API is limited compared to GitHub
+    /// and must be enabled for the workspace
+    pub async fn search_workspace_code(
+        &self,
+        workspace: &str,
+        query: &str,
+    ) -> Result<Vec<CodeSearchItem>> {
+        if self.is_server() {
+            return Err(BitbucketError::Rejected(
+                "Code search isn't available on Bitbucket Data Center".to_string(),
+            ));
+        }
+        let url = format!("{}/workspaces/{}/search/code", self.base_url, workspace);
+        let auth_header = self.auth_header();
 
         with_retry(&self.retry_config, || async {
-            let mut request = self.client.get(&url).query(&[("search_query", query)]);
+            let mut request = self.get(&url).query(&[("search_query", query)]);
 
             if let Some(ref auth) = auth_header {
                 request = request.header(reqwest::header::AUTHORIZATION, auth);
@@ -315,7 +595,7 @@ impl BitbucketClient {
             let response = request.send().await?;
 
             if response.status() == 404 {
-                return Err(BitbucketError::NotFound(query.to_string()));
+                return Err(BitbucketError::NotFound(format!("workspace {}", workspace)));
             }
 
             if response.status() == 401 {
@@ -323,14 +603,16 @@ impl BitbucketClient {
             }
 
             if response.status() == 429 {
-                return Err(BitbucketError::RateLimitExceeded);
+                return Err(BitbucketError::RateLimitExceeded {
+                    retry_after: rate_limit_delay(response.headers(), Utc::now()),
+                });
             }
 
             let status = response.status();
 
             if status.is_client_error() && !is_retryable_status(status) {
                 let body = response.text().await.unwrap_or_default();
-                return Err(BitbucketError::RequestFailed(format!(
+                return Err(BitbucketError::Rejected(format!(
                     "Status {}: {}",
                     status, body
                 )));
@@ -338,10 +620,10 @@ impl BitbucketClient {
 
             if !response.status().is_success() {
                 let body = response.text().await.unwrap_or_default();
-                return Err(BitbucketError::RequestFailed(format!(
-                    "Status {}: {}",
-                    status, body
-                )));
+                return Err(BitbucketError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
             }
 
             let search_result: CodeSearchResponse = response.json().await?;
@@ -351,6 +633,36 @@ impl BitbucketClient {
     }
 }
 
+/// BBQL filter for public repositories updated since a moment, optionally in one language
+fn recently_updated_filter(updated_since: DateTime<Utc>, language: Option<&str>) -> String {
+    let mut filter = format!(
+        "is_private = false AND updated_on >= {}",
+        updated_since.format("%Y-%m-%dT%H:%M:%S%:z")
+    );
+    if let Some(language) = language {
+        // Bitbucket stores languages lower-cased
+        filter.push_str(&format!(
+            " AND language = \"{}\"",
+            language.to_lowercase().replace('"', "")
+        ));
+    }
+    filter
+}
+
+/// The `/user` fields we show
+#[derive(Debug, Deserialize)]
+struct BitbucketUser {
+    username: String,
+    display_name: Option<String>,
+}
+
+/// Paginated response where only the total count is of interest
+#[derive(Debug, Deserialize)]
+struct CountResponse {
+    #[serde(default)]
+    size: u32,
+}
+
 /// Bitbucket API repository search response
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -409,6 +721,53 @@ pub struct FileInfo {
     pub path: String,
     #[serde(rename = "type")]
     pub file_type: String,
+    #[serde(default)]
+    pub commit: Option<FileCommit>,
+}
+
+/// Commit (and owning repository) a code search hit was found at
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileCommit {
+    pub hash: String,
+    #[serde(default)]
+    pub repository: Option<FileRepository>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileRepository {
+    pub full_name: String,
+    #[serde(default)]
+    pub links: Option<Links>,
+}
+
+impl CodeSearchItem {
+    /// Full name of the repository containing the match, if reported
+    pub fn repository_full_name(&self) -> Option<&str> {
+        self.file
+            .commit
+            .as_ref()
+            .and_then(|c| c.repository.as_ref())
+            .map(|r| r.full_name.as_str())
+    }
+
+    /// Web URL of the repository containing the match
+    pub fn repository_url(&self) -> Option<String> {
+        let repo = self.file.commit.as_ref()?.repository.as_ref()?;
+        Some(match &repo.links {
+            Some(links) => links.html.href.clone(),
+            None => format!("https://bitbucket.org/{}", repo.full_name),
+        })
+    }
+
+    /// Web URL of the matched file, pinned to the commit it was found at
+    pub fn file_url(&self) -> Option<String> {
+        let commit = self.file.commit.as_ref()?;
+        let repo_url = self.repository_url()?;
+        Some(format!(
+            "{}/src/{}/{}",
+            repo_url, commit.hash, self.file.path
+        ))
+    }
 }
 
 /// Bitbucket repository representation
@@ -431,6 +790,14 @@ pub struct BitbucketRepository {
     pub mainbranch: Option<MainBranch>,
     pub workspace: Workspace,
     pub owner: Owner,
+    /// Repository this one was forked from, only present on forks
+    #[serde(default)]
+    pub parent: Option<BitbucketForkParent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BitbucketForkParent {
+    pub full_name: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -503,4 +870,48 @@ mod tests {
         assert!(auth_header.is_some());
         assert!(auth_header.unwrap().starts_with("Basic "));
     }
+
+    #[test]
+    fn test_recently_updated_filter() {
+        let since = DateTime::parse_from_rfc3339("2024-05-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            recently_updated_filter(since, None),
+            "is_private = false AND updated_on >= 2024-05-01T00:00:00+00:00"
+        );
+        assert_eq!(
+            recently_updated_filter(since, Some("Rust")),
+            "is_private = false AND updated_on >= 2024-05-01T00:00:00+00:00 AND language = \"rust\""
+        );
+    }
+
+    #[test]
+    fn test_workspace_code_search_item_urls() {
+        let json = r#"{
+            "content_matches": [{"lines": [{"line": 3, "segments": [
+                {"text": "fn "}, {"text": "parse", "match": true}
+            ]}]}],
+            "path_matches": [],
+            "file": {
+                "path": "src/lib.rs",
+                "type": "commit_file",
+                "commit": {
+                    "hash": "abc123",
+                    "repository": {
+                        "full_name": "team/tool",
+                        "links": {"html": {"href": "https://bitbucket.org/team/tool"}}
+                    }
+                }
+            }
+        }"#;
+        let item: CodeSearchItem = serde_json::from_str(json).unwrap();
+
+        assert_eq!(item.repository_full_name(), Some("team/tool"));
+        assert_eq!(
+            item.file_url().as_deref(),
+            Some("https://bitbucket.org/team/tool/src/abc123/src/lib.rs")
+        );
+        assert!(item.content_matches[0].lines[0].segments[1].match_);
+    }
 }
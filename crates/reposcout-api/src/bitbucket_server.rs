@@ -0,0 +1,456 @@
+// Bitbucket Data Center (Server) support for BitbucketClient
+//
+// Data Center speaks REST API 1.0 rather than Cloud's 2.0. Repositories live
+// in projects, addressed by project key ("PROJ/repo", or "~user/repo" for
+// personal ones) where Cloud has workspaces. Responses are mapped onto
+// BitbucketRepository so callers don't care which one they talk to.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+use crate::auth::AuthenticatedUser;
+use crate::bitbucket::{
+    BitbucketClient, BitbucketError, BitbucketForkParent, BitbucketRepository, CloneLink, Link,
+    Links, Owner, Result, Workspace,
+};
+use crate::retry::{is_retryable_status, rate_limit_delay, with_retry};
+use crate::search_terms;
+
+/// Repositories fetched per listing; matching against the query happens
+/// afterwards, so this is more than a page of results
+const LIST_LIMIT: u32 = 100;
+
+/// Data Center settings, set by [`BitbucketClient::server`]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ServerOptions {
+    /// HTTP access token, sent as a bearer token
+    pub token: Option<String>,
+    /// Project key searches are limited to
+    pub project: Option<String>,
+}
+
+impl BitbucketClient {
+    /// Repositories whose name contains every search term
+    ///
+    /// The `/repos` listing filters by one name fragment, so the first term
+    /// narrows it there and the rest are matched here. With a project set,
+    /// the project's repositories are listed and matched instead.
+    ///
+    /// `start` is the listing offset Data Center hands back as
+    /// `nextPageStart`, returned as the cursor of the following page.
+    pub(crate) async fn server_search(
+        &self,
+        query: &str,
+        start: Option<&str>,
+    ) -> Result<(Vec<BitbucketRepository>, Option<String>)> {
+        let terms = search_terms(query);
+        let limit = LIST_LIMIT.to_string();
+        let start = start.unwrap_or("0");
+        let project = self.server.as_ref().and_then(|s| s.project.as_deref());
+
+        let page: Page<ServerRepository> = match project {
+            Some(key) => {
+                let path = format!("projects/{}/repos", urlencoding::encode(key));
+                self.server_json(&path, &[("limit", &limit), ("start", start)], key)
+                    .await?
+            }
+            None => {
+                let name = terms.first().map(String::as_str).unwrap_or_default();
+                let params = [("name", name), ("limit", &limit), ("start", start)];
+                self.server_json("repos", &params, "repos").await?
+            }
+        };
+
+        let next = page.next_start();
+        let repos = page
+            .values
+            .into_iter()
+            .filter(|repo| repo.matches(&terms))
+            .map(|repo| repo.into_repository(&self.base_url))
+            .collect();
+        Ok((repos, next))
+    }
+
+    /// One repository, by project key and repository slug
+    pub(crate) async fn server_get_repository(
+        &self,
+        project: &str,
+        slug: &str,
+    ) -> Result<BitbucketRepository> {
+        let path = repo_path(project, slug);
+        let full_name = format!("{}/{}", project, slug);
+        let repo: ServerRepository = self.server_json(&path, &[], &full_name).await?;
+        Ok(repo.into_repository(&self.base_url))
+    }
+
+    /// Committer date of the default branch's latest commit, None for a
+    /// repository without commits
+    pub(crate) async fn server_last_commit_date(
+        &self,
+        project: &str,
+        slug: &str,
+    ) -> Result<Option<DateTime<Utc>>> {
+        let path = format!("{}/commits", repo_path(project, slug));
+        let full_name = format!("{}/{}", project, slug);
+        let page: Page<ServerCommit> = self
+            .server_json(&path, &[("limit", "1")], &full_name)
+            .await?;
+        Ok(page
+            .values
+            .first()
+            .and_then(|commit| DateTime::from_timestamp_millis(commit.committer_timestamp)))
+    }
+
+    /// Raw file content from the default branch
+    pub(crate) async fn server_file_content(
+        &self,
+        project: &str,
+        slug: &str,
+        path: &str,
+    ) -> Result<String> {
+        let endpoint = format!("{}/raw/{}", repo_path(project, slug), path);
+        let missing = format!("{} not found in {}/{}", path, project, slug);
+        with_retry(&self.retry_config, || async {
+            let response = self.server_send(&endpoint, &[], &missing).await?;
+            Ok(response.text().await?)
+        })
+        .await
+    }
+
+    /// Data Center names the user behind the credentials in the
+    /// X-AUSERNAME header of any authenticated response
+    pub(crate) async fn server_authenticated_user(&self) -> Result<AuthenticatedUser> {
+        if !self.has_credentials() {
+            return Err(BitbucketError::AuthRequired);
+        }
+        with_retry(&self.retry_config, || async {
+            let response = self
+                .server_send("application-properties", &[], "application-properties")
+                .await?;
+            let username = response
+                .headers()
+                .get("x-ausername")
+                .and_then(|value| value.to_str().ok())
+                .map(String::from)
+                .ok_or(BitbucketError::AuthRequired)?;
+            Ok(AuthenticatedUser {
+                username,
+                display_name: None,
+                scopes: None,
+                rate_limit: None,
+            })
+        })
+        .await
+    }
+
+    async fn server_json<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        what: &str,
+    ) -> Result<T> {
+        with_retry(&self.retry_config, || async {
+            let response = self.server_send(path, params, what).await?;
+            Ok(response.json().await?)
+        })
+        .await
+    }
+
+    /// GET a REST endpoint, turning failed statuses into errors. `what`
+    /// names the thing that wasn't found on a 404.
+    async fn server_send(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+        what: &str,
+    ) -> Result<reqwest::Response> {
+        let url = format!("{}/{}", self.base_url, path);
+        let mut request = self.get(&url).query(params);
+        if let Some(auth) = self.auth_header() {
+            request = request.header(reqwest::header::AUTHORIZATION, auth);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+
+        if status == 401 {
+            return Err(BitbucketError::AuthRequired);
+        }
+
+        if status == 404 {
+            return Err(BitbucketError::NotFound(what.to_string()));
+        }
+
+        if status == 429 {
+            return Err(BitbucketError::RateLimitExceeded {
+                retry_after: rate_limit_delay(response.headers(), Utc::now()),
+            });
+        }
+
+        if status.is_client_error() && !is_retryable_status(status) {
+            let body = response.text().await.unwrap_or_default();
+            return Err(BitbucketError::Rejected(format!(
+                "Status {}: {}",
+                status, body
+            )));
+        }
+
+        if !status.is_success() {
+            return Err(BitbucketError::RequestFailed(format!("Status {}", status)));
+        }
+
+        Ok(response)
+    }
+}
+
+fn repo_path(project: &str, slug: &str) -> String {
+    format!(
+        "projects/{}/repos/{}",
+        urlencoding::encode(project),
+        urlencoding::encode(slug)
+    )
+}
+
+/// A page of a Data Center listing
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Page<T> {
+    values: Vec<T>,
+    #[serde(default = "last_page")]
+    is_last_page: bool,
+    #[serde(default)]
+    next_page_start: Option<u32>,
+}
+
+/// Listings without paging fields are complete
+fn last_page() -> bool {
+    true
+}
+
+impl<T> Page<T> {
+    /// Offset the following page starts at, None on the last page
+    fn next_start(&self) -> Option<String> {
+        if self.is_last_page {
+            return None;
+        }
+        self.next_page_start.map(|start| start.to_string())
+    }
+}
+
+/// Data Center repository representation
+#[derive(Debug, Clone, Deserialize)]
+struct ServerRepository {
+    id: u64,
+    slug: String,
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    project: ServerProject,
+    #[serde(default)]
+    public: bool,
+    /// Repository this one was forked from, only present on forks
+    #[serde(default)]
+    origin: Option<ServerOrigin>,
+    #[serde(default)]
+    links: ServerLinks,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ServerProject {
+    key: String,
+    id: u64,
+    name: String,
+    #[serde(default)]
+    public: bool,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ServerOrigin {
+    slug: String,
+    project: ServerProjectKey,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct ServerProjectKey {
+    key: String,
+}
+
+/// A commit from a Data Center commit listing
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ServerCommit {
+    /// Milliseconds since the Unix epoch
+    committer_timestamp: i64,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ServerLinks {
+    #[serde(rename = "self", default)]
+    self_links: Vec<Link>,
+    #[serde(default)]
+    clone: Vec<CloneLink>,
+}
+
+impl ServerRepository {
+    /// Whether every search term appears in the repository name or slug
+    fn matches(&self, terms: &[String]) -> bool {
+        let haystack = format!("{} {}", self.name, self.slug).to_lowercase();
+        terms.iter().all(|term| haystack.contains(term.as_str()))
+    }
+
+    /// Map onto the Cloud representation. Data Center listings carry no
+    /// dates, size or language, so those are left empty; the dates stay at
+    /// the Unix epoch until [`BitbucketClient::last_commit_date`] fills them.
+    fn into_repository(self, base_url: &str) -> BitbucketRepository {
+        let web_url = match self.links.self_links.first() {
+            Some(link) => link.href.clone(),
+            None => format!(
+                "{}/projects/{}/repos/{}/browse",
+                base_url.trim_end_matches("/rest/api/1.0"),
+                self.project.key,
+                self.slug
+            ),
+        };
+        let epoch = DateTime::<Utc>::UNIX_EPOCH;
+
+        BitbucketRepository {
+            uuid: self.id.to_string(),
+            name: self.name,
+            full_name: format!("{}/{}", self.project.key, self.slug),
+            description: self.description,
+            is_private: !(self.public || self.project.public),
+            links: Links {
+                html: Link { href: web_url },
+                avatar: None,
+                clone: Some(self.links.clone),
+            },
+            created_on: epoch,
+            updated_on: epoch,
+            size: None,
+            language: None,
+            has_issues: false,
+            mainbranch: None,
+            workspace: Workspace {
+                slug: self.project.key,
+                name: self.project.name.clone(),
+                uuid: self.project.id.to_string(),
+            },
+            owner: Owner {
+                display_name: self.project.name,
+                uuid: self.project.id.to_string(),
+                username: None,
+            },
+            parent: self.origin.map(|origin| BitbucketForkParent {
+                full_name: format!("{}/{}", origin.project.key, origin.slug),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_server_client_auth() {
+        let client = BitbucketClient::server(
+            "https://bitbucket.example.com/",
+            Some("jdoe".to_string()),
+            Some("secret".to_string()),
+            Some("token".to_string()),
+            Some("PROJ".to_string()),
+        );
+        assert!(client.is_server());
+        assert_eq!(
+            client.base_url,
+            "https://bitbucket.example.com/rest/api/1.0"
+        );
+        // The access token wins over the username and password
+        assert_eq!(client.auth_header().as_deref(), Some("Bearer token"));
+
+        let client = BitbucketClient::server(
+            "https://bitbucket.example.com",
+            Some("jdoe".to_string()),
+            Some("secret".to_string()),
+            None,
+            None,
+        );
+        assert!(client.auth_header().unwrap().starts_with("Basic "));
+        assert!(
+            !BitbucketClient::server("https://bitbucket.example.com", None, None, None, None)
+                .has_credentials()
+        );
+    }
+
+    #[test]
+    fn test_page_cursor() {
+        let page: Page<u32> =
+            serde_json::from_str(r#"{"values": [1], "isLastPage": false, "nextPageStart": 100}"#)
+                .unwrap();
+        assert_eq!(page.next_start().as_deref(), Some("100"));
+
+        let page: Page<u32> =
+            serde_json::from_str(r#"{"values": [1], "isLastPage": true}"#).unwrap();
+        assert_eq!(page.next_start(), None);
+        let page: Page<u32> = serde_json::from_str(r#"{"values": []}"#).unwrap();
+        assert_eq!(page.next_start(), None);
+    }
+
+    #[test]
+    fn test_commit_timestamp() {
+        let page: Page<ServerCommit> = serde_json::from_str(
+            r#"{"values": [{"id": "def0123abcdef4567abcdef8987abcdef6543abc",
+                "committerTimestamp": 1710000000000}], "isLastPage": false}"#,
+        )
+        .unwrap();
+        let date = DateTime::from_timestamp_millis(page.values[0].committer_timestamp).unwrap();
+        assert_eq!(date.to_rfc3339(), "2024-03-09T16:00:00+00:00");
+    }
+
+    #[test]
+    fn test_server_repository_mapping() {
+        let json = r#"{
+            "slug": "my-repo",
+            "id": 1,
+            "name": "My Repo",
+            "hierarchyId": "e3c939f9ef4a7fae272e",
+            "scmId": "git",
+            "state": "AVAILABLE",
+            "forkable": true,
+            "project": {"key": "PRJ", "id": 7, "name": "My Cool Project", "public": false, "type": "NORMAL"},
+            "public": false,
+            "origin": {"slug": "upstream", "project": {"key": "CORE", "id": 2, "name": "Core"}},
+            "links": {
+                "clone": [{"href": "ssh://git@bitbucket.example.com:7999/prj/my-repo.git", "name": "ssh"}],
+                "self": [{"href": "https://bitbucket.example.com/projects/PRJ/repos/my-repo/browse"}]
+            }
+        }"#;
+        let repo: ServerRepository = serde_json::from_str(json).unwrap();
+        assert!(repo.matches(&search_terms("repo my language:java")));
+        assert!(!repo.matches(&search_terms("other")));
+
+        let repo = repo.into_repository("https://bitbucket.example.com/rest/api/1.0");
+        assert_eq!(repo.full_name, "PRJ/my-repo");
+        assert_eq!(
+            repo.links.html.href,
+            "https://bitbucket.example.com/projects/PRJ/repos/my-repo/browse"
+        );
+        assert!(repo.is_private);
+        assert_eq!(repo.workspace.slug, "PRJ");
+        assert_eq!(repo.parent.unwrap().full_name, "CORE/upstream");
+
+        // Without a self link the browse URL is built from the instance URL
+        let repo: ServerRepository = serde_json::from_str(
+            r#"{"slug": "tool", "id": 2, "name": "tool", "public": true,
+                "project": {"key": "~JDOE", "id": 3, "name": "John Doe"}}"#,
+        )
+        .unwrap();
+        let repo = repo.into_repository("https://bitbucket.example.com/rest/api/1.0");
+        assert_eq!(
+            repo.links.html.href,
+            "https://bitbucket.example.com/projects/~JDOE/repos/tool/browse"
+        );
+        assert!(!repo.is_private);
+        assert!(repo.parent.is_none());
+    }
+}
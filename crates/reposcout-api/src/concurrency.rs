@@ -0,0 +1,63 @@
+// Bounded-concurrency fetching for commands that look up many repositories
+use futures::stream::{self, StreamExt};
+use std::future::Future;
+
+/// How many fetches run at once unless configured otherwise
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Run `fetch` for every item with at most `limit` in flight, returning the
+/// results in the order of `items`.
+///
+/// A new fetch starts as soon as any running one finishes, not when the
+/// oldest does, so a request sitting in retry backoff after a rate limit
+/// holds only its own slot while the others keep going.
+pub async fn fetch_concurrently<T, R, F, Fut>(
+    items: impl IntoIterator<Item = T>,
+    limit: usize,
+    mut fetch: F,
+) -> Vec<R>
+where
+    F: FnMut(T) -> Fut,
+    Fut: Future<Output = R>,
+{
+    let mut results: Vec<(usize, R)> = stream::iter(items.into_iter().enumerate())
+        .map(|(i, item)| {
+            let fetching = fetch(item);
+            async move { (i, fetching.await) }
+        })
+        .buffer_unordered(limit.max(1))
+        .collect()
+        .await;
+
+    results.sort_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_never_exceeds_limit() {
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+
+        let results = fetch_concurrently(0..10u64, 3, |i| {
+            let (in_flight, max_in_flight) = (&in_flight, &max_in_flight);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                max_in_flight.fetch_max(now, Ordering::SeqCst);
+                // Uneven durations so fetches finish out of order
+                tokio::time::sleep(Duration::from_millis(5 * ((i * 7) % 4 + 1))).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                i * 10
+            }
+        })
+        .await;
+
+        assert_eq!(results, (0..10).map(|i| i * 10).collect::<Vec<_>>());
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 3);
+    }
+}
@@ -2,10 +2,17 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::retry::{is_retryable_status, with_retry, RetryConfig};
+use crate::auth::{scopes_from_header, AuthenticatedUser};
+use crate::retry::{
+    is_retryable_status, rate_limit_delay, with_retry, RateLimitState, RetryConfig, RetryHint,
+};
+use std::sync::{Arc, Mutex};
 
 const GITHUB_API_BASE: &str = "https://api.github.com";
 
+/// Media type for plain JSON responses
+const GITHUB_JSON: &str = "application/vnd.github.v3+json";
+
 #[derive(Error, Debug)]
 pub enum GitHubError {
     #[error("API request failed: {0}")]
@@ -20,20 +27,84 @@ pub enum GitHubError {
     #[error("Authentication required")]
     AuthRequired,
 
+    #[error(
+        "Request timed out after {}s (raise [network] timeout_secs on slow connections)",
+        crate::http::timeout().as_secs()
+    )]
+    Timeout,
+
     #[error("Network error: {0}")]
-    NetworkError(#[from] reqwest::Error),
+    NetworkError(reqwest::Error),
+
+    #[error("Request rejected: {0}")]
+    Rejected(String),
 
     #[error("JSON parsing failed: {0}")]
-    ParseError(#[from] serde_json::Error),
+    ParseError(String),
+}
+
+impl From<reqwest::Error> for GitHubError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            GitHubError::Timeout
+        } else if error.is_decode() {
+            // The server answered, but not with what we expected
+            GitHubError::ParseError(error.to_string())
+        } else {
+            GitHubError::NetworkError(error)
+        }
+    }
+}
+
+impl From<serde_json::Error> for GitHubError {
+    fn from(error: serde_json::Error) -> Self {
+        GitHubError::ParseError(error.to_string())
+    }
+}
+
+impl GitHubError {
+    /// Error for a status we didn't handle. Only 5xx, 408 and 429 are
+    /// worth retrying; any other client error comes back the same.
+    fn unexpected_status(status: reqwest::StatusCode, message: String) -> Self {
+        if is_retryable_status(status) {
+            GitHubError::RequestFailed(message)
+        } else {
+            GitHubError::Rejected(message)
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, GitHubError>;
 
+impl RetryHint for GitHubError {
+    fn retry_after(&self) -> Option<std::time::Duration> {
+        match self {
+            GitHubError::RateLimitExceeded { reset_at } => {
+                Some((*reset_at - Utc::now()).to_std().unwrap_or_default())
+            }
+            _ => None,
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            GitHubError::RequestFailed(_)
+                | GitHubError::RateLimitExceeded { .. }
+                | GitHubError::Timeout
+                | GitHubError::NetworkError(_)
+        )
+    }
+}
+
+#[derive(Clone)]
 pub struct GitHubClient {
     client: reqwest::Client,
     token: Option<String>,
     base_url: String,
     retry_config: RetryConfig,
+    /// Rate limit numbers from the most recent response, shared by clones
+    rate_limit: Arc<Mutex<Option<RateLimitState>>>,
 }
 
 impl GitHubClient {
@@ -41,31 +112,38 @@ impl GitHubClient {
         Self::with_base_url(token, GITHUB_API_BASE.to_string())
     }
 
-    /// For GitHub Enterprise or testing with custom API URL
+    /// For GitHub Enterprise Server (e.g. https://github.example.com/api/v3)
+    /// or testing with a custom API URL. Every endpoint is built on it.
     pub fn with_base_url(token: Option<String>, base_url: String) -> Self {
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::USER_AGENT,
-            reqwest::header::HeaderValue::from_static("RepoScout/0.1.0"),
-        );
-        headers.insert(
-            reqwest::header::ACCEPT,
-            reqwest::header::HeaderValue::from_static("application/vnd.github.v3+json"),
-        );
-
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .build()
-            .expect("Failed to build HTTP client"); // This should never fail
-
         Self {
-            client,
+            client: crate::http::client(),
             token,
-            base_url,
+            base_url: base_url.trim_end_matches('/').to_string(),
             retry_config: RetryConfig::default(),
+            rate_limit: Arc::new(Mutex::new(None)),
         }
     }
 
+    /// GET with the JSON media type. Requests for another representation
+    /// (raw files, text matches) set their own Accept header instead.
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        self.client.get(url).header(
+            reqwest::header::ACCEPT,
+            reqwest::header::HeaderValue::from_static(GITHUB_JSON),
+        )
+    }
+
+    /// Rate limit status as of the last API response, if any was seen.
+    /// Callers can use this to warn before requests start getting throttled.
+    pub fn rate_limit(&self) -> Option<RateLimitState> {
+        *self.rate_limit.lock().unwrap()
+    }
+
+    /// Whether requests are authenticated (and get the higher rate limit)
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
     /// Create client with custom retry configuration
     pub fn with_retry_config(token: Option<String>, retry_config: RetryConfig) -> Self {
         let mut client = Self::new(token);
@@ -75,14 +153,26 @@ impl GitHubClient {
 
     /// Search repositories on GitHub
     pub async fn search_repositories(&self, query: &str, per_page: u32) -> Result<Vec<GitHubRepo>> {
+        self.search_repositories_page(query, per_page, 1).await
+    }
+
+    /// One page of search results, counting from 1. GitHub serves at most
+    /// the first 1000 results of a search.
+    pub async fn search_repositories_page(
+        &self,
+        query: &str,
+        per_page: u32,
+        page: u32,
+    ) -> Result<Vec<GitHubRepo>> {
         let url = format!("{}/search/repositories", self.base_url);
         let token = self.token.clone();
 
         // Wrap in retry logic
         with_retry(&self.retry_config, || async {
-            let mut request = self.client.get(&url).query(&[
+            let mut request = self.get(&url).query(&[
                 ("q", query),
                 ("per_page", &per_page.to_string()),
+                ("page", &page.to_string()),
                 ("sort", "stars"),
             ]);
 
@@ -100,11 +190,14 @@ impl GitHubClient {
             }
 
             let status = response.status();
+            if status == 401 {
+                return Err(GitHubError::AuthRequired);
+            }
 
             // Don't retry client errors (except rate limit which is checked above)
             if status.is_client_error() && !is_retryable_status(status) {
                 let body = response.text().await.unwrap_or_default();
-                return Err(GitHubError::RequestFailed(format!(
+                return Err(GitHubError::Rejected(format!(
                     "Status {}: {}",
                     status, body
                 )));
@@ -113,10 +206,10 @@ impl GitHubClient {
             // Retry server errors
             if !response.status().is_success() {
                 let body = response.text().await.unwrap_or_default();
-                return Err(GitHubError::RequestFailed(format!(
-                    "Status {}: {}",
-                    status, body
-                )));
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
             }
 
             let search_result: SearchResponse = response.json().await?;
@@ -152,10 +245,10 @@ impl GitHubClient {
             if !response.status().is_success() {
                 let status = response.status();
                 let body = response.text().await.unwrap_or_default();
-                return Err(GitHubError::RequestFailed(format!(
-                    "Status {}: {}",
-                    status, body
-                )));
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
             }
 
             let readme_content = response.text().await?;
@@ -197,10 +290,10 @@ impl GitHubClient {
             if !response.status().is_success() {
                 let status = response.status();
                 let body = response.text().await.unwrap_or_default();
-                return Err(GitHubError::RequestFailed(format!(
-                    "Status {}: {}",
-                    status, body
-                )));
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
             }
 
             let content = response.text().await?;
@@ -224,6 +317,198 @@ impl GitHubClient {
         self.get_file_content(owner, repo, "requirements.txt").await
     }
 
+    /// Get the language breakdown of a repository (language -> bytes of code)
+    pub async fn get_languages(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<std::collections::HashMap<String, u64>> {
+        let url = format!("{}/repos/{}/{}/languages", self.base_url, owner, repo);
+        let token = self.token.clone();
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.get(&url);
+
+            if let Some(ref token) = token {
+                request = request.bearer_auth(token);
+            }
+
+            let response = request.send().await?;
+
+            self.check_rate_limit(&response)?;
+
+            if response.status() == 404 {
+                return Err(GitHubError::NotFound(format!("{}/{}", owner, repo)));
+            }
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
+            let languages = response.json().await?;
+            Ok(languages)
+        })
+        .await
+    }
+
+    /// Commits per week for the last year, oldest first.
+    ///
+    /// GitHub computes these statistics in the background and answers 202
+    /// until they're ready; that comes back as None so callers can try again
+    /// later.
+    pub async fn get_commit_activity(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Option<Vec<CommitActivityWeek>>> {
+        let url = format!(
+            "{}/repos/{}/{}/stats/commit_activity",
+            self.base_url, owner, repo
+        );
+        let token = self.token.clone();
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.get(&url);
+
+            if let Some(ref token) = token {
+                request = request.bearer_auth(token);
+            }
+
+            let response = request.send().await?;
+            self.check_rate_limit(&response)?;
+
+            match response.status().as_u16() {
+                202 => return Ok(None),
+                // Empty repositories have no statistics
+                204 => return Ok(Some(Vec::new())),
+                404 => return Err(GitHubError::NotFound(format!("{}/{}", owner, repo))),
+                _ => {}
+            }
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
+            Ok(Some(response.json().await?))
+        })
+        .await
+    }
+
+    /// Number of contributors, anonymous ones included.
+    ///
+    /// Asks for one contributor per page and reads the total from the last
+    /// page number in the `Link` header.
+    pub async fn get_contributor_count(&self, owner: &str, repo: &str) -> Result<u32> {
+        let url = format!("{}/repos/{}/{}/contributors", self.base_url, owner, repo);
+        let token = self.token.clone();
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.get(&url).query(&[("per_page", "1"), ("anon", "1")]);
+
+            if let Some(ref token) = token {
+                request = request.bearer_auth(token);
+            }
+
+            let response = request.send().await?;
+            self.check_rate_limit(&response)?;
+
+            if response.status() == 204 {
+                return Ok(0);
+            }
+            if response.status() == 404 {
+                return Err(GitHubError::NotFound(format!("{}/{}", owner, repo)));
+            }
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
+            let last_page = response
+                .headers()
+                .get(reqwest::header::LINK)
+                .and_then(|value| value.to_str().ok())
+                .and_then(last_page_from_link);
+            match last_page {
+                Some(count) => Ok(count),
+                // A single page: the contributor, if any, is all there is
+                None => {
+                    let contributors: Vec<serde_json::Value> = response.json().await?;
+                    Ok(contributors.len() as u32)
+                }
+            }
+        })
+        .await
+    }
+
+    /// Get the latest release rendered as markdown
+    pub async fn get_latest_release_notes(&self, owner: &str, repo: &str) -> Result<String> {
+        let url = format!("{}/repos/{}/{}/releases/latest", self.base_url, owner, repo);
+        let token = self.token.clone();
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.get(&url);
+
+            if let Some(ref token) = token {
+                request = request.bearer_auth(token);
+            }
+
+            let response = request.send().await?;
+
+            self.check_rate_limit(&response)?;
+
+            if response.status() == 404 {
+                return Err(GitHubError::NotFound(format!(
+                    "No releases for {}/{}",
+                    owner, repo
+                )));
+            }
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
+            let release: GitHubRelease = response.json().await?;
+            Ok(crate::format_release_notes(
+                &release.tag_name,
+                release.name.as_deref(),
+                release.body.as_deref(),
+            ))
+        })
+        .await
+    }
+
+    /// Get the changelog, falling back to the latest release notes
+    /// when none of the usual changelog files exist
+    pub async fn get_changelog(&self, owner: &str, repo: &str) -> Result<String> {
+        for file in crate::CHANGELOG_FILES {
+            match self.get_file_content(owner, repo, file).await {
+                Ok(content) => return Ok(content),
+                Err(GitHubError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.get_latest_release_notes(owner, repo).await
+    }
+
     /// Search for code across GitHub repositories
     ///
     /// GitHub Code Search API has special rate limits:
@@ -269,7 +554,7 @@ impl GitHubClient {
             // Don't retry client errors
             if status.is_client_error() && !is_retryable_status(status) {
                 let body = response.text().await.unwrap_or_default();
-                return Err(GitHubError::RequestFailed(format!(
+                return Err(GitHubError::Rejected(format!(
                     "Status {}: {}",
                     status, body
                 )));
@@ -278,10 +563,10 @@ impl GitHubClient {
             // Retry server errors
             if !response.status().is_success() {
                 let body = response.text().await.unwrap_or_default();
-                return Err(GitHubError::RequestFailed(format!(
-                    "Status {}: {}",
-                    status, body
-                )));
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
             }
 
             // Get response text for debugging
@@ -298,7 +583,7 @@ impl GitHubClient {
                         "Response snippet: {}",
                         &response_text[..response_text.len().min(1000)]
                     );
-                    GitHubError::ParseError(e)
+                    GitHubError::from(e)
                 })?;
             Ok(search_result.items)
         })
@@ -313,7 +598,7 @@ impl GitHubClient {
 
         // Wrap in retry logic
         with_retry(&self.retry_config, || async {
-            let mut request = self.client.get(&url);
+            let mut request = self.get(&url);
 
             if let Some(ref token) = token {
                 request = request.bearer_auth(token);
@@ -327,10 +612,13 @@ impl GitHubClient {
             }
 
             let status = response.status();
+            if status == 401 {
+                return Err(GitHubError::AuthRequired);
+            }
 
             // Don't retry client errors
             if status.is_client_error() && !is_retryable_status(status) {
-                return Err(GitHubError::RequestFailed(format!(
+                return Err(GitHubError::Rejected(format!(
                     "Failed to fetch repo: {}",
                     status
                 )));
@@ -338,10 +626,10 @@ impl GitHubClient {
 
             // Retry server errors
             if !response.status().is_success() {
-                return Err(GitHubError::RequestFailed(format!(
-                    "Failed to fetch repo: {}",
-                    status
-                )));
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Failed to fetch repo: {}", status),
+                ));
             }
 
             let repo: GitHubRepo = response.json().await?;
@@ -350,6 +638,39 @@ impl GitHubClient {
         .await
     }
 
+    /// The account the token belongs to, with its scopes and rate limit
+    pub async fn get_authenticated_user(&self) -> Result<AuthenticatedUser> {
+        let token = self.token.clone().ok_or(GitHubError::AuthRequired)?;
+        let url = format!("{}/user", self.base_url);
+
+        with_retry(&self.retry_config, || async {
+            let response = self.get(&url).bearer_auth(&token).send().await?;
+            self.check_rate_limit(&response)?;
+
+            if response.status() == 401 {
+                return Err(GitHubError::AuthRequired);
+            }
+            if !response.status().is_success() {
+                return Err(GitHubError::unexpected_status(
+                    response.status(),
+                    format!("Failed to fetch user: {}", response.status()),
+                ));
+            }
+
+            // Classic tokens list their scopes; fine-grained ones send no header
+            let scopes = scopes_from_header(response.headers(), "x-oauth-scopes");
+            let rate_limit = RateLimitState::from_headers(response.headers());
+            let user: GitHubUser = response.json().await?;
+            Ok(AuthenticatedUser {
+                username: user.login,
+                display_name: user.name,
+                scopes,
+                rate_limit,
+            })
+        })
+        .await
+    }
+
     /// Get notifications for the authenticated user
     pub async fn get_notifications(
         &self,
@@ -361,7 +682,7 @@ impl GitHubClient {
         let token = self.token.clone();
 
         with_retry(&self.retry_config, || async {
-            let mut request = self.client.get(&url).query(&[
+            let mut request = self.get(&url).query(&[
                 ("all", if all { "true" } else { "false" }),
                 (
                     "participating",
@@ -387,10 +708,10 @@ impl GitHubClient {
 
             if !response.status().is_success() {
                 let _body = response.text().await.unwrap_or_default();
-                return Err(GitHubError::RequestFailed(format!(
-                    "Failed to fetch notifications: {}",
-                    status
-                )));
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Failed to fetch notifications: {}", status),
+                ));
             }
 
             let notifications: Vec<crate::notifications::Notification> = response.json().await?;
@@ -424,10 +745,10 @@ impl GitHubClient {
 
             if !response.status().is_success() {
                 let _body = response.text().await.unwrap_or_default();
-                return Err(GitHubError::RequestFailed(format!(
-                    "Failed to mark notification as read: {}",
-                    status
-                )));
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Failed to mark notification as read: {}", status),
+                ));
             }
 
             Ok(())
@@ -444,6 +765,10 @@ impl GitHubClient {
             let mut request = self
                 .client
                 .put(&url)
+                .header(
+                    reqwest::header::ACCEPT,
+                    reqwest::header::HeaderValue::from_static(GITHUB_JSON),
+                )
                 .json(&serde_json::json!({"read": true}));
 
             if let Some(ref token) = token {
@@ -466,10 +791,10 @@ impl GitHubClient {
                 && status != reqwest::StatusCode::ACCEPTED
             {
                 let _body = response.text().await.unwrap_or_default();
-                return Err(GitHubError::RequestFailed(format!(
-                    "Failed to mark all notifications as read: {}",
-                    status
-                )));
+                return Err(GitHubError::unexpected_status(
+                    status,
+                    format!("Failed to mark all notifications as read: {}", status),
+                ));
             }
 
             Ok(())
@@ -477,24 +802,57 @@ impl GitHubClient {
         .await
     }
 
-    /// Check if we're hitting rate limits and return helpful error
+    /// Record the rate limit headers and turn rate-limited responses into
+    /// an error carrying when to retry
     fn check_rate_limit(&self, response: &reqwest::Response) -> Result<()> {
-        if response.status() == 403 {
-            // Rate limit hit - GitHub returns 403
-            if let Some(reset) = response.headers().get("x-ratelimit-reset") {
-                if let Ok(reset_str) = reset.to_str() {
-                    if let Ok(reset_timestamp) = reset_str.parse::<i64>() {
-                        let reset_at =
-                            DateTime::from_timestamp(reset_timestamp, 0).unwrap_or_else(Utc::now);
-                        return Err(GitHubError::RateLimitExceeded { reset_at });
-                    }
-                }
+        let headers = response.headers();
+        if let Some(state) = RateLimitState::from_headers(headers) {
+            *self.rate_limit.lock().unwrap() = Some(state);
+        }
+
+        // GitHub returns 403 for the primary rate limit and 429 (with
+        // Retry-After) for secondary limits
+        let status = response.status();
+        if status == 403 || status == 429 {
+            let now = Utc::now();
+            if let Some(delay) = rate_limit_delay(headers, now) {
+                let reset_at = now
+                    + chrono::Duration::from_std(delay)
+                        .unwrap_or_else(|_| chrono::Duration::zero());
+                return Err(GitHubError::RateLimitExceeded { reset_at });
             }
         }
         Ok(())
     }
 }
 
+/// The `/user` fields we show
+#[derive(Debug, Deserialize)]
+struct GitHubUser {
+    login: String,
+    name: Option<String>,
+}
+
+/// One week of `/stats/commit_activity`
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommitActivityWeek {
+    /// Start of the week (a Sunday) as a Unix timestamp
+    pub week: i64,
+    pub total: u32,
+    /// Commits per day, Sunday first
+    pub days: [u32; 7],
+}
+
+/// Page number of the `rel="last"` link in a `Link` header
+fn last_page_from_link(link: &str) -> Option<u32> {
+    link.split(',')
+        .find(|part| part.contains("rel=\"last\""))?
+        .split(['?', '&', '>'])
+        .find_map(|param| param.strip_prefix("page="))?
+        .parse()
+        .ok()
+}
+
 /// GitHub API repository search response
 #[derive(Debug, Deserialize)]
 struct SearchResponse {
@@ -507,6 +865,14 @@ struct CodeSearchResponse {
     items: Vec<CodeSearchItem>,
 }
 
+/// GitHub API latest release, shown when there's no changelog file
+#[derive(Debug, Deserialize)]
+struct GitHubRelease {
+    tag_name: String,
+    name: Option<String>,
+    body: Option<String>,
+}
+
 /// GitHub code search result item
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CodeSearchItem {
@@ -582,6 +948,8 @@ pub struct GitHubRepo {
     pub default_branch: String,
     pub archived: bool,
     pub private: bool,
+    #[serde(default)]
+    pub fork: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -602,6 +970,51 @@ pub struct License {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_last_page_from_link() {
+        let link = "<https://api.github.com/repositories/1/contributors?per_page=1&anon=1&page=2>; rel=\"next\", \
+                    <https://api.github.com/repositories/1/contributors?per_page=1&anon=1&page=417>; rel=\"last\"";
+        assert_eq!(last_page_from_link(link), Some(417));
+        assert_eq!(
+            last_page_from_link("<https://api.github.com/x?page=1>; rel=\"prev\""),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forbidden_is_not_retried() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                let mut buf = [0u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let _ = socket
+                    .write_all(
+                        b"HTTP/1.1 403 Forbidden\r\ncontent-length: 6\r\nconnection: close\r\n\r\nnot ok",
+                    )
+                    .await;
+            }
+        });
+
+        let mut client = GitHubClient::with_base_url(None, url);
+        client.retry_config = RetryConfig {
+            initial_delay_ms: 1,
+            ..RetryConfig::default()
+        };
+        let error = client.get_readme("owner", "repo").await.unwrap_err();
+
+        assert!(matches!(error, GitHubError::Rejected(_)));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_client_creation() {
         let client = GitHubClient::new(None);
@@ -609,6 +1022,13 @@ mod tests {
         assert_eq!(client.base_url, GITHUB_API_BASE);
     }
 
+    #[test]
+    fn test_client_with_enterprise_url() {
+        let client =
+            GitHubClient::with_base_url(None, "https://github.example.com/api/v3/".to_string());
+        assert_eq!(client.base_url, "https://github.example.com/api/v3");
+    }
+
     #[test]
     fn test_client_with_token() {
         let token = "ghp_test_token".to_string();
@@ -1,8 +1,13 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use thiserror::Error;
 
-use crate::retry::{is_retryable_status, with_retry, RetryConfig};
+use crate::auth::AuthenticatedUser;
+use crate::retry::{
+    is_retryable_status, rate_limit_delay, with_retry, RateLimitState, RetryConfig, RetryHint,
+};
+use std::sync::{Arc, Mutex};
 
 const GITLAB_API_BASE: &str = "https://gitlab.com/api/v4";
 
@@ -12,7 +17,7 @@ pub enum GitLabError {
     RequestFailed(String),
 
     #[error("Rate limit exceeded")]
-    RateLimitExceeded,
+    RateLimitExceeded { retry_after: Option<Duration> },
 
     #[error("Project not found: {0}")]
     NotFound(String),
@@ -20,20 +25,82 @@ pub enum GitLabError {
     #[error("Authentication required")]
     AuthRequired,
 
+    #[error(
+        "Request timed out after {}s (raise [network] timeout_secs on slow connections)",
+        crate::http::timeout().as_secs()
+    )]
+    Timeout,
+
     #[error("Network error: {0}")]
-    NetworkError(#[from] reqwest::Error),
+    NetworkError(reqwest::Error),
+
+    #[error("Request rejected: {0}")]
+    Rejected(String),
 
     #[error("JSON parsing failed: {0}")]
-    ParseError(#[from] serde_json::Error),
+    ParseError(String),
+}
+
+impl From<reqwest::Error> for GitLabError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            GitLabError::Timeout
+        } else if error.is_decode() {
+            // The server answered, but not with what we expected
+            GitLabError::ParseError(error.to_string())
+        } else {
+            GitLabError::NetworkError(error)
+        }
+    }
+}
+
+impl From<serde_json::Error> for GitLabError {
+    fn from(error: serde_json::Error) -> Self {
+        GitLabError::ParseError(error.to_string())
+    }
+}
+
+impl GitLabError {
+    /// Error for a status we didn't handle. Only 5xx, 408 and 429 are
+    /// worth retrying; any other client error comes back the same.
+    fn unexpected_status(status: reqwest::StatusCode, message: String) -> Self {
+        if is_retryable_status(status) {
+            GitLabError::RequestFailed(message)
+        } else {
+            GitLabError::Rejected(message)
+        }
+    }
 }
 
 pub type Result<T> = std::result::Result<T, GitLabError>;
 
+impl RetryHint for GitLabError {
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            GitLabError::RateLimitExceeded { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            GitLabError::RequestFailed(_)
+                | GitLabError::RateLimitExceeded { .. }
+                | GitLabError::Timeout
+                | GitLabError::NetworkError(_)
+        )
+    }
+}
+
+#[derive(Clone)]
 pub struct GitLabClient {
     client: reqwest::Client,
     token: Option<String>,
     base_url: String,
     retry_config: RetryConfig,
+    /// Rate limit numbers from the most recent response, shared by clones
+    rate_limit: Arc<Mutex<Option<RateLimitState>>>,
 }
 
 impl GitLabClient {
@@ -43,22 +110,23 @@ impl GitLabClient {
 
     /// For self-hosted GitLab instances
     pub fn with_base_url(token: Option<String>, base_url: String) -> Self {
-        let mut headers = reqwest::header::HeaderMap::new();
-        headers.insert(
-            reqwest::header::USER_AGENT,
-            reqwest::header::HeaderValue::from_static("RepoScout/0.1.0"),
-        );
-
-        let client = reqwest::Client::builder()
-            .default_headers(headers)
-            .build()
-            .expect("Failed to build HTTP client");
-
         Self {
-            client,
+            client: crate::http::client(),
             token,
             base_url,
             retry_config: RetryConfig::default(),
+            rate_limit: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Rate limit status as of the last API response, if any was seen
+    pub fn rate_limit(&self) -> Option<RateLimitState> {
+        *self.rate_limit.lock().unwrap()
+    }
+
+    fn record_rate_limit(&self, response: &reqwest::Response) {
+        if let Some(state) = RateLimitState::from_headers(response.headers()) {
+            *self.rate_limit.lock().unwrap() = Some(state);
         }
     }
 
@@ -71,6 +139,18 @@ impl GitLabClient {
 
     /// Search projects on GitLab
     pub async fn search_projects(&self, query: &str, per_page: u32) -> Result<Vec<GitLabProject>> {
+        let (projects, _) = self.search_projects_page(query, per_page, 1).await?;
+        Ok(projects)
+    }
+
+    /// One page of search results, counting from 1, with the number of the
+    /// next page GitLab reports (None on the last one)
+    pub async fn search_projects_page(
+        &self,
+        query: &str,
+        per_page: u32,
+        page: u32,
+    ) -> Result<(Vec<GitLabProject>, Option<u32>)> {
         let url = format!("{}/projects", self.base_url);
         let token = self.token.clone();
 
@@ -79,6 +159,7 @@ impl GitLabClient {
             let mut request = self.client.get(&url).query(&[
                 ("search", query),
                 ("per_page", &per_page.to_string()),
+                ("page", &page.to_string()),
                 ("order_by", "star_count"),
                 ("sort", "desc"),
             ]);
@@ -88,6 +169,7 @@ impl GitLabClient {
             }
 
             let response = request.send().await?;
+            self.record_rate_limit(&response);
 
             if response.status() == 404 {
                 return Err(GitLabError::NotFound(query.to_string()));
@@ -98,7 +180,9 @@ impl GitLabClient {
             }
 
             if response.status() == 429 {
-                return Err(GitLabError::RateLimitExceeded);
+                return Err(GitLabError::RateLimitExceeded {
+                    retry_after: rate_limit_delay(response.headers(), Utc::now()),
+                });
             }
 
             if !response.status().is_success() {
@@ -112,12 +196,80 @@ impl GitLabClient {
                     )));
                 }
 
-                return Err(GitLabError::RequestFailed(format!(
+                return Err(GitLabError::Rejected(format!(
                     "Status {}: {}",
                     status, body
                 )));
             }
 
+            // The header is empty on the last page
+            let next_page = response
+                .headers()
+                .get("x-next-page")
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse().ok());
+            let projects: Vec<GitLabProject> = response.json().await?;
+            Ok((projects, next_page))
+        })
+        .await
+    }
+
+    /// Most-starred public projects with activity since `active_since`
+    ///
+    /// GitLab has no trending endpoint, so this lists `/projects` ordered by
+    /// star count and limited to projects pushed to or updated in the window.
+    pub async fn trending_projects(
+        &self,
+        active_since: DateTime<Utc>,
+        language: Option<&str>,
+        topic: Option<&str>,
+        per_page: u32,
+    ) -> Result<Vec<GitLabProject>> {
+        let url = format!("{}/projects", self.base_url);
+        let token = self.token.clone();
+        let mut params = vec![
+            ("visibility", "public".to_string()),
+            ("order_by", "star_count".to_string()),
+            ("sort", "desc".to_string()),
+            ("last_activity_after", active_since.to_rfc3339()),
+            ("per_page", per_page.to_string()),
+        ];
+        if let Some(language) = language {
+            params.push(("with_programming_language", language.to_string()));
+        }
+        if let Some(topic) = topic {
+            params.push(("topic", topic.to_string()));
+        }
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.client.get(&url).query(&params);
+
+            if let Some(ref token) = token {
+                request = request.header("PRIVATE-TOKEN", token);
+            }
+
+            let response = request.send().await?;
+            self.record_rate_limit(&response);
+
+            if response.status() == 401 {
+                return Err(GitLabError::AuthRequired);
+            }
+
+            if response.status() == 429 {
+                return Err(GitLabError::RateLimitExceeded {
+                    retry_after: rate_limit_delay(response.headers(), Utc::now()),
+                });
+            }
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(GitLabError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
             let projects: Vec<GitLabProject> = response.json().await?;
             Ok(projects)
         })
@@ -142,6 +294,7 @@ impl GitLabClient {
             }
 
             let response = request.send().await?;
+            self.record_rate_limit(&response);
 
             if response.status() == 404 {
                 // Try other common README names
@@ -158,10 +311,10 @@ impl GitLabClient {
             if !response.status().is_success() {
                 let status = response.status();
                 let body = response.text().await.unwrap_or_default();
-                return Err(GitLabError::RequestFailed(format!(
-                    "Status {}: {}",
-                    status, body
-                )));
+                return Err(GitLabError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
             }
 
             let readme_content = response.text().await?;
@@ -189,6 +342,7 @@ impl GitLabClient {
             }
 
             let response = request.send().await?;
+            self.record_rate_limit(&response);
 
             if response.status() == 404 {
                 return Err(GitLabError::NotFound(format!(
@@ -204,10 +358,10 @@ impl GitLabClient {
             if !response.status().is_success() {
                 let status = response.status();
                 let body = response.text().await.unwrap_or_default();
-                return Err(GitLabError::RequestFailed(format!(
-                    "Status {}: {}",
-                    status, body
-                )));
+                return Err(GitLabError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
             }
 
             let content = response.text().await?;
@@ -231,6 +385,111 @@ impl GitLabClient {
         self.get_file_content(path, "requirements.txt").await
     }
 
+    /// Get the language breakdown of a project (language -> percentage of code)
+    pub async fn get_languages(
+        &self,
+        path: &str,
+    ) -> Result<std::collections::HashMap<String, f64>> {
+        let encoded_path = urlencoding::encode(path);
+        let url = format!("{}/projects/{}/languages", self.base_url, encoded_path);
+        let token = self.token.clone();
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.client.get(&url);
+
+            if let Some(ref token) = token {
+                request = request.header("PRIVATE-TOKEN", token);
+            }
+
+            let response = request.send().await?;
+            self.record_rate_limit(&response);
+
+            if response.status() == 404 {
+                return Err(GitLabError::NotFound(path.to_string()));
+            }
+
+            if response.status() == 401 {
+                return Err(GitLabError::AuthRequired);
+            }
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(GitLabError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
+            let languages = response.json().await?;
+            Ok(languages)
+        })
+        .await
+    }
+
+    /// Get the latest release rendered as markdown
+    pub async fn get_latest_release_notes(&self, path: &str) -> Result<String> {
+        let encoded_path = urlencoding::encode(path);
+        let url = format!("{}/projects/{}/releases", self.base_url, encoded_path);
+        let token = self.token.clone();
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.client.get(&url).query(&[("per_page", "1")]);
+
+            if let Some(ref token) = token {
+                request = request.header("PRIVATE-TOKEN", token);
+            }
+
+            let response = request.send().await?;
+            self.record_rate_limit(&response);
+
+            if response.status() == 404 {
+                return Err(GitLabError::NotFound(path.to_string()));
+            }
+
+            if response.status() == 401 {
+                return Err(GitLabError::AuthRequired);
+            }
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(GitLabError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
+            // Releases are returned newest first
+            let releases: Vec<GitLabRelease> = response.json().await?;
+            let release = releases
+                .into_iter()
+                .next()
+                .ok_or_else(|| GitLabError::NotFound(format!("No releases for {}", path)))?;
+
+            Ok(crate::format_release_notes(
+                &release.tag_name,
+                release.name.as_deref(),
+                release.description.as_deref(),
+            ))
+        })
+        .await
+    }
+
+    /// Get the changelog, falling back to the latest release notes
+    /// when none of the usual changelog files exist
+    pub async fn get_changelog(&self, path: &str) -> Result<String> {
+        for file in crate::CHANGELOG_FILES {
+            match self.get_file_content(path, file).await {
+                Ok(content) => return Ok(content),
+                Err(GitLabError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        self.get_latest_release_notes(path).await
+    }
+
     /// Search for code across GitLab projects
     ///
     /// Uses the GitLab Search API with scope=blobs
@@ -255,6 +514,7 @@ impl GitLabClient {
             }
 
             let response = request.send().await?;
+            self.record_rate_limit(&response);
 
             if response.status() == 404 {
                 return Err(GitLabError::NotFound(query.to_string()));
@@ -265,7 +525,9 @@ impl GitLabClient {
             }
 
             if response.status() == 429 {
-                return Err(GitLabError::RateLimitExceeded);
+                return Err(GitLabError::RateLimitExceeded {
+                    retry_after: rate_limit_delay(response.headers(), Utc::now()),
+                });
             }
 
             if !response.status().is_success() {
@@ -279,7 +541,7 @@ impl GitLabClient {
                     )));
                 }
 
-                return Err(GitLabError::RequestFailed(format!(
+                return Err(GitLabError::Rejected(format!(
                     "Status {}: {}",
                     status, body
                 )));
@@ -291,6 +553,12 @@ impl GitLabClient {
         .await
     }
 
+    /// Get a project by its numeric ID (as returned by code search)
+    pub async fn get_project_by_id(&self, id: u64) -> Result<GitLabProject> {
+        // The projects endpoint accepts either an ID or an encoded path
+        self.get_project(&id.to_string()).await
+    }
+
     /// Get a specific project by path (e.g., "gitlab-org/gitlab")
     pub async fn get_project(&self, path: &str) -> Result<GitLabProject> {
         // GitLab uses URL-encoded paths
@@ -306,6 +574,7 @@ impl GitLabClient {
             }
 
             let response = request.send().await?;
+            self.record_rate_limit(&response);
 
             if response.status() == 404 {
                 return Err(GitLabError::NotFound(path.to_string()));
@@ -318,10 +587,10 @@ impl GitLabClient {
             if !response.status().is_success() {
                 let status = response.status();
                 let body = response.text().await.unwrap_or_default();
-                return Err(GitLabError::RequestFailed(format!(
-                    "Status {}: {}",
-                    status, body
-                )));
+                return Err(GitLabError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
             }
 
             let project: GitLabProject = response.json().await?;
@@ -329,6 +598,125 @@ impl GitLabClient {
         })
         .await
     }
+
+    /// The account the token belongs to, with its scopes and rate limit
+    pub async fn get_authenticated_user(&self) -> Result<AuthenticatedUser> {
+        let token = self.token.clone().ok_or(GitLabError::AuthRequired)?;
+        let url = format!("{}/user", self.base_url);
+
+        let user: GitLabUser = with_retry(&self.retry_config, || async {
+            let response = self
+                .client
+                .get(&url)
+                .header("PRIVATE-TOKEN", &token)
+                .send()
+                .await?;
+            self.record_rate_limit(&response);
+
+            if response.status() == 401 {
+                return Err(GitLabError::AuthRequired);
+            }
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(GitLabError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
+            Ok(response.json().await?)
+        })
+        .await?;
+
+        // Only personal access tokens can describe themselves (GitLab 15.5+)
+        let scopes = match self
+            .client
+            .get(format!("{}/personal_access_tokens/self", self.base_url))
+            .header("PRIVATE-TOKEN", &token)
+            .send()
+            .await
+        {
+            Ok(response) if response.status().is_success() => response
+                .json::<GitLabTokenInfo>()
+                .await
+                .ok()
+                .map(|info| info.scopes),
+            _ => None,
+        };
+
+        Ok(AuthenticatedUser {
+            username: user.username,
+            display_name: user.name,
+            scopes,
+            rate_limit: self.rate_limit(),
+        })
+    }
+
+    /// Number of open merge requests, read from the `x-total` pagination header
+    pub async fn get_open_merge_request_count(&self, path: &str) -> Result<u32> {
+        let encoded_path = urlencoding::encode(path);
+        let url = format!(
+            "{}/projects/{}/merge_requests?state=opened&per_page=1",
+            self.base_url, encoded_path
+        );
+        let token = self.token.clone();
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.client.get(&url);
+
+            if let Some(ref token) = token {
+                request = request.header("PRIVATE-TOKEN", token);
+            }
+
+            let response = request.send().await?;
+            self.record_rate_limit(&response);
+
+            if response.status() == 404 {
+                return Err(GitLabError::NotFound(path.to_string()));
+            }
+
+            if response.status() == 401 {
+                return Err(GitLabError::AuthRequired);
+            }
+
+            if !response.status().is_success() {
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(GitLabError::unexpected_status(
+                    status,
+                    format!("Status {}: {}", status, body),
+                ));
+            }
+
+            response
+                .headers()
+                .get("x-total")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse().ok())
+                .ok_or_else(|| GitLabError::RequestFailed("Missing x-total header".to_string()))
+        })
+        .await
+    }
+}
+
+/// The `/user` fields we show
+#[derive(Debug, Deserialize)]
+struct GitLabUser {
+    username: String,
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabTokenInfo {
+    scopes: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabRelease {
+    tag_name: String,
+    name: Option<String>,
+    description: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -355,6 +743,15 @@ pub struct GitLabProject {
     pub visibility: String,
     pub default_branch: Option<String>,
     pub namespace: GitLabNamespace,
+    /// Upstream project, only present on forks
+    #[serde(default)]
+    pub forked_from_project: Option<GitLabForkParent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitLabForkParent {
+    pub id: u64,
+    pub path_with_namespace: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -374,6 +771,7 @@ pub struct GitLabCodeSearchItem {
     pub path: String,
     pub filename: String,
     pub id: Option<u64>,
+    #[serde(rename = "ref")]
     pub ref_: Option<String>,
     pub startline: usize,
     pub project_id: u64,
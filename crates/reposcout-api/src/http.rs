@@ -0,0 +1,97 @@
+// Shared HTTP client for all platform APIs
+//
+// Every API client clones one process-wide reqwest::Client, so they share a
+// connection pool, the User-Agent and the request timeout. Without a timeout
+// a connection that stops responding blocks the caller forever.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+pub const USER_AGENT: &str = "reposcout/1.0.0";
+
+/// Request timeout unless `[network] timeout_secs` says otherwise
+pub const DEFAULT_TIMEOUT_SECS: u64 = 15;
+
+static TIMEOUT_SECS: AtomicU64 = AtomicU64::new(DEFAULT_TIMEOUT_SECS);
+static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+
+/// Set the request timeout. Only clients created afterwards see it, so call
+/// this at startup before any API client exists.
+pub fn set_timeout(timeout: Duration) {
+    TIMEOUT_SECS.store(timeout.as_secs().max(1), Ordering::Relaxed);
+}
+
+/// The configured request timeout
+pub fn timeout() -> Duration {
+    Duration::from_secs(TIMEOUT_SECS.load(Ordering::Relaxed))
+}
+
+/// The shared client, built on first use. Clones share its connection pool.
+pub fn client() -> reqwest::Client {
+    CLIENT.get_or_init(|| build_client(timeout())).clone()
+}
+
+fn build_client(timeout: Duration) -> reqwest::Client {
+    reqwest::Client::builder()
+        .user_agent(USER_AGENT)
+        .timeout(timeout)
+        .build()
+        .expect("Failed to build HTTP client")
+}
+
+/// Whether an error, or anything in its source chain, is a request that
+/// timed out
+pub fn is_timeout(error: &(dyn std::error::Error + 'static)) -> bool {
+    use crate::{
+        azure_devops::AzureDevOpsError, bitbucket::BitbucketError, github::GitHubError,
+        gitlab::GitLabError, sourcehut::SourceHutError,
+    };
+
+    let mut current = Some(error);
+    while let Some(error) = current {
+        let timed_out = error
+            .downcast_ref::<reqwest::Error>()
+            .is_some_and(reqwest::Error::is_timeout)
+            || matches!(error.downcast_ref(), Some(GitHubError::Timeout))
+            || matches!(error.downcast_ref(), Some(GitLabError::Timeout))
+            || matches!(error.downcast_ref(), Some(BitbucketError::Timeout))
+            || matches!(error.downcast_ref(), Some(SourceHutError::Timeout))
+            || matches!(error.downcast_ref(), Some(AzureDevOpsError::Timeout));
+        if timed_out {
+            return true;
+        }
+        current = error.source();
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::GitHubError;
+
+    #[tokio::test]
+    async fn test_hung_connection_times_out() {
+        // Accepts the connection but never answers
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let _server = tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            std::future::pending::<()>().await;
+        });
+
+        let error = build_client(Duration::from_millis(100))
+            .get(&url)
+            .send()
+            .await
+            .unwrap_err();
+        let error = GitHubError::from(error);
+
+        assert!(matches!(error, GitHubError::Timeout));
+        assert!(error.to_string().starts_with("Request timed out"));
+        let wrapped = anyhow::Error::from(error).context("Search failed");
+        assert!(is_timeout(wrapped.as_ref()));
+        assert!(!is_timeout(&GitHubError::AuthRequired));
+    }
+}
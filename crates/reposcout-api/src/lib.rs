@@ -1,13 +1,76 @@
 // API client implementations for various platforms
+pub mod auth;
+pub mod azure_devops;
 pub mod bitbucket;
+mod bitbucket_server;
+pub mod concurrency;
 pub mod github;
 pub mod gitlab;
+pub mod http;
 pub mod notifications;
 pub mod retry;
+pub mod sourcehut;
 
 // Re-export common types
+pub use auth::AuthenticatedUser;
+pub use azure_devops::{AzureDevOpsClient, AzureDevOpsRepository};
 pub use bitbucket::{BitbucketClient, BitbucketRepository};
+pub use concurrency::{fetch_concurrently, DEFAULT_CONCURRENCY};
 pub use github::{GitHubClient, GitHubRepo};
 pub use gitlab::{GitLabClient, GitLabProject};
-pub use notifications::{Notification, NotificationFilters, NotificationReason};
-pub use retry::RetryConfig;
+pub use notifications::{
+    Notification, NotificationFilters, NotificationReason, NOTIFICATION_SUBJECT_TYPES,
+};
+pub use retry::{RateLimitState, RetryConfig};
+pub use sourcehut::{SourceHutClient, SourceHutRepository};
+
+/// Changelog file names tried, in order, before falling back to release notes
+pub const CHANGELOG_FILES: &[&str] = &[
+    "CHANGELOG.md",
+    "CHANGES.md",
+    "HISTORY.md",
+    "RELEASES.md",
+    "CHANGELOG",
+];
+
+/// Lower-cased search words with `key:value` qualifiers dropped, for
+/// platforms that can only list repositories and match them locally
+pub(crate) fn search_terms(query: &str) -> Vec<String> {
+    query
+        .split_whitespace()
+        .filter(|word| !word.contains(':'))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Render a release as markdown so it can be shown like a changelog file
+pub fn format_release_notes(tag: &str, name: Option<&str>, body: Option<&str>) -> String {
+    let title = match name {
+        Some(name) if !name.trim().is_empty() && name != tag => format!("{} ({})", name, tag),
+        _ => tag.to_string(),
+    };
+    let body = body
+        .map(str::trim)
+        .filter(|b| !b.is_empty())
+        .unwrap_or("_No release notes provided._");
+
+    format!("# Latest release: {}\n\n{}\n", title, body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_release_notes() {
+        let notes = format_release_notes("v1.2.0", Some("Spring release"), Some("- Fixed bug\n"));
+        assert_eq!(
+            notes,
+            "# Latest release: Spring release (v1.2.0)\n\n- Fixed bug\n"
+        );
+
+        let empty = format_release_notes("v1.2.0", Some("v1.2.0"), Some("  "));
+        assert!(empty.starts_with("# Latest release: v1.2.0\n"));
+        assert!(empty.contains("No release notes provided"));
+    }
+}
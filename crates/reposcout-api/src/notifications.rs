@@ -83,6 +83,71 @@ impl std::fmt::Display for NotificationReason {
     }
 }
 
+impl NotificationReason {
+    /// Every reason GitHub documents, in the order the TUI cycles through them
+    pub const ALL: [NotificationReason; 11] = [
+        NotificationReason::Assign,
+        NotificationReason::Author,
+        NotificationReason::Comment,
+        NotificationReason::Invitation,
+        NotificationReason::Manual,
+        NotificationReason::Mention,
+        NotificationReason::ReviewRequested,
+        NotificationReason::SecurityAlert,
+        NotificationReason::StateChange,
+        NotificationReason::Subscribed,
+        NotificationReason::TeamMention,
+    ];
+
+    /// The value GitHub sends in a notification's `reason` field
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotificationReason::Assign => "assign",
+            NotificationReason::Author => "author",
+            NotificationReason::Comment => "comment",
+            NotificationReason::Invitation => "invitation",
+            NotificationReason::Manual => "manual",
+            NotificationReason::Mention => "mention",
+            NotificationReason::ReviewRequested => "review_requested",
+            NotificationReason::SecurityAlert => "security_alert",
+            NotificationReason::StateChange => "state_change",
+            NotificationReason::Subscribed => "subscribed",
+            NotificationReason::TeamMention => "team_mention",
+            NotificationReason::Other => "other",
+        }
+    }
+}
+
+impl std::str::FromStr for NotificationReason {
+    type Err = String;
+
+    /// Accepts the API value in any case, with `-` or `_` between words
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().to_lowercase().replace('-', "_");
+        Self::ALL
+            .into_iter()
+            .find(|reason| reason.as_str() == value)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|r| r.as_str()).collect();
+                format!(
+                    "Unknown notification reason '{}', use one of: {}",
+                    s,
+                    names.join(", ")
+                )
+            })
+    }
+}
+
+/// Subject types GitHub sends notifications for
+pub const NOTIFICATION_SUBJECT_TYPES: [&str; 6] = [
+    "Issue",
+    "PullRequest",
+    "Release",
+    "Commit",
+    "Discussion",
+    "CheckSuite",
+];
+
 /// Filters for notification queries
 #[derive(Debug, Clone, Default)]
 pub struct NotificationFilters {
@@ -118,8 +183,112 @@ impl NotificationFilters {
         self
     }
 
+    pub fn subject_type(mut self, subject_type: String) -> Self {
+        self.subject_type = Some(subject_type);
+        self
+    }
+
     pub fn participating(mut self) -> Self {
         self.participating = true;
         self
     }
+
+    /// Whether a fetched notification passes the repository, reason, subject
+    /// type and unread filters. Participating is left to the API, since a
+    /// notification doesn't say whether you're involved.
+    pub fn matches(&self, notification: &Notification) -> bool {
+        if self.unread_only && !notification.unread {
+            return false;
+        }
+        if let Some(ref repo) = self.repository {
+            if !notification.repository.full_name.eq_ignore_ascii_case(repo) {
+                return false;
+            }
+        }
+        if let Some(reason) = self.reason {
+            if notification.reason != reason.as_str() {
+                return false;
+            }
+        }
+        if let Some(ref subject_type) = self.subject_type {
+            // "pull-request" and "pull_request" should find PullRequest too
+            let wanted = subject_type.replace(['-', '_'], "");
+            if !notification
+                .subject
+                .subject_type
+                .eq_ignore_ascii_case(&wanted)
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn notification(reason: &str, subject_type: &str) -> Notification {
+        serde_json::from_value(serde_json::json!({
+            "id": "1",
+            "repository": {
+                "id": 1,
+                "name": "repo",
+                "full_name": "owner/repo",
+                "owner": { "login": "owner", "avatar_url": "" },
+                "html_url": "https://github.com/owner/repo"
+            },
+            "subject": {
+                "title": "Fix it",
+                "type": subject_type,
+                "url": null,
+                "latest_comment_url": null
+            },
+            "reason": reason,
+            "unread": true,
+            "updated_at": "2024-01-01T00:00:00Z",
+            "last_read_at": null,
+            "url": ""
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_reason_from_str() {
+        assert_eq!(
+            "review-requested".parse::<NotificationReason>(),
+            Ok(NotificationReason::ReviewRequested)
+        );
+        assert_eq!(
+            "Mention".parse::<NotificationReason>(),
+            Ok(NotificationReason::Mention)
+        );
+        for reason in NotificationReason::ALL {
+            assert_eq!(reason.as_str().parse(), Ok(reason));
+        }
+        assert!("other".parse::<NotificationReason>().is_err());
+        assert!("bogus".parse::<NotificationReason>().is_err());
+    }
+
+    #[test]
+    fn test_filters_match() {
+        let pr = notification("review_requested", "PullRequest");
+
+        assert!(NotificationFilters::new().matches(&pr));
+        assert!(NotificationFilters::new()
+            .reason(NotificationReason::ReviewRequested)
+            .subject_type("pull-request".to_string())
+            .repository("Owner/Repo".to_string())
+            .matches(&pr));
+        assert!(!NotificationFilters::new()
+            .reason(NotificationReason::Mention)
+            .matches(&pr));
+        assert!(!NotificationFilters::new()
+            .subject_type("Issue".to_string())
+            .matches(&pr));
+        assert!(!NotificationFilters::new()
+            .repository("owner/other".to_string())
+            .matches(&pr));
+    }
 }
@@ -1,4 +1,6 @@
 // Retry logic with exponential backoff
+use chrono::{DateTime, Utc};
+use reqwest::header::HeaderMap;
 use std::time::Duration;
 use tokio::time::sleep;
 use tracing::{debug, warn};
@@ -10,29 +12,105 @@ pub struct RetryConfig {
     pub initial_delay_ms: u64,
     pub max_delay_ms: u64,
     pub backoff_multiplier: f64,
+    /// Longest we'll sleep waiting for a rate limit to reset. If the server
+    /// asks for more, the error is returned instead of retrying.
+    pub max_rate_limit_wait_ms: u64,
 }
 
 impl Default for RetryConfig {
     fn default() -> Self {
         Self {
             max_retries: 3,
-            initial_delay_ms: 1000,  // Start with 1 second
-            max_delay_ms: 30000,     // Max 30 seconds
-            backoff_multiplier: 2.0, // Double each time
+            initial_delay_ms: 1000,        // Start with 1 second
+            max_delay_ms: 30000,           // Max 30 seconds
+            backoff_multiplier: 2.0,       // Double each time
+            max_rate_limit_wait_ms: 60000, // Wait up to a minute for a reset
         }
     }
 }
 
+/// Errors that know how long the server asked us to wait before retrying
+pub trait RetryHint {
+    /// Server-requested delay (e.g. until a rate limit resets), if any
+    fn retry_after(&self) -> Option<Duration> {
+        None
+    }
+
+    /// Whether trying again could succeed. Missing resources, rejected
+    /// credentials and malformed responses come back the same every time.
+    fn is_retryable(&self) -> bool {
+        true
+    }
+}
+
+impl RetryHint for &str {}
+
+/// Rate limit numbers from the last response's `X-RateLimit-*` headers
+/// (GitLab sends the same numbers as `RateLimit-*`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitState {
+    pub limit: u32,
+    pub remaining: u32,
+    pub reset_at: Option<DateTime<Utc>>,
+}
+
+impl RateLimitState {
+    /// Parse `X-RateLimit-Limit`/`-Remaining`/`-Reset`, if present
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let rate_header = |name: &str| {
+            header_u64(headers, &format!("x-ratelimit-{}", name))
+                .or_else(|| header_u64(headers, &format!("ratelimit-{}", name)))
+        };
+        let remaining = rate_header("remaining")?;
+        let limit = rate_header("limit").unwrap_or(remaining);
+        let reset_at = rate_header("reset").and_then(|ts| DateTime::from_timestamp(ts as i64, 0));
+
+        Some(Self {
+            limit: limit as u32,
+            remaining: remaining as u32,
+            reset_at,
+        })
+    }
+
+    /// True when fewer than 10% of requests are left
+    pub fn is_low(&self) -> bool {
+        (self.remaining as u64) * 10 < self.limit as u64
+    }
+}
+
+fn header_u64(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+/// How long to wait before retrying a rate-limited (403/429) response.
+///
+/// `Retry-After` (in seconds) wins; otherwise, if the quota is used up,
+/// wait until `X-RateLimit-Reset`. Returns None when the response carries
+/// no rate limit information, i.e. it's an ordinary 403.
+pub fn rate_limit_delay(headers: &HeaderMap, now: DateTime<Utc>) -> Option<Duration> {
+    if let Some(secs) = header_u64(headers, "retry-after") {
+        return Some(Duration::from_secs(secs));
+    }
+
+    let state = RateLimitState::from_headers(headers)?;
+    if state.remaining > 0 {
+        return None;
+    }
+    let reset_at = state.reset_at?;
+    Some((reset_at - now).to_std().unwrap_or_default())
+}
+
 /// Execute a function with retry logic
 ///
 /// Uses exponential backoff: if a request fails, we wait progressively
 /// longer before trying again. This is polite to APIs and helps when
-/// there are temporary network issues.
+/// there are temporary network issues. Rate-limited errors instead wait
+/// exactly as long as the server asked, up to `max_rate_limit_wait_ms`.
 pub async fn with_retry<F, Fut, T, E>(config: &RetryConfig, mut operation: F) -> Result<T, E>
 where
     F: FnMut() -> Fut,
     Fut: std::future::Future<Output = Result<T, E>>,
-    E: std::fmt::Display,
+    E: std::fmt::Display + RetryHint,
 {
     let mut attempt = 0;
     let mut delay_ms = config.initial_delay_ms;
@@ -46,16 +124,8 @@ where
                 return Ok(result);
             }
             Err(err) => {
-                // Check if this is a retryable error before incrementing attempt
                 // Don't retry client errors like auth failures, 404s, etc.
-                let err_msg = err.to_string();
-                let is_retryable = !err_msg.contains("Authentication required")
-                    && !err_msg.contains("Not found")
-                    && !err_msg.contains("Unauthorized")
-                    && !err_msg.contains("Forbidden")
-                    && !err_msg.contains("Bad request");
-
-                if !is_retryable {
+                if !err.is_retryable() {
                     debug!("Non-retryable error: {}", err);
                     return Err(err);
                 }
@@ -70,6 +140,22 @@ where
                     return Err(err);
                 }
 
+                if let Some(wait) = err.retry_after() {
+                    let wait_ms = wait.as_millis() as u64;
+                    if wait_ms > config.max_rate_limit_wait_ms {
+                        // Retrying before the reset can't succeed
+                        warn!("Rate limited for {}ms, not waiting: {}", wait_ms, err);
+                        return Err(err);
+                    }
+
+                    warn!(
+                        "Rate limited (attempt {}/{}): {}. Retrying in {}ms...",
+                        attempt, config.max_retries, err, wait_ms
+                    );
+                    sleep(wait).await;
+                    continue;
+                }
+
                 warn!(
                     "Request failed (attempt {}/{}): {}. Retrying in {}ms...",
                     attempt, config.max_retries, err, delay_ms
@@ -127,6 +213,7 @@ mod tests {
             initial_delay_ms: 10, // Fast for testing
             max_delay_ms: 100,
             backoff_multiplier: 2.0,
+            max_rate_limit_wait_ms: 100,
         };
         let call_count = AtomicU32::new(0);
 
@@ -153,6 +240,7 @@ mod tests {
             initial_delay_ms: 10,
             max_delay_ms: 100,
             backoff_multiplier: 2.0,
+            max_rate_limit_wait_ms: 100,
         };
         let call_count = AtomicU32::new(0);
 
@@ -166,6 +254,29 @@ mod tests {
         assert_eq!(call_count.load(Ordering::SeqCst), 3); // Initial attempt + 2 retries
     }
 
+    #[tokio::test]
+    async fn test_retry_skips_errors_that_wont_change() {
+        use crate::gitlab::GitLabError;
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let config = RetryConfig {
+            initial_delay_ms: 10,
+            ..Default::default()
+        };
+        let call_count = AtomicU32::new(0);
+
+        let result = with_retry(&config, || async {
+            call_count.fetch_add(1, Ordering::SeqCst);
+            Err::<i32, _>(GitLabError::NotFound("group/project".to_string()))
+        })
+        .await;
+
+        assert!(matches!(result, Err(GitLabError::NotFound(_))));
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+        assert!(!GitLabError::Rejected("Status 422".to_string()).is_retryable());
+        assert!(GitLabError::Timeout.is_retryable());
+    }
+
     #[test]
     fn test_retryable_status_codes() {
         assert!(is_retryable_status(
@@ -181,4 +292,48 @@ mod tests {
         assert!(!is_retryable_status(reqwest::StatusCode::BAD_REQUEST));
         assert!(!is_retryable_status(reqwest::StatusCode::UNAUTHORIZED));
     }
+
+    #[test]
+    fn test_rate_limit_delay_from_retry_after() {
+        use reqwest::header::HeaderValue;
+
+        let now = DateTime::from_timestamp(1_700_000_000, 0).unwrap();
+        let mut headers = HeaderMap::new();
+        headers.insert("retry-after", HeaderValue::from_static("2"));
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("0"));
+        headers.insert(
+            "x-ratelimit-reset",
+            HeaderValue::from_str(&(now.timestamp() + 600).to_string()).unwrap(),
+        );
+        assert_eq!(
+            rate_limit_delay(&headers, now),
+            Some(Duration::from_secs(2))
+        );
+
+        // Without Retry-After, wait for the quota to reset
+        headers.remove("retry-after");
+        assert_eq!(
+            rate_limit_delay(&headers, now),
+            Some(Duration::from_secs(600))
+        );
+
+        // Quota left means the 403 wasn't a rate limit
+        headers.insert("x-ratelimit-remaining", HeaderValue::from_static("12"));
+        assert_eq!(rate_limit_delay(&headers, now), None);
+    }
+
+    #[test]
+    fn test_rate_limit_state_reads_gitlab_headers() {
+        use reqwest::header::HeaderValue;
+
+        let mut headers = HeaderMap::new();
+        headers.insert("ratelimit-limit", HeaderValue::from_static("2000"));
+        headers.insert("ratelimit-remaining", HeaderValue::from_static("150"));
+        let state = RateLimitState::from_headers(&headers).unwrap();
+        assert_eq!((state.remaining, state.limit), (150, 2000));
+        assert!(state.is_low());
+        assert_eq!(state.reset_at, None);
+
+        assert_eq!(RateLimitState::from_headers(&HeaderMap::new()), None);
+    }
 }
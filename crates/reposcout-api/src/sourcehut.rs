@@ -0,0 +1,451 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::time::Duration;
+use thiserror::Error;
+
+use crate::retry::{is_retryable_status, rate_limit_delay, with_retry, RetryConfig, RetryHint};
+
+const SOURCEHUT_GIT_BASE: &str = "https://git.sr.ht";
+
+/// Fields requested for every repository
+const REPOSITORY_FIELDS: &str = "
+    name
+    description
+    visibility
+    created
+    updated
+    owner { canonicalName }
+    HEAD { name }
+";
+
+#[derive(Error, Debug)]
+pub enum SourceHutError {
+    #[error("API request failed: {0}")]
+    RequestFailed(String),
+
+    #[error("GraphQL error: {0}")]
+    GraphQL(String),
+
+    #[error("Rate limit exceeded")]
+    RateLimitExceeded { retry_after: Option<Duration> },
+
+    #[error("Repository not found: {0}")]
+    NotFound(String),
+
+    #[error("Authentication required (set SOURCEHUT_TOKEN)")]
+    AuthRequired,
+
+    #[error(
+        "Request timed out after {}s (raise [network] timeout_secs on slow connections)",
+        crate::http::timeout().as_secs()
+    )]
+    Timeout,
+
+    #[error("Network error: {0}")]
+    NetworkError(reqwest::Error),
+
+    #[error("Request rejected: {0}")]
+    Rejected(String),
+
+    #[error("JSON parsing failed: {0}")]
+    ParseError(String),
+}
+
+impl From<reqwest::Error> for SourceHutError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            SourceHutError::Timeout
+        } else if error.is_decode() {
+            // The server answered, but not with what we expected
+            SourceHutError::ParseError(error.to_string())
+        } else {
+            SourceHutError::NetworkError(error)
+        }
+    }
+}
+
+impl From<serde_json::Error> for SourceHutError {
+    fn from(error: serde_json::Error) -> Self {
+        SourceHutError::ParseError(error.to_string())
+    }
+}
+
+impl SourceHutError {
+    /// Error for a status we didn't handle. Only 5xx, 408 and 429 are
+    /// worth retrying; any other client error comes back the same.
+    fn unexpected_status(status: reqwest::StatusCode, message: String) -> Self {
+        if is_retryable_status(status) {
+            SourceHutError::RequestFailed(message)
+        } else {
+            SourceHutError::Rejected(message)
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, SourceHutError>;
+
+impl RetryHint for SourceHutError {
+    fn retry_after(&self) -> Option<Duration> {
+        match self {
+            SourceHutError::RateLimitExceeded { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+
+    fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            SourceHutError::RequestFailed(_)
+                | SourceHutError::RateLimitExceeded { .. }
+                | SourceHutError::Timeout
+                | SourceHutError::NetworkError(_)
+        )
+    }
+}
+
+/// Client for git.sr.ht's GraphQL API
+///
+/// SourceHut has no global repository search. Searches cover the token
+/// owner's repositories, or another user's public ones when the query
+/// starts with `~username`. Every GraphQL call needs a personal access token.
+#[derive(Clone)]
+pub struct SourceHutClient {
+    client: reqwest::Client,
+    token: Option<String>,
+    base_url: String,
+    retry_config: RetryConfig,
+}
+
+impl SourceHutClient {
+    pub fn new(token: Option<String>) -> Self {
+        Self::with_base_url(token, SOURCEHUT_GIT_BASE.to_string())
+    }
+
+    /// For self-hosted SourceHut instances or testing with a custom URL
+    pub fn with_base_url(token: Option<String>, base_url: String) -> Self {
+        Self {
+            client: crate::http::client(),
+            token,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            retry_config: RetryConfig::default(),
+        }
+    }
+
+    /// Create client with custom retry configuration
+    pub fn with_retry_config(token: Option<String>, retry_config: RetryConfig) -> Self {
+        let mut client = Self::new(token);
+        client.retry_config = retry_config;
+        client
+    }
+
+    /// Run a GraphQL query and return its `data` object
+    async fn graphql(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        let token = self.token.as_ref().ok_or(SourceHutError::AuthRequired)?;
+        let url = format!("{}/query", self.base_url);
+        let body = json!({ "query": query, "variables": variables });
+
+        with_retry(&self.retry_config, || async {
+            let response = self
+                .client
+                .post(&url)
+                .bearer_auth(token)
+                .json(&body)
+                .send()
+                .await?;
+
+            let status = response.status();
+
+            if status == 401 || status == 403 {
+                return Err(SourceHutError::AuthRequired);
+            }
+
+            if status == 429 {
+                return Err(SourceHutError::RateLimitExceeded {
+                    retry_after: rate_limit_delay(response.headers(), Utc::now()),
+                });
+            }
+
+            if status.is_client_error() && !is_retryable_status(status) {
+                let body = response.text().await.unwrap_or_default();
+                return Err(SourceHutError::Rejected(format!(
+                    "Status {}: {}",
+                    status, body
+                )));
+            }
+
+            if !status.is_success() {
+                return Err(SourceHutError::RequestFailed(format!("Status {}", status)));
+            }
+
+            let response: GraphQLResponse = response.json().await?;
+            if let Some(error) = response.errors.first() {
+                return Err(SourceHutError::GraphQL(error.message.clone()));
+            }
+            Ok(response.data.unwrap_or_default())
+        })
+        .await
+    }
+
+    /// Search repositories
+    ///
+    /// A leading `~username` searches that user's repositories, otherwise the
+    /// token owner's. GitHub-style qualifiers (`language:rust`, `stars:>10`)
+    /// are dropped since SourceHut only matches on name and description.
+    /// Without a token this returns no results rather than failing the search.
+    pub async fn search_repositories(
+        &self,
+        query: &str,
+        per_page: u32,
+    ) -> Result<Vec<SourceHutRepository>> {
+        if self.token.is_none() {
+            return Ok(Vec::new());
+        }
+
+        let (user, search) = parse_search_query(query);
+        let filter = json!({ "search": search, "count": per_page });
+
+        let data = match &user {
+            Some(username) => {
+                let query = format!(
+                    "query($username: String!, $filter: Filter) {{
+                        user(username: $username) {{
+                            repositories(filter: $filter) {{ results {{ {} }} }}
+                        }}
+                    }}",
+                    REPOSITORY_FIELDS
+                );
+                let data = self
+                    .graphql(&query, json!({ "username": username, "filter": filter }))
+                    .await?;
+                data["user"]["repositories"]["results"].clone()
+            }
+            None => {
+                let query = format!(
+                    "query($filter: Filter) {{
+                        repositories(filter: $filter) {{ results {{ {} }} }}
+                    }}",
+                    REPOSITORY_FIELDS
+                );
+                let data = self.graphql(&query, json!({ "filter": filter })).await?;
+                data["repositories"]["results"].clone()
+            }
+        };
+
+        if data.is_null() {
+            return Ok(Vec::new());
+        }
+        Ok(serde_json::from_value(data)?)
+    }
+
+    /// Get detailed info about a specific repository
+    pub async fn get_repository(&self, owner: &str, name: &str) -> Result<SourceHutRepository> {
+        let query = format!(
+            "query($username: String!, $name: String!) {{
+                user(username: $username) {{ repository(name: $name) {{ {} }} }}
+            }}",
+            REPOSITORY_FIELDS
+        );
+        let data = self
+            .graphql(
+                &query,
+                json!({ "username": owner.trim_start_matches('~'), "name": name }),
+            )
+            .await?;
+
+        let repo = &data["user"]["repository"];
+        if repo.is_null() {
+            return Err(SourceHutError::NotFound(format!(
+                "~{}/{}",
+                owner.trim_start_matches('~'),
+                name
+            )));
+        }
+        Ok(serde_json::from_value(repo.clone())?)
+    }
+
+    /// Get repository README content
+    pub async fn get_readme(&self, owner: &str, name: &str) -> Result<String> {
+        for readme_name in &["README.md", "README", "README.rst", "readme.md"] {
+            match self.get_file_content(owner, name, readme_name).await {
+                Ok(content) => return Ok(content),
+                Err(SourceHutError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(SourceHutError::NotFound(format!(
+            "README not found for ~{}/{}",
+            owner.trim_start_matches('~'),
+            name
+        )))
+    }
+
+    /// Get the changelog file (SourceHut has no releases API to fall back on)
+    pub async fn get_changelog(&self, owner: &str, name: &str) -> Result<String> {
+        for file in crate::CHANGELOG_FILES {
+            match self.get_file_content(owner, name, file).await {
+                Ok(content) => return Ok(content),
+                Err(SourceHutError::NotFound(_)) => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(SourceHutError::NotFound(format!(
+            "Changelog not found for ~{}/{}",
+            owner.trim_start_matches('~'),
+            name
+        )))
+    }
+
+    /// Get raw file content from the default branch
+    pub async fn get_file_content(&self, owner: &str, name: &str, path: &str) -> Result<String> {
+        let owner = owner.trim_start_matches('~');
+        let url = format!("{}/~{}/{}/blob/HEAD/{}", self.base_url, owner, name, path);
+
+        with_retry(&self.retry_config, || async {
+            let mut request = self.client.get(&url);
+            if let Some(token) = &self.token {
+                request = request.bearer_auth(token);
+            }
+
+            let response = request.send().await?;
+
+            if response.status() == 404 {
+                return Err(SourceHutError::NotFound(format!(
+                    "{} not found in ~{}/{}",
+                    path, owner, name
+                )));
+            }
+
+            if !response.status().is_success() {
+                return Err(SourceHutError::unexpected_status(
+                    response.status(),
+                    format!("Status {}", response.status()),
+                ));
+            }
+
+            Ok(response.text().await?)
+        })
+        .await
+    }
+
+    /// Web URL of a repository
+    pub fn repository_url(&self, repo: &SourceHutRepository) -> String {
+        format!("{}/{}", self.base_url, repo.full_name())
+    }
+}
+
+/// Split an optional leading `~username` from the search terms and drop
+/// GitHub-style `key:value` qualifiers
+fn parse_search_query(query: &str) -> (Option<String>, String) {
+    let mut user = None;
+    let mut terms = Vec::new();
+
+    for (i, word) in query.split_whitespace().enumerate() {
+        if i == 0 && word.starts_with('~') && word.len() > 1 {
+            user = Some(word.trim_start_matches('~').to_string());
+        } else if !word.contains(':') {
+            terms.push(word);
+        }
+    }
+
+    (user, terms.join(" "))
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLResponse {
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+    #[serde(default)]
+    errors: Vec<GraphQLError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQLError {
+    message: String,
+}
+
+/// SourceHut repository representation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceHutRepository {
+    pub name: String,
+    pub description: Option<String>,
+    /// PUBLIC, UNLISTED or PRIVATE
+    pub visibility: String,
+    pub created: DateTime<Utc>,
+    pub updated: DateTime<Utc>,
+    pub owner: SourceHutOwner,
+    /// Default branch reference, None for empty repositories
+    #[serde(rename = "HEAD", default)]
+    pub head: Option<SourceHutReference>,
+}
+
+impl SourceHutRepository {
+    /// "~owner/name", the way SourceHut writes repository paths
+    pub fn full_name(&self) -> String {
+        format!("{}/{}", self.owner.canonical_name, self.name)
+    }
+
+    /// Default branch name without the refs/heads/ prefix
+    pub fn default_branch(&self) -> Option<&str> {
+        self.head
+            .as_ref()
+            .map(|head| head.name.trim_start_matches("refs/heads/"))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceHutOwner {
+    /// Username with the leading tilde, e.g. "~sircmpwn"
+    #[serde(rename = "canonicalName")]
+    pub canonical_name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SourceHutReference {
+    pub name: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_search_query() {
+        assert_eq!(
+            parse_search_query("~sircmpwn mail language:c stars:>=10"),
+            (Some("sircmpwn".to_string()), "mail".to_string())
+        );
+        assert_eq!(
+            parse_search_query("static site"),
+            (None, "static site".to_string())
+        );
+        assert_eq!(parse_search_query("~"), (None, "~".to_string()));
+    }
+
+    #[test]
+    fn test_repository_deserialization() {
+        let json = r#"{
+            "name": "scdoc",
+            "description": "Simple man page generator",
+            "visibility": "PUBLIC",
+            "created": "2017-12-10T18:24:05.000000Z",
+            "updated": "2024-03-01T10:00:00.000000Z",
+            "owner": {"canonicalName": "~sircmpwn"},
+            "HEAD": {"name": "refs/heads/master"}
+        }"#;
+
+        let repo: SourceHutRepository = serde_json::from_str(json).unwrap();
+        assert_eq!(repo.full_name(), "~sircmpwn/scdoc");
+        assert_eq!(repo.default_branch(), Some("master"));
+        assert_eq!(
+            SourceHutClient::new(None).repository_url(&repo),
+            "https://git.sr.ht/~sircmpwn/scdoc"
+        );
+    }
+}
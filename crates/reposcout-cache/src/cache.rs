@@ -20,6 +20,19 @@ pub enum CacheError {
 
 pub type Result<T> = std::result::Result<T, CacheError>;
 
+/// Default cap on cached repositories and cached queries (each)
+pub const DEFAULT_MAX_ENTRIES: usize = 5000;
+
+/// Star counts seen for a repository within this long of its last snapshot
+/// update that snapshot instead of adding one
+const STAR_SNAPSHOT_INTERVAL_SECS: i64 = 60 * 60;
+
+/// Star snapshots kept per repository, oldest dropped first
+const MAX_STAR_SNAPSHOTS: i64 = 200;
+
+/// Star snapshots older than this are dropped by [`CacheManager::cleanup_expired`]
+const STAR_HISTORY_MAX_AGE_SECS: i64 = 365 * 24 * 60 * 60;
+
 /// Cache manager using SQLite + FTS5
 ///
 /// SQLite was chosen because:
@@ -30,10 +43,19 @@ pub type Result<T> = std::result::Result<T, CacheError>;
 pub struct CacheManager {
     conn: Connection,
     ttl_seconds: i64,
+    query_ttl_seconds: i64,
+    max_entries: usize,
+    stale_reads: bool,
 }
 
 impl CacheManager {
     pub fn new(db_path: &str, ttl_hours: u64) -> Result<Self> {
+        Self::with_ttls(db_path, ttl_hours, ttl_hours)
+    }
+
+    /// Open the cache with separate TTLs for repository lookups and cached
+    /// query results (search and trending), since query results go stale sooner
+    pub fn with_ttls(db_path: &str, repo_ttl_hours: u64, query_ttl_hours: u64) -> Result<Self> {
         let conn = Connection::open(db_path)?;
 
         // Initialize schema on first run
@@ -41,10 +63,32 @@ impl CacheManager {
 
         Ok(Self {
             conn,
-            ttl_seconds: (ttl_hours * 3600) as i64,
+            ttl_seconds: (repo_ttl_hours * 3600) as i64,
+            query_ttl_seconds: (query_ttl_hours * 3600) as i64,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            stale_reads: false,
         })
     }
 
+    /// Cap the number of cached repositories and cached queries.
+    /// Least recently used entries are evicted on insert once the cap is hit.
+    pub fn with_max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries.max(1);
+        self
+    }
+
+    /// Serve expired entries instead of returning `CacheError::Expired`
+    /// (offline mode, where stale data beats no data)
+    pub fn with_stale_reads(mut self, stale_reads: bool) -> Self {
+        self.stale_reads = stale_reads;
+        self
+    }
+
+    /// Current entry cap
+    pub fn max_entries(&self) -> usize {
+        self.max_entries
+    }
+
     fn init_schema(conn: &Connection) -> SqlResult<()> {
         // Create repositories table
         conn.execute(
@@ -54,6 +98,7 @@ impl CacheManager {
                 full_name TEXT NOT NULL,
                 data TEXT NOT NULL,
                 cached_at INTEGER NOT NULL,
+                last_accessed INTEGER NOT NULL DEFAULT 0,
                 UNIQUE(platform, full_name)
             )",
             [],
@@ -83,6 +128,90 @@ impl CacheManager {
             [],
         )?;
 
+        // Full-text index over bookmark names, tags, notes and descriptions,
+        // kept in sync with the bookmarks table by triggers
+        let had_bookmarks_fts: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE name = 'bookmarks_fts'",
+            [],
+            |row| row.get(0),
+        )?;
+        conn.execute_batch(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS bookmarks_fts
+             USING fts5(full_name, tags, notes, description);
+
+             CREATE TRIGGER IF NOT EXISTS bookmarks_fts_insert AFTER INSERT ON bookmarks BEGIN
+                 INSERT INTO bookmarks_fts (rowid, full_name, tags, notes, description)
+                 VALUES (new.id, new.full_name, new.tags, new.notes,
+                         json_extract(new.data, '$.description'));
+             END;
+
+             CREATE TRIGGER IF NOT EXISTS bookmarks_fts_update AFTER UPDATE ON bookmarks BEGIN
+                 DELETE FROM bookmarks_fts WHERE rowid = old.id;
+                 INSERT INTO bookmarks_fts (rowid, full_name, tags, notes, description)
+                 VALUES (new.id, new.full_name, new.tags, new.notes,
+                         json_extract(new.data, '$.description'));
+             END;
+
+             CREATE TRIGGER IF NOT EXISTS bookmarks_fts_delete AFTER DELETE ON bookmarks BEGIN
+                 DELETE FROM bookmarks_fts WHERE rowid = old.id;
+             END;",
+        )?;
+        // Databases from before the index existed need their bookmarks indexed once
+        if !had_bookmarks_fts {
+            conn.execute(
+                "INSERT INTO bookmarks_fts (rowid, full_name, tags, notes, description)
+                 SELECT id, full_name, tags, notes, json_extract(data, '$.description')
+                 FROM bookmarks",
+                [],
+            )?;
+        }
+
+        // Create watched table
+        // Repositories to poll for new pushes and star milestones
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS watched (
+                id INTEGER PRIMARY KEY,
+                platform TEXT NOT NULL,
+                full_name TEXT NOT NULL,
+                last_seen_pushed INTEGER NOT NULL,
+                last_seen_stars INTEGER NOT NULL,
+                watched_at INTEGER NOT NULL,
+                UNIQUE(platform, full_name)
+            )",
+            [],
+        )?;
+
+        // Create reviewed table
+        // Repositories already evaluated while triaging, so they can be dimmed or hidden
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS reviewed (
+                id INTEGER PRIMARY KEY,
+                platform TEXT NOT NULL,
+                full_name TEXT NOT NULL,
+                reviewed_at INTEGER NOT NULL,
+                UNIQUE(platform, full_name)
+            )",
+            [],
+        )?;
+
+        // Create star history table
+        // Star counts seen each time a repository is fetched, for real growth trends
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS star_history (
+                id INTEGER PRIMARY KEY,
+                platform TEXT NOT NULL,
+                full_name TEXT NOT NULL,
+                recorded_at INTEGER NOT NULL,
+                stars INTEGER NOT NULL
+            )",
+            [],
+        )?;
+        conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_star_history_repo
+             ON star_history(platform, full_name, recorded_at)",
+            [],
+        )?;
+
         // Create search history table
         // Tracks previous searches for quick re-run and auto-complete
         conn.execute(
@@ -111,7 +240,8 @@ impl CacheManager {
                 query_hash TEXT NOT NULL UNIQUE,
                 query TEXT NOT NULL,
                 results TEXT NOT NULL,
-                cached_at INTEGER NOT NULL
+                cached_at INTEGER NOT NULL,
+                last_accessed INTEGER NOT NULL DEFAULT 0
             )",
             [],
         )?;
@@ -123,9 +253,56 @@ impl CacheManager {
             [],
         )?;
 
+        // Databases created before LRU eviction lack the access timestamp
+        Self::ensure_column(
+            conn,
+            "repositories",
+            "last_accessed",
+            "INTEGER NOT NULL DEFAULT 0",
+        )?;
+        Self::ensure_column(
+            conn,
+            "query_cache",
+            "last_accessed",
+            "INTEGER NOT NULL DEFAULT 0",
+        )?;
+
+        Ok(())
+    }
+
+    /// Add a column to an existing table if it isn't there yet
+    fn ensure_column(conn: &Connection, table: &str, column: &str, decl: &str) -> SqlResult<()> {
+        let mut stmt = conn.prepare(&format!("PRAGMA table_info({})", table))?;
+        let exists = stmt
+            .query_map([], |row| row.get::<_, String>(1))?
+            .filter_map(|r| r.ok())
+            .any(|name| name == column);
+
+        if !exists {
+            conn.execute(
+                &format!("ALTER TABLE {} ADD COLUMN {} {}", table, column, decl),
+                [],
+            )?;
+        }
         Ok(())
     }
 
+    /// Drop least recently used rows from a table until it fits the cap.
+    /// Rows never read since insert fall back to their insert time.
+    fn evict_lru(&self, table: &str) -> Result<usize> {
+        let evicted = self.conn.execute(
+            &format!(
+                "DELETE FROM {table} WHERE id IN (
+                    SELECT id FROM {table}
+                    ORDER BY MAX(last_accessed, cached_at) DESC, id DESC
+                    LIMIT -1 OFFSET ?1
+                )"
+            ),
+            params![self.max_entries as i64],
+        )?;
+        Ok(evicted)
+    }
+
     /// Store a repository in cache
     pub fn set<T: Serialize>(&self, platform: &str, full_name: &str, data: &T) -> Result<()> {
         let json = serde_json::to_string(data)?;
@@ -159,8 +336,8 @@ impl CacheManager {
 
         // Insert new entry
         self.conn.execute(
-            "INSERT INTO repositories (platform, full_name, data, cached_at)
-             VALUES (?1, ?2, ?3, ?4)",
+            "INSERT INTO repositories (platform, full_name, data, cached_at, last_accessed)
+             VALUES (?1, ?2, ?3, ?4, ?4)",
             params![platform, full_name, json, now],
         )?;
 
@@ -172,6 +349,8 @@ impl CacheManager {
             params![row_id, full_name, description, topics],
         )?;
 
+        self.evict_lru("repositories")?;
+
         Ok(())
     }
 
@@ -192,10 +371,15 @@ impl CacheManager {
             .unwrap()
             .as_secs() as i64;
 
-        if now - cached_at > self.ttl_seconds {
+        if now - cached_at > self.ttl_seconds && !self.stale_reads {
             return Err(CacheError::Expired);
         }
 
+        self.conn.execute(
+            "UPDATE repositories SET last_accessed = ?1 WHERE platform = ?2 AND full_name = ?3",
+            params![now, platform, full_name],
+        )?;
+
         Ok(serde_json::from_str(&data)?)
     }
 
@@ -225,6 +409,25 @@ impl CacheManager {
         Ok(results)
     }
 
+    /// Search cached repositories for free text, best matches first.
+    ///
+    /// Unlike `search`, the query isn't FTS5 syntax: each word matches as a
+    /// prefix, and search qualifiers like `language:rust` are ignored.
+    pub fn search_text<T: for<'de> Deserialize<'de>>(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<T>> {
+        let words: Vec<&str> = query
+            .split_whitespace()
+            .filter(|word| !word.contains(':'))
+            .collect();
+        match fts_match_expr(&words.join(" ")) {
+            Some(expr) => self.search(&expr, limit),
+            None => Ok(Vec::new()),
+        }
+    }
+
     /// Get all cached repositories (useful for offline mode)
     pub fn get_all<T: for<'de> Deserialize<'de>>(&self, limit: usize) -> Result<Vec<T>> {
         let mut stmt = self
@@ -247,9 +450,37 @@ impl CacheManager {
     pub fn clear(&self) -> Result<()> {
         self.conn.execute("DELETE FROM repositories", [])?;
         self.conn.execute("DELETE FROM search_history", [])?;
+        self.conn.execute("DELETE FROM star_history", [])?;
         Ok(())
     }
 
+    /// Drop one repository's cached entry and star history so the next
+    /// lookup fetches it fresh. With no `platform`, drops it on every
+    /// platform. Names match case-insensitively. Returns the number of
+    /// cached entries removed.
+    pub fn invalidate_repo(&self, platform: Option<&str>, full_name: &str) -> Result<usize> {
+        let mut removed = 0;
+        for table in ["repositories", "star_history"] {
+            let deleted = match platform {
+                Some(platform) => self.conn.execute(
+                    &format!(
+                        "DELETE FROM {table}
+                         WHERE platform = ?1 COLLATE NOCASE AND full_name = ?2 COLLATE NOCASE"
+                    ),
+                    params![platform, full_name],
+                )?,
+                None => self.conn.execute(
+                    &format!("DELETE FROM {table} WHERE full_name = ?1 COLLATE NOCASE"),
+                    params![full_name],
+                )?,
+            };
+            if table == "repositories" {
+                removed = deleted;
+            }
+        }
+        Ok(removed)
+    }
+
     /// Delete expired entries
     pub fn cleanup_expired(&self) -> Result<usize> {
         let now = SystemTime::now()
@@ -263,6 +494,10 @@ impl CacheManager {
             "DELETE FROM repositories WHERE cached_at < ?1",
             params![cutoff],
         )?;
+        self.conn.execute(
+            "DELETE FROM star_history WHERE recorded_at < ?1",
+            params![now - STAR_HISTORY_MAX_AGE_SECS],
+        )?;
 
         Ok(deleted)
     }
@@ -293,7 +528,7 @@ impl CacheManager {
 
         let query_expired: i64 = self.conn.query_row(
             "SELECT COUNT(*) FROM query_cache WHERE cached_at < ?1",
-            params![cutoff],
+            params![now - self.query_ttl_seconds],
             |row| row.get(0),
         )?;
 
@@ -319,6 +554,7 @@ impl CacheManager {
             query_cache_expired: query_expired as usize,
             bookmarks_count: bookmarks as usize,
             size_bytes: size_bytes as usize,
+            max_entries: self.max_entries,
         })
     }
 
@@ -339,15 +575,66 @@ impl CacheManager {
             .unwrap()
             .as_secs() as i64;
 
+        // Upsert rather than INSERT OR REPLACE: REPLACE's implicit delete
+        // doesn't fire triggers, which would leave stale rows in bookmarks_fts
         self.conn.execute(
-            "INSERT OR REPLACE INTO bookmarks (platform, full_name, data, bookmarked_at, tags, notes)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            "INSERT INTO bookmarks (platform, full_name, data, bookmarked_at, tags, notes)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(platform, full_name) DO UPDATE SET
+                 data = excluded.data,
+                 bookmarked_at = excluded.bookmarked_at,
+                 tags = excluded.tags,
+                 notes = excluded.notes",
             params![platform, full_name, json, now, tags, notes],
         )?;
 
         Ok(())
     }
 
+    /// Replace a bookmark's stored repository snapshot, keeping its
+    /// bookmark time, tags and notes. Returns false if it isn't bookmarked
+    pub fn update_bookmark_data<T: Serialize>(
+        &self,
+        platform: &str,
+        full_name: &str,
+        data: &T,
+    ) -> Result<bool> {
+        let json = serde_json::to_string(data)?;
+        let updated = self.conn.execute(
+            "UPDATE bookmarks SET data = ?3 WHERE platform = ?1 AND full_name = ?2",
+            params![platform, full_name, json],
+        )?;
+        Ok(updated > 0)
+    }
+
+    /// Replace a bookmark's tags (comma-separated). Returns false if it isn't bookmarked
+    pub fn update_bookmark_tags(
+        &self,
+        platform: &str,
+        full_name: &str,
+        tags: Option<&str>,
+    ) -> Result<bool> {
+        let updated = self.conn.execute(
+            "UPDATE bookmarks SET tags = ?3 WHERE platform = ?1 AND full_name = ?2",
+            params![platform, full_name, tags],
+        )?;
+        Ok(updated > 0)
+    }
+
+    /// Replace a bookmark's notes. Returns false if it isn't bookmarked
+    pub fn update_bookmark_notes(
+        &self,
+        platform: &str,
+        full_name: &str,
+        notes: Option<&str>,
+    ) -> Result<bool> {
+        let updated = self.conn.execute(
+            "UPDATE bookmarks SET notes = ?3 WHERE platform = ?1 AND full_name = ?2",
+            params![platform, full_name, notes],
+        )?;
+        Ok(updated > 0)
+    }
+
     /// Remove a bookmark
     pub fn remove_bookmark(&self, platform: &str, full_name: &str) -> Result<()> {
         self.conn.execute(
@@ -409,6 +696,38 @@ impl CacheManager {
         Ok(results)
     }
 
+    /// Full-text search over bookmark names, tags, notes and descriptions,
+    /// best matches first. Each word matches as a prefix, and all must match
+    pub fn search_bookmarks(&self, query: &str) -> Result<Vec<BookmarkEntry>> {
+        let Some(match_expr) = fts_match_expr(query) else {
+            return self.get_bookmarks_with_metadata();
+        };
+
+        let mut stmt = self.conn.prepare(
+            "SELECT b.platform, b.full_name, b.data, b.bookmarked_at, b.tags, b.notes
+             FROM bookmarks b
+             INNER JOIN bookmarks_fts fts ON b.id = fts.rowid
+             WHERE bookmarks_fts MATCH ?1
+             ORDER BY rank",
+        )?;
+
+        let results = stmt
+            .query_map(params![match_expr], |row| {
+                Ok(BookmarkEntry {
+                    platform: row.get(0)?,
+                    full_name: row.get(1)?,
+                    data: row.get(2)?,
+                    bookmarked_at: row.get(3)?,
+                    tags: row.get(4)?,
+                    notes: row.get(5)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(results)
+    }
+
     /// Clear all bookmarks
     pub fn clear_bookmarks(&self) -> Result<()> {
         self.conn.execute("DELETE FROM bookmarks", [])?;
@@ -423,6 +742,201 @@ impl CacheManager {
         Ok(count as usize)
     }
 
+    // ===== Watched Methods =====
+
+    /// Start watching a repository, with its current push time (unix seconds)
+    /// and star count as the baseline. Watching it again resets the baseline.
+    pub fn add_watched(
+        &self,
+        platform: &str,
+        full_name: &str,
+        last_seen_pushed: i64,
+        last_seen_stars: u32,
+    ) -> Result<()> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        self.conn.execute(
+            "INSERT INTO watched (platform, full_name, last_seen_pushed, last_seen_stars, watched_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(platform, full_name) DO UPDATE SET
+                 last_seen_pushed = excluded.last_seen_pushed,
+                 last_seen_stars = excluded.last_seen_stars",
+            params![platform, full_name, last_seen_pushed, last_seen_stars, now],
+        )?;
+        Ok(())
+    }
+
+    /// Record what was seen on the latest check.
+    /// Returns false if the repository isn't watched.
+    pub fn update_watched(
+        &self,
+        platform: &str,
+        full_name: &str,
+        last_seen_pushed: i64,
+        last_seen_stars: u32,
+    ) -> Result<bool> {
+        let updated = self.conn.execute(
+            "UPDATE watched SET last_seen_pushed = ?3, last_seen_stars = ?4
+             WHERE platform = ?1 AND full_name = ?2",
+            params![platform, full_name, last_seen_pushed, last_seen_stars],
+        )?;
+        Ok(updated > 0)
+    }
+
+    /// Stop watching a repository. Returns false if it wasn't watched.
+    pub fn remove_watched(&self, platform: &str, full_name: &str) -> Result<bool> {
+        let removed = self.conn.execute(
+            "DELETE FROM watched WHERE platform = ?1 AND full_name = ?2",
+            params![platform, full_name],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// All watched repositories, oldest first
+    pub fn get_watched(&self) -> Result<Vec<WatchedEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT platform, full_name, last_seen_pushed, last_seen_stars, watched_at
+             FROM watched ORDER BY watched_at, id",
+        )?;
+
+        let results = stmt
+            .query_map([], |row| {
+                Ok(WatchedEntry {
+                    platform: row.get(0)?,
+                    full_name: row.get(1)?,
+                    last_seen_pushed: row.get(2)?,
+                    last_seen_stars: row.get(3)?,
+                    watched_at: row.get(4)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(results)
+    }
+
+    // ===== Reviewed Methods =====
+
+    /// Mark a repository as reviewed. Marking it again keeps the first time.
+    pub fn add_reviewed(&self, platform: &str, full_name: &str) -> Result<()> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        self.conn.execute(
+            "INSERT OR IGNORE INTO reviewed (platform, full_name, reviewed_at)
+             VALUES (?1, ?2, ?3)",
+            params![platform, full_name, now],
+        )?;
+        Ok(())
+    }
+
+    /// Unmark a repository. Returns false if it wasn't reviewed.
+    pub fn remove_reviewed(&self, platform: &str, full_name: &str) -> Result<bool> {
+        let removed = self.conn.execute(
+            "DELETE FROM reviewed WHERE platform = ?1 AND full_name = ?2",
+            params![platform, full_name],
+        )?;
+        Ok(removed > 0)
+    }
+
+    /// All reviewed repositories, most recently reviewed first
+    pub fn get_reviewed(&self) -> Result<Vec<ReviewedEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT platform, full_name, reviewed_at
+             FROM reviewed ORDER BY reviewed_at DESC, id DESC",
+        )?;
+
+        let results = stmt
+            .query_map([], |row| {
+                Ok(ReviewedEntry {
+                    platform: row.get(0)?,
+                    full_name: row.get(1)?,
+                    reviewed_at: row.get(2)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Unmark every reviewed repository, returning how many there were
+    pub fn clear_reviewed(&self) -> Result<usize> {
+        let cleared = self.conn.execute("DELETE FROM reviewed", [])?;
+        Ok(cleared)
+    }
+
+    // ===== Star History Methods =====
+
+    /// Record a repository's current star count. Counts seen within an hour
+    /// of the last snapshot replace it, so frequent searches don't pile up
+    /// rows, and only the latest [`MAX_STAR_SNAPSHOTS`] are kept.
+    pub fn record_stars(&self, platform: &str, full_name: &str, stars: u32) -> Result<()> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        let updated = self.conn.execute(
+            "UPDATE star_history SET recorded_at = ?3, stars = ?4
+             WHERE id = (
+                SELECT id FROM star_history
+                WHERE platform = ?1 AND full_name = ?2 AND recorded_at > ?5
+                ORDER BY recorded_at DESC LIMIT 1
+             )",
+            params![
+                platform,
+                full_name,
+                now,
+                stars,
+                now - STAR_SNAPSHOT_INTERVAL_SECS
+            ],
+        )?;
+        if updated == 0 {
+            self.conn.execute(
+                "INSERT INTO star_history (platform, full_name, recorded_at, stars)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![platform, full_name, now, stars],
+            )?;
+            self.conn.execute(
+                "DELETE FROM star_history WHERE id IN (
+                    SELECT id FROM star_history
+                    WHERE platform = ?1 AND full_name = ?2
+                    ORDER BY recorded_at DESC, id DESC
+                    LIMIT -1 OFFSET ?3
+                )",
+                params![platform, full_name, MAX_STAR_SNAPSHOTS],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Star counts recorded for a repository, oldest first
+    pub fn get_star_history(&self, platform: &str, full_name: &str) -> Result<Vec<StarSnapshot>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT recorded_at, stars FROM star_history
+             WHERE platform = ?1 COLLATE NOCASE AND full_name = ?2
+             ORDER BY recorded_at, id",
+        )?;
+
+        let results = stmt
+            .query_map(params![platform, full_name], |row| {
+                Ok(StarSnapshot {
+                    recorded_at: row.get(0)?,
+                    stars: row.get(1)?,
+                })
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(results)
+    }
+
     // ===== Search History Methods =====
 
     /// Add a search to history
@@ -543,6 +1057,45 @@ impl CacheManager {
         Ok(count as usize)
     }
 
+    /// Restore exported history entries, keeping their original timestamps.
+    /// Entries already present with the same query and time are skipped, and
+    /// history is trimmed back to the newest 100 afterwards. Returns how many
+    /// entries were inserted.
+    pub fn import_search_history(&self, entries: &[SearchHistoryEntry]) -> Result<usize> {
+        let mut imported = 0;
+        for entry in entries {
+            let exists: bool = self.conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM search_history WHERE query = ?1 AND searched_at = ?2)",
+                params![entry.query, entry.searched_at],
+                |row| row.get(0),
+            )?;
+            if exists {
+                continue;
+            }
+
+            self.conn.execute(
+                "INSERT INTO search_history (query, filters, result_count, searched_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    entry.query,
+                    entry.filters,
+                    entry.result_count,
+                    entry.searched_at
+                ],
+            )?;
+            imported += 1;
+        }
+
+        self.conn.execute(
+            "DELETE FROM search_history WHERE id IN (
+                SELECT id FROM search_history ORDER BY searched_at DESC LIMIT -1 OFFSET 100
+            )",
+            [],
+        )?;
+
+        Ok(imported)
+    }
+
     // ===== Query Cache Methods =====
 
     /// Generate a stable hash for a query string
@@ -574,7 +1127,7 @@ impl CacheManager {
             .unwrap()
             .as_secs() as i64;
 
-        if now - cached_at > self.ttl_seconds {
+        if now - cached_at > self.query_ttl_seconds && !self.stale_reads {
             // Delete expired entry
             self.conn.execute(
                 "DELETE FROM query_cache WHERE query_hash = ?1",
@@ -583,6 +1136,11 @@ impl CacheManager {
             return Err(CacheError::Expired);
         }
 
+        self.conn.execute(
+            "UPDATE query_cache SET last_accessed = ?1 WHERE query_hash = ?2",
+            params![now, query_hash],
+        )?;
+
         let results: Vec<T> = serde_json::from_str(&results_json)?;
         Ok(results)
     }
@@ -597,11 +1155,13 @@ impl CacheManager {
             .as_secs() as i64;
 
         self.conn.execute(
-            "INSERT OR REPLACE INTO query_cache (query_hash, query, results, cached_at)
-             VALUES (?1, ?2, ?3, ?4)",
+            "INSERT OR REPLACE INTO query_cache (query_hash, query, results, cached_at, last_accessed)
+             VALUES (?1, ?2, ?3, ?4, ?4)",
             params![query_hash, query, results_json, now],
         )?;
 
+        self.evict_lru("query_cache")?;
+
         Ok(())
     }
 
@@ -611,6 +1171,16 @@ impl CacheManager {
         Ok(())
     }
 
+    /// Drop the cached results for one exact query. Returns the number of
+    /// entries removed.
+    pub fn invalidate_query(&self, query: &str) -> Result<usize> {
+        let removed = self.conn.execute(
+            "DELETE FROM query_cache WHERE query_hash = ?1",
+            params![Self::hash_query(query)],
+        )?;
+        Ok(removed)
+    }
+
     /// Clean up expired query cache entries
     pub fn cleanup_expired_query_cache(&self) -> Result<usize> {
         let now = SystemTime::now()
@@ -620,7 +1190,7 @@ impl CacheManager {
 
         let deleted = self.conn.execute(
             "DELETE FROM query_cache WHERE cached_at < ?1",
-            params![now - self.ttl_seconds],
+            params![now - self.query_ttl_seconds],
         )?;
 
         Ok(deleted)
@@ -636,6 +1206,31 @@ pub struct CacheStats {
     pub query_cache_expired: usize,
     pub bookmarks_count: usize,
     pub size_bytes: usize,
+    /// Cap applied to repository and query entries (each)
+    pub max_entries: usize,
+}
+
+impl CacheStats {
+    /// Percentage of the entry cap used by the repository cache
+    pub fn repo_utilization(&self) -> f64 {
+        self.total_entries as f64 / self.max_entries.max(1) as f64 * 100.0
+    }
+
+    /// Percentage of the entry cap used by the query cache
+    pub fn query_utilization(&self) -> f64 {
+        self.query_cache_entries as f64 / self.max_entries.max(1) as f64 * 100.0
+    }
+}
+
+/// Turn free text into an FTS5 query where every word must match as a
+/// prefix. None if there are no words
+fn fts_match_expr(query: &str) -> Option<String> {
+    let expr = query
+        .split_whitespace()
+        .map(|word| format!("\"{}\"*", word.replace('"', "\"\"")))
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!expr.is_empty()).then_some(expr)
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -648,6 +1243,30 @@ pub struct BookmarkEntry {
     pub notes: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct WatchedEntry {
+    pub platform: String,
+    pub full_name: String,
+    /// Push time seen on the last check, in unix seconds
+    pub last_seen_pushed: i64,
+    pub last_seen_stars: u32,
+    pub watched_at: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ReviewedEntry {
+    pub platform: String,
+    pub full_name: String,
+    pub reviewed_at: i64,
+}
+
+/// A repository's star count at one point in time
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StarSnapshot {
+    pub recorded_at: i64,
+    pub stars: u32,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct SearchHistoryEntry {
     pub id: i64,
@@ -703,6 +1322,30 @@ mod tests {
         assert!(matches!(result, Err(CacheError::Expired)));
     }
 
+    #[test]
+    fn test_separate_query_ttl() {
+        let cache = CacheManager::with_ttls(":memory:", 24, 0).unwrap();
+
+        let repo = TestRepo {
+            name: "test/repo".to_string(),
+            description: None,
+            topics: vec![],
+        };
+
+        cache.set("github", "test/repo", &repo).unwrap();
+        cache
+            .set_query_cache("trending", std::slice::from_ref(&repo))
+            .unwrap();
+
+        // Query results expire with their own TTL, repo entries stay valid
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        assert!(cache.get::<TestRepo>("github", "test/repo").is_ok());
+        assert!(matches!(
+            cache.get_query_cache::<TestRepo>("trending"),
+            Err(CacheError::Expired)
+        ));
+    }
+
     #[test]
     fn test_cache_stats() {
         let cache = CacheManager::new(":memory:", 24).unwrap();
@@ -718,4 +1361,417 @@ mod tests {
         let stats = cache.stats().unwrap();
         assert_eq!(stats.total_entries, 1);
     }
+
+    #[test]
+    fn test_lru_eviction() {
+        let cache = CacheManager::new(":memory:", 24)
+            .unwrap()
+            .with_max_entries(2);
+
+        let repo = |name: &str| TestRepo {
+            name: name.to_string(),
+            description: None,
+            topics: vec![],
+        };
+
+        cache.set("github", "a/one", &repo("a/one")).unwrap();
+        cache.set("github", "a/two", &repo("a/two")).unwrap();
+
+        // Make "a/one" the most recently used entry
+        cache
+            .conn
+            .execute(
+                "UPDATE repositories SET last_accessed = last_accessed + 100 WHERE full_name = 'a/one'",
+                [],
+            )
+            .unwrap();
+
+        cache.set("github", "a/three", &repo("a/three")).unwrap();
+
+        let stats = cache.stats().unwrap();
+        assert_eq!(stats.total_entries, 2);
+        assert_eq!(stats.max_entries, 2);
+        assert!(cache.get::<TestRepo>("github", "a/one").is_ok());
+        assert!(cache.get::<TestRepo>("github", "a/two").is_err());
+        assert!(cache.get::<TestRepo>("github", "a/three").is_ok());
+    }
+
+    #[test]
+    fn test_search_bookmarks() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+        let repo = |name: &str, description: &str| TestRepo {
+            name: name.to_string(),
+            description: Some(description.to_string()),
+            topics: vec![],
+        };
+        cache
+            .add_bookmark(
+                "github",
+                "tokio-rs/tokio",
+                &repo("tokio-rs/tokio", "An asynchronous runtime"),
+                Some("async,runtime"),
+                None,
+            )
+            .unwrap();
+        cache
+            .add_bookmark(
+                "github",
+                "ratatui/ratatui",
+                &repo("ratatui/ratatui", "Terminal user interfaces"),
+                Some("tui"),
+                Some("try for the dashboard"),
+            )
+            .unwrap();
+
+        let names = |query: &str| -> Vec<String> {
+            cache
+                .search_bookmarks(query)
+                .unwrap()
+                .into_iter()
+                .map(|b| b.full_name)
+                .collect()
+        };
+        assert_eq!(names("runtime"), vec!["tokio-rs/tokio"]);
+        assert_eq!(names("dash"), vec!["ratatui/ratatui"]);
+        assert_eq!(names("terminal tui"), vec!["ratatui/ratatui"]);
+        assert_eq!(names("tokio-rs/tokio"), vec!["tokio-rs/tokio"]);
+        assert!(names("missing").is_empty());
+        assert_eq!(names("").len(), 2);
+
+        // Re-adding updates the index instead of leaving the old notes behind
+        cache
+            .add_bookmark(
+                "github",
+                "ratatui/ratatui",
+                &repo("ratatui/ratatui", "Terminal user interfaces"),
+                Some("tui"),
+                Some("used in production"),
+            )
+            .unwrap();
+        assert!(names("dashboard").is_empty());
+        assert_eq!(names("production"), vec!["ratatui/ratatui"]);
+
+        cache.remove_bookmark("github", "tokio-rs/tokio").unwrap();
+        assert!(names("runtime").is_empty());
+    }
+
+    #[test]
+    fn test_search_bookmarks_indexes_existing_database() {
+        let path =
+            std::env::temp_dir().join(format!("reposcout-fts-migration-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        {
+            // A bookmarks table from before the FTS index was added
+            let conn = Connection::open(&path).unwrap();
+            conn.execute(
+                "CREATE TABLE bookmarks (
+                    id INTEGER PRIMARY KEY,
+                    platform TEXT NOT NULL,
+                    full_name TEXT NOT NULL,
+                    data TEXT NOT NULL,
+                    bookmarked_at INTEGER NOT NULL,
+                    tags TEXT,
+                    notes TEXT,
+                    UNIQUE(platform, full_name)
+                )",
+                [],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO bookmarks (platform, full_name, data, bookmarked_at, tags, notes)
+                 VALUES ('github', 'a/legacy', '{\"description\":\"old favourite\"}', 0, 'cli', NULL)",
+                [],
+            )
+            .unwrap();
+        }
+
+        let cache = CacheManager::new(path.to_str().unwrap(), 24).unwrap();
+        let found = cache.search_bookmarks("favourite").unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].full_name, "a/legacy");
+        drop(cache);
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_stale_reads_and_text_search() {
+        let cache = CacheManager::new(":memory:", 0).unwrap();
+        let repo = TestRepo {
+            name: "tokio-rs/tokio".to_string(),
+            description: Some("An asynchronous runtime".to_string()),
+            topics: vec!["async".to_string()],
+        };
+        cache.set("GitHub", "tokio-rs/tokio", &repo).unwrap();
+        cache.set_query_cache("tokio", &[&repo]).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(1100));
+
+        // A zero TTL expires everything, unless stale reads are allowed
+        let cache = cache.with_stale_reads(true);
+        assert_eq!(
+            cache.get::<TestRepo>("GitHub", "tokio-rs/tokio").unwrap(),
+            repo
+        );
+        assert_eq!(cache.get_query_cache::<TestRepo>("tokio").unwrap().len(), 1);
+        let cache = cache.with_stale_reads(false);
+        assert!(matches!(
+            cache.get::<TestRepo>("GitHub", "tokio-rs/tokio"),
+            Err(CacheError::Expired)
+        ));
+
+        let found: Vec<TestRepo> = cache.search_text("asynch language:rust", 10).unwrap();
+        assert_eq!(found, vec![repo]);
+        assert!(cache
+            .search_text::<TestRepo>("stars:>100", 10)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_update_bookmark_data_keeps_metadata() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+        let mut repo = TestRepo {
+            name: "test/repo".to_string(),
+            description: None,
+            topics: vec![],
+        };
+        cache
+            .add_bookmark(
+                "github",
+                "test/repo",
+                &repo,
+                Some("cli"),
+                Some("look later"),
+            )
+            .unwrap();
+        let before = cache.get_bookmarks_with_metadata().unwrap();
+
+        repo.description = Some("Now with a description".to_string());
+        assert!(cache
+            .update_bookmark_data("github", "test/repo", &repo)
+            .unwrap());
+        assert!(!cache
+            .update_bookmark_data("gitlab", "test/repo", &repo)
+            .unwrap());
+
+        let after = cache.get_bookmarks_with_metadata().unwrap();
+        assert_eq!(after.len(), 1);
+        assert_eq!(after[0].bookmarked_at, before[0].bookmarked_at);
+        assert_eq!(after[0].tags.as_deref(), Some("cli"));
+        assert_eq!(after[0].notes.as_deref(), Some("look later"));
+        let stored: Vec<TestRepo> = cache.get_bookmarks().unwrap();
+        assert_eq!(stored, vec![repo]);
+
+        assert!(cache
+            .update_bookmark_tags("github", "test/repo", Some("cli,tui"))
+            .unwrap());
+        assert!(cache
+            .update_bookmark_notes("github", "test/repo", None)
+            .unwrap());
+        let edited = cache.get_bookmarks_with_metadata().unwrap();
+        assert_eq!(edited[0].tags.as_deref(), Some("cli,tui"));
+        assert_eq!(edited[0].notes, None);
+        assert_eq!(cache.search_bookmarks("tui").unwrap().len(), 1);
+        assert!(!cache
+            .update_bookmark_tags("github", "other/repo", Some("x"))
+            .unwrap());
+    }
+
+    #[test]
+    fn test_import_search_history_dedupes() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+        cache
+            .add_search_history("rust cli", None, Some(10))
+            .unwrap();
+        let exported = cache.get_search_history(100).unwrap();
+
+        let older = SearchHistoryEntry {
+            id: 0,
+            query: "rust cli".to_string(),
+            filters: Some("language:rust".to_string()),
+            result_count: Some(3),
+            searched_at: 1_700_000_000,
+        };
+        let mut entries = exported.clone();
+        entries.push(older.clone());
+
+        assert_eq!(cache.import_search_history(&entries).unwrap(), 1);
+        assert_eq!(cache.import_search_history(&entries).unwrap(), 0);
+
+        let history = cache.get_search_history(100).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].searched_at, older.searched_at);
+        assert_eq!(history[1].filters, older.filters);
+    }
+
+    #[test]
+    fn test_watched_roundtrip() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+
+        cache.add_watched("github", "a/one", 100, 40).unwrap();
+        cache.add_watched("gitlab", "b/two", 200, 5).unwrap();
+        let watched = cache.get_watched().unwrap();
+        assert_eq!(watched.len(), 2);
+        assert_eq!(watched[0].full_name, "a/one");
+        assert_eq!(watched[0].last_seen_pushed, 100);
+        assert_eq!(watched[0].last_seen_stars, 40);
+
+        assert!(cache.update_watched("github", "a/one", 150, 120).unwrap());
+        assert!(!cache.update_watched("github", "b/two", 150, 120).unwrap());
+        let watched = cache.get_watched().unwrap();
+        assert_eq!(watched[0].last_seen_pushed, 150);
+        assert_eq!(watched[0].last_seen_stars, 120);
+
+        // Watching again resets the baseline without duplicating the entry
+        cache.add_watched("github", "a/one", 300, 130).unwrap();
+        let watched = cache.get_watched().unwrap();
+        assert_eq!(watched.len(), 2);
+        assert_eq!(watched[0].last_seen_pushed, 300);
+
+        assert!(cache.remove_watched("gitlab", "b/two").unwrap());
+        assert!(!cache.remove_watched("gitlab", "b/two").unwrap());
+        assert_eq!(cache.get_watched().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_reviewed_roundtrip() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+
+        cache.add_reviewed("github", "a/one").unwrap();
+        cache.add_reviewed("gitlab", "a/one").unwrap();
+        // Marking again doesn't duplicate
+        cache.add_reviewed("github", "a/one").unwrap();
+        let reviewed = cache.get_reviewed().unwrap();
+        assert_eq!(reviewed.len(), 2);
+        assert!(reviewed
+            .iter()
+            .any(|r| r.platform == "gitlab" && r.full_name == "a/one"));
+
+        assert!(cache.remove_reviewed("gitlab", "a/one").unwrap());
+        assert!(!cache.remove_reviewed("gitlab", "a/one").unwrap());
+        assert_eq!(cache.get_reviewed().unwrap().len(), 1);
+
+        assert_eq!(cache.clear_reviewed().unwrap(), 1);
+        assert!(cache.get_reviewed().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_invalidate_repo_and_query() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+        let repo = TestRepo {
+            name: "a/one".to_string(),
+            description: None,
+            topics: vec![],
+        };
+        cache.set("GitHub", "a/one", &repo).unwrap();
+        cache.set("GitLab", "a/one", &repo).unwrap();
+        cache.set("GitHub", "a/two", &repo).unwrap();
+
+        assert_eq!(cache.invalidate_repo(Some("gitlab"), "A/One").unwrap(), 1);
+        assert!(cache.get::<TestRepo>("GitHub", "a/one").is_ok());
+        assert_eq!(cache.invalidate_repo(None, "a/one").unwrap(), 1);
+        assert!(cache.get::<TestRepo>("GitHub", "a/one").is_err());
+        assert!(cache.get::<TestRepo>("GitHub", "a/two").is_ok());
+        assert_eq!(cache.invalidate_repo(None, "a/missing").unwrap(), 0);
+
+        cache.set_query_cache("rust cli", &[repo]).unwrap();
+        cache
+            .set_query_cache("rust tui", &Vec::<TestRepo>::new())
+            .unwrap();
+        assert_eq!(cache.invalidate_query("rust cli").unwrap(), 1);
+        assert_eq!(cache.invalidate_query("rust cli").unwrap(), 0);
+        assert!(cache.get_query_cache::<TestRepo>("rust cli").is_err());
+        assert!(cache.get_query_cache::<TestRepo>("rust tui").is_ok());
+    }
+
+    #[test]
+    fn test_star_history() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+        assert!(cache
+            .get_star_history("GitHub", "a/one")
+            .unwrap()
+            .is_empty());
+
+        // Snapshots from a day ago and two days ago, then today's fetches
+        for (age_days, stars) in [(2, 100), (1, 150)] {
+            cache
+                .conn
+                .execute(
+                    "INSERT INTO star_history (platform, full_name, recorded_at, stars)
+                     VALUES ('GitHub', 'a/one', strftime('%s', 'now') - ?1 * 86400, ?2)",
+                    params![age_days, stars],
+                )
+                .unwrap();
+        }
+        cache.record_stars("GitHub", "a/one", 180).unwrap();
+        // Within the hour: replaces the snapshot just taken
+        cache.record_stars("GitHub", "a/one", 190).unwrap();
+        cache.record_stars("GitLab", "a/one", 5).unwrap();
+        // Caching the repository leaves recording to whoever fetched it
+        cache
+            .set(
+                "GitHub",
+                "a/one",
+                &serde_json::json!({ "full_name": "a/one", "stars": 200 }),
+            )
+            .unwrap();
+
+        let history = cache.get_star_history("github", "a/one").unwrap();
+        let stars: Vec<u32> = history.iter().map(|s| s.stars).collect();
+        assert_eq!(stars, vec![100, 150, 190]);
+        assert!(history
+            .windows(2)
+            .all(|w| w[0].recorded_at <= w[1].recorded_at));
+
+        // Invalidating a repository forgets its history on that platform
+        cache.invalidate_repo(Some("GitHub"), "a/one").unwrap();
+        assert!(cache
+            .get_star_history("GitHub", "a/one")
+            .unwrap()
+            .is_empty());
+        assert_eq!(cache.get_star_history("GitLab", "a/one").unwrap().len(), 1);
+        cache.clear().unwrap();
+        assert!(cache
+            .get_star_history("GitLab", "a/one")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_star_history_is_pruned() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+        // Hourly snapshots going back past the cap, and one from two years ago
+        for hours in 0..MAX_STAR_SNAPSHOTS + 10 {
+            cache
+                .conn
+                .execute(
+                    "INSERT INTO star_history (platform, full_name, recorded_at, stars)
+                     VALUES ('GitHub', 'a/one', strftime('%s', 'now') - ?1 * 7200, ?1)",
+                    params![hours + 1],
+                )
+                .unwrap();
+        }
+        cache
+            .conn
+            .execute(
+                "INSERT INTO star_history (platform, full_name, recorded_at, stars)
+                 VALUES ('GitHub', 'b/two', strftime('%s', 'now') - 2 * 365 * 86400, 1)",
+                [],
+            )
+            .unwrap();
+
+        cache.record_stars("GitHub", "a/one", 0).unwrap();
+        let history = cache.get_star_history("GitHub", "a/one").unwrap();
+        assert_eq!(history.len() as i64, MAX_STAR_SNAPSHOTS);
+        assert_eq!(history.last().unwrap().stars, 0);
+
+        cache.cleanup_expired().unwrap();
+        assert!(cache
+            .get_star_history("GitHub", "b/two")
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            cache.get_star_history("GitHub", "a/one").unwrap().len() as i64,
+            MAX_STAR_SNAPSHOTS
+        );
+    }
 }
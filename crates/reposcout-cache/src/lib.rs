@@ -3,4 +3,7 @@
 
 pub mod cache;
 
-pub use cache::{BookmarkEntry, CacheError, CacheManager, CacheStats, SearchHistoryEntry};
+pub use cache::{
+    BookmarkEntry, CacheError, CacheManager, CacheStats, ReviewedEntry, SearchHistoryEntry,
+    StarSnapshot, WatchedEntry, DEFAULT_MAX_ENTRIES,
+};
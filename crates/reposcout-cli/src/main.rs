@@ -1,12 +1,19 @@
+use chrono::NaiveDate;
 use clap::Parser;
+use reposcout_api::AzureDevOpsClient;
 use reposcout_cache::{BookmarkEntry, CacheManager};
 use reposcout_core::{
-    providers::{BitbucketProvider, GitHubProvider, GitLabProvider},
+    models::format_day,
+    providers::{
+        AzureDevOpsProvider, BitbucketProvider, GitHubProvider, GitLabProvider, SourceHutProvider,
+    },
     CachedSearchEngine,
 };
 use std::path::PathBuf;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+mod output;
+
 #[derive(Parser)]
 #[command(name = "reposcout")]
 #[command(version, about = "Terminal-based Git repository discovery platform", long_about = None)]
@@ -18,6 +25,11 @@ struct Cli {
     #[arg(long, env)]
     github_token: Option<String>,
 
+    /// GitHub API URL, for GitHub Enterprise Server e.g.
+    /// https://github.example.com/api/v3 (or set GITHUB_URL env var)
+    #[arg(long, env, value_name = "URL")]
+    github_url: Option<String>,
+
     /// GitLab personal access token (or set GITLAB_TOKEN env var)
     #[arg(long, env)]
     gitlab_token: Option<String>,
@@ -29,6 +41,159 @@ struct Cli {
     /// Bitbucket app password (or set BITBUCKET_APP_PASSWORD env var)
     #[arg(long, env)]
     bitbucket_app_password: Option<String>,
+
+    /// Bitbucket workspace to run code search in (or set BITBUCKET_WORKSPACE env var)
+    #[arg(long, env)]
+    bitbucket_workspace: Option<String>,
+
+    /// Bitbucket Data Center URL, e.g. https://bitbucket.example.com; Bitbucket
+    /// Cloud is used when unset (or set BITBUCKET_URL env var)
+    #[arg(long, env, value_name = "URL")]
+    bitbucket_url: Option<String>,
+
+    /// Bitbucket Data Center HTTP access token, used instead of the username
+    /// and password (or set BITBUCKET_TOKEN env var)
+    #[arg(long, env)]
+    bitbucket_token: Option<String>,
+
+    /// Bitbucket Data Center project key to limit searches to (or set
+    /// BITBUCKET_PROJECT env var)
+    #[arg(long, env, value_name = "KEY")]
+    bitbucket_project: Option<String>,
+
+    /// SourceHut personal access token (or set SOURCEHUT_TOKEN env var)
+    #[arg(long, env)]
+    sourcehut_token: Option<String>,
+
+    #[command(flatten)]
+    azure: AzureDevOpsArgs,
+
+    /// Don't fall back to tokens from the gh/glab CLI configs
+    #[arg(long, env = "REPOSCOUT_NO_CLI_CREDENTIALS")]
+    no_cli_credentials: bool,
+
+    /// Cache TTL in hours for all cached data (overrides the config file)
+    #[arg(long, global = true, value_name = "HOURS")]
+    cache_ttl: Option<u64>,
+
+    /// How many repositories batch commands fetch at once (overrides the
+    /// config file, default 4)
+    #[arg(long, global = true, value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+    concurrency: Option<u16>,
+
+    /// Skip the result cache for this run, always fetching fresh data
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    /// Never touch the network; only show cached results (even stale ones)
+    #[arg(long, global = true, conflicts_with = "no_cache")]
+    offline: bool,
+
+    /// TUI theme for this session only (see `reposcout theme list`)
+    #[arg(long, global = true, value_name = "NAME")]
+    theme: Option<String>,
+
+    /// Plain ASCII output for logs and scripts: no emoji, colors or
+    /// separators, one tab-separated record per line, and warnings only
+    /// from the log
+    #[arg(long, short = 'q', global = true, visible_alias = "plain")]
+    quiet: bool,
+}
+
+/// Azure DevOps has no global search, so it needs an organization to search in
+#[derive(clap::Args, Debug, Clone, Default)]
+struct AzureDevOpsArgs {
+    /// Azure DevOps personal access token (or set AZURE_DEVOPS_TOKEN env var)
+    #[arg(long = "azure-token", env = "AZURE_DEVOPS_TOKEN")]
+    token: Option<String>,
+
+    /// Azure DevOps organization to search; repositories outside it are never
+    /// found (or set AZURE_DEVOPS_ORG env var)
+    #[arg(long = "azure-org", env = "AZURE_DEVOPS_ORG")]
+    organization: Option<String>,
+
+    /// Limit Azure DevOps searches to one project in the organization
+    /// (or set AZURE_DEVOPS_PROJECT env var)
+    #[arg(long = "azure-project", env = "AZURE_DEVOPS_PROJECT")]
+    project: Option<String>,
+}
+
+impl AzureDevOpsArgs {
+    fn client(&self) -> AzureDevOpsClient {
+        AzureDevOpsClient::new(
+            self.token.clone(),
+            self.organization.clone(),
+            self.project.clone(),
+        )
+    }
+
+    fn provider(&self) -> AzureDevOpsProvider {
+        AzureDevOpsProvider::with_client(self.client())
+    }
+}
+
+/// How `search` prints its results
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    /// Several lines per repository with description and health
+    List,
+    /// One aligned row per repository
+    Table,
+}
+
+/// What `search --group-by` puts results under
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum Grouping {
+    /// A section per platform
+    Platform,
+    /// A section per primary language
+    Language,
+}
+
+impl From<Grouping> for reposcout_core::GroupBy {
+    fn from(grouping: Grouping) -> Self {
+        match grouping {
+            Grouping::Platform => reposcout_core::GroupBy::Platform,
+            Grouping::Language => reposcout_core::GroupBy::Language,
+        }
+    }
+}
+
+/// What `--min-health` does with repositories it can't score
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum HealthUnknown {
+    /// Keep them in the results
+    Keep,
+    /// Leave them out
+    Drop,
+}
+
+/// Where `trending` looks for repositories
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum TrendingPlatform {
+    /// New repositories gaining stars
+    Github,
+    /// Most-starred projects with recent activity
+    Gitlab,
+    /// Recently updated repositories ranked by watchers
+    Bitbucket,
+    /// All of the above
+    All,
+}
+
+impl TrendingPlatform {
+    fn includes(self, platform: TrendingPlatform) -> bool {
+        self == TrendingPlatform::All || self == platform
+    }
+}
+
+/// How `badge` writes its snippets
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum BadgeOutput {
+    /// Markdown image links
+    Markdown,
+    /// `<img>` tags wrapped in links
+    Html,
 }
 
 #[derive(clap::Subcommand)]
@@ -38,9 +203,9 @@ enum Commands {
         /// Search query
         query: String,
 
-        /// Number of results to show
-        #[arg(short = 'n', long, default_value = "10")]
-        limit: usize,
+        /// Number of results to show (default: 10)
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
 
         /// Filter by programming language (e.g., rust, python, go)
         #[arg(short = 'l', long)]
@@ -58,13 +223,101 @@ enum Commands {
         #[arg(long)]
         pushed: Option<String>,
 
-        /// Sort by: stars, forks, updated (default: stars)
-        #[arg(short = 's', long, default_value = "stars")]
-        sort: String,
+        /// Only repositories created on or after this date (YYYY-MM-DD)
+        #[arg(long, alias = "since", value_name = "DATE", value_parser = parse_date)]
+        created_after: Option<NaiveDate>,
 
-        /// Export results to file (format detected from extension: .json, .csv, .md)
+        /// Only repositories created on or before this date (YYYY-MM-DD)
+        #[arg(long, alias = "until", value_name = "DATE", value_parser = parse_date)]
+        created_before: Option<NaiveDate>,
+
+        /// Sort by: stars, forks, updated, trending-score (default: stars)
+        #[arg(short = 's', long)]
+        sort: Option<String>,
+
+        /// Export results to file (format detected from extension: .json, .jsonl, .csv, .md, .html)
         #[arg(short = 'o', long)]
         export: Option<String>,
+
+        /// CSV columns to export, in order (e.g. full_name,stars,language,url)
+        #[arg(long, requires = "export")]
+        columns: Option<String>,
+
+        /// JSON/JSONL keys to keep in each exported repository
+        /// (e.g. full_name,stars,url)
+        #[arg(long, requires = "export", conflicts_with = "columns")]
+        fields: Option<String>,
+
+        /// Add to an existing export instead of overwriting it: JSON/JSONL
+        /// merge (skipping repositories already there), CSV adds rows under
+        /// the header and Markdown a new section
+        #[arg(long, requires = "export")]
+        append: bool,
+
+        /// Rank by star percentile within each platform instead of raw stars
+        #[arg(long)]
+        normalize: bool,
+
+        /// Leave out forks
+        #[arg(long)]
+        exclude_forks: bool,
+
+        /// Leave out archived (read-only) repositories
+        #[arg(long)]
+        exclude_archived: bool,
+
+        /// Leave out repositories marked as reviewed (`x` in the TUI)
+        #[arg(long)]
+        hide_reviewed: bool,
+
+        /// Take at most N results from each platform and interleave them
+        /// (kept in that order unless --sort is given)
+        #[arg(long, value_name = "N")]
+        per_platform: Option<usize>,
+
+        /// Collapse the same project mirrored on several platforms into the
+        /// most-starred copy (matched by repo name and similar description)
+        #[arg(long)]
+        dedupe: bool,
+
+        /// Leave out repositories whose health score is below this (0-100)
+        #[arg(long, value_name = "SCORE", value_parser = clap::value_parser!(u8).range(0..=100))]
+        min_health: Option<u8>,
+
+        /// With --min-health, keep or drop repositories that have no health
+        /// score (SourceHut and Azure DevOps report no stars, forks or issues)
+        #[arg(long, value_enum, default_value = "keep", requires = "min_health")]
+        health_unknown: HealthUnknown,
+
+        /// Print results as a compact aligned table (same as `--format table`)
+        #[arg(long, conflicts_with = "export")]
+        table: bool,
+
+        /// Output format: list (detailed, default) or table
+        #[arg(
+            long,
+            value_enum,
+            alias = "output-format",
+            default_value = "list",
+            conflicts_with = "export"
+        )]
+        format: OutputFormat,
+
+        /// Show results in a section per platform or language, each still
+        /// in the sort order
+        #[arg(long, value_enum, conflicts_with = "export")]
+        group_by: Option<Grouping>,
+
+        /// Open the N-th result in the browser instead of listing results
+        /// (default: the top one). Skips --export.
+        #[arg(
+            long,
+            value_name = "N",
+            num_args = 0..=1,
+            default_missing_value = "1",
+            value_parser = clap::value_parser!(u16).range(1..)
+        )]
+        open: Option<u16>,
     },
     /// Search for code within repositories
     Code {
@@ -90,11 +343,57 @@ enum Commands {
         /// File extension filter (e.g., "rs", "py")
         #[arg(short = 'e', long)]
         extension: Option<String>,
+
+        /// Lines of surrounding code to show around each match, read from
+        /// the file itself (0 skips fetching files)
+        #[arg(short = 'C', long, value_name = "N", default_value_t = reposcout_core::DEFAULT_CONTEXT_LINES)]
+        context: usize,
     },
     /// Show repository details
     Show {
+        /// Repository name (owner/repo)
+        #[arg(required_unless_present = "repos_from_file")]
+        name: Option<String>,
+
+        /// Show every repository listed in a file, one owner/repo per line
+        /// (blank lines and # comments are skipped)
+        #[arg(long, value_name = "PATH", conflicts_with = "name")]
+        repos_from_file: Option<PathBuf>,
+
+        /// Print the repository as JSON (an array with --repos-from-file)
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print shields.io badges for a repository, ready to paste into a README
+    Badge {
+        /// Repository name (owner/repo)
+        name: String,
+
+        /// Output format: markdown or html
+        #[arg(short = 'f', long, value_enum, default_value = "markdown")]
+        format: BadgeOutput,
+    },
+    /// Analyze a repository's dependencies from its manifest (Cargo.toml,
+    /// package.json, ...) picked by the repository's language
+    Deps {
         /// Repository name (owner/repo)
         name: String,
+
+        /// Print the dependency info as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Compare two repositories side by side
+    Compare {
+        /// First repository (owner/repo)
+        repo_a: String,
+
+        /// Second repository (owner/repo)
+        repo_b: String,
+
+        /// Print the comparison as JSON
+        #[arg(long)]
+        json: bool,
     },
     /// Cache management
     Cache {
@@ -106,11 +405,26 @@ enum Commands {
         #[command(subcommand)]
         action: BookmarkAction,
     },
+    /// Watch repositories for new pushes and star milestones
+    Watch {
+        #[command(subcommand)]
+        action: WatchAction,
+    },
+    /// Share portfolios built in the TUI
+    Portfolio {
+        #[command(subcommand)]
+        action: PortfolioAction,
+    },
     /// Search history management
     History {
         #[command(subcommand)]
         action: HistoryAction,
     },
+    /// Repositories marked as reviewed while triaging in the TUI
+    Reviewed {
+        #[command(subcommand)]
+        action: ReviewedAction,
+    },
     /// Launch interactive TUI
     Tui,
     /// Show trending repositories
@@ -131,13 +445,29 @@ enum Commands {
         #[arg(short = 't', long)]
         topic: Option<String>,
 
-        /// Number of results to show
-        #[arg(short = 'n', long, default_value = "20")]
-        limit: usize,
+        /// Number of results to show (default: 20)
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
 
         /// Sort by star velocity (stars/day) instead of total stars
         #[arg(short = 'v', long)]
         velocity: bool,
+
+        /// Platform to find trending repositories on
+        #[arg(long, value_enum, default_value = "all")]
+        platform: TrendingPlatform,
+
+        /// Also include repos where --language makes up at least this % of the code
+        #[arg(long, requires = "language", value_parser = clap::value_parser!(u8).range(1..=100))]
+        min_language_percent: Option<u8>,
+
+        /// Print results as a compact aligned table
+        #[arg(long)]
+        table: bool,
+
+        /// Export results to file (.xml writes an Atom feed; also .json, .jsonl, .csv, .md, .html)
+        #[arg(short = 'o', long)]
+        export: Option<String>,
     },
     /// Semantic search using natural language queries
     Semantic {
@@ -156,20 +486,79 @@ enum Commands {
         #[arg(long, default_value = "0.3")]
         min_similarity: f32,
 
-        /// Export results to file (format detected from extension: .json, .csv, .md)
+        /// Export results to file (format detected from extension: .json, .jsonl, .csv, .md, .html)
         #[arg(short = 'o', long)]
         export: Option<String>,
+
+        /// Embedding model: minilm, bge-small, bge-base, or custom:<dir>
+        /// (defaults to the one the index was built with, else minilm)
+        #[arg(long)]
+        model: Option<reposcout_semantic::EmbeddingModel>,
+    },
+    /// Find repositories in the semantic index similar to a given one
+    Similar {
+        /// Repository name in format owner/repo
+        name: String,
+
+        /// Number of results to show
+        #[arg(short = 'n', long, default_value = "10")]
+        limit: usize,
+
+        /// Embedding model the index was built with (see `semantic --model`);
+        /// read from the index when not given
+        #[arg(long)]
+        model: Option<reposcout_semantic::EmbeddingModel>,
     },
     /// Semantic index management
     SemanticIndex {
         #[command(subcommand)]
         action: SemanticIndexAction,
     },
+    /// Check which account each configured token belongs to, with its
+    /// scopes and remaining rate limit
+    Whoami,
     /// Manage GitHub notifications
     Notifications {
         #[command(subcommand)]
         action: NotificationAction,
     },
+    /// Theme utilities
+    Theme {
+        #[command(subcommand)]
+        action: ThemeAction,
+    },
+    /// Manage the owner blocklist (hidden from all results)
+    Block {
+        #[command(subcommand)]
+        action: BlockAction,
+    },
+}
+
+#[derive(clap::Subcommand)]
+enum ThemeAction {
+    /// List built-in themes
+    List,
+    /// Check themes for low-contrast color combinations
+    Check {
+        /// Only check this theme (default: all themes)
+        name: Option<String>,
+    },
+}
+
+#[derive(clap::Subcommand)]
+enum BlockAction {
+    /// Never show repositories from this owner/org
+    Add {
+        /// Owner or organization name
+        owner: String,
+    },
+    /// List blocked owners
+    List,
+    /// Remove an owner from the blocklist
+    Remove {
+        /// Owner or organization name
+        owner: String,
+    },
 }
 
 #[derive(clap::Subcommand)]
@@ -191,6 +580,14 @@ enum NotificationAction {
         /// Filter by repository (owner/repo)
         #[arg(short = 'r', long)]
         repo: Option<String>,
+
+        /// Filter by subject type (Issue, PullRequest, Release, Commit, Discussion, CheckSuite)
+        #[arg(short = 't', long = "type", value_name = "TYPE", value_parser = parse_subject_type)]
+        subject_type: Option<String>,
+
+        /// Filter by reason (mention, review_requested, assign, author, comment, ...)
+        #[arg(long, value_name = "REASON")]
+        reason: Option<reposcout_core::NotificationReason>,
     },
     /// Mark a notification as read
     MarkRead {
@@ -207,9 +604,14 @@ enum SemanticIndexAction {
     Stats,
     /// Rebuild the semantic index from cached repositories
     Rebuild {
-        /// Force rebuild even if index exists
+        /// Wipe the index first instead of only adding repos not yet indexed
         #[arg(short = 'f', long)]
         force: bool,
+
+        /// Embedding model: minilm, bge-small, bge-base, or custom:<dir>
+        /// (defaults to the one the index was built with, else minilm)
+        #[arg(long)]
+        model: Option<reposcout_semantic::EmbeddingModel>,
     },
     /// Clear the semantic index
     Clear,
@@ -223,6 +625,17 @@ enum CacheAction {
     Clear,
     /// Clean up expired entries
     Cleanup,
+    /// Drop one repository (owner/repo) or one search query from the cache
+    Invalidate {
+        /// Repository name (owner/repo), or a quoted search query
+        target: String,
+        /// Only drop the repository cached for this platform
+        #[arg(short = 'p', long, conflicts_with = "query")]
+        platform: Option<String>,
+        /// Treat the target as a search query even if it looks like owner/repo
+        #[arg(long)]
+        query: bool,
+    },
 }
 
 #[derive(clap::Subcommand)]
@@ -245,6 +658,11 @@ enum BookmarkAction {
         /// Repository name (owner/repo)
         name: String,
     },
+    /// Search bookmarks by name, tags, notes and description
+    Search {
+        /// Words to look for (each matches as a prefix)
+        term: String,
+    },
     /// Export bookmarks to file
     Export {
         /// Output file path
@@ -252,6 +670,10 @@ enum BookmarkAction {
         /// Export format: json or csv
         #[arg(short = 'f', long, default_value = "json")]
         format: String,
+        /// CSV columns to export, in order: repository fields plus
+        /// bookmarked_at, tags and notes (e.g. full_name,stars,tags)
+        #[arg(long)]
+        columns: Option<String>,
     },
     /// Import bookmarks from file
     Import {
@@ -260,6 +682,62 @@ enum BookmarkAction {
     },
     /// Clear all bookmarks
     Clear,
+    /// Re-fetch bookmarks and show what changed since they were saved
+    Refresh {
+        /// Show the changes without updating the stored bookmarks
+        #[arg(long)]
+        dry_run: bool,
+        /// Only refresh the N most recently bookmarked repositories
+        #[arg(short = 'n', long)]
+        limit: Option<usize>,
+    },
+    /// Summarize bookmarks by platform, language, stars, tags and health
+    Stats {
+        /// Print the statistics as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+#[derive(clap::Subcommand)]
+enum WatchAction {
+    /// Start watching a repository (owner/repo)
+    Add {
+        /// Repository name (owner/repo)
+        name: String,
+    },
+    /// Stop watching a repository
+    Remove {
+        /// Repository name (owner/repo)
+        name: String,
+    },
+    /// List watched repositories
+    List,
+    /// Re-fetch watched repositories and report new pushes and star milestones
+    Check {
+        /// Print a one-line banner (e.g. for a shell prompt hook) and leave
+        /// the last seen state alone
+        #[arg(long)]
+        notify: bool,
+    },
+}
+
+#[derive(clap::Subcommand)]
+enum PortfolioAction {
+    /// List portfolios with their ids
+    List,
+    /// Export a portfolio and its notes (format from extension: .json or .md)
+    Export {
+        /// Portfolio id or name
+        id: String,
+        /// Output file
+        output: String,
+    },
+    /// Import a portfolio from a JSON export (it gets a fresh id)
+    Import {
+        /// JSON file written by `portfolio export`
+        file: String,
+    },
 }
 
 #[derive(clap::Subcommand)]
@@ -280,38 +758,72 @@ enum HistoryAction {
     },
     /// Clear all search history
     Clear,
+    /// Export all search history (format from extension: .json or .csv)
+    Export {
+        /// Output file path
+        output: String,
+    },
+    /// Import search history written by `history export`
+    Import {
+        /// JSON or CSV file written by `history export`
+        input: String,
+    },
+}
+
+#[derive(clap::Subcommand)]
+enum ReviewedAction {
+    /// List reviewed repositories, most recent first
+    List,
+    /// Unmark every reviewed repository
+    Clear,
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let mut cli = Cli::parse();
 
-    // Load tokens from secure storage if not provided via env/CLI
-    use reposcout_core::TokenStore;
-    if let Ok(store) = TokenStore::load() {
-        if cli.github_token.is_none() {
-            cli.github_token = store.get_token("github");
-        }
-        if cli.gitlab_token.is_none() {
-            cli.gitlab_token = store.get_token("gitlab");
-        }
-        // Note: Bitbucket uses username+password, not stored in TokenStore yet
-    }
-
     // Only initialize tracing for non-TUI commands to prevent log interference
     let is_tui_mode = matches!(cli.command, Some(Commands::Tui));
 
+    output::set_plain(cli.quiet);
+
     if !is_tui_mode {
         // Initialize logging - helps when things go sideways
+        let default_level = if cli.quiet {
+            "reposcout=warn"
+        } else {
+            "reposcout=info"
+        };
         tracing_subscriber::registry()
             .with(
                 tracing_subscriber::EnvFilter::try_from_default_env()
-                    .unwrap_or_else(|_| "reposcout=info".into()),
+                    .unwrap_or_else(|_| default_level.into()),
             )
-            .with(tracing_subscriber::fmt::layer())
+            .with(tracing_subscriber::fmt::layer().with_ansi(!cli.quiet))
             .init();
     }
 
+    resolve_tokens(&mut cli);
+
+    // Without an organization Azure DevOps searches find nothing, so say why
+    if !cli.quiet && cli.azure.token.is_some() && cli.azure.organization.is_none() {
+        tracing::warn!(
+            "{}",
+            reposcout_api::azure_devops::AzureDevOpsError::OrganizationRequired
+        );
+    }
+
+    // Persistent defaults from config.toml. CLI flags win over them for this
+    // run; `saved_config` keeps the file's own values for commands that
+    // change and save it
+    let saved_config = reposcout_core::Config::load()?;
+    let mut config = saved_config.clone();
+    apply_cli_overrides(&mut cli, &mut config);
+    config.health.install()?;
+    config.trending_score.install()?;
+    config.network.install();
+    reposcout_core::set_offline(config.cache.offline_mode);
+
     match cli.command {
         Some(Commands::Search {
             query,
@@ -320,22 +832,64 @@ async fn main() -> anyhow::Result<()> {
             min_stars,
             max_stars,
             pushed,
+            created_after,
+            created_before,
             sort,
             export,
+            columns,
+            fields,
+            append,
+            normalize,
+            exclude_forks,
+            exclude_archived,
+            hide_reviewed,
+            per_platform,
+            dedupe,
+            min_health,
+            health_unknown,
+            table,
+            format,
+            group_by,
+            open,
         }) => {
+            let sort = sort.or(config.search.default_sort.clone());
+            let columns = columns
+                .as_deref()
+                .map(reposcout_core::CsvColumn::parse_list)
+                .transpose()?;
+            let fields = fields
+                .as_deref()
+                .map(reposcout_core::parse_json_fields)
+                .transpose()?;
             search_repositories(
+                &config,
                 &query,
-                limit,
-                language,
+                limit.or(config.search.default_limit).unwrap_or(10),
+                language.or(config.search.default_language.clone()),
                 min_stars,
                 max_stars,
                 pushed,
-                &sort,
-                export,
+                (created_after, created_before),
+                exclude_forks,
+                exclude_archived,
+                hide_reviewed,
+                sort.as_deref(),
+                per_platform,
+                dedupe,
+                min_health.map(|score| (score, health_unknown == HealthUnknown::Keep)),
+                export.map(|path| (path, append)),
+                columns,
+                fields,
+                normalize,
+                table || format == OutputFormat::Table,
+                group_by.map(Into::into),
+                open.map(usize::from),
                 cli.github_token,
                 cli.gitlab_token,
                 cli.bitbucket_username,
                 cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
             )
             .await?;
         }
@@ -346,53 +900,151 @@ async fn main() -> anyhow::Result<()> {
             repo,
             path,
             extension,
+            context,
         }) => {
             search_code(
+                &config,
                 &query,
                 limit,
                 language,
                 repo,
                 path,
                 extension,
+                context,
+                cli.github_token,
+                cli.gitlab_token,
+                cli.bitbucket_username,
+                cli.bitbucket_app_password,
+                cli.bitbucket_workspace,
+            )
+            .await?;
+        }
+        Some(Commands::Show {
+            repos_from_file: Some(path),
+            json,
+            ..
+        }) => {
+            show_repositories_from_file(
+                &config,
+                &path,
+                json,
                 cli.github_token,
                 cli.gitlab_token,
                 cli.bitbucket_username,
                 cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
             )
             .await?;
         }
-        Some(Commands::Show { name }) => {
+        Some(Commands::Show { name, json, .. }) => {
             show_repository(
+                &config,
+                &name.unwrap_or_default(),
+                json,
+                cli.github_token,
+                cli.gitlab_token,
+                cli.bitbucket_username,
+                cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
+            )
+            .await?;
+        }
+        Some(Commands::Badge { name, format }) => {
+            print_badges(
+                &config,
+                &name,
+                format,
+                cli.github_token,
+                cli.gitlab_token,
+                cli.bitbucket_username,
+                cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
+            )
+            .await?;
+        }
+        Some(Commands::Deps { name, json }) => {
+            show_dependencies(
+                &config,
                 &name,
+                json,
+                cli.github_token,
+                cli.gitlab_token,
+                cli.bitbucket_username,
+                cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
+            )
+            .await?;
+        }
+        Some(Commands::Compare {
+            repo_a,
+            repo_b,
+            json,
+        }) => {
+            compare_repositories(
+                &config,
+                &repo_a,
+                &repo_b,
+                json,
                 cli.github_token,
                 cli.gitlab_token,
                 cli.bitbucket_username,
                 cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
             )
             .await?;
         }
         Some(Commands::Cache { action }) => {
-            handle_cache_command(action).await?;
+            handle_cache_command(&config, action).await?;
         }
         Some(Commands::Bookmark { action }) => {
             handle_bookmark_command(
+                &config,
+                action,
+                cli.github_token,
+                cli.gitlab_token,
+                cli.bitbucket_username,
+                cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
+            )
+            .await?;
+        }
+        Some(Commands::Watch { action }) => {
+            handle_watch_command(
+                &config,
                 action,
                 cli.github_token,
                 cli.gitlab_token,
                 cli.bitbucket_username,
                 cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
             )
             .await?;
         }
+        Some(Commands::Portfolio { action }) => {
+            handle_portfolio_command(action)?;
+        }
         Some(Commands::History { action }) => {
-            handle_history_command(action).await?;
+            handle_history_command(&config, action).await?;
+        }
+        Some(Commands::Reviewed { action }) => {
+            handle_reviewed_command(&config, action)?;
         }
         Some(Commands::Tui) => {
             run_tui_mode(
+                config,
                 cli.github_token,
                 cli.gitlab_token,
                 cli.bitbucket_username,
                 cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
             )
             .await?;
         }
@@ -403,14 +1055,23 @@ async fn main() -> anyhow::Result<()> {
             topic,
             limit,
             velocity,
+            platform,
+            min_language_percent,
+            table,
+            export,
         }) => {
             show_trending(
+                &config,
                 &period,
-                language,
+                language.or(config.search.default_language.clone()),
                 min_stars,
                 topic,
-                limit,
+                limit.or(config.search.default_limit).unwrap_or(20),
                 velocity,
+                platform,
+                min_language_percent,
+                table,
+                export,
                 cli.github_token,
                 cli.gitlab_token,
                 cli.bitbucket_username,
@@ -424,76 +1085,230 @@ async fn main() -> anyhow::Result<()> {
             hybrid,
             min_similarity,
             export,
+            model,
         }) => {
             handle_semantic_search(
+                &config,
                 &query,
                 limit,
                 hybrid,
                 min_similarity,
                 export,
+                model,
                 cli.github_token,
                 cli.gitlab_token,
                 cli.bitbucket_username,
                 cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
             )
             .await?;
         }
-        Some(Commands::SemanticIndex { action }) => {
-            handle_semantic_index(&action).await?;
+        Some(Commands::Similar { name, limit, model }) => {
+            show_similar(
+                &config,
+                &name,
+                limit,
+                model,
+                cli.github_token,
+                cli.gitlab_token,
+                cli.bitbucket_username,
+                cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure,
+            )
+            .await?;
         }
-        Some(Commands::Notifications { action }) => {
-            handle_notifications(action, cli.github_token).await?;
+        Some(Commands::SemanticIndex { action }) => {
+            handle_semantic_index(&config, &action).await?;
         }
-        None => {
-            println!("No command specified. Try --help");
+        Some(Commands::Whoami) => {
+            show_whoami(
+                &config,
+                cli.github_token,
+                cli.gitlab_token,
+                cli.bitbucket_username,
+                cli.bitbucket_app_password,
+                cli.sourcehut_token,
+                cli.azure.token,
+            )
+            .await?;
+        }
+        Some(Commands::Notifications { action }) => {
+            handle_notifications(&config, action, cli.github_token).await?;
+        }
+        Some(Commands::Theme { action }) => {
+            handle_theme_command(action)?;
+        }
+        Some(Commands::Block { action }) => {
+            handle_block_command(action, saved_config)?;
+        }
+        None => {
+            println!("No command specified. Try --help");
         }
     }
 
     Ok(())
 }
 
+/// Fill in missing tokens: flags/env vars win, then our token store,
+/// then the gh/glab CLI configs (unless opted out)
+fn resolve_tokens(cli: &mut Cli) {
+    use reposcout_core::TokenStore;
+
+    if let Ok(store) = TokenStore::load() {
+        if cli.github_token.is_none() {
+            cli.github_token = store.get_token("github");
+            if cli.github_token.is_some() {
+                tracing::info!("Using GitHub token from secure storage");
+            }
+        }
+        if cli.gitlab_token.is_none() {
+            cli.gitlab_token = store.get_token("gitlab");
+            if cli.gitlab_token.is_some() {
+                tracing::info!("Using GitLab token from secure storage");
+            }
+        }
+        if cli.sourcehut_token.is_none() {
+            cli.sourcehut_token = store.get_token("sourcehut");
+            if cli.sourcehut_token.is_some() {
+                tracing::info!("Using SourceHut token from secure storage");
+            }
+        }
+        if cli.azure.token.is_none() {
+            cli.azure.token = store.get_token("azure_devops");
+            if cli.azure.token.is_some() {
+                tracing::info!("Using Azure DevOps token from secure storage");
+            }
+        }
+        // Note: Bitbucket uses username+password, not stored in TokenStore yet
+    }
+
+    if cli.no_cli_credentials {
+        return;
+    }
+
+    if cli.github_token.is_none() {
+        if let Some((token, path)) = TokenStore::discover_cli_token("github") {
+            tracing::info!("Using GitHub token from gh CLI config ({})", path.display());
+            cli.github_token = Some(token);
+        }
+    }
+    if cli.gitlab_token.is_none() {
+        if let Some((token, path)) = TokenStore::discover_cli_token("gitlab") {
+            tracing::info!(
+                "Using GitLab token from glab CLI config ({})",
+                path.display()
+            );
+            cli.gitlab_token = Some(token);
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn search_repositories(
+    config: &reposcout_core::Config,
     query: &str,
     limit: usize,
     language: Option<String>,
     min_stars: Option<u32>,
     max_stars: Option<u32>,
     pushed: Option<String>,
-    sort: &str,
-    export: Option<String>,
+    created: (Option<NaiveDate>, Option<NaiveDate>),
+    exclude_forks: bool,
+    exclude_archived: bool,
+    hide_reviewed: bool,
+    sort: Option<&str>,
+    per_platform: Option<usize>,
+    dedupe: bool,
+    min_health: Option<(u8, bool)>,
+    export: Option<(String, bool)>,
+    columns: Option<Vec<reposcout_core::CsvColumn>>,
+    fields: Option<Vec<String>>,
+    normalize: bool,
+    table: bool,
+    group_by: Option<reposcout_core::GroupBy>,
+    open: Option<usize>,
     github_token: Option<String>,
     gitlab_token: Option<String>,
     bitbucket_username: Option<String>,
     bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
 ) -> anyhow::Result<()> {
     // Build GitHub search query with filters
-    let search_query = build_github_query(
+    let search_query = reposcout_core::build_github_query(
         query,
-        language.clone(),
-        min_stars,
-        max_stars,
-        pushed.clone(),
+        &reposcout_core::QueryFilters {
+            language: language.clone(),
+            min_stars,
+            max_stars,
+            pushed: pushed.clone(),
+            created_after: created.0,
+            created_before: created.1,
+            exclude_forks,
+            exclude_archived,
+        },
     );
     tracing::info!("Searching for: {}", search_query);
 
     // Initialize cache
     let cache_path = get_cache_path()?;
-    let cache = CacheManager::new(cache_path.to_str().unwrap(), 24)?;
+    let cache = open_cache(config, &cache_path)?;
 
-    let mut engine = CachedSearchEngine::with_cache(cache);
+    let mut engine = cached_engine(config, cache);
+    engine.set_normalize(normalize);
+    engine.set_filters(config.filters.clone());
+    engine.set_per_provider_limit(per_platform);
+    engine.set_dedupe(dedupe);
     // Add all providers - search across all platforms
-    engine.add_provider(Box::new(GitHubProvider::new(github_token)));
+    engine.add_provider(Box::new(github_provider(config, github_token)));
     engine.add_provider(Box::new(GitLabProvider::new(gitlab_token)));
-    engine.add_provider(Box::new(BitbucketProvider::new(
+    engine.add_provider(Box::new(bitbucket_provider(
+        config,
         bitbucket_username,
         bitbucket_app_password,
     )));
+    engine.add_provider(Box::new(SourceHutProvider::new(sourcehut_token)));
+    engine.add_provider(Box::new(azure.provider()));
 
     let mut results = engine.search(&search_query).await?;
 
-    // Sort results based on user preference
-    sort_results(&mut results, sort);
+    // GitHub honours fork:/archived:/created: in the query, the other platforms don't
+    let (created_after, created_before) = created;
+    results.retain(|repo| {
+        let created_on = repo.created_at.date_naive();
+        let excluded = (exclude_forks && repo.is_fork)
+            || (exclude_archived && repo.is_archived)
+            || created_after.is_some_and(|after| created_on < after)
+            || created_before.is_some_and(|before| created_on > before);
+        !excluded
+    });
+    if let Some((min_score, keep_unknown)) = min_health {
+        reposcout_core::retain_min_health(&mut results, min_score, keep_unknown);
+    }
+    if hide_reviewed {
+        let reviewed: std::collections::HashSet<_> = open_cache(config, &cache_path)?
+            .get_reviewed()?
+            .into_iter()
+            .map(|entry| (entry.platform, entry.full_name))
+            .collect();
+        results.retain(|repo| {
+            let key = (
+                repo.platform.to_string().to_lowercase(),
+                repo.full_name.clone(),
+            );
+            !reviewed.contains(&key)
+        });
+    }
+
+    // Sort results based on user preference (normalized ranking is already applied).
+    // With --per-platform the interleaved order stands unless a sort was asked for.
+    let keep_interleave = per_platform.is_some() && sort.is_none();
+    let sort = sort.unwrap_or("stars");
+    if !normalize && !keep_interleave {
+        sort_results(&mut results, sort);
+    }
 
     // Record search in history (create new cache instance to avoid borrow issues)
     let filters = build_filters_string(
@@ -501,70 +1316,175 @@ async fn search_repositories(
         min_stars,
         max_stars,
         pushed.as_deref(),
+        created,
+        exclude_forks,
+        exclude_archived,
         sort,
     );
-    let history_cache = CacheManager::new(cache_path.to_str().unwrap(), 24)?;
+    let history_cache = open_cache(config, &cache_path)?;
     if let Err(e) =
         history_cache.add_search_history(query, filters.as_deref(), Some(results.len() as i64))
     {
         tracing::warn!("Failed to save search history: {}", e);
     }
 
-    if results.is_empty() {
-        println!("No repositories found for '{}'", query);
+    // --open jumps straight to one result, so there's nothing to list or export
+    if let Some(n) = open {
+        let Some(repo) = results.get(n - 1) else {
+            anyhow::bail!(
+                "Can't open result {}: the search for '{}' found {} {}",
+                n,
+                query,
+                results.len(),
+                if results.len() == 1 {
+                    "repository"
+                } else {
+                    "repositories"
+                }
+            );
+        };
+        open::that(&repo.url).map_err(|e| anyhow::anyhow!("Failed to open {}: {}", repo.url, e))?;
+        output::status(
+            "✓",
+            format!("Opened {} ({}) {}", repo.full_name, repo.platform, repo.url),
+        );
         return Ok(());
     }
 
-    // Handle export if requested
-    if let Some(export_path) = export {
+    // Handle export if requested. Empty results still produce a file so
+    // downstream tooling sees an empty export rather than a missing one.
+    if let Some((export_path, append)) = export {
         use reposcout_core::Exporter;
 
-        // Export all results (not limited by display limit)
-        Exporter::export_to_file(&results, &export_path)
+        if append {
+            let added = Exporter::append_search_results(
+                &results,
+                &export_path,
+                query,
+                columns.as_deref(),
+                fields.as_deref(),
+            )
             .map_err(|e| anyhow::anyhow!("Export failed: {}", e))?;
+            output::status(
+                "✓",
+                format!(
+                    "Appended {} repositories to {} ({} already there)",
+                    added,
+                    export_path,
+                    results.len() - added
+                ),
+            );
+            return Ok(());
+        }
 
-        println!(
-            "✓ Exported {} repositories to {}",
-            results.len(),
-            export_path
+        // Export all results (not limited by display limit)
+        Exporter::export_search_results(
+            &results,
+            &export_path,
+            query,
+            columns.as_deref(),
+            fields.as_deref(),
+        )
+        .map_err(|e| anyhow::anyhow!("Export failed: {}", e))?;
+
+        output::status(
+            "✓",
+            format!("Exported {} repositories to {}", results.len(), export_path),
         );
         return Ok(());
     }
 
-    println!("\nFound {} repositories:\n", results.len());
+    if results.is_empty() {
+        output::notice(format!("No repositories found for '{}'", query));
+        return Ok(());
+    }
 
-    for (i, repo) in results.iter().take(limit).enumerate() {
-        println!("{}. {} ({})", i + 1, repo.full_name, repo.platform);
-        if let Some(desc) = &repo.description {
-            println!("   {}", desc);
+    let shown = &results[..limit.min(results.len())];
+    if let Some(group_by) = group_by {
+        if !table {
+            output::heading(format!("\nFound {} repositories:", results.len()));
         }
+        // Numbering runs on across groups
+        let mut number = 0;
+        for group in reposcout_core::group_repositories(shown, group_by) {
+            let repos: Vec<_> = group.indices.iter().map(|&i| shown[i].clone()).collect();
+            // Plain records carry their group instead of a banner
+            if output::is_plain() {
+                for repo in &repos {
+                    output::repo_record(repo, std::slice::from_ref(&group.name));
+                }
+                continue;
+            }
+            println!("\n=== {} ({}) ===", group.name, group.indices.len());
+            if table {
+                print_repo_table(&repos, repos.len());
+            } else {
+                println!();
+                for repo in &repos {
+                    number += 1;
+                    print_repo_entry(number, repo);
+                }
+            }
+        }
+        return Ok(());
+    }
 
-        // Show health indicator if available
-        let health_indicator = if let Some(health) = &repo.health {
-            format!(" {} {}", health.status.emoji(), health.maintenance.label())
-        } else {
-            String::new()
-        };
+    if table {
+        print_repo_table(&results, limit);
+        return Ok(());
+    }
 
-        println!(
-            "   ⭐ {} | 🍴 {} | {}{}",
-            repo.stars,
-            repo.forks,
-            repo.language.as_deref().unwrap_or("Unknown"),
-            health_indicator
-        );
-        println!("   {}\n", repo.url);
+    output::heading(format!("\nFound {} repositories:\n", results.len()));
+
+    for (i, repo) in shown.iter().enumerate() {
+        print_repo_entry(i + 1, repo);
     }
 
     Ok(())
 }
 
+/// One numbered result in the detailed list format
+fn print_repo_entry(number: usize, repo: &reposcout_core::models::Repository) {
+    if output::is_plain() {
+        output::repo_record(repo, &[]);
+        return;
+    }
+    println!("{}. {} ({})", number, repo.full_name, repo.platform);
+    if let Some(desc) = &repo.description {
+        println!("   {}", desc);
+    }
+
+    // Show health indicator if available
+    let health_indicator = if let Some(health) = &repo.health {
+        format!(" {} {}", health.status.emoji(), health.maintenance.label())
+    } else {
+        String::new()
+    };
+
+    println!(
+        "   ⭐ {} | 🍴 {} | {}{}",
+        repo.stars,
+        repo.forks,
+        repo.language.as_deref().unwrap_or("Unknown"),
+        health_indicator
+    );
+    if let Some(mirrors) = repo.mirrors_label() {
+        println!("   🪞 {}", mirrors);
+    }
+    println!("   {}\n", repo.url);
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn show_repository(
+    config: &reposcout_core::Config,
     full_name: &str,
+    json: bool,
     github_token: Option<String>,
     gitlab_token: Option<String>,
     bitbucket_username: Option<String>,
     bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
 ) -> anyhow::Result<()> {
     // Parse owner/repo format
     let parts: Vec<&str> = full_name.split('/').collect();
@@ -577,19 +1497,31 @@ async fn show_repository(
 
     // Initialize cache
     let cache_path = get_cache_path()?;
-    let cache = CacheManager::new(cache_path.to_str().unwrap(), 24)?;
+    let cache = open_cache(config, &cache_path)?;
 
-    let mut engine = CachedSearchEngine::with_cache(cache);
+    let mut engine = cached_engine(config, cache);
     // Add all providers - will try all platforms
-    engine.add_provider(Box::new(GitHubProvider::new(github_token)));
+    engine.add_provider(Box::new(github_provider(config, github_token)));
     engine.add_provider(Box::new(GitLabProvider::new(gitlab_token)));
-    engine.add_provider(Box::new(BitbucketProvider::new(
+    engine.add_provider(Box::new(bitbucket_provider(
+        config,
         bitbucket_username,
         bitbucket_app_password,
     )));
+    engine.add_provider(Box::new(SourceHutProvider::new(sourcehut_token)));
+    engine.add_provider(Box::new(azure.provider()));
 
     let repository = engine.get_repository(owner, repo).await?;
 
+    if json {
+        println!("{}", serde_json::to_string_pretty(&repository)?);
+        return Ok(());
+    }
+    if output::is_plain() {
+        print_repository_record(&repository);
+        return Ok(());
+    }
+
     println!("\n{}\n", "=".repeat(60));
     println!("📦 {}", repository.full_name);
     println!("{}\n", "=".repeat(60));
@@ -606,19 +1538,16 @@ async fn show_repository(
     println!("Stars:         ⭐ {}", repository.stars);
     println!("Forks:         🍴 {}", repository.forks);
     println!("Open Issues:   {}", repository.open_issues);
+    if let Some(mrs) = repository.open_merge_requests {
+        println!("Open MRs:      {}", mrs);
+    }
     println!(
         "License:       {}",
         repository.license.as_deref().unwrap_or("None")
     );
-    println!(
-        "Created:       {}",
-        repository.created_at.format("%Y-%m-%d")
-    );
-    println!(
-        "Last Updated:  {}",
-        repository.updated_at.format("%Y-%m-%d")
-    );
-    println!("Last Pushed:   {}", repository.pushed_at.format("%Y-%m-%d"));
+    println!("Created:       {}", format_day(repository.created_at));
+    println!("Last Updated:  {}", format_day(repository.updated_at));
+    println!("Last Pushed:   {}", format_day(repository.pushed_at));
 
     if !repository.topics.is_empty() {
         println!("\nTopics: {}", repository.topics.join(", "));
@@ -635,20 +1564,461 @@ async fn show_repository(
     Ok(())
 }
 
-async fn handle_cache_command(action: CacheAction) -> anyhow::Result<()> {
+/// `show` output under `--quiet`: one field-and-value record per line
+fn print_repository_record(repo: &reposcout_core::models::Repository) {
+    // Dates the platform didn't report are left empty, like other fields
+    let date = |d: chrono::DateTime<chrono::Utc>| {
+        if repo.has_dates() {
+            d.format("%Y-%m-%d").to_string()
+        } else {
+            String::new()
+        }
+    };
+    let mut fields = vec![
+        ("name", repo.full_name.clone()),
+        ("platform", repo.platform.to_string()),
+        ("language", repo.language.clone().unwrap_or_default()),
+        ("stars", repo.stars.to_string()),
+        ("forks", repo.forks.to_string()),
+        ("open_issues", repo.open_issues.to_string()),
+    ];
+    if let Some(mrs) = repo.open_merge_requests {
+        fields.push(("open_merge_requests", mrs.to_string()));
+    }
+    fields.extend([
+        ("license", repo.license.clone().unwrap_or_default()),
+        ("created", date(repo.created_at)),
+        ("updated", date(repo.updated_at)),
+        ("pushed", date(repo.pushed_at)),
+        ("topics", repo.topics.join(",")),
+        ("homepage", repo.homepage_url.clone().unwrap_or_default()),
+        ("url", repo.url.clone()),
+        ("description", repo.description.clone().unwrap_or_default()),
+    ]);
+    for (name, value) in fields {
+        output::record(&[name, value.as_str()]);
+    }
+}
+
+/// Fetch every repository listed in `path` and print them as one table or
+/// JSON array. Lines that fail are reported at the end instead of stopping
+/// the batch, and the command fails if any did.
+#[allow(clippy::too_many_arguments)]
+async fn show_repositories_from_file(
+    config: &reposcout_core::Config,
+    path: &std::path::Path,
+    json: bool,
+    github_token: Option<String>,
+    gitlab_token: Option<String>,
+    bitbucket_username: Option<String>,
+    bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
+) -> anyhow::Result<()> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+
+    // (line number, entry), skipping blank lines and comments
+    let entries: Vec<(usize, &str)> = contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .collect();
+
+    let cache_path = get_cache_path()?;
+    let cache = open_cache(config, &cache_path)?;
+
+    let mut engine = cached_engine(config, cache);
+    engine.add_provider(Box::new(github_provider(config, github_token)));
+    engine.add_provider(Box::new(GitLabProvider::new(gitlab_token)));
+    engine.add_provider(Box::new(bitbucket_provider(
+        config,
+        bitbucket_username,
+        bitbucket_app_password,
+    )));
+    engine.add_provider(Box::new(SourceHutProvider::new(sourcehut_token)));
+    engine.add_provider(Box::new(azure.provider()));
+
+    let engine = &engine;
+    let results = reposcout_core::fetch_concurrently(
+        entries.iter().copied(),
+        fetch_concurrency(config),
+        |(_, entry)| async move {
+            match entry.split_once('/') {
+                Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() => engine
+                    .get_repository(owner, repo)
+                    .await
+                    .map_err(|e| e.to_string()),
+                _ => Err("not in 'owner/repo' format".to_string()),
+            }
+        },
+    )
+    .await;
+
+    let mut repositories = Vec::new();
+    let mut failures = Vec::new();
+    for (&(line, entry), result) in entries.iter().zip(results) {
+        match result {
+            Ok(repository) => repositories.push(repository),
+            Err(e) => failures.push((line, entry, e)),
+        }
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&repositories)?);
+    } else if !repositories.is_empty() {
+        print_repo_table(&repositories, repositories.len());
+    }
+
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    eprintln!(
+        "\n{} of {} repositories could not be fetched:",
+        failures.len(),
+        entries.len()
+    );
+    for (line, entry, error) in &failures {
+        eprintln!("  line {}: {}: {}", line, entry, error);
+    }
+    anyhow::bail!("Batch finished with {} failed lines", failures.len())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn print_badges(
+    config: &reposcout_core::Config,
+    full_name: &str,
+    format: BadgeOutput,
+    github_token: Option<String>,
+    gitlab_token: Option<String>,
+    bitbucket_username: Option<String>,
+    bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
+) -> anyhow::Result<()> {
+    use reposcout_core::{Badge, BadgeFormat};
+
+    let Some((owner, repo)) = full_name.split_once('/') else {
+        anyhow::bail!("Repository name must be in 'owner/repo' format");
+    };
+
+    let cache_path = get_cache_path()?;
+    let cache = open_cache(config, &cache_path)?;
+
+    let mut engine = cached_engine(config, cache);
+    engine.add_provider(Box::new(github_provider(config, github_token)));
+    engine.add_provider(Box::new(GitLabProvider::new(gitlab_token)));
+    engine.add_provider(Box::new(bitbucket_provider(
+        config,
+        bitbucket_username,
+        bitbucket_app_password,
+    )));
+    engine.add_provider(Box::new(SourceHutProvider::new(sourcehut_token)));
+    engine.add_provider(Box::new(azure.provider()));
+
+    let mut repository = engine.get_repository(owner, repo).await?;
+
+    let format = match format {
+        BadgeOutput::Markdown => BadgeFormat::Markdown,
+        BadgeOutput::Html => BadgeFormat::Html,
+    };
+    for badge in Badge::for_repository(&mut repository) {
+        println!("{}", badge.render(format));
+    }
+
+    Ok(())
+}
+
+/// Fetch the manifest for a repository's language and print its
+/// dependencies grouped by type
+#[allow(clippy::too_many_arguments)]
+async fn show_dependencies(
+    config: &reposcout_core::Config,
+    full_name: &str,
+    json: bool,
+    github_token: Option<String>,
+    gitlab_token: Option<String>,
+    bitbucket_username: Option<String>,
+    bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
+) -> anyhow::Result<()> {
+    use reposcout_core::missing_as_none;
+    use reposcout_core::models::Platform;
+    use reposcout_deps::DependencyType;
+
+    let Some((owner, repo)) = full_name.split_once('/') else {
+        anyhow::bail!("Repository name must be in 'owner/repo' format");
+    };
+    reposcout_core::ensure_online("Dependency analysis")?;
+
     let cache_path = get_cache_path()?;
-    let cache = CacheManager::new(cache_path.to_str().unwrap(), 24)?;
+    let cache = open_cache(config, &cache_path)?;
+
+    let mut engine = cached_engine(config, cache);
+    engine.add_provider(Box::new(github_provider(config, github_token.clone())));
+    engine.add_provider(Box::new(GitLabProvider::new(gitlab_token.clone())));
+    engine.add_provider(Box::new(bitbucket_provider(
+        config,
+        bitbucket_username.clone(),
+        bitbucket_app_password.clone(),
+    )));
+    engine.add_provider(Box::new(SourceHutProvider::new(sourcehut_token.clone())));
+    engine.add_provider(Box::new(azure.provider()));
+
+    let repository = engine.get_repository(owner, repo).await?;
+    let language = repository.language.as_deref();
+
+    // Manifests are read from wherever the repository was found. A missing
+    // file moves on to the next manifest; any other failure is reported
+    let (owner, repo) = repository
+        .full_name
+        .split_once('/')
+        .unwrap_or((owner, repo));
+    let github = github_client(config, github_token);
+    let gitlab = reposcout_api::GitLabClient::new(gitlab_token);
+    let bitbucket = bitbucket_client(config, bitbucket_username, bitbucket_app_password);
+    let sourcehut = reposcout_api::SourceHutClient::new(sourcehut_token);
+    let azure_devops = azure.client();
+    let fetch = |path: &'static str| {
+        let (github, gitlab, bitbucket, sourcehut, azure_devops) =
+            (&github, &gitlab, &bitbucket, &sourcehut, &azure_devops);
+        let full_name = repository.full_name.as_str();
+        let platform = repository.platform;
+        async move {
+            let file = match platform {
+                Platform::GitHub => {
+                    missing_as_none(github.get_file_content(owner, repo, path).await)
+                }
+                Platform::GitLab => missing_as_none(gitlab.get_file_content(full_name, path).await),
+                Platform::Bitbucket => {
+                    missing_as_none(bitbucket.get_file_content(owner, repo, path).await)
+                }
+                Platform::SourceHut => {
+                    missing_as_none(sourcehut.get_file_content(owner, repo, path).await)
+                }
+                Platform::AzureDevOps => {
+                    missing_as_none(azure_devops.get_file_content(owner, repo, path).await)
+                }
+            };
+            Ok(file?)
+        }
+    };
+
+    let Some(info) = reposcout_deps::analyze_dependencies(language, fetch).await? else {
+        match language {
+            Some(language) => anyhow::bail!(
+                "No supported dependency file found in {} ({})",
+                repository.full_name,
+                language
+            ),
+            None => anyhow::bail!(
+                "{} has no detected language to pick a dependency file by",
+                repository.full_name
+            ),
+        }
+    };
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+        return Ok(());
+    }
+
+    let groups: Vec<_> = [
+        (DependencyType::Runtime, "Runtime"),
+        (DependencyType::Dev, "Dev"),
+        (DependencyType::Build, "Build"),
+        (DependencyType::Optional, "Optional"),
+        (DependencyType::Indirect, "Indirect"),
+    ]
+    .into_iter()
+    .map(|(dep_type, label)| {
+        let deps: Vec<_> = info
+            .dependencies
+            .iter()
+            .filter(|d| d.dep_type == dep_type)
+            .collect();
+        (label, deps)
+    })
+    .filter(|(_, deps)| !deps.is_empty())
+    .collect();
+
+    println!("\n{}\n", "=".repeat(60));
+    println!("📦 {} ({})", repository.full_name, info.ecosystem);
+    println!("{}\n", "=".repeat(60));
+
+    println!("Total:     {}", info.total_count);
+    for (label, deps) in &groups {
+        println!("{:<11}{}", format!("{}:", label), deps.len());
+    }
+
+    for (label, deps) in &groups {
+        println!("\n{}:", label);
+        for dep in deps {
+            println!("  {} {}", dep.name, dep.version);
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn compare_repositories(
+    config: &reposcout_core::Config,
+    repo_a: &str,
+    repo_b: &str,
+    json: bool,
+    github_token: Option<String>,
+    gitlab_token: Option<String>,
+    bitbucket_username: Option<String>,
+    bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
+) -> anyhow::Result<()> {
+    let split = |full_name: &str| -> anyhow::Result<(String, String)> {
+        match full_name.split_once('/') {
+            Some((owner, repo)) if !owner.is_empty() && !repo.is_empty() => {
+                Ok((owner.to_string(), repo.to_string()))
+            }
+            _ => anyhow::bail!("Repository name must be in 'owner/repo' format"),
+        }
+    };
+    let (owner_a, name_a) = split(repo_a)?;
+    let (owner_b, name_b) = split(repo_b)?;
+
+    // Initialize cache
+    let cache_path = get_cache_path()?;
+    let cache = open_cache(config, &cache_path)?;
+
+    let mut engine = cached_engine(config, cache);
+    engine.add_provider(Box::new(github_provider(config, github_token)));
+    engine.add_provider(Box::new(GitLabProvider::new(gitlab_token)));
+    engine.add_provider(Box::new(bitbucket_provider(
+        config,
+        bitbucket_username,
+        bitbucket_app_password,
+    )));
+    engine.add_provider(Box::new(SourceHutProvider::new(sourcehut_token)));
+    engine.add_provider(Box::new(azure.provider()));
+
+    let engine = &engine;
+    let fetched = reposcout_core::fetch_concurrently(
+        [(owner_a, name_a), (owner_b, name_b)],
+        fetch_concurrency(config),
+        |(owner, name)| async move { engine.get_repository(&owner, &name).await },
+    )
+    .await;
+    let [a, b]: [_; 2] = fetched.try_into().expect("two repositories were fetched");
+    let (mut a, mut b) = (a?, b?);
+
+    // Cached repos may lack health metrics; the comparison computes them
+    let comparison = reposcout_core::compare_repositories(&mut a, &mut b);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&comparison)?);
+        return Ok(());
+    }
+
+    let (a, b) = (&comparison.repo_a, &comparison.repo_b);
+    let mark = |name: &str| {
+        if comparison.healthier.as_deref() == Some(name) {
+            " ✓"
+        } else {
+            ""
+        }
+    };
+    let row = |label: &str, left: String, right: String| {
+        println!("{:<14} {:<30} {}", label, left, right);
+    };
+
+    println!("\n{}", "=".repeat(76));
+    row("", a.full_name.clone(), b.full_name.clone());
+    println!("{}", "=".repeat(76));
+    row("Platform", a.platform.clone(), b.platform.clone());
+    row("Stars", a.stars.to_string(), b.stars.to_string());
+    row("Forks", a.forks.to_string(), b.forks.to_string());
+    row(
+        "Open Issues",
+        a.open_issues.to_string(),
+        b.open_issues.to_string(),
+    );
+    row(
+        "Language",
+        a.language.clone().unwrap_or_else(|| "Unknown".to_string()),
+        b.language.clone().unwrap_or_else(|| "Unknown".to_string()),
+    );
+    row(
+        "License",
+        a.license.clone().unwrap_or_else(|| "None".to_string()),
+        b.license.clone().unwrap_or_else(|| "None".to_string()),
+    );
+    row(
+        "Age",
+        format!("{} days", a.age_days),
+        format!("{} days", b.age_days),
+    );
+    row(
+        "Last Pushed",
+        format!(
+            "{} ({}d ago)",
+            a.pushed_at.format("%Y-%m-%d"),
+            a.days_since_push
+        ),
+        format!(
+            "{} ({}d ago)",
+            b.pushed_at.format("%Y-%m-%d"),
+            b.days_since_push
+        ),
+    );
+    row(
+        "Health",
+        format!("{}/100 {}", a.health_score, a.health_status),
+        format!("{}/100 {}", b.health_score, b.health_status),
+    );
+    row(
+        "Maintenance",
+        format!("{}{}", a.maintenance, mark(&a.full_name)),
+        format!("{}{}", b.maintenance, mark(&b.full_name)),
+    );
+
+    println!();
+    for line in &comparison.summary {
+        println!("• {}", line);
+    }
+    println!();
+
+    Ok(())
+}
+
+async fn handle_cache_command(
+    config: &reposcout_core::Config,
+    action: CacheAction,
+) -> anyhow::Result<()> {
+    let cache_path = get_cache_path()?;
+    let cache = open_cache(config, &cache_path)?;
 
     match action {
         CacheAction::Stats => {
             let stats = cache.stats()?;
             println!("\n📊 Cache Statistics:\n");
             println!("Repository Cache:");
-            println!("  Total entries:   {}", stats.total_entries);
+            println!(
+                "  Total entries:   {} / {} ({:.1}% of cap)",
+                stats.total_entries,
+                stats.max_entries,
+                stats.repo_utilization()
+            );
             println!("  Valid entries:   {}", stats.valid_entries);
             println!("  Expired entries: {}", stats.expired_entries);
             println!("\nQuery Cache:");
-            println!("  Cached queries:  {}", stats.query_cache_entries);
+            println!(
+                "  Cached queries:  {} / {} ({:.1}% of cap)",
+                stats.query_cache_entries,
+                stats.max_entries,
+                stats.query_utilization()
+            );
             println!("  Expired queries: {}", stats.query_cache_expired);
             println!(
                 "  Valid queries:   {}",
@@ -673,151 +2043,650 @@ async fn handle_cache_command(action: CacheAction) -> anyhow::Result<()> {
                 deleted_repos, deleted_queries
             );
         }
+        CacheAction::Invalidate {
+            target,
+            platform,
+            query,
+        } => {
+            let is_repo = !query
+                && target.contains('/')
+                && !target.contains(':')
+                && !target.contains(char::is_whitespace);
+            if is_repo {
+                let removed = cache.invalidate_repo(platform.as_deref(), &target)?;
+                if removed == 0 {
+                    output::notice(format!("No cached entry for {}", target));
+                } else {
+                    output::status(
+                        "✅",
+                        format!(
+                            "Removed {} cached {} for {}",
+                            removed,
+                            if removed == 1 { "entry" } else { "entries" },
+                            target
+                        ),
+                    );
+                }
+            } else {
+                if platform.is_some() {
+                    anyhow::bail!(
+                        "--platform only applies to a repository (owner/repo), not to the search query \"{}\"",
+                        target
+                    );
+                }
+                let removed = cache.invalidate_query(&target)?;
+                if removed == 0 {
+                    // Search filters become part of the cached query
+                    output::notice(format!(
+                        "No cached results for \"{}\" (include filters as they were searched, e.g. \"rust language:rust\")",
+                        target
+                    ));
+                } else {
+                    output::status("✅", format!("Removed cached results for \"{}\"", target));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn handle_bookmark_command(
+    config: &reposcout_core::Config,
+    action: BookmarkAction,
+    github_token: Option<String>,
+    gitlab_token: Option<String>,
+    bitbucket_username: Option<String>,
+    bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
+) -> anyhow::Result<()> {
+    use reposcout_core::models::Repository;
+
+    let cache_path = get_cache_path()?;
+    let cache = open_cache(config, &cache_path)?;
+
+    match action {
+        BookmarkAction::List => {
+            let bookmarks: Vec<Repository> = cache.get_bookmarks()?;
+
+            if bookmarks.is_empty() {
+                output::notice(
+                    "No bookmarks found. Use 'reposcout bookmark add <repo>' to add one.",
+                );
+                return Ok(());
+            }
+
+            output::heading(format!("\n📚 Your Bookmarks ({}):\n", bookmarks.len()));
+            for (i, repo) in bookmarks.iter().enumerate() {
+                if output::is_plain() {
+                    output::repo_record(repo, &[]);
+                    continue;
+                }
+                println!("{}. {} ({})", i + 1, repo.full_name, repo.platform);
+                if let Some(desc) = &repo.description {
+                    println!("   {}", desc);
+                }
+                println!(
+                    "   ⭐ {} | 🍴 {} | {}",
+                    repo.stars,
+                    repo.forks,
+                    repo.language.as_deref().unwrap_or("Unknown")
+                );
+                println!("   {}\n", repo.url);
+            }
+        }
+        BookmarkAction::Search { term } => {
+            let matches = cache.search_bookmarks(&term)?;
+
+            if matches.is_empty() {
+                output::notice(format!("No bookmarks match '{}'.", term));
+                return Ok(());
+            }
+
+            output::heading(format!(
+                "\n📚 Bookmarks matching '{}' ({}):\n",
+                term,
+                matches.len()
+            ));
+            for (i, entry) in matches.iter().enumerate() {
+                let repo: Repository = serde_json::from_str(&entry.data)?;
+                if output::is_plain() {
+                    let tags = entry.tags.clone().unwrap_or_default();
+                    let notes = entry.notes.clone().unwrap_or_default();
+                    output::repo_record(&repo, &[tags, notes]);
+                    continue;
+                }
+                println!("{}. {} ({})", i + 1, repo.full_name, repo.platform);
+                if let Some(desc) = &repo.description {
+                    println!("   {}", desc);
+                }
+                if let Some(tags) = entry.tags.as_deref().filter(|t| !t.is_empty()) {
+                    println!("   🏷  {}", tags);
+                }
+                if let Some(notes) = entry.notes.as_deref().filter(|n| !n.is_empty()) {
+                    println!("   📝 {}", notes);
+                }
+                println!("   {}\n", repo.url);
+            }
+        }
+        BookmarkAction::Add { name, tags, notes } => {
+            // Parse owner/repo format
+            let parts: Vec<&str> = name.split('/').collect();
+            if parts.len() != 2 {
+                anyhow::bail!("Repository name must be in 'owner/repo' format");
+            }
+
+            let (owner, repo_name) = (parts[0], parts[1]);
+
+            // Fetch repository details
+            let cache_manager = open_cache(config, &cache_path)?;
+            let mut engine = cached_engine(config, cache_manager);
+            engine.add_provider(Box::new(github_provider(config, github_token)));
+            engine.add_provider(Box::new(GitLabProvider::new(gitlab_token)));
+            engine.add_provider(Box::new(bitbucket_provider(
+                config,
+                bitbucket_username,
+                bitbucket_app_password,
+            )));
+            engine.add_provider(Box::new(SourceHutProvider::new(sourcehut_token)));
+            engine.add_provider(Box::new(azure.provider()));
+
+            let repository = engine.get_repository(owner, repo_name).await?;
+
+            // Add to bookmarks
+            cache.add_bookmark(
+                &repository.platform.to_string().to_lowercase(),
+                &repository.full_name,
+                &repository,
+                tags.as_deref(),
+                notes.as_deref(),
+            )?;
+
+            output::status("✅", format!("Bookmarked: {}", repository.full_name));
+        }
+        BookmarkAction::Remove { name } => {
+            // Try to remove from all platforms
+            let removed_github = cache.remove_bookmark("github", &name).is_ok();
+            let removed_gitlab = cache.remove_bookmark("gitlab", &name).is_ok();
+            let removed_bitbucket = cache.remove_bookmark("bitbucket", &name).is_ok();
+
+            if removed_github || removed_gitlab || removed_bitbucket {
+                output::status("✅", format!("Removed bookmark: {}", name));
+            } else {
+                output::status("❌", format!("Bookmark not found: {}", name));
+            }
+        }
+        BookmarkAction::Export {
+            output,
+            format,
+            columns,
+        } => {
+            let bookmarks = cache.get_bookmarks_with_metadata()?;
+
+            if columns.is_some() && format != "csv" {
+                anyhow::bail!("--columns only applies to --format csv");
+            }
+
+            match format.as_str() {
+                "json" => {
+                    let json = serde_json::to_string_pretty(&bookmarks)?;
+                    std::fs::write(&output, json)?;
+                    println!("✅ Exported {} bookmarks to {}", bookmarks.len(), output);
+                }
+                "csv" => {
+                    match columns {
+                        Some(spec) => export_bookmarks_csv_columns(
+                            &bookmarks,
+                            &output,
+                            &BookmarkColumn::parse_list(&spec)?,
+                        )?,
+                        None => export_bookmarks_csv(&bookmarks, &output)?,
+                    }
+                    println!("✅ Exported {} bookmarks to {}", bookmarks.len(), output);
+                }
+                _ => {
+                    anyhow::bail!("Unsupported format: {}. Use 'json' or 'csv'", format);
+                }
+            }
+        }
+        BookmarkAction::Import { input } => {
+            let content = std::fs::read_to_string(&input)?;
+            let bookmarks: Vec<BookmarkEntry> = serde_json::from_str(&content)?;
+
+            for entry in &bookmarks {
+                let repo: Repository = serde_json::from_str(&entry.data)?;
+                cache.add_bookmark(
+                    &entry.platform,
+                    &entry.full_name,
+                    &repo,
+                    entry.tags.as_deref(),
+                    entry.notes.as_deref(),
+                )?;
+            }
+
+            println!("✅ Imported {} bookmarks from {}", bookmarks.len(), input);
+        }
+        BookmarkAction::Clear => {
+            cache.clear_bookmarks()?;
+            println!("✅ All bookmarks cleared");
+        }
+        BookmarkAction::Refresh { dry_run, limit } => {
+            let entries = cache.get_bookmarks_with_metadata()?;
+            if entries.is_empty() {
+                println!("No bookmarks found. Use 'reposcout bookmark add <repo>' to add one.");
+                return Ok(());
+            }
+
+            let engines = platform_engines(
+                config,
+                github_token,
+                gitlab_token,
+                bitbucket_username,
+                bitbucket_app_password,
+                sourcehut_token,
+                azure,
+            );
+
+            let entries: Vec<_> = entries
+                .into_iter()
+                .take(limit.unwrap_or(usize::MAX))
+                .collect();
+            println!("\n🔄 Refreshing {} bookmarks...\n", entries.len());
+
+            let now = chrono::Utc::now();
+            let mut refreshed = 0;
+            let mut failed = 0;
+
+            // Bookmarks that can be looked up, with the engine for their platform
+            let mut lookups = Vec::new();
+            for entry in &entries {
+                let old: Repository = match serde_json::from_str(&entry.data) {
+                    Ok(repo) => repo,
+                    Err(e) => {
+                        println!("⚠️  {}: unreadable bookmark data ({})", entry.full_name, e);
+                        failed += 1;
+                        continue;
+                    }
+                };
+                if !old.full_name.contains('/') {
+                    println!("⚠️  {}: not in owner/repo format", old.full_name);
+                    failed += 1;
+                    continue;
+                }
+                let Some((_, engine)) = engines.iter().find(|(p, _)| *p == old.platform) else {
+                    continue;
+                };
+                lookups.push((entry, old, engine));
+            }
+
+            let fetched = reposcout_core::fetch_concurrently(
+                &lookups,
+                fetch_concurrency(config),
+                |(_, old, engine)| async move {
+                    let (owner, name) = old.full_name.rsplit_once('/').unwrap_or_default();
+                    engine.get_repository(owner, name).await
+                },
+            )
+            .await;
+
+            for ((entry, old, _), result) in lookups.iter().zip(fetched) {
+                let fresh = match result {
+                    Ok(fresh) => fresh,
+                    Err(e) => {
+                        println!("⚠️  {}: failed to refresh ({})", old.full_name, e);
+                        failed += 1;
+                        continue;
+                    }
+                };
+
+                let diff = reposcout_core::BookmarkDiff::between(old, &fresh);
+                println!(
+                    "{}  (bookmarked {})",
+                    diff.summary(now),
+                    format_timestamp(entry.bookmarked_at)
+                );
+                if !dry_run && diff.has_changes() {
+                    cache.update_bookmark_data(&entry.platform, &entry.full_name, &fresh)?;
+                }
+                refreshed += 1;
+            }
+
+            println!();
+            if dry_run {
+                println!("🔍 Dry run: checked {} bookmarks, nothing saved", refreshed);
+            } else {
+                println!("✅ Refreshed {} bookmarks", refreshed);
+            }
+            if failed > 0 {
+                println!("⚠️  {} could not be refreshed", failed);
+            }
+        }
+        BookmarkAction::Stats { json } => {
+            let entries = cache.get_bookmarks_with_metadata()?;
+            let stats = reposcout_core::BookmarkStats::from_entries(&entries);
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&stats)?);
+                return Ok(());
+            }
+
+            if stats.total == 0 {
+                println!("No bookmarks found. Use 'reposcout bookmark add <repo>' to add one.");
+                return Ok(());
+            }
+
+            println!("\n📊 Bookmark Stats\n");
+            println!("Total:          {}", stats.total);
+            if stats.skipped > 0 {
+                println!("Unreadable:     {} (skipped)", stats.skipped);
+            }
+            if let Some(health) = stats.average_health {
+                println!("Average health: {:.0}/100", health);
+            }
+
+            let print_counts = |title: &str, counts: &[(String, usize)]| {
+                if counts.is_empty() {
+                    return;
+                }
+                println!("\n{}:", title);
+                for (name, count) in counts {
+                    println!("  {:<14} {:>5}", name, count);
+                }
+            };
+            print_counts("Platforms", &stats.platforms);
+            print_counts("Top languages", &stats.top_languages);
+            print_counts("Stars", &stats.star_buckets);
+            print_counts("Top tags", &stats.top_tags);
+            println!();
+        }
     }
 
     Ok(())
 }
 
-async fn handle_bookmark_command(
-    action: BookmarkAction,
+/// One uncached engine per platform, so a stored repository is only looked up
+/// where it lives and the data is fresh rather than from the cache
+fn platform_engines(
+    config: &reposcout_core::Config,
     github_token: Option<String>,
     gitlab_token: Option<String>,
     bitbucket_username: Option<String>,
     bitbucket_app_password: Option<String>,
-) -> anyhow::Result<()> {
-    use reposcout_core::models::Repository;
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
+) -> Vec<(reposcout_core::models::Platform, CachedSearchEngine)> {
+    let providers: Vec<Box<dyn reposcout_core::search::SearchProvider>> = vec![
+        Box::new(github_provider(config, github_token)),
+        Box::new(GitLabProvider::new(gitlab_token)),
+        Box::new(bitbucket_provider(
+            config,
+            bitbucket_username,
+            bitbucket_app_password,
+        )),
+        Box::new(SourceHutProvider::new(sourcehut_token)),
+        Box::new(azure.provider()),
+    ];
+    providers
+        .into_iter()
+        .map(|provider| {
+            let platform = provider.platform();
+            let mut engine = CachedSearchEngine::new();
+            engine.add_provider(provider);
+            (platform, engine)
+        })
+        .collect()
+}
 
-    let cache_path = get_cache_path()?;
-    let cache = CacheManager::new(cache_path.to_str().unwrap(), 24)?;
+fn handle_portfolio_command(action: PortfolioAction) -> anyhow::Result<()> {
+    use reposcout_core::{Exporter, PortfolioManager};
 
+    let mut manager = PortfolioManager::load()?;
     match action {
-        BookmarkAction::List => {
-            let bookmarks: Vec<Repository> = cache.get_bookmarks()?;
-
-            if bookmarks.is_empty() {
-                println!("No bookmarks found. Use 'reposcout bookmark add <repo>' to add one.");
+        PortfolioAction::List => {
+            let mut portfolios = manager.list_portfolios();
+            if portfolios.is_empty() {
+                println!("No portfolios yet. Create one in the TUI (N in Portfolio mode).");
                 return Ok(());
             }
+            portfolios.sort_by_key(|p| p.created_at);
 
-            println!("\n📚 Your Bookmarks ({}):\n", bookmarks.len());
-            for (i, repo) in bookmarks.iter().enumerate() {
-                println!("{}. {} ({})", i + 1, repo.full_name, repo.platform);
-                if let Some(desc) = &repo.description {
-                    println!("   {}", desc);
-                }
+            println!("\n📁 Portfolios ({}):\n", portfolios.len());
+            for portfolio in portfolios {
                 println!(
-                    "   ⭐ {} | 🍴 {} | {}",
-                    repo.stars,
-                    repo.forks,
-                    repo.language.as_deref().unwrap_or("Unknown")
+                    "  {} {} - {} repos\n     id: {}",
+                    portfolio.icon.as_emoji(),
+                    portfolio.name,
+                    portfolio.repo_count(),
+                    portfolio.id
                 );
-                println!("   {}\n", repo.url);
             }
+            println!();
         }
-        BookmarkAction::Add { name, tags, notes } => {
-            // Parse owner/repo format
-            let parts: Vec<&str> = name.split('/').collect();
-            if parts.len() != 2 {
-                anyhow::bail!("Repository name must be in 'owner/repo' format");
-            }
+        PortfolioAction::Export { id, output } => {
+            let portfolio = manager
+                .find_portfolio(&id)
+                .ok_or_else(|| anyhow::anyhow!("No portfolio with id or name '{}'", id))?;
+            Exporter::export_portfolio(portfolio, &output)
+                .map_err(|e| anyhow::anyhow!("Export failed: {}", e))?;
+            println!(
+                "✓ Exported portfolio '{}' ({} repos) to {}",
+                portfolio.name,
+                portfolio.repo_count(),
+                output
+            );
+        }
+        PortfolioAction::Import { file } => {
+            let json = std::fs::read_to_string(&file)?;
+            let portfolio = manager.import_json(&json)?;
+            manager.save()?;
+            println!(
+                "✓ Imported portfolio '{}' ({} repos) as {}",
+                portfolio.name,
+                portfolio.repo_count(),
+                portfolio.id
+            );
+        }
+    }
+    Ok(())
+}
 
-            let (owner, repo_name) = (parts[0], parts[1]);
+#[allow(clippy::too_many_arguments)]
+async fn handle_watch_command(
+    config: &reposcout_core::Config,
+    action: WatchAction,
+    github_token: Option<String>,
+    gitlab_token: Option<String>,
+    bitbucket_username: Option<String>,
+    bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
+) -> anyhow::Result<()> {
+    let cache_path = get_cache_path()?;
+    let cache = open_cache(config, &cache_path)?;
 
-            // Fetch repository details
-            let cache_manager = CacheManager::new(cache_path.to_str().unwrap(), 24)?;
-            let mut engine = CachedSearchEngine::with_cache(cache_manager);
-            engine.add_provider(Box::new(GitHubProvider::new(github_token)));
+    match action {
+        WatchAction::Add { name } => {
+            let Some((owner, repo_name)) = name.split_once('/') else {
+                anyhow::bail!("Repository name must be in 'owner/repo' format");
+            };
+
+            let mut engine = cached_engine(config, open_cache(config, &cache_path)?);
+            engine.add_provider(Box::new(github_provider(config, github_token)));
             engine.add_provider(Box::new(GitLabProvider::new(gitlab_token)));
-            engine.add_provider(Box::new(BitbucketProvider::new(
+            engine.add_provider(Box::new(bitbucket_provider(
+                config,
                 bitbucket_username,
                 bitbucket_app_password,
             )));
+            engine.add_provider(Box::new(SourceHutProvider::new(sourcehut_token)));
+            engine.add_provider(Box::new(azure.provider()));
 
             let repository = engine.get_repository(owner, repo_name).await?;
-
-            // Add to bookmarks
-            cache.add_bookmark(
+            cache.add_watched(
                 &repository.platform.to_string().to_lowercase(),
                 &repository.full_name,
-                &repository,
-                tags.as_deref(),
-                notes.as_deref(),
+                repository.pushed_at.timestamp(),
+                repository.stars,
             )?;
 
-            println!("✅ Bookmarked: {}", repository.full_name);
+            println!(
+                "👀 Watching {} ({} ⭐)",
+                repository.full_name, repository.stars
+            );
         }
-        BookmarkAction::Remove { name } => {
-            // Try to remove from all platforms
-            let removed_github = cache.remove_bookmark("github", &name).is_ok();
-            let removed_gitlab = cache.remove_bookmark("gitlab", &name).is_ok();
-            let removed_bitbucket = cache.remove_bookmark("bitbucket", &name).is_ok();
+        WatchAction::Remove { name } => {
+            let mut removed = false;
+            // Watches are stored under the lower-cased platform name
+            for platform in reposcout_core::models::Platform::ALL {
+                removed |= cache.remove_watched(&platform.to_string().to_lowercase(), &name)?;
+            }
 
-            if removed_github || removed_gitlab || removed_bitbucket {
-                println!("✅ Removed bookmark: {}", name);
+            if removed {
+                println!("✅ Stopped watching {}", name);
             } else {
-                println!("❌ Bookmark not found: {}", name);
+                println!("❌ Not watching {}", name);
             }
         }
-        BookmarkAction::Export { output, format } => {
-            let bookmarks = cache.get_bookmarks_with_metadata()?;
+        WatchAction::List => {
+            let watched = cache.get_watched()?;
+            if watched.is_empty() {
+                println!("Not watching anything. Use 'reposcout watch add <repo>' to start.");
+                return Ok(());
+            }
 
-            match format.as_str() {
-                "json" => {
-                    let json = serde_json::to_string_pretty(&bookmarks)?;
-                    std::fs::write(&output, json)?;
-                    println!("✅ Exported {} bookmarks to {}", bookmarks.len(), output);
+            println!("\n👀 Watched Repositories ({}):\n", watched.len());
+            for entry in &watched {
+                println!(
+                    "  {} ({}) - {} ⭐, last push {}",
+                    entry.full_name,
+                    entry.platform,
+                    entry.last_seen_stars,
+                    format_timestamp(entry.last_seen_pushed)
+                );
+            }
+            println!();
+        }
+        WatchAction::Check { notify } => {
+            let watched = cache.get_watched()?;
+            if watched.is_empty() {
+                if !notify {
+                    println!("Not watching anything. Use 'reposcout watch add <repo>' to start.");
                 }
-                "csv" => {
-                    export_bookmarks_csv(&bookmarks, &output)?;
-                    println!("✅ Exported {} bookmarks to {}", bookmarks.len(), output);
+                return Ok(());
+            }
+
+            let engines = platform_engines(
+                config,
+                github_token,
+                gitlab_token,
+                bitbucket_username,
+                bitbucket_app_password,
+                sourcehut_token,
+                azure,
+            );
+
+            let mut updates = Vec::new();
+            let mut failed = 0;
+            for entry in &watched {
+                let Some((owner, name)) = entry.full_name.rsplit_once('/') else {
+                    failed += 1;
+                    continue;
+                };
+                let Some((_, engine)) = engines
+                    .iter()
+                    .find(|(p, _)| p.to_string().to_lowercase() == entry.platform)
+                else {
+                    continue;
+                };
+
+                let fresh = match engine.get_repository(owner, name).await {
+                    Ok(fresh) => fresh,
+                    Err(e) => {
+                        if !notify {
+                            println!("⚠️  {}: failed to check ({})", entry.full_name, e);
+                        }
+                        failed += 1;
+                        continue;
+                    }
+                };
+
+                let last_seen_pushed =
+                    chrono::DateTime::from_timestamp(entry.last_seen_pushed, 0).unwrap_or_default();
+                if let Some(update) = reposcout_core::WatchUpdate::check(
+                    last_seen_pushed,
+                    entry.last_seen_stars,
+                    &fresh,
+                ) {
+                    updates.push(update);
                 }
-                _ => {
-                    anyhow::bail!("Unsupported format: {}. Use 'json' or 'csv'", format);
+                if !notify {
+                    cache.update_watched(
+                        &entry.platform,
+                        &entry.full_name,
+                        fresh.pushed_at.timestamp(),
+                        fresh.stars,
+                    )?;
                 }
             }
-        }
-        BookmarkAction::Import { input } => {
-            let content = std::fs::read_to_string(&input)?;
-            let bookmarks: Vec<BookmarkEntry> = serde_json::from_str(&content)?;
 
-            for entry in &bookmarks {
-                let repo: Repository = serde_json::from_str(&entry.data)?;
-                cache.add_bookmark(
-                    &entry.platform,
-                    &entry.full_name,
-                    &repo,
-                    entry.tags.as_deref(),
-                    entry.notes.as_deref(),
-                )?;
+            if notify {
+                match updates.len() {
+                    0 => {}
+                    1 => println!("👀 1 watched repo updated"),
+                    n => println!("👀 {} watched repos updated", n),
+                }
+                return Ok(());
             }
 
-            println!("✅ Imported {} bookmarks from {}", bookmarks.len(), input);
-        }
-        BookmarkAction::Clear => {
-            cache.clear_bookmarks()?;
-            println!("✅ All bookmarks cleared");
+            println!();
+            if updates.is_empty() {
+                println!("✅ No news from {} watched repositories", watched.len());
+            } else {
+                for update in &updates {
+                    println!("{}", update.summary());
+                }
+                println!(
+                    "\n👀 {} of {} watched repositories updated",
+                    updates.len(),
+                    watched.len()
+                );
+            }
+            if failed > 0 {
+                println!("⚠️  {} could not be checked", failed);
+            }
         }
     }
 
     Ok(())
 }
 
-async fn handle_history_command(action: HistoryAction) -> anyhow::Result<()> {
+async fn handle_history_command(
+    config: &reposcout_core::Config,
+    action: HistoryAction,
+) -> anyhow::Result<()> {
+    use reposcout_core::Exporter;
+
     let cache_path = get_cache_path()?;
-    let cache = CacheManager::new(cache_path.to_str().unwrap(), 24)?;
+    let cache = open_cache(config, &cache_path)?;
 
     match action {
         HistoryAction::List { limit } => {
             let history = cache.get_search_history(limit)?;
 
             if history.is_empty() {
-                println!("No search history found. Start searching to build your history!");
+                output::notice("No search history found. Start searching to build your history!");
                 return Ok(());
             }
 
-            println!("\n📜 Recent Search History ({}):\n", history.len());
+            output::heading(format!("\n📜 Recent Search History ({}):\n", history.len()));
 
             for (i, entry) in history.iter().enumerate() {
+                if output::is_plain() {
+                    print_history_record(entry);
+                    continue;
+                }
                 // Format timestamp as relative time
                 let timestamp = format_timestamp(entry.searched_at);
 
@@ -841,17 +2710,21 @@ async fn handle_history_command(action: HistoryAction) -> anyhow::Result<()> {
             let history = cache.search_history(&term, limit)?;
 
             if history.is_empty() {
-                println!("No search history matching '{}'", term);
+                output::notice(format!("No search history matching '{}'", term));
                 return Ok(());
             }
 
-            println!(
+            output::heading(format!(
                 "\n🔍 Search History matching '{}' ({}):\n",
                 term,
                 history.len()
-            );
+            ));
 
             for (i, entry) in history.iter().enumerate() {
+                if output::is_plain() {
+                    print_history_record(entry);
+                    continue;
+                }
                 let timestamp = format_timestamp(entry.searched_at);
 
                 println!("{}. \"{}\"", i + 1, entry.query);
@@ -873,13 +2746,93 @@ async fn handle_history_command(action: HistoryAction) -> anyhow::Result<()> {
         HistoryAction::Clear => {
             let count = cache.search_history_count()?;
             cache.clear_search_history()?;
-            println!("✅ Cleared {} search history entries", count);
+            output::status("✅", format!("Cleared {} search history entries", count));
+        }
+        HistoryAction::Export { output } => {
+            let history = cache.get_search_history(cache.search_history_count()?)?;
+            Exporter::export_search_history(&history, &output)
+                .map_err(|e| anyhow::anyhow!("Export failed: {}", e))?;
+            println!(
+                "✅ Exported {} search history entries to {}",
+                history.len(),
+                output
+            );
+        }
+        HistoryAction::Import { input } => {
+            let entries = Exporter::read_search_history(&input)
+                .map_err(|e| anyhow::anyhow!("Import failed: {}", e))?;
+            let imported = cache.import_search_history(&entries)?;
+            println!(
+                "✅ Imported {} search history entries from {} ({} already present)",
+                imported,
+                input,
+                entries.len() - imported
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_reviewed_command(
+    config: &reposcout_core::Config,
+    action: ReviewedAction,
+) -> anyhow::Result<()> {
+    let cache = open_cache(config, &get_cache_path()?)?;
+
+    match action {
+        ReviewedAction::List => {
+            let reviewed = cache.get_reviewed()?;
+
+            if reviewed.is_empty() {
+                output::notice("No reviewed repositories. Press 'x' in the TUI to mark one.");
+                return Ok(());
+            }
+
+            output::heading(format!("\n✓ Reviewed Repositories ({}):\n", reviewed.len()));
+            for entry in &reviewed {
+                if output::is_plain() {
+                    let reviewed_at = chrono::DateTime::from_timestamp(entry.reviewed_at, 0)
+                        .map(|t| t.to_rfc3339())
+                        .unwrap_or_default();
+                    output::record(&[&entry.full_name, &entry.platform, &reviewed_at]);
+                    continue;
+                }
+                println!(
+                    "{} ({}) - {}",
+                    entry.full_name,
+                    entry.platform,
+                    format_timestamp(entry.reviewed_at)
+                );
+            }
+        }
+        ReviewedAction::Clear => {
+            let count = cache.clear_reviewed()?;
+            output::status("✅", format!("Cleared {} reviewed repositories", count));
         }
     }
 
     Ok(())
 }
 
+/// History entry under `--quiet`: query, time searched (RFC 3339), result
+/// count and filters
+fn print_history_record(entry: &reposcout_cache::SearchHistoryEntry) {
+    let searched_at = chrono::DateTime::from_timestamp(entry.searched_at, 0)
+        .map(|t| t.to_rfc3339())
+        .unwrap_or_default();
+    let count = entry
+        .result_count
+        .map(|c| c.to_string())
+        .unwrap_or_default();
+    output::record(&[
+        entry.query.as_str(),
+        &searched_at,
+        &count,
+        entry.filters.as_deref().unwrap_or(""),
+    ]);
+}
+
 /// Format Unix timestamp as relative time (e.g., "2 hours ago")
 fn format_timestamp(timestamp: i64) -> String {
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -947,44 +2900,115 @@ fn export_bookmarks_csv(bookmarks: &[BookmarkEntry], output: &str) -> anyhow::Re
     Ok(())
 }
 
-/// Build GitHub search query with filters
-///
-/// GitHub uses special syntax like "language:rust stars:>1000"
-/// We build this query string based on user filters
-fn build_github_query(
-    query: &str,
-    language: Option<String>,
-    min_stars: Option<u32>,
-    max_stars: Option<u32>,
-    pushed: Option<String>,
-) -> String {
-    let mut parts = vec![query.to_string()];
+/// A column of `bookmark export --columns`: a repository field or bookmark metadata
+#[derive(Clone, Copy)]
+enum BookmarkColumn {
+    Repo(reposcout_core::CsvColumn),
+    BookmarkedAt,
+    Tags,
+    Notes,
+}
 
-    if let Some(lang) = language {
-        parts.push(format!("language:{}", lang));
+impl BookmarkColumn {
+    fn parse_list(spec: &str) -> anyhow::Result<Vec<BookmarkColumn>> {
+        let columns = spec
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(|name| match name.to_lowercase().as_str() {
+                "bookmarked_at" => Ok(BookmarkColumn::BookmarkedAt),
+                "tags" => Ok(BookmarkColumn::Tags),
+                "notes" => Ok(BookmarkColumn::Notes),
+                _ => name.parse().map(BookmarkColumn::Repo).map_err(|_| {
+                    anyhow::anyhow!(
+                        "Unknown column '{}'. Valid columns: {}, bookmarked_at, tags, notes",
+                        name,
+                        reposcout_core::CsvColumn::valid_names()
+                    )
+                }),
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        if columns.is_empty() {
+            anyhow::bail!("No columns given");
+        }
+        Ok(columns)
     }
 
-    // GitHub stars filter syntax
-    match (min_stars, max_stars) {
-        (Some(min), Some(max)) => parts.push(format!("stars:{}..{}", min, max)),
-        (Some(min), None) => parts.push(format!("stars:>={}", min)),
-        (None, Some(max)) => parts.push(format!("stars:<={}", max)),
-        (None, None) => {}
+    fn header(&self) -> &'static str {
+        match self {
+            BookmarkColumn::Repo(column) => column.header(),
+            BookmarkColumn::BookmarkedAt => "Bookmarked At",
+            BookmarkColumn::Tags => "Tags",
+            BookmarkColumn::Notes => "Notes",
+        }
     }
 
-    if let Some(pushed_date) = pushed {
-        parts.push(format!("pushed:{}", pushed_date));
+    fn value(&self, entry: &BookmarkEntry, repo: &reposcout_core::models::Repository) -> String {
+        match self {
+            BookmarkColumn::Repo(column) => column.value(repo),
+            BookmarkColumn::BookmarkedAt => entry.bookmarked_at.to_string(),
+            BookmarkColumn::Tags => entry.tags.clone().unwrap_or_default(),
+            BookmarkColumn::Notes => entry.notes.clone().unwrap_or_default(),
+        }
+    }
+}
+
+/// Bookmarks as CSV with the chosen columns, in that order
+fn export_bookmarks_csv_columns(
+    bookmarks: &[BookmarkEntry],
+    output: &str,
+    columns: &[BookmarkColumn],
+) -> anyhow::Result<()> {
+    use reposcout_core::Exporter;
+
+    let mut csv = Exporter::csv_row(columns.iter().map(BookmarkColumn::header));
+    for entry in bookmarks {
+        let repo: reposcout_core::models::Repository = serde_json::from_str(&entry.data)?;
+        csv.push_str(&Exporter::csv_row(
+            columns.iter().map(|column| column.value(entry, &repo)),
+        ));
     }
+    std::fs::write(output, csv)?;
+    Ok(())
+}
+
+/// Parse a `--created-after`/`--created-before` date
+fn parse_date(value: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        format!(
+            "'{}' isn't a date, expected YYYY-MM-DD (e.g. 2024-01-31)",
+            value
+        )
+    })
+}
 
-    parts.join(" ")
+/// Parse a notification `--type`, in any case and with `-`/`_` between
+/// words (e.g. pull-request), into the subject type GitHub reports
+fn parse_subject_type(value: &str) -> Result<String, String> {
+    let wanted = value.replace(['-', '_'], "");
+    reposcout_api::NOTIFICATION_SUBJECT_TYPES
+        .iter()
+        .find(|subject_type| subject_type.eq_ignore_ascii_case(&wanted))
+        .map(|subject_type| subject_type.to_string())
+        .ok_or_else(|| {
+            format!(
+                "'{}' isn't a notification type, expected one of: {}",
+                value,
+                reposcout_api::NOTIFICATION_SUBJECT_TYPES.join(", ")
+            )
+        })
 }
 
 /// Build a human-readable filters string for search history
+#[allow(clippy::too_many_arguments)]
 fn build_filters_string(
     language: Option<&str>,
     min_stars: Option<u32>,
     max_stars: Option<u32>,
     pushed: Option<&str>,
+    created: (Option<NaiveDate>, Option<NaiveDate>),
+    exclude_forks: bool,
+    exclude_archived: bool,
     sort: &str,
 ) -> Option<String> {
     let mut filters = Vec::new();
@@ -1004,6 +3028,20 @@ fn build_filters_string(
         filters.push(format!("pushed:{}", pushed_date));
     }
 
+    match created {
+        (Some(after), Some(before)) => filters.push(format!("created:{}..{}", after, before)),
+        (Some(after), None) => filters.push(format!("created:≥{}", after)),
+        (None, Some(before)) => filters.push(format!("created:≤{}", before)),
+        (None, None) => {}
+    }
+
+    if exclude_forks {
+        filters.push("no-forks".to_string());
+    }
+    if exclude_archived {
+        filters.push("no-archived".to_string());
+    }
+
     if sort != "stars" {
         filters.push(format!("sort:{}", sort));
     }
@@ -1018,20 +3056,24 @@ fn build_filters_string(
 /// Sort repository results based on user preference
 fn sort_results(results: &mut [reposcout_core::models::Repository], sort_by: &str) {
     match sort_by {
-        "stars" => results.sort_by(|a, b| b.stars.cmp(&a.stars)),
-        "forks" => results.sort_by(|a, b| b.forks.cmp(&a.forks)),
-        "updated" => results.sort_by(|a, b| b.updated_at.cmp(&a.updated_at)),
+        "stars" => results.sort_by_key(|r| std::cmp::Reverse(r.stars)),
+        "forks" => results.sort_by_key(|r| std::cmp::Reverse(r.forks)),
+        "updated" => results.sort_by_key(|r| std::cmp::Reverse(r.updated_at)),
+        "trending-score" => reposcout_core::sort_by_trending_score(results),
         _ => {} // Already sorted by relevance from API
     }
 }
 
 async fn run_tui_mode(
+    config: reposcout_core::Config,
     mut github_token: Option<String>,
     mut gitlab_token: Option<String>,
     bitbucket_username: Option<String>,
     bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
 ) -> anyhow::Result<()> {
-    use reposcout_api::{BitbucketClient, GitHubClient, GitLabClient};
+    use reposcout_api::{GitLabClient, SourceHutClient};
     use reposcout_core::TokenStore;
     use reposcout_tui::{run_tui, App};
 
@@ -1052,50 +3094,66 @@ async fn run_tui_mode(
     }
 
     let mut app = App::new();
+    apply_config_defaults(&mut app, &config);
     let cache_path = get_cache_path()?;
-    let cache_path_str = cache_path.to_str().unwrap().to_string();
 
     // Create API clients for README fetching
-    let github_client = GitHubClient::new(github_token.clone());
-    let gitlab_client = GitLabClient::new(gitlab_token.clone());
-    let bitbucket_client =
-        BitbucketClient::new(bitbucket_username.clone(), bitbucket_app_password.clone());
+    let github_client = github_client(&config, github_token);
+    let gitlab_client = GitLabClient::new(gitlab_token);
+    let bitbucket_client = bitbucket_client(
+        &config,
+        bitbucket_username.clone(),
+        bitbucket_app_password.clone(),
+    );
+    let sourcehut_client = SourceHutClient::new(sourcehut_token.clone());
+    let azure_devops_client = azure.client();
 
     // Set platform status based on provided credentials
     // GitHub and GitLab are always available (public repos don't need auth)
-    let bitbucket_configured = bitbucket_username.is_some() && bitbucket_app_password.is_some();
-    app.set_platform_status(true, true, bitbucket_configured);
+    app.set_platform_status(true, true, bitbucket_client.has_credentials());
 
     // Create cache manager for bookmarks
-    let cache = CacheManager::new(cache_path.to_str().unwrap(), 24)?;
+    let cache = open_cache(&config, &cache_path)?;
+
+    // One engine serves every search so it remembers where each platform's
+    // results left off when scrolling asks for more. It shares the clients
+    // above so the header can show their rate limits.
+    let mut engine = cached_engine(&config, open_cache(&config, &cache_path)?);
+    engine.set_filters(config.filters.clone());
+    engine.add_provider(Box::new(GitHubProvider::with_client(github_client.clone())));
+    engine.add_provider(Box::new(GitLabProvider::with_client(gitlab_client.clone())));
+    engine.add_provider(Box::new(bitbucket_provider(
+        &config,
+        bitbucket_username,
+        bitbucket_app_password,
+    )));
+    engine.add_provider(Box::new(SourceHutProvider::new(sourcehut_token)));
+    engine.add_provider(Box::new(AzureDevOpsProvider::with_client(
+        azure_devops_client.clone(),
+    )));
+    let engine = std::rc::Rc::new(engine);
+    let more_engine = engine.clone();
 
     run_tui(
         app,
-        move |query| {
-            let github_token_clone = github_token.clone();
-            let gitlab_token_clone = gitlab_token.clone();
-            let bitbucket_username_clone = bitbucket_username.clone();
-            let bitbucket_app_password_clone = bitbucket_app_password.clone();
-            let cache_path_clone = cache_path_str.clone();
-
+        move |query, on_batch| {
+            let engine = engine.clone();
             Box::pin(async move {
-                // Use query-specific cache for accurate, fast results
-                // This avoids FTS5 cross-contamination by caching complete result sets per exact query
-                let cache = CacheManager::new(&cache_path_clone, 24)?;
-                let mut engine = CachedSearchEngine::with_cache(cache);
-                // Search across all platforms
-                engine.add_provider(Box::new(GitHubProvider::new(github_token_clone)));
-                engine.add_provider(Box::new(GitLabProvider::new(gitlab_token_clone)));
-                engine.add_provider(Box::new(BitbucketProvider::new(
-                    bitbucket_username_clone,
-                    bitbucket_app_password_clone,
-                )));
-                engine.search(query).await.map_err(|e| e.into())
+                engine
+                    .search_with_progress(query, on_batch)
+                    .await
+                    .map_err(|e| e.into())
             })
         },
+        move || {
+            let engine = more_engine.clone();
+            Box::pin(async move { engine.load_more().await.map_err(|e| e.into()) })
+        },
         github_client,
         gitlab_client,
         bitbucket_client,
+        sourcehut_client,
+        azure_devops_client,
         cache,
     )
     .await
@@ -1103,37 +3161,49 @@ async fn run_tui_mode(
 
 #[allow(clippy::too_many_arguments)]
 async fn search_code(
+    config: &reposcout_core::Config,
     query: &str,
     limit: usize,
     language: Option<String>,
     repo: Option<String>,
     path: Option<String>,
     extension: Option<String>,
+    context: usize,
     github_token: Option<String>,
     gitlab_token: Option<String>,
     bitbucket_username: Option<String>,
     bitbucket_app_password: Option<String>,
+    bitbucket_workspace: Option<String>,
 ) -> anyhow::Result<()> {
-    use reposcout_api::{GitHubClient, GitLabClient};
+    use reposcout_api::GitLabClient;
     use reposcout_core::models::{CodeMatch, CodeSearchResult, Platform};
+    reposcout_core::ensure_online("Code search")?;
+
+    use std::collections::{hash_map::Entry, HashMap};
 
     // Build enhanced query with filters
     let mut search_query = query.to_string();
 
-    if let Some(lang) = language {
+    // Bitbucket uses its own qualifier names, and the repo is scoped separately
+    let mut bitbucket_query = query.to_string();
+
+    if let Some(lang) = &language {
         search_query.push_str(&format!(" language:{}", lang));
+        bitbucket_query.push_str(&format!(" lang:{}", lang));
     }
 
-    if let Some(repository) = repo {
+    if let Some(repository) = &repo {
         search_query.push_str(&format!(" repo:{}", repository));
     }
 
-    if let Some(path_filter) = path {
+    if let Some(path_filter) = &path {
         search_query.push_str(&format!(" path:{}", path_filter));
+        bitbucket_query.push_str(&format!(" path:{}", path_filter));
     }
 
-    if let Some(ext) = extension {
+    if let Some(ext) = &extension {
         search_query.push_str(&format!(" extension:{}", ext));
+        bitbucket_query.push_str(&format!(" ext:{}", ext));
     }
 
     tracing::info!("Searching code for: {}", search_query);
@@ -1142,7 +3212,7 @@ async fn search_code(
 
     // Search GitHub
     if let Some(ref token) = github_token {
-        let github_client = GitHubClient::new(Some(token.clone()));
+        let github_client = github_client(config, Some(token.clone()));
         match github_client.search_code(&search_query, limit as u32).await {
             Ok(items) => {
                 for item in items {
@@ -1181,26 +3251,43 @@ async fn search_code(
                         file_url: item.html_url.clone(),
                         repository_url: item.repository.html_url.clone(),
                         matches,
-                        repository_stars: 0, // Code search API doesn't return star count
+                        repository_stars: 0, // Filled in by the star lookup below
                     });
                 }
                 tracing::info!("Found {} results from GitHub", all_results.len());
-            }
-            Err(e) => {
-                let error_str = e.to_string();
-                if error_str.contains("Authentication required") {
-                    eprintln!("❌ GitHub code search requires authentication.");
+                if let Some(rate_limit) = github_client.rate_limit().filter(|r| r.is_low()) {
                     eprintln!(
-                        "   Set GITHUB_TOKEN environment variable or use --github-token flag."
+                        "⚠️  GitHub rate limit nearly exhausted: {}/{} requests left{}\n",
+                        rate_limit.remaining,
+                        rate_limit.limit,
+                        rate_limit
+                            .reset_at
+                            .map(|t| format!(", resets at {}", t.format("%H:%M UTC")))
+                            .unwrap_or_default()
                     );
-                    eprintln!("   Example: export GITHUB_TOKEN=your_token_here\n");
-                } else if error_str.contains("Rate limit") {
-                    eprintln!("❌ GitHub API rate limit exceeded.");
-                    eprintln!("   Please wait a few minutes and try again.\n");
-                } else {
-                    eprintln!("❌ GitHub code search failed: {}\n", error_str);
                 }
+            }
+            Err(e) => {
                 tracing::warn!("GitHub code search failed: {}", e);
+                match reposcout_core::Error::from(e) {
+                    reposcout_core::Error::Unauthorized { .. } => {
+                        eprintln!("❌ GitHub code search requires authentication.");
+                        eprintln!(
+                            "   Set GITHUB_TOKEN environment variable or use --github-token flag."
+                        );
+                        eprintln!("   Example: export GITHUB_TOKEN=your_token_here\n");
+                    }
+                    reposcout_core::Error::RateLimited { retry_after } => {
+                        eprintln!("❌ GitHub API rate limit exceeded.");
+                        match retry_after {
+                            Some(wait) => {
+                                eprintln!("   Try again in {} seconds.\n", wait.as_secs().max(1))
+                            }
+                            None => eprintln!("   Please wait a few minutes and try again.\n"),
+                        }
+                    }
+                    error => eprintln!("❌ GitHub code search failed: {}\n", error),
+                }
             }
         }
     } else {
@@ -1214,9 +3301,34 @@ async fn search_code(
         let gitlab_client = GitLabClient::new(Some(token.clone()));
         match gitlab_client.search_code(query, limit as u32).await {
             Ok(items) => {
-                // We need to fetch project details for each result
-                // For now, create basic results
+                // Code search only returns project IDs; resolve each project
+                // once for its path, URL and star count
+                let mut projects = HashMap::new();
                 for item in items {
+                    if let Entry::Vacant(entry) = projects.entry(item.project_id) {
+                        let project = match gitlab_client.get_project_by_id(item.project_id).await {
+                            Ok(project) => Some(project),
+                            Err(e) => {
+                                tracing::warn!(
+                                    "Failed to fetch GitLab project {}: {}",
+                                    item.project_id,
+                                    e
+                                );
+                                None
+                            }
+                        };
+                        entry.insert(project);
+                    }
+                    let Some(project) = &projects[&item.project_id] else {
+                        continue;
+                    };
+
+                    let git_ref = item
+                        .ref_
+                        .clone()
+                        .or_else(|| project.default_branch.clone())
+                        .unwrap_or_else(|| "HEAD".to_string());
+
                     let matches = vec![CodeMatch {
                         content: item.data.clone(),
                         line_number: item.startline,
@@ -1226,13 +3338,16 @@ async fn search_code(
 
                     all_results.push(CodeSearchResult {
                         platform: Platform::GitLab,
-                        repository: format!("project-{}", item.project_id),
+                        repository: project.path_with_namespace.clone(),
                         file_path: item.path.clone(),
                         language: None,
-                        file_url: format!("https://gitlab.com/projects/{}", item.project_id),
-                        repository_url: format!("https://gitlab.com/projects/{}", item.project_id),
+                        file_url: format!(
+                            "{}/-/blob/{}/{}#L{}",
+                            project.web_url, git_ref, item.path, item.startline
+                        ),
+                        repository_url: project.web_url.clone(),
                         matches,
-                        repository_stars: 0,
+                        repository_stars: project.star_count,
                     });
                 }
                 tracing::info!(
@@ -1241,20 +3356,26 @@ async fn search_code(
                 );
             }
             Err(e) => {
-                let error_str = e.to_string();
-                if error_str.contains("Authentication required") {
-                    eprintln!("❌ GitLab code search requires authentication.");
-                    eprintln!(
-                        "   Set GITLAB_TOKEN environment variable or use --gitlab-token flag."
-                    );
-                    eprintln!("   Example: export GITLAB_TOKEN=your_token_here\n");
-                } else if error_str.contains("Rate limit") {
-                    eprintln!("❌ GitLab API rate limit exceeded.");
-                    eprintln!("   Please wait a few minutes and try again.\n");
-                } else {
-                    eprintln!("❌ GitLab code search failed: {}\n", error_str);
-                }
                 tracing::warn!("GitLab code search failed: {}", e);
+                match reposcout_core::Error::from(e) {
+                    reposcout_core::Error::Unauthorized { .. } => {
+                        eprintln!("❌ GitLab code search requires authentication.");
+                        eprintln!(
+                            "   Set GITLAB_TOKEN environment variable or use --gitlab-token flag."
+                        );
+                        eprintln!("   Example: export GITLAB_TOKEN=your_token_here\n");
+                    }
+                    reposcout_core::Error::RateLimited { retry_after } => {
+                        eprintln!("❌ GitLab API rate limit exceeded.");
+                        match retry_after {
+                            Some(wait) => {
+                                eprintln!("   Try again in {} seconds.\n", wait.as_secs().max(1))
+                            }
+                            None => eprintln!("   Please wait a few minutes and try again.\n"),
+                        }
+                    }
+                    error => eprintln!("❌ GitLab code search failed: {}\n", error),
+                }
             }
         }
     } else {
@@ -1265,9 +3386,143 @@ async fn search_code(
 
     // Search Bitbucket
     if bitbucket_username.is_some() && bitbucket_app_password.is_some() {
-        // Note: Bitbucket code search is limited and requires workspace/repo context
-        // For now, we'll skip it in multi-platform search
-        tracing::info!("Bitbucket code search requires workspace/repo context - skipping in multi-platform search");
+        // Bitbucket searches per workspace; `--repo workspace/slug` narrows it to one repo
+        let scope = match (
+            repo.as_deref().and_then(|r| r.split_once('/')),
+            &bitbucket_workspace,
+        ) {
+            (Some((workspace, slug)), _) => Some((workspace.to_string(), Some(slug.to_string()))),
+            (None, Some(workspace)) => Some((workspace.clone(), None)),
+            (None, None) => None,
+        };
+
+        match scope {
+            Some((workspace, slug)) => {
+                let bitbucket_client = bitbucket_client(
+                    config,
+                    bitbucket_username.clone(),
+                    bitbucket_app_password.clone(),
+                );
+                let results = match &slug {
+                    Some(slug) => {
+                        bitbucket_client
+                            .search_code(&workspace, slug, &bitbucket_query)
+                            .await
+                    }
+                    None => {
+                        bitbucket_client
+                            .search_workspace_code(&workspace, &bitbucket_query)
+                            .await
+                    }
+                };
+
+                match results {
+                    Ok(items) => {
+                        let fallback_repo = match &slug {
+                            Some(slug) => format!("{}/{}", workspace, slug),
+                            None => workspace.clone(),
+                        };
+                        for item in items {
+                            let mut matches: Vec<CodeMatch> = item
+                                .content_matches
+                                .iter()
+                                .flat_map(|cm| &cm.lines)
+                                .map(|line| CodeMatch {
+                                    content: line
+                                        .segments
+                                        .iter()
+                                        .map(|s| s.text.as_str())
+                                        .collect(),
+                                    line_number: line.line as usize,
+                                    context_before: vec![],
+                                    context_after: vec![],
+                                })
+                                .collect();
+                            if matches.is_empty() {
+                                matches.push(CodeMatch {
+                                    content: format!("Match found in {}", item.file.path),
+                                    line_number: 1,
+                                    context_before: vec![],
+                                    context_after: vec![],
+                                });
+                            }
+
+                            let repository = item
+                                .repository_full_name()
+                                .unwrap_or(&fallback_repo)
+                                .to_string();
+                            let repository_url = item
+                                .repository_url()
+                                .unwrap_or_else(|| format!("https://bitbucket.org/{}", repository));
+                            let file_url = item.file_url().unwrap_or_else(|| {
+                                format!("{}/src/HEAD/{}", repository_url, item.file.path)
+                            });
+
+                            all_results.push(CodeSearchResult {
+                                platform: Platform::Bitbucket,
+                                repository,
+                                file_path: item.file.path.clone(),
+                                language: None,
+                                file_url,
+                                repository_url,
+                                matches,
+                                // Bitbucket has no stars
+                                repository_stars: 0,
+                            });
+                        }
+                        tracing::info!(
+                            "Found {} total results (including Bitbucket)",
+                            all_results.len()
+                        );
+                    }
+                    Err(e) => {
+                        tracing::warn!("Bitbucket code search failed: {}", e);
+                        match reposcout_core::Error::from(e) {
+                            reposcout_core::Error::NotFound(_) => {
+                                eprintln!("❌ Bitbucket workspace '{}' not found, or code search is not enabled for it.\n", workspace);
+                            }
+                            error => eprintln!("❌ Bitbucket code search failed: {}\n", error),
+                        }
+                    }
+                }
+            }
+            None => {
+                eprintln!("⚠️  Bitbucket workspace not provided. Set BITBUCKET_WORKSPACE or use --bitbucket-workspace");
+                eprintln!("   Bitbucket only supports code search within a workspace.");
+                eprintln!("   Example: export BITBUCKET_WORKSPACE=your_workspace\n");
+            }
+        }
+    }
+
+    // Code search doesn't return star counts for GitHub; look each repository
+    // up once (cache first) so the cross-platform sort by stars is meaningful
+    if all_results.iter().any(|r| r.platform == Platform::GitHub) {
+        let mut engine = match open_cache(config, &get_cache_path()?) {
+            Ok(cache) => cached_engine(config, cache),
+            Err(_) => CachedSearchEngine::new(),
+        };
+        engine.add_provider(Box::new(github_provider(config, github_token.clone())));
+
+        let mut stars: HashMap<String, u32> = HashMap::new();
+        for result in all_results
+            .iter_mut()
+            .filter(|r| r.platform == Platform::GitHub)
+        {
+            if let Entry::Vacant(entry) = stars.entry(result.repository.clone()) {
+                let count = match result.repository.split_once('/') {
+                    Some((owner, name)) => match engine.get_repository(owner, name).await {
+                        Ok(repo) => repo.stars,
+                        Err(e) => {
+                            tracing::debug!("Star lookup failed for {}: {}", result.repository, e);
+                            0
+                        }
+                    },
+                    None => 0,
+                };
+                entry.insert(count);
+            }
+            result.repository_stars = stars[&result.repository];
+        }
     }
 
     // Display results
@@ -1283,7 +3538,50 @@ async fn search_code(
     }
 
     // Sort by repository stars
-    all_results.sort_by(|a, b| b.repository_stars.cmp(&a.repository_stars));
+    all_results.sort_by_key(|r| std::cmp::Reverse(r.repository_stars));
+
+    // Search fragments carry no surrounding code or (on GitHub) line numbers;
+    // fetch each shown file and locate its matches in it
+    if context > 0 {
+        let terms = reposcout_core::query_terms(query);
+        let github = github_client(config, github_token.clone());
+        let gitlab = GitLabClient::new(gitlab_token.clone());
+        let bitbucket = bitbucket_client(
+            config,
+            bitbucket_username.clone(),
+            bitbucket_app_password.clone(),
+        );
+        let (github, gitlab, bitbucket) = (&github, &gitlab, &bitbucket);
+
+        let files = reposcout_core::fetch_concurrently(
+            all_results.iter().take(limit),
+            fetch_concurrency(config),
+            |result| async move {
+                let (owner, name) = result.repository.split_once('/')?;
+                let path = result.file_path.as_str();
+                let file = match result.platform {
+                    Platform::GitHub => github.get_file_content(owner, name, path).await.ok(),
+                    Platform::GitLab => {
+                        gitlab.get_file_content(&result.repository, path).await.ok()
+                    }
+                    Platform::Bitbucket => bitbucket.get_file_content(owner, name, path).await.ok(),
+                    _ => None,
+                };
+                if file.is_none() {
+                    tracing::debug!("Couldn't fetch {} from {}", path, result.repository);
+                }
+                file
+            },
+        )
+        .await;
+
+        for (result, file) in all_results.iter_mut().zip(files) {
+            let Some(file) = file else { continue };
+            for code_match in &mut result.matches {
+                reposcout_core::apply_file_context(code_match, &file, &terms, context);
+            }
+        }
+    }
 
     println!("\n🔍 Found {} code matches:\n", all_results.len());
 
@@ -1297,8 +3595,14 @@ async fn search_code(
             println!("   Language: {}", lang);
         }
 
-        // Show first match snippet
-        if let Some(first_match) = result.matches.first() {
+        // Show the first match in its surrounding code when the file was read
+        let in_context = result
+            .matches
+            .first()
+            .filter(|m| !m.context_before.is_empty() || !m.context_after.is_empty());
+        if let Some(code_match) = in_context {
+            print_code_match(code_match);
+        } else if let Some(first_match) = result.matches.first() {
             let snippet = if first_match.content.len() > 150 {
                 format!("{}...", &first_match.content[..150])
             } else {
@@ -1313,14 +3617,41 @@ async fn search_code(
     Ok(())
 }
 
+/// A code match with its context lines, numbered, the matched lines marked
+fn print_code_match(code_match: &reposcout_core::models::CodeMatch) {
+    let first_line = code_match.line_number - code_match.context_before.len();
+    let matched = code_match.content.lines().map(|line| (true, line));
+    let lines = code_match
+        .context_before
+        .iter()
+        .map(|line| (false, line.as_str()))
+        .chain(matched)
+        .chain(
+            code_match
+                .context_after
+                .iter()
+                .map(|line| (false, line.as_str())),
+        );
+
+    for (i, (is_match, line)) in lines.enumerate() {
+        let marker = if is_match { '>' } else { ' ' };
+        println!("   {} {:>5} │ {}", marker, first_line + i, line);
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn show_trending(
+    config: &reposcout_core::Config,
     period_str: &str,
     language: Option<String>,
     min_stars: u32,
     topic: Option<String>,
     limit: usize,
     velocity: bool,
+    platform: TrendingPlatform,
+    min_language_percent: Option<u8>,
+    table: bool,
+    export: Option<String>,
     github_token: Option<String>,
     gitlab_token: Option<String>,
     bitbucket_username: Option<String>,
@@ -1338,44 +3669,116 @@ async fn show_trending(
         }
     };
 
-    println!("\n🔥 Trending Repositories - {}\n", period.display_name());
+    output::heading(format!(
+        "\n🔥 Trending Repositories - {}\n",
+        period.display_name()
+    ));
 
     // Create providers
-    let github_provider = GitHubProvider::new(github_token);
+    let github_provider = github_provider(config, github_token);
     let gitlab_provider = GitLabProvider::new(gitlab_token);
-    let bitbucket_provider = BitbucketProvider::new(bitbucket_username, bitbucket_app_password);
+    let bitbucket_provider = bitbucket_provider(config, bitbucket_username, bitbucket_app_password);
 
     // Create trending finder
     let mut finder = TrendingFinder::new();
-    finder.add_provider(&github_provider);
-    finder.add_provider(&gitlab_provider);
-    finder.add_provider(&bitbucket_provider);
+    finder.set_filters(config.filters.clone());
+    if platform.includes(TrendingPlatform::Github) {
+        finder.add_provider(&github_provider);
+    }
+    if platform.includes(TrendingPlatform::Gitlab) {
+        finder.add_provider(&gitlab_provider);
+    }
+    if platform.includes(TrendingPlatform::Bitbucket) {
+        finder.add_provider(&bitbucket_provider);
+    }
 
     // Build filters
     let filters = TrendingFilters {
         language: language.clone(),
         min_stars: Some(min_stars),
         topic: topic.clone(),
+        min_language_percent: min_language_percent.map(f64::from),
     };
 
+    // Trending results are cached under their own key with a shorter TTL
+    let cache = open_trending_cache(config, &get_cache_path()?)?;
+    let cache_key = format!(
+        "trending:{:?}:{:?}:{}:{}:{}:{}:{}",
+        period,
+        platform,
+        language.as_deref().unwrap_or(""),
+        min_stars,
+        topic.as_deref().unwrap_or(""),
+        min_language_percent.unwrap_or(0),
+        velocity
+    );
+
     // Find trending repos
-    let results = if velocity {
-        finder.find_trending_by_velocity(period, &filters).await?
+    let cached = if config.cache.bypass {
+        Err(reposcout_cache::CacheError::NotFound(cache_key.clone()))
     } else {
-        finder.find_trending(period, &filters).await?
+        cache.get_query_cache::<reposcout_core::models::Repository>(&cache_key)
+    };
+    let results = match cached {
+        Ok(mut cached) => {
+            tracing::debug!("Trending cache hit for {}", cache_key);
+            // The blocklist may have changed since these were cached
+            config.filters.clone().retain_allowed(&mut cached);
+            cached
+        }
+        Err(_) => {
+            reposcout_core::ensure_online("Trending results that aren't cached")?;
+            let results = if velocity {
+                finder.find_trending_by_velocity(period, &filters).await?
+            } else {
+                finder.find_trending(period, &filters).await?
+            };
+            if !config.cache.bypass {
+                if let Err(e) = cache.set_query_cache(&cache_key, &results) {
+                    tracing::warn!("Failed to cache trending results: {}", e);
+                }
+            }
+            results
+        }
     };
 
     if results.is_empty() {
-        println!("No trending repositories found for the specified criteria.");
+        output::notice("No trending repositories found for the specified criteria.");
+        return Ok(());
+    }
+
+    if let Some(export_path) = export {
+        use reposcout_core::Exporter;
+
+        let mut title = format!("Trending Repositories - {}", period.display_name());
+        if let Some(ref lang) = language {
+            title.push_str(&format!(" ({})", lang));
+        }
+        let repos: Vec<_> = results.into_iter().take(limit).collect();
+        Exporter::export_trending_results(&repos, &export_path, &title)
+            .map_err(|e| anyhow::anyhow!("Export failed: {}", e))?;
+
+        output::status(
+            "✓",
+            format!("Exported {} repositories to {}", repos.len(), export_path),
+        );
+        return Ok(());
+    }
+
+    if table {
+        print_repo_table(&results, limit);
         return Ok(());
     }
 
-    println!("Found {} trending repositories:\n", results.len());
+    output::heading(format!("Found {} trending repositories:\n", results.len()));
 
     // Display filters if any
     let mut filter_parts = Vec::new();
     if let Some(ref lang) = language {
-        filter_parts.push(format!("Language: {}", lang));
+        match min_language_percent {
+            Some(pct) => filter_parts.push(format!("Language: {} (≥{}% of code)", lang, pct)),
+            None => filter_parts.push(format!("Language: {}", lang)),
+        }
     }
     if min_stars > 0 {
         filter_parts.push(format!("Min Stars: {}", min_stars));
@@ -1384,19 +3787,27 @@ async fn show_trending(
         filter_parts.push(format!("Topic: {}", t));
     }
     if !filter_parts.is_empty() {
-        println!("Filters: {}\n", filter_parts.join(" | "));
+        output::heading(format!("Filters: {}\n", filter_parts.join(" | ")));
     }
 
     if velocity {
-        println!("Sorted by: ⚡ Star Velocity (stars/day)\n");
+        output::heading("Sorted by: ⚡ Star Velocity (stars/day)\n");
     } else {
-        println!("Sorted by: ⭐ Total Stars\n");
+        output::heading("Sorted by: ⭐ Total Stars\n");
     }
 
     for (i, repo) in results.iter().take(limit).enumerate() {
         // Calculate velocity for display
         let age_days = (chrono::Utc::now() - repo.created_at).num_days().max(1);
-        let star_velocity = repo.stars as f64 / age_days as f64;
+        let star_velocity = repo.star_velocity();
+
+        if output::is_plain() {
+            output::repo_record(
+                repo,
+                &[format!("{:.1}", star_velocity), age_days.to_string()],
+            );
+            continue;
+        }
 
         println!("{}. {} ({})", i + 1, repo.full_name, repo.platform);
         if let Some(desc) = &repo.description {
@@ -1422,6 +3833,97 @@ async fn show_trending(
     Ok(())
 }
 
+/// GitHub client for the configured API URL (public GitHub unless overridden)
+/// Fold the command-line flags that override config.toml into `config`
+fn apply_cli_overrides(cli: &mut Cli, config: &mut reposcout_core::Config) {
+    // `--theme` wins for this run without touching the saved choice
+    if let Some(theme) = cli.theme.take() {
+        config.ui.theme = theme;
+    }
+    if let Some(ttl) = cli.cache_ttl {
+        config.cache.ttl_hours = ttl;
+        config.cache.trending_ttl_hours = ttl;
+    }
+    config.cache.bypass = cli.no_cache;
+    config.cache.offline_mode |= cli.offline;
+    if let Some(n) = cli.concurrency {
+        config.search.fetch_concurrency = Some(n.into());
+    }
+    if let Some(url) = cli.github_url.take() {
+        config
+            .platforms
+            .github
+            .get_or_insert_with(Default::default)
+            .api_url = url;
+    }
+    if cli.bitbucket_url.is_some()
+        || cli.bitbucket_token.is_some()
+        || cli.bitbucket_project.is_some()
+    {
+        let bitbucket = config
+            .platforms
+            .bitbucket
+            .get_or_insert_with(Default::default);
+        bitbucket.url = cli.bitbucket_url.take().or(bitbucket.url.take());
+        bitbucket.token = cli.bitbucket_token.take().or(bitbucket.token.take());
+        bitbucket.project = cli.bitbucket_project.take().or(bitbucket.project.take());
+    }
+}
+
+fn github_client(
+    config: &reposcout_core::Config,
+    token: Option<String>,
+) -> reposcout_api::GitHubClient {
+    match &config.platforms.github {
+        Some(github) => reposcout_api::GitHubClient::with_base_url(token, github.api_url.clone()),
+        None => reposcout_api::GitHubClient::new(token),
+    }
+}
+
+/// Bitbucket client for Cloud, or for Data Center when a URL is configured
+fn bitbucket_client(
+    config: &reposcout_core::Config,
+    username: Option<String>,
+    app_password: Option<String>,
+) -> reposcout_api::BitbucketClient {
+    let server = config.platforms.bitbucket.as_ref().and_then(|bitbucket| {
+        let url = bitbucket.url.as_deref()?;
+        // Pointing at Bitbucket Cloud itself just means Cloud
+        (!url.contains("bitbucket.org")).then_some((url, bitbucket))
+    });
+    match server {
+        Some((url, bitbucket)) => reposcout_api::BitbucketClient::server(
+            url,
+            username,
+            app_password,
+            bitbucket.token.clone(),
+            bitbucket.project.clone(),
+        ),
+        None => reposcout_api::BitbucketClient::new(username, app_password),
+    }
+}
+
+fn bitbucket_provider(
+    config: &reposcout_core::Config,
+    username: Option<String>,
+    app_password: Option<String>,
+) -> BitbucketProvider {
+    BitbucketProvider::with_client(bitbucket_client(config, username, app_password))
+}
+
+/// Repositories fetched at once, from `--concurrency` or the config file
+fn fetch_concurrency(config: &reposcout_core::Config) -> usize {
+    config
+        .search
+        .fetch_concurrency
+        .unwrap_or(reposcout_core::DEFAULT_CONCURRENCY)
+        .max(1)
+}
+
+fn github_provider(config: &reposcout_core::Config, token: Option<String>) -> GitHubProvider {
+    GitHubProvider::with_client(github_client(config, token))
+}
+
 fn get_cache_path() -> anyhow::Result<PathBuf> {
     let cache_dir = if cfg!(target_os = "windows") {
         dirs::cache_dir()
@@ -1437,14 +3939,326 @@ fn get_cache_path() -> anyhow::Result<PathBuf> {
     Ok(cache_dir.join("reposcout.db"))
 }
 
+/// Open the cache database with the configured TTL and entry cap.
+///
+/// TTL precedence: `--cache-ttl` flag, then `[cache] ttl_hours`, then 24h.
+fn open_cache(
+    config: &reposcout_core::Config,
+    cache_path: &std::path::Path,
+) -> anyhow::Result<CacheManager> {
+    let cache = CacheManager::new(cache_path.to_str().unwrap(), config.cache.ttl_hours)?
+        .with_max_entries(config.cache.max_entries)
+        .with_stale_reads(reposcout_core::is_offline());
+    Ok(cache)
+}
+
+/// Search engine backed by the cache, unless `--no-cache` says to skip it.
+/// In offline mode it answers from the cache alone
+fn cached_engine(config: &reposcout_core::Config, cache: CacheManager) -> CachedSearchEngine {
+    let mut engine = CachedSearchEngine::with_cache(cache);
+    engine.set_bypass_cache(config.cache.bypass);
+    engine.set_offline(reposcout_core::is_offline());
+    engine
+}
+
+/// Open the cache with the shorter trending TTL applied to query results.
+///
+/// `--cache-ttl` still wins over `[cache] trending_ttl_hours` when given.
+fn open_trending_cache(
+    config: &reposcout_core::Config,
+    cache_path: &std::path::Path,
+) -> anyhow::Result<CacheManager> {
+    let cache = CacheManager::with_ttls(
+        cache_path.to_str().unwrap(),
+        config.cache.ttl_hours,
+        config.cache.trending_ttl_hours,
+    )?
+    .with_max_entries(config.cache.max_entries)
+    .with_stale_reads(reposcout_core::is_offline());
+    Ok(cache)
+}
+
+/// Print repositories as an aligned table, fitted to the terminal width.
+///
+/// Name and description cells are truncated to one line rather than wrapped.
+/// When stdout isn't a terminal the table is fitted to 80 columns instead.
+fn print_repo_table(repos: &[reposcout_core::models::Repository], limit: usize) {
+    use comfy_table::{presets, Cell, CellAlignment, ContentArrangement, Row, Table};
+
+    const PIPED_WIDTH: u16 = 80;
+
+    if output::is_plain() {
+        for repo in repos.iter().take(limit) {
+            output::repo_record(repo, &[]);
+        }
+        return;
+    }
+
+    let mut table = Table::new();
+    table
+        .load_preset(presets::ASCII_HORIZONTAL_ONLY)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            "#",
+            "Name",
+            "Platform",
+            "Stars",
+            "Forks",
+            "Lang",
+            "Health",
+            "Description",
+        ]);
+    if table.width().is_none() {
+        table.set_width(PIPED_WIDTH);
+    }
+
+    for (i, repo) in repos.iter().take(limit).enumerate() {
+        let description = repo.description.as_deref().unwrap_or("");
+        let health = repo
+            .health
+            .as_ref()
+            .map(|h| h.score.to_string())
+            .unwrap_or_else(|| "-".to_string());
+
+        let mut row = Row::from(vec![
+            Cell::new(i + 1).set_alignment(CellAlignment::Right),
+            Cell::new(&repo.full_name),
+            Cell::new(repo.platform),
+            Cell::new(repo.stars).set_alignment(CellAlignment::Right),
+            Cell::new(repo.forks).set_alignment(CellAlignment::Right),
+            Cell::new(repo.language.as_deref().unwrap_or("-")),
+            Cell::new(health).set_alignment(CellAlignment::Right),
+            Cell::new(description),
+        ]);
+        row.max_height(1);
+        table.add_row(row);
+    }
+
+    println!("{table}");
+}
+
+/// Seed the TUI with the theme and search defaults from the config file
+fn apply_config_defaults(app: &mut reposcout_tui::App, config: &reposcout_core::Config) {
+    if !app.set_theme_by_name(&config.ui.theme) {
+        tracing::warn!("Unknown theme: {}", config.ui.theme);
+    }
+    if !app.set_code_theme_by_name(&config.ui.code_theme) {
+        tracing::warn!("Unknown code theme: {}", config.ui.code_theme);
+    }
+    if let Some(language) = &config.search.default_language {
+        app.filters.language = Some(language.clone());
+        app.trending_filters.language = Some(language.clone());
+    }
+    if let Some(sort) = &config.search.default_sort {
+        app.filters.sort_by = sort.clone();
+    }
+    app.live_search = config.ui.live_search;
+    app.min_terminal_size = (config.ui.min_width, config.ui.min_height);
+}
+
+/// Change the owner blocklist. Takes the config as saved in the file, so
+/// this run's command-line overrides aren't written back with it.
+fn handle_block_command(
+    action: BlockAction,
+    mut config: reposcout_core::Config,
+) -> anyhow::Result<()> {
+    match action {
+        BlockAction::Add { owner } => {
+            if config.filters.block_owner(&owner) {
+                config.save()?;
+                println!("🚫 Blocked {} - their repositories will be hidden", owner);
+            } else {
+                println!("{} is already blocked", owner);
+            }
+        }
+        BlockAction::List => {
+            if config.filters.blocked_owners.is_empty() {
+                println!("No blocked owners");
+            } else {
+                println!(
+                    "\n🚫 Blocked owners ({}):\n",
+                    config.filters.blocked_owners.len()
+                );
+                for owner in &config.filters.blocked_owners {
+                    println!("  {}", owner);
+                }
+            }
+        }
+        BlockAction::Remove { owner } => {
+            if config.filters.unblock_owner(&owner) {
+                config.save()?;
+                println!("✅ Unblocked {}", owner);
+            } else {
+                println!("{} is not blocked", owner);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_theme_command(action: ThemeAction) -> anyhow::Result<()> {
+    use reposcout_core::Theme;
+
+    match action {
+        ThemeAction::List => {
+            println!("\n🎨 Available themes:\n");
+            for theme in Theme::all_themes() {
+                println!("  {}", theme.name);
+            }
+        }
+        ThemeAction::Check { name } => {
+            let themes = match name {
+                Some(name) => vec![Theme::by_name(&name)
+                    .ok_or_else(|| anyhow::anyhow!("Unknown theme: {}", name))?],
+                None => Theme::all_themes(),
+            };
+
+            println!("\n🔍 Checking theme contrast (WCAG AA):\n");
+            let mut flagged = 0;
+            for theme in &themes {
+                let issues = theme.check_contrast();
+                if issues.is_empty() {
+                    println!("✅ {}", theme.name);
+                    continue;
+                }
+
+                flagged += 1;
+                println!("⚠️  {} ({} low-contrast pairs)", theme.name, issues.len());
+                for issue in issues {
+                    println!(
+                        "     {:<26} {:>5.2}:1  (needs {:.1}:1)",
+                        issue.element, issue.ratio, issue.required
+                    );
+                }
+            }
+
+            println!(
+                "\n{} of {} themes have low-contrast combinations",
+                flagged,
+                themes.len()
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Look up the account behind each platform's credentials, so a bad token
+/// shows up here instead of as a 401 halfway through a search
+async fn show_whoami(
+    config: &reposcout_core::Config,
+    github_token: Option<String>,
+    gitlab_token: Option<String>,
+    bitbucket_username: Option<String>,
+    bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure_token: Option<String>,
+) -> anyhow::Result<()> {
+    use reposcout_api::{AuthenticatedUser, GitLabClient};
+
+    reposcout_core::ensure_online("Checking credentials")?;
+
+    let github = match github_token {
+        Some(token) => Some(
+            github_client(config, Some(token))
+                .get_authenticated_user()
+                .await
+                .map_err(|e| e.to_string()),
+        ),
+        None => None,
+    };
+    let gitlab = match gitlab_token {
+        Some(token) => Some(
+            GitLabClient::new(Some(token))
+                .get_authenticated_user()
+                .await
+                .map_err(|e| e.to_string()),
+        ),
+        None => None,
+    };
+    // Data Center also takes an access token on its own
+    let bitbucket = bitbucket_client(config, bitbucket_username, bitbucket_app_password);
+    let bitbucket = if bitbucket.has_credentials() {
+        Some(
+            bitbucket
+                .get_authenticated_user()
+                .await
+                .map_err(|e| e.to_string()),
+        )
+    } else {
+        None
+    };
+
+    let mut failed = 0;
+    for (platform, result) in [
+        ("GitHub", github),
+        ("GitLab", gitlab),
+        ("Bitbucket", bitbucket),
+    ] {
+        match result {
+            None => println!("{}: unauthenticated (public access only)", platform),
+            Some(Err(e)) => {
+                failed += 1;
+                println!("{}: ❌ {}", platform, e);
+            }
+            Some(Ok(AuthenticatedUser {
+                username,
+                display_name,
+                scopes,
+                rate_limit,
+            })) => {
+                match display_name.filter(|name| !name.is_empty() && *name != username) {
+                    Some(name) => println!("{}: ✅ {} ({})", platform, username, name),
+                    None => println!("{}: ✅ {}", platform, username),
+                }
+                match scopes {
+                    Some(scopes) if scopes.is_empty() => println!("   Scopes: none"),
+                    Some(scopes) => println!("   Scopes: {}", scopes.join(", ")),
+                    None => println!("   Scopes: not reported"),
+                }
+                if let Some(rate_limit) = rate_limit {
+                    println!(
+                        "   Rate limit: {}/{} requests left{}",
+                        rate_limit.remaining,
+                        rate_limit.limit,
+                        rate_limit
+                            .reset_at
+                            .map(|t| format!(", resets at {}", t.format("%H:%M UTC")))
+                            .unwrap_or_default()
+                    );
+                }
+            }
+        }
+    }
+
+    // No user endpoint is wired up for these yet
+    for (platform, token) in [
+        ("SourceHut", sourcehut_token),
+        ("Azure DevOps", azure_token),
+    ] {
+        match token {
+            Some(_) => println!("{}: token set (not checked)", platform),
+            None => println!("{}: unauthenticated (public access only)", platform),
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!("{} credential check(s) failed", failed);
+    }
+    Ok(())
+}
+
 async fn handle_notifications(
+    config: &reposcout_core::Config,
     action: NotificationAction,
     github_token: Option<String>,
 ) -> anyhow::Result<()> {
+    reposcout_core::ensure_online("Notifications")?;
     let github_token = github_token
         .ok_or_else(|| anyhow::anyhow!("GitHub token required for notifications. Set GITHUB_TOKEN or use Ctrl+S in TUI to save token."))?;
 
-    let client = reposcout_api::GitHubClient::new(Some(github_token));
+    let client = github_client(config, Some(github_token));
 
     match action {
         NotificationAction::List {
@@ -1452,18 +4266,21 @@ async fn handle_notifications(
             participating,
             limit,
             repo,
+            subject_type,
+            reason,
         } => {
             let notifications = client.get_notifications(all, participating, limit).await?;
 
-            // Filter by repo if specified
-            let notifications: Vec<_> = if let Some(repo_filter) = repo {
-                notifications
-                    .into_iter()
-                    .filter(|n| n.repository.full_name == repo_filter)
-                    .collect()
-            } else {
-                notifications
+            let filters = reposcout_core::NotificationFilters {
+                repository: repo,
+                reason,
+                subject_type,
+                ..Default::default()
             };
+            let notifications: Vec<_> = notifications
+                .into_iter()
+                .filter(|n| filters.matches(n))
+                .collect();
 
             if notifications.is_empty() {
                 println!("No notifications found.");
@@ -1518,15 +4335,19 @@ async fn handle_notifications(
 
 #[allow(clippy::too_many_arguments)]
 async fn handle_semantic_search(
+    config: &reposcout_core::Config,
     query: &str,
     limit: usize,
     hybrid: bool,
     min_similarity: f32,
     export: Option<String>,
+    model: Option<reposcout_semantic::EmbeddingModel>,
     github_token: Option<String>,
     gitlab_token: Option<String>,
     bitbucket_username: Option<String>,
     bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
 ) -> anyhow::Result<()> {
     use reposcout_semantic::{SemanticConfig, SemanticSearchEngine};
 
@@ -1536,65 +4357,71 @@ async fn handle_semantic_search(
     let cache_path = get_cache_path()?;
     let semantic_cache_path = cache_path.join("semantic");
 
-    let config = SemanticConfig {
+    let semantic_config = SemanticConfig {
         enabled: true,
         cache_path: semantic_cache_path.to_string_lossy().to_string(),
         min_similarity,
         max_results: limit * 2, // Get more results for better ranking
         ..Default::default()
-    };
+    }
+    .with_model(model);
 
-    let engine = SemanticSearchEngine::new(config)?;
+    let engine = SemanticSearchEngine::new(semantic_config)?;
     engine.initialize().await?;
 
+    // The persisted index only holds vectors; look repositories up from the cache
+    let repo_cache = open_cache(config, &cache_path)?;
+    let cached_count = repo_cache.stats()?.total_entries;
+    engine
+        .register_repositories(
+            repo_cache.get_all::<reposcout_core::models::Repository>(cached_count)?,
+        )
+        .await;
+
     println!("Searching with semantic understanding...");
 
     let results = if hybrid {
         // Perform keyword search first
-        let cache = reposcout_cache::CacheManager::new(cache_path.to_str().unwrap(), 24)?;
-        let mut keyword_engine = reposcout_core::CachedSearchEngine::with_cache(cache);
-        keyword_engine.add_provider(Box::new(GitHubProvider::new(github_token)));
+        let cache = open_cache(config, &cache_path)?;
+        let mut keyword_engine = cached_engine(config, cache);
+        keyword_engine.set_filters(config.filters.clone());
+        keyword_engine.add_provider(Box::new(github_provider(config, github_token)));
         keyword_engine.add_provider(Box::new(GitLabProvider::new(gitlab_token)));
-        keyword_engine.add_provider(Box::new(BitbucketProvider::new(
+        keyword_engine.add_provider(Box::new(bitbucket_provider(
+            config,
             bitbucket_username,
             bitbucket_app_password,
         )));
+        keyword_engine.add_provider(Box::new(SourceHutProvider::new(sourcehut_token)));
+        keyword_engine.add_provider(Box::new(azure.provider()));
 
         let keyword_results = keyword_engine.search(query).await?;
 
         // Combine with semantic search
-        let keyword_pairs: Vec<(reposcout_core::models::Repository, f32)> = keyword_results
-            .into_iter()
-            .enumerate()
-            .map(|(i, repo)| {
-                // Assign decreasing scores based on position
-                let score = 1.0 - (i as f32 / 100.0).min(0.9);
-                (repo, score)
-            })
-            .collect();
+        let keyword_pairs = engine.score_keyword_results(query, keyword_results);
 
         engine.hybrid_search(query, keyword_pairs, limit).await?
     } else {
         engine.search(query, limit).await?
     };
 
-    if results.is_empty() {
-        println!("No repositories found for '{}'", query);
-        return Ok(());
-    }
-
-    // Handle export if requested
+    // Handle export if requested (empty results still produce a file)
     if let Some(export_path) = export {
         use reposcout_core::Exporter;
 
         let repos: Vec<_> = results.iter().map(|r| r.repository.clone()).collect();
-        Exporter::export_to_file(&repos, &export_path)
+        Exporter::export_search_results(&repos, &export_path, query, None, None)
             .map_err(|e| anyhow::anyhow!("Export failed: {}", e))?;
 
         println!("✓ Exported {} repositories to {}", repos.len(), export_path);
         return Ok(());
     }
 
+    if results.is_empty() {
+        println!("No repositories found for '{}'", query);
+        return Ok(());
+    }
+
     println!(
         "\nFound {} repositories (semantic search):\n",
         results.len()
@@ -1616,13 +4443,115 @@ async fn handle_semantic_search(
 
         if hybrid {
             if let Some(keyword_score) = result.keyword_score {
+                let dominant = result
+                    .dominant
+                    .map(|source| format!(", mostly {}", source.label()))
+                    .unwrap_or_default();
                 println!(
-                    "   Hybrid score: {:.2} (semantic: {:.2}, keyword: {:.2})",
-                    result.hybrid_score, result.semantic_score, keyword_score
+                    "   Hybrid score: {:.2} (semantic: {:.2}, keyword: {:.2}{})",
+                    result.hybrid_score, result.semantic_score, keyword_score, dominant
                 );
             }
         }
 
+        if !result.matched_terms.is_empty() {
+            println!("   matched on: {}", result.matched_terms.join(", "));
+        }
+
+        println!(
+            "   ⭐ {} stars | 🍴 {} forks | 📝 {}",
+            repo.stars,
+            repo.forks,
+            repo.language.as_deref().unwrap_or("Unknown")
+        );
+        println!("   {}", repo.url);
+        println!();
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn show_similar(
+    config: &reposcout_core::Config,
+    full_name: &str,
+    limit: usize,
+    model: Option<reposcout_semantic::EmbeddingModel>,
+    github_token: Option<String>,
+    gitlab_token: Option<String>,
+    bitbucket_username: Option<String>,
+    bitbucket_app_password: Option<String>,
+    sourcehut_token: Option<String>,
+    azure: AzureDevOpsArgs,
+) -> anyhow::Result<()> {
+    use reposcout_semantic::{SemanticConfig, SemanticSearchEngine};
+
+    let Some((owner, repo)) = full_name.split_once('/') else {
+        anyhow::bail!("Repository name must be in 'owner/repo' format");
+    };
+
+    let cache_path = get_cache_path()?;
+    let semantic_config = SemanticConfig {
+        enabled: true,
+        cache_path: cache_path.join("semantic").to_string_lossy().to_string(),
+        ..Default::default()
+    }
+    .with_model(model);
+    println!("Initializing semantic search engine...");
+    let engine = SemanticSearchEngine::new(semantic_config)?;
+    engine.initialize().await?;
+    if engine.indexed_count().await == 0 {
+        anyhow::bail!(reposcout_semantic::SemanticError::EmptyIndex);
+    }
+
+    let cache = open_cache(config, &cache_path)?;
+    let cached_count = cache.stats()?.total_entries;
+    engine
+        .register_repositories(cache.get_all::<reposcout_core::models::Repository>(cached_count)?)
+        .await;
+
+    let mut search = cached_engine(config, cache);
+    search.add_provider(Box::new(github_provider(config, github_token)));
+    search.add_provider(Box::new(GitLabProvider::new(gitlab_token)));
+    search.add_provider(Box::new(bitbucket_provider(
+        config,
+        bitbucket_username,
+        bitbucket_app_password,
+    )));
+    search.add_provider(Box::new(SourceHutProvider::new(sourcehut_token)));
+    search.add_provider(Box::new(azure.provider()));
+    let repository = search.get_repository(owner, repo).await?;
+
+    let results = engine.find_similar(&repository, None, limit).await?;
+
+    if results.is_empty() {
+        println!(
+            "No repositories in the semantic index are similar to {}",
+            repository.full_name
+        );
+        return Ok(());
+    }
+
+    println!(
+        "\nRepositories similar to {} ({}):\n",
+        repository.full_name,
+        results.len()
+    );
+    for (i, result) in results.iter().enumerate() {
+        let repo = &result.repository;
+        println!(
+            "{}. {} ({}) [similarity: {:.2}]",
+            i + 1,
+            repo.full_name,
+            repo.platform,
+            result.semantic_score
+        );
+        if let Some(desc) = &repo.description {
+            println!("   {}", desc);
+        }
+        if !result.matched_terms.is_empty() {
+            println!("   shares: {}", result.matched_terms.join(", "));
+        }
         println!(
             "   ⭐ {} stars | 🍴 {} forks | 📝 {}",
             repo.stars,
@@ -1636,21 +4565,31 @@ async fn handle_semantic_search(
     Ok(())
 }
 
-async fn handle_semantic_index(action: &SemanticIndexAction) -> anyhow::Result<()> {
+async fn handle_semantic_index(
+    config: &reposcout_core::Config,
+    action: &SemanticIndexAction,
+) -> anyhow::Result<()> {
     use reposcout_semantic::{SemanticConfig, SemanticSearchEngine};
 
     let cache_path = get_cache_path()?;
     let semantic_cache_path = cache_path.join("semantic");
 
-    let config = SemanticConfig {
+    // Stats describe the index as built; rebuilding keeps its model
+    // unless another one is asked for
+    let model = match action {
+        SemanticIndexAction::Rebuild { model, .. } => model.clone(),
+        _ => None,
+    };
+    let semantic_config = SemanticConfig {
         enabled: true,
         cache_path: semantic_cache_path.to_string_lossy().to_string(),
         ..Default::default()
-    };
+    }
+    .with_model(model);
 
     match action {
         SemanticIndexAction::Stats => {
-            let engine = SemanticSearchEngine::new(config)?;
+            let engine = SemanticSearchEngine::new(semantic_config)?;
             let stats = engine.stats().await;
 
             println!("\nSemantic Index Statistics:");
@@ -1662,6 +4601,10 @@ async fn handle_semantic_index(action: &SemanticIndexAction) -> anyhow::Result<(
             );
             println!("Model: {}", stats.model_name);
             println!("Vector dimension: {}", stats.dimension);
+            println!(
+                "Embedded: {} | Skipped (unchanged): {}",
+                stats.newly_embedded, stats.skipped_unchanged
+            );
             println!(
                 "Last updated: {}",
                 stats.last_updated.format("%Y-%m-%d %H:%M:%S")
@@ -1671,19 +4614,64 @@ async fn handle_semantic_index(action: &SemanticIndexAction) -> anyhow::Result<(
                 stats.created_at.format("%Y-%m-%d %H:%M:%S")
             );
         }
-        SemanticIndexAction::Rebuild { force } => {
-            if !force {
-                println!("Warning: This will rebuild the entire semantic index.");
-                println!("Use --force to confirm.");
+        SemanticIndexAction::Rebuild { force, .. } => {
+            const BATCH_SIZE: usize = 50;
+
+            let cache = open_cache(config, &cache_path)?;
+            let cached_count = cache.stats()?.total_entries;
+            let repos: Vec<reposcout_core::models::Repository> = cache.get_all(cached_count)?;
+
+            let engine = SemanticSearchEngine::new(semantic_config)?;
+            if *force {
+                println!("Clearing existing semantic index...");
+                engine.clear().await?;
+            }
+
+            // Without --force, only embed repositories that aren't indexed yet
+            let mut pending = Vec::new();
+            for repo in repos {
+                let repo_id = format!("{}:{}", repo.platform, repo.full_name);
+                if !engine.is_indexed(&repo_id).await {
+                    pending.push((repo, None));
+                }
+            }
+
+            if pending.is_empty() {
+                println!(
+                    "✓ Semantic index is up to date ({} cached repositories)",
+                    cached_count
+                );
                 return Ok(());
             }
 
-            println!("Note: Semantic index rebuild from cache is not yet implemented.");
-            println!("The index will be automatically built as you search repositories.");
-            println!("Use semantic search commands to populate the index.");
+            println!(
+                "Indexing {} of {} cached repositories...",
+                pending.len(),
+                cached_count
+            );
+            engine.initialize().await?;
+
+            let total = pending.len();
+            let mut indexed = 0;
+            for batch in pending.chunks(BATCH_SIZE) {
+                indexed += engine.index_repositories(batch.to_vec()).await?;
+                println!("  Indexed {}/{}", indexed, total);
+            }
+
+            engine.save().await?;
+
+            let stats = engine.stats().await;
+            println!("\n✓ Semantic index rebuilt from cache");
+            println!("  Newly indexed:      {}", indexed);
+            println!("  Total repositories: {}", stats.total_repositories);
+            println!(
+                "  Index size:         {:.2} MB",
+                stats.index_size_bytes as f64 / 1_048_576.0
+            );
+            println!("  Model:              {}", stats.model_name);
         }
         SemanticIndexAction::Clear => {
-            let engine = SemanticSearchEngine::new(config)?;
+            let engine = SemanticSearchEngine::new(semantic_config)?;
             engine.clear().await?;
 
             println!("✓ Semantic index cleared");
@@ -0,0 +1,84 @@
+// Output formatting for the CLI commands
+//
+// `--quiet` turns the decorated, human-oriented listings into plain output
+// for logs and scripts: no emoji, banners or separators, and one
+// tab-separated record per line on stdout. Notices such as "No bookmarks
+// found" go to stderr instead, so a pipe only ever sees records.
+
+use reposcout_core::models::Repository;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+/// Switch to plain output for the rest of the run
+pub fn set_plain(plain: bool) {
+    PLAIN.store(plain, Ordering::Relaxed);
+}
+
+/// Whether `--quiet` was given
+pub fn is_plain() -> bool {
+    PLAIN.load(Ordering::Relaxed)
+}
+
+/// A heading or summary above a listing. Plain output drops it.
+pub fn heading(text: impl Display) {
+    if !is_plain() {
+        println!("{}", text);
+    }
+}
+
+/// A message that isn't a record, e.g. an empty result. Plain output sends
+/// it to stderr.
+pub fn notice(text: impl Display) {
+    if is_plain() {
+        eprintln!("{}", text);
+    } else {
+        println!("{}", text);
+    }
+}
+
+/// The outcome of an action, shown after `mark` unless output is plain
+pub fn status(mark: &str, text: impl Display) {
+    if is_plain() {
+        println!("{}", text);
+    } else {
+        println!("{} {}", mark, text);
+    }
+}
+
+/// Print one tab-separated record
+pub fn record<S: AsRef<str>>(fields: &[S]) {
+    let fields: Vec<String> = fields.iter().map(|f| clean(f.as_ref())).collect();
+    println!("{}", fields.join("\t"));
+}
+
+/// Print a repository as one record: name, platform, stars, forks, language
+/// and URL, then `extra`, with the description last
+pub fn repo_record(repo: &Repository, extra: &[String]) {
+    let mut fields = vec![
+        repo.full_name.clone(),
+        repo.platform.to_string(),
+        repo.stars.to_string(),
+        repo.forks.to_string(),
+        repo.language.clone().unwrap_or_default(),
+        repo.url.clone(),
+    ];
+    fields.extend_from_slice(extra);
+    fields.push(repo.description.clone().unwrap_or_default());
+    record(&fields);
+}
+
+/// Tabs and line breaks inside a value would split the record
+fn clean(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| {
+            if matches!(c, '\t' | '\n' | '\r') {
+                ' '
+            } else {
+                c
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,149 @@
+// shields.io badges for a repository, ready to paste into a README
+use crate::export::Exporter;
+use crate::models::Repository;
+
+/// How badges are written out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeFormat {
+    Markdown,
+    Html,
+}
+
+/// A single static shields.io badge
+#[derive(Debug, Clone, PartialEq)]
+pub struct Badge {
+    pub label: String,
+    pub message: String,
+    /// shields.io color name
+    pub color: String,
+    /// Where clicking the badge leads
+    pub link: String,
+}
+
+impl Badge {
+    /// Stars, forks, open issues, license, last commit and RepoScout health.
+    /// Health is calculated if the repository doesn't carry it yet.
+    pub fn for_repository(repo: &mut Repository) -> Vec<Badge> {
+        let link = repo.url.clone();
+        let badge = |label: &str, message: String, color: &str| Badge {
+            label: label.to_string(),
+            message,
+            color: color.to_string(),
+            link: link.clone(),
+        };
+
+        let health = repo.get_health().clone();
+        vec![
+            badge("stars", Exporter::format_number(repo.stars), "blue"),
+            badge("forks", Exporter::format_number(repo.forks), "blue"),
+            badge(
+                "open issues",
+                Exporter::format_number(repo.open_issues),
+                "blue",
+            ),
+            badge(
+                "license",
+                repo.license.clone().unwrap_or_else(|| "none".to_string()),
+                if repo.license.is_some() {
+                    "blue"
+                } else {
+                    "lightgrey"
+                },
+            ),
+            badge(
+                "last commit",
+                repo.pushed_at.format("%Y-%m-%d").to_string(),
+                "blue",
+            ),
+            badge(
+                "health",
+                format!("{}/100", health.score),
+                health.status.color_code(),
+            ),
+        ]
+    }
+
+    /// Static badge image URL
+    pub fn image_url(&self) -> String {
+        format!(
+            "https://img.shields.io/badge/{}-{}-{}",
+            shields_escape(&self.label),
+            shields_escape(&self.message),
+            shields_escape(&self.color)
+        )
+    }
+
+    pub fn render(&self, format: BadgeFormat) -> String {
+        match format {
+            BadgeFormat::Markdown => {
+                format!("[![{}]({})]({})", self.label, self.image_url(), self.link)
+            }
+            BadgeFormat::Html => format!(
+                "<a href=\"{}\"><img alt=\"{}\" src=\"{}\"></a>",
+                Exporter::escape_html(&self.link),
+                Exporter::escape_html(&self.label),
+                Exporter::escape_html(&self.image_url())
+            ),
+        }
+    }
+}
+
+/// Escape a badge path segment: shields.io reads `-` and `_` as separators
+/// and spaces, so those are doubled, and anything else unsafe is percent-encoded
+fn shields_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '-' => escaped.push_str("--"),
+            '_' => escaped.push_str("__"),
+            ' ' => escaped.push_str("%20"),
+            c if c.is_ascii_alphanumeric() || matches!(c, '.' | '~') => escaped.push(c),
+            c => {
+                let mut buf = [0; 4];
+                for byte in c.encode_utf8(&mut buf).bytes() {
+                    escaped.push_str(&format!("%{:02X}", byte));
+                }
+            }
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Platform;
+
+    #[test]
+    fn test_badges() {
+        let mut repo = Repository {
+            url: "https://github.com/tokio-rs/tokio".to_string(),
+            forks: 2_300,
+            open_issues: 42,
+            language: Some("Rust".to_string()),
+            license: Some("MIT".to_string()),
+            default_branch: "master".to_string(),
+            ..Repository::test_fixture(Platform::GitHub, "tokio-rs/tokio", 25_300)
+        };
+
+        let badges = Badge::for_repository(&mut repo);
+        assert_eq!(badges.len(), 6);
+        assert_eq!(
+            badges[0].render(BadgeFormat::Markdown),
+            "[![stars](https://img.shields.io/badge/stars-25.3k-blue)](https://github.com/tokio-rs/tokio)"
+        );
+        assert_eq!(
+            badges[2].image_url(),
+            "https://img.shields.io/badge/open%20issues-42-blue"
+        );
+        assert!(badges[4].message.contains('-'));
+        assert!(badges[4].image_url().contains("--"));
+
+        let health = repo.health.as_ref().unwrap();
+        assert_eq!(badges[5].color, health.status.color_code());
+        assert!(badges[5]
+            .render(BadgeFormat::Html)
+            .starts_with("<a href=\"https://github.com/tokio-rs/tokio\"><img alt=\"health\""));
+        assert!(badges[5].image_url().contains("%2F100"));
+    }
+}
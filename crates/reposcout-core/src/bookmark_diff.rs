@@ -0,0 +1,120 @@
+// What changed in a bookmarked repository since its snapshot was saved
+use crate::models::Repository;
+use chrono::{DateTime, Utc};
+
+/// Differences between a stored bookmark snapshot and freshly fetched data
+#[derive(Debug, Clone, PartialEq)]
+pub struct BookmarkDiff {
+    pub full_name: String,
+    pub stars_delta: i64,
+    pub forks_delta: i64,
+    /// Net change in open issues (opened minus closed)
+    pub open_issues_delta: i64,
+    pub old_pushed_at: DateTime<Utc>,
+    pub new_pushed_at: DateTime<Utc>,
+    /// New archived state, if it flipped
+    pub archived: Option<bool>,
+}
+
+impl BookmarkDiff {
+    /// Compare the saved snapshot with fresh data
+    pub fn between(old: &Repository, new: &Repository) -> Self {
+        Self {
+            full_name: new.full_name.clone(),
+            stars_delta: new.stars as i64 - old.stars as i64,
+            forks_delta: new.forks as i64 - old.forks as i64,
+            open_issues_delta: new.open_issues as i64 - old.open_issues as i64,
+            old_pushed_at: old.pushed_at,
+            new_pushed_at: new.pushed_at,
+            archived: (old.is_archived != new.is_archived).then_some(new.is_archived),
+        }
+    }
+
+    pub fn has_changes(&self) -> bool {
+        self.stars_delta != 0
+            || self.forks_delta != 0
+            || self.open_issues_delta != 0
+            || self.old_pushed_at != self.new_pushed_at
+            || self.archived.is_some()
+    }
+
+    /// One line summary, e.g. "tokio-rs/tokio: +420 ⭐, last pushed 2d ago (was 30d)"
+    pub fn summary(&self, now: DateTime<Utc>) -> String {
+        if !self.has_changes() {
+            return format!("{}: no changes", self.full_name);
+        }
+
+        let mut parts = Vec::new();
+        if self.stars_delta != 0 {
+            parts.push(format!("{:+} ⭐", self.stars_delta));
+        }
+        if self.forks_delta != 0 {
+            parts.push(format!("{:+} 🍴", self.forks_delta));
+        }
+        if self.open_issues_delta != 0 {
+            parts.push(format!("{:+} open issues", self.open_issues_delta));
+        }
+        if self.old_pushed_at != self.new_pushed_at {
+            parts.push(format!(
+                "last pushed {} ago (was {})",
+                short_age(self.new_pushed_at, now),
+                short_age(self.old_pushed_at, now)
+            ));
+        }
+        match self.archived {
+            Some(true) => parts.push("now archived".to_string()),
+            Some(false) => parts.push("no longer archived".to_string()),
+            None => {}
+        }
+
+        format!("{}: {}", self.full_name, parts.join(", "))
+    }
+}
+
+/// Compact age like "45m", "3h", "2d", "5mo" or "1y"
+fn short_age(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let minutes = (now - then).num_minutes().max(0);
+    match minutes {
+        m if m < 60 => format!("{}m", m),
+        m if m < 60 * 24 => format!("{}h", m / 60),
+        m if m < 60 * 24 * 60 => format!("{}d", m / (60 * 24)),
+        m if m < 60 * 24 * 365 => format!("{}mo", m / (60 * 24 * 30)),
+        m => format!("{}y", m / (60 * 24 * 365)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Platform;
+    use chrono::Duration;
+
+    fn repo(stars: u32, open_issues: u32, pushed_at: DateTime<Utc>) -> Repository {
+        Repository {
+            forks: 10,
+            open_issues,
+            created_at: pushed_at,
+            updated_at: pushed_at,
+            pushed_at,
+            default_branch: "master".to_string(),
+            ..Repository::test_fixture(Platform::GitHub, "tokio-rs/tokio", stars)
+        }
+    }
+
+    #[test]
+    fn test_summary_reports_deltas() {
+        let now = Utc::now();
+        let old = repo(1000, 50, now - Duration::days(30));
+        let new = repo(1420, 47, now - Duration::days(2));
+
+        let diff = BookmarkDiff::between(&old, &new);
+        assert_eq!(
+            diff.summary(now),
+            "tokio-rs/tokio: +420 ⭐, -3 open issues, last pushed 2d ago (was 30d)"
+        );
+
+        let unchanged = BookmarkDiff::between(&old, &old);
+        assert!(!unchanged.has_changes());
+        assert_eq!(unchanged.summary(now), "tokio-rs/tokio: no changes");
+    }
+}
@@ -0,0 +1,141 @@
+// Aggregate statistics over the bookmark collection
+use crate::models::Repository;
+use reposcout_cache::BookmarkEntry;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Star buckets as (label, lower bound), in ascending order
+const STAR_BUCKETS: &[(&str, u32)] = &[
+    ("<100", 0),
+    ("100-999", 100),
+    ("1k-9.9k", 1_000),
+    ("10k-99k", 10_000),
+    ("100k+", 100_000),
+];
+
+/// Summary of all bookmarks
+#[derive(Debug, Clone, Serialize)]
+pub struct BookmarkStats {
+    pub total: usize,
+    /// Bookmarks whose stored repository data couldn't be parsed
+    pub skipped: usize,
+    /// (platform, count), most common first
+    pub platforms: Vec<(String, usize)>,
+    /// Top 5 (language, count), most common first
+    pub top_languages: Vec<(String, usize)>,
+    /// (bucket label, count), in ascending star order
+    pub star_buckets: Vec<(String, usize)>,
+    /// Top 10 (tag, count), most common first
+    pub top_tags: Vec<(String, usize)>,
+    pub average_health: Option<f64>,
+}
+
+/// Sort counts descending, ties alphabetically, and keep the first `limit`
+fn ranked(counts: HashMap<String, usize>, limit: usize) -> Vec<(String, usize)> {
+    let mut counts: Vec<_> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts.truncate(limit);
+    counts
+}
+
+impl BookmarkStats {
+    /// Aggregate bookmark entries, skipping ones whose data fails to parse
+    pub fn from_entries(entries: &[BookmarkEntry]) -> Self {
+        let mut skipped = 0;
+        let mut platforms = HashMap::new();
+        let mut languages = HashMap::new();
+        let mut tags = HashMap::new();
+        let mut star_buckets = vec![0usize; STAR_BUCKETS.len()];
+        let mut health_total = 0u64;
+        let mut parsed = 0usize;
+
+        for entry in entries {
+            let mut repo: Repository = match serde_json::from_str(&entry.data) {
+                Ok(repo) => repo,
+                Err(e) => {
+                    tracing::warn!("Skipping bookmark {}: {}", entry.full_name, e);
+                    skipped += 1;
+                    continue;
+                }
+            };
+            parsed += 1;
+
+            *platforms.entry(repo.platform.to_string()).or_default() += 1;
+            if let Some(language) = &repo.language {
+                *languages.entry(language.clone()).or_default() += 1;
+            }
+            for tag in entry.tags.as_deref().unwrap_or("").split(',') {
+                let tag = tag.trim();
+                if !tag.is_empty() {
+                    *tags.entry(tag.to_string()).or_default() += 1;
+                }
+            }
+
+            let bucket = STAR_BUCKETS
+                .iter()
+                .rposition(|&(_, min)| repo.stars >= min)
+                .unwrap_or(0);
+            star_buckets[bucket] += 1;
+
+            health_total += repo.get_health().score as u64;
+        }
+
+        Self {
+            total: entries.len(),
+            skipped,
+            platforms: ranked(platforms, usize::MAX),
+            top_languages: ranked(languages, 5),
+            star_buckets: STAR_BUCKETS
+                .iter()
+                .zip(star_buckets)
+                .map(|(&(label, _), count)| (label.to_string(), count))
+                .collect(),
+            top_tags: ranked(tags, 10),
+            average_health: (parsed > 0).then(|| health_total as f64 / parsed as f64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Platform;
+
+    fn entry(name: &str, stars: u32, language: Option<&str>, tags: Option<&str>) -> BookmarkEntry {
+        let repo = Repository {
+            url: format!("https://github.com/{}", name),
+            language: language.map(String::from),
+            ..Repository::test_fixture(Platform::GitHub, name, stars)
+        };
+        BookmarkEntry {
+            platform: "github".to_string(),
+            full_name: name.to_string(),
+            data: serde_json::to_string(&repo).unwrap(),
+            bookmarked_at: 0,
+            tags: tags.map(String::from),
+            notes: None,
+        }
+    }
+
+    #[test]
+    fn test_bookmark_stats() {
+        let mut broken = entry("x/broken", 0, None, None);
+        broken.data = "{not json".to_string();
+        let entries = vec![
+            entry("a/one", 50, Some("Rust"), Some("cli, tui")),
+            entry("b/two", 2_500, Some("Rust"), Some("cli")),
+            entry("c/three", 150_000, Some("Go"), None),
+            broken,
+        ];
+
+        let stats = BookmarkStats::from_entries(&entries);
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.skipped, 1);
+        assert_eq!(stats.platforms, vec![("GitHub".to_string(), 3)]);
+        assert_eq!(stats.top_languages[0], ("Rust".to_string(), 2));
+        assert_eq!(stats.top_tags[0], ("cli".to_string(), 2));
+        let buckets: Vec<usize> = stats.star_buckets.iter().map(|(_, c)| *c).collect();
+        assert_eq!(buckets, vec![1, 0, 1, 0, 1]);
+        assert!(stats.average_health.is_some());
+    }
+}
@@ -0,0 +1,155 @@
+// Surrounding lines for code search matches
+//
+// GitHub code search returns a fragment of the file with no line numbers or
+// context. Given the whole file, the fragment can be located and the lines
+// around it filled in, so previews show the code the match sits in.
+
+use crate::models::CodeMatch;
+
+/// Context lines shown around a code match unless `--context` says otherwise
+pub const DEFAULT_CONTEXT_LINES: usize = 3;
+
+/// Fragment lines shorter than this are too common to locate a match by
+const MIN_ANCHOR_LEN: usize = 4;
+
+/// Find where a fragment sits in a file, as a 0-based line index and the
+/// number of lines it spans.
+///
+/// Tries the whole fragment first, then each of its lines (the API trims
+/// fragments mid-line), then the query `terms` on their own. Returns `None`
+/// if nothing can be found, e.g. because the file changed since indexing.
+pub fn locate_match(file: &str, fragment: &str, terms: &[&str]) -> Option<(usize, usize)> {
+    let lines: Vec<&str> = file.lines().collect();
+    let span = fragment.trim_end().lines().count().max(1);
+
+    let fragment = fragment.trim();
+    if !fragment.is_empty() {
+        if let Some(offset) = file.find(fragment) {
+            let line = file[..offset].matches('\n').count();
+            return Some((line, fragment.lines().count().max(1)));
+        }
+    }
+
+    for (i, anchor) in fragment.lines().enumerate() {
+        let anchor = anchor.trim();
+        if anchor.len() < MIN_ANCHOR_LEN {
+            continue;
+        }
+        if let Some(line) = lines.iter().position(|l| l.contains(anchor)) {
+            let start = line.saturating_sub(i);
+            return Some((start, span.min(lines.len() - start)));
+        }
+    }
+
+    let terms: Vec<String> = terms
+        .iter()
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_lowercase())
+        .collect();
+    lines
+        .iter()
+        .position(|l| {
+            let l = l.to_lowercase();
+            terms.iter().any(|t| l.contains(t.as_str()))
+        })
+        .map(|line| (line, 1))
+}
+
+/// Locate a match in the fetched file and fill in its line number, the
+/// matched lines and up to `context` lines either side. Returns false, and
+/// leaves the match alone, if it can't be located.
+pub fn apply_file_context(
+    code_match: &mut CodeMatch,
+    file: &str,
+    terms: &[&str],
+    context: usize,
+) -> bool {
+    let Some((start, span)) = locate_match(file, &code_match.content, terms) else {
+        return false;
+    };
+    let lines: Vec<&str> = file.lines().collect();
+    let end = (start + span).min(lines.len());
+    let to_strings = |range: &[&str]| range.iter().map(|l| l.to_string()).collect();
+
+    code_match.line_number = start + 1;
+    code_match.content = lines[start..end].join("\n");
+    code_match.context_before = to_strings(&lines[start.saturating_sub(context)..start]);
+    code_match.context_after = to_strings(&lines[end..(end + context).min(lines.len())]);
+    true
+}
+
+/// Search words worth looking for in a file, without qualifiers such as
+/// `language:rust`
+pub fn query_terms(query: &str) -> Vec<&str> {
+    query
+        .split_whitespace()
+        .filter(|word| !word.contains(':'))
+        .map(|word| word.trim_matches('"'))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FILE: &str = "use std::io;\n\nfn main() {\n    let x = parse();\n    println!(\"{}\", x);\n}\n\nfn parse() -> u32 {\n    42\n}\n";
+
+    fn code_match(content: &str) -> CodeMatch {
+        CodeMatch {
+            content: content.to_string(),
+            line_number: 1,
+            context_before: vec![],
+            context_after: vec![],
+        }
+    }
+
+    #[test]
+    fn test_locate_match() {
+        // Whole fragment
+        assert_eq!(
+            locate_match(FILE, "fn parse() -> u32 {\n    42", &[]),
+            Some((7, 2))
+        );
+        // Fragment cut mid-line falls back to its lines
+        assert_eq!(
+            locate_match(FILE, "t x = parse();\n    println!(\"{}\", x);", &[]),
+            Some((3, 2))
+        );
+        // Only the query term left
+        assert_eq!(locate_match(FILE, "", &["PRINTLN"]), Some((4, 1)));
+        assert_eq!(locate_match(FILE, "gone", &["missing"]), None);
+    }
+
+    #[test]
+    fn test_apply_file_context() {
+        let mut m = code_match("println!(\"{}\", x);");
+        assert!(apply_file_context(&mut m, FILE, &[], 2));
+        assert_eq!(m.line_number, 5);
+        assert_eq!(m.content, "    println!(\"{}\", x);");
+        assert_eq!(
+            m.context_before,
+            vec!["fn main() {", "    let x = parse();"]
+        );
+        assert_eq!(m.context_after, vec!["}", ""]);
+
+        // Context stops at the start of the file
+        let mut m = code_match("use std::io;");
+        assert!(apply_file_context(&mut m, FILE, &[], 3));
+        assert_eq!(m.line_number, 1);
+        assert!(m.context_before.is_empty());
+        assert_eq!(m.context_after.len(), 3);
+
+        let mut m = code_match("not in the file");
+        assert!(!apply_file_context(&mut m, FILE, &[], 3));
+        assert_eq!(m.line_number, 1);
+    }
+
+    #[test]
+    fn test_query_terms() {
+        assert_eq!(
+            query_terms("parse \"main\" language:rust repo:a/b"),
+            vec!["parse", "main"]
+        );
+    }
+}
@@ -0,0 +1,172 @@
+// Side-by-side repository comparison
+use crate::{health::MaintenanceLevel, models::Repository};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// The compared fields of one repository
+#[derive(Debug, Clone, Serialize)]
+pub struct ComparedRepo {
+    pub full_name: String,
+    pub platform: String,
+    pub url: String,
+    pub stars: u32,
+    pub forks: u32,
+    pub open_issues: u32,
+    pub language: Option<String>,
+    pub license: Option<String>,
+    pub age_days: i64,
+    pub pushed_at: DateTime<Utc>,
+    pub days_since_push: i64,
+    pub health_score: u8,
+    pub health_status: String,
+    pub maintenance: String,
+}
+
+/// Result of comparing two repositories
+#[derive(Debug, Clone, Serialize)]
+pub struct RepoComparison {
+    pub repo_a: ComparedRepo,
+    pub repo_b: ComparedRepo,
+    /// repo_a stars / repo_b stars (None when repo_b has no stars)
+    pub stars_ratio: Option<f64>,
+    /// repo_a forks / repo_b forks (None when repo_b has no forks)
+    pub forks_ratio: Option<f64>,
+    /// repo_a health score minus repo_b health score
+    pub health_delta: i16,
+    /// Name of the better-maintained repository, None on a tie
+    pub healthier: Option<String>,
+    /// Human-readable summary lines ("a has 3.2x more stars than b")
+    pub summary: Vec<String>,
+}
+
+/// Lower is better: Active < Maintained < ... < Abandoned
+fn maintenance_rank(level: MaintenanceLevel) -> u8 {
+    match level {
+        MaintenanceLevel::Active => 0,
+        MaintenanceLevel::Maintained => 1,
+        MaintenanceLevel::Stale => 2,
+        MaintenanceLevel::Inactive => 3,
+        MaintenanceLevel::Abandoned => 4,
+    }
+}
+
+fn ratio(a: u32, b: u32) -> Option<f64> {
+    (b > 0).then(|| a as f64 / b as f64)
+}
+
+/// "a has 3.2x more stars than b", phrased from the larger side
+fn ratio_summary(metric: &str, a_name: &str, a: u32, b_name: &str, b: u32) -> String {
+    let (hi_name, hi, lo_name, lo) = if a >= b {
+        (a_name, a, b_name, b)
+    } else {
+        (b_name, b, a_name, a)
+    };
+
+    if hi == lo {
+        format!("Both have {} {}", hi, metric)
+    } else if lo == 0 {
+        format!("{} has {} {}, {} has none", hi_name, hi, metric, lo_name)
+    } else {
+        format!(
+            "{} has {:.1}x more {} than {}",
+            hi_name,
+            hi as f64 / lo as f64,
+            metric,
+            lo_name
+        )
+    }
+}
+
+impl ComparedRepo {
+    fn from_repo(repo: &mut Repository, now: DateTime<Utc>) -> Self {
+        let health = repo.get_health().clone();
+        Self {
+            full_name: repo.full_name.clone(),
+            platform: repo.platform.to_string(),
+            url: repo.url.clone(),
+            stars: repo.stars,
+            forks: repo.forks,
+            open_issues: repo.open_issues,
+            language: repo.language.clone(),
+            license: repo.license.clone(),
+            age_days: (now - repo.created_at).num_days(),
+            pushed_at: repo.pushed_at,
+            days_since_push: (now - repo.pushed_at).num_days(),
+            health_score: health.score,
+            health_status: health.status.label().to_string(),
+            maintenance: health.maintenance.label().to_string(),
+        }
+    }
+}
+
+/// Compare two repositories, computing health metrics where missing
+pub fn compare_repositories(a: &mut Repository, b: &mut Repository) -> RepoComparison {
+    let now = Utc::now();
+    let a_maintenance = a.get_health().maintenance;
+    let b_maintenance = b.get_health().maintenance;
+    let repo_a = ComparedRepo::from_repo(a, now);
+    let repo_b = ComparedRepo::from_repo(b, now);
+
+    // Maintenance level decides, the overall health score breaks ties
+    let healthier = maintenance_rank(a_maintenance)
+        .cmp(&maintenance_rank(b_maintenance))
+        .reverse()
+        .then(repo_a.health_score.cmp(&repo_b.health_score));
+    let healthier = match healthier {
+        std::cmp::Ordering::Greater => Some(repo_a.full_name.clone()),
+        std::cmp::Ordering::Less => Some(repo_b.full_name.clone()),
+        std::cmp::Ordering::Equal => None,
+    };
+
+    let (a_name, b_name) = (repo_a.full_name.as_str(), repo_b.full_name.as_str());
+    let mut summary = vec![
+        ratio_summary("stars", a_name, repo_a.stars, b_name, repo_b.stars),
+        ratio_summary("forks", a_name, repo_a.forks, b_name, repo_b.forks),
+    ];
+    match &healthier {
+        Some(name) => summary.push(format!("{} is better maintained", name)),
+        None => summary.push("Both are equally well maintained".to_string()),
+    }
+
+    RepoComparison {
+        stars_ratio: ratio(repo_a.stars, repo_b.stars),
+        forks_ratio: ratio(repo_a.forks, repo_b.forks),
+        health_delta: repo_a.health_score as i16 - repo_b.health_score as i16,
+        healthier,
+        summary,
+        repo_a,
+        repo_b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Platform;
+    use chrono::Duration;
+
+    fn repo(name: &str, stars: u32, pushed_days_ago: i64) -> Repository {
+        let now = Utc::now();
+        Repository {
+            url: format!("https://github.com/{}", name),
+            forks: 10,
+            created_at: now - Duration::days(1000),
+            pushed_at: now - Duration::days(pushed_days_ago),
+            ..Repository::test_fixture(Platform::GitHub, name, stars)
+        }
+    }
+
+    #[test]
+    fn test_compare_repositories() {
+        let mut a = repo("a/fast", 3200, 2);
+        let mut b = repo("b/slow", 1000, 400);
+
+        let cmp = compare_repositories(&mut a, &mut b);
+        assert!((cmp.stars_ratio.unwrap() - 3.2).abs() < 1e-9);
+        assert_eq!(cmp.healthier.as_deref(), Some("a/fast"));
+        assert!(cmp.health_delta > 0);
+        assert_eq!(cmp.summary[0], "a/fast has 3.2x more stars than b/slow");
+        assert_eq!(cmp.summary[1], "Both have 10 forks");
+        assert!(a.health.is_some() && b.health.is_some());
+    }
+}
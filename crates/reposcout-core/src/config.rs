@@ -1,3 +1,4 @@
+use crate::models::Repository;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
@@ -7,9 +8,22 @@ use std::path::PathBuf;
 /// Priority: CLI > Env > File > Defaults (like a sensible person would do)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Config {
+    #[serde(default)]
     pub platforms: PlatformConfig,
+    #[serde(default)]
     pub cache: CacheConfig,
+    #[serde(default)]
     pub ui: UiConfig,
+    #[serde(default)]
+    pub search: SearchConfig,
+    #[serde(default)]
+    pub filters: FilterConfig,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    #[serde(default)]
+    pub health: crate::health::HealthWeights,
+    #[serde(default)]
+    pub trending_score: crate::trending::TrendingScoreWeights,
 }
 
 impl Config {
@@ -19,15 +33,24 @@ impl Config {
 
         if config_path.exists() {
             let contents = std::fs::read_to_string(&config_path)?;
-            let config: Config = toml::from_str(&contents)
-                .map_err(|e| crate::Error::ConfigError(format!("Failed to parse config: {}", e)))?;
-            Ok(config)
+            Self::parse(&contents, &config_path.display().to_string())
         } else {
             // No config file? Use defaults
             Ok(Self::default())
         }
     }
 
+    /// Parse config file contents. Sections and keys left out fall back to defaults.
+    ///
+    /// Errors carry the line, column and offending key from the TOML parser.
+    fn parse(contents: &str, source: &str) -> crate::Result<Self> {
+        let config: Self = toml::from_str(contents)
+            .map_err(|e| crate::Error::ConfigError(format!("Failed to parse {}: {}", source, e)))?;
+        config.health.validate()?;
+        config.trending_score.validate()?;
+        Ok(config)
+    }
+
     /// Save config to disk
     pub fn save(&self) -> crate::Result<()> {
         let config_path = Self::config_path()?;
@@ -129,14 +152,31 @@ impl Default for GitLabConfig {
 pub struct BitbucketConfig {
     pub username: Option<String>,
     pub app_password: Option<String>,
+
+    /// Bitbucket Data Center instance URL (Bitbucket Cloud when unset)
+    #[serde(default)]
+    pub url: Option<String>,
+
+    /// Data Center HTTP access token
+    #[serde(default)]
+    pub token: Option<String>,
+
+    /// Data Center project key to limit searches to
+    #[serde(default)]
+    pub project: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheConfig {
-    /// Cache TTL in hours
+    /// Cache TTL in hours (overridden by the `--cache-ttl` flag)
     #[serde(default = "default_cache_ttl")]
     pub ttl_hours: u64,
 
+    /// TTL in hours for cached trending results, which go stale faster
+    /// than repository metadata
+    #[serde(default = "default_trending_ttl")]
+    pub trending_ttl_hours: u64,
+
     /// Max cache size in MB
     #[serde(default = "default_cache_size")]
     pub max_size_mb: u64,
@@ -144,32 +184,57 @@ pub struct CacheConfig {
     /// Enable offline mode (use cache even if stale)
     #[serde(default)]
     pub offline_mode: bool,
+
+    /// Max cached repositories / queries before least recently used ones are evicted
+    #[serde(default = "default_cache_max_entries")]
+    pub max_entries: usize,
+
+    /// Skip the cache for this run (`--no-cache`); never read from or saved
+    /// to the file
+    #[serde(skip)]
+    pub bypass: bool,
 }
 
 fn default_cache_ttl() -> u64 {
     24 // 24 hours is reasonable for repo metadata
 }
 
+fn default_trending_ttl() -> u64 {
+    6 // trending lists shift within a day
+}
+
 fn default_cache_size() -> u64 {
     500 // 500MB should be plenty
 }
 
+fn default_cache_max_entries() -> usize {
+    reposcout_cache::DEFAULT_MAX_ENTRIES
+}
+
 impl Default for CacheConfig {
     fn default() -> Self {
         Self {
             ttl_hours: default_cache_ttl(),
+            trending_ttl_hours: default_trending_ttl(),
             max_size_mb: default_cache_size(),
             offline_mode: false,
+            max_entries: default_cache_max_entries(),
+            bypass: false,
         }
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UiConfig {
-    /// UI theme name (Default Dark, Light, Nord, Dracula, Gruvbox Dark)
+    /// UI theme name (Default Dark, Light, Nord, Dracula, Gruvbox Dark, High Contrast, ...)
     #[serde(default = "default_theme")]
     pub theme: String,
 
+    /// Syntax highlighting theme for code previews (one of syntect's
+    /// bundled themes, cycled with `t` in Code mode)
+    #[serde(default = "default_code_theme")]
+    pub code_theme: String,
+
     /// Enable mouse support in TUI
     #[serde(default = "default_mouse")]
     pub mouse_enabled: bool,
@@ -177,12 +242,29 @@ pub struct UiConfig {
     /// Enable portfolio/watchlist feature
     #[serde(default = "default_portfolio_enabled")]
     pub portfolio_enabled: bool,
+
+    /// Search as you type in the TUI instead of waiting for Enter (off by
+    /// default since every pause in typing spends API requests)
+    #[serde(default)]
+    pub live_search: bool,
+
+    /// Smallest terminal the TUI lays itself out in; below this it only
+    /// asks for a bigger window
+    #[serde(default = "default_min_width")]
+    pub min_width: u16,
+
+    #[serde(default = "default_min_height")]
+    pub min_height: u16,
 }
 
 fn default_theme() -> String {
     "Default Dark".to_string() // because who uses light theme in a terminal?
 }
 
+fn default_code_theme() -> String {
+    "base16-ocean.dark".to_string()
+}
+
 fn default_mouse() -> bool {
     true
 }
@@ -191,16 +273,120 @@ fn default_portfolio_enabled() -> bool {
     true // Enable portfolio feature by default
 }
 
+fn default_min_width() -> u16 {
+    40
+}
+
+fn default_min_height() -> u16 {
+    10
+}
+
 impl Default for UiConfig {
     fn default() -> Self {
         Self {
             theme: default_theme(),
+            code_theme: default_code_theme(),
             mouse_enabled: default_mouse(),
             portfolio_enabled: default_portfolio_enabled(),
+            live_search: false,
+            min_width: default_min_width(),
+            min_height: default_min_height(),
         }
     }
 }
 
+/// Defaults for search options, used when the matching CLI flag isn't given
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SearchConfig {
+    /// Number of results to show
+    #[serde(default)]
+    pub default_limit: Option<usize>,
+
+    /// Language filter (e.g. "rust")
+    #[serde(default)]
+    pub default_language: Option<String>,
+
+    /// Sort order: stars, forks, updated or trending-score
+    #[serde(default)]
+    pub default_sort: Option<String>,
+
+    /// How many repositories batch commands (show --repos-from-file, compare,
+    /// bookmark refresh) fetch at once, 4 when unset
+    #[serde(default)]
+    pub fetch_concurrency: Option<usize>,
+}
+
+/// HTTP settings shared by every API client
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkConfig {
+    /// Seconds before a request that hasn't completed is abandoned
+    #[serde(default = "default_timeout_secs")]
+    pub timeout_secs: u64,
+}
+
+fn default_timeout_secs() -> u64 {
+    reposcout_api::http::DEFAULT_TIMEOUT_SECS
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: default_timeout_secs(),
+        }
+    }
+}
+
+impl NetworkConfig {
+    /// Apply these settings to API clients created from now on
+    pub fn install(&self) {
+        reposcout_api::http::set_timeout(std::time::Duration::from_secs(self.timeout_secs));
+    }
+}
+
+/// Persistent result filters applied to every search, trending and discovery query
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FilterConfig {
+    /// Owners/orgs whose repositories are never shown (case-insensitive)
+    #[serde(default)]
+    pub blocked_owners: Vec<String>,
+}
+
+impl FilterConfig {
+    /// Check whether a repository's owner is on the blocklist
+    pub fn is_blocked(&self, full_name: &str) -> bool {
+        let owner = full_name.split('/').next().unwrap_or(full_name);
+        self.blocked_owners
+            .iter()
+            .any(|blocked| blocked.eq_ignore_ascii_case(owner))
+    }
+
+    /// Drop repositories from blocked owners
+    pub fn retain_allowed(&self, repos: &mut Vec<Repository>) {
+        if !self.blocked_owners.is_empty() {
+            repos.retain(|repo| !self.is_blocked(&repo.full_name));
+        }
+    }
+
+    /// Add an owner to the blocklist. Returns false if it was already blocked
+    pub fn block_owner(&mut self, owner: &str) -> bool {
+        let owner = owner.trim().trim_end_matches('/');
+        if owner.is_empty() || self.is_blocked(owner) {
+            return false;
+        }
+        self.blocked_owners.push(owner.to_string());
+        true
+    }
+
+    /// Remove an owner from the blocklist. Returns false if it wasn't blocked
+    pub fn unblock_owner(&mut self, owner: &str) -> bool {
+        let owner = owner.trim().trim_end_matches('/');
+        let before = self.blocked_owners.len();
+        self.blocked_owners
+            .retain(|blocked| !blocked.eq_ignore_ascii_case(owner));
+        self.blocked_owners.len() != before
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -209,6 +395,7 @@ mod tests {
     fn test_default_config() {
         let config = Config::default();
         assert_eq!(config.cache.ttl_hours, 24);
+        assert_eq!(config.cache.trending_ttl_hours, 6);
         assert_eq!(config.cache.max_size_mb, 500);
         assert_eq!(config.ui.theme, "Default Dark");
     }
@@ -220,4 +407,79 @@ mod tests {
         assert!(toml.contains("ttl_hours"));
         assert!(toml.contains("theme"));
     }
+
+    #[test]
+    fn test_partial_config_uses_defaults() {
+        let config = Config::parse(
+            "[search]\ndefault_limit = 25\ndefault_language = \"rust\"\n\n[ui]\ntheme = \"Nord\"\n",
+            "test",
+        )
+        .unwrap();
+        assert_eq!(config.search.default_limit, Some(25));
+        assert_eq!(config.search.default_language.as_deref(), Some("rust"));
+        assert_eq!(config.search.default_sort, None);
+        assert_eq!(config.ui.theme, "Nord");
+        assert_eq!(config.cache.ttl_hours, 24);
+        assert!(config.ui.mouse_enabled);
+        assert_eq!(config.network.timeout_secs, 15);
+
+        let config = Config::parse("[network]\ntimeout_secs = 40\n", "test").unwrap();
+        assert_eq!(config.network.timeout_secs, 40);
+    }
+
+    #[test]
+    fn test_malformed_config_names_key() {
+        let err = Config::parse("[search]\ndefault_limit = \"ten\"\n", "test")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("line 2"), "{}", err);
+        assert!(err.contains("default_limit"), "{}", err);
+    }
+
+    #[test]
+    fn test_health_weights() {
+        let config = Config::parse(
+            "[health]\nactivity = 40\ncommunity = 15\nresponsiveness = 20\nmaturity = 15\ndocumentation = 10\n",
+            "test",
+        )
+        .unwrap();
+        assert_eq!(config.health.activity, 40);
+        assert_eq!(config.health.community, 15);
+        assert_eq!(
+            Config::default().health,
+            crate::health::HealthWeights::default()
+        );
+
+        let err = Config::parse("[health]\nactivity = 50\n", "test")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("must add up to 100, got 120"), "{}", err);
+    }
+
+    #[test]
+    fn test_trending_score_weights() {
+        let config = Config::parse("[trending_score]\nvelocity_weight = 2.5\n", "test").unwrap();
+        assert_eq!(config.trending_score.velocity_weight, 2.5);
+        assert_eq!(config.trending_score.recency_half_life_days, 30.0);
+
+        let err = Config::parse("[trending_score]\nrecency_half_life_days = 0\n", "test")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("must be positive"), "{}", err);
+    }
+
+    #[test]
+    fn test_blocked_owners() {
+        let mut filters = FilterConfig::default();
+        assert!(filters.block_owner("SpamOrg"));
+        assert!(!filters.block_owner("spamorg"));
+
+        assert!(filters.is_blocked("spamorg/awesome-thing"));
+        assert!(filters.is_blocked("SPAMORG/sub/project"));
+        assert!(!filters.is_blocked("spamorgs/legit"));
+
+        assert!(filters.unblock_owner("spamorg"));
+        assert!(!filters.unblock_owner("spamorg"));
+        assert!(!filters.is_blocked("spamorg/awesome-thing"));
+    }
 }
@@ -1,5 +1,15 @@
 // Enhanced discovery features for finding interesting repositories
 use chrono::{Duration, Utc};
+use reposcout_api::GitHubClient;
+use std::collections::HashMap;
+
+use crate::Result;
+
+/// Search results whose topics are tallied for suggestions
+const SUGGESTION_SAMPLE_SIZE: u32 = 50;
+
+/// Most topics suggested for one seed
+const MAX_SUGGESTIONS: usize = 20;
 
 /// Build a search query for "New & Notable" - recently created repos gaining traction
 pub fn new_and_notable_query(language: Option<&str>, days_back: i64) -> String {
@@ -65,6 +75,64 @@ pub fn popular_topics() -> Vec<(&'static str, &'static str)> {
     ]
 }
 
+/// A topic related to a seed, found on repositories matching it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicSuggestion {
+    pub topic: String,
+    /// How many of the sampled repositories carry the topic
+    pub repos: usize,
+}
+
+/// Suggest topics related to `seed` from the topics of the most starred
+/// GitHub repositories matching it. Callers fall back to `popular_topics`
+/// when this fails, e.g. offline or rate limited.
+pub async fn suggest_topics(client: &GitHubClient, seed: &str) -> Result<Vec<TopicSuggestion>> {
+    crate::ensure_online("Topic suggestions")?;
+    let repos = client
+        .search_repositories(seed, SUGGESTION_SAMPLE_SIZE)
+        .await?;
+
+    Ok(rank_topics(
+        repos
+            .iter()
+            .map(|repo| (repo.topics.as_slice(), repo.stargazers_count)),
+        MAX_SUGGESTIONS,
+    ))
+}
+
+/// Rank topics by how many repositories use them, breaking ties by the
+/// combined stars of those repositories and then alphabetically
+pub fn rank_topics<'a>(
+    repos: impl IntoIterator<Item = (&'a [String], u32)>,
+    limit: usize,
+) -> Vec<TopicSuggestion> {
+    let mut tally: HashMap<&str, (usize, u64)> = HashMap::new();
+    for (topics, stars) in repos {
+        for topic in topics {
+            let entry = tally.entry(topic.as_str()).or_default();
+            entry.0 += 1;
+            entry.1 += u64::from(stars);
+        }
+    }
+
+    let mut ranked: Vec<_> = tally.into_iter().collect();
+    ranked.sort_by(|(a, (a_repos, a_stars)), (b, (b_repos, b_stars))| {
+        b_repos
+            .cmp(a_repos)
+            .then(b_stars.cmp(a_stars))
+            .then(a.cmp(b))
+    });
+
+    ranked
+        .into_iter()
+        .take(limit)
+        .map(|(topic, (repos, _))| TopicSuggestion {
+            topic: topic.to_string(),
+            repos,
+        })
+        .collect()
+}
+
 /// Popular awesome lists
 pub fn awesome_lists() -> Vec<(&'static str, &'static str)> {
     vec![
@@ -119,3 +187,28 @@ pub fn calculate_gem_score(
     let engagement_ratio = (forks + open_issues) as f64 / stars.max(1) as f64;
     engagement_ratio * recency_multiplier
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn topics(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_rank_topics_by_usage_then_stars() {
+        let repos = [
+            (topics(&["rust", "cli", "terminal"]), 100),
+            (topics(&["rust", "tui"]), 5000),
+            (topics(&["cli", "tui"]), 10),
+            (topics(&["rust"]), 1),
+        ];
+
+        let ranked = rank_topics(repos.iter().map(|(t, s)| (t.as_slice(), *s)), 3);
+        let names: Vec<_> = ranked.iter().map(|s| s.topic.as_str()).collect();
+        // tui and cli both appear twice; tui's repositories have more stars
+        assert_eq!(names, ["rust", "tui", "cli"]);
+        assert_eq!(ranked[0].repos, 3);
+    }
+}
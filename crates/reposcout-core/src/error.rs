@@ -1,13 +1,24 @@
+use crate::models::Platform;
+use reposcout_api::{
+    azure_devops::AzureDevOpsError, bitbucket::BitbucketError, github::GitHubError,
+    gitlab::GitLabError, retry::RetryHint, sourcehut::SourceHutError,
+};
+use std::time::Duration;
 use thiserror::Error;
 
 /// All the ways things can go wrong in RepoScout
 ///
 /// We use thiserror here because it generates the boilerplate for us.
 /// Life's too short to manually implement Display and Error traits.
+///
+/// API client errors are sorted into the variants below when they cross
+/// into core, so callers can match on what went wrong instead of digging
+/// through messages.
 #[derive(Error, Debug)]
 pub enum Error {
-    #[error("API request failed: {0}")]
-    ApiError(String),
+    /// A platform failed in a way none of the other variants cover
+    #[error("{platform}: {message}")]
+    Provider { platform: Platform, message: String },
 
     #[error("Cache operation failed: {0}")]
     CacheError(String),
@@ -15,24 +26,260 @@ pub enum Error {
     #[error("Configuration error: {0}")]
     ConfigError(String),
 
-    #[error("Repository not found: {0}")]
+    #[error("Not found: {0}")]
     NotFound(String),
 
-    #[error("Rate limit exceeded. Try again in {retry_after} seconds")]
-    RateLimitExceeded { retry_after: u64 },
+    #[error("Rate limit exceeded{}", retry_hint(.retry_after))]
+    RateLimited { retry_after: Option<Duration> },
 
-    #[error("Authentication failed: {0}")]
-    AuthError(String),
+    /// Missing or rejected credentials
+    #[error("{platform}: {message}")]
+    Unauthorized { platform: Platform, message: String },
 
+    /// The request never got an answer: no connection, DNS, reset
     #[error("Network error: {0}")]
-    NetworkError(#[from] reqwest::Error),
+    Network(String),
+
+    #[error(
+        "Request timed out after {}s (raise [network] timeout_secs on slow connections)",
+        reposcout_api::http::timeout().as_secs()
+    )]
+    Timeout,
 
-    #[error("Serialization error: {0}")]
-    SerializationError(#[from] serde_json::Error),
+    /// A response or file that doesn't have the expected shape
+    #[error("Parse error: {0}")]
+    Parse(String),
 
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
 
+    #[error("Offline mode: {0}")]
+    Offline(String),
+
     #[error("Unknown error occurred: {0}")]
     Unknown(String),
 }
+
+fn retry_hint(retry_after: &Option<Duration>) -> String {
+    match retry_after {
+        Some(wait) => format!(". Try again in {} seconds", wait.as_secs().max(1)),
+        None => String::new(),
+    }
+}
+
+impl Error {
+    /// Whether the same request could succeed later. Auth failures, missing
+    /// repositories and malformed responses won't change by retrying.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            Error::Network(_) | Error::Timeout | Error::RateLimited { .. }
+        )
+    }
+
+    /// How long the platform asked us to wait, when rate limited
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Error::RateLimited { retry_after } => *retry_after,
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            Error::Timeout
+        } else if error.is_decode() {
+            Error::Parse(error.to_string())
+        } else {
+            Error::Network(error.to_string())
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(error: serde_json::Error) -> Self {
+        Error::Parse(error.to_string())
+    }
+}
+
+impl From<GitHubError> for Error {
+    fn from(error: GitHubError) -> Self {
+        let retry_after = error.retry_after();
+        match error {
+            GitHubError::RateLimitExceeded { .. } => Error::RateLimited { retry_after },
+            GitHubError::NotFound(what) => Error::NotFound(what),
+            GitHubError::AuthRequired => unauthorized(Platform::GitHub, &error),
+            GitHubError::Timeout => Error::Timeout,
+            GitHubError::NetworkError(e) => Error::Network(e.to_string()),
+            GitHubError::ParseError(message) => Error::Parse(message),
+            GitHubError::RequestFailed(_) | GitHubError::Rejected(_) => {
+                provider(Platform::GitHub, &error)
+            }
+        }
+    }
+}
+
+impl From<GitLabError> for Error {
+    fn from(error: GitLabError) -> Self {
+        match error {
+            GitLabError::RateLimitExceeded { retry_after } => Error::RateLimited { retry_after },
+            GitLabError::NotFound(what) => Error::NotFound(what),
+            GitLabError::AuthRequired => unauthorized(Platform::GitLab, &error),
+            GitLabError::Timeout => Error::Timeout,
+            GitLabError::NetworkError(e) => Error::Network(e.to_string()),
+            GitLabError::ParseError(message) => Error::Parse(message),
+            GitLabError::RequestFailed(_) | GitLabError::Rejected(_) => {
+                provider(Platform::GitLab, &error)
+            }
+        }
+    }
+}
+
+impl From<BitbucketError> for Error {
+    fn from(error: BitbucketError) -> Self {
+        match error {
+            BitbucketError::RateLimitExceeded { retry_after } => Error::RateLimited { retry_after },
+            BitbucketError::NotFound(what) => Error::NotFound(what),
+            BitbucketError::AuthRequired => unauthorized(Platform::Bitbucket, &error),
+            BitbucketError::Timeout => Error::Timeout,
+            BitbucketError::NetworkError(e) => Error::Network(e.to_string()),
+            BitbucketError::ParseError(message) => Error::Parse(message),
+            BitbucketError::RequestFailed(_) | BitbucketError::Rejected(_) => {
+                provider(Platform::Bitbucket, &error)
+            }
+        }
+    }
+}
+
+impl From<SourceHutError> for Error {
+    fn from(error: SourceHutError) -> Self {
+        match error {
+            SourceHutError::RateLimitExceeded { retry_after } => Error::RateLimited { retry_after },
+            SourceHutError::NotFound(what) => Error::NotFound(what),
+            SourceHutError::AuthRequired => unauthorized(Platform::SourceHut, &error),
+            SourceHutError::Timeout => Error::Timeout,
+            SourceHutError::NetworkError(e) => Error::Network(e.to_string()),
+            SourceHutError::ParseError(message) => Error::Parse(message),
+            SourceHutError::RequestFailed(_)
+            | SourceHutError::Rejected(_)
+            | SourceHutError::GraphQL(_) => provider(Platform::SourceHut, &error),
+        }
+    }
+}
+
+impl From<AzureDevOpsError> for Error {
+    fn from(error: AzureDevOpsError) -> Self {
+        match error {
+            AzureDevOpsError::RateLimitExceeded { retry_after } => {
+                Error::RateLimited { retry_after }
+            }
+            AzureDevOpsError::NotFound(what) => Error::NotFound(what),
+            AzureDevOpsError::AuthRequired => unauthorized(Platform::AzureDevOps, &error),
+            AzureDevOpsError::OrganizationRequired => Error::ConfigError(error.to_string()),
+            AzureDevOpsError::Timeout => Error::Timeout,
+            AzureDevOpsError::NetworkError(e) => Error::Network(e.to_string()),
+            AzureDevOpsError::ParseError(message) => Error::Parse(message),
+            AzureDevOpsError::RequestFailed(_) | AzureDevOpsError::Rejected(_) => {
+                provider(Platform::AzureDevOps, &error)
+            }
+        }
+    }
+}
+
+/// Treat a missing file or repository as an answer rather than a failure:
+/// `NotFound` becomes `None`, every other error is kept
+pub fn missing_as_none<T, E: Into<Error>>(
+    result: std::result::Result<T, E>,
+) -> Result<Option<T>, Error> {
+    match result.map_err(Into::into) {
+        Ok(value) => Ok(Some(value)),
+        Err(Error::NotFound(_)) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn unauthorized(platform: Platform, error: &dyn std::fmt::Display) -> Error {
+    Error::Unauthorized {
+        platform,
+        message: error.to_string(),
+    }
+}
+
+fn provider(platform: Platform, error: &dyn std::fmt::Display) -> Error {
+    Error::Provider {
+        platform,
+        message: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_errors_map_to_variants() {
+        let error = Error::from(GitLabError::RateLimitExceeded {
+            retry_after: Some(Duration::from_secs(30)),
+        });
+        assert!(matches!(error, Error::RateLimited { .. }));
+        assert!(error.is_retryable());
+        assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+        assert_eq!(
+            error.to_string(),
+            "Rate limit exceeded. Try again in 30 seconds"
+        );
+
+        let error = Error::from(SourceHutError::AuthRequired);
+        assert!(matches!(
+            error,
+            Error::Unauthorized {
+                platform: Platform::SourceHut,
+                ..
+            }
+        ));
+        assert!(!error.is_retryable());
+        assert_eq!(
+            error.to_string(),
+            "SourceHut: Authentication required (set SOURCEHUT_TOKEN)"
+        );
+
+        assert!(matches!(
+            Error::from(GitHubError::NotFound("a/b".into())),
+            Error::NotFound(name) if name == "a/b"
+        ));
+        assert!(matches!(
+            Error::from(BitbucketError::Timeout),
+            Error::Timeout
+        ));
+        assert!(matches!(
+            Error::from(AzureDevOpsError::ParseError("bad".into())),
+            Error::Parse(_)
+        ));
+        assert!(matches!(
+            Error::from(GitHubError::Rejected("Status 422".into())),
+            Error::Provider {
+                platform: Platform::GitHub,
+                ..
+            }
+        ));
+        assert!(matches!(
+            Error::from(AzureDevOpsError::OrganizationRequired),
+            Error::ConfigError(_)
+        ));
+
+        let parse_error = serde_json::from_str::<u32>("x").unwrap_err();
+        assert!(matches!(Error::from(parse_error), Error::Parse(_)));
+    }
+
+    #[test]
+    fn test_missing_as_none() {
+        let found: std::result::Result<u32, GitHubError> = Ok(1);
+        assert_eq!(missing_as_none(found).unwrap(), Some(1));
+        let missing: std::result::Result<u32, _> = Err(GitHubError::NotFound("a/b".into()));
+        assert_eq!(missing_as_none(missing).unwrap(), None);
+        let failed: std::result::Result<u32, _> = Err(GitHubError::Timeout);
+        assert!(matches!(missing_as_none(failed), Err(Error::Timeout)));
+    }
+}
@@ -1,23 +1,31 @@
-use crate::{models::Repository, Error, Result};
+use crate::{models::Repository, portfolio::Portfolio, Error, Result};
+use reposcout_cache::SearchHistoryEntry;
+use serde::{Deserialize, Serialize};
 use serde_json;
 use std::fs::File;
-use std::io::Write;
+use std::io::{BufWriter, Write};
 use std::path::Path;
 
 /// Export format options
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExportFormat {
     Json,
+    Jsonl,
     Csv,
     Markdown,
+    Html,
+    Atom,
 }
 
 impl ExportFormat {
     pub fn from_extension(ext: &str) -> Option<Self> {
         match ext.to_lowercase().as_str() {
             "json" => Some(ExportFormat::Json),
+            "jsonl" | "ndjson" => Some(ExportFormat::Jsonl),
             "csv" => Some(ExportFormat::Csv),
             "md" | "markdown" => Some(ExportFormat::Markdown),
+            "html" | "htm" => Some(ExportFormat::Html),
+            "xml" | "atom" => Some(ExportFormat::Atom),
             _ => None,
         }
     }
@@ -25,12 +33,375 @@ impl ExportFormat {
     pub fn extension(&self) -> &'static str {
         match self {
             ExportFormat::Json => "json",
+            ExportFormat::Jsonl => "jsonl",
             ExportFormat::Csv => "csv",
             ExportFormat::Markdown => "md",
+            ExportFormat::Html => "html",
+            ExportFormat::Atom => "xml",
         }
     }
 }
 
+/// A column of CSV exports. `--columns` picks and orders them by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvColumn {
+    Platform,
+    FullName,
+    Description,
+    Stars,
+    Forks,
+    Watchers,
+    OpenIssues,
+    Language,
+    License,
+    CreatedAt,
+    UpdatedAt,
+    PushedAt,
+    HealthScore,
+    HealthStatus,
+    Maintenance,
+    Url,
+    StarsPerDay,
+}
+
+impl CsvColumn {
+    /// Every column, in the default export order
+    pub const ALL: [CsvColumn; 17] = [
+        CsvColumn::Platform,
+        CsvColumn::FullName,
+        CsvColumn::Description,
+        CsvColumn::Stars,
+        CsvColumn::Forks,
+        CsvColumn::Watchers,
+        CsvColumn::OpenIssues,
+        CsvColumn::Language,
+        CsvColumn::License,
+        CsvColumn::CreatedAt,
+        CsvColumn::UpdatedAt,
+        CsvColumn::PushedAt,
+        CsvColumn::HealthScore,
+        CsvColumn::HealthStatus,
+        CsvColumn::Maintenance,
+        CsvColumn::Url,
+        CsvColumn::StarsPerDay,
+    ];
+
+    /// Name used to select the column
+    pub fn name(&self) -> &'static str {
+        match self {
+            CsvColumn::Platform => "platform",
+            CsvColumn::FullName => "full_name",
+            CsvColumn::Description => "description",
+            CsvColumn::Stars => "stars",
+            CsvColumn::Forks => "forks",
+            CsvColumn::Watchers => "watchers",
+            CsvColumn::OpenIssues => "open_issues",
+            CsvColumn::Language => "language",
+            CsvColumn::License => "license",
+            CsvColumn::CreatedAt => "created_at",
+            CsvColumn::UpdatedAt => "updated_at",
+            CsvColumn::PushedAt => "pushed_at",
+            CsvColumn::HealthScore => "health_score",
+            CsvColumn::HealthStatus => "health_status",
+            CsvColumn::Maintenance => "maintenance",
+            CsvColumn::Url => "url",
+            CsvColumn::StarsPerDay => "stars_per_day",
+        }
+    }
+
+    /// Header row label
+    pub fn header(&self) -> &'static str {
+        match self {
+            CsvColumn::Platform => "Platform",
+            CsvColumn::FullName => "Name",
+            CsvColumn::Description => "Description",
+            CsvColumn::Stars => "Stars",
+            CsvColumn::Forks => "Forks",
+            CsvColumn::Watchers => "Watchers",
+            CsvColumn::OpenIssues => "Open Issues",
+            CsvColumn::Language => "Language",
+            CsvColumn::License => "License",
+            CsvColumn::CreatedAt => "Created At",
+            CsvColumn::UpdatedAt => "Updated At",
+            CsvColumn::PushedAt => "Pushed At",
+            CsvColumn::HealthScore => "Health Score",
+            CsvColumn::HealthStatus => "Health Status",
+            CsvColumn::Maintenance => "Maintenance Level",
+            CsvColumn::Url => "URL",
+            CsvColumn::StarsPerDay => "Stars Per Day",
+        }
+    }
+
+    /// The column's value for `repo`, before CSV escaping
+    pub fn value(&self, repo: &Repository) -> String {
+        match self {
+            CsvColumn::Platform => repo.platform.to_string(),
+            CsvColumn::FullName => repo.full_name.clone(),
+            CsvColumn::Description => repo.description.clone().unwrap_or_default(),
+            CsvColumn::Stars => repo.stars.to_string(),
+            CsvColumn::Forks => repo.forks.to_string(),
+            CsvColumn::Watchers => repo.watchers.to_string(),
+            CsvColumn::OpenIssues => repo.open_issues.to_string(),
+            CsvColumn::Language => repo.language.clone().unwrap_or_default(),
+            CsvColumn::License => repo.license.clone().unwrap_or_default(),
+            CsvColumn::CreatedAt => repo.created_at.format("%Y-%m-%d").to_string(),
+            CsvColumn::UpdatedAt => repo.updated_at.format("%Y-%m-%d").to_string(),
+            CsvColumn::PushedAt => repo.pushed_at.format("%Y-%m-%d").to_string(),
+            CsvColumn::HealthScore => repo
+                .health
+                .as_ref()
+                .map(|h| h.score.to_string())
+                .unwrap_or_default(),
+            CsvColumn::HealthStatus => repo
+                .health
+                .as_ref()
+                .map(|h| h.status.label().to_string())
+                .unwrap_or_default(),
+            CsvColumn::Maintenance => repo
+                .health
+                .as_ref()
+                .map(|h| h.maintenance.label().to_string())
+                .unwrap_or_default(),
+            CsvColumn::Url => repo.url.clone(),
+            CsvColumn::StarsPerDay => format!("{:.2}", repo.star_velocity()),
+        }
+    }
+
+    /// Comma-separated valid column names, for error messages
+    pub fn valid_names() -> String {
+        Self::ALL.map(|c| c.name()).join(", ")
+    }
+
+    /// Parse a comma-separated column list such as `full_name,stars,url`
+    pub fn parse_list(spec: &str) -> Result<Vec<CsvColumn>> {
+        let columns = spec
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<_>>>()?;
+        if columns.is_empty() {
+            return Err(Error::ConfigError(format!(
+                "No columns given. Valid columns: {}",
+                Self::valid_names()
+            )));
+        }
+        Ok(columns)
+    }
+}
+
+impl std::str::FromStr for CsvColumn {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|c| c.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                Error::ConfigError(format!(
+                    "Unknown column '{}'. Valid columns: {}",
+                    s,
+                    Self::valid_names()
+                ))
+            })
+    }
+}
+
+/// Fields computed at export time rather than stored on `Repository`
+const COMPUTED_JSON_FIELDS: &[&str] = &["star_velocity"];
+
+/// Every key a JSON export can contain, in struct order
+pub fn json_field_names() -> Vec<&'static str> {
+    let mut names = struct_field_names::<Repository>().to_vec();
+    names.extend_from_slice(COMPUTED_JSON_FIELDS);
+    names
+}
+
+/// Parse a comma-separated JSON field list such as `full_name,stars,url`
+pub fn parse_json_fields(spec: &str) -> Result<Vec<String>> {
+    let valid = json_field_names();
+    let fields = spec
+        .split(',')
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| {
+            valid
+                .iter()
+                .find(|field| field.eq_ignore_ascii_case(name))
+                .map(|field| field.to_string())
+                .ok_or_else(|| {
+                    Error::ConfigError(format!(
+                        "Unknown field '{}'. Valid fields: {}",
+                        name,
+                        valid.join(", ")
+                    ))
+                })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if fields.is_empty() {
+        return Err(Error::ConfigError(format!(
+            "No fields given. Valid fields: {}",
+            valid.join(", ")
+        )));
+    }
+    Ok(fields)
+}
+
+/// Field names of a struct as serde sees them, read from its derived
+/// `Deserialize` impl so the list can't drift from the struct
+fn struct_field_names<T: serde::de::DeserializeOwned>() -> &'static [&'static str] {
+    use serde::de::{self, Visitor};
+
+    struct FieldNames<'a>(&'a mut &'static [&'static str]);
+
+    impl<'de> de::Deserializer<'de> for FieldNames<'_> {
+        type Error = de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(
+            self,
+            _visitor: V,
+        ) -> std::result::Result<V::Value, Self::Error> {
+            Err(de::Error::custom("only struct field names are read"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _visitor: V,
+        ) -> std::result::Result<V::Value, Self::Error> {
+            *self.0 = fields;
+            Err(de::Error::custom("only struct field names are read"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map enum identifier ignored_any
+        }
+    }
+
+    let mut fields: &'static [&'static str] = &[];
+    let _ = T::deserialize(FieldNames(&mut fields));
+    fields
+}
+
+/// Inline stylesheet for HTML exports. Badge classes are the
+/// `HealthStatus::color_code` names.
+const HTML_STYLE: &str = "<style>
+body { font-family: -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; margin: 2rem; color: #1f2328; }
+header p { color: #59636e; margin: 0.25rem 0; }
+table { border-collapse: collapse; width: 100%; margin-top: 1.5rem; }
+th, td { padding: 0.5rem 0.75rem; border-bottom: 1px solid #d1d9e0; text-align: left; vertical-align: top; }
+th { background: #f6f8fa; cursor: pointer; user-select: none; white-space: nowrap; }
+th .sort { color: #8c959f; font-size: 0.8em; }
+tbody tr:hover { background: #f6f8fa; }
+td.num { text-align: right; white-space: nowrap; }
+a { color: #0969da; text-decoration: none; }
+a:hover { text-decoration: underline; }
+.badge { display: inline-block; padding: 0.1rem 0.5rem; border-radius: 1rem; font-size: 0.85em; color: #fff; white-space: nowrap; }
+.badge.green { background: #1a7f37; }
+.badge.yellow { background: #bf8700; }
+.badge.orange { background: #bc4c00; }
+.badge.red { background: #cf222e; }
+</style>
+";
+
+/// Columns of a search history CSV export
+const HISTORY_CSV_HEADER: [&str; 5] = [
+    "query",
+    "filters",
+    "result_count",
+    "searched_at",
+    "searched_at_human",
+];
+
+/// A search history entry as exported. The row id is local to one database,
+/// so it is left out.
+#[derive(Debug, Serialize, Deserialize)]
+struct HistoryRecord {
+    query: String,
+    filters: Option<String>,
+    result_count: Option<i64>,
+    /// Unix timestamp, the value imports use
+    searched_at: i64,
+    #[serde(default)]
+    searched_at_human: String,
+}
+
+impl From<&SearchHistoryEntry> for HistoryRecord {
+    fn from(entry: &SearchHistoryEntry) -> Self {
+        let searched_at_human = chrono::DateTime::from_timestamp(entry.searched_at, 0)
+            .map(|time| time.to_rfc3339_opts(chrono::SecondsFormat::Secs, true))
+            .unwrap_or_default();
+        Self {
+            query: entry.query.clone(),
+            filters: entry.filters.clone(),
+            result_count: entry.result_count,
+            searched_at: entry.searched_at,
+            searched_at_human,
+        }
+    }
+}
+
+impl HistoryRecord {
+    fn from_csv_row(row: &[String], line: usize) -> Result<Self> {
+        let invalid = |what: &str| Error::ConfigError(format!("Line {}: {}", line, what));
+        let [query, filters, result_count, searched_at, human] = row else {
+            return Err(invalid(&format!(
+                "expected {} columns, found {}",
+                HISTORY_CSV_HEADER.len(),
+                row.len()
+            )));
+        };
+        let optional = |s: &String| (!s.is_empty()).then(|| s.clone());
+
+        Ok(Self {
+            query: query.clone(),
+            filters: optional(filters),
+            result_count: match optional(result_count) {
+                Some(count) => Some(count.parse().map_err(|_| invalid("invalid result_count"))?),
+                None => None,
+            },
+            searched_at: searched_at
+                .parse()
+                .map_err(|_| invalid("invalid searched_at"))?,
+            searched_at_human: human.clone(),
+        })
+    }
+}
+
+/// Split CSV into rows of unescaped fields, the inverse of `Exporter::csv_row`
+fn parse_csv(content: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            '\r' if !quoted => {}
+            c => field.push(c),
+        }
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    rows
+}
+
 /// Exporter for repository data
 pub struct Exporter;
 
@@ -38,20 +409,470 @@ impl Exporter {
     /// Export repositories to a file with automatic format detection
     pub fn export_to_file<P: AsRef<Path>>(repos: &[Repository], path: P) -> Result<()> {
         let path = path.as_ref();
+        Self::export_to_file_with_format(repos, path, Self::detect_format(path)?)
+    }
 
-        // Detect format from extension
-        let format = path
-            .extension()
+    /// Export search results, recording the query in formats that have a
+    /// header for it (HTML). `columns` picks the CSV columns and `fields` the
+    /// JSON/JSONL keys (see `parse_json_fields`), None for all
+    pub fn export_search_results<P: AsRef<Path>>(
+        repos: &[Repository],
+        path: P,
+        query: &str,
+        columns: Option<&[CsvColumn]>,
+        fields: Option<&[String]>,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let format = Self::detect_format(path)?;
+        if let Some(fields) = fields {
+            return match format {
+                ExportFormat::Json => {
+                    Self::write_file(path, &Self::to_json_with_fields(repos, fields)?)
+                }
+                ExportFormat::Jsonl => {
+                    let file = File::create(path)
+                        .map_err(|e| Error::ConfigError(format!("Failed to create file: {}", e)))?;
+                    Self::write_jsonl_with_fields(repos, Some(fields), BufWriter::new(file))
+                }
+                format => Err(Error::ConfigError(format!(
+                    "Field selection only applies to JSON and JSONL exports, not .{}",
+                    format.extension()
+                ))),
+            };
+        }
+        if let Some(columns) = columns {
+            if format != ExportFormat::Csv {
+                return Err(Error::ConfigError(format!(
+                    "Column selection only applies to CSV exports, not .{}",
+                    format.extension()
+                )));
+            }
+            return Self::write_file(path, &Self::to_csv_with_columns(repos, columns)?);
+        }
+        match format {
+            ExportFormat::Html => Self::write_file(path, &Self::to_html(repos, Some(query))),
+            ExportFormat::Atom => Self::write_file(
+                path,
+                &Self::to_atom(repos, &format!("RepoScout: {}", query)),
+            ),
+            format => Self::export_to_file_with_format(repos, path, format),
+        }
+    }
+
+    /// Add search results to an existing export instead of replacing it.
+    ///
+    /// JSON and JSONL merge into the records already there, skipping
+    /// repositories they hold (same full_name and platform). CSV adds rows
+    /// under the existing header, skipping repositories already listed when it
+    /// has Name and Platform columns. Markdown gets a new section for `query`.
+    /// A missing or empty file is written as by `export_search_results`.
+    /// Exports with different fields or columns than the file are refused.
+    /// Returns how many repositories were added.
+    pub fn append_search_results<P: AsRef<Path>>(
+        repos: &[Repository],
+        path: P,
+        query: &str,
+        columns: Option<&[CsvColumn]>,
+        fields: Option<&[String]>,
+    ) -> Result<usize> {
+        let path = path.as_ref();
+        let existing = match std::fs::read_to_string(path) {
+            Ok(existing) => existing,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => {
+                return Err(Error::ConfigError(format!(
+                    "Failed to read {}: {}",
+                    path.display(),
+                    e
+                )))
+            }
+        };
+        if existing.trim().is_empty() {
+            Self::export_search_results(repos, path, query, columns, fields)?;
+            return Ok(repos.len());
+        }
+
+        let format = Self::detect_format(path)?;
+        if fields.is_some() && !matches!(format, ExportFormat::Json | ExportFormat::Jsonl) {
+            return Err(Error::ConfigError(format!(
+                "Field selection only applies to JSON and JSONL exports, not .{}",
+                format.extension()
+            )));
+        }
+        if columns.is_some() && format != ExportFormat::Csv {
+            return Err(Error::ConfigError(format!(
+                "Column selection only applies to CSV exports, not .{}",
+                format.extension()
+            )));
+        }
+
+        match format {
+            ExportFormat::Json => {
+                let mut records: Vec<serde_json::Value> =
+                    serde_json::from_str(&existing).map_err(|_| {
+                        Error::ConfigError(format!(
+                            "Can't append to {}: it isn't a JSON array of repositories",
+                            path.display()
+                        ))
+                    })?;
+                let added = Self::merge_json_records(&mut records, repos, fields, path)?;
+                let json = serde_json::to_string_pretty(&records)
+                    .map_err(|e| Error::ConfigError(format!("Failed to serialize JSON: {}", e)))?;
+                Self::write_file(path, &json)?;
+                Ok(added)
+            }
+            ExportFormat::Jsonl => {
+                let mut records = existing
+                    .lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(serde_json::from_str)
+                    .collect::<std::result::Result<Vec<serde_json::Value>, _>>()
+                    .map_err(|_| {
+                        Error::ConfigError(format!(
+                            "Can't append to {}: it isn't JSON lines of repositories",
+                            path.display()
+                        ))
+                    })?;
+                let before = records.len();
+                let added = Self::merge_json_records(&mut records, repos, fields, path)?;
+
+                let mut content = String::new();
+                if !existing.ends_with('\n') {
+                    content.push('\n');
+                }
+                for record in &records[before..] {
+                    content.push_str(&record.to_string());
+                    content.push('\n');
+                }
+                Self::append_file(path, &content)?;
+                Ok(added)
+            }
+            ExportFormat::Csv => {
+                let columns = columns.unwrap_or(&CsvColumn::ALL);
+                let rows = parse_csv(&existing);
+                let header: Vec<&str> = columns.iter().map(CsvColumn::header).collect();
+                if rows[0] != header {
+                    return Err(Error::ConfigError(format!(
+                        "Can't append to {}: its columns are {}, this export's are {}. \
+                         Append with the same --columns",
+                        path.display(),
+                        rows[0].join(","),
+                        header.join(",")
+                    )));
+                }
+
+                // Skip repositories already listed, when the file says which they are
+                let position = |column: CsvColumn| columns.iter().position(|&c| c == column);
+                let identity = position(CsvColumn::FullName).zip(position(CsvColumn::Platform));
+                let mut seen: std::collections::HashSet<(String, String)> = identity
+                    .map(|(name, platform)| {
+                        rows[1..]
+                            .iter()
+                            .filter(|row| row.len() > name.max(platform))
+                            .map(|row| (row[name].clone(), row[platform].clone()))
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let mut content = String::new();
+                if !existing.ends_with('\n') {
+                    content.push('\n');
+                }
+                let mut added = 0;
+                for repo in repos {
+                    let key = (
+                        CsvColumn::FullName.value(repo),
+                        CsvColumn::Platform.value(repo),
+                    );
+                    if identity.is_some() && !seen.insert(key) {
+                        continue;
+                    }
+                    content.push_str(&Self::csv_row(columns.iter().map(|c| c.value(repo))));
+                    added += 1;
+                }
+                Self::append_file(path, &content)?;
+                Ok(added)
+            }
+            ExportFormat::Markdown => {
+                let section = Self::to_markdown(repos).replacen(
+                    "# Repository Search Results",
+                    &format!("# Repository Search Results: {}", query),
+                    1,
+                );
+                let separator = if existing.ends_with("\n\n") { "" } else { "\n" };
+                Self::append_file(path, &format!("{}{}", separator, section))?;
+                Ok(repos.len())
+            }
+            ExportFormat::Html | ExportFormat::Atom => Err(Error::ConfigError(format!(
+                "Can't append to .{} exports; use .json, .jsonl, .csv or .md",
+                format.extension()
+            ))),
+        }
+    }
+
+    /// Add repositories to parsed JSON records unless they're already there,
+    /// refusing records shaped differently from this export's
+    fn merge_json_records(
+        records: &mut Vec<serde_json::Value>,
+        repos: &[Repository],
+        fields: Option<&[String]>,
+        path: &Path,
+    ) -> Result<usize> {
+        let identity = |record: &serde_json::Value| {
+            let name = record.get("full_name")?.as_str()?.to_string();
+            let platform = record.get("platform").map(|p| p.to_string());
+            Some((name, platform))
+        };
+
+        let new_records = repos
+            .iter()
+            .map(|repo| Self::to_json_value(repo, fields))
+            .collect::<Result<Vec<_>>>()?;
+        if let Some(first) = new_records.first() {
+            let expected = Self::required_json_keys(first, fields);
+            let valid = json_field_names();
+            let compatible = records.iter().all(|record| {
+                record.as_object().is_some_and(|object| match fields {
+                    Some(_) => object.keys().eq(expected.iter()),
+                    None => {
+                        expected.iter().all(|key| object.contains_key(key))
+                            && object.keys().all(|key| valid.contains(&key.as_str()))
+                    }
+                })
+            });
+            if !compatible {
+                return Err(Error::ConfigError(format!(
+                    "Can't append to {}: its records have different fields than this export. \
+                     Append with the same --fields",
+                    path.display()
+                )));
+            }
+        }
+
+        let mut seen: std::collections::HashSet<_> = records.iter().filter_map(identity).collect();
+        let mut added = 0;
+        for record in new_records {
+            if identity(&record).is_some_and(|key| !seen.insert(key)) {
+                continue;
+            }
+            records.push(record);
+            added += 1;
+        }
+        Ok(added)
+    }
+
+    /// Keys every record of an export has: the chosen fields, or for a full
+    /// export those of `record` that are never skipped when empty
+    fn required_json_keys(
+        record: &serde_json::Value,
+        fields: Option<&[String]>,
+    ) -> std::collections::BTreeSet<String> {
+        if let Some(fields) = fields {
+            return fields.iter().cloned().collect();
+        }
+        const SKIPPED_WHEN_EMPTY: [&str; 6] = [
+            "open_merge_requests",
+            "languages",
+            "contributors",
+            "commit_activity",
+            "mirrors",
+            "health",
+        ];
+        record
+            .as_object()
+            .into_iter()
+            .flat_map(|object| object.keys())
+            .filter(|key| !SKIPPED_WHEN_EMPTY.contains(&key.as_str()))
+            .cloned()
+            .collect()
+    }
+
+    /// Export trending results, using `title` as the feed title for Atom
+    pub fn export_trending_results<P: AsRef<Path>>(
+        repos: &[Repository],
+        path: P,
+        title: &str,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        match Self::detect_format(path)? {
+            ExportFormat::Atom => Self::write_file(path, &Self::to_atom(repos, title)),
+            format => Self::export_to_file_with_format(repos, path, format),
+        }
+    }
+
+    /// Export a portfolio with its notes as JSON (re-importable) or Markdown
+    pub fn export_portfolio<P: AsRef<Path>>(portfolio: &Portfolio, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let content = match Self::detect_format(path)? {
+            ExportFormat::Json => serde_json::to_string_pretty(portfolio)
+                .map_err(|e| Error::ConfigError(format!("JSON serialization failed: {}", e)))?,
+            ExportFormat::Markdown => Self::portfolio_to_markdown(portfolio),
+            format => {
+                return Err(Error::ConfigError(format!(
+                    "Portfolios export to .json or .md, not .{}",
+                    format.extension()
+                )))
+            }
+        };
+        Self::write_file(path, &content)
+    }
+
+    /// Export search history as JSON or CSV, both re-importable with
+    /// `read_search_history`
+    pub fn export_search_history<P: AsRef<Path>>(
+        entries: &[SearchHistoryEntry],
+        path: P,
+    ) -> Result<()> {
+        let path = path.as_ref();
+        let content = match Self::detect_format(path)? {
+            ExportFormat::Json => Self::search_history_to_json(entries)?,
+            ExportFormat::Csv => Self::search_history_to_csv(entries),
+            format => {
+                return Err(Error::ConfigError(format!(
+                    "Search history exports to .json or .csv, not .{}",
+                    format.extension()
+                )))
+            }
+        };
+        Self::write_file(path, &content)
+    }
+
+    /// Search history as a JSON array, with each time both as a Unix
+    /// timestamp and as RFC 3339
+    pub fn search_history_to_json(entries: &[SearchHistoryEntry]) -> Result<String> {
+        let records: Vec<HistoryRecord> = entries.iter().map(HistoryRecord::from).collect();
+        serde_json::to_string_pretty(&records)
+            .map_err(|e| Error::ConfigError(format!("JSON serialization failed: {}", e)))
+    }
+
+    /// Search history as CSV, one row per search
+    pub fn search_history_to_csv(entries: &[SearchHistoryEntry]) -> String {
+        let mut csv = Self::csv_row(HISTORY_CSV_HEADER);
+        for record in entries.iter().map(HistoryRecord::from) {
+            csv.push_str(&Self::csv_row([
+                record.query,
+                record.filters.unwrap_or_default(),
+                record
+                    .result_count
+                    .map(|count| count.to_string())
+                    .unwrap_or_default(),
+                record.searched_at.to_string(),
+                record.searched_at_human,
+            ]));
+        }
+        csv
+    }
+
+    /// Read search history written by `export_search_history`, format from
+    /// the extension
+    pub fn read_search_history<P: AsRef<Path>>(path: P) -> Result<Vec<SearchHistoryEntry>> {
+        let path = path.as_ref();
+        let format = Self::detect_format(path)?;
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::ConfigError(format!("Failed to read file: {}", e)))?;
+        Self::parse_search_history(&content, format)
+    }
+
+    /// Parse an exported search history. Only the Unix timestamp is read
+    /// back; the human-readable time is for people looking at the file.
+    pub fn parse_search_history(
+        content: &str,
+        format: ExportFormat,
+    ) -> Result<Vec<SearchHistoryEntry>> {
+        let records: Vec<HistoryRecord> = match format {
+            ExportFormat::Json => serde_json::from_str(content)
+                .map_err(|e| Error::ConfigError(format!("Invalid history JSON: {}", e)))?,
+            ExportFormat::Csv => {
+                let mut rows = parse_csv(content).into_iter();
+                if rows
+                    .next()
+                    .map_or(true, |header| header != HISTORY_CSV_HEADER)
+                {
+                    return Err(Error::ConfigError(format!(
+                        "Expected a history CSV with the columns {}",
+                        HISTORY_CSV_HEADER.join(",")
+                    )));
+                }
+                rows.enumerate()
+                    .map(|(i, row)| HistoryRecord::from_csv_row(&row, i + 2))
+                    .collect::<Result<_>>()?
+            }
+            format => {
+                return Err(Error::ConfigError(format!(
+                    "Search history imports from .json or .csv, not .{}",
+                    format.extension()
+                )))
+            }
+        };
+
+        Ok(records
+            .into_iter()
+            .map(|record| SearchHistoryEntry {
+                id: 0,
+                query: record.query,
+                filters: record.filters,
+                result_count: record.result_count,
+                searched_at: record.searched_at,
+            })
+            .collect())
+    }
+
+    /// Portfolio as a Markdown document: a header, then one table row per repo
+    pub fn portfolio_to_markdown(portfolio: &Portfolio) -> String {
+        let mut output = format!(
+            "# {} {}
+
+",
+            portfolio.icon.as_emoji(),
+            portfolio.name
+        );
+        output.push_str(&format!(
+            "**Color:** {} | **Repositories:** {} | **Total stars:** {} | **Updated:** {}\n\n",
+            portfolio.color.as_str(),
+            portfolio.repo_count(),
+            Self::format_number(portfolio.total_stars()),
+            portfolio.updated_at.format("%Y-%m-%d")
+        ));
+        if let Some(description) = &portfolio.description {
+            output.push_str(&format!("{}\n\n", description));
+        }
+        if portfolio.repos.is_empty() {
+            output.push_str("_No repositories yet._\n");
+            return output;
+        }
+
+        output.push_str("| Repository | Platform | ⭐ Stars | Description | Notes |\n");
+        output.push_str("|------------|----------|---------|-------------|-------|\n");
+        for watched in &portfolio.repos {
+            let repo = &watched.repo;
+            let mut notes = watched.notes.clone().unwrap_or_default();
+            if !watched.tags.is_empty() {
+                let tags: Vec<String> = watched.tags.iter().map(|t| format!("`{}`", t)).collect();
+                notes = format!("{} {}", notes, tags.join(" ")).trim().to_string();
+            }
+            output.push_str(&format!(
+                "| [{}]({}) | {} | {} | {} | {} |\n",
+                repo.full_name,
+                repo.url,
+                repo.platform,
+                Self::format_number(repo.stars),
+                Self::escape_markdown_cell(repo.description.as_deref().unwrap_or("")),
+                Self::escape_markdown_cell(&notes)
+            ));
+        }
+        output
+    }
+
+    fn detect_format(path: &Path) -> Result<ExportFormat> {
+        path.extension()
             .and_then(|e| e.to_str())
             .and_then(ExportFormat::from_extension)
             .ok_or_else(|| {
                 Error::ConfigError(
-                    "Could not determine export format from extension. Use .json, .csv, or .md"
+                    "Could not determine export format from extension. Use .json, .jsonl, .csv, .md, .html, or .xml"
                         .to_string(),
                 )
-            })?;
-
-        Self::export_to_file_with_format(repos, path, format)
+            })
     }
 
     /// Export repositories to a file with explicit format
@@ -64,8 +885,31 @@ impl Exporter {
             ExportFormat::Json => Self::to_json(repos)?,
             ExportFormat::Csv => Self::to_csv(repos)?,
             ExportFormat::Markdown => Self::to_markdown(repos),
+            ExportFormat::Html => Self::to_html(repos, None),
+            ExportFormat::Atom => Self::to_atom(repos, "RepoScout Results"),
+            ExportFormat::Jsonl => {
+                let file = File::create(path)
+                    .map_err(|e| Error::ConfigError(format!("Failed to create file: {}", e)))?;
+                return Self::write_jsonl(repos, BufWriter::new(file));
+            }
         };
 
+        Self::write_file(path.as_ref(), &content)
+    }
+
+    fn append_file(path: &Path, content: &str) -> Result<()> {
+        let mut file = std::fs::OpenOptions::new()
+            .append(true)
+            .open(path)
+            .map_err(|e| Error::ConfigError(format!("Failed to open file: {}", e)))?;
+
+        file.write_all(content.as_bytes())
+            .map_err(|e| Error::ConfigError(format!("Failed to write file: {}", e)))?;
+
+        Ok(())
+    }
+
+    fn write_file(path: &Path, content: &str) -> Result<()> {
         let mut file = File::create(path)
             .map_err(|e| Error::ConfigError(format!("Failed to create file: {}", e)))?;
 
@@ -77,58 +921,102 @@ impl Exporter {
 
     /// Export repositories to JSON format
     pub fn to_json(repos: &[Repository]) -> Result<String> {
-        serde_json::to_string_pretty(repos)
+        Self::json_array(repos, None)
+    }
+
+    /// Export repositories to JSON keeping only `fields` (names from
+    /// `parse_json_fields`). A field a repository lacks comes out as null.
+    pub fn to_json_with_fields(repos: &[Repository], fields: &[String]) -> Result<String> {
+        Self::json_array(repos, Some(fields))
+    }
+
+    fn json_array(repos: &[Repository], fields: Option<&[String]>) -> Result<String> {
+        let values = repos
+            .iter()
+            .map(|repo| Self::to_json_value(repo, fields))
+            .collect::<Result<Vec<_>>>()?;
+        serde_json::to_string_pretty(&values)
             .map_err(|e| Error::ConfigError(format!("Failed to serialize JSON: {}", e)))
     }
 
+    /// A repository as exported JSON: its fields plus computed star velocity,
+    /// projected down to `fields` if given
+    fn to_json_value(repo: &Repository, fields: Option<&[String]>) -> Result<serde_json::Value> {
+        let mut value = serde_json::to_value(repo)
+            .map_err(|e| Error::ConfigError(format!("Failed to serialize JSON: {}", e)))?;
+        let Some(object) = value.as_object_mut() else {
+            return Ok(value);
+        };
+        let velocity = (repo.star_velocity() * 100.0).round() / 100.0;
+        object.insert("star_velocity".to_string(), velocity.into());
+
+        let Some(fields) = fields else {
+            return Ok(value);
+        };
+        let projected: serde_json::Map<_, _> = fields
+            .iter()
+            .map(|field| {
+                let value = object.remove(field).unwrap_or(serde_json::Value::Null);
+                (field.clone(), value)
+            })
+            .collect();
+        Ok(projected.into())
+    }
+
+    /// Write repositories as JSON lines (one compact object per line).
+    ///
+    /// Each line is flushed as it's written so large exports stream.
+    pub fn write_jsonl<W: Write>(repos: &[Repository], writer: W) -> Result<()> {
+        Self::write_jsonl_with_fields(repos, None, writer)
+    }
+
+    /// Write repositories as JSON lines, keeping only `fields` if given
+    pub fn write_jsonl_with_fields<W: Write>(
+        repos: &[Repository],
+        fields: Option<&[String]>,
+        mut writer: W,
+    ) -> Result<()> {
+        for repo in repos {
+            serde_json::to_writer(&mut writer, &Self::to_json_value(repo, fields)?)
+                .map_err(|e| Error::ConfigError(format!("Failed to serialize JSON: {}", e)))?;
+            writer
+                .write_all(b"\n")
+                .and_then(|_| writer.flush())
+                .map_err(|e| Error::ConfigError(format!("Failed to write file: {}", e)))?;
+        }
+        Ok(())
+    }
+
     /// Export repositories to CSV format
     pub fn to_csv(repos: &[Repository]) -> Result<String> {
-        let mut output = String::new();
+        Self::to_csv_with_columns(repos, &CsvColumn::ALL)
+    }
 
-        // CSV Header
-        output.push_str(
-            "Platform,Name,Description,Stars,Forks,Watchers,Open Issues,Language,License,\
-             Created At,Updated At,Pushed At,Health Score,Health Status,Maintenance Level,URL\n",
-        );
+    /// Export repositories to CSV with the given columns, in that order
+    pub fn to_csv_with_columns(repos: &[Repository], columns: &[CsvColumn]) -> Result<String> {
+        let header: Vec<&str> = columns.iter().map(CsvColumn::header).collect();
+        let mut output = Self::csv_row(header);
 
-        // CSV Rows
         for repo in repos {
-            let health_score = repo
-                .health
-                .as_ref()
-                .map(|h| h.score.to_string())
-                .unwrap_or_default();
-            let health_status = repo.health.as_ref().map(|h| h.status.label()).unwrap_or("");
-            let maintenance = repo
-                .health
-                .as_ref()
-                .map(|h| h.maintenance.label())
-                .unwrap_or("");
-
-            output.push_str(&format!(
-                "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}\n",
-                repo.platform,
-                Self::escape_csv(&repo.full_name),
-                Self::escape_csv(repo.description.as_deref().unwrap_or("")),
-                repo.stars,
-                repo.forks,
-                repo.watchers,
-                repo.open_issues,
-                repo.language.as_deref().unwrap_or(""),
-                repo.license.as_deref().unwrap_or(""),
-                repo.created_at.format("%Y-%m-%d"),
-                repo.updated_at.format("%Y-%m-%d"),
-                repo.pushed_at.format("%Y-%m-%d"),
-                health_score,
-                health_status,
-                maintenance,
-                repo.url,
-            ));
+            output.push_str(&Self::csv_row(columns.iter().map(|c| c.value(repo))));
         }
 
         Ok(output)
     }
 
+    /// One CSV line from unescaped fields
+    pub fn csv_row<I, S>(fields: I) -> String
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let fields: Vec<String> = fields
+            .into_iter()
+            .map(|field| Self::escape_csv(field.as_ref()))
+            .collect();
+        format!("{}\n", fields.join(","))
+    }
+
     /// Export repositories to Markdown format
     pub fn to_markdown(repos: &[Repository]) -> String {
         let mut output = String::new();
@@ -231,24 +1119,24 @@ impl Exporter {
                 output.push_str("| Score Component | Value |\n");
                 output.push_str("|-----------------|-------|\n");
                 output.push_str(&format!(
-                    "| Activity | {}/30 |\n",
-                    health.metrics.activity_score
+                    "| Activity | {}/{} |\n",
+                    health.metrics.activity_score, health.weights.activity
                 ));
                 output.push_str(&format!(
-                    "| Community | {}/25 |\n",
-                    health.metrics.community_score
+                    "| Community | {}/{} |\n",
+                    health.metrics.community_score, health.weights.community
                 ));
                 output.push_str(&format!(
-                    "| Responsiveness | {}/20 |\n",
-                    health.metrics.responsiveness_score
+                    "| Responsiveness | {}/{} |\n",
+                    health.metrics.responsiveness_score, health.weights.responsiveness
                 ));
                 output.push_str(&format!(
-                    "| Maturity | {}/15 |\n",
-                    health.metrics.maturity_score
+                    "| Maturity | {}/{} |\n",
+                    health.metrics.maturity_score, health.weights.maturity
                 ));
                 output.push_str(&format!(
-                    "| Documentation | {}/10 |\n",
-                    health.metrics.documentation_score
+                    "| Documentation | {}/{} |\n",
+                    health.metrics.documentation_score, health.weights.documentation
                 ));
             }
 
@@ -297,9 +1185,167 @@ impl Exporter {
         output
     }
 
+    /// Export repositories as a self-contained HTML page (inline CSS, no
+    /// external assets) with a header showing the query and export time
+    pub fn to_html(repos: &[Repository], query: Option<&str>) -> String {
+        let mut output = String::new();
+
+        output.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+        output.push_str("<title>RepoScout Results</title>\n");
+        output.push_str(HTML_STYLE);
+        output.push_str("</head>\n<body>\n<header>\n<h1>Repository Search Results</h1>\n");
+        if let Some(query) = query {
+            output.push_str(&format!(
+                "<p>Query: <code>{}</code></p>\n",
+                Self::escape_html(query)
+            ));
+        }
+        output.push_str(&format!(
+            "<p>Generated {} &middot; {} repositories</p>\n</header>\n",
+            chrono::Utc::now().format("%Y-%m-%d %H:%M UTC"),
+            repos.len()
+        ));
+
+        output.push_str("<table>\n<thead>\n<tr>");
+        for column in [
+            "#",
+            "Repository",
+            "Platform",
+            "Stars",
+            "Forks",
+            "Language",
+            "Health",
+            "Description",
+        ] {
+            output.push_str(&format!(
+                "<th>{} <span class=\"sort\">&#8597;</span></th>",
+                column
+            ));
+        }
+        output.push_str("</tr>\n</thead>\n<tbody>\n");
+
+        for (i, repo) in repos.iter().enumerate() {
+            let health = match &repo.health {
+                Some(health) => format!(
+                    "<span class=\"badge {}\">{} {}</span>",
+                    health.status.color_code(),
+                    health.status.label(),
+                    health.score
+                ),
+                None => "&ndash;".to_string(),
+            };
+
+            output.push_str(&format!(
+                "<tr><td class=\"num\">{}</td><td><a href=\"{}\">{}</a></td><td>{}</td>\
+                 <td class=\"num\">⭐ {}</td><td class=\"num\">🍴 {}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                i + 1,
+                Self::escape_html(&repo.url),
+                Self::escape_html(&repo.full_name),
+                repo.platform,
+                Self::format_number(repo.stars),
+                Self::format_number(repo.forks),
+                Self::escape_html(repo.language.as_deref().unwrap_or("")),
+                health,
+                Self::escape_html(repo.description.as_deref().unwrap_or("")),
+            ));
+        }
+
+        output.push_str("</tbody>\n</table>\n</body>\n</html>\n");
+        output
+    }
+
+    /// Export repositories as an Atom feed. Entry ids are derived from the
+    /// repository URL so feed readers recognise the same repo across exports.
+    pub fn to_atom(repos: &[Repository], title: &str) -> String {
+        let updated = repos
+            .iter()
+            .map(|r| r.pushed_at)
+            .max()
+            .unwrap_or_else(chrono::Utc::now);
+
+        let mut output = String::new();
+        output.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        output.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+        output.push_str(&format!("  <title>{}</title>\n", Self::escape_html(title)));
+        output.push_str(&format!(
+            "  <id>urn:reposcout:feed:{}</id>\n",
+            Self::escape_html(&title.to_lowercase().replace(' ', "-"))
+        ));
+        output.push_str(&format!(
+            "  <updated>{}</updated>\n",
+            updated.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        ));
+        output.push_str("  <generator>RepoScout</generator>\n");
+
+        for repo in repos {
+            let mut summary = String::new();
+            if let Some(description) = &repo.description {
+                summary.push_str(description);
+                summary.push_str(" \u{2014} ");
+            }
+            summary.push_str(&format!(
+                "⭐ {} stars ({:.1}/day)",
+                Self::format_number(repo.stars),
+                repo.star_velocity()
+            ));
+            if let Some(language) = &repo.language {
+                summary.push_str(&format!(" · {}", language));
+            }
+
+            output.push_str("  <entry>\n");
+            output.push_str(&format!(
+                "    <title>{}</title>\n",
+                Self::escape_html(&repo.full_name)
+            ));
+            output.push_str(&format!(
+                "    <link href=\"{}\"/>\n",
+                Self::escape_html(&repo.url)
+            ));
+            output.push_str(&format!("    <id>{}</id>\n", Self::escape_html(&repo.url)));
+            output.push_str(&format!(
+                "    <updated>{}</updated>\n",
+                repo.pushed_at
+                    .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+            ));
+            output.push_str(&format!(
+                "    <author><name>{}</name></author>\n",
+                Self::escape_html(repo.full_name.split('/').next().unwrap_or(&repo.full_name))
+            ));
+            output.push_str(&format!(
+                "    <summary>{}</summary>\n",
+                Self::escape_html(&summary)
+            ));
+            output.push_str("  </entry>\n");
+        }
+
+        output.push_str("</feed>\n");
+        output
+    }
+
+    /// Escape HTML special characters (safe in both text and attributes)
+    pub(crate) fn escape_html(s: &str) -> String {
+        let mut escaped = String::with_capacity(s.len());
+        for c in s.chars() {
+            match c {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&#39;"),
+                c => escaped.push(c),
+            }
+        }
+        escaped
+    }
+
+    /// Keep free text from breaking out of a Markdown table cell
+    fn escape_markdown_cell(s: &str) -> String {
+        s.replace('|', "\\|").replace(['\n', '\r'], " ")
+    }
+
     /// Escape CSV special characters
     fn escape_csv(s: &str) -> String {
-        if s.contains(',') || s.contains('"') || s.contains('\n') {
+        if s.contains([',', '"', '\n', '\r']) {
             format!("\"{}\"", s.replace('"', "\"\""))
         } else {
             s.to_string()
@@ -307,7 +1353,7 @@ impl Exporter {
     }
 
     /// Format numbers with K/M suffixes
-    fn format_number(num: u32) -> String {
+    pub(crate) fn format_number(num: u32) -> String {
         if num >= 1_000_000 {
             format!("{:.1}M", num as f64 / 1_000_000.0)
         } else if num >= 1_000 {
@@ -321,30 +1367,19 @@ impl Exporter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::Utc;
 
     fn create_test_repo() -> Repository {
         Repository {
-            platform: crate::models::Platform::GitHub,
-            full_name: "test/repo".to_string(),
             description: Some("A test repository".to_string()),
             url: "https://github.com/test/repo".to_string(),
-            homepage_url: None,
-            stars: 1234,
             forks: 567,
             watchers: 89,
             open_issues: 12,
             language: Some("Rust".to_string()),
             topics: vec!["test".to_string(), "rust".to_string()],
             license: Some("MIT".to_string()),
-            created_at: Utc::now(),
-            updated_at: Utc::now(),
-            pushed_at: Utc::now(),
             size: 1024,
-            default_branch: "main".to_string(),
-            is_archived: false,
-            is_private: false,
-            health: None,
+            ..Repository::test_fixture(crate::models::Platform::GitHub, "test/repo", 1234)
         }
     }
 
@@ -358,6 +1393,10 @@ mod tests {
             ExportFormat::from_extension("JSON"),
             Some(ExportFormat::Json)
         );
+        assert_eq!(
+            ExportFormat::from_extension("ndjson"),
+            Some(ExportFormat::Jsonl)
+        );
         assert_eq!(ExportFormat::from_extension("csv"), Some(ExportFormat::Csv));
         assert_eq!(
             ExportFormat::from_extension("md"),
@@ -375,9 +1414,111 @@ mod tests {
         let repos = vec![create_test_repo()];
         let json = Exporter::to_json(&repos).unwrap();
         assert!(json.contains("test/repo"));
+        assert!(json.contains("\"star_velocity\": 1234.0"));
         assert!(json.contains("A test repository"));
     }
 
+    #[test]
+    fn test_jsonl_export() {
+        let repos = vec![create_test_repo(), create_test_repo()];
+        let mut out = Vec::new();
+        Exporter::write_jsonl(&repos, &mut out).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<_> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let parsed: Repository = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(parsed.full_name, "test/repo");
+
+        let mut empty = Vec::new();
+        Exporter::write_jsonl(&[], &mut empty).unwrap();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_json_field_projection() {
+        let fields = parse_json_fields("full_name, STARS,star_velocity,health").unwrap();
+        assert_eq!(fields, ["full_name", "stars", "star_velocity", "health"]);
+
+        let json = Exporter::to_json_with_fields(&[create_test_repo()], &fields).unwrap();
+        let values: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+        let object = values[0].as_object().unwrap();
+        assert_eq!(object.len(), 4);
+        assert_eq!(object["full_name"], "test/repo");
+        assert_eq!(object["star_velocity"], 1234.0);
+        // Skipped when serialized, but still present so every object has the same keys
+        assert!(object["health"].is_null());
+
+        let mut out = Vec::new();
+        Exporter::write_jsonl_with_fields(&[create_test_repo()], Some(&fields[..1]), &mut out)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"full_name\":\"test/repo\"}\n"
+        );
+
+        let err = parse_json_fields("full_name,owner")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Unknown field 'owner'"));
+        assert!(err.contains("open_merge_requests"));
+        assert!(err.contains("star_velocity"));
+        assert!(parse_json_fields(" , ").is_err());
+    }
+
+    #[test]
+    fn test_append_search_results() {
+        let dir = std::env::temp_dir().join(format!("reposcout-append-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut other = create_test_repo();
+        other.full_name = "test/other".to_string();
+
+        // JSON merges and skips repositories already there
+        let json = dir.join("out.json");
+        let added = Exporter::append_search_results(&[create_test_repo()], &json, "a", None, None);
+        assert_eq!(added.unwrap(), 1);
+        let repos = [create_test_repo(), other.clone()];
+        assert_eq!(
+            Exporter::append_search_results(&repos, &json, "b", None, None).unwrap(),
+            1
+        );
+        let records: Vec<Repository> =
+            serde_json::from_str(&std::fs::read_to_string(&json).unwrap()).unwrap();
+        assert_eq!(records.len(), 2);
+        let fields = vec!["full_name".to_string()];
+        let err = Exporter::append_search_results(&repos, &json, "c", None, Some(&fields))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("different fields"));
+
+        // CSV keeps one header
+        let csv = dir.join("out.csv");
+        Exporter::append_search_results(&[create_test_repo()], &csv, "a", None, None).unwrap();
+        assert_eq!(
+            Exporter::append_search_results(&repos, &csv, "b", None, None).unwrap(),
+            1
+        );
+        let content = std::fs::read_to_string(&csv).unwrap();
+        assert_eq!(content.matches("Platform,Name").count(), 1);
+        assert_eq!(content.lines().count(), 3);
+        let columns = [CsvColumn::FullName];
+        assert!(Exporter::append_search_results(&repos, &csv, "c", Some(&columns), None).is_err());
+
+        // Markdown gets a section per search
+        let md = dir.join("out.md");
+        Exporter::append_search_results(&[create_test_repo()], &md, "a", None, None).unwrap();
+        Exporter::append_search_results(&[other], &md, "async", None, None).unwrap();
+        let content = std::fs::read_to_string(&md).unwrap();
+        assert!(content.contains("# Repository Search Results: async"));
+        assert!(content.contains("[test/other]"));
+
+        let html = dir.join("out.html");
+        Exporter::append_search_results(&repos, &html, "a", None, None).unwrap();
+        assert!(Exporter::append_search_results(&repos, &html, "b", None, None).is_err());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_csv_export() {
         let repos = vec![create_test_repo()];
@@ -397,10 +1538,125 @@ mod tests {
         assert!(md.contains("⭐ Stars"));
     }
 
+    #[test]
+    fn test_html_export() {
+        let mut repo = create_test_repo();
+        repo.description = Some("Fast <script> & \"safe\"".to_string());
+        repo.calculate_health();
+        let html = Exporter::to_html(&[repo], Some("rust <tui>"));
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("Query: <code>rust &lt;tui&gt;</code>"));
+        assert!(html.contains("<a href=\"https://github.com/test/repo\">test/repo</a>"));
+        assert!(html.contains("Fast &lt;script&gt; &amp; &quot;safe&quot;"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("<span class=\"badge "));
+        assert!(html.contains("⭐ 1.2k"));
+        assert_eq!(
+            ExportFormat::from_extension("html"),
+            Some(ExportFormat::Html)
+        );
+    }
+
+    #[test]
+    fn test_atom_export() {
+        let mut repo = create_test_repo();
+        repo.description = Some("Fast & <small>".to_string());
+        let atom = Exporter::to_atom(&[repo.clone()], "Trending Rust");
+
+        assert!(atom.starts_with("<?xml version=\"1.0\" encoding=\"utf-8\"?>"));
+        assert!(atom.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+        assert!(atom.contains("<title>test/repo</title>"));
+        assert!(atom.contains("<link href=\"https://github.com/test/repo\"/>"));
+        assert!(atom.contains("<id>https://github.com/test/repo</id>"));
+        assert!(atom.contains("Fast &amp; &lt;small&gt;"));
+        assert!(atom.contains("⭐ 1.2k stars"));
+        assert!(atom.contains(&format!(
+            "<updated>{}</updated>",
+            repo.pushed_at
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+        )));
+        assert!(atom.trim_end().ends_with("</feed>"));
+        assert_eq!(
+            ExportFormat::from_extension("xml"),
+            Some(ExportFormat::Atom)
+        );
+    }
+
+    #[test]
+    fn test_csv_column_selection() {
+        let repos = vec![create_test_repo()];
+        let columns = CsvColumn::parse_list("full_name, STARS,url").unwrap();
+        assert_eq!(
+            columns,
+            [CsvColumn::FullName, CsvColumn::Stars, CsvColumn::Url]
+        );
+        let csv = Exporter::to_csv_with_columns(&repos, &columns).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(lines.next(), Some("Name,Stars,URL"));
+        assert_eq!(
+            lines.next(),
+            Some(format!("test/repo,1234,{}", repos[0].url).as_str())
+        );
+
+        let err = CsvColumn::parse_list("full_name,stargazers").unwrap_err();
+        assert!(err.to_string().contains("Unknown column 'stargazers'"));
+        assert!(err.to_string().contains("stars_per_day"));
+        assert!(CsvColumn::parse_list(" , ").is_err());
+
+        // The default set keeps the full header
+        assert!(Exporter::to_csv(&repos)
+            .unwrap()
+            .starts_with("Platform,Name,Description,Stars,Forks,Watchers,Open Issues"));
+    }
+
+    #[test]
+    fn test_search_history_roundtrip() {
+        let entries = vec![
+            SearchHistoryEntry {
+                id: 7,
+                query: "web \"framework\", async".to_string(),
+                filters: Some("language:rust".to_string()),
+                result_count: Some(42),
+                searched_at: 1_700_000_000,
+            },
+            SearchHistoryEntry {
+                id: 8,
+                query: "cli".to_string(),
+                filters: None,
+                result_count: None,
+                searched_at: 1_600_000_000,
+            },
+        ];
+
+        let json = Exporter::search_history_to_json(&entries).unwrap();
+        assert!(json.contains("\"searched_at_human\": \"2023-11-14T22:13:20Z\""));
+        let csv = Exporter::search_history_to_csv(&entries);
+        assert!(csv.starts_with("query,filters,result_count,searched_at,searched_at_human\n"));
+
+        for (content, format) in [(json, ExportFormat::Json), (csv, ExportFormat::Csv)] {
+            let parsed = Exporter::parse_search_history(&content, format).unwrap();
+            assert_eq!(parsed.len(), 2);
+            for (parsed, entry) in parsed.iter().zip(&entries) {
+                assert_eq!(parsed.query, entry.query);
+                assert_eq!(parsed.filters, entry.filters);
+                assert_eq!(parsed.result_count, entry.result_count);
+                assert_eq!(parsed.searched_at, entry.searched_at);
+            }
+        }
+
+        assert!(Exporter::parse_search_history("a,b\n", ExportFormat::Csv).is_err());
+    }
+
     #[test]
     fn test_csv_escaping() {
         assert_eq!(Exporter::escape_csv("simple"), "simple");
         assert_eq!(Exporter::escape_csv("with,comma"), "\"with,comma\"");
         assert_eq!(Exporter::escape_csv("with\"quote"), "\"with\"\"quote\"");
+        assert_eq!(Exporter::escape_csv("line\r\nbreak"), "\"line\r\nbreak\"");
+        assert_eq!(
+            Exporter::escape_csv("carriage\rreturn"),
+            "\"carriage\rreturn\""
+        );
     }
 }
@@ -0,0 +1,122 @@
+// Grouping search results for display
+//
+// Purely a view over results that are already sorted: each group keeps the
+// order its repositories came in, and groups appear in the order of their
+// first (best ranked) repository.
+use crate::models::Repository;
+
+/// What to group results by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    Platform,
+    /// Primary language, "Unknown" when there is none
+    Language,
+}
+
+impl GroupBy {
+    pub fn label(self) -> &'static str {
+        match self {
+            GroupBy::Platform => "platform",
+            GroupBy::Language => "language",
+        }
+    }
+
+    /// Name of the group a repository belongs to
+    pub fn group_name(self, repo: &Repository) -> String {
+        match self {
+            GroupBy::Platform => repo.platform.to_string(),
+            GroupBy::Language => repo.language.as_deref().unwrap_or("Unknown").to_string(),
+        }
+    }
+}
+
+impl std::str::FromStr for GroupBy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "platform" => Ok(GroupBy::Platform),
+            "language" | "lang" => Ok(GroupBy::Language),
+            other => Err(format!(
+                "Unknown grouping '{}', use platform or language",
+                other
+            )),
+        }
+    }
+}
+
+/// One group of results
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepoGroup {
+    pub name: String,
+    /// Indices into the grouped slice, in their original order
+    pub indices: Vec<usize>,
+}
+
+/// Split results into groups without reordering within a group
+pub fn group_repositories(repos: &[Repository], by: GroupBy) -> Vec<RepoGroup> {
+    let mut groups: Vec<RepoGroup> = Vec::new();
+    for (i, repo) in repos.iter().enumerate() {
+        let name = by.group_name(repo);
+        match groups.iter_mut().find(|group| group.name == name) {
+            Some(group) => group.indices.push(i),
+            None => groups.push(RepoGroup {
+                name,
+                indices: vec![i],
+            }),
+        }
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Platform;
+
+    fn repo(name: &str, platform: Platform, language: Option<&str>) -> Repository {
+        Repository {
+            language: language.map(str::to_string),
+            ..Repository::test_fixture(platform, name, 0)
+        }
+    }
+
+    #[test]
+    fn test_groups_keep_result_order() {
+        let repos = [
+            repo("a/one", Platform::GitLab, Some("Rust")),
+            repo("b/two", Platform::GitHub, Some("Go")),
+            repo("c/three", Platform::GitLab, None),
+            repo("d/four", Platform::GitHub, Some("Rust")),
+        ];
+
+        let by_platform = group_repositories(&repos, GroupBy::Platform);
+        assert_eq!(
+            by_platform,
+            vec![
+                RepoGroup {
+                    name: "GitLab".to_string(),
+                    indices: vec![0, 2]
+                },
+                RepoGroup {
+                    name: "GitHub".to_string(),
+                    indices: vec![1, 3]
+                },
+            ]
+        );
+
+        let by_language: Vec<_> = group_repositories(&repos, GroupBy::Language)
+            .into_iter()
+            .map(|group| (group.name, group.indices))
+            .collect();
+        assert_eq!(
+            by_language,
+            [
+                ("Rust".to_string(), vec![0, 3]),
+                ("Go".to_string(), vec![1]),
+                ("Unknown".to_string(), vec![2]),
+            ]
+        );
+        assert_eq!("Lang".parse(), Ok(GroupBy::Language));
+    }
+}
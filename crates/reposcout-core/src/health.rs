@@ -1,5 +1,9 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+/// Weights installed from the config file, used by `Repository::calculate_health`
+static CONFIGURED_WEIGHTS: OnceLock<HealthWeights> = OnceLock::new();
 
 /// Repository health metrics and scoring
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -12,6 +16,152 @@ pub struct HealthMetrics {
     pub maintenance: MaintenanceLevel,
     /// Individual metric scores
     pub metrics: DetailedMetrics,
+    /// Maximum of each metric score when this was calculated
+    #[serde(default)]
+    pub weights: HealthWeights,
+    /// Why the score is what it is, one entry per factor
+    #[serde(default)]
+    pub signals: Vec<HealthSignal>,
+}
+
+/// The sub-score a health signal counts toward
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthCategory {
+    Activity,
+    Community,
+    Responsiveness,
+    Maturity,
+    Documentation,
+}
+
+impl HealthCategory {
+    pub fn label(&self) -> &'static str {
+        match self {
+            HealthCategory::Activity => "activity",
+            HealthCategory::Community => "community",
+            HealthCategory::Responsiveness => "responsiveness",
+            HealthCategory::Maturity => "maturity",
+            HealthCategory::Documentation => "documentation",
+        }
+    }
+
+    /// This category's share of the score under `weights`
+    fn weight(&self, weights: &HealthWeights) -> u8 {
+        match self {
+            HealthCategory::Activity => weights.activity,
+            HealthCategory::Community => weights.community,
+            HealthCategory::Responsiveness => weights.responsiveness,
+            HealthCategory::Maturity => weights.maturity,
+            HealthCategory::Documentation => weights.documentation,
+        }
+    }
+}
+
+/// One reason behind a health score, e.g. "No commits in 14 months (-25 activity)"
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HealthSignal {
+    pub reason: String,
+    /// Sub-score affected, None when the signal overrides the whole score
+    pub category: Option<HealthCategory>,
+    /// Points earned (positive) or missed (negative) because of this
+    pub impact: i8,
+}
+
+impl HealthSignal {
+    /// Signal for a factor that earned `earned` of its `max` points. It
+    /// counts as helping when at least half the points were earned, and
+    /// as costing the missed points otherwise.
+    fn new(category: HealthCategory, reason: String, earned: u8, max: u8) -> Self {
+        let impact = if earned * 2 >= max {
+            earned as i8
+        } else {
+            -((max - earned) as i8)
+        };
+        Self {
+            reason,
+            category: Some(category),
+            impact,
+        }
+    }
+
+    /// Whether this signal raised the score
+    pub fn is_positive(&self) -> bool {
+        self.impact > 0
+    }
+}
+
+impl std::fmt::Display for HealthSignal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.category {
+            Some(category) => write!(
+                f,
+                "{} ({:+} {})",
+                self.reason,
+                self.impact,
+                category.label()
+            ),
+            None => write!(f, "{} ({:+})", self.reason, self.impact),
+        }
+    }
+}
+
+/// Maximum points each sub-score contributes to the overall score.
+/// Configurable under `[health]`; must add up to 100
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct HealthWeights {
+    pub activity: u8,
+    pub community: u8,
+    pub responsiveness: u8,
+    pub maturity: u8,
+    pub documentation: u8,
+}
+
+impl Default for HealthWeights {
+    fn default() -> Self {
+        Self {
+            activity: 30,
+            community: 25,
+            responsiveness: 20,
+            maturity: 15,
+            documentation: 10,
+        }
+    }
+}
+
+impl HealthWeights {
+    pub fn total(&self) -> u32 {
+        self.activity as u32
+            + self.community as u32
+            + self.responsiveness as u32
+            + self.maturity as u32
+            + self.documentation as u32
+    }
+
+    /// Check that the weights add up to 100
+    pub fn validate(&self) -> crate::Result<()> {
+        match self.total() {
+            100 => Ok(()),
+            total => Err(crate::Error::ConfigError(format!(
+                "[health] weights must add up to 100, got {}",
+                total
+            ))),
+        }
+    }
+
+    /// Use these weights for every health calculation in this process.
+    /// Only the first call has an effect
+    pub fn install(self) -> crate::Result<()> {
+        self.validate()?;
+        let _ = CONFIGURED_WEIGHTS.set(self);
+        Ok(())
+    }
+
+    /// Weights from the config file, or the defaults if none were installed
+    pub fn configured() -> Self {
+        CONFIGURED_WEIGHTS.get().copied().unwrap_or_default()
+    }
 }
 
 /// Overall health status categories
@@ -37,6 +187,8 @@ impl HealthStatus {
         }
     }
 
+    /// Colour name for the status, as shields.io takes it. The TUI maps
+    /// these names onto its own palette, so both stay in step.
     pub fn color_code(&self) -> &'static str {
         match self {
             HealthStatus::Healthy => "green",
@@ -150,12 +302,33 @@ impl DetailedMetrics {
 }
 
 /// Health calculator for repositories
-pub struct HealthCalculator;
+#[derive(Debug, Clone, Default)]
+pub struct HealthCalculator {
+    weights: HealthWeights,
+}
 
 impl HealthCalculator {
+    /// Calculator using custom weights, which must add up to 100
+    pub fn new(weights: HealthWeights) -> crate::Result<Self> {
+        weights.validate()?;
+        Ok(Self { weights })
+    }
+
+    /// Calculator using the weights installed from the config file
+    pub fn configured() -> Self {
+        Self {
+            weights: HealthWeights::configured(),
+        }
+    }
+
+    pub fn weights(&self) -> HealthWeights {
+        self.weights
+    }
+
     /// Calculate health metrics for a repository
     #[allow(clippy::too_many_arguments)]
     pub fn calculate(
+        &self,
         stars: u32,
         forks: u32,
         watchers: u32,
@@ -182,16 +355,54 @@ impl HealthCalculator {
                     maturity_score: 0,
                     documentation_score: 0,
                 },
+                weights: self.weights,
+                signals: vec![HealthSignal {
+                    reason: "Archived, so the score is 0".to_string(),
+                    category: None,
+                    impact: -100,
+                }],
             };
         }
 
-        // Calculate individual scores
-        let activity_score = Self::calculate_activity_score(pushed_at, now);
-        let community_score = Self::calculate_community_score(stars, forks, watchers);
-        let responsiveness_score = Self::calculate_responsiveness_score(open_issues, stars);
-        let maturity_score = Self::calculate_maturity_score(created_at, now);
-        let documentation_score =
-            Self::calculate_documentation_score(has_description, topics_count);
+        // Individual scores are calculated against the default caps, then
+        // rescaled to the configured weights
+        let defaults = HealthWeights::default();
+        let weights = self.weights;
+        let mut signals = Vec::new();
+        let activity_score = scale(
+            Self::calculate_activity_score(pushed_at, now, &mut signals),
+            defaults.activity,
+            weights.activity,
+        );
+        let community_score = scale(
+            Self::calculate_community_score(stars, forks, watchers, &mut signals),
+            defaults.community,
+            weights.community,
+        );
+        let responsiveness_score = scale(
+            Self::calculate_responsiveness_score(open_issues, stars, &mut signals),
+            defaults.responsiveness,
+            weights.responsiveness,
+        );
+        let maturity_score = scale(
+            Self::calculate_maturity_score(created_at, now, &mut signals),
+            defaults.maturity,
+            weights.maturity,
+        );
+        let documentation_score = scale(
+            Self::calculate_documentation_score(has_description, topics_count, &mut signals),
+            defaults.documentation,
+            weights.documentation,
+        );
+
+        // Signals were recorded against the default caps too
+        for signal in &mut signals {
+            if let Some(category) = signal.category {
+                let cap = category.weight(&defaults);
+                let points = scale(signal.impact.unsigned_abs(), cap, category.weight(&weights));
+                signal.impact = points as i8 * signal.impact.signum();
+            }
+        }
 
         let metrics = DetailedMetrics {
             activity_score,
@@ -210,14 +421,20 @@ impl HealthCalculator {
             status,
             maintenance,
             metrics,
+            weights,
+            signals,
         }
     }
 
     /// Activity score (0-30): Recent push activity
-    fn calculate_activity_score(pushed_at: DateTime<Utc>, now: DateTime<Utc>) -> u8 {
+    fn calculate_activity_score(
+        pushed_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+        signals: &mut Vec<HealthSignal>,
+    ) -> u8 {
         let days_since_push = (now - pushed_at).num_days();
 
-        match days_since_push {
+        let score = match days_since_push {
             0..=7 => 30,     // Within a week: excellent
             8..=30 => 25,    // Within a month: very good
             31..=90 => 20,   // Within 3 months: good
@@ -225,11 +442,29 @@ impl HealthCalculator {
             181..=365 => 10, // Within a year: low
             366..=730 => 5,  // Within 2 years: very low
             _ => 0,          // Over 2 years: inactive
-        }
+        };
+
+        let reason = if days_since_push <= 7 {
+            "Commits in the last week".to_string()
+        } else {
+            format!("No commits in {}", describe_days(days_since_push))
+        };
+        signals.push(HealthSignal::new(
+            HealthCategory::Activity,
+            reason,
+            score,
+            30,
+        ));
+        score
     }
 
     /// Community score (0-25): Based on popularity metrics
-    fn calculate_community_score(stars: u32, forks: u32, watchers: u32) -> u8 {
+    fn calculate_community_score(
+        stars: u32,
+        forks: u32,
+        watchers: u32,
+        signals: &mut Vec<HealthSignal>,
+    ) -> u8 {
         // Calculate a weighted community score
         // Stars are most important, then forks, then watchers
         let stars_score = match stars {
@@ -243,14 +478,35 @@ impl HealthCalculator {
 
         let forks_bonus = if forks > 10 { 2 } else { 0 };
         let watchers_bonus = if watchers > 10 { 2 } else { 0 };
+        let score = (stars_score + forks_bonus + watchers_bonus).min(25);
 
-        (stars_score + forks_bonus + watchers_bonus).min(25)
+        let mut reason = format!("{} stars", stars);
+        if score > stars_score {
+            reason.push_str(&format!(", {} forks, {} watchers", forks, watchers));
+        }
+        signals.push(HealthSignal::new(
+            HealthCategory::Community,
+            reason,
+            score,
+            25,
+        ));
+        score
     }
 
     /// Responsiveness score (0-20): Issue management
-    fn calculate_responsiveness_score(open_issues: u32, stars: u32) -> u8 {
+    fn calculate_responsiveness_score(
+        open_issues: u32,
+        stars: u32,
+        signals: &mut Vec<HealthSignal>,
+    ) -> u8 {
         // If no stars, this metric doesn't apply well
         if stars < 10 {
+            signals.push(HealthSignal::new(
+                HealthCategory::Responsiveness,
+                "Too few stars to judge the issue load".to_string(),
+                15,
+                20,
+            ));
             return 15; // Neutral score for small projects
         }
 
@@ -262,51 +518,144 @@ impl HealthCalculator {
         };
 
         // Lower ratio is better (fewer issues per star)
-        match issue_ratio {
+        let score = match issue_ratio {
             r if r < 0.01 => 20, // Excellent: < 1%
             r if r < 0.05 => 17, // Very good: < 5%
             r if r < 0.10 => 14, // Good: < 10%
             r if r < 0.20 => 11, // Moderate: < 20%
             r if r < 0.30 => 8,  // Fair: < 30%
             _ => 5,              // Poor: >= 30%
-        }
+        };
+
+        signals.push(HealthSignal::new(
+            HealthCategory::Responsiveness,
+            format!(
+                "{} open issues for {} stars ({:.1}%)",
+                open_issues,
+                stars,
+                issue_ratio * 100.0
+            ),
+            score,
+            20,
+        ));
+        score
     }
 
     /// Maturity score (0-15): Repository age
-    fn calculate_maturity_score(created_at: DateTime<Utc>, now: DateTime<Utc>) -> u8 {
+    fn calculate_maturity_score(
+        created_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+        signals: &mut Vec<HealthSignal>,
+    ) -> u8 {
         let days_old = (now - created_at).num_days();
 
-        match days_old {
+        let score = match days_old {
             0..=30 => 3,     // Brand new
             31..=90 => 5,    // Very young
             91..=180 => 8,   // Young
             181..=365 => 11, // Established
             366..=730 => 13, // Mature
             _ => 15,         // Very mature (2+ years)
-        }
+        };
+
+        signals.push(HealthSignal::new(
+            HealthCategory::Maturity,
+            format!("Created {} ago", describe_days(days_old)),
+            score,
+            15,
+        ));
+        score
     }
 
     /// Documentation score (0-10): Presence of documentation
-    fn calculate_documentation_score(has_description: bool, topics_count: usize) -> u8 {
+    fn calculate_documentation_score(
+        has_description: bool,
+        topics_count: usize,
+        signals: &mut Vec<HealthSignal>,
+    ) -> u8 {
         let mut score = 0;
 
         // Description present
         if has_description {
             score += 5;
         }
+        signals.push(HealthSignal::new(
+            HealthCategory::Documentation,
+            if has_description {
+                "Has a description"
+            } else {
+                "No description"
+            }
+            .to_string(),
+            score,
+            5,
+        ));
 
         // Topics/tags help with discovery
-        score += match topics_count {
+        let topics_score = match topics_count {
             0 => 0,
             1..=2 => 2,
             3..=5 => 3,
             _ => 5,
         };
+        score += topics_score;
+        signals.push(HealthSignal::new(
+            HealthCategory::Documentation,
+            match topics_count {
+                0 => "No topics".to_string(),
+                1 => "1 topic".to_string(),
+                n => format!("{} topics", n),
+            },
+            topics_score,
+            5,
+        ));
 
         score.min(10)
     }
 }
 
+/// Drop repositories whose health score is below `min_score`, calculating
+/// health for those that don't have it yet. Repositories without a
+/// computable score (see `Repository::has_health_signals`) are kept only when
+/// `keep_unknown` is set.
+pub fn retain_min_health(
+    results: &mut Vec<crate::models::Repository>,
+    min_score: u8,
+    keep_unknown: bool,
+) {
+    results.retain_mut(|repo| {
+        if !repo.has_health_signals() {
+            return keep_unknown;
+        }
+        repo.get_health().score >= min_score
+    });
+}
+
+/// "5 days", "14 months" or "3 years"
+fn describe_days(days: i64) -> String {
+    let plural = |n: i64, unit: &str| {
+        if n == 1 {
+            format!("1 {}", unit)
+        } else {
+            format!("{} {}s", n, unit)
+        }
+    };
+    match days.max(0) {
+        d if d < 60 => plural(d, "day"),
+        d if d < 730 => plural(d / 30, "month"),
+        d => plural(d / 365, "year"),
+    }
+}
+
+/// Rescale a score out of `cap` to be out of `weight`, rounding to nearest
+fn scale(score: u8, cap: u8, weight: u8) -> u8 {
+    if cap == weight {
+        return score;
+    }
+    let cap = cap as u32;
+    ((score as u32 * weight as u32 + cap / 2) / cap) as u8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,7 +702,7 @@ mod tests {
         let created = now - Duration::days(730); // 2 years old
         let pushed = now - Duration::days(7); // Pushed last week
 
-        let health = HealthCalculator::calculate(
+        let health = HealthCalculator::default().calculate(
             1000, // stars
             200,  // forks
             50,   // watchers
@@ -374,7 +723,7 @@ mod tests {
         let created = now - Duration::days(365);
         let pushed = now - Duration::days(30);
 
-        let health = HealthCalculator::calculate(
+        let health = HealthCalculator::default().calculate(
             5000, 100, 50, 5, created, now, pushed, true, // archived
             true, 5,
         );
@@ -389,10 +738,147 @@ mod tests {
         let created = now - Duration::days(1095); // 3 years old
         let pushed = now - Duration::days(500); // No push in >1 year
 
-        let health =
-            HealthCalculator::calculate(50, 5, 2, 10, created, now, pushed, false, true, 2);
+        let health = HealthCalculator::default()
+            .calculate(50, 5, 2, 10, created, now, pushed, false, true, 2);
 
         assert_eq!(health.maintenance, MaintenanceLevel::Abandoned);
         assert!(health.score < 60);
     }
+
+    #[test]
+    fn test_custom_weights_change_score() {
+        let now = Utc::now();
+        let created = now - Duration::days(1095);
+        // Popular and well documented but hasn't been pushed in over a year
+        let pushed = now - Duration::days(500);
+        let calculate = |calculator: &HealthCalculator| {
+            calculator.calculate(20_000, 900, 300, 40, created, now, pushed, false, true, 8)
+        };
+
+        let default = calculate(&HealthCalculator::default());
+        // activity 5/30 + community 25/25 + responsiveness 20/20 + maturity 15/15 + docs 10/10
+        assert_eq!(default.score, 75);
+        assert_eq!(default.status, HealthStatus::Moderate);
+
+        // A team that mostly cares about recent activity
+        let activity_first = HealthCalculator::new(HealthWeights {
+            activity: 60,
+            community: 10,
+            responsiveness: 10,
+            maturity: 10,
+            documentation: 10,
+        })
+        .unwrap();
+        let weighted = calculate(&activity_first);
+        assert_eq!(weighted.metrics.activity_score, 10);
+        assert_eq!(weighted.score, 50);
+        assert_eq!(weighted.status, HealthStatus::Warning);
+        assert_eq!(weighted.weights.activity, 60);
+
+        let invalid = HealthWeights {
+            activity: 50,
+            ..HealthWeights::default()
+        };
+        assert!(HealthCalculator::new(invalid).is_err());
+    }
+
+    #[test]
+    fn test_health_signals_explain_score() {
+        let now = Utc::now();
+        let created = now - Duration::days(1095);
+        let pushed = now - Duration::days(500);
+
+        let health = HealthCalculator::default()
+            .calculate(20_000, 900, 300, 40, created, now, pushed, false, false, 0);
+        let reasons: Vec<String> = health.signals.iter().map(|s| s.to_string()).collect();
+        assert_eq!(
+            reasons,
+            [
+                "No commits in 16 months (-25 activity)",
+                "20000 stars (+25 community)",
+                "40 open issues for 20000 stars (0.2%) (+20 responsiveness)",
+                "Created 3 years ago (+15 maturity)",
+                "No description (-5 documentation)",
+                "No topics (-5 documentation)",
+            ]
+        );
+        assert!(!health.signals[0].is_positive());
+        assert!(health.signals[1].is_positive());
+
+        // Impacts follow the configured weights
+        let activity_first = HealthCalculator::new(HealthWeights {
+            activity: 60,
+            community: 10,
+            responsiveness: 10,
+            maturity: 10,
+            documentation: 10,
+        })
+        .unwrap();
+        let weighted =
+            activity_first.calculate(20_000, 900, 300, 40, created, now, pushed, false, true, 8);
+        assert_eq!(weighted.signals[0].impact, -50);
+
+        let archived =
+            HealthCalculator::default().calculate(5, 0, 0, 0, created, now, pushed, true, true, 0);
+        assert_eq!(archived.signals.len(), 1);
+        assert_eq!(
+            archived.signals[0].to_string(),
+            "Archived, so the score is 0 (-100)"
+        );
+    }
+
+    #[test]
+    fn test_gitlab_merge_requests_count_toward_responsiveness() {
+        let now = Utc::now();
+        let mut repo = crate::models::Repository {
+            open_issues: 5,
+            created_at: now - Duration::days(1000),
+            ..crate::models::Repository::test_fixture(
+                crate::models::Platform::GitLab,
+                "gitlab-org/gitaly",
+                1_000,
+            )
+        };
+        assert_eq!(repo.get_health().metrics.responsiveness_score, 20);
+
+        // 5 issues + 150 MRs per 1000 stars falls in the < 20% band
+        repo.open_merge_requests = Some(150);
+        repo.calculate_health();
+        assert_eq!(
+            repo.health.as_ref().unwrap().metrics.responsiveness_score,
+            11
+        );
+    }
+
+    #[test]
+    fn test_retain_min_health() {
+        let now = Utc::now();
+        let repo = |platform, name: &str, pushed_days_ago| crate::models::Repository {
+            description: Some("A project".to_string()),
+            forks: 200,
+            watchers: 100,
+            open_issues: 10,
+            created_at: now - Duration::days(1000),
+            updated_at: now - Duration::days(pushed_days_ago),
+            pushed_at: now - Duration::days(pushed_days_ago),
+            ..crate::models::Repository::test_fixture(platform, name, 2_000)
+        };
+        use crate::models::Platform;
+        let results = vec![
+            repo(Platform::GitHub, "a/active", 1),
+            repo(Platform::GitHub, "a/abandoned", 900),
+            repo(Platform::SourceHut, "~a/unknown", 1),
+        ];
+        assert_eq!(results[2].health_score(), None);
+
+        let mut kept = results.clone();
+        retain_min_health(&mut kept, 80, true);
+        let names: Vec<_> = kept.iter().map(|r| r.full_name.as_str()).collect();
+        assert_eq!(names, ["a/active", "~a/unknown"]);
+        assert!(kept[0].health.is_some());
+
+        let mut dropped = results;
+        retain_min_health(&mut dropped, 80, false);
+        assert_eq!(dropped.len(), 1);
+    }
 }
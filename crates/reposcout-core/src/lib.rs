@@ -1,34 +1,61 @@
 // Core business logic lives here - the brain of the operation
+pub mod badge;
+pub mod bookmark_diff;
+pub mod bookmark_stats;
+pub mod code_context;
+pub mod compare;
 pub mod config;
 pub mod discovery;
 pub mod error;
 pub mod export;
+pub mod grouping;
 pub mod health;
 pub mod models;
+pub mod offline;
 pub mod packages;
 pub mod portfolio;
 pub mod providers;
+pub mod query;
 pub mod registries;
 pub mod search;
 pub mod search_with_cache;
 pub mod theme;
 pub mod token_store;
 pub mod trending;
+pub mod watch;
 
-pub use config::Config;
-pub use error::Error;
-pub use export::{ExportFormat, Exporter};
-pub use health::{HealthCalculator, HealthMetrics, HealthStatus, MaintenanceLevel};
+pub use badge::{Badge, BadgeFormat};
+pub use bookmark_diff::BookmarkDiff;
+pub use bookmark_stats::BookmarkStats;
+pub use code_context::{apply_file_context, query_terms, DEFAULT_CONTEXT_LINES};
+pub use compare::{compare_repositories, ComparedRepo, RepoComparison};
+pub use config::{Config, FilterConfig, NetworkConfig, SearchConfig};
+pub use error::{missing_as_none, Error};
+pub use export::{json_field_names, parse_json_fields, CsvColumn, ExportFormat, Exporter};
+pub use grouping::{group_repositories, GroupBy, RepoGroup};
+pub use health::{
+    retain_min_health, HealthCalculator, HealthCategory, HealthMetrics, HealthSignal, HealthStatus,
+    HealthWeights, MaintenanceLevel,
+};
+pub use offline::{ensure_online, is_offline, set_offline};
 pub use packages::{License, LicenseCompatibility, PackageDetector, PackageInfo, PackageManager};
 pub use portfolio::{Portfolio, PortfolioColor, PortfolioIcon, PortfolioManager};
-pub use registries::RegistryClient;
+pub use query::{build_github_query, QueryFilters};
+pub use registries::{CrateUsage, RegistryClient};
+pub use reposcout_api::{fetch_concurrently, DEFAULT_CONCURRENCY};
 pub use search_with_cache::CachedSearchEngine;
-pub use theme::{Color, Theme, ThemeColors};
+pub use theme::{Color, ContrastIssue, Theme, ThemeColors};
 pub use token_store::TokenStore;
-pub use trending::{TrendingFilters, TrendingFinder, TrendingPeriod};
+pub use trending::{
+    language_share, languages_to_percentages, sort_by_star_velocity, sort_by_trending_score,
+    TrendingFilters, TrendingFinder, TrendingPeriod, TrendingScoreWeights, MAX_LANGUAGE_LOOKUPS,
+};
+pub use watch::{WatchUpdate, STAR_MILESTONES};
 
 // Re-export notification types from API crate
-pub use reposcout_api::{Notification, NotificationFilters, NotificationReason};
+pub use reposcout_api::{
+    Notification, NotificationFilters, NotificationReason, NOTIFICATION_SUBJECT_TYPES,
+};
 
 /// Result type alias because typing Result<T, Error> everywhere is tedious
 pub type Result<T> = std::result::Result<T, Error>;
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 use crate::health::HealthMetrics;
 
@@ -15,6 +16,9 @@ pub struct Repository {
     pub forks: u32,
     pub watchers: u32,
     pub open_issues: u32,
+    /// Open merge requests (GitLab only, fetched with the full repository)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open_merge_requests: Option<u32>,
     pub language: Option<String>,
     pub topics: Vec<String>,
     pub license: Option<String>,
@@ -25,7 +29,23 @@ pub struct Repository {
     pub default_branch: String,
     pub is_archived: bool,
     pub is_private: bool,
-    /// Health metrics (calculated on-demand)
+    #[serde(default)]
+    pub is_fork: bool,
+    /// Bytes of code per language (fetched on demand, GitHub only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub languages: Option<HashMap<String, u64>>,
+    /// Contributor count, anonymous ones included (fetched on demand, GitHub only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contributors: Option<u32>,
+    /// Commits per day over the last 52 weeks, oldest week first and each
+    /// week starting on Sunday (fetched on demand, GitHub only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_activity: Option<Vec<[u32; 7]>>,
+    /// Copies of this project on other platforms, collapsed into it by
+    /// `CachedSearchEngine::set_dedupe`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mirrors: Vec<(Platform, String)>,
+    /// Health metrics, calculated once when fetched and kept in the cache
     #[serde(skip_serializing_if = "Option::is_none")]
     pub health: Option<HealthMetrics>,
 }
@@ -33,18 +53,101 @@ pub struct Repository {
 impl Repository {
     /// Calculate and set health metrics for this repository
     pub fn calculate_health(&mut self) {
-        self.health = Some(crate::health::HealthCalculator::calculate(
+        self.health = Some(self.compute_health());
+    }
+
+    /// Calculate health metrics unless they're already there. Metrics
+    /// worked out under other `[health]` weights are calculated again.
+    pub fn ensure_health(&mut self) {
+        let weights = crate::health::HealthWeights::configured();
+        if self.health.as_ref().map_or(true, |h| h.weights != weights) {
+            self.calculate_health();
+        }
+    }
+
+    fn compute_health(&self) -> HealthMetrics {
+        crate::health::HealthCalculator::configured().calculate(
             self.stars,
             self.forks,
             self.watchers,
-            self.open_issues,
+            // GitHub counts pull requests as issues; GitLab keeps MRs separate
+            self.open_issues + self.open_merge_requests.unwrap_or(0),
             self.created_at,
             self.updated_at,
             self.pushed_at,
             self.is_archived,
             self.description.is_some(),
             self.topics.len(),
-        ));
+        )
+    }
+
+    /// Whether the platform reports the stars, forks and issue counts that
+    /// health is judged by. SourceHut and Azure DevOps report none of them,
+    /// and activity can't be judged without dates.
+    pub fn has_health_signals(&self) -> bool {
+        !matches!(self.platform, Platform::SourceHut | Platform::AzureDevOps) && self.has_dates()
+    }
+
+    /// Whether the platform said when the repository last changed. Dates it
+    /// didn't report are left at the Unix epoch, e.g. for an empty Bitbucket
+    /// Data Center repository.
+    pub fn has_dates(&self) -> bool {
+        self.pushed_at != DateTime::<Utc>::UNIX_EPOCH
+    }
+
+    /// Health score, calculated on the fly when not stored yet. None when
+    /// the platform gives nothing to judge health by.
+    pub fn health_score(&self) -> Option<u8> {
+        if !self.has_health_signals() {
+            return None;
+        }
+        Some(match &self.health {
+            Some(health) => health.score,
+            None => self.compute_health().score,
+        })
+    }
+
+    /// Stars gained per day since creation (age is floored at one day)
+    pub fn star_velocity(&self) -> f64 {
+        let age_days = (Utc::now() - self.created_at).num_days().max(1);
+        self.stars as f64 / age_days as f64
+    }
+
+    /// "Also on GitLab (owner/name), ..." when mirrors were collapsed into this repo
+    pub fn mirrors_label(&self) -> Option<String> {
+        if self.mirrors.is_empty() {
+            return None;
+        }
+        let mirrors: Vec<String> = self
+            .mirrors
+            .iter()
+            .map(|(platform, full_name)| format!("{} ({})", platform, full_name))
+            .collect();
+        Some(format!("Also on {}", mirrors.join(", ")))
+    }
+
+    /// Issue tracker page, if the platform keeps one next to the repository
+    /// (SourceHut trackers live on todo.sr.ht under their own names, Azure
+    /// DevOps work items belong to the project rather than the repository)
+    pub fn issues_url(&self) -> Option<String> {
+        let url = self.url.trim_end_matches('/');
+        match self.platform {
+            Platform::GitHub | Platform::Bitbucket => Some(format!("{}/issues", url)),
+            Platform::GitLab => Some(format!("{}/-/issues", url)),
+            Platform::SourceHut | Platform::AzureDevOps => None,
+        }
+    }
+
+    /// Pull/merge request page (SourceHut takes patches by email instead)
+    pub fn pulls_url(&self) -> Option<String> {
+        let url = self.url.trim_end_matches('/');
+        match self.platform {
+            Platform::GitHub => Some(format!("{}/pulls", url)),
+            Platform::GitLab => Some(format!("{}/-/merge_requests", url)),
+            Platform::Bitbucket => Some(format!("{}/pull-requests", url)),
+            Platform::AzureDevOps => Some(format!("{}/pullrequests", url)),
+            Platform::SourceHut => None,
+        }
     }
 
     /// Get health metrics, calculating if not already present
@@ -56,12 +159,73 @@ impl Repository {
     }
 }
 
+/// A repository date as shown to people: the day, or "unknown" for a date
+/// the platform didn't report (see [`Repository::has_dates`])
+pub fn format_day(date: DateTime<Utc>) -> String {
+    if date == DateTime::<Utc>::UNIX_EPOCH {
+        "unknown".to_string()
+    } else {
+        date.format("%Y-%m-%d").to_string()
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl Repository {
+    /// A repository with just a platform, name and star count, everything
+    /// else empty or zero and every date set to now. Tests fill in the rest
+    /// with struct update syntax, so new fields only need adding here.
+    pub fn test_fixture(platform: Platform, full_name: &str, stars: u32) -> Self {
+        let now = Utc::now();
+        Self {
+            platform,
+            full_name: full_name.to_string(),
+            description: None,
+            url: String::new(),
+            homepage_url: None,
+            stars,
+            forks: 0,
+            watchers: 0,
+            open_issues: 0,
+            open_merge_requests: None,
+            language: None,
+            topics: Vec::new(),
+            license: None,
+            created_at: now,
+            updated_at: now,
+            pushed_at: now,
+            size: 0,
+            default_branch: "main".to_string(),
+            is_archived: false,
+            is_private: false,
+            is_fork: false,
+            languages: None,
+            contributors: None,
+            commit_activity: None,
+            mirrors: Vec::new(),
+            health: None,
+        }
+    }
+}
+
 /// Which platform this repo lives on
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum Platform {
     GitHub,
     GitLab,
     Bitbucket,
+    SourceHut,
+    AzureDevOps,
+}
+
+impl Platform {
+    /// Every supported platform
+    pub const ALL: [Platform; 5] = [
+        Platform::GitHub,
+        Platform::GitLab,
+        Platform::Bitbucket,
+        Platform::SourceHut,
+        Platform::AzureDevOps,
+    ];
 }
 
 impl std::fmt::Display for Platform {
@@ -70,6 +234,8 @@ impl std::fmt::Display for Platform {
             Platform::GitHub => write!(f, "GitHub"),
             Platform::GitLab => write!(f, "GitLab"),
             Platform::Bitbucket => write!(f, "Bitbucket"),
+            Platform::SourceHut => write!(f, "SourceHut"),
+            Platform::AzureDevOps => write!(f, "Azure DevOps"),
         }
     }
 }
@@ -143,3 +309,65 @@ pub struct CodeMatch {
     pub context_before: Vec<String>,
     pub context_after: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_and_pull_urls_per_platform() {
+        let repo = |platform, url: &str| Repository {
+            url: url.to_string(),
+            ..Repository::test_fixture(platform, "owner/name", 0)
+        };
+
+        let github = repo(Platform::GitHub, "https://github.com/owner/name");
+        assert_eq!(
+            github.issues_url().as_deref(),
+            Some("https://github.com/owner/name/issues")
+        );
+        assert_eq!(
+            github.pulls_url().as_deref(),
+            Some("https://github.com/owner/name/pulls")
+        );
+
+        let gitlab = repo(Platform::GitLab, "https://gitlab.com/owner/name/");
+        assert_eq!(
+            gitlab.pulls_url().as_deref(),
+            Some("https://gitlab.com/owner/name/-/merge_requests")
+        );
+
+        let bitbucket = repo(Platform::Bitbucket, "https://bitbucket.org/owner/name");
+        assert_eq!(
+            bitbucket.pulls_url().as_deref(),
+            Some("https://bitbucket.org/owner/name/pull-requests")
+        );
+
+        let sourcehut = repo(Platform::SourceHut, "https://git.sr.ht/~owner/name");
+        assert_eq!(sourcehut.issues_url(), None);
+        assert_eq!(sourcehut.pulls_url(), None);
+    }
+
+    #[test]
+    fn test_unreported_dates() {
+        let epoch = DateTime::<Utc>::UNIX_EPOCH;
+        let repo = Repository {
+            created_at: epoch,
+            updated_at: epoch,
+            pushed_at: epoch,
+            ..Repository::test_fixture(Platform::Bitbucket, "PRJ/empty", 0)
+        };
+        assert!(!repo.has_dates());
+        // Nothing to judge activity by, rather than fifty years of silence
+        assert_eq!(repo.health_score(), None);
+        assert_eq!(format_day(repo.pushed_at), "unknown");
+
+        let repo = Repository::test_fixture(Platform::Bitbucket, "PRJ/busy", 0);
+        assert!(repo.has_dates());
+        assert!(repo.health_score().is_some());
+        assert_eq!(
+            format_day(repo.pushed_at),
+            repo.pushed_at.format("%Y-%m-%d").to_string()
+        );
+    }
+}
@@ -0,0 +1,25 @@
+// Process-wide offline switch. When it's on, nothing should touch the
+// network: providers fail fast and the search engine serves the cache only.
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+/// Turn offline mode on or off for the whole process
+pub fn set_offline(offline: bool) {
+    OFFLINE.store(offline, Ordering::Relaxed);
+}
+
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Relaxed)
+}
+
+/// Fail fast with an "offline mode" error if `feature` would need the network
+pub fn ensure_online(feature: &str) -> crate::Result<()> {
+    if is_offline() {
+        return Err(crate::Error::Offline(format!(
+            "{} needs the network",
+            feature
+        )));
+    }
+    Ok(())
+}
@@ -276,20 +276,21 @@ impl License {
             License::MIT
         } else if s_lower.contains("apache") {
             License::Apache2
+        } else if s_lower.contains("agpl") {
+            // AGPL and LGPL contain "gpl", so they're matched first
+            License::AGPL
+        } else if s_lower.contains("lgpl") {
+            License::LGPL
         } else if s_lower.contains("gpl") && s_lower.contains('3') {
             License::GPL3
         } else if s_lower.contains("gpl") && s_lower.contains('2') {
             License::GPL2
-        } else if s_lower.contains("lgpl") {
-            License::LGPL
         } else if s_lower.contains("bsd") && s_lower.contains('3') {
             License::BSD3
         } else if s_lower.contains("bsd") && s_lower.contains('2') {
             License::BSD2
         } else if s_lower.contains("mpl") {
             License::MPL2
-        } else if s_lower.contains("agpl") {
-            License::AGPL
         } else if s_lower.contains("unlicense") {
             License::Unlicense
         } else if s_lower.contains("isc") {
@@ -336,6 +337,39 @@ impl License {
         }
     }
 
+    /// Whether a dependency under this license can be used by a project
+    /// under `project`.
+    ///
+    /// Unlike `check_compatibility` this is one-directional: a GPL crate in an
+    /// MIT project is a problem, an MIT crate in a GPL project isn't.
+    pub fn dependency_compatibility(&self, project: &License) -> LicenseCompatibility {
+        use License::*;
+        use LicenseCompatibility::*;
+
+        match (self, project) {
+            (License::Unknown, _) | (_, License::Unknown) => LicenseCompatibility::Unknown,
+
+            // The FSF considers Apache-2.0 incompatible with GPLv2-only
+            (Apache2, GPL2) => Incompatible,
+            (MIT | BSD2 | BSD3 | Apache2 | ISC | Unlicense, _) => Compatible,
+
+            // Weak copyleft: fine for copyleft projects, review the terms otherwise
+            (MPL2, Proprietary) => Warning,
+            (MPL2, _) => Compatible,
+            (LGPL, LGPL | GPL2 | GPL3 | AGPL) => Compatible,
+            (LGPL, _) => Warning,
+
+            // Strong copyleft requires the project to be under a compatible license
+            (GPL2, GPL2) | (GPL3, GPL3 | AGPL) | (AGPL, AGPL) => Compatible,
+            (GPL2, GPL3 | AGPL) | (AGPL, GPL3) => Warning,
+            (GPL2 | GPL3 | AGPL, _) => Incompatible,
+
+            (Proprietary, Proprietary) => Compatible,
+            (Proprietary, GPL2 | GPL3 | AGPL) => Incompatible,
+            (Proprietary, _) => Warning,
+        }
+    }
+
     /// Get a human-readable compatibility message
     pub fn compatibility_message(&self, other: &License) -> String {
         match self.check_compatibility(other) {
@@ -413,6 +447,24 @@ mod tests {
         assert_eq!(License::parse_license("GPL-3.0"), License::GPL3);
     }
 
+    #[test]
+    fn test_dependency_compatibility() {
+        assert_eq!(
+            License::GPL3.dependency_compatibility(&License::MIT),
+            LicenseCompatibility::Incompatible
+        );
+        assert_eq!(
+            License::MIT.dependency_compatibility(&License::GPL3),
+            LicenseCompatibility::Compatible
+        );
+        assert_eq!(
+            License::LGPL.dependency_compatibility(&License::Apache2),
+            LicenseCompatibility::Warning
+        );
+        assert_eq!(License::parse_license("LGPL-2.1-or-later"), License::LGPL);
+        assert_eq!(License::parse_license("AGPL-3.0"), License::AGPL);
+    }
+
     #[test]
     fn test_license_compatibility() {
         assert_eq!(
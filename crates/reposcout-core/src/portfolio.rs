@@ -2,6 +2,7 @@ use crate::models::Repository;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// A portfolio/watchlist containing grouped repositories
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -172,6 +173,72 @@ impl PortfolioManager {
         }
     }
 
+    /// Load saved portfolios, or start empty if none were saved yet
+    pub fn load() -> crate::Result<Self> {
+        Self::load_from(&Self::store_path()?)
+    }
+
+    pub fn load_from(path: &Path) -> crate::Result<Self> {
+        let mut manager = Self::new();
+        if path.exists() {
+            let contents = std::fs::read_to_string(path)?;
+            let portfolios: Vec<Portfolio> = serde_json::from_str(&contents).map_err(|e| {
+                crate::Error::ConfigError(format!("Failed to parse portfolios: {}", e))
+            })?;
+            for portfolio in portfolios {
+                manager.portfolios.insert(portfolio.id.clone(), portfolio);
+            }
+        }
+        Ok(manager)
+    }
+
+    /// Save all portfolios to disk
+    pub fn save(&self) -> crate::Result<()> {
+        self.save_to(&Self::store_path()?)
+    }
+
+    pub fn save_to(&self, path: &Path) -> crate::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut portfolios = self.list_portfolios();
+        portfolios.sort_by_key(|p| p.created_at);
+        let contents = serde_json::to_string_pretty(&portfolios).map_err(|e| {
+            crate::Error::ConfigError(format!("Failed to serialize portfolios: {}", e))
+        })?;
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Portfolios live next to the token store in the data directory
+    fn store_path() -> crate::Result<PathBuf> {
+        let data_dir = dirs::data_dir()
+            .ok_or_else(|| crate::Error::ConfigError("Could not find data directory".into()))?;
+        Ok(data_dir.join("reposcout").join("portfolios.json"))
+    }
+
+    /// Add a portfolio from its JSON export under a fresh id, so importing
+    /// the same file twice (or on the machine it came from) can't collide
+    pub fn import_json(&mut self, json: &str) -> crate::Result<Portfolio> {
+        let mut portfolio: Portfolio = serde_json::from_str(json)
+            .map_err(|e| crate::Error::ConfigError(format!("Not a portfolio export: {}", e)))?;
+        portfolio.id = uuid::Uuid::new_v4().to_string();
+        portfolio.updated_at = Utc::now();
+
+        self.portfolios
+            .insert(portfolio.id.clone(), portfolio.clone());
+        Ok(portfolio)
+    }
+
+    /// Look a portfolio up by id, falling back to a case-insensitive name match
+    pub fn find_portfolio(&self, id_or_name: &str) -> Option<&Portfolio> {
+        self.get_portfolio(id_or_name).or_else(|| {
+            self.portfolios
+                .values()
+                .find(|p| p.name.eq_ignore_ascii_case(id_or_name))
+        })
+    }
+
     /// Create a new portfolio
     pub fn create_portfolio(
         &mut self,
@@ -389,14 +456,69 @@ impl Portfolio {
     /// Get most recently added repos
     pub fn recent_repos(&self, limit: usize) -> Vec<&WatchedRepo> {
         let mut repos: Vec<_> = self.repos.iter().collect();
-        repos.sort_by(|a, b| b.added_at.cmp(&a.added_at));
+        repos.sort_by_key(|r| std::cmp::Reverse(r.added_at));
         repos.into_iter().take(limit).collect()
     }
 
     /// Get repos sorted by stars
     pub fn top_starred_repos(&self, limit: usize) -> Vec<&WatchedRepo> {
         let mut repos: Vec<_> = self.repos.iter().collect();
-        repos.sort_by(|a, b| b.repo.stars.cmp(&a.repo.stars));
+        repos.sort_by_key(|r| std::cmp::Reverse(r.repo.stars));
         repos.into_iter().take(limit).collect()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::Exporter;
+    use crate::models::Platform;
+
+    #[test]
+    fn test_portfolio_export_and_import() {
+        let repo = Repository {
+            description: Some("Async runtime | batteries included".to_string()),
+            url: "https://github.com/tokio-rs/tokio".to_string(),
+            language: Some("Rust".to_string()),
+            default_branch: "master".to_string(),
+            ..Repository::test_fixture(Platform::GitHub, "tokio-rs/tokio", 25_300)
+        };
+
+        let mut manager = PortfolioManager::new();
+        let original = manager.create_portfolio(
+            "Async".to_string(),
+            None,
+            PortfolioColor::Green,
+            PortfolioIcon::Stars,
+        );
+        manager
+            .add_repo_to_portfolio(
+                &original.id,
+                repo,
+                Some("Default runtime\nfor services".to_string()),
+                vec!["runtime".to_string()],
+            )
+            .unwrap();
+        let portfolio = manager.find_portfolio("async").unwrap();
+
+        let markdown = Exporter::portfolio_to_markdown(portfolio);
+        assert!(markdown.starts_with("# ⭐ Async\n"));
+        assert!(markdown.contains("**Color:** Green | **Repositories:** 1"));
+        assert!(markdown.contains(
+            "| [tokio-rs/tokio](https://github.com/tokio-rs/tokio) | GitHub | 25.3k | \
+             Async runtime \\| batteries included | Default runtime for services `runtime` |"
+        ));
+
+        let json = serde_json::to_string(portfolio).unwrap();
+        let imported = manager.import_json(&json).unwrap();
+        assert_ne!(imported.id, original.id);
+        assert_eq!(imported.name, "Async");
+        assert_eq!(
+            imported.repos[0].notes.as_deref(),
+            Some("Default runtime\nfor services")
+        );
+        assert_eq!(manager.list_portfolios().len(), 2);
+
+        assert!(manager.import_json("{}").is_err());
+    }
+}
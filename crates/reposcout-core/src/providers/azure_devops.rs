@@ -0,0 +1,91 @@
+// Azure DevOps provider implementation - bridges API client with SearchProvider trait
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reposcout_api::{AzureDevOpsClient, AzureDevOpsRepository};
+
+use crate::{
+    models::{Platform, Repository},
+    search::SearchProvider,
+    Result,
+};
+
+/// Wrapper around AzureDevOpsClient that implements SearchProvider
+pub struct AzureDevOpsProvider {
+    client: AzureDevOpsClient,
+}
+
+impl AzureDevOpsProvider {
+    pub fn new(
+        token: Option<String>,
+        organization: Option<String>,
+        project: Option<String>,
+    ) -> Self {
+        Self::with_client(AzureDevOpsClient::new(token, organization, project))
+    }
+
+    /// Use an existing client
+    pub fn with_client(client: AzureDevOpsClient) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait]
+impl SearchProvider for AzureDevOpsProvider {
+    async fn search(&self, query: &str) -> Result<Vec<Repository>> {
+        crate::ensure_online("Azure DevOps search")?;
+        let repos = self.client.search_repositories(query, 30).await?;
+
+        Ok(repos.into_iter().map(azure_devops_to_repo).collect())
+    }
+
+    async fn get_repository(&self, owner: &str, name: &str) -> Result<Repository> {
+        crate::ensure_online("Fetching from Azure DevOps")?;
+        // Repositories are addressed as "project/name" within the organization
+        let repo = self.client.get_repository(owner, name).await?;
+
+        Ok(azure_devops_to_repo(repo))
+    }
+
+    fn platform(&self) -> Platform {
+        Platform::AzureDevOps
+    }
+}
+
+/// Convert Azure DevOps API repository to our internal Repository model
+fn azure_devops_to_repo(az: AzureDevOpsRepository) -> Repository {
+    // Azure Repos has no stars, forks counts or watchers, and the repository
+    // listing carries no dates, so the project's last update stands in
+    let updated = az
+        .project
+        .last_update_time
+        .unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
+
+    Repository {
+        platform: Platform::AzureDevOps,
+        full_name: az.full_name(),
+        url: az.web_url.clone(),
+        description: az.project.description.clone(),
+        homepage_url: None,
+        stars: 0,       // No stars on Azure Repos
+        forks: 0,       // Fork counts aren't reported
+        watchers: 0,    // No followers either
+        open_issues: 0, // Work items live in Azure Boards
+        open_merge_requests: None,
+        language: None, // Not reported by the repositories API
+        topics: Vec::new(),
+        license: None,
+        created_at: updated,
+        updated_at: updated,
+        pushed_at: updated,
+        size: az.size / 1024, // Bytes, we store KB like GitHub
+        default_branch: az.default_branch_name().unwrap_or("main").to_string(),
+        is_archived: az.is_disabled,
+        is_private: az.project.visibility.as_deref() != Some("public"),
+        is_fork: az.is_fork,
+        languages: None,
+        mirrors: Vec::new(),
+        contributors: None,
+        commit_activity: None,
+        health: None,
+    }
+}
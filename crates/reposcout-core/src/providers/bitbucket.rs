@@ -1,13 +1,20 @@
 // Bitbucket provider implementation - bridges API client with SearchProvider trait
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use reposcout_api::{BitbucketClient, BitbucketRepository};
 
 use crate::{
+    fetch_concurrently,
     models::{Platform, Repository},
-    search::SearchProvider,
-    Error, Result,
+    search::{SearchPage, SearchProvider},
+    trending::TrendingFilters,
+    Result, DEFAULT_CONCURRENCY,
 };
 
+/// Recently updated repositories whose watchers are looked up per trending
+/// request (each lookup is one API request)
+const MAX_WATCHER_LOOKUPS: u32 = 30;
+
 /// Wrapper around BitbucketClient that implements SearchProvider
 pub struct BitbucketProvider {
     client: BitbucketClient,
@@ -15,32 +22,107 @@ pub struct BitbucketProvider {
 
 impl BitbucketProvider {
     pub fn new(username: Option<String>, app_password: Option<String>) -> Self {
-        Self {
-            client: BitbucketClient::new(username, app_password),
+        Self::with_client(BitbucketClient::new(username, app_password))
+    }
+
+    /// Use an existing client, e.g. one for Bitbucket Data Center
+    pub fn with_client(client: BitbucketClient) -> Self {
+        Self { client }
+    }
+
+    /// Convert API repositories. Data Center reports no dates, so each
+    /// repository's latest commit is looked up for them (one request per
+    /// repository); the creation date isn't known either, so as with Azure
+    /// DevOps the latest change stands in.
+    async fn to_repos(&self, repos: Vec<BitbucketRepository>) -> Vec<Repository> {
+        if !self.client.is_server() {
+            return repos.into_iter().map(bitbucket_to_repo).collect();
         }
+        fetch_concurrently(repos, DEFAULT_CONCURRENCY, |bb| async move {
+            let changed = match bb.full_name.split_once('/') {
+                Some((project, slug)) => self
+                    .client
+                    .last_commit_date(project, slug)
+                    .await
+                    .ok()
+                    .flatten(),
+                None => None,
+            };
+            let mut repo = bitbucket_to_repo(bb);
+            if let Some(changed) = changed {
+                repo.created_at = changed;
+                repo.updated_at = changed;
+                repo.pushed_at = changed;
+            }
+            repo
+        })
+        .await
     }
 }
 
 #[async_trait]
 impl SearchProvider for BitbucketProvider {
     async fn search(&self, query: &str) -> Result<Vec<Repository>> {
-        let repos = self
-            .client
-            .search_repositories(query, 30)
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+        Ok(self.search_page(query, None).await?.repos)
+    }
+
+    async fn search_page(&self, query: &str, cursor: Option<&str>) -> Result<SearchPage> {
+        crate::ensure_online("Bitbucket search")?;
+        let (repos, next) = self.client.search_repositories_page(query, cursor).await?;
 
-        Ok(repos.into_iter().map(bitbucket_to_repo).collect())
+        Ok(SearchPage {
+            repos: self.to_repos(repos).await,
+            next,
+        })
     }
 
     async fn get_repository(&self, owner: &str, name: &str) -> Result<Repository> {
-        let repo = self
+        crate::ensure_online("Fetching from Bitbucket")?;
+        let repo = self.client.get_repository(owner, name).await?;
+
+        Ok(self.to_repos(vec![repo]).await.remove(0))
+    }
+
+    fn platform(&self) -> Platform {
+        Platform::Bitbucket
+    }
+
+    async fn trending(
+        &self,
+        since: DateTime<Utc>,
+        filters: &TrendingFilters,
+    ) -> Result<Vec<Repository>> {
+        // Bitbucket has no topics, so nothing can match a topic filter
+        if filters.topic.is_some() {
+            return Ok(Vec::new());
+        }
+
+        crate::ensure_online("Bitbucket trending")?;
+        let recent = self
             .client
-            .get_repository(owner, name)
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+            .recently_updated_repositories(since, filters.language.as_deref(), MAX_WATCHER_LOOKUPS)
+            .await?;
+
+        // Watchers stand in for stars, the listing doesn't include them
+        let names: Vec<String> = recent.iter().map(|bb| bb.full_name.clone()).collect();
+        let watchers = fetch_concurrently(names, DEFAULT_CONCURRENCY, |full_name| async move {
+            let (workspace, slug) = full_name.split_once('/')?;
+            self.client.get_watcher_count(workspace, slug).await.ok()
+        })
+        .await;
 
-        Ok(bitbucket_to_repo(repo))
+        let min_stars = filters.min_stars.unwrap_or(0);
+        Ok(recent
+            .into_iter()
+            .zip(watchers)
+            .filter_map(|(bb, watchers)| {
+                let watchers = watchers?;
+                let mut repo = bitbucket_to_repo(bb);
+                repo.stars = watchers;
+                repo.watchers = watchers;
+                (watchers >= min_stars).then_some(repo)
+            })
+            .collect())
     }
 }
 
@@ -58,6 +140,7 @@ fn bitbucket_to_repo(bb: BitbucketRepository) -> Repository {
         forks: 0,           // Would need additional API call
         watchers: 0,        // Would need additional API call
         open_issues: 0,     // Bitbucket has issues but count requires additional API call
+        open_merge_requests: None,
         language: bb.language,
         topics: Vec::new(), // Bitbucket doesn't have topics/tags in API v2.0
         license: None,      // Would need to parse from repository files
@@ -71,6 +154,11 @@ fn bitbucket_to_repo(bb: BitbucketRepository) -> Repository {
             .unwrap_or_else(|| "main".to_string()),
         is_archived: false, // Would need additional API call
         is_private: bb.is_private,
+        is_fork: bb.parent.is_some(),
+        languages: None,
+        mirrors: Vec::new(),
+        contributors: None,
+        commit_activity: None,
         health: None,
     }
 }
@@ -1,13 +1,22 @@
 // GitHub provider implementation - bridges API client with SearchProvider trait
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use reposcout_api::{GitHubClient, GitHubRepo};
+use std::collections::HashMap;
 
 use crate::{
     models::{Platform, Repository},
-    search::SearchProvider,
-    Error, Result,
+    search::{SearchPage, SearchProvider},
+    trending::{github_trending_query, TrendingFilters},
+    Result,
 };
 
+/// Results per search page
+const PER_PAGE: u32 = 30;
+
+/// GitHub stops serving search results past this many
+const MAX_SEARCH_RESULTS: u32 = 1000;
+
 /// Wrapper around GitHubClient that implements SearchProvider
 pub struct GitHubProvider {
     client: GitHubClient,
@@ -19,29 +28,62 @@ impl GitHubProvider {
             client: GitHubClient::new(token),
         }
     }
+
+    /// Search through an existing client, sharing its rate limit tracking
+    pub fn with_client(client: GitHubClient) -> Self {
+        Self { client }
+    }
 }
 
 #[async_trait]
 impl SearchProvider for GitHubProvider {
     async fn search(&self, query: &str) -> Result<Vec<Repository>> {
+        Ok(self.search_page(query, None).await?.repos)
+    }
+
+    async fn search_page(&self, query: &str, cursor: Option<&str>) -> Result<SearchPage> {
+        crate::ensure_online("GitHub search")?;
+        let page = cursor.and_then(|c| c.parse::<u32>().ok()).unwrap_or(1);
         let repos = self
             .client
-            .search_repositories(query, 30)
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+            .search_repositories_page(query, PER_PAGE, page)
+            .await?;
 
-        Ok(repos.into_iter().map(github_to_repo).collect())
+        // A short page is the last one
+        let next = (repos.len() as u32 == PER_PAGE && page * PER_PAGE < MAX_SEARCH_RESULTS)
+            .then(|| (page + 1).to_string());
+        Ok(SearchPage {
+            repos: repos.into_iter().map(github_to_repo).collect(),
+            next,
+        })
     }
 
     async fn get_repository(&self, owner: &str, name: &str) -> Result<Repository> {
-        let repo = self
-            .client
-            .get_repository(owner, name)
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+        crate::ensure_online("Fetching from GitHub")?;
+        let repo = self.client.get_repository(owner, name).await?;
 
         Ok(github_to_repo(repo))
     }
+
+    fn platform(&self) -> Platform {
+        Platform::GitHub
+    }
+
+    async fn trending(
+        &self,
+        since: DateTime<Utc>,
+        filters: &TrendingFilters,
+    ) -> Result<Vec<Repository>> {
+        // Newly created repositories that already gathered stars
+        self.search(&github_trending_query(since, filters)).await
+    }
+
+    async fn get_languages(&self, owner: &str, name: &str) -> Result<HashMap<String, f64>> {
+        crate::ensure_online("GitHub language stats")?;
+        let bytes = self.client.get_languages(owner, name).await?;
+
+        Ok(crate::trending::languages_to_percentages(&bytes))
+    }
 }
 
 /// Convert GitHub API repo to our internal Repository model
@@ -56,6 +98,7 @@ fn github_to_repo(gh: GitHubRepo) -> Repository {
         forks: gh.forks_count,
         watchers: gh.watchers_count,
         open_issues: gh.open_issues_count,
+        open_merge_requests: None,
         language: gh.language,
         topics: gh.topics,
         license: gh.license.map(|l| l.name),
@@ -66,6 +109,11 @@ fn github_to_repo(gh: GitHubRepo) -> Repository {
         default_branch: gh.default_branch,
         is_archived: gh.archived,
         is_private: gh.private,
+        is_fork: gh.fork,
+        languages: None,
+        mirrors: Vec::new(),
+        contributors: None,
+        commit_activity: None,
         health: None,
     }
 }
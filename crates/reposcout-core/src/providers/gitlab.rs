@@ -1,11 +1,15 @@
 // GitLab provider implementation - bridges API client with SearchProvider trait
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use reposcout_api::{GitLabClient, GitLabProject};
+use std::collections::HashMap;
 
 use crate::{
+    fetch_concurrently,
     models::{Platform, Repository},
-    search::SearchProvider,
-    Error, Result,
+    search::{SearchPage, SearchProvider},
+    trending::TrendingFilters,
+    Error, Result, DEFAULT_CONCURRENCY,
 };
 
 /// Wrapper around GitLabClient that implements SearchProvider
@@ -19,30 +23,89 @@ impl GitLabProvider {
             client: GitLabClient::new(token),
         }
     }
+
+    /// Search through an existing client, sharing its rate limit tracking
+    pub fn with_client(client: GitLabClient) -> Self {
+        Self { client }
+    }
+
+    /// Convert API projects, looking up each one's open merge request count
+    /// (one request per project). Best effort: a project is still useful
+    /// without the count.
+    async fn to_repos(&self, projects: Vec<GitLabProject>) -> Vec<Repository> {
+        fetch_concurrently(projects, DEFAULT_CONCURRENCY, |project| async move {
+            let mut repo = gitlab_to_repo(project);
+            repo.open_merge_requests = self
+                .client
+                .get_open_merge_request_count(&repo.full_name)
+                .await
+                .ok();
+            repo
+        })
+        .await
+    }
 }
 
 #[async_trait]
 impl SearchProvider for GitLabProvider {
     async fn search(&self, query: &str) -> Result<Vec<Repository>> {
-        let projects = self
-            .client
-            .search_projects(query, 30)
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+        Ok(self.search_page(query, None).await?.repos)
+    }
 
-        Ok(projects.into_iter().map(gitlab_to_repo).collect())
+    async fn search_page(&self, query: &str, cursor: Option<&str>) -> Result<SearchPage> {
+        crate::ensure_online("GitLab search")?;
+        let page = cursor.and_then(|c| c.parse::<u32>().ok()).unwrap_or(1);
+        let (projects, next_page) = self.client.search_projects_page(query, 30, page).await?;
+
+        Ok(SearchPage {
+            repos: self.to_repos(projects).await,
+            next: next_page.map(|page| page.to_string()),
+        })
     }
 
     async fn get_repository(&self, owner: &str, name: &str) -> Result<Repository> {
+        crate::ensure_online("Fetching from GitLab")?;
         // GitLab uses "owner/name" format as the path
         let path = format!("{}/{}", owner, name);
-        let project = self
+        let project = self.client.get_project(&path).await?;
+
+        Ok(self.to_repos(vec![project]).await.remove(0))
+    }
+
+    fn platform(&self) -> Platform {
+        Platform::GitLab
+    }
+
+    async fn get_languages(&self, owner: &str, name: &str) -> Result<HashMap<String, f64>> {
+        crate::ensure_online("GitLab language stats")?;
+        // GitLab already reports percentages
+        let path = format!("{}/{}", owner, name);
+        self.client.get_languages(&path).await.map_err(Error::from)
+    }
+
+    async fn trending(
+        &self,
+        since: DateTime<Utc>,
+        filters: &TrendingFilters,
+    ) -> Result<Vec<Repository>> {
+        crate::ensure_online("GitLab trending")?;
+        // Most-starred projects that saw activity in the window
+        let projects = self
             .client
-            .get_project(&path)
-            .await
-            .map_err(|e| Error::ApiError(e.to_string()))?;
+            .trending_projects(
+                since,
+                filters.language.as_deref(),
+                filters.topic.as_deref(),
+                30,
+            )
+            .await?;
 
-        Ok(gitlab_to_repo(project))
+        let min_stars = filters.min_stars.unwrap_or(0);
+        let projects = projects
+            .into_iter()
+            .filter(|project| project.star_count >= min_stars)
+            .collect();
+        Ok(self.to_repos(projects).await)
     }
 }
 
@@ -64,6 +127,7 @@ fn gitlab_to_repo(gl: GitLabProject) -> Repository {
         forks: gl.forks_count,
         watchers: 0, // GitLab doesn't have watchers concept
         open_issues: gl.open_issues,
+        open_merge_requests: None,
         language: None, // Would need additional API call to get this
         topics: all_topics,
         license: None, // Would need additional API call to get this
@@ -74,6 +138,11 @@ fn gitlab_to_repo(gl: GitLabProject) -> Repository {
         default_branch: gl.default_branch.unwrap_or_else(|| "main".to_string()),
         is_archived: false, // Would need additional API call
         is_private: gl.visibility != "public",
+        is_fork: gl.forked_from_project.is_some(),
+        languages: None,
+        mirrors: Vec::new(),
+        contributors: None,
+        commit_activity: None,
         health: None,
     }
 }
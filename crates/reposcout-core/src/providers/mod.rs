@@ -1,8 +1,12 @@
 // Provider implementations for different platforms
+pub mod azure_devops;
 pub mod bitbucket;
 pub mod github;
 pub mod gitlab;
+pub mod sourcehut;
 
+pub use azure_devops::AzureDevOpsProvider;
 pub use bitbucket::BitbucketProvider;
 pub use github::GitHubProvider;
 pub use gitlab::GitLabProvider;
+pub use sourcehut::SourceHutProvider;
@@ -0,0 +1,79 @@
+// SourceHut provider implementation - bridges API client with SearchProvider trait
+use async_trait::async_trait;
+use reposcout_api::{SourceHutClient, SourceHutRepository};
+
+use crate::{
+    models::{Platform, Repository},
+    search::SearchProvider,
+    Result,
+};
+
+/// Wrapper around SourceHutClient that implements SearchProvider
+pub struct SourceHutProvider {
+    client: SourceHutClient,
+}
+
+impl SourceHutProvider {
+    pub fn new(token: Option<String>) -> Self {
+        Self {
+            client: SourceHutClient::new(token),
+        }
+    }
+}
+
+#[async_trait]
+impl SearchProvider for SourceHutProvider {
+    async fn search(&self, query: &str) -> Result<Vec<Repository>> {
+        crate::ensure_online("SourceHut search")?;
+        let repos = self.client.search_repositories(query, 30).await?;
+
+        Ok(repos
+            .into_iter()
+            .map(|repo| sourcehut_to_repo(&self.client, repo))
+            .collect())
+    }
+
+    async fn get_repository(&self, owner: &str, name: &str) -> Result<Repository> {
+        crate::ensure_online("Fetching from SourceHut")?;
+        let repo = self.client.get_repository(owner, name).await?;
+
+        Ok(sourcehut_to_repo(&self.client, repo))
+    }
+
+    fn platform(&self) -> Platform {
+        Platform::SourceHut
+    }
+}
+
+/// Convert SourceHut API repository to our internal Repository model
+fn sourcehut_to_repo(client: &SourceHutClient, sh: SourceHutRepository) -> Repository {
+    // SourceHut deliberately has no stars, forks or watchers, so those are zero
+    Repository {
+        platform: Platform::SourceHut,
+        full_name: sh.full_name(),
+        url: client.repository_url(&sh),
+        description: sh.description.clone(),
+        homepage_url: None,
+        stars: 0,       // No stars on SourceHut
+        forks: 0,       // Clones aren't tracked
+        watchers: 0,    // No public follower counts
+        open_issues: 0, // Issues live in a separate todo.sr.ht tracker
+        open_merge_requests: None,
+        language: None, // Not reported by git.sr.ht
+        topics: Vec::new(),
+        license: None,
+        created_at: sh.created,
+        updated_at: sh.updated,
+        pushed_at: sh.updated, // `updated` moves on every push
+        size: 0,
+        default_branch: sh.default_branch().unwrap_or("master").to_string(),
+        is_archived: false,
+        is_private: sh.visibility == "PRIVATE",
+        is_fork: false, // SourceHut doesn't record fork relationships
+        languages: None,
+        mirrors: Vec::new(),
+        contributors: None,
+        commit_activity: None,
+        health: None,
+    }
+}
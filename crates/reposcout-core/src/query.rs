@@ -0,0 +1,94 @@
+// Search query building shared by the CLI and TUI
+//
+// Filters are sent as GitHub search qualifiers like "language:rust
+// stars:>=1000". Other platforms ignore the ones they don't understand and
+// get filtered locally afterwards.
+
+use chrono::NaiveDate;
+
+/// Filters that turn into search qualifiers
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct QueryFilters {
+    pub language: Option<String>,
+    pub min_stars: Option<u32>,
+    pub max_stars: Option<u32>,
+    /// A `pushed:` value such as ">2024-01-01"
+    pub pushed: Option<String>,
+    pub created_after: Option<NaiveDate>,
+    pub created_before: Option<NaiveDate>,
+    pub exclude_forks: bool,
+    pub exclude_archived: bool,
+}
+
+/// Append the filters to `query` as GitHub search qualifiers. Empty
+/// language and pushed values are left out.
+pub fn build_github_query(query: &str, filters: &QueryFilters) -> String {
+    let mut parts = vec![query.to_string()];
+
+    if let Some(lang) = filters.language.as_deref().filter(|l| !l.is_empty()) {
+        parts.push(format!("language:{}", lang));
+    }
+
+    match (filters.min_stars, filters.max_stars) {
+        (Some(min), Some(max)) => parts.push(format!("stars:{}..{}", min, max)),
+        (Some(min), None) => parts.push(format!("stars:>={}", min)),
+        (None, Some(max)) => parts.push(format!("stars:<={}", max)),
+        (None, None) => {}
+    }
+
+    if let Some(pushed) = filters.pushed.as_deref().filter(|p| !p.is_empty()) {
+        parts.push(format!("pushed:{}", pushed));
+    }
+
+    match (filters.created_after, filters.created_before) {
+        (Some(after), Some(before)) => parts.push(format!("created:{}..{}", after, before)),
+        (Some(after), None) => parts.push(format!("created:>={}", after)),
+        (None, Some(before)) => parts.push(format!("created:<={}", before)),
+        (None, None) => {}
+    }
+
+    // Filtering server-side keeps forks from eating into the result quota
+    if filters.exclude_forks {
+        parts.push("fork:false".to_string());
+    }
+    if filters.exclude_archived {
+        parts.push("archived:false".to_string());
+    }
+
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_github_query() {
+        assert_eq!(build_github_query("tui", &QueryFilters::default()), "tui");
+
+        let filters = QueryFilters {
+            language: Some("rust".to_string()),
+            min_stars: Some(100),
+            pushed: Some(">2024-01-01".to_string()),
+            created_before: NaiveDate::from_ymd_opt(2023, 6, 30),
+            exclude_forks: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            build_github_query("tui", &filters),
+            "tui language:rust stars:>=100 pushed:>2024-01-01 created:<=2023-06-30 fork:false"
+        );
+
+        let filters = QueryFilters {
+            language: Some(String::new()),
+            min_stars: Some(10),
+            max_stars: Some(50),
+            exclude_archived: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            build_github_query("cli", &filters),
+            "cli stars:10..50 archived:false"
+        );
+    }
+}
@@ -2,13 +2,23 @@
 // Supports crates.io, npmjs.com, PyPI, and more
 
 use crate::packages::{PackageInfo, PackageManager};
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
+use std::collections::HashMap;
 
 /// Crates.io API response for crate metadata
 #[derive(Debug, Deserialize)]
 struct CratesIoResponse {
     #[serde(rename = "crate")]
     crate_data: CrateData,
+    #[serde(default)]
+    versions: Vec<CrateVersion>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CrateVersion {
+    num: String,
+    license: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -17,10 +27,33 @@ struct CrateData {
     name: String,
     max_version: String,
     downloads: u64,
+    /// Downloads over the last 90 days
+    #[serde(default)]
+    recent_downloads: Option<u64>,
     description: Option<String>,
     homepage: Option<String>,
 }
 
+/// Crates.io reverse dependencies response, only the pagination total is used
+#[derive(Debug, Deserialize)]
+struct ReverseDependenciesResponse {
+    meta: ReverseDependenciesMeta,
+}
+
+#[derive(Debug, Deserialize)]
+struct ReverseDependenciesMeta {
+    total: u64,
+}
+
+/// How widely a crate is used
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrateUsage {
+    /// Number of crates depending on it
+    pub reverse_dependencies: u64,
+    /// Downloads over the last 90 days
+    pub recent_downloads: Option<u64>,
+}
+
 /// npm registry API response
 #[derive(Debug, Deserialize)]
 struct NpmResponse {
@@ -30,6 +63,9 @@ struct NpmResponse {
     #[serde(rename = "dist-tags")]
     dist_tags: NpmDistTags,
     homepage: Option<String>,
+    /// Usually an SPDX string, older packages use a {"type": ...} object
+    #[serde(default)]
+    license: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -61,13 +97,9 @@ pub struct RegistryClient {
 impl RegistryClient {
     /// Create a new registry client
     pub fn new() -> Self {
-        let client = reqwest::Client::builder()
-            .user_agent("RepoScout/0.1.0")
-            .timeout(std::time::Duration::from_secs(10))
-            .build()
-            .unwrap_or_else(|_| reqwest::Client::new());
-
-        Self { client }
+        Self {
+            client: reposcout_api::http::client(),
+        }
     }
 
     /// Fetch package metadata from appropriate registry
@@ -86,10 +118,86 @@ impl RegistryClient {
     /// Fetch metadata from crates.io
     async fn fetch_crates_io(&self, package_info: &mut PackageInfo) -> Result<(), String> {
         let url = format!("https://crates.io/api/v1/crates/{}", package_info.name);
+        let data: CratesIoResponse = self.get_crates_io(&url).await?;
+
+        // Update package info with fetched data
+        package_info.downloads = Some(data.crate_data.downloads);
+        package_info.license = data
+            .versions
+            .iter()
+            .find(|v| v.num == data.crate_data.max_version)
+            .and_then(|v| v.license.clone());
+        package_info.latest_version = Some(data.crate_data.max_version);
+        package_info.description = data.crate_data.description;
+        package_info.homepage = data.crate_data.homepage;
+
+        // Update registry URL to actual package page
+        package_info.registry_url = format!("https://crates.io/crates/{}", package_info.name);
+
+        Ok(())
+    }
+
+    /// Look up the declared licenses of several packages concurrently.
+    ///
+    /// Packages whose metadata can't be fetched, or that declare no license,
+    /// map to None. Registries without metadata support return all None.
+    pub async fn fetch_licenses(
+        &self,
+        manager: PackageManager,
+        names: &[String],
+    ) -> HashMap<String, Option<String>> {
+        let lookups = names.iter().map(|name| async move {
+            let mut package_info = PackageInfo::new(manager, name.clone());
+            let license = match self.fetch_metadata(&mut package_info).await {
+                Ok(()) => package_info.license,
+                Err(e) => {
+                    tracing::debug!("License lookup for {} failed: {}", name, e);
+                    None
+                }
+            };
+            (name.clone(), license)
+        });
+        futures::future::join_all(lookups)
+            .await
+            .into_iter()
+            .collect()
+    }
+
+    /// Fetch reverse dependency and recent download counts from crates.io.
+    /// Returns None for packages that aren't crates.
+    pub async fn fetch_crate_usage(
+        &self,
+        package_info: &PackageInfo,
+    ) -> Result<Option<CrateUsage>, String> {
+        if package_info.manager != PackageManager::Cargo {
+            return Ok(None);
+        }
+
+        // One result per page is enough, meta.total counts all of them
+        let reverse: ReverseDependenciesResponse = self
+            .get_crates_io(&format!(
+                "https://crates.io/api/v1/crates/{}/reverse_dependencies?per_page=1",
+                package_info.name
+            ))
+            .await?;
+        let data: CratesIoResponse = self
+            .get_crates_io(&format!(
+                "https://crates.io/api/v1/crates/{}",
+                package_info.name
+            ))
+            .await?;
+
+        Ok(Some(CrateUsage {
+            reverse_dependencies: reverse.meta.total,
+            recent_downloads: data.crate_data.recent_downloads,
+        }))
+    }
 
+    /// GET a crates.io API endpoint and parse the JSON body
+    async fn get_crates_io<T: DeserializeOwned>(&self, url: &str) -> Result<T, String> {
         let response = self
             .client
-            .get(&url)
+            .get(url)
             .send()
             .await
             .map_err(|e| format!("Failed to fetch from crates.io: {}", e))?;
@@ -98,21 +206,10 @@ impl RegistryClient {
             return Err(format!("crates.io returned status: {}", response.status()));
         }
 
-        let data: CratesIoResponse = response
+        response
             .json()
             .await
-            .map_err(|e| format!("Failed to parse crates.io response: {}", e))?;
-
-        // Update package info with fetched data
-        package_info.latest_version = Some(data.crate_data.max_version);
-        package_info.downloads = Some(data.crate_data.downloads);
-        package_info.description = data.crate_data.description;
-        package_info.homepage = data.crate_data.homepage;
-
-        // Update registry URL to actual package page
-        package_info.registry_url = format!("https://crates.io/crates/{}", package_info.name);
-
-        Ok(())
+            .map_err(|e| format!("Failed to parse crates.io response: {}", e))
     }
 
     /// Fetch metadata from npm registry
@@ -139,6 +236,10 @@ impl RegistryClient {
         package_info.latest_version = Some(data.dist_tags.latest);
         package_info.description = data.description;
         package_info.homepage = data.homepage;
+        package_info.license = data.license.and_then(|license| match license {
+            serde_json::Value::String(s) => Some(s),
+            other => other.get("type")?.as_str().map(str::to_string),
+        });
 
         // Update registry URL
         package_info.registry_url = format!("https://www.npmjs.com/package/{}", package_info.name);
@@ -204,6 +305,18 @@ mod tests {
         assert!(pkg.downloads.is_some());
     }
 
+    #[test]
+    fn test_reverse_dependencies_total() {
+        let json = r#"{
+            "dependencies": [{"id": 1, "crate_id": "tokio", "req": "^1"}],
+            "versions": [],
+            "meta": {"total": 31337}
+        }"#;
+
+        let parsed: ReverseDependenciesResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(parsed.meta.total, 31337);
+    }
+
     #[tokio::test]
     async fn test_fetch_npm() {
         let client = RegistryClient::new();
@@ -1,4 +1,18 @@
-use crate::{models::Repository, Result};
+use crate::{
+    models::{Platform, Repository},
+    trending::TrendingFilters,
+    Result,
+};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// One page of search results and where the next one starts
+#[derive(Debug, Clone, Default)]
+pub struct SearchPage {
+    pub repos: Vec<Repository>,
+    /// Opaque cursor for the following page, None once results run out
+    pub next: Option<String>,
+}
 
 /// Trait for search providers - makes testing easier and keeps things flexible
 ///
@@ -8,6 +22,40 @@ use crate::{models::Repository, Result};
 pub trait SearchProvider: Send + Sync {
     async fn search(&self, query: &str) -> Result<Vec<Repository>>;
     async fn get_repository(&self, owner: &str, name: &str) -> Result<Repository>;
+
+    /// Platform this provider talks to
+    fn platform(&self) -> Platform;
+
+    /// A page of search results, starting after `cursor` (None for the first)
+    ///
+    /// Platforms without paging serve everything as a single page.
+    async fn search_page(&self, query: &str, cursor: Option<&str>) -> Result<SearchPage> {
+        if cursor.is_some() {
+            return Ok(SearchPage::default());
+        }
+        Ok(SearchPage {
+            repos: self.search(query).await?,
+            next: None,
+        })
+    }
+
+    /// Language breakdown as percentages of code (language -> 0-100)
+    ///
+    /// Platforms without a languages API return an empty map.
+    async fn get_languages(&self, _owner: &str, _name: &str) -> Result<HashMap<String, f64>> {
+        Ok(HashMap::new())
+    }
+
+    /// Repositories trending since `since`, as the platform best approximates it
+    ///
+    /// Platforms without a usable heuristic return nothing.
+    async fn trending(
+        &self,
+        _since: DateTime<Utc>,
+        _filters: &TrendingFilters,
+    ) -> Result<Vec<Repository>> {
+        Ok(Vec::new())
+    }
 }
 
 /// The main search engine that coordinates searches across platforms
@@ -1,13 +1,45 @@
 // Search engine with caching support
-use crate::{models::Repository, search::SearchProvider, Result};
+use crate::{
+    config::FilterConfig,
+    models::{Platform, Repository},
+    search::{SearchPage, SearchProvider},
+    Result,
+};
+use futures::stream::{FuturesUnordered, StreamExt};
 use reposcout_cache::CacheManager;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
 use tracing::{debug, info};
 
+/// Most cached repositories an offline text search returns
+const OFFLINE_SEARCH_LIMIT: usize = 50;
+
+/// Pages `load_more` reads from one provider looking for unseen results
+const MAX_PAGES_PER_LOAD: usize = 2;
+
+/// Where the last search left off, so `load_more` can continue it
+#[derive(Default)]
+struct Pagination {
+    query: String,
+    /// Next-page cursor per provider (by registration index). None starts
+    /// over from the first page, which happens after a cache hit; providers
+    /// without an entry have nothing more to give.
+    cursors: HashMap<usize, Option<String>>,
+    /// Everything returned so far, so later pages only add new repositories
+    seen: HashSet<(Platform, String)>,
+}
+
 /// Search engine that checks cache before hitting APIs
 pub struct CachedSearchEngine {
     providers: Vec<Box<dyn SearchProvider>>,
     cache: Option<Arc<CacheManager>>,
+    normalize: bool,
+    filters: FilterConfig,
+    per_provider_limit: Option<usize>,
+    bypass_cache: bool,
+    offline: bool,
+    dedupe: bool,
+    pagination: Mutex<Pagination>,
 }
 
 impl CachedSearchEngine {
@@ -15,6 +47,13 @@ impl CachedSearchEngine {
         Self {
             providers: Vec::new(),
             cache: None,
+            normalize: false,
+            filters: FilterConfig::default(),
+            per_provider_limit: None,
+            bypass_cache: false,
+            offline: false,
+            dedupe: false,
+            pagination: Mutex::new(Pagination::default()),
         }
     }
 
@@ -23,6 +62,13 @@ impl CachedSearchEngine {
             providers: Vec::new(),
             #[allow(clippy::arc_with_non_send_sync)]
             cache: Some(Arc::new(cache)),
+            normalize: false,
+            filters: FilterConfig::default(),
+            per_provider_limit: None,
+            bypass_cache: false,
+            offline: false,
+            dedupe: false,
+            pagination: Mutex::new(Pagination::default()),
         }
     }
 
@@ -30,18 +76,88 @@ impl CachedSearchEngine {
         self.providers.push(provider);
     }
 
+    /// Rank merged results by per-platform popularity instead of raw stars
+    pub fn set_normalize(&mut self, normalize: bool) {
+        self.normalize = normalize;
+    }
+
+    /// Persistent filters (e.g. blocked owners) applied to every result set
+    pub fn set_filters(&mut self, filters: FilterConfig) {
+        self.filters = filters;
+    }
+
+    /// Cap each platform at `limit` results and interleave the platforms
+    /// round-robin, so one big platform can't crowd out the others
+    pub fn set_per_provider_limit(&mut self, limit: Option<usize>) {
+        self.per_provider_limit = limit;
+    }
+
+    /// Skip cache reads and writes, always hitting the providers
+    pub fn set_bypass_cache(&mut self, bypass: bool) {
+        self.bypass_cache = bypass;
+    }
+
+    /// Never call the providers: searches are answered from the query cache,
+    /// falling back to a text search over cached repositories
+    pub fn set_offline(&mut self, offline: bool) {
+        self.offline = offline;
+    }
+
+    /// Collapse cross-platform mirrors into a single result (see `collapse_mirrors`)
+    pub fn set_dedupe(&mut self, dedupe: bool) {
+        self.dedupe = dedupe;
+    }
+
+    /// The cache, unless it's missing or bypassed
+    fn active_cache(&self) -> Option<&CacheManager> {
+        if self.bypass_cache {
+            return None;
+        }
+        self.cache.as_deref()
+    }
+
     /// Search with cache-first strategy
     pub async fn search(&self, query: &str) -> Result<Vec<Repository>> {
+        self.search_with_progress(query, |_| {}).await
+    }
+
+    /// Search with cache-first strategy, reporting results as they arrive
+    ///
+    /// `on_batch` is called once per provider as soon as that provider
+    /// responds, with its filtered results minus anything already reported.
+    /// A cache hit arrives as a single batch. The returned list is the full
+    /// merged set, ranked the same way as `search`.
+    pub async fn search_with_progress<F>(
+        &self,
+        query: &str,
+        mut on_batch: F,
+    ) -> Result<Vec<Repository>>
+    where
+        F: FnMut(&[Repository]),
+    {
+        if self.offline {
+            let results = self.search_offline(query)?;
+            self.reset_pagination(query, HashMap::new(), &results);
+            on_batch(&results);
+            return Ok(results);
+        }
+
         // Try query-specific cache first if available
-        if let Some(cache) = &self.cache {
+        if let Some(cache) = self.active_cache() {
             debug!("Checking query cache for: {}", query);
             match cache.get_query_cache::<Repository>(query) {
                 Ok(mut results) if !results.is_empty() => {
                     info!("Query cache hit! Found {} results", results.len());
-                    // Calculate health metrics for cached results (in case they were cached before health was added)
+                    // Results cached before health was added, or under other weights, lack it
                     for repo in &mut results {
-                        repo.calculate_health();
+                        repo.ensure_health();
                     }
+                    // The cache doesn't know the cursors, so more results start over
+                    let cursors = (0..self.providers.len()).map(|i| (i, None)).collect();
+                    self.reset_pagination(query, cursors, &results);
+                    self.filters.retain_allowed(&mut results);
+                    let results = self.rank(results);
+                    on_batch(&results);
                     return Ok(results);
                 }
                 Ok(_) => debug!("Query cache miss - no results"),
@@ -49,41 +165,392 @@ impl CachedSearchEngine {
             }
         }
 
-        // Cache miss - hit the APIs
+        // Cache miss - hit the APIs, handing each provider's results over as they land
         info!("Fetching from providers for query: {}", query);
-        let mut results = self.search_providers(query).await?;
+        let mut results = Vec::new();
+        let mut seen = HashSet::new();
+        let mut reported: HashMap<Platform, usize> = HashMap::new();
+        let mut cursors = HashMap::new();
+        let mut pending: FuturesUnordered<_> = self
+            .providers
+            .iter()
+            .enumerate()
+            .map(
+                |(index, provider)| async move { (index, provider.search_page(query, None).await) },
+            )
+            .collect();
 
-        // Calculate health metrics for all results
-        for repo in &mut results {
-            repo.calculate_health();
+        while let Some((index, outcome)) = pending.next().await {
+            let mut batch = match outcome {
+                Ok(SearchPage { repos, next }) => {
+                    if next.is_some() {
+                        cursors.insert(index, next);
+                    }
+                    repos
+                }
+                Err(e) => {
+                    debug!("Provider search failed: {}", e);
+                    continue;
+                }
+            };
+
+            // Drop repeats within a platform (its search can list a repo twice).
+            // The same name on two platforms is kept; merging mirrors is what
+            // `set_dedupe` is for, since it needs both copies to pick one.
+            batch.retain(|repo| seen.insert((repo.platform, repo.full_name.clone())));
+            for repo in &mut batch {
+                repo.ensure_health();
+            }
+            results.extend(batch.iter().cloned());
+
+            // Blocked owners stay in `results` so the cache holds the unfiltered set
+            self.filters.retain_allowed(&mut batch);
+            if let Some(limit) = self.per_provider_limit {
+                batch.retain(|repo| {
+                    let count = reported.entry(repo.platform).or_insert(0);
+                    *count += 1;
+                    *count <= limit
+                });
+            }
+            if !batch.is_empty() {
+                on_batch(&batch);
+            }
         }
 
+        self.reset_pagination(query, cursors, &results);
+
         // Store results in query cache
-        if let Some(cache) = &self.cache {
+        if let Some(cache) = self.active_cache() {
             if let Err(e) = cache.set_query_cache(query, &results) {
                 debug!("Failed to cache query results: {}", e);
             } else {
                 info!("Cached {} repositories for query: {}", results.len(), query);
             }
         }
+        self.record_star_counts(&results);
+
+        // Filter after caching so unblocking an owner takes effect without a refetch
+        self.filters.retain_allowed(&mut results);
+
+        Ok(self.rank(results))
+    }
+
+    /// Start tracking pages for a new search
+    fn reset_pagination(
+        &self,
+        query: &str,
+        cursors: HashMap<usize, Option<String>>,
+        results: &[Repository],
+    ) {
+        let mut pagination = self.pagination.lock().unwrap();
+        *pagination = Pagination {
+            query: query.to_string(),
+            cursors,
+            seen: results
+                .iter()
+                .map(|repo| (repo.platform, repo.full_name.clone()))
+                .collect(),
+        };
+    }
+
+    /// Whether `load_more` can add to the last search
+    ///
+    /// Never true offline or with a per-provider limit, since both cap the
+    /// result set on purpose.
+    pub fn has_more(&self) -> bool {
+        !self.offline
+            && self.per_provider_limit.is_none()
+            && !self.pagination.lock().unwrap().cursors.is_empty()
+    }
+
+    /// Fetch the next page of the last search from every provider that has one
+    ///
+    /// Returns only repositories not already returned for this search,
+    /// filtered but not re-ranked, so callers can append them as they are.
+    /// A provider that fails keeps its place and is retried next time; the
+    /// call only fails if every provider did.
+    pub async fn load_more(&self) -> Result<Vec<Repository>> {
+        if !self.has_more() {
+            return Ok(Vec::new());
+        }
+        let (query, cursors) = {
+            let pagination = self.pagination.lock().unwrap();
+            (pagination.query.clone(), pagination.cursors.clone())
+        };
 
+        let query = query.as_str();
+        let mut pending: FuturesUnordered<_> = cursors
+            .into_iter()
+            .filter_map(|(index, cursor)| {
+                let provider = self.providers.get(index)?;
+                Some(async move {
+                    (
+                        index,
+                        self.next_unseen_page(provider.as_ref(), query, cursor)
+                            .await,
+                    )
+                })
+            })
+            .collect();
+
+        let mut results = Vec::new();
+        let mut last_error = None;
+        let mut succeeded = false;
+        while let Some((index, outcome)) = pending.next().await {
+            match outcome {
+                Ok((repos, next)) => {
+                    succeeded = true;
+                    let mut pagination = self.pagination.lock().unwrap();
+                    // A new search started meanwhile; these pages belong to the old one
+                    if pagination.query != query {
+                        return Ok(Vec::new());
+                    }
+                    match next {
+                        Some(next) => pagination.cursors.insert(index, Some(next)),
+                        None => pagination.cursors.remove(&index),
+                    };
+                    results.extend(repos);
+                }
+                Err(e) => {
+                    debug!("Loading more results failed: {}", e);
+                    last_error = Some(e);
+                }
+            }
+        }
+        if let (false, Some(e)) = (succeeded, last_error) {
+            return Err(e);
+        }
+
+        for repo in &mut results {
+            repo.ensure_health();
+        }
+        self.record_star_counts(&results);
+        self.filters.retain_allowed(&mut results);
         Ok(results)
     }
 
+    /// Snapshot the star counts of freshly fetched results for growth trends
+    fn record_star_counts(&self, repos: &[Repository]) {
+        let Some(cache) = self.active_cache() else {
+            return;
+        };
+        for repo in repos {
+            let platform = repo.platform.to_string();
+            if let Err(e) = cache.record_stars(&platform, &repo.full_name, repo.stars) {
+                debug!("Failed to record star count: {}", e);
+                return;
+            }
+        }
+    }
+
+    /// Read pages from `cursor` on until one holds something unseen, giving
+    /// up after a few. Returns the new repositories and the cursor after them.
+    async fn next_unseen_page(
+        &self,
+        provider: &dyn SearchProvider,
+        query: &str,
+        mut cursor: Option<String>,
+    ) -> Result<(Vec<Repository>, Option<String>)> {
+        for _ in 0..MAX_PAGES_PER_LOAD {
+            let SearchPage { mut repos, next } =
+                provider.search_page(query, cursor.as_deref()).await?;
+            {
+                let mut pagination = self.pagination.lock().unwrap();
+                repos.retain(|repo| {
+                    pagination
+                        .seen
+                        .insert((repo.platform, repo.full_name.clone()))
+                });
+            }
+            cursor = next;
+            if !repos.is_empty() || cursor.is_none() {
+                return Ok((repos, cursor));
+            }
+        }
+        Ok((Vec::new(), cursor))
+    }
+
+    /// Answer a search from the cache alone
+    fn search_offline(&self, query: &str) -> Result<Vec<Repository>> {
+        let cache = self.cache.as_deref().ok_or_else(|| {
+            crate::Error::Offline("search needs a cache to serve results from".into())
+        })?;
+
+        let mut results = match cache.get_query_cache::<Repository>(query) {
+            Ok(results) if !results.is_empty() => results,
+            _ => {
+                debug!("Offline: no cached query, searching cached repositories");
+                cache
+                    .search_text::<Repository>(query, OFFLINE_SEARCH_LIMIT)
+                    .map_err(|e| crate::Error::CacheError(e.to_string()))?
+            }
+        };
+        for repo in &mut results {
+            repo.ensure_health();
+        }
+        self.filters.retain_allowed(&mut results);
+        Ok(self.rank(results))
+    }
+
+    /// Apply mirror collapsing, the per-provider cap and normalized ranking, if enabled
+    fn rank(&self, mut results: Vec<Repository>) -> Vec<Repository> {
+        if self.dedupe {
+            results = Self::collapse_mirrors(results);
+        }
+        if let Some(limit) = self.per_provider_limit {
+            let order: Vec<Platform> = self.providers.iter().map(|p| p.platform()).collect();
+            results = Self::interleave_platforms(results, limit, &order);
+        }
+        if self.normalize {
+            Self::rank_by_normalized_popularity(&mut results);
+        }
+        results
+    }
+
+    /// Keep the first `limit` results of each platform and merge them
+    /// round-robin, one result per platform at a time.
+    ///
+    /// Platforms take turns in `order` (the provider registration order);
+    /// any platform not listed there follows in order of first appearance.
+    /// Within a platform the original ranking is preserved.
+    pub fn interleave_platforms(
+        results: Vec<Repository>,
+        limit: usize,
+        order: &[Platform],
+    ) -> Vec<Repository> {
+        let mut buckets: Vec<(Platform, VecDeque<Repository>)> = Vec::new();
+        for platform in order {
+            if !buckets.iter().any(|(p, _)| p == platform) {
+                buckets.push((*platform, VecDeque::new()));
+            }
+        }
+        for repo in results {
+            let index = match buckets.iter().position(|(p, _)| *p == repo.platform) {
+                Some(index) => index,
+                None => {
+                    buckets.push((repo.platform, VecDeque::new()));
+                    buckets.len() - 1
+                }
+            };
+            let bucket = &mut buckets[index].1;
+            if bucket.len() < limit {
+                bucket.push_back(repo);
+            }
+        }
+
+        let mut interleaved = Vec::new();
+        loop {
+            let before = interleaved.len();
+            for (_, bucket) in &mut buckets {
+                if let Some(repo) = bucket.pop_front() {
+                    interleaved.push(repo);
+                }
+            }
+            if interleaved.len() == before {
+                break;
+            }
+        }
+        interleaved
+    }
+
+    /// Collapse the same project showing up on several platforms.
+    ///
+    /// Two results are mirrors when they're on different platforms, their
+    /// repo names (the part after the owner) match case-insensitively, and
+    /// their descriptions are similar: either one is missing, or at least
+    /// half of their words are shared. Same-platform results are never
+    /// merged, since those are separate projects that happen to share a name.
+    ///
+    /// The mirror with the most stars is kept (earlier results win ties) and
+    /// lists the others in `mirrors`. It takes the place of the first mirror
+    /// seen, so the surrounding order is preserved.
+    pub fn collapse_mirrors(results: Vec<Repository>) -> Vec<Repository> {
+        let mut groups: Vec<Vec<Repository>> = Vec::new();
+        for repo in results {
+            let group = groups.iter_mut().find(|group| {
+                group.iter().all(|other| other.platform != repo.platform)
+                    && repo_name(&group[0]).eq_ignore_ascii_case(repo_name(&repo))
+                    && similar_descriptions(&group[0], &repo)
+            });
+            match group {
+                Some(group) => group.push(repo),
+                None => groups.push(vec![repo]),
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|mut group| {
+                let mut best = 0;
+                for (i, repo) in group.iter().enumerate() {
+                    if repo.stars > group[best].stars {
+                        best = i;
+                    }
+                }
+                let mut kept = group.swap_remove(best);
+                kept.mirrors.extend(
+                    group
+                        .into_iter()
+                        .map(|mirror| (mirror.platform, mirror.full_name)),
+                );
+                kept
+            })
+            .collect()
+    }
+
+    /// Sort results by star percentile within their own platform.
+    ///
+    /// GitHub's user base dwarfs GitLab's and Bitbucket's, so raw star counts
+    /// bury smaller-platform projects. Ranking each repo against the other
+    /// results from the same platform makes the merged list comparable.
+    /// Ties fall back to raw stars.
+    pub fn rank_by_normalized_popularity(results: &mut [Repository]) {
+        let mut platform_stars: Vec<(Platform, Vec<u32>)> = Vec::new();
+        for repo in results.iter() {
+            match platform_stars.iter_mut().find(|(p, _)| *p == repo.platform) {
+                Some((_, stars)) => stars.push(repo.stars),
+                None => platform_stars.push((repo.platform, vec![repo.stars])),
+            }
+        }
+
+        let percentile = |repo: &Repository| -> f64 {
+            let stars = platform_stars
+                .iter()
+                .find(|(p, _)| *p == repo.platform)
+                .map(|(_, s)| s.as_slice())
+                .unwrap_or_default();
+            let below = stars.iter().filter(|&&s| s < repo.stars).count() as f64;
+            let equal = stars.iter().filter(|&&s| s == repo.stars).count() as f64;
+            // Mid-rank percentile so ties share the same score
+            (below + equal / 2.0) / stars.len().max(1) as f64
+        };
+
+        results.sort_by(|a, b| {
+            percentile(b)
+                .partial_cmp(&percentile(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| b.stars.cmp(&a.stars))
+        });
+    }
+
     /// Get repository with cache
     pub async fn get_repository(&self, owner: &str, name: &str) -> Result<Repository> {
         let full_name = format!("{}/{}", owner, name);
 
+        if self.offline {
+            return self
+                .cache
+                .as_deref()
+                .and_then(|cache| cached_repository(cache, &full_name))
+                .ok_or_else(|| crate::Error::Offline(format!("{} isn't cached", full_name)));
+        }
+
         // Try cache first
-        if let Some(cache) = &self.cache {
+        if let Some(cache) = self.active_cache() {
             debug!("Checking cache for repository: {}", full_name);
-            // Try all platforms since we don't know which one it's from
-            for platform in &["GitHub", "GitLab", "Bitbucket"] {
-                if let Ok(mut repo) = cache.get::<Repository>(platform, &full_name) {
-                    info!("Cache hit for {}", full_name);
-                    repo.calculate_health();
-                    return Ok(repo);
-                }
+            if let Some(repo) = cached_repository(cache, &full_name) {
+                info!("Cache hit for {}", full_name);
+                return Ok(repo);
             }
         }
 
@@ -95,13 +562,14 @@ impl CachedSearchEngine {
             match provider.get_repository(owner, name).await {
                 Ok(mut repo) => {
                     // Calculate health metrics
-                    repo.calculate_health();
+                    repo.ensure_health();
                     // Cache it
-                    if let Some(cache) = &self.cache {
+                    if let Some(cache) = self.active_cache() {
                         if let Err(e) = cache.set(&repo.platform.to_string(), &full_name, &repo) {
                             debug!("Failed to cache {}: {}", full_name, e);
                         }
                     }
+                    self.record_star_counts(std::slice::from_ref(&repo));
                     return Ok(repo);
                 }
                 Err(e) => {
@@ -115,25 +583,43 @@ impl CachedSearchEngine {
         Err(last_error
             .unwrap_or_else(|| crate::Error::ConfigError("No search providers configured".into())))
     }
+}
 
-    /// Search across all providers (without cache)
-    async fn search_providers(&self, query: &str) -> Result<Vec<Repository>> {
-        use futures::future::join_all;
-
-        let searches: Vec<_> = self
-            .providers
-            .iter()
-            .map(|provider| provider.search(query))
-            .collect();
+/// A cached repository under any platform, since the caller doesn't know
+/// which one it's from
+fn cached_repository(cache: &CacheManager, full_name: &str) -> Option<Repository> {
+    Platform::ALL.iter().find_map(|platform| {
+        let mut repo = cache
+            .get::<Repository>(&platform.to_string(), full_name)
+            .ok()?;
+        repo.ensure_health();
+        Some(repo)
+    })
+}
 
-        let results = join_all(searches).await;
+/// Repo name without the owner ("tokio" for "tokio-rs/tokio")
+fn repo_name(repo: &Repository) -> &str {
+    repo.full_name.rsplit('/').next().unwrap_or(&repo.full_name)
+}
 
-        let mut repos = Vec::new();
-        for mut r in results.into_iter().flatten() {
-            repos.append(&mut r);
+/// Whether two descriptions plausibly describe the same project
+fn similar_descriptions(a: &Repository, b: &Repository) -> bool {
+    let words = |repo: &Repository| -> Option<HashSet<String>> {
+        let words: HashSet<String> = repo
+            .description
+            .as_deref()?
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        (!words.is_empty()).then_some(words)
+    };
+    match (words(a), words(b)) {
+        (Some(a), Some(b)) => {
+            let shared = a.intersection(&b).count();
+            shared * 2 >= a.len().max(b.len())
         }
-
-        Ok(repos)
+        _ => true,
     }
 }
 
@@ -142,3 +628,393 @@ impl Default for CachedSearchEngine {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalized_ranking_lifts_smaller_platforms() {
+        let mut results = vec![
+            Repository::test_fixture(Platform::GitHub, "gh/huge", 50_000),
+            Repository::test_fixture(Platform::GitHub, "gh/big", 20_000),
+            Repository::test_fixture(Platform::GitHub, "gh/medium", 5_000),
+            Repository::test_fixture(Platform::GitHub, "gh/small", 1_000),
+            Repository::test_fixture(Platform::Bitbucket, "bb/top", 300),
+            Repository::test_fixture(Platform::Bitbucket, "bb/low", 10),
+        ];
+
+        CachedSearchEngine::rank_by_normalized_popularity(&mut results);
+
+        let names: Vec<_> = results.iter().map(|r| r.full_name.as_str()).collect();
+        // bb/top is the best Bitbucket result, so it outranks mid-tier GitHub repos
+        assert_eq!(names[0], "gh/huge");
+        assert_eq!(names[1], "bb/top");
+        assert_eq!(names.last(), Some(&"gh/small"));
+    }
+
+    #[test]
+    fn test_collapse_mirrors_keeps_most_starred() {
+        let described = |platform, name: &str, stars, description: &str| Repository {
+            description: Some(description.to_string()),
+            ..Repository::test_fixture(platform, name, stars)
+        };
+        let results = vec![
+            described(
+                Platform::GitLab,
+                "inkscape/inkscape",
+                900,
+                "Inkscape vector image editor",
+            ),
+            described(Platform::GitHub, "other/editor", 50, "A text editor"),
+            described(
+                Platform::GitHub,
+                "inkscape/Inkscape",
+                3_000,
+                "Inkscape: a vector image editor (mirror)",
+            ),
+            // Same name, unrelated project
+            described(
+                Platform::Bitbucket,
+                "someone/inkscape",
+                5,
+                "Build scripts for our CI",
+            ),
+        ];
+
+        let collapsed = CachedSearchEngine::collapse_mirrors(results);
+        let names: Vec<_> = collapsed.iter().map(|r| r.full_name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["inkscape/Inkscape", "other/editor", "someone/inkscape"]
+        );
+        assert_eq!(
+            collapsed[0].mirrors,
+            vec![(Platform::GitLab, "inkscape/inkscape".to_string())]
+        );
+        assert_eq!(
+            collapsed[0].mirrors_label().as_deref(),
+            Some("Also on GitLab (inkscape/inkscape)")
+        );
+        assert!(collapsed[2].mirrors.is_empty());
+    }
+
+    struct FakeProvider {
+        platform: Platform,
+        delay_ms: u64,
+        names: Vec<&'static str>,
+    }
+
+    #[async_trait::async_trait]
+    impl SearchProvider for FakeProvider {
+        async fn search(&self, _query: &str) -> Result<Vec<Repository>> {
+            tokio::time::sleep(std::time::Duration::from_millis(self.delay_ms)).await;
+            Ok(self
+                .names
+                .iter()
+                .map(|name| Repository::test_fixture(self.platform, name, 10))
+                .collect())
+        }
+
+        async fn get_repository(&self, _owner: &str, _name: &str) -> Result<Repository> {
+            Err(crate::Error::ConfigError("not supported".into()))
+        }
+
+        fn platform(&self) -> Platform {
+            self.platform
+        }
+    }
+
+    /// Serves `pages` one at a time, the cursor being the page index
+    struct PagedProvider {
+        platform: Platform,
+        pages: Vec<Vec<&'static str>>,
+    }
+
+    #[async_trait::async_trait]
+    impl SearchProvider for PagedProvider {
+        async fn search(&self, query: &str) -> Result<Vec<Repository>> {
+            Ok(self.search_page(query, None).await?.repos)
+        }
+
+        async fn search_page(&self, _query: &str, cursor: Option<&str>) -> Result<SearchPage> {
+            let page: usize = cursor.map_or(0, |c| c.parse().unwrap());
+            Ok(SearchPage {
+                repos: self.pages[page]
+                    .iter()
+                    .map(|name| Repository::test_fixture(self.platform, name, 10))
+                    .collect(),
+                next: (page + 1 < self.pages.len()).then(|| (page + 1).to_string()),
+            })
+        }
+
+        async fn get_repository(&self, _owner: &str, _name: &str) -> Result<Repository> {
+            Err(crate::Error::ConfigError("not supported".into()))
+        }
+
+        fn platform(&self) -> Platform {
+            self.platform
+        }
+    }
+
+    #[tokio::test]
+    async fn test_load_more_appends_unseen_pages() {
+        let mut engine = CachedSearchEngine::new();
+        engine.add_provider(Box::new(PagedProvider {
+            platform: Platform::GitHub,
+            pages: vec![
+                vec!["gh/1", "gh/2"],
+                vec!["gh/2", "gh/3"],
+                // Nothing new here, so the next page is read straight away
+                vec!["gh/3"],
+                vec!["gh/4"],
+            ],
+        }));
+        engine.add_provider(Box::new(FakeProvider {
+            platform: Platform::GitLab,
+            delay_ms: 0,
+            names: vec!["gl/1"],
+        }));
+
+        assert!(!engine.has_more());
+        assert_eq!(engine.search("anything").await.unwrap().len(), 3);
+        assert!(engine.has_more());
+
+        let names = |repos: Vec<Repository>| -> Vec<String> {
+            repos.into_iter().map(|r| r.full_name).collect()
+        };
+        assert_eq!(names(engine.load_more().await.unwrap()), ["gh/3"]);
+        assert_eq!(names(engine.load_more().await.unwrap()), ["gh/4"]);
+        assert!(!engine.has_more());
+        assert!(engine.load_more().await.unwrap().is_empty());
+
+        // A new search starts from the first page again
+        engine.search("other").await.unwrap();
+        assert!(engine.has_more());
+    }
+
+    #[tokio::test]
+    async fn test_search_with_progress_streams_batches() {
+        let mut engine = CachedSearchEngine::new();
+        engine.add_provider(Box::new(FakeProvider {
+            platform: Platform::GitHub,
+            delay_ms: 50,
+            names: vec!["slow/one", "fast/one", "blocked/repo"],
+        }));
+        engine.add_provider(Box::new(FakeProvider {
+            platform: Platform::GitHub,
+            delay_ms: 0,
+            names: vec!["fast/one", "fast/two"],
+        }));
+        engine.set_filters(FilterConfig {
+            blocked_owners: vec!["blocked".to_string()],
+        });
+
+        let mut batches: Vec<Vec<String>> = Vec::new();
+        let results = engine
+            .search_with_progress("anything", |batch| {
+                batches.push(batch.iter().map(|r| r.full_name.clone()).collect());
+            })
+            .await
+            .unwrap();
+
+        // Fast provider reports first, the duplicate from the slow one is dropped
+        assert_eq!(
+            batches,
+            vec![
+                vec!["fast/one".to_string(), "fast/two".to_string()],
+                vec!["slow/one".to_string()],
+            ]
+        );
+        assert_eq!(results.len(), 3);
+        assert!(results.iter().all(|r| r.health.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_search_keeps_the_same_name_on_other_platforms() {
+        let mut engine = CachedSearchEngine::new();
+        engine.add_provider(Box::new(FakeProvider {
+            platform: Platform::GitLab,
+            delay_ms: 20,
+            names: vec!["owner/shared", "owner/shared"],
+        }));
+        engine.add_provider(Box::new(FakeProvider {
+            platform: Platform::GitHub,
+            delay_ms: 0,
+            names: vec!["owner/shared"],
+        }));
+
+        let results = engine.search("anything").await.unwrap();
+        let found: Vec<(Platform, &str)> = results
+            .iter()
+            .map(|r| (r.platform, r.full_name.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (Platform::GitHub, "owner/shared"),
+                (Platform::GitLab, "owner/shared"),
+            ]
+        );
+
+        // With mirror collapsing on, the two copies become one
+        engine.set_dedupe(true);
+        let results = engine.search("anything").await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].mirrors.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_per_provider_limit_interleaves_platforms() {
+        let mut engine = CachedSearchEngine::new();
+        engine.add_provider(Box::new(FakeProvider {
+            platform: Platform::GitHub,
+            delay_ms: 20,
+            names: vec!["gh/1", "gh/2", "gh/3", "gh/4"],
+        }));
+        engine.add_provider(Box::new(FakeProvider {
+            platform: Platform::GitLab,
+            delay_ms: 0,
+            names: vec!["gl/1"],
+        }));
+        engine.add_provider(Box::new(FakeProvider {
+            platform: Platform::Bitbucket,
+            delay_ms: 10,
+            names: vec!["bb/1", "bb/2", "bb/3"],
+        }));
+        engine.set_per_provider_limit(Some(3));
+
+        let mut streamed = 0;
+        let results = engine
+            .search_with_progress("anything", |batch| streamed += batch.len())
+            .await
+            .unwrap();
+
+        // Registration order wins over arrival order, gh/4 is over the limit
+        let names: Vec<_> = results.iter().map(|r| r.full_name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["gh/1", "gl/1", "bb/1", "gh/2", "bb/2", "gh/3", "bb/3"]
+        );
+        assert_eq!(streamed, 7);
+    }
+
+    #[tokio::test]
+    async fn test_offline_serves_cache_only() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+        let mut cached = Repository::test_fixture(Platform::GitHub, "tokio-rs/tokio", 100);
+        cached.description = Some("An asynchronous runtime".to_string());
+        cache
+            .set("GitHub", &cached.full_name.clone(), &cached)
+            .unwrap();
+        cache
+            .set_query_cache(
+                "cached query",
+                &[Repository::test_fixture(Platform::GitLab, "gl/cached", 5)],
+            )
+            .unwrap();
+        let mut engine = CachedSearchEngine::with_cache(cache);
+        engine.add_provider(Box::new(FakeProvider {
+            platform: Platform::GitHub,
+            delay_ms: 0,
+            names: vec!["network/result"],
+        }));
+        engine.set_offline(true);
+
+        let results = engine.search("cached query").await.unwrap();
+        assert_eq!(results[0].full_name, "gl/cached");
+        // No cached query: fall back to searching cached repositories
+        let results = engine.search("runtime language:rust").await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].full_name, "tokio-rs/tokio");
+        assert!(engine.search("nothing here").await.unwrap().is_empty());
+
+        assert!(engine.get_repository("tokio-rs", "tokio").await.is_ok());
+        let err = engine.get_repository("other", "repo").await.unwrap_err();
+        assert!(matches!(err, crate::Error::Offline(_)));
+    }
+
+    #[tokio::test]
+    async fn test_get_repository_reads_every_platform_from_cache() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+        let cached = Repository::test_fixture(Platform::AzureDevOps, "project/repo", 3);
+        cache
+            .set(
+                &Platform::AzureDevOps.to_string(),
+                &cached.full_name,
+                &cached,
+            )
+            .unwrap();
+        let mut engine = CachedSearchEngine::with_cache(cache);
+
+        let repo = engine.get_repository("project", "repo").await.unwrap();
+        assert_eq!(repo.platform, Platform::AzureDevOps);
+
+        engine.set_offline(true);
+        let repo = engine.get_repository("project", "repo").await.unwrap();
+        assert_eq!(repo.platform, Platform::AzureDevOps);
+    }
+
+    #[tokio::test]
+    async fn test_cached_health_is_reused() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+        let mut cached = Repository::test_fixture(Platform::GitHub, "tokio-rs/tokio", 100);
+        cached.calculate_health();
+        // A score the calculator wouldn't give shows it wasn't recalculated
+        cached.health.as_mut().unwrap().score = 1;
+        cache
+            .set("GitHub", &cached.full_name.clone(), &cached)
+            .unwrap();
+        let mut stale = Repository::test_fixture(Platform::GitLab, "gl/stale", 5);
+        stale.calculate_health();
+        stale.health.as_mut().unwrap().weights.activity += 1;
+        cache.set_query_cache("cached query", &[stale]).unwrap();
+        let engine = CachedSearchEngine::with_cache(cache);
+
+        let repo = engine.get_repository("tokio-rs", "tokio").await.unwrap();
+        assert_eq!(repo.health.unwrap().score, 1);
+
+        // Health worked out under other weights is calculated again
+        let results = engine.search("cached query").await.unwrap();
+        let health = results[0].health.as_ref().unwrap();
+        assert_eq!(health.weights, crate::HealthWeights::configured());
+    }
+
+    #[tokio::test]
+    async fn test_bypass_cache_skips_reads_and_writes() {
+        let cache = CacheManager::new(":memory:", 24).unwrap();
+        cache
+            .set_query_cache(
+                "anything",
+                &[Repository::test_fixture(
+                    Platform::GitHub,
+                    "stale/result",
+                    1,
+                )],
+            )
+            .unwrap();
+        let mut engine = CachedSearchEngine::with_cache(cache);
+        engine.add_provider(Box::new(FakeProvider {
+            platform: Platform::GitHub,
+            delay_ms: 0,
+            names: vec!["fresh/result"],
+        }));
+
+        engine.set_bypass_cache(true);
+        let results = engine.search("anything").await.unwrap();
+        assert_eq!(results[0].full_name, "fresh/result");
+        let results = engine.search("new query").await.unwrap();
+        assert_eq!(results[0].full_name, "fresh/result");
+
+        // Nothing was written while bypassed
+        engine.set_bypass_cache(false);
+        let results = engine.search("anything").await.unwrap();
+        assert_eq!(results[0].full_name, "stale/result");
+        let cached = engine
+            .cache
+            .as_ref()
+            .unwrap()
+            .get_query_cache::<Repository>("new query");
+        assert!(cached.is_err());
+    }
+}
@@ -68,6 +68,75 @@ impl Color {
             b: (hex & 0xFF) as u8,
         }
     }
+
+    /// Relative luminance as defined by WCAG 2.x (0.0 = black, 1.0 = white)
+    pub fn relative_luminance(&self) -> f64 {
+        let channel = |c: u8| {
+            let c = c as f64 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * channel(self.r) + 0.7152 * channel(self.g) + 0.0722 * channel(self.b)
+    }
+
+    /// WCAG contrast ratio against another color, from 1.0 (none) to 21.0 (black on white)
+    pub fn contrast_ratio(&self, other: &Color) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        let (lighter, darker) = if a > b { (a, b) } else { (b, a) };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+}
+
+/// Minimum contrast for regular text (WCAG AA)
+pub const MIN_TEXT_CONTRAST: f64 = 4.5;
+
+/// Minimum contrast for borders, dimmed text and other non-essential UI (WCAG AA large/UI)
+pub const MIN_UI_CONTRAST: f64 = 3.0;
+
+/// A foreground/background pair that doesn't meet its contrast target
+#[derive(Debug, Clone)]
+pub struct ContrastIssue {
+    pub element: &'static str,
+    pub ratio: f64,
+    pub required: f64,
+}
+
+impl ThemeColors {
+    /// Foreground/background pairs that end up on screen together,
+    /// with the contrast each one needs
+    pub fn contrast_pairs(&self) -> Vec<(&'static str, Color, Color, f64)> {
+        let bg = self.background;
+        vec![
+            ("foreground", self.foreground, bg, MIN_TEXT_CONTRAST),
+            ("title", self.title, bg, MIN_TEXT_CONTRAST),
+            ("primary", self.primary, bg, MIN_TEXT_CONTRAST),
+            ("secondary", self.secondary, bg, MIN_TEXT_CONTRAST),
+            ("accent", self.accent, bg, MIN_TEXT_CONTRAST),
+            ("success", self.success, bg, MIN_TEXT_CONTRAST),
+            ("warning", self.warning, bg, MIN_TEXT_CONTRAST),
+            ("error", self.error, bg, MIN_TEXT_CONTRAST),
+            ("info", self.info, bg, MIN_TEXT_CONTRAST),
+            ("stars", self.stars, bg, MIN_TEXT_CONTRAST),
+            ("forks", self.forks, bg, MIN_TEXT_CONTRAST),
+            ("issues", self.issues, bg, MIN_TEXT_CONTRAST),
+            ("language", self.language, bg, MIN_TEXT_CONTRAST),
+            (
+                "selected on selected_bg",
+                self.selected,
+                self.selected_bg,
+                MIN_TEXT_CONTRAST,
+            ),
+            ("subtitle", self.subtitle, bg, MIN_UI_CONTRAST),
+            ("muted", self.muted, bg, MIN_UI_CONTRAST),
+            ("tab_inactive", self.tab_inactive, bg, MIN_UI_CONTRAST),
+            ("tab_active", self.tab_active, bg, MIN_UI_CONTRAST),
+            ("border_focused", self.border_focused, bg, MIN_UI_CONTRAST),
+        ]
+    }
 }
 
 impl Theme {
@@ -671,6 +740,47 @@ impl Theme {
         }
     }
 
+    /// Get High Contrast theme
+    /// Pure black background with bright, saturated colors for low-vision users
+    pub fn high_contrast() -> Self {
+        Self {
+            name: "High Contrast".to_string(),
+            colors: ThemeColors {
+                background: Color::rgb(0x000000),
+                foreground: Color::rgb(0xffffff),
+                border: Color::rgb(0xbfbfbf),
+                border_focused: Color::rgb(0xffff00),
+
+                success: Color::rgb(0x00ff7f),
+                warning: Color::rgb(0xffd700),
+                error: Color::rgb(0xff6b6b),
+                info: Color::rgb(0x00e5ff),
+
+                title: Color::rgb(0xffff00),
+                subtitle: Color::rgb(0xd0d0d0),
+                selected: Color::rgb(0x000000),
+                selected_bg: Color::rgb(0xffff00),
+                tab_active: Color::rgb(0xffff00),
+                tab_inactive: Color::rgb(0xb0b0b0),
+
+                primary: Color::rgb(0x00e5ff),
+                secondary: Color::rgb(0xff9cff),
+                accent: Color::rgb(0xffd700),
+                muted: Color::rgb(0xb0b0b0),
+
+                health_healthy: Color::rgb(0x00ff7f),
+                health_moderate: Color::rgb(0xffd700),
+                health_warning: Color::rgb(0xffa040),
+                health_critical: Color::rgb(0xff6b6b),
+
+                stars: Color::rgb(0xffd700),
+                forks: Color::rgb(0x00e5ff),
+                issues: Color::rgb(0xff6b6b),
+                language: Color::rgb(0xff9cff),
+            },
+        }
+    }
+
     /// Get all available themes
     pub fn all_themes() -> Vec<Theme> {
         vec![
@@ -689,6 +799,7 @@ impl Theme {
             Self::everforest(),
             Self::rose_pine(),
             Self::kanagawa(),
+            Self::high_contrast(),
         ]
     }
 
@@ -698,6 +809,22 @@ impl Theme {
             .into_iter()
             .find(|t| t.name.to_lowercase() == name.to_lowercase())
     }
+
+    /// Check every foreground/background pair against its contrast target
+    pub fn check_contrast(&self) -> Vec<ContrastIssue> {
+        self.colors
+            .contrast_pairs()
+            .into_iter()
+            .filter_map(|(element, fg, bg, required)| {
+                let ratio = fg.contrast_ratio(&bg);
+                (ratio < required).then_some(ContrastIssue {
+                    element,
+                    ratio,
+                    required,
+                })
+            })
+            .collect()
+    }
 }
 
 impl Default for Theme {
@@ -705,3 +832,35 @@ impl Default for Theme {
         Self::default_dark()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contrast_ratio_extremes() {
+        let black = Color::rgb(0x000000);
+        let white = Color::rgb(0xffffff);
+
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 0.01);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 0.01);
+        assert!((white.contrast_ratio(&white) - 1.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_high_contrast_theme_passes_checks() {
+        let theme = Theme::high_contrast();
+        let issues = theme.check_contrast();
+        assert!(issues.is_empty(), "unexpected issues: {:?}", issues);
+        assert!(Theme::by_name("high contrast").is_some());
+    }
+
+    #[test]
+    fn test_low_contrast_pair_is_reported() {
+        let mut theme = Theme::default_dark();
+        theme.colors.foreground = theme.colors.background;
+
+        let issues = theme.check_contrast();
+        assert!(issues.iter().any(|i| i.element == "foreground"));
+    }
+}
@@ -196,6 +196,90 @@ impl TokenStore {
     }
 }
 
+// Tokens discovered from the official `gh` and `glab` CLI configs.
+// Lets people already logged in with those tools skip setting up RepoScout
+// tokens. Only used as a last resort after flags, env vars and our own store.
+impl TokenStore {
+    /// Look for a token in the platform's official CLI config.
+    /// Returns the token together with the file it was read from.
+    pub fn discover_cli_token(platform: &str) -> Option<(String, PathBuf)> {
+        let path = Self::cli_config_path(platform)?;
+        let contents = std::fs::read_to_string(&path).ok()?;
+
+        let token = match platform {
+            "github" => parse_host_token(&contents, "github.com", "oauth_token"),
+            "gitlab" => parse_host_token(&contents, "gitlab.com", "token"),
+            _ => None,
+        }?;
+
+        Some((token, path))
+    }
+
+    /// Location of the official CLI config for a platform, honouring the
+    /// same override env vars the CLIs themselves use
+    fn cli_config_path(platform: &str) -> Option<PathBuf> {
+        let (override_var, dir_name, file_name) = match platform {
+            "github" => ("GH_CONFIG_DIR", "gh", "hosts.yml"),
+            "gitlab" => ("GLAB_CONFIG_DIR", "glab-cli", "config.yml"),
+            _ => return None,
+        };
+
+        if let Some(dir) = std::env::var_os(override_var) {
+            return Some(PathBuf::from(dir).join(file_name));
+        }
+
+        // Both CLIs use ~/.config even on macOS, so don't rely on dirs::config_dir there
+        let config_home = match std::env::var_os("XDG_CONFIG_HOME") {
+            Some(dir) => PathBuf::from(dir),
+            None if cfg!(target_os = "windows") => dirs::config_dir()?,
+            None => dirs::home_dir()?.join(".config"),
+        };
+
+        Some(config_home.join(dir_name).join(file_name))
+    }
+}
+
+/// Pull `key` out of the block for `host` in a gh/glab style YAML config.
+///
+/// Both files are simple indentation-based maps, so a line scanner is enough
+/// and saves pulling in a YAML parser for one value.
+fn parse_host_token(contents: &str, host: &str, key: &str) -> Option<String> {
+    let host_header = format!("{}:", host);
+    let key_prefix = format!("{}:", key);
+    let mut host_indent: Option<usize> = None;
+
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let indent = line.len() - line.trim_start().len();
+
+        match host_indent {
+            None => {
+                if trimmed == host_header {
+                    host_indent = Some(indent);
+                }
+            }
+            Some(base) => {
+                // Left the host block without finding the key
+                if indent <= base {
+                    break;
+                }
+
+                if let Some(value) = trimmed.strip_prefix(&key_prefix) {
+                    let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+                    if !value.is_empty() {
+                        return Some(value.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    None
+}
+
 impl Default for TokenStore {
     fn default() -> Self {
         Self::new()
@@ -243,6 +327,27 @@ mod tests {
         assert!(!store.has_valid_token("github"));
     }
 
+    #[test]
+    fn test_parse_gh_hosts() {
+        let hosts = "github.example.com:\n    oauth_token: ghp_enterprise\ngithub.com:\n    user: octocat\n    oauth_token: gho_abc123\n    git_protocol: https\n";
+
+        assert_eq!(
+            parse_host_token(hosts, "github.com", "oauth_token"),
+            Some("gho_abc123".to_string())
+        );
+        assert_eq!(parse_host_token("", "github.com", "oauth_token"), None);
+    }
+
+    #[test]
+    fn test_parse_glab_config() {
+        let config = "git_protocol: ssh\nhosts:\n    gitlab.com:\n        api_protocol: https\n        token: \"glpat-xyz\"\n    gitlab.example.org:\n        token: glpat-other\n";
+
+        assert_eq!(
+            parse_host_token(config, "gitlab.com", "token"),
+            Some("glpat-xyz".to_string())
+        );
+    }
+
     #[test]
     fn test_token_removal() {
         let mut store = TokenStore::new();
@@ -1,6 +1,16 @@
 // Trending repositories discovery
-use crate::{models::Repository, search::SearchProvider, Result};
-use chrono::{Duration, Utc};
+use crate::{config::FilterConfig, models::Repository, search::SearchProvider, Result};
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Weights installed from the config file, used by `sort_by_trending_score`
+static CONFIGURED_SCORE_WEIGHTS: OnceLock<TrendingScoreWeights> = OnceLock::new();
+
+/// Max number of non-primary-language candidates whose language breakdown
+/// is fetched per trending search (each lookup is one API request)
+pub const MAX_LANGUAGE_LOOKUPS: usize = 30;
 
 /// Time range for trending repositories
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -11,15 +21,27 @@ pub enum TrendingPeriod {
 }
 
 impl TrendingPeriod {
+    /// Length of the window this period looks back over
+    pub fn duration(&self) -> Duration {
+        match self {
+            TrendingPeriod::Daily => Duration::days(1),
+            TrendingPeriod::Weekly => Duration::weeks(1),
+            TrendingPeriod::Monthly => Duration::days(30),
+        }
+    }
+
+    /// Start of the window as of `now`, rounded down to midnight UTC
+    ///
+    /// Every provider gets the same start, whether it filters by day
+    /// (GitHub's `created:`) or by timestamp (GitLab and Bitbucket).
+    pub fn start_date(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        let start = (now - self.duration()).date_naive();
+        start.and_time(NaiveTime::MIN).and_utc()
+    }
+
     /// Get the date range for this period
     pub fn date_range(&self) -> String {
-        let now = Utc::now();
-        let start_date = match self {
-            TrendingPeriod::Daily => now - Duration::days(1),
-            TrendingPeriod::Weekly => now - Duration::weeks(1),
-            TrendingPeriod::Monthly => now - Duration::days(30),
-        };
-        format!(">={}", start_date.format("%Y-%m-%d"))
+        format!(">={}", self.start_date(Utc::now()).format("%Y-%m-%d"))
     }
 
     /// Get display name
@@ -38,17 +60,155 @@ pub struct TrendingFilters {
     pub language: Option<String>,
     pub min_stars: Option<u32>,
     pub topic: Option<String>,
+    /// Also include repos where `language` is a secondary language making up
+    /// at least this percentage of the code (not just the primary language)
+    pub min_language_percent: Option<f64>,
+}
+
+impl TrendingFilters {
+    /// Language share threshold, if secondary-language matching is enabled
+    fn language_share_threshold(&self) -> Option<(&str, f64)> {
+        match (&self.language, self.min_language_percent) {
+            (Some(lang), Some(pct)) if pct > 0.0 => Some((lang.as_str(), pct)),
+            _ => None,
+        }
+    }
+}
+
+/// GitHub search query for repositories created since `since` matching `filters`
+pub fn github_trending_query(since: DateTime<Utc>, filters: &TrendingFilters) -> String {
+    let mut query_parts = vec![
+        "stars:>100".to_string(), // Minimum stars threshold
+        format!("created:>={}", since.format("%Y-%m-%d")),
+    ];
+
+    if let Some(min_stars) = filters.min_stars {
+        query_parts.push(format!("stars:>={}", min_stars));
+    }
+
+    if let Some(ref topic) = filters.topic {
+        query_parts.push(format!("topic:{}", topic));
+    }
+
+    if let Some(ref lang) = filters.language {
+        query_parts.push(format!("language:{}", lang));
+    }
+
+    query_parts.join(" ")
+}
+
+/// Convert a byte-count language breakdown into percentages of the total
+pub fn languages_to_percentages(bytes: &HashMap<String, u64>) -> HashMap<String, f64> {
+    let total: u64 = bytes.values().sum();
+    if total == 0 {
+        return HashMap::new();
+    }
+
+    bytes
+        .iter()
+        .map(|(lang, &count)| (lang.clone(), count as f64 * 100.0 / total as f64))
+        .collect()
+}
+
+/// Percentage of code written in `language` (case-insensitive), 0 if absent
+pub fn language_share(languages: &HashMap<String, f64>, language: &str) -> f64 {
+    languages
+        .iter()
+        .find(|(lang, _)| lang.eq_ignore_ascii_case(language))
+        .map(|(_, &pct)| pct)
+        .unwrap_or(0.0)
+}
+
+/// Sort repositories by star velocity (stars per day), fastest first
+pub fn sort_by_star_velocity(repos: &mut [Repository]) {
+    repos.sort_by(|a, b| b.star_velocity().total_cmp(&a.star_velocity()));
+}
+
+/// Tuning for the `trending-score` sort. Configurable under `[trending_score]`
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(default)]
+pub struct TrendingScoreWeights {
+    /// How much each star per day of age adds to the score
+    pub velocity_weight: f64,
+    /// Days since the last push after which the popularity term counts half
+    pub recency_half_life_days: f64,
+}
+
+impl Default for TrendingScoreWeights {
+    fn default() -> Self {
+        Self {
+            velocity_weight: 1.0,
+            recency_half_life_days: 30.0,
+        }
+    }
+}
+
+impl TrendingScoreWeights {
+    pub fn validate(&self) -> Result<()> {
+        if self.velocity_weight.is_nan() || self.velocity_weight < 0.0 {
+            return Err(crate::Error::ConfigError(format!(
+                "[trending_score] velocity_weight can't be negative, got {}",
+                self.velocity_weight
+            )));
+        }
+        if self.recency_half_life_days.is_nan() || self.recency_half_life_days <= 0.0 {
+            return Err(crate::Error::ConfigError(format!(
+                "[trending_score] recency_half_life_days must be positive, got {}",
+                self.recency_half_life_days
+            )));
+        }
+        Ok(())
+    }
+
+    /// Use these weights for every trending-score sort in this process.
+    /// Only the first call has an effect
+    pub fn install(self) -> Result<()> {
+        self.validate()?;
+        let _ = CONFIGURED_SCORE_WEIGHTS.set(self);
+        Ok(())
+    }
+
+    /// Weights from the config file, or the defaults if none were installed
+    pub fn configured() -> Self {
+        CONFIGURED_SCORE_WEIGHTS.get().copied().unwrap_or_default()
+    }
+
+    /// Blend of popularity, freshness and momentum:
+    ///
+    /// `ln(stars + 1) * 0.5^(days_since_push / recency_half_life_days)
+    ///  + velocity_weight * stars_per_day`
+    ///
+    /// where `stars_per_day` is stars divided by age in days (at least 1), so a
+    /// young project gaining stars quickly can outrank an older, larger one
+    /// that has gone quiet.
+    pub fn score(&self, repo: &Repository, now: DateTime<Utc>) -> f64 {
+        let days_since_push = (now - repo.pushed_at).num_seconds().max(0) as f64 / 86_400.0;
+        let recency = 0.5_f64.powf(days_since_push / self.recency_half_life_days);
+        let age_days = (now - repo.created_at).num_days().max(1) as f64;
+        let velocity = repo.stars as f64 / age_days;
+
+        (repo.stars as f64 + 1.0).ln() * recency + self.velocity_weight * velocity
+    }
+}
+
+/// Sort repositories by the configured trending score, highest first
+pub fn sort_by_trending_score(repos: &mut [Repository]) {
+    let weights = TrendingScoreWeights::configured();
+    let now = Utc::now();
+    repos.sort_by(|a, b| weights.score(b, now).total_cmp(&weights.score(a, now)));
 }
 
 /// Trending repository finder
 pub struct TrendingFinder<'a> {
     providers: Vec<&'a dyn SearchProvider>,
+    owner_filters: FilterConfig,
 }
 
 impl<'a> TrendingFinder<'a> {
     pub fn new() -> Self {
         Self {
             providers: Vec::new(),
+            owner_filters: FilterConfig::default(),
         }
     }
 
@@ -56,40 +216,60 @@ impl<'a> TrendingFinder<'a> {
         self.providers.push(provider);
     }
 
+    /// Persistent filters (e.g. blocked owners) applied to trending results
+    pub fn set_filters(&mut self, filters: FilterConfig) {
+        self.owner_filters = filters;
+    }
+
     /// Find trending repositories for a given period
     pub async fn find_trending(
         &self,
         period: TrendingPeriod,
         filters: &TrendingFilters,
     ) -> Result<Vec<Repository>> {
-        // Build search query for trending
-        let mut query_parts = vec!["stars:>100".to_string()]; // Minimum stars threshold
-
-        // Add date filter
-        let date_filter = format!("created:{}", period.date_range());
-        query_parts.push(date_filter);
+        let since = period.start_date(Utc::now());
+        let mut repos = self.trending_all(since, filters).await;
 
-        // Add optional filters
-        if let Some(ref lang) = filters.language {
-            query_parts.push(format!("language:{}", lang));
+        // Secondary-language matching: also consider repos whose primary
+        // language differs, and keep those above the byte-share threshold
+        if let Some((lang, min_percent)) = filters.language_share_threshold() {
+            let any_language = TrendingFilters {
+                language: None,
+                ..filters.clone()
+            };
+            let mut candidates = self.trending_all(since, &any_language).await;
+            candidates.retain(|c| {
+                !repos
+                    .iter()
+                    .any(|r| r.platform == c.platform && r.full_name == c.full_name)
+            });
+            // Don't spend lookups on repos that would be dropped anyway
+            self.owner_filters.retain_allowed(&mut candidates);
+            repos.extend(
+                self.retain_language_share(candidates, lang, min_percent)
+                    .await,
+            );
         }
 
-        if let Some(min_stars) = filters.min_stars {
-            query_parts.push(format!("stars:>={}", min_stars));
-        }
+        self.owner_filters.retain_allowed(&mut repos);
 
-        if let Some(ref topic) = filters.topic {
-            query_parts.push(format!("topic:{}", topic));
-        }
+        // Sort by stars (descending) - these are the "hottest" repos
+        repos.sort_by_key(|r| std::cmp::Reverse(r.stars));
 
-        let query = query_parts.join(" ");
+        Ok(repos)
+    }
 
-        // Search across all providers
+    /// Ask every provider for its trending repositories, ignoring failing ones
+    async fn trending_all(
+        &self,
+        since: DateTime<Utc>,
+        filters: &TrendingFilters,
+    ) -> Vec<Repository> {
         use futures::future::join_all;
         let searches: Vec<_> = self
             .providers
             .iter()
-            .map(|provider| provider.search(&query))
+            .map(|provider| provider.trending(since, filters))
             .collect();
 
         let results = join_all(searches).await;
@@ -98,23 +278,48 @@ impl<'a> TrendingFinder<'a> {
         for mut r in results.into_iter().flatten() {
             repos.append(&mut r);
         }
+        repos
+    }
 
-        // Sort by stars (descending) - these are the "hottest" repos
-        repos.sort_by(|a, b| b.stars.cmp(&a.stars));
-
-        // Enrich with velocity calculation (as metadata in description if needed)
-        for repo in &mut repos {
-            let age_days = (Utc::now() - repo.created_at).num_days() as f64;
-            if age_days > 0.0 {
-                let velocity = repo.stars as f64 / age_days;
-                // Store velocity in a custom field or just use it for sorting
-                // For now, repos are already sorted by total stars
-                // Could add: repo.star_velocity = Some(velocity);
-                let _ = velocity; // Suppress warning for now
-            }
-        }
+    /// Keep candidates where `language` makes up at least `min_percent` of the code.
+    ///
+    /// Only the most-starred `MAX_LANGUAGE_LOOKUPS` candidates are looked up,
+    /// through the provider for each one's platform.
+    pub async fn retain_language_share(
+        &self,
+        mut candidates: Vec<Repository>,
+        language: &str,
+        min_percent: f64,
+    ) -> Vec<Repository> {
+        use futures::future::join_all;
 
-        Ok(repos)
+        candidates.sort_by_key(|r| std::cmp::Reverse(r.stars));
+        candidates.truncate(MAX_LANGUAGE_LOOKUPS);
+
+        let lookups: Vec<_> = candidates
+            .iter()
+            .map(|repo| async move {
+                let provider = self
+                    .providers
+                    .iter()
+                    .find(|p| p.platform() == repo.platform)?;
+                let (owner, name) = repo.full_name.rsplit_once('/')?;
+                provider.get_languages(owner, name).await.ok()
+            })
+            .collect();
+
+        let breakdowns = join_all(lookups).await;
+
+        candidates
+            .into_iter()
+            .zip(breakdowns)
+            .filter(|(_, languages)| {
+                languages
+                    .as_ref()
+                    .is_some_and(|l| language_share(l, language) >= min_percent)
+            })
+            .map(|(repo, _)| repo)
+            .collect()
     }
 
     /// Get trending repos with star velocity sorting
@@ -125,20 +330,7 @@ impl<'a> TrendingFinder<'a> {
         filters: &TrendingFilters,
     ) -> Result<Vec<Repository>> {
         let mut repos = self.find_trending(period, filters).await?;
-
-        // Calculate and sort by velocity (stars per day)
-        repos.sort_by(|a, b| {
-            let age_a = (Utc::now() - a.created_at).num_days().max(1) as f64;
-            let age_b = (Utc::now() - b.created_at).num_days().max(1) as f64;
-
-            let velocity_a = a.stars as f64 / age_a;
-            let velocity_b = b.stars as f64 / age_b;
-
-            velocity_b
-                .partial_cmp(&velocity_a)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-
+        sort_by_star_velocity(&mut repos);
         Ok(repos)
     }
 }
@@ -148,3 +340,147 @@ impl<'a> Default for TrendingFinder<'a> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Platform;
+
+    fn repo(stars: u32, created_days_ago: i64, pushed_days_ago: i64) -> Repository {
+        let now = Utc::now();
+        Repository {
+            created_at: now - Duration::days(created_days_ago),
+            updated_at: now - Duration::days(pushed_days_ago),
+            pushed_at: now - Duration::days(pushed_days_ago),
+            ..Repository::test_fixture(Platform::GitHub, &format!("owner/repo-{}", stars), stars)
+        }
+    }
+
+    #[test]
+    fn test_trending_score() {
+        // A moment after the fixtures are built, so their ages round as intended
+        let now = Utc::now() + Duration::seconds(1);
+        let weights = TrendingScoreWeights::default();
+
+        // Pushed just now: full popularity term plus velocity
+        let fresh = repo(999, 100, 0);
+        let expected = 1000f64.ln() + 999.0 / 100.0;
+        assert!((weights.score(&fresh, now) - expected).abs() < 1e-3);
+
+        // One half-life without pushes halves the popularity term
+        let quiet = repo(999, 100, 30);
+        let expected = 1000f64.ln() * 0.5 + 999.0 / 100.0;
+        assert!((weights.score(&quiet, now) - expected).abs() < 1e-3);
+
+        // A small, young, busy project beats a big one that went quiet
+        let mut repos = vec![repo(50_000, 3650, 400), repo(800, 20, 1)];
+        sort_by_trending_score(&mut repos);
+        assert_eq!(repos[0].stars, 800);
+
+        let bad = TrendingScoreWeights {
+            recency_half_life_days: 0.0,
+            ..Default::default()
+        };
+        assert!(bad.validate().is_err());
+    }
+
+    struct FakeProvider {
+        platform: Platform,
+        stars: Vec<u32>,
+    }
+
+    #[async_trait::async_trait]
+    impl SearchProvider for FakeProvider {
+        async fn search(&self, _query: &str) -> Result<Vec<Repository>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_repository(&self, _owner: &str, _name: &str) -> Result<Repository> {
+            Err(crate::Error::ConfigError("not supported".into()))
+        }
+
+        fn platform(&self) -> Platform {
+            self.platform
+        }
+
+        async fn trending(
+            &self,
+            since: DateTime<Utc>,
+            _filters: &TrendingFilters,
+        ) -> Result<Vec<Repository>> {
+            // Every provider gets the same midnight-aligned window
+            assert_eq!(since, TrendingPeriod::Weekly.start_date(Utc::now()));
+            Ok(self
+                .stars
+                .iter()
+                .map(|&stars| Repository {
+                    platform: self.platform,
+                    ..repo(stars, 3, 0)
+                })
+                .collect())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_find_trending_across_platforms() {
+        let github = FakeProvider {
+            platform: Platform::GitHub,
+            stars: vec![500, 150],
+        };
+        let gitlab = FakeProvider {
+            platform: Platform::GitLab,
+            stars: vec![300],
+        };
+        let sourcehut = crate::providers::SourceHutProvider::new(None);
+
+        let mut finder = TrendingFinder::new();
+        finder.add_provider(&github);
+        finder.add_provider(&gitlab);
+        finder.add_provider(&sourcehut);
+
+        let repos = finder
+            .find_trending(TrendingPeriod::Weekly, &TrendingFilters::default())
+            .await
+            .unwrap();
+        let found: Vec<_> = repos.iter().map(|r| (r.platform, r.stars)).collect();
+        assert_eq!(
+            found,
+            [
+                (Platform::GitHub, 500),
+                (Platform::GitLab, 300),
+                (Platform::GitHub, 150)
+            ]
+        );
+
+        let now = DateTime::parse_from_rfc3339("2024-05-10T15:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let start = TrendingPeriod::Daily.start_date(now);
+        assert_eq!(start.to_rfc3339(), "2024-05-09T00:00:00+00:00");
+        assert_eq!(
+            github_trending_query(
+                start,
+                &TrendingFilters {
+                    language: Some("rust".to_string()),
+                    ..Default::default()
+                }
+            ),
+            "stars:>100 created:>=2024-05-09 language:rust"
+        );
+    }
+
+    #[test]
+    fn test_language_share() {
+        let bytes = HashMap::from([
+            ("TypeScript".to_string(), 600u64),
+            ("Rust".to_string(), 300),
+            ("Shell".to_string(), 100),
+        ]);
+        let languages = languages_to_percentages(&bytes);
+
+        assert!((language_share(&languages, "rust") - 30.0).abs() < 1e-9);
+        assert!((language_share(&languages, "TypeScript") - 60.0).abs() < 1e-9);
+        assert_eq!(language_share(&languages, "Go"), 0.0);
+        assert!(languages_to_percentages(&HashMap::new()).is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+// What's new in a watched repository since it was last checked
+use crate::models::Repository;
+use chrono::{DateTime, Utc};
+
+/// Star counts worth calling out when a watched repository passes them
+pub const STAR_MILESTONES: &[u32] = &[
+    100, 250, 500, 1_000, 2_500, 5_000, 10_000, 25_000, 50_000, 100_000,
+];
+
+/// A watched repository that got new pushes or passed a star milestone
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchUpdate {
+    pub full_name: String,
+    /// Latest push time, if it's newer than the one last seen
+    pub pushed_at: Option<DateTime<Utc>>,
+    pub stars_delta: i64,
+    /// Highest milestone passed since the last check
+    pub milestone: Option<u32>,
+}
+
+impl WatchUpdate {
+    /// Compare what was seen last time with fresh data.
+    /// Returns None when nothing worth reporting happened.
+    pub fn check(
+        last_seen_pushed: DateTime<Utc>,
+        last_seen_stars: u32,
+        fresh: &Repository,
+    ) -> Option<Self> {
+        let pushed_at = (fresh.pushed_at > last_seen_pushed).then_some(fresh.pushed_at);
+        let milestone = STAR_MILESTONES
+            .iter()
+            .rev()
+            .find(|&&m| last_seen_stars < m && fresh.stars >= m)
+            .copied();
+
+        if pushed_at.is_none() && milestone.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            full_name: fresh.full_name.clone(),
+            pushed_at,
+            stars_delta: fresh.stars as i64 - last_seen_stars as i64,
+            milestone,
+        })
+    }
+
+    /// One line summary, e.g. "tokio-rs/tokio: new pushes (2024-05-01 12:00), passed 1000 ⭐, +35 ⭐"
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(pushed_at) = self.pushed_at {
+            parts.push(format!(
+                "new pushes ({})",
+                pushed_at.format("%Y-%m-%d %H:%M")
+            ));
+        }
+        if let Some(milestone) = self.milestone {
+            parts.push(format!("passed {} ⭐", milestone));
+        }
+        if self.stars_delta != 0 {
+            parts.push(format!("{:+} ⭐", self.stars_delta));
+        }
+
+        format!("{}: {}", self.full_name, parts.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Platform;
+    use chrono::Duration;
+
+    fn repo(stars: u32, pushed_at: DateTime<Utc>) -> Repository {
+        Repository {
+            created_at: pushed_at,
+            updated_at: pushed_at,
+            pushed_at,
+            default_branch: "master".to_string(),
+            ..Repository::test_fixture(Platform::GitHub, "tokio-rs/tokio", stars)
+        }
+    }
+
+    #[test]
+    fn test_watch_update_detects_pushes_and_milestones() {
+        let seen = Utc::now() - Duration::days(3);
+
+        // Stars moved but no milestone and no push: nothing to report
+        assert_eq!(WatchUpdate::check(seen, 120, &repo(180, seen)), None);
+
+        let pushed = WatchUpdate::check(seen, 120, &repo(120, seen + Duration::days(1))).unwrap();
+        assert!(pushed.pushed_at.is_some());
+        assert_eq!(pushed.milestone, None);
+
+        // Jumping past several milestones reports the highest one
+        let starred = WatchUpdate::check(seen, 90, &repo(1_200, seen)).unwrap();
+        assert_eq!(starred.pushed_at, None);
+        assert_eq!(starred.milestone, Some(1_000));
+        assert_eq!(
+            starred.summary(),
+            "tokio-rs/tokio: passed 1000 ⭐, +1110 ⭐"
+        );
+    }
+}
@@ -1,8 +1,15 @@
 // Dependency analysis module
 // Parses and analyzes dependencies from various package managers
 
+pub mod manifests;
 pub mod models;
+pub mod osv;
 pub mod parsers;
 
-pub use models::{Dependency, DependencyInfo, DependencyType};
-pub use parsers::{parse_cargo_toml, parse_package_json, parse_requirements_txt};
+pub use manifests::{analyze_dependencies, manifests_for_language};
+pub use models::{Advisory, Dependency, DependencyInfo, DependencyType};
+pub use osv::OsvClient;
+pub use parsers::{
+    parse_cargo_toml, parse_composer_json, parse_gemfile, parse_go_mod, parse_gradle,
+    parse_package_json, parse_pom_xml, parse_pyproject_toml, parse_requirements_txt,
+};
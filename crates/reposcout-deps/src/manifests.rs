@@ -0,0 +1,115 @@
+// Which manifest files to look for, by repository language
+//
+// Fetching is left to the caller, so the TUI and the CLI can both use their
+// own API clients and only the language-to-parser mapping lives here.
+
+use crate::models::DependencyInfo;
+use crate::parsers;
+use anyhow::Result;
+use std::future::Future;
+
+/// A manifest parser, e.g. `parse_cargo_toml`
+pub type Parser = fn(&str) -> Result<DependencyInfo>;
+
+/// Manifest files for a repository language, most likely first, paired with
+/// their parsers. Empty for languages without a supported package manager.
+pub fn manifests_for_language(language: &str) -> &'static [(&'static str, Parser)] {
+    match language {
+        "Rust" => &[("Cargo.toml", parsers::parse_cargo_toml)],
+        "JavaScript" | "TypeScript" => &[("package.json", parsers::parse_package_json)],
+        "Python" => &[
+            ("pyproject.toml", parsers::parse_pyproject_toml),
+            ("requirements.txt", parsers::parse_requirements_txt),
+        ],
+        "Go" => &[("go.mod", parsers::parse_go_mod)],
+        "PHP" => &[("composer.json", parsers::parse_composer_json)],
+        "Ruby" => &[("Gemfile", parsers::parse_gemfile)],
+        // Java projects lean towards Maven and Kotlin ones towards Gradle,
+        // so each tries its more likely build file first
+        "Java" => &[
+            ("pom.xml", parsers::parse_pom_xml),
+            ("build.gradle", parsers::parse_gradle),
+            ("build.gradle.kts", parsers::parse_gradle),
+        ],
+        "Kotlin" => &[
+            ("build.gradle.kts", parsers::parse_gradle),
+            ("build.gradle", parsers::parse_gradle),
+            ("pom.xml", parsers::parse_pom_xml),
+        ],
+        _ => &[],
+    }
+}
+
+/// Analyze a repository's dependencies from the first of its language's
+/// manifests that `fetch` returns and parses.
+///
+/// `fetch` gets a manifest path and returns its content, None when the file
+/// doesn't exist. Any other fetch error stops the analysis and is returned,
+/// so an unreachable host isn't mistaken for a repository without manifests.
+/// Returns None when the language isn't supported or no manifest was found,
+/// and the last parse error when none of them parsed.
+pub async fn analyze_dependencies<F, Fut>(
+    language: Option<&str>,
+    mut fetch: F,
+) -> Result<Option<DependencyInfo>>
+where
+    F: FnMut(&'static str) -> Fut,
+    Fut: Future<Output = Result<Option<String>>>,
+{
+    let Some(language) = language else {
+        return Ok(None);
+    };
+
+    let mut last_error = None;
+    for (manifest, parse) in manifests_for_language(language) {
+        let Some(content) = fetch(manifest).await? else {
+            continue;
+        };
+        match parse(&content) {
+            Ok(info) => return Ok(Some(info)),
+            Err(e) => last_error = Some(e),
+        }
+    }
+
+    match last_error {
+        Some(e) => Err(e),
+        None => Ok(None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_analyze_tries_manifests_in_order() {
+        let fetch = |manifest: &'static str| async move {
+            Ok(match manifest {
+                "requirements.txt" => Some("requests>=2.31\n".to_string()),
+                _ => None,
+            })
+        };
+
+        let info = analyze_dependencies(Some("Python"), fetch)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(info.dependencies[0].name, "requests");
+
+        assert!(analyze_dependencies(Some("Haskell"), fetch)
+            .await
+            .unwrap()
+            .is_none());
+        assert!(analyze_dependencies(Some("Go"), fetch)
+            .await
+            .unwrap()
+            .is_none());
+        assert_eq!(manifests_for_language("Kotlin")[0].0, "build.gradle.kts");
+
+        // A failed fetch isn't taken for a missing manifest
+        let unreachable = |_: &'static str| async { Err(anyhow::anyhow!("connection refused")) };
+        assert!(analyze_dependencies(Some("Rust"), unreachable)
+            .await
+            .is_err());
+    }
+}
@@ -5,6 +5,32 @@ pub struct Dependency {
     pub name: String,
     pub version: String,
     pub dep_type: DependencyType,
+    /// Known vulnerabilities, filled in by an OSV check
+    #[serde(default)]
+    pub vulnerabilities: Vec<Advisory>,
+}
+
+/// A published security advisory affecting a dependency
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Advisory {
+    /// OSV id, e.g. GHSA-xxxx-xxxx-xxxx or RUSTSEC-2024-0001
+    pub id: String,
+    /// CVE ids the advisory is also published under
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    /// Severity as reported by the advisory database (e.g. "HIGH"), if any
+    pub severity: Option<String>,
+    pub summary: String,
+}
+
+impl Advisory {
+    /// The CVE id if there is one, otherwise the OSV id
+    pub fn display_id(&self) -> &str {
+        self.aliases
+            .iter()
+            .find(|alias| alias.starts_with("CVE-"))
+            .unwrap_or(&self.id)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -13,6 +39,7 @@ pub enum DependencyType {
     Dev,      // Development dependencies
     Build,    // Build dependencies
     Optional, // Optional dependencies
+    Indirect, // Transitive dependencies pinned in the manifest (Go `// indirect`)
 }
 
 impl std::fmt::Display for DependencyType {
@@ -22,17 +49,21 @@ impl std::fmt::Display for DependencyType {
             DependencyType::Dev => write!(f, "dev"),
             DependencyType::Build => write!(f, "build"),
             DependencyType::Optional => write!(f, "optional"),
+            DependencyType::Indirect => write!(f, "indirect"),
         }
     }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DependencyInfo {
-    pub ecosystem: String, // "rust", "node", "python"
+    pub ecosystem: String, // "rust", "node", "python", "go"
     pub total_count: usize,
     pub runtime_count: usize,
     pub dev_count: usize,
     pub dependencies: Vec<Dependency>,
+    /// Whether the dependencies were checked against OSV
+    #[serde(default)]
+    pub vulnerabilities_checked: bool,
 }
 
 impl DependencyInfo {
@@ -53,6 +84,14 @@ impl DependencyInfo {
             runtime_count,
             dev_count,
             dependencies,
+            vulnerabilities_checked: false,
         }
     }
+
+    /// Dependencies with at least one known vulnerability
+    pub fn vulnerable(&self) -> impl Iterator<Item = &Dependency> {
+        self.dependencies
+            .iter()
+            .filter(|d| !d.vulnerabilities.is_empty())
+    }
 }
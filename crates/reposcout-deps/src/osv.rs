@@ -0,0 +1,393 @@
+// Known-vulnerability lookup through the OSV.dev API
+//
+// All dependencies go out in one batch query, which only returns advisory
+// ids, so the details of each distinct advisory are fetched afterwards.
+// Manifests usually hold version requirements rather than exact versions;
+// the lowest version a requirement allows is what gets checked.
+
+use crate::models::{Advisory, DependencyInfo};
+use anyhow::{Context, Result};
+use reposcout_api::{fetch_concurrently, DEFAULT_CONCURRENCY};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+const OSV_API_BASE: &str = "https://api.osv.dev/v1";
+
+/// Most queries OSV accepts in one batch request
+const MAX_BATCH: usize = 1000;
+
+/// (OSV ecosystem, package name, version)
+type PackageKey = (String, String, String);
+
+/// Client for the OSV.dev vulnerability database
+///
+/// Results are cached per package version for the life of the client, and
+/// clones share the cache.
+#[derive(Clone)]
+pub struct OsvClient {
+    client: reqwest::Client,
+    base_url: String,
+    cache: Arc<Mutex<HashMap<PackageKey, Vec<Advisory>>>>,
+}
+
+impl Default for OsvClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OsvClient {
+    pub fn new() -> Self {
+        Self::with_base_url(OSV_API_BASE.to_string())
+    }
+
+    /// For testing with a custom API URL
+    pub fn with_base_url(base_url: String) -> Self {
+        Self {
+            client: reposcout_api::http::client(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Fill in the `vulnerabilities` of every dependency that has a concrete
+    /// version in an ecosystem OSV covers. Returns how many are affected.
+    pub async fn check(&self, info: &mut DependencyInfo) -> Result<usize> {
+        let Some(ecosystem) = osv_ecosystem(&info.ecosystem) else {
+            anyhow::bail!("OSV has no data for {} dependencies", info.ecosystem);
+        };
+
+        let keys: Vec<Option<PackageKey>> = info
+            .dependencies
+            .iter()
+            .map(|dep| {
+                let version = concrete_version(&dep.version, ecosystem)?;
+                Some((ecosystem.to_string(), dep.name.clone(), version))
+            })
+            .collect();
+
+        let mut missing: Vec<PackageKey> = {
+            let cache = self.cache.lock().unwrap();
+            keys.iter()
+                .flatten()
+                .filter(|key| !cache.contains_key(*key))
+                .cloned()
+                .collect()
+        };
+        missing.sort();
+        missing.dedup();
+
+        for chunk in missing.chunks(MAX_BATCH) {
+            let found = self.query_batch(chunk).await?;
+            self.cache.lock().unwrap().extend(found);
+        }
+
+        let cache = self.cache.lock().unwrap();
+        for (dep, key) in info.dependencies.iter_mut().zip(&keys) {
+            dep.vulnerabilities = key
+                .as_ref()
+                .and_then(|key| cache.get(key))
+                .cloned()
+                .unwrap_or_default();
+        }
+        info.vulnerabilities_checked = true;
+        Ok(info.vulnerable().count())
+    }
+
+    /// Advisories for each package, in one batch query plus a lookup per
+    /// distinct advisory
+    async fn query_batch(
+        &self,
+        packages: &[PackageKey],
+    ) -> Result<Vec<(PackageKey, Vec<Advisory>)>> {
+        let request = BatchRequest {
+            queries: packages
+                .iter()
+                .map(|(ecosystem, name, version)| Query {
+                    package: Package { name, ecosystem },
+                    version,
+                })
+                .collect(),
+        };
+        let response: BatchResponse = self
+            .client
+            .post(format!("{}/querybatch", self.base_url))
+            .json(&request)
+            .send()
+            .await
+            .context("OSV query failed")?
+            .error_for_status()
+            .context("OSV query failed")?
+            .json()
+            .await
+            .context("Unexpected OSV response")?;
+
+        let mut ids: Vec<String> = response
+            .results
+            .iter()
+            .flat_map(|result| result.vulns.iter().map(|v| v.id.clone()))
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+
+        let details = fetch_concurrently(ids.clone(), DEFAULT_CONCURRENCY, |id| async move {
+            self.advisory(&id).await
+        })
+        .await;
+        let advisories: HashMap<String, Advisory> = ids
+            .into_iter()
+            .zip(details)
+            .map(|(id, detail)| {
+                // An advisory whose details can't be fetched is still listed
+                let advisory = detail.unwrap_or_else(|e| {
+                    tracing::debug!("OSV lookup of {} failed: {}", id, e);
+                    Advisory {
+                        id: id.clone(),
+                        aliases: Vec::new(),
+                        severity: None,
+                        summary: String::new(),
+                    }
+                });
+                (id, advisory)
+            })
+            .collect();
+
+        Ok(packages
+            .iter()
+            .cloned()
+            .zip(response.results)
+            .map(|(key, result)| {
+                let found = result
+                    .vulns
+                    .iter()
+                    .filter_map(|v| advisories.get(v.id.as_str()).cloned())
+                    .collect();
+                (key, found)
+            })
+            .collect())
+    }
+
+    async fn advisory(&self, id: &str) -> Result<Advisory> {
+        let vuln: Vulnerability = self
+            .client
+            .get(format!("{}/vulns/{}", self.base_url, id))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(vuln.into())
+    }
+}
+
+/// OSV ecosystem name for a `DependencyInfo::ecosystem`
+pub fn osv_ecosystem(ecosystem: &str) -> Option<&'static str> {
+    Some(match ecosystem {
+        "Rust" => "crates.io",
+        "Node.js" => "npm",
+        "Go" => "Go",
+        "PHP (Composer)" => "Packagist",
+        "Ruby (Bundler)" => "RubyGems",
+        e if e.starts_with("Python") => "PyPI",
+        e if e.starts_with("Java") => "Maven",
+        _ => return None,
+    })
+}
+
+/// The lowest version a requirement like `^1.2`, `>=2.0,<3` or `~> 5.1`
+/// allows, None for wildcards, git sources and the like. Upper bounds
+/// (`<3`) and exclusions (`!=1.5`) don't name a version that's allowed, so
+/// a requirement made only of those has none either.
+fn concrete_version(requirement: &str, ecosystem: &str) -> Option<String> {
+    let lower_bound = requirement
+        .split(',')
+        .map(str::trim)
+        .find(|part| !part.starts_with(['<', '!']))?;
+    let version: String = lower_bound
+        .trim_start_matches(|c: char| "^~=<>! v".contains(c))
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '+'))
+        .collect();
+    if !version.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    // Semver ecosystems read "1" and "1.2" as the first release of that line
+    if matches!(ecosystem, "crates.io" | "npm" | "Go") && !version.contains(['-', '+']) {
+        let parts = version.split('.').count();
+        if parts < 3 {
+            return Some(format!("{}{}", version, ".0".repeat(3 - parts)));
+        }
+    }
+    Some(version)
+}
+
+#[derive(Serialize)]
+struct BatchRequest<'a> {
+    queries: Vec<Query<'a>>,
+}
+
+#[derive(Serialize)]
+struct Query<'a> {
+    package: Package<'a>,
+    version: &'a str,
+}
+
+#[derive(Serialize)]
+struct Package<'a> {
+    name: &'a str,
+    ecosystem: &'a str,
+}
+
+#[derive(Deserialize)]
+struct BatchResponse {
+    results: Vec<BatchResult>,
+}
+
+#[derive(Deserialize)]
+struct BatchResult {
+    #[serde(default)]
+    vulns: Vec<VulnId>,
+}
+
+#[derive(Deserialize)]
+struct VulnId {
+    id: String,
+}
+
+#[derive(Deserialize)]
+struct Vulnerability {
+    id: String,
+    #[serde(default)]
+    summary: Option<String>,
+    #[serde(default)]
+    details: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    database_specific: Option<DatabaseSpecific>,
+}
+
+#[derive(Deserialize)]
+struct DatabaseSpecific {
+    #[serde(default)]
+    severity: Option<String>,
+}
+
+impl From<Vulnerability> for Advisory {
+    fn from(vuln: Vulnerability) -> Self {
+        // Some databases only write details; their first line reads as a summary
+        let summary = vuln
+            .summary
+            .filter(|s| !s.is_empty())
+            .or_else(|| vuln.details?.lines().next().map(str::to_string))
+            .unwrap_or_default();
+        Advisory {
+            id: vuln.id,
+            aliases: vuln.aliases,
+            severity: vuln
+                .database_specific
+                .and_then(|d| d.severity)
+                .map(|s| s.to_uppercase()),
+            summary,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Dependency, DependencyType};
+
+    #[test]
+    fn test_versions_and_ecosystems() {
+        assert_eq!(osv_ecosystem("Python (Poetry)"), Some("PyPI"));
+        assert_eq!(osv_ecosystem("Java/Kotlin (Gradle)"), Some("Maven"));
+        assert_eq!(osv_ecosystem("Haskell"), None);
+
+        assert_eq!(
+            concrete_version("^1.2", "crates.io").as_deref(),
+            Some("1.2.0")
+        );
+        assert_eq!(concrete_version("1", "crates.io").as_deref(), Some("1.0.0"));
+        assert_eq!(
+            concrete_version(">=2.31.0,<3", "PyPI").as_deref(),
+            Some("2.31.0")
+        );
+        assert_eq!(
+            concrete_version("~> 7.1", "RubyGems").as_deref(),
+            Some("7.1")
+        );
+        assert_eq!(concrete_version("v0.17.0", "Go").as_deref(), Some("0.17.0"));
+        assert_eq!(
+            concrete_version("<3, >=2.0", "PyPI").as_deref(),
+            Some("2.0")
+        );
+        assert_eq!(concrete_version("<2.0", "PyPI"), None);
+        assert_eq!(concrete_version("!=1.5", "PyPI"), None);
+        assert_eq!(concrete_version("<=4", "npm"), None);
+        assert_eq!(concrete_version("*", "npm"), None);
+        assert_eq!(concrete_version("workspace", "crates.io"), None);
+    }
+
+    #[test]
+    fn test_advisory_from_osv() {
+        let json = r#"{
+            "id": "GHSA-c827-hfw6-qwvm",
+            "details": "rustls has an infinite loop\nMore text",
+            "aliases": ["CVE-2024-32650"],
+            "database_specific": {"severity": "HIGH"}
+        }"#;
+        let advisory: Advisory = serde_json::from_str::<Vulnerability>(json).unwrap().into();
+        assert_eq!(advisory.display_id(), "CVE-2024-32650");
+        assert_eq!(advisory.severity.as_deref(), Some("HIGH"));
+        assert_eq!(advisory.summary, "rustls has an infinite loop");
+    }
+
+    #[tokio::test]
+    async fn test_check_uses_cache() {
+        let advisory = Advisory {
+            id: "RUSTSEC-2024-0001".to_string(),
+            aliases: Vec::new(),
+            severity: None,
+            summary: "Bad".to_string(),
+        };
+        // Nothing listens here, so any request would fail the check
+        let client = OsvClient::with_base_url("http://127.0.0.1:9".to_string());
+        client.cache.lock().unwrap().extend([
+            (
+                (
+                    "crates.io".to_string(),
+                    "old".to_string(),
+                    "0.1.0".to_string(),
+                ),
+                vec![advisory.clone()],
+            ),
+            (
+                (
+                    "crates.io".to_string(),
+                    "fine".to_string(),
+                    "1.0.0".to_string(),
+                ),
+                Vec::new(),
+            ),
+        ]);
+
+        let dep = |name: &str, version: &str| Dependency {
+            name: name.to_string(),
+            version: version.to_string(),
+            dep_type: DependencyType::Runtime,
+            vulnerabilities: Vec::new(),
+        };
+        let mut info = DependencyInfo::new(
+            "Rust".to_string(),
+            vec![dep("old", "0.1"), dep("fine", "^1"), dep("local", "*")],
+        );
+
+        assert_eq!(client.check(&mut info).await.unwrap(), 1);
+        assert!(info.vulnerabilities_checked);
+        assert_eq!(info.dependencies[0].vulnerabilities, vec![advisory]);
+        assert!(info.dependencies[2].vulnerabilities.is_empty());
+    }
+}
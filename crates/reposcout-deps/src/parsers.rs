@@ -14,6 +14,7 @@ pub fn parse_cargo_toml(content: &str) -> Result<DependencyInfo> {
                 name: name.clone(),
                 version,
                 dep_type: DependencyType::Runtime,
+                vulnerabilities: Vec::new(),
             });
         }
     }
@@ -26,6 +27,7 @@ pub fn parse_cargo_toml(content: &str) -> Result<DependencyInfo> {
                 name: name.clone(),
                 version,
                 dep_type: DependencyType::Dev,
+                vulnerabilities: Vec::new(),
             });
         }
     }
@@ -38,6 +40,7 @@ pub fn parse_cargo_toml(content: &str) -> Result<DependencyInfo> {
                 name: name.clone(),
                 version,
                 dep_type: DependencyType::Build,
+                vulnerabilities: Vec::new(),
             });
         }
     }
@@ -58,6 +61,7 @@ pub fn parse_package_json(content: &str) -> Result<DependencyInfo> {
                 name: name.clone(),
                 version,
                 dep_type: DependencyType::Runtime,
+                vulnerabilities: Vec::new(),
             });
         }
     }
@@ -70,6 +74,7 @@ pub fn parse_package_json(content: &str) -> Result<DependencyInfo> {
                 name: name.clone(),
                 version,
                 dep_type: DependencyType::Dev,
+                vulnerabilities: Vec::new(),
             });
         }
     }
@@ -113,12 +118,644 @@ pub fn parse_requirements_txt(content: &str) -> Result<DependencyInfo> {
             name,
             version,
             dep_type: DependencyType::Runtime,
+            vulnerabilities: Vec::new(),
         });
     }
 
     Ok(DependencyInfo::new("Python".to_string(), dependencies))
 }
 
+/// Parse pyproject.toml for Python dependencies
+///
+/// Supports the PEP 621 `[project]` layout and Poetry's `[tool.poetry]`
+/// layout. Optional-dependency extras and Poetry groups count as dev.
+pub fn parse_pyproject_toml(content: &str) -> Result<DependencyInfo> {
+    let pyproject: toml::Value = toml::from_str(content)?;
+    let mut dependencies = Vec::new();
+
+    if let Some(poetry) = pyproject.get("tool").and_then(|t| t.get("poetry")) {
+        // Parse [tool.poetry.dependencies], skipping the interpreter constraint
+        if let Some(deps) = poetry.get("dependencies").and_then(|v| v.as_table()) {
+            for (name, value) in deps {
+                if name.eq_ignore_ascii_case("python") {
+                    continue;
+                }
+                dependencies.push(Dependency {
+                    name: name.clone(),
+                    version: extract_version(value),
+                    dep_type: DependencyType::Runtime,
+                    vulnerabilities: Vec::new(),
+                });
+            }
+        }
+
+        // Parse [tool.poetry.group.<name>.dependencies] and the legacy
+        // [tool.poetry.dev-dependencies]
+        let groups = poetry
+            .get("group")
+            .and_then(|v| v.as_table())
+            .into_iter()
+            .flat_map(|groups| groups.values())
+            .filter_map(|group| group.get("dependencies"))
+            .chain(poetry.get("dev-dependencies"));
+        for deps in groups.filter_map(|v| v.as_table()) {
+            for (name, value) in deps {
+                dependencies.push(Dependency {
+                    name: name.clone(),
+                    version: extract_version(value),
+                    dep_type: DependencyType::Dev,
+                    vulnerabilities: Vec::new(),
+                });
+            }
+        }
+
+        return Ok(DependencyInfo::new(
+            "Python (Poetry)".to_string(),
+            dependencies,
+        ));
+    }
+
+    let project = pyproject
+        .get("project")
+        .ok_or_else(|| anyhow::anyhow!("pyproject.toml has no [project] or [tool.poetry] table"))?;
+
+    // Parse [project] dependencies = [...]
+    if let Some(deps) = project.get("dependencies").and_then(|v| v.as_array()) {
+        for requirement in deps.iter().filter_map(|v| v.as_str()) {
+            if let Some(dep) = parse_pep508(requirement, DependencyType::Runtime) {
+                dependencies.push(dep);
+            }
+        }
+    }
+
+    // Parse [project.optional-dependencies]
+    if let Some(extras) = project
+        .get("optional-dependencies")
+        .and_then(|v| v.as_table())
+    {
+        for requirement in extras
+            .values()
+            .filter_map(|v| v.as_array())
+            .flatten()
+            .filter_map(|v| v.as_str())
+        {
+            if let Some(dep) = parse_pep508(requirement, DependencyType::Dev) {
+                dependencies.push(dep);
+            }
+        }
+    }
+
+    Ok(DependencyInfo::new(
+        "Python (PEP 621)".to_string(),
+        dependencies,
+    ))
+}
+
+/// Split a PEP 508 requirement like `requests[socks]>=2.0,<3; python_version>"3.8"`
+/// into name and version specifier, dropping extras and environment markers
+fn parse_pep508(requirement: &str, dep_type: DependencyType) -> Option<Dependency> {
+    let requirement = requirement.split(';').next()?.trim();
+    let name_end = requirement
+        .find(|c: char| !(c.is_alphanumeric() || matches!(c, '-' | '_' | '.')))
+        .unwrap_or(requirement.len());
+    let name = &requirement[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut rest = requirement[name_end..].trim();
+    // Drop extras like [socks]
+    if rest.starts_with('[') {
+        rest = rest.find(']').map_or("", |idx| rest[idx + 1..].trim());
+    }
+    let version = rest.trim_start_matches('(').trim_end_matches(')').trim();
+
+    Some(Dependency {
+        name: name.to_string(),
+        version: if version.is_empty() {
+            "*".to_string()
+        } else {
+            version.to_string()
+        },
+        dep_type,
+        vulnerabilities: Vec::new(),
+    })
+}
+
+/// Parse go.mod for Go module dependencies
+///
+/// Handles single-line and grouped `require` directives. Requirements marked
+/// `// indirect` are reported as indirect; `go`, `toolchain`, `replace`,
+/// `exclude` and `retract` directives are skipped.
+pub fn parse_go_mod(content: &str) -> Result<DependencyInfo> {
+    let mut dependencies = Vec::new();
+    // Name of the directive whose `( ... )` block we're inside, if any
+    let mut block: Option<&str> = None;
+
+    for line in content.lines() {
+        let (code, comment) = match line.find("//") {
+            Some(idx) => (&line[..idx], Some(&line[idx + 2..])),
+            None => (line, None),
+        };
+        let code = code.trim();
+
+        if code.is_empty() {
+            continue;
+        }
+
+        let spec = match block {
+            Some(_) if code == ")" => {
+                block = None;
+                continue;
+            }
+            Some("require") => code,
+            Some(_) => continue,
+            None => {
+                let (directive, rest) = code.split_once(char::is_whitespace).unwrap_or((code, ""));
+                let rest = rest.trim();
+                if rest == "(" {
+                    block = Some(directive);
+                    continue;
+                }
+                if directive != "require" {
+                    continue;
+                }
+                rest
+            }
+        };
+
+        let mut parts = spec.split_whitespace();
+        let (Some(name), Some(version)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+
+        let indirect = comment.is_some_and(|c| c.trim_start().starts_with("indirect"));
+        dependencies.push(Dependency {
+            name: name.trim_matches('"').to_string(),
+            version: version.to_string(),
+            dep_type: if indirect {
+                DependencyType::Indirect
+            } else {
+                DependencyType::Runtime
+            },
+            vulnerabilities: Vec::new(),
+        });
+    }
+
+    Ok(DependencyInfo::new("Go".to_string(), dependencies))
+}
+
+/// Parse composer.json for PHP dependencies
+///
+/// `require` is runtime and `require-dev` is dev. Platform requirements
+/// (`php` itself and `ext-*`/`lib-*` extensions) aren't packages, so they're skipped.
+pub fn parse_composer_json(content: &str) -> Result<DependencyInfo> {
+    let composer: serde_json::Value = serde_json::from_str(content)?;
+    let mut dependencies = Vec::new();
+
+    for (section, dep_type) in [
+        ("require", DependencyType::Runtime),
+        ("require-dev", DependencyType::Dev),
+    ] {
+        let Some(deps) = composer.get(section).and_then(|v| v.as_object()) else {
+            continue;
+        };
+        for (name, value) in deps {
+            if is_composer_platform_package(name) {
+                continue;
+            }
+            dependencies.push(Dependency {
+                name: name.clone(),
+                version: value.as_str().unwrap_or("*").to_string(),
+                dep_type,
+                vulnerabilities: Vec::new(),
+            });
+        }
+    }
+
+    Ok(DependencyInfo::new(
+        "PHP (Composer)".to_string(),
+        dependencies,
+    ))
+}
+
+fn is_composer_platform_package(name: &str) -> bool {
+    name == "php"
+        || name.starts_with("php-")
+        || name.starts_with("ext-")
+        || name.starts_with("lib-")
+}
+
+/// Parse a Gemfile for Ruby dependencies
+///
+/// Reads `gem 'name', '~> x.y'` lines. Gems inside a `group` block that
+/// includes `:development` or `:test`, or declared with such a `group:`
+/// option, count as dev. Comments, `source`, `ruby`, `gemspec` and other
+/// directives are ignored.
+pub fn parse_gemfile(content: &str) -> Result<DependencyInfo> {
+    let mut dependencies = Vec::new();
+    // One entry per open block that `end` closes: whether it's a dev group
+    let mut blocks: Vec<bool> = Vec::new();
+
+    for line in content.lines() {
+        let code = strip_ruby_comment(line).trim();
+        if code.is_empty() {
+            continue;
+        }
+
+        if code == "end" {
+            blocks.pop();
+            continue;
+        }
+
+        if code.ends_with(" do") || code.contains(" do |") {
+            let dev = code.starts_with("group") && is_dev_group(code);
+            blocks.push(dev);
+            continue;
+        }
+
+        // Conditionals end with `end` too; `gem "x" if ...` modifiers don't
+        let keyword = code.split(|c: char| !c.is_ascii_alphabetic()).next();
+        if matches!(
+            keyword,
+            Some("if" | "unless" | "case" | "while" | "until" | "begin")
+        ) {
+            blocks.push(false);
+            continue;
+        }
+
+        let Some(args) = code
+            .strip_prefix("gem ")
+            .or_else(|| code.strip_prefix("gem("))
+        else {
+            continue;
+        };
+
+        let mut parts = args.split(',');
+        let Some(name) = parts.next().and_then(ruby_string) else {
+            continue;
+        };
+
+        let rest: Vec<&str> = parts.collect();
+        let versions: Vec<&str> = rest.iter().map_while(|part| ruby_string(part)).collect();
+        let version = if versions.is_empty() {
+            "*".to_string()
+        } else {
+            versions.join(", ")
+        };
+
+        let dev_option = rest.join(",").contains("group") && is_dev_group(&rest.join(","));
+        let dev = dev_option || blocks.iter().any(|&dev| dev);
+
+        dependencies.push(Dependency {
+            name: name.to_string(),
+            version,
+            dep_type: if dev {
+                DependencyType::Dev
+            } else {
+                DependencyType::Runtime
+            },
+            vulnerabilities: Vec::new(),
+        });
+    }
+
+    Ok(DependencyInfo::new(
+        "Ruby (Bundler)".to_string(),
+        dependencies,
+    ))
+}
+
+/// Whether a group list names the development or test group
+fn is_dev_group(groups: &str) -> bool {
+    groups.contains(":development") || groups.contains(":test")
+}
+
+/// The contents of a single- or double-quoted Ruby string, ignoring what follows it
+fn ruby_string(s: &str) -> Option<&str> {
+    let s = s.trim().trim_start_matches('(');
+    let quote = s.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+    let end = s[1..].find(quote)?;
+    Some(&s[1..1 + end])
+}
+
+/// Drop a trailing `#` comment, leaving `#` inside strings alone
+fn strip_ruby_comment(line: &str) -> &str {
+    let mut quote = None;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '#') => return &line[..i],
+            (None, '\'' | '"') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Parse build.gradle or build.gradle.kts for Java/Kotlin dependencies
+///
+/// Reads declarations inside `dependencies { ... }` blocks, in Groovy
+/// (`implementation 'g:a:v'`, `implementation group: 'g', name: 'a', version: 'v'`)
+/// and Kotlin (`implementation("g:a:v")`, `implementation(group = "g", name = "a")`)
+/// syntax. Test configurations count as dev, compile-time-only ones as build.
+/// Versions held in variables (`$kotlinVersion`) are kept as written.
+/// Project dependencies, file dependencies and version catalog aliases are skipped.
+pub fn parse_gradle(content: &str) -> Result<DependencyInfo> {
+    let mut dependencies = Vec::new();
+    let mut found_block = false;
+    // Names of the currently open `{ ... }` blocks, innermost last
+    let mut blocks: Vec<String> = Vec::new();
+    let mut in_comment = false;
+
+    for line in content.lines() {
+        let mut code = line.trim();
+        if in_comment {
+            match code.find("*/") {
+                Some(end) => {
+                    code = code[end + 2..].trim();
+                    in_comment = false;
+                }
+                None => continue,
+            }
+        }
+        if code.starts_with("/*") {
+            in_comment = !code.contains("*/");
+            continue;
+        }
+        let code = strip_line_comment(code).trim();
+        if code.is_empty() {
+            continue;
+        }
+
+        // Split the line into statements at braces and semicolons outside
+        // strings, so one-line blocks like `dependencies { api 'a:b:1' }`
+        // are read too, and track the braces so closures and nested blocks
+        // are skipped
+        let mut quote = None;
+        let mut start = 0;
+        for (i, c) in code.char_indices().chain([(code.len(), ';')]) {
+            match (quote, c) {
+                (None, '\'' | '"') => quote = Some(c),
+                (Some(q), c) if c == q => quote = None,
+                (None, '{' | '}' | ';') => {
+                    let statement = code[start..i].trim();
+                    start = i + c.len_utf8();
+                    if blocks.last().map(String::as_str) == Some("dependencies") {
+                        if let Some(dependency) = gradle_dependency(statement) {
+                            dependencies.push(dependency);
+                        }
+                    }
+                    match c {
+                        '{' => {
+                            let name = statement
+                                .split(|c: char| c.is_whitespace() || c == '(')
+                                .rfind(|part| !part.is_empty())
+                                .unwrap_or("")
+                                .to_string();
+                            found_block |= name == "dependencies";
+                            blocks.push(name);
+                        }
+                        '}' => {
+                            blocks.pop();
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if !found_block {
+        anyhow::bail!("No dependencies block found");
+    }
+
+    Ok(DependencyInfo::new(
+        "Java/Kotlin (Gradle)".to_string(),
+        dependencies,
+    ))
+}
+
+/// One dependency declaration from inside a `dependencies` block
+fn gradle_dependency(code: &str) -> Option<Dependency> {
+    let config_end = code
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(code.len());
+    let (config, args) = code.split_at(config_end);
+    let dep_type = gradle_dependency_type(config)?;
+
+    let args = args.trim().trim_start_matches('(').trim_start();
+    if args.starts_with("project(") || args.starts_with("files(") || args.starts_with("fileTree(") {
+        return None;
+    }
+
+    let (name, version) = match gradle_named_arg(args, "name") {
+        Some(artifact) => {
+            let group = gradle_named_arg(args, "group").unwrap_or("");
+            let name = if group.is_empty() {
+                artifact.to_string()
+            } else {
+                format!("{}:{}", group, artifact)
+            };
+            (name, gradle_named_arg(args, "version").unwrap_or("*"))
+        }
+        None => {
+            // The first string is the coordinate, also inside platform("...")
+            let coordinate = quoted_strings(args).into_iter().next()?;
+            let coordinate = coordinate.split('@').next().unwrap_or(coordinate);
+            let mut parts = coordinate.split(':');
+            let group = parts.next()?;
+            let artifact = parts.next()?;
+            (
+                format!("{}:{}", group, artifact),
+                parts.next().filter(|v| !v.is_empty()).unwrap_or("*"),
+            )
+        }
+    };
+
+    Some(Dependency {
+        name,
+        version: version.to_string(),
+        dep_type,
+        vulnerabilities: Vec::new(),
+    })
+}
+
+/// Dependency type for a Gradle configuration, None for unknown configurations
+fn gradle_dependency_type(config: &str) -> Option<DependencyType> {
+    match config {
+        "implementation" | "api" | "compile" | "runtime" | "runtimeOnly" => {
+            Some(DependencyType::Runtime)
+        }
+        "compileOnly" | "annotationProcessor" | "kapt" | "ksp" => Some(DependencyType::Build),
+        c if c.starts_with("test") || c.starts_with("androidTest") => Some(DependencyType::Dev),
+        c if c.ends_with("Implementation") || c.ends_with("Api") => Some(DependencyType::Runtime),
+        _ => None,
+    }
+}
+
+/// Value of a `key: 'value'` (Groovy) or `key = "value"` (Kotlin) argument
+fn gradle_named_arg<'a>(args: &'a str, key: &str) -> Option<&'a str> {
+    args.match_indices(key).find_map(|(i, _)| {
+        let before = args[..i].chars().next_back();
+        if before.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return None;
+        }
+        let rest = args[i + key.len()..].trim_start();
+        let rest = rest.strip_prefix(':').or_else(|| rest.strip_prefix('='))?;
+        quoted_strings(rest.trim_start())
+            .into_iter()
+            .next()
+            .filter(|_| rest.trim_start().starts_with(['\'', '"']))
+    })
+}
+
+/// Contents of the single- or double-quoted strings in `s`, in order
+fn quoted_strings(s: &str) -> Vec<&str> {
+    let mut strings = Vec::new();
+    let mut start = None;
+    let mut quote = ' ';
+    for (i, c) in s.char_indices() {
+        match start {
+            None if c == '\'' || c == '"' => {
+                start = Some(i + 1);
+                quote = c;
+            }
+            Some(from) if c == quote => {
+                strings.push(&s[from..i]);
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    strings
+}
+
+/// Drop a trailing `//` comment, leaving `//` inside strings (URLs) alone
+fn strip_line_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut prev = ' ';
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (None, '/') if prev == '/' => return &line[..i - 1],
+            (None, '\'' | '"') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            _ => {}
+        }
+        prev = c;
+    }
+    line
+}
+
+/// Parse a Maven pom.xml for Java dependencies
+///
+/// Reads the project's own `<dependencies>` (not `<dependencyManagement>`).
+/// Scope `test` is dev, `provided` is build (the runtime supplies it), and
+/// `compile`, `runtime` or no scope are runtime; `<optional>true</optional>`
+/// marks a dependency optional. `${property}` versions are resolved against
+/// `<properties>` when defined there, otherwise kept as written.
+pub fn parse_pom_xml(content: &str) -> Result<DependencyInfo> {
+    let doc = roxmltree::Document::parse(content)?;
+    let project = doc.root_element();
+
+    let mut properties = std::collections::HashMap::new();
+    if let Some(version) = xml_child_text(project, "version") {
+        properties.insert("project.version".to_string(), version.to_string());
+    }
+    if let Some(props) = xml_child(project, "properties") {
+        for prop in props.children().filter(|n| n.is_element()) {
+            let value = prop.text().unwrap_or("").trim();
+            properties.insert(prop.tag_name().name().to_string(), value.to_string());
+        }
+    }
+
+    let Some(section) = xml_child(project, "dependencies") else {
+        anyhow::bail!("No dependencies section found");
+    };
+
+    let mut dependencies = Vec::new();
+    for dep in section
+        .children()
+        .filter(|n| n.is_element() && n.tag_name().name() == "dependency")
+    {
+        let (Some(group), Some(artifact)) = (
+            xml_child_text(dep, "groupId"),
+            xml_child_text(dep, "artifactId"),
+        ) else {
+            continue;
+        };
+
+        let version = xml_child_text(dep, "version")
+            .map(|v| resolve_pom_properties(v, &properties))
+            .unwrap_or_else(|| "*".to_string());
+        let dep_type = if xml_child_text(dep, "optional") == Some("true") {
+            DependencyType::Optional
+        } else {
+            match xml_child_text(dep, "scope") {
+                Some("test") => DependencyType::Dev,
+                Some("provided") => DependencyType::Build,
+                _ => DependencyType::Runtime,
+            }
+        };
+
+        dependencies.push(Dependency {
+            name: format!(
+                "{}:{}",
+                resolve_pom_properties(group, &properties),
+                artifact
+            ),
+            version,
+            dep_type,
+            vulnerabilities: Vec::new(),
+        });
+    }
+
+    Ok(DependencyInfo::new(
+        "Java (Maven)".to_string(),
+        dependencies,
+    ))
+}
+
+/// First child element with this local name, whatever its namespace
+fn xml_child<'a, 'input>(
+    node: roxmltree::Node<'a, 'input>,
+    name: &str,
+) -> Option<roxmltree::Node<'a, 'input>> {
+    node.children()
+        .find(|n| n.is_element() && n.tag_name().name() == name)
+}
+
+/// Trimmed text of the first child element with this local name
+fn xml_child_text<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Option<&'a str> {
+    xml_child(node, name)
+        .and_then(|n| n.text())
+        .map(str::trim)
+        .filter(|text| !text.is_empty())
+}
+
+/// Substitute `${name}` placeholders that `<properties>` defines
+fn resolve_pom_properties(
+    value: &str,
+    properties: &std::collections::HashMap<String, String>,
+) -> String {
+    let mut resolved = String::new();
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        let placeholder = &rest[start..start + len + 1];
+        resolved.push_str(&rest[..start]);
+        match properties.get(&placeholder[2..placeholder.len() - 1]) {
+            Some(property) => resolved.push_str(property),
+            None => resolved.push_str(placeholder),
+        }
+        rest = &rest[start + len + 1..];
+    }
+    resolved.push_str(rest);
+    resolved
+}
+
 /// Extract version from TOML value (can be string or table)
 fn extract_version(value: &toml::Value) -> String {
     match value {
@@ -189,4 +826,299 @@ numpy
         assert_eq!(info.ecosystem, "Python");
         assert_eq!(info.total_count, 4);
     }
+
+    #[test]
+    fn test_parse_go_mod() {
+        let content = r#"
+module github.com/example/app
+
+go 1.22
+
+toolchain go1.22.3
+
+require github.com/spf13/cobra v1.8.0
+
+require (
+	github.com/stretchr/testify v1.9.0
+	golang.org/x/sys v0.20.0 // indirect
+)
+
+replace github.com/spf13/cobra => ../cobra
+
+exclude (
+	golang.org/x/net v0.1.0
+)
+        "#;
+
+        let info = parse_go_mod(content).unwrap();
+        assert_eq!(info.ecosystem, "Go");
+        assert_eq!(info.total_count, 3);
+        assert_eq!(info.runtime_count, 2);
+        assert_eq!(info.dev_count, 0);
+
+        let sys = info
+            .dependencies
+            .iter()
+            .find(|d| d.name == "golang.org/x/sys")
+            .unwrap();
+        assert_eq!(sys.version, "v0.20.0");
+        assert_eq!(sys.dep_type, DependencyType::Indirect);
+    }
+
+    #[test]
+    fn test_parse_pyproject_toml() {
+        let pep621 = r#"
+[project]
+name = "app"
+dependencies = [
+    "requests[socks]>=2.28,<3; python_version >= '3.8'",
+    "click",
+]
+
+[project.optional-dependencies]
+test = ["pytest>=7.0"]
+        "#;
+
+        let info = parse_pyproject_toml(pep621).unwrap();
+        assert_eq!(info.ecosystem, "Python (PEP 621)");
+        assert_eq!(info.runtime_count, 2);
+        assert_eq!(info.dev_count, 1);
+        assert_eq!(info.dependencies[0].name, "requests");
+        assert_eq!(info.dependencies[0].version, ">=2.28,<3");
+        assert_eq!(info.dependencies[1].version, "*");
+
+        let poetry = r#"
+[tool.poetry.dependencies]
+python = "^3.10"
+httpx = "^0.27"
+pydantic = { version = "^2.0", extras = ["email"] }
+
+[tool.poetry.group.dev.dependencies]
+ruff = "^0.4"
+        "#;
+
+        let info = parse_pyproject_toml(poetry).unwrap();
+        assert_eq!(info.ecosystem, "Python (Poetry)");
+        assert_eq!(info.runtime_count, 2);
+        assert_eq!(info.dev_count, 1);
+        assert!(info.dependencies.iter().all(|d| d.name != "python"));
+
+        assert!(parse_pyproject_toml("[build-system]\nrequires = []").is_err());
+    }
+
+    #[test]
+    fn test_parse_composer_json() {
+        let content = r#"
+{
+  "require": {
+    "php": ">=8.1",
+    "ext-json": "*",
+    "laravel/framework": "^11.0",
+    "guzzlehttp/guzzle": "^7.2"
+  },
+  "require-dev": {
+    "phpunit/phpunit": "^10.5"
+  }
+}
+        "#;
+
+        let info = parse_composer_json(content).unwrap();
+        assert_eq!(info.ecosystem, "PHP (Composer)");
+        assert_eq!(info.runtime_count, 2);
+        assert_eq!(info.dev_count, 1);
+        assert!(info
+            .dependencies
+            .iter()
+            .all(|d| d.name != "php" && d.name != "ext-json"));
+    }
+
+    #[test]
+    fn test_parse_gemfile() {
+        let content = r#"
+# frozen_string_literal: true
+source "https://rubygems.org"
+git_source(:github) { |repo| "https://github.com/#{repo}.git" }
+
+ruby "3.3.0"
+
+gem "rails", "~> 7.1", ">= 7.1.3" # web framework
+gem 'pg'
+gem "rubocop", require: false, group: :development
+
+group :development, :test do
+  gem "rspec-rails", '~> 6.1'
+  platforms :mri do
+    gem "debug"
+  end
+  if RUBY_PLATFORM.include?("darwin")
+    gem "terminal-notifier"
+  end
+  gem "factory_bot" unless ENV["CI"]
+end
+
+group :production do
+  gem "lograge"
+end
+        "#;
+
+        let info = parse_gemfile(content).unwrap();
+        assert_eq!(info.ecosystem, "Ruby (Bundler)");
+        assert_eq!(info.total_count, 8);
+        assert_eq!(info.runtime_count, 3);
+        // Including the gem after the `if` block, still in the group
+        assert_eq!(info.dev_count, 5);
+
+        let rails = &info.dependencies[0];
+        assert_eq!(rails.name, "rails");
+        assert_eq!(rails.version, "~> 7.1, >= 7.1.3");
+        assert_eq!(info.dependencies[1].version, "*");
+        let lograge = info.dependencies.last().unwrap();
+        assert_eq!(lograge.name, "lograge");
+        assert_eq!(lograge.dep_type, DependencyType::Runtime);
+    }
+
+    #[test]
+    fn test_parse_gradle() {
+        let groovy = r#"
+buildscript {
+    dependencies {
+        classpath 'com.android.tools.build:gradle:8.2.0'
+    }
+}
+
+dependencies {
+    implementation 'com.google.guava:guava:33.0.0-jre' // core utils
+    api group: 'org.slf4j', name: 'slf4j-api', version: '2.0.9'
+    implementation "org.jetbrains.kotlin:kotlin-stdlib:$kotlinVersion"
+    implementation(project(':core'))
+    implementation('com.squareup.okhttp3:okhttp:4.12.0') {
+        exclude group: 'org.jetbrains.kotlin'
+    }
+    /* compileOnly 'ignored:in-comment:1.0' */
+    compileOnly 'org.projectlombok:lombok:1.18.30'
+    testImplementation 'junit:junit:4.13.2'
+    androidTestImplementation 'androidx.test:runner:1.5.2@aar'
+}
+        "#;
+
+        let info = parse_gradle(groovy).unwrap();
+        assert_eq!(info.ecosystem, "Java/Kotlin (Gradle)");
+        assert_eq!(info.total_count, 7);
+        assert_eq!(info.runtime_count, 4);
+        assert_eq!(info.dev_count, 2);
+
+        let slf4j = &info.dependencies[1];
+        assert_eq!(slf4j.name, "org.slf4j:slf4j-api");
+        assert_eq!(slf4j.version, "2.0.9");
+        assert_eq!(info.dependencies[2].version, "$kotlinVersion");
+        assert_eq!(info.dependencies[6].version, "1.5.2");
+
+        let kotlin = r#"
+plugins {
+    kotlin("jvm") version "1.9.22"
+}
+
+dependencies {
+    implementation(platform("io.ktor:ktor-bom:2.3.7"))
+    implementation("io.ktor:ktor-server-core")
+    implementation(group = "com.fasterxml.jackson.core", name = "jackson-databind", version = "2.16.1")
+    implementation(libs.kotlinx.coroutines)
+    testImplementation(kotlin("test"))
+    testImplementation("io.mockk:mockk:${mockkVersion}")
+}
+        "#;
+
+        // A whole block on one line
+        let info = parse_gradle(
+            "dependencies { implementation 'a:b:1.0'; testImplementation(\"c:d:2.0\") }",
+        )
+        .unwrap();
+        assert_eq!(info.total_count, 2);
+        assert_eq!(info.dependencies[0].name, "a:b");
+        assert_eq!(info.dependencies[1].dep_type, DependencyType::Dev);
+
+        let info = parse_gradle(kotlin).unwrap();
+        assert_eq!(info.runtime_count, 3);
+        assert_eq!(info.dev_count, 1);
+        assert_eq!(info.dependencies[0].name, "io.ktor:ktor-bom");
+        assert_eq!(info.dependencies[1].version, "*");
+        assert_eq!(
+            info.dependencies[2].name,
+            "com.fasterxml.jackson.core:jackson-databind"
+        );
+        assert_eq!(info.dependencies[3].version, "${mockkVersion}");
+
+        assert!(parse_gradle("plugins {\n    id 'java'\n}").is_err());
+    }
+
+    #[test]
+    fn test_parse_pom_xml() {
+        let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <modelVersion>4.0.0</modelVersion>
+  <groupId>com.example</groupId>
+  <artifactId>app</artifactId>
+  <version>1.2.0</version>
+  <properties>
+    <spring.version>6.1.3</spring.version>
+  </properties>
+  <dependencyManagement>
+    <dependencies>
+      <dependency>
+        <groupId>org.managed</groupId>
+        <artifactId>bom</artifactId>
+        <version>1.0</version>
+      </dependency>
+    </dependencies>
+  </dependencyManagement>
+  <dependencies>
+    <dependency>
+      <groupId>org.springframework</groupId>
+      <artifactId>spring-core</artifactId>
+      <version>${spring.version}</version>
+    </dependency>
+    <dependency>
+      <groupId>com.example</groupId>
+      <artifactId>shared</artifactId>
+      <version>${project.version}</version>
+      <scope>runtime</scope>
+    </dependency>
+    <dependency>
+      <groupId>jakarta.servlet</groupId>
+      <artifactId>jakarta.servlet-api</artifactId>
+      <version>${servlet.version}</version>
+      <scope>provided</scope>
+    </dependency>
+    <dependency>
+      <groupId>org.junit.jupiter</groupId>
+      <artifactId>junit-jupiter</artifactId>
+      <scope>test</scope>
+    </dependency>
+    <dependency>
+      <groupId>com.h2database</groupId>
+      <artifactId>h2</artifactId>
+      <version>2.2.224</version>
+      <optional>true</optional>
+    </dependency>
+  </dependencies>
+</project>
+"#;
+
+        let info = parse_pom_xml(content).unwrap();
+        assert_eq!(info.ecosystem, "Java (Maven)");
+        assert_eq!(info.total_count, 5);
+        assert_eq!(info.runtime_count, 2);
+        assert_eq!(info.dev_count, 1);
+
+        assert_eq!(info.dependencies[0].name, "org.springframework:spring-core");
+        assert_eq!(info.dependencies[0].version, "6.1.3");
+        assert_eq!(info.dependencies[1].version, "1.2.0");
+        assert_eq!(info.dependencies[2].version, "${servlet.version}");
+        assert_eq!(info.dependencies[2].dep_type, DependencyType::Build);
+        assert_eq!(info.dependencies[3].version, "*");
+        assert_eq!(info.dependencies[4].dep_type, DependencyType::Optional);
+
+        assert!(parse_pom_xml("<project><artifactId>x</artifactId></project>").is_err());
+        assert!(parse_pom_xml("not xml").is_err());
+    }
 }
@@ -0,0 +1,141 @@
+// Field-weighted BM25 for keyword search results
+//
+// The platform APIs return keyword results without a relevance score, so
+// hybrid search ranks them itself. Each repository is one document made of
+// its name, topics and description, and a term found in a weighted field
+// counts that many times over (BM25F). A query naming a library then ranks
+// the library above projects that only mention it in their description.
+
+use crate::models::FieldWeights;
+use crate::preprocessing::STOPWORDS;
+use reposcout_core::models::Repository;
+use std::collections::HashMap;
+
+/// Term frequency saturation
+const K1: f32 = 1.2;
+/// How much longer-than-average documents are penalized
+const B: f32 = 0.75;
+
+/// Scores repositories against a query, relative to each other
+pub struct BM25Scorer {
+    weights: FieldWeights,
+}
+
+impl BM25Scorer {
+    pub fn new(weights: FieldWeights) -> Self {
+        Self { weights }
+    }
+
+    /// BM25 score of each repository for `query`, in the order given.
+    /// Document frequencies come from `repos` themselves.
+    pub fn score(&self, query: &str, repos: &[Repository]) -> Vec<f32> {
+        let terms = query_terms(query);
+        let docs: Vec<HashMap<String, f32>> =
+            repos.iter().map(|repo| self.term_weights(repo)).collect();
+        let lengths: Vec<f32> = docs.iter().map(|doc| doc.values().sum()).collect();
+        let avg_length = lengths.iter().sum::<f32>() / docs.len().max(1) as f32;
+
+        let n = docs.len() as f32;
+        let idf: Vec<f32> = terms
+            .iter()
+            .map(|term| {
+                let df = docs.iter().filter(|doc| doc.contains_key(term)).count() as f32;
+                (1.0 + (n - df + 0.5) / (df + 0.5)).ln()
+            })
+            .collect();
+
+        docs.iter()
+            .zip(&lengths)
+            .map(|(doc, length)| {
+                let norm = if avg_length > 0.0 {
+                    1.0 - B + B * length / avg_length
+                } else {
+                    1.0
+                };
+                terms
+                    .iter()
+                    .zip(&idf)
+                    .map(|(term, idf)| {
+                        let tf = doc.get(term).copied().unwrap_or(0.0);
+                        idf * tf * (K1 + 1.0) / (tf + K1 * norm)
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+
+    /// Weighted frequency of every term in the repository's fields
+    fn term_weights(&self, repo: &Repository) -> HashMap<String, f32> {
+        let mut weights = HashMap::new();
+        let mut add = |text: &str, weight: f32| {
+            for token in tokenize(text) {
+                *weights.entry(token).or_insert(0.0) += weight;
+            }
+        };
+
+        add(&repo.full_name, self.weights.name);
+        for topic in &repo.topics {
+            add(topic, self.weights.topics);
+        }
+        if let Some(description) = &repo.description {
+            add(description, self.weights.description);
+        }
+        weights
+    }
+}
+
+/// Lower-cased words, splitting names like "tokio-rs/tokio" into their parts
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Distinct query words, without stopwords and `key:value` qualifiers
+fn query_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in query.split_whitespace().filter(|word| !word.contains(':')) {
+        for term in tokenize(word) {
+            if !STOPWORDS.contains(&term.as_str()) && !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+    }
+    terms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reposcout_core::models::Platform;
+
+    fn repo(full_name: &str, description: &str) -> Repository {
+        Repository {
+            description: Some(description.to_string()),
+            url: format!("https://github.com/{}", full_name),
+            ..Repository::test_fixture(Platform::GitHub, full_name, 0)
+        }
+    }
+
+    #[test]
+    fn test_name_match_outranks_description_match() {
+        let repos = [
+            repo("someone/web-starter", "Starter template built on axum"),
+            repo("tokio-rs/axum", "Ergonomic and modular web framework"),
+            repo("someone/cli-kit", "Helpers for command line apps"),
+        ];
+
+        let scores = BM25Scorer::new(FieldWeights::default()).score("axum", &repos);
+        assert!(scores[1] > scores[0], "{:?}", scores);
+        assert_eq!(scores[2], 0.0);
+
+        // Unweighted, both mention it once in equally long text
+        let flat = FieldWeights {
+            name: 1.0,
+            topics: 1.0,
+            description: 1.0,
+        };
+        let scores = BM25Scorer::new(flat).score("axum language:rust", &repos);
+        assert_eq!(scores[0], scores[1]);
+    }
+}
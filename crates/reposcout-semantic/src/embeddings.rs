@@ -1,19 +1,23 @@
 use crate::error::{Result, SemanticError};
-use crate::models::EmbeddingEntry;
+use crate::models::{EmbeddingEntry, EmbeddingModel};
 use crate::preprocessing::{preprocess_query, preprocess_repository};
-use fastembed::{EmbeddingModel, InitOptions, TextEmbedding};
+use fastembed::{
+    EmbeddingModel as FastEmbedModel, InitOptions, InitOptionsUserDefined, TextEmbedding,
+    TokenizerFiles, UserDefinedEmbeddingModel,
+};
 use reposcout_core::models::Repository;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use tracing::{debug, info, warn};
+use tracing::{debug, info};
 
 /// Embedding generator using fastembed
 pub struct EmbeddingGenerator {
     /// The underlying embedding model
     model: Arc<RwLock<Option<TextEmbedding>>>,
 
-    /// Model name
-    model_name: String,
+    /// Which model to load
+    model_kind: EmbeddingModel,
 
     /// Vector dimension
     dimension: usize,
@@ -21,20 +25,16 @@ pub struct EmbeddingGenerator {
 
 impl EmbeddingGenerator {
     /// Create a new embedding generator (lazy initialization)
-    pub fn new(model_name: String) -> Self {
-        // Determine dimension based on model
-        let dimension = match model_name.as_str() {
-            "sentence-transformers/all-MiniLM-L6-v2" => 384,
-            "BAAI/bge-small-en-v1.5" => 384,
-            "BAAI/bge-base-en-v1.5" => 768,
-            _ => 384, // default
-        };
+    ///
+    /// Fails for a custom model whose dimension can't be read.
+    pub fn new(model_kind: EmbeddingModel) -> Result<Self> {
+        let dimension = model_kind.dimension()?;
 
-        Self {
+        Ok(Self {
             model: Arc::new(RwLock::new(None)),
-            model_name,
+            model_kind,
             dimension,
-        }
+        })
     }
 
     /// Initialize the model (downloads if needed)
@@ -46,19 +46,16 @@ impl EmbeddingGenerator {
             return Ok(());
         }
 
-        info!("Initializing embedding model: {}", self.model_name);
-
-        // Determine the model enum variant
-        let model_type = match self.model_name.as_str() {
-            "sentence-transformers/all-MiniLM-L6-v2" => EmbeddingModel::AllMiniLML6V2,
-            "BAAI/bge-small-en-v1.5" => EmbeddingModel::BGESmallENV15,
-            "BAAI/bge-base-en-v1.5" => EmbeddingModel::BGEBaseENV15,
-            _ => {
-                warn!(
-                    "Unknown model {}, defaulting to all-MiniLM-L6-v2",
-                    self.model_name
-                );
-                EmbeddingModel::AllMiniLML6V2
+        info!("Initializing embedding model: {}", self.model_kind.name());
+
+        let model_type = match &self.model_kind {
+            EmbeddingModel::MiniLmL6 => FastEmbedModel::AllMiniLML6V2,
+            EmbeddingModel::BgeSmall => FastEmbedModel::BGESmallENV15,
+            EmbeddingModel::BgeBase => FastEmbedModel::BGEBaseENV15,
+            EmbeddingModel::Custom(dir) => {
+                *model_guard = Some(Self::load_custom(dir)?);
+                info!("Custom embedding model loaded from {}", dir.display());
+                return Ok(());
             }
         };
 
@@ -74,6 +71,36 @@ impl EmbeddingGenerator {
         Ok(())
     }
 
+    /// Load an ONNX model and its tokenizer files from a local directory
+    fn load_custom(dir: &Path) -> Result<TextEmbedding> {
+        let read = |name: &str| {
+            std::fs::read(dir.join(name)).map_err(|e| {
+                SemanticError::ModelLoadError(format!(
+                    "Cannot read {} from {}: {}",
+                    name,
+                    dir.display(),
+                    e
+                ))
+            })
+        };
+
+        let tokenizer_files = TokenizerFiles {
+            tokenizer_file: read("tokenizer.json")?,
+            config_file: read("config.json")?,
+            special_tokens_map_file: read("special_tokens_map.json")?,
+            tokenizer_config_file: read("tokenizer_config.json")?,
+        };
+        let model = UserDefinedEmbeddingModel::new(read("model.onnx")?, tokenizer_files);
+
+        TextEmbedding::try_new_from_user_defined(model, InitOptionsUserDefined::new())
+            .map_err(|e| SemanticError::ModelLoadError(e.to_string()))
+    }
+
+    /// The configured embedding model
+    pub fn model(&self) -> &EmbeddingModel {
+        &self.model_kind
+    }
+
     /// Get the vector dimension
     pub fn dimension(&self) -> usize {
         self.dimension
@@ -185,10 +212,7 @@ impl EmbeddingGenerator {
 
         // Create embedding entries
         let mut entries = Vec::new();
-        for ((vector, source_text), repo_id) in vectors
-            .into_iter()
-            .zip(source_texts.into_iter())
-            .zip(repo_ids.into_iter())
+        for ((vector, source_text), repo_id) in vectors.into_iter().zip(source_texts).zip(repo_ids)
         {
             entries.push(EmbeddingEntry::new(repo_id, vector, source_text));
         }
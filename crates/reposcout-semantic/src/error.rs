@@ -33,6 +33,24 @@ pub enum SemanticError {
     #[error("Repository not found in index: {repo_id}")]
     RepositoryNotFound { repo_id: String },
 
+    #[error(
+        "Semantic index was built with {index_model} ({index_dimension} dims) but the configured \
+         model is {configured_model} ({configured_dimension} dims). Rebuild it with \
+         `reposcout semantic-index rebuild --force`"
+    )]
+    ModelMismatch {
+        index_model: String,
+        index_dimension: usize,
+        configured_model: String,
+        configured_dimension: usize,
+    },
+
+    #[error(
+        "The semantic index is empty. Build it first with `reposcout semantic-index rebuild`, \
+         or search a few times in the TUI to index the results"
+    )]
+    EmptyIndex,
+
     #[error("Invalid configuration: {0}")]
     ConfigError(String),
 
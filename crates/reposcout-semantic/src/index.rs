@@ -79,6 +79,18 @@ impl VectorIndex {
         &self.stats
     }
 
+    /// Count embedded vs. unchanged repositories from a batch indexing run
+    pub fn record_indexing(&mut self, embedded: usize, skipped: usize) {
+        self.stats.record_indexing(embedded, skipped);
+    }
+
+    /// Whether `repo_id` is indexed with an embedding of exactly `source_text`
+    pub fn is_unchanged(&self, repo_id: &str, source_text: &str) -> bool {
+        self.metadata
+            .get(repo_id)
+            .is_some_and(|entry| !entry.text_changed(source_text))
+    }
+
     /// Add a repository embedding to the index
     pub fn add(&mut self, entry: EmbeddingEntry) -> Result<()> {
         if entry.vector.len() != self.dimension {
@@ -253,6 +265,13 @@ impl VectorIndex {
         Ok(())
     }
 
+    /// Read the persisted stats (model name and dimension) without loading
+    /// the index itself
+    pub fn read_stats(index_path: &Path) -> Option<IndexStats> {
+        let stats_data = std::fs::read_to_string(index_path.join("stats.json")).ok()?;
+        serde_json::from_str(&stats_data).ok()
+    }
+
     /// Load the index from disk
     pub fn load(index_path: PathBuf, dimension: usize) -> Result<Self> {
         info!("Loading semantic index from {:?}", index_path);
@@ -4,6 +4,7 @@
 // and vector similarity search. It enables natural language queries and
 // finding repositories by use case rather than just keywords.
 
+pub mod bm25;
 pub mod embeddings;
 pub mod error;
 pub mod index;
@@ -12,11 +13,15 @@ pub mod preprocessing;
 pub mod search;
 
 // Re-export main types
+pub use bm25::BM25Scorer;
 pub use embeddings::{cosine_similarity, EmbeddingGenerator};
 pub use error::{Result, SemanticError};
 pub use index::VectorIndex;
-pub use models::{EmbeddingEntry, IndexStats, SemanticConfig, SemanticSearchResult};
-pub use preprocessing::{preprocess_query, preprocess_repository};
+pub use models::{
+    EmbeddingEntry, EmbeddingModel, FieldWeights, IndexStats, MatchSource, SemanticConfig,
+    SemanticSearchResult,
+};
+pub use preprocessing::{matched_terms, preprocess_query, preprocess_repository};
 pub use search::SemanticSearchEngine;
 
 #[cfg(test)]
@@ -1,6 +1,9 @@
+use crate::error::{Result, SemanticError};
 use chrono::{DateTime, Utc};
 use reposcout_core::models::Repository;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::str::FromStr;
 
 /// Embedding entry for a repository
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,6 +74,28 @@ pub struct SemanticSearchResult {
 
     /// Distance in vector space (lower is better)
     pub distance: f32,
+
+    /// Query terms and phrases found in the indexed text, strongest first
+    pub matched_terms: Vec<String>,
+
+    /// Which score contributed more to a hybrid result
+    pub dominant: Option<MatchSource>,
+}
+
+/// The half of a hybrid score that carried a result
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchSource {
+    Semantic,
+    Keyword,
+}
+
+impl MatchSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            MatchSource::Semantic => "semantic",
+            MatchSource::Keyword => "keyword",
+        }
+    }
 }
 
 impl SemanticSearchResult {
@@ -82,6 +107,8 @@ impl SemanticSearchResult {
             keyword_score: None,
             hybrid_score: semantic_score,
             distance,
+            matched_terms: Vec::new(),
+            dominant: None,
         }
     }
 
@@ -93,15 +120,22 @@ impl SemanticSearchResult {
         semantic_weight: f32,
         distance: f32,
     ) -> Self {
-        let hybrid_score =
-            (semantic_score * semantic_weight) + (keyword_score * (1.0 - semantic_weight));
+        let semantic_part = semantic_score * semantic_weight;
+        let keyword_part = keyword_score * (1.0 - semantic_weight);
+        let dominant = if keyword_part > semantic_part {
+            MatchSource::Keyword
+        } else {
+            MatchSource::Semantic
+        };
 
         Self {
             repository,
             semantic_score,
             keyword_score: Some(keyword_score),
-            hybrid_score,
+            hybrid_score: semantic_part + keyword_part,
             distance,
+            matched_terms: Vec::new(),
+            dominant: Some(dominant),
         }
     }
 }
@@ -126,6 +160,14 @@ pub struct IndexStats {
 
     /// Index creation time
     pub created_at: DateTime<Utc>,
+
+    /// Repositories embedded by `index_repositories` (new or changed text)
+    #[serde(default)]
+    pub newly_embedded: usize,
+
+    /// Repositories `index_repositories` skipped because their text was unchanged
+    #[serde(default)]
+    pub skipped_unchanged: usize,
 }
 
 impl IndexStats {
@@ -138,6 +180,8 @@ impl IndexStats {
             model_name,
             dimension,
             created_at: Utc::now(),
+            newly_embedded: 0,
+            skipped_unchanged: 0,
         }
     }
 
@@ -147,6 +191,108 @@ impl IndexStats {
         self.index_size_bytes = size_bytes;
         self.last_updated = Utc::now();
     }
+
+    /// Count the outcome of one batch indexing run
+    pub fn record_indexing(&mut self, embedded: usize, skipped: usize) {
+        self.newly_embedded += embedded;
+        self.skipped_unchanged += skipped;
+    }
+}
+
+/// Embedding model used to build and query the semantic index.
+///
+/// Indexes are only valid for the model (and dimension) they were built
+/// with, so changing this requires rebuilding the index.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum EmbeddingModel {
+    /// sentence-transformers/all-MiniLM-L6-v2 (384 dimensions)
+    #[default]
+    MiniLmL6,
+    /// BAAI/bge-small-en-v1.5 (384 dimensions)
+    BgeSmall,
+    /// BAAI/bge-base-en-v1.5 (768 dimensions)
+    BgeBase,
+    /// Local directory with `model.onnx` and Hugging Face tokenizer files
+    Custom(PathBuf),
+}
+
+impl EmbeddingModel {
+    /// Name recorded in the index stats
+    pub fn name(&self) -> String {
+        match self {
+            EmbeddingModel::MiniLmL6 => "sentence-transformers/all-MiniLM-L6-v2".to_string(),
+            EmbeddingModel::BgeSmall => "BAAI/bge-small-en-v1.5".to_string(),
+            EmbeddingModel::BgeBase => "BAAI/bge-base-en-v1.5".to_string(),
+            EmbeddingModel::Custom(path) => format!("custom:{}", path.display()),
+        }
+    }
+
+    /// Vector dimension produced by the model.
+    ///
+    /// Custom models report it as `hidden_size` in their `config.json`;
+    /// without one the dimension is unknown, which is an error.
+    pub fn dimension(&self) -> Result<usize> {
+        match self {
+            EmbeddingModel::MiniLmL6 | EmbeddingModel::BgeSmall => Ok(384),
+            EmbeddingModel::BgeBase => Ok(768),
+            EmbeddingModel::Custom(path) => {
+                let config_file = path.join("config.json");
+                let unreadable = |reason: String| {
+                    SemanticError::ModelLoadError(format!(
+                        "Cannot read hidden_size from {}: {}",
+                        config_file.display(),
+                        reason
+                    ))
+                };
+                let config =
+                    std::fs::read_to_string(&config_file).map_err(|e| unreadable(e.to_string()))?;
+                let config: serde_json::Value =
+                    serde_json::from_str(&config).map_err(|e| unreadable(e.to_string()))?;
+                config["hidden_size"]
+                    .as_u64()
+                    .map(|size| size as usize)
+                    .ok_or_else(|| unreadable("missing or not a number".to_string()))
+            }
+        }
+    }
+}
+
+impl FromStr for EmbeddingModel {
+    type Err = String;
+
+    /// Accepts short names (`minilm`, `bge-small`, `bge-base`), the full
+    /// model names, or `custom:<dir>` for a local model
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim() {
+            "minilm" | "minilm-l6" | "sentence-transformers/all-MiniLM-L6-v2" => {
+                Ok(EmbeddingModel::MiniLmL6)
+            }
+            "bge-small" | "BAAI/bge-small-en-v1.5" => Ok(EmbeddingModel::BgeSmall),
+            "bge-base" | "BAAI/bge-base-en-v1.5" => Ok(EmbeddingModel::BgeBase),
+            other => match other.strip_prefix("custom:") {
+                Some(path) if !path.is_empty() => Ok(EmbeddingModel::Custom(PathBuf::from(path))),
+                _ => Err(format!(
+                    "Unknown embedding model '{}'. Use minilm, bge-small, bge-base, or custom:<dir>",
+                    other
+                )),
+            },
+        }
+    }
+}
+
+impl TryFrom<String> for EmbeddingModel {
+    type Error = String;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<EmbeddingModel> for String {
+    fn from(model: EmbeddingModel) -> Self {
+        model.name()
+    }
 }
 
 /// Configuration for semantic search
@@ -157,8 +303,8 @@ pub struct SemanticConfig {
     pub enabled: bool,
 
     /// Embedding model to use
-    #[serde(default = "default_model")]
-    pub model: String,
+    #[serde(default)]
+    pub model: EmbeddingModel,
 
     /// Auto-build index on startup
     #[serde(default = "default_auto_build")]
@@ -183,19 +329,60 @@ pub struct SemanticConfig {
     /// Maximum cache size in MB
     #[serde(default = "default_max_cache_size")]
     pub max_cache_size_mb: usize,
+
+    /// How much each repository field counts when scoring keyword results
+    #[serde(default)]
+    pub field_weights: FieldWeights,
+}
+
+/// Keyword match multipliers per repository field, relative to the description
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FieldWeights {
+    /// owner/name
+    pub name: f32,
+    pub topics: f32,
+    pub description: f32,
+}
+
+impl Default for FieldWeights {
+    fn default() -> Self {
+        Self {
+            name: 3.0,
+            topics: 2.0,
+            description: 1.0,
+        }
+    }
+}
+
+impl SemanticConfig {
+    /// Model the index under `cache_path` was built with, if there is one
+    pub fn index_model(&self) -> Option<EmbeddingModel> {
+        let stats = crate::index::VectorIndex::read_stats(std::path::Path::new(&self.cache_path))?;
+        stats.model_name.parse().ok()
+    }
+
+    /// Use `model` when one is asked for, otherwise whatever the existing
+    /// index was built with, so an index built with `--model` keeps
+    /// working without repeating it
+    pub fn with_model(mut self, model: Option<EmbeddingModel>) -> Self {
+        self.model = model.or_else(|| self.index_model()).unwrap_or_default();
+        self
+    }
 }
 
 impl Default for SemanticConfig {
     fn default() -> Self {
         Self {
             enabled: default_enabled(),
-            model: default_model(),
+            model: EmbeddingModel::default(),
             index_auto_build: default_auto_build(),
             semantic_weight: default_semantic_weight(),
             min_similarity: default_min_similarity(),
             max_results: default_max_results(),
             cache_path: default_cache_path(),
             max_cache_size_mb: default_max_cache_size(),
+            field_weights: FieldWeights::default(),
         }
     }
 }
@@ -205,10 +392,6 @@ fn default_enabled() -> bool {
     true
 }
 
-fn default_model() -> String {
-    "sentence-transformers/all-MiniLM-L6-v2".to_string()
-}
-
 fn default_auto_build() -> bool {
     true
 }
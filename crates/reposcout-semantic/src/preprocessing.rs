@@ -4,6 +4,12 @@ use reposcout_core::models::Repository;
 /// Maximum tokens to use for embedding (BERT limit)
 const MAX_TOKENS: usize = 512;
 
+/// Query words too common to explain a match
+pub(crate) const STOPWORDS: &[&str] = &[
+    "and", "for", "the", "with", "that", "this", "from", "into", "using", "use", "like", "tool",
+    "library", "app", "written", "based", "can", "are", "how", "what", "which",
+];
+
 /// Preprocess repository data into text suitable for embedding
 pub fn preprocess_repository(repo: &Repository, readme: Option<&str>) -> String {
     let mut parts = Vec::new();
@@ -51,6 +57,61 @@ pub fn preprocess_query(query: &str) -> String {
     truncate_to_tokens(&cleaned, MAX_TOKENS)
 }
 
+/// Query terms and phrases that appear in a repository's indexed text
+///
+/// Embeddings can't say why two texts are close, so this approximates it by
+/// term overlap: adjacent query words found together in `source_text` are
+/// reported as one phrase, then the remaining words that occur in it (also as
+/// a prefix, so "log" matches "logging"), most frequent first.
+pub fn matched_terms(query: &str, source_text: &str, limit: usize) -> Vec<String> {
+    let query = preprocess_query(query);
+    let terms: Vec<&str> = query
+        .split_whitespace()
+        .filter(|term| term.len() >= 3 && !STOPWORDS.contains(term))
+        .collect();
+    let source = source_text.to_lowercase();
+    let words: Vec<&str> = source.split_whitespace().collect();
+
+    let occurrences = |term: &str| {
+        words
+            .iter()
+            .filter(|word| word.starts_with(term) || (word.len() >= 4 && term.starts_with(**word)))
+            .count()
+    };
+
+    let mut phrases = Vec::new();
+    let mut in_phrase = vec![false; terms.len()];
+    for (i, pair) in terms.windows(2).enumerate() {
+        if in_phrase[i] {
+            continue;
+        }
+        if words.windows(2).any(|w| w[0] == pair[0] && w[1] == pair[1]) {
+            phrases.push(format!("{} {}", pair[0], pair[1]));
+            in_phrase[i] = true;
+            in_phrase[i + 1] = true;
+        }
+    }
+
+    let mut singles: Vec<(&str, usize)> = terms
+        .iter()
+        .zip(&in_phrase)
+        .filter(|(_, used)| !**used)
+        .map(|(term, _)| (*term, occurrences(term)))
+        .filter(|(_, count)| *count > 0)
+        .collect();
+    // Stable, so equally frequent terms keep the query's order
+    singles.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+    let mut matched = phrases;
+    for (term, _) in singles {
+        if !matched.iter().any(|m| m.split(' ').any(|w| w == term)) {
+            matched.push(term.to_string());
+        }
+    }
+    matched.truncate(limit);
+    matched
+}
+
 /// Clean text by removing special characters and normalizing whitespace
 fn clean_text(text: &str) -> String {
     // Remove URLs
@@ -179,6 +240,25 @@ More information here.
         assert!(!excerpt.contains('#'));
     }
 
+    #[test]
+    fn test_matched_terms() {
+        let source = "acme/tracer go distributed tracing and logging for microservices \
+                      structured logging with log levels";
+        assert_eq!(
+            matched_terms("Distributed tracing for the log pipeline!", source, 5),
+            vec!["distributed tracing", "log"]
+        );
+        assert_eq!(
+            matched_terms("logging microservices tracing kafka", source, 5),
+            vec!["logging", "microservices", "tracing"]
+        );
+        assert_eq!(
+            matched_terms("logging microservices tracing", source, 2),
+            vec!["logging", "microservices"]
+        );
+        assert!(matched_terms("the and", source, 5).is_empty());
+    }
+
     #[test]
     fn test_calculate_text_similarity() {
         let text1 = "rust web framework";
@@ -1,7 +1,9 @@
+use crate::bm25::BM25Scorer;
 use crate::embeddings::EmbeddingGenerator;
-use crate::error::Result;
+use crate::error::{Result, SemanticError};
 use crate::index::VectorIndex;
 use crate::models::{IndexStats, SemanticConfig, SemanticSearchResult};
+use crate::preprocessing::{matched_terms, preprocess_repository};
 use reposcout_core::models::Repository;
 use std::collections::HashMap;
 use std::path::PathBuf;
@@ -22,24 +24,43 @@ pub struct SemanticSearchEngine {
 
     /// Repository cache for quick lookup
     repo_cache: Arc<RwLock<HashMap<String, Repository>>>,
+
+    /// Stats of a persisted index built with a different model. It is left
+    /// on disk untouched until the index is cleared and rebuilt.
+    mismatched_index: Arc<RwLock<Option<IndexStats>>>,
 }
 
 impl SemanticSearchEngine {
     /// Create a new semantic search engine
     pub fn new(config: SemanticConfig) -> Result<Self> {
-        let embedder = Arc::new(EmbeddingGenerator::new(config.model.clone()));
+        let embedder = Arc::new(EmbeddingGenerator::new(config.model.clone())?);
+        let model_name = config.model.name();
 
         let index_path = PathBuf::from(&config.cache_path);
 
-        // Try to load existing index, or create new one
-        let index = match VectorIndex::load(index_path.clone(), embedder.dimension()) {
-            Ok(idx) => {
-                info!("Loaded existing semantic index");
-                idx
-            }
-            Err(e) => {
-                warn!("Could not load existing index: {}. Creating new one.", e);
-                VectorIndex::new(embedder.dimension(), config.model.clone(), index_path)?
+        // Vectors from another model are meaningless to this one, so don't
+        // load an index built with a different model or dimension
+        let mismatched_index = VectorIndex::read_stats(&index_path).filter(|stats| {
+            stats.model_name != model_name || stats.dimension != embedder.dimension()
+        });
+
+        let index = if let Some(stats) = &mismatched_index {
+            warn!(
+                "Semantic index was built with {} but {} is configured",
+                stats.model_name, model_name
+            );
+            VectorIndex::new(embedder.dimension(), model_name, index_path)?
+        } else {
+            // Try to load existing index, or create new one
+            match VectorIndex::load(index_path.clone(), embedder.dimension()) {
+                Ok(idx) => {
+                    info!("Loaded existing semantic index");
+                    idx
+                }
+                Err(e) => {
+                    warn!("Could not load existing index: {}. Creating new one.", e);
+                    VectorIndex::new(embedder.dimension(), model_name, index_path)?
+                }
             }
         };
 
@@ -48,14 +69,31 @@ impl SemanticSearchEngine {
             index: Arc::new(RwLock::new(index)),
             config,
             repo_cache: Arc::new(RwLock::new(HashMap::new())),
+            mismatched_index: Arc::new(RwLock::new(mismatched_index)),
         })
     }
 
-    /// Initialize the embedding model
+    /// Initialize the embedding model.
+    ///
+    /// Fails if the persisted index was built with a different model; it
+    /// has to be cleared and rebuilt first.
     pub async fn initialize(&self) -> Result<()> {
+        self.check_model_matches().await?;
         self.embedder.initialize().await
     }
 
+    async fn check_model_matches(&self) -> Result<()> {
+        match &*self.mismatched_index.read().await {
+            Some(stats) => Err(SemanticError::ModelMismatch {
+                index_model: stats.model_name.clone(),
+                index_dimension: stats.dimension,
+                configured_model: self.config.model.name(),
+                configured_dimension: self.embedder.dimension(),
+            }),
+            None => Ok(()),
+        }
+    }
+
     /// Index a single repository
     pub async fn index_repository(&self, repo: &Repository, readme: Option<&str>) -> Result<()> {
         debug!("Indexing repository: {}", repo.full_name);
@@ -85,23 +123,42 @@ impl SemanticSearchEngine {
 
         info!("Indexing {} repositories...", repos.len());
 
-        // Prepare for batch embedding
+        // Only embed repositories whose preprocessed text changed since they
+        // were last indexed; re-embedding identical text gives the same vector
         debug!("Preparing repository references for embedding");
-        let repo_refs: Vec<(&Repository, Option<&str>)> = repos
-            .iter()
-            .map(|(repo, readme)| (repo, readme.as_deref()))
-            .collect();
-        debug!("Prepared {} repository references", repo_refs.len());
+        let repo_refs: Vec<(&Repository, Option<&str>)> = {
+            let index = self.index.read().await;
+            repos
+                .iter()
+                .map(|(repo, readme)| (repo, readme.as_deref()))
+                .filter(|(repo, readme)| {
+                    let repo_id = format!("{}:{}", repo.platform, repo.full_name);
+                    !index.is_unchanged(&repo_id, &preprocess_repository(repo, *readme))
+                })
+                .collect()
+        };
+        let skipped = repos.len() - repo_refs.len();
+        debug!(
+            "Prepared {} repository references ({} unchanged)",
+            repo_refs.len(),
+            skipped
+        );
 
         // Generate embeddings in batch
-        info!("Generating embeddings for {} repositories", repo_refs.len());
-        let entries = self.embedder.embed_repositories(repo_refs).await?;
+        let entries = if repo_refs.is_empty() {
+            Vec::new()
+        } else {
+            info!("Generating embeddings for {} repositories", repo_refs.len());
+            self.embedder.embed_repositories(repo_refs).await?
+        };
         info!("Generated {} embeddings", entries.len());
 
         // Add to index
         info!("Adding {} entries to vector index", entries.len());
         let mut index = self.index.write().await;
+        let embedded = entries.len();
         index.add_batch(entries)?;
+        index.record_indexing(embedded, skipped);
         info!("Added entries to index successfully");
 
         // Cache repositories
@@ -116,6 +173,18 @@ impl SemanticSearchEngine {
         Ok(repos.len())
     }
 
+    /// Make repositories available for result lookup without embedding them.
+    ///
+    /// A persisted index only stores vectors, so after loading one the
+    /// repository data has to be supplied again (e.g. from the local cache).
+    pub async fn register_repositories(&self, repos: impl IntoIterator<Item = Repository>) {
+        let mut cache = self.repo_cache.write().await;
+        for repo in repos {
+            let repo_id = format!("{}:{}", repo.platform, repo.full_name);
+            cache.insert(repo_id, repo);
+        }
+    }
+
     /// Perform semantic search
     pub async fn search(&self, query: &str, limit: usize) -> Result<Vec<SemanticSearchResult>> {
         debug!("Semantic search query: {}", query);
@@ -142,11 +211,10 @@ impl SemanticSearchEngine {
         for (repo_id, similarity) in filtered_results {
             if let Some(repo) = cache.get(&repo_id) {
                 let distance = 1.0 - similarity;
-                results.push(SemanticSearchResult::semantic_only(
-                    repo.clone(),
-                    similarity,
-                    distance,
-                ));
+                let mut result =
+                    SemanticSearchResult::semantic_only(repo.clone(), similarity, distance);
+                result.matched_terms = explain_match(&index, &repo_id, query);
+                results.push(result);
             } else {
                 warn!("Repository {} not found in cache", repo_id);
             }
@@ -165,6 +233,72 @@ impl SemanticSearchEngine {
         Ok(results)
     }
 
+    /// Indexed repositories most similar to `repo`, best first, leaving out
+    /// `repo` itself. `repo` doesn't have to be indexed.
+    ///
+    /// Fails with `EmptyIndex` before loading the model when there is
+    /// nothing to compare against.
+    pub async fn find_similar(
+        &self,
+        repo: &Repository,
+        readme: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<SemanticSearchResult>> {
+        if self.index.read().await.is_empty() {
+            return Err(SemanticError::EmptyIndex);
+        }
+
+        let source_text = preprocess_repository(repo, readme);
+        if source_text.is_empty() {
+            return Err(SemanticError::PreprocessingError(
+                "No text content to embed".to_string(),
+            ));
+        }
+        let vector = self.embedder.embed_text(&source_text).await?;
+
+        // One extra neighbor, as the repository finds itself when indexed
+        let repo_id = format!("{}:{}", repo.platform, repo.full_name);
+        let index = self.index.read().await;
+        let cache = self.repo_cache.read().await;
+        let mut results: Vec<SemanticSearchResult> = index
+            .search(&vector, limit + 1)?
+            .into_iter()
+            .filter(|(id, similarity)| *id != repo_id && *similarity >= self.config.min_similarity)
+            .filter_map(|(id, similarity)| {
+                let Some(similar) = cache.get(&id) else {
+                    warn!("Repository {} not found in cache", id);
+                    return None;
+                };
+                let mut result = SemanticSearchResult::semantic_only(
+                    similar.clone(),
+                    similarity,
+                    1.0 - similarity,
+                );
+                result.matched_terms = explain_match(&index, &id, &source_text);
+                Some(result)
+            })
+            .collect();
+
+        results.sort_by(|a, b| {
+            b.semantic_score
+                .partial_cmp(&a.semantic_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        results.truncate(limit);
+        Ok(results)
+    }
+
+    /// Score keyword search results for `hybrid_search` with field-weighted
+    /// BM25, so name and topic matches count more than description ones
+    pub fn score_keyword_results(
+        &self,
+        query: &str,
+        repos: Vec<Repository>,
+    ) -> Vec<(Repository, f32)> {
+        let scores = BM25Scorer::new(self.config.field_weights).score(query, &repos);
+        repos.into_iter().zip(scores).collect()
+    }
+
     /// Perform hybrid search (combining semantic and keyword scores)
     pub async fn hybrid_search(
         &self,
@@ -225,6 +359,7 @@ impl SemanticSearchEngine {
 
         let mut hybrid_results = Vec::new();
         let cache = self.repo_cache.read().await;
+        let index = self.index.read().await;
 
         for repo_id in all_repo_ids {
             if let Some(repo) = cache.get(&repo_id) {
@@ -234,13 +369,14 @@ impl SemanticSearchEngine {
                 // Calculate distance (for semantic-only results)
                 let distance = 1.0 - semantic_score;
 
-                let result = SemanticSearchResult::hybrid(
+                let mut result = SemanticSearchResult::hybrid(
                     repo.clone(),
                     semantic_score,
                     keyword_score,
                     self.config.semantic_weight,
                     distance,
                 );
+                result.matched_terms = explain_match(&index, &repo_id, query);
 
                 hybrid_results.push(result);
             }
@@ -291,6 +427,8 @@ impl SemanticSearchEngine {
 
     /// Save the index to disk
     pub async fn save(&self) -> Result<()> {
+        // Never overwrite an index built with another model unless cleared
+        self.check_model_matches().await?;
         let mut index = self.index.write().await;
         index.save()
     }
@@ -301,6 +439,7 @@ impl SemanticSearchEngine {
         index.clear()?;
 
         self.repo_cache.write().await.clear();
+        *self.mismatched_index.write().await = None;
 
         Ok(())
     }
@@ -324,34 +463,36 @@ impl SemanticSearchEngine {
     }
 }
 
+/// How many matched terms a result lists
+const MAX_MATCHED_TERMS: usize = 5;
+
+/// Query terms found in the text `repo_id` was embedded from
+fn explain_match(index: &VectorIndex, repo_id: &str, query: &str) -> Vec<String> {
+    index
+        .get_metadata(repo_id)
+        .map(|entry| matched_terms(query, &entry.source_text, MAX_MATCHED_TERMS))
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::EmbeddingModel;
     use reposcout_core::models::Platform;
     use tempfile::TempDir;
 
     fn create_test_repo(name: &str, description: &str) -> Repository {
         Repository {
-            platform: Platform::GitHub,
-            full_name: name.to_string(),
             description: Some(description.to_string()),
             url: format!("https://github.com/{}", name),
-            homepage_url: None,
-            stars: 100,
             forks: 10,
             watchers: 50,
             open_issues: 5,
             language: Some("Rust".to_string()),
             topics: vec!["rust".to_string(), "cli".to_string()],
             license: Some("MIT".to_string()),
-            created_at: chrono::Utc::now(),
-            updated_at: chrono::Utc::now(),
-            pushed_at: chrono::Utc::now(),
             size: 1024,
-            default_branch: "main".to_string(),
-            is_archived: false,
-            is_private: false,
-            health: None,
+            ..Repository::test_fixture(Platform::GitHub, name, 100)
         }
     }
 
@@ -381,4 +522,127 @@ mod tests {
         assert!(!results.is_empty());
         assert_eq!(results[0].repository.full_name, "user/logging-lib");
     }
+
+    #[tokio::test]
+    async fn test_model_mismatch_requires_rebuild() {
+        let temp_dir = TempDir::new().unwrap();
+        let stats = IndexStats::new("BAAI/bge-base-en-v1.5".to_string(), 768);
+        std::fs::write(
+            temp_dir.path().join("stats.json"),
+            serde_json::to_string(&stats).unwrap(),
+        )
+        .unwrap();
+
+        let config = SemanticConfig {
+            cache_path: temp_dir.path().to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        let engine = SemanticSearchEngine::new(config).unwrap();
+
+        let err = engine.initialize().await.unwrap_err();
+        assert!(matches!(
+            err,
+            SemanticError::ModelMismatch {
+                index_dimension: 768,
+                ..
+            }
+        ));
+        assert!(err.to_string().contains("rebuild --force"));
+        assert!(engine.save().await.is_err());
+
+        // Clearing starts over with the configured model
+        engine.clear().await.unwrap();
+        assert!(engine.check_model_matches().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_config_follows_the_index_model() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = SemanticConfig {
+            cache_path: temp_dir.path().to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        // No index yet: the default model
+        assert_eq!(
+            config.clone().with_model(None).model,
+            EmbeddingModel::MiniLmL6
+        );
+
+        let stats = IndexStats::new("BAAI/bge-base-en-v1.5".to_string(), 768);
+        std::fs::write(
+            temp_dir.path().join("stats.json"),
+            serde_json::to_string(&stats).unwrap(),
+        )
+        .unwrap();
+        let config = config.with_model(None);
+        assert_eq!(config.model, EmbeddingModel::BgeBase);
+        let engine = SemanticSearchEngine::new(config.clone()).unwrap();
+        assert!(engine.check_model_matches().await.is_ok());
+        assert_eq!(engine.stats().await.dimension, 768);
+
+        // An explicit model still wins
+        let config = config.with_model(Some(EmbeddingModel::BgeSmall));
+        assert_eq!(config.model, EmbeddingModel::BgeSmall);
+    }
+
+    #[test]
+    fn test_custom_model_needs_its_dimension() {
+        let temp_dir = TempDir::new().unwrap();
+        let model = EmbeddingModel::Custom(temp_dir.path().to_path_buf());
+        let err = model.dimension().unwrap_err();
+        assert!(err.to_string().contains("config.json"));
+
+        std::fs::write(
+            temp_dir.path().join("config.json"),
+            r#"{"hidden_size": 512}"#,
+        )
+        .unwrap();
+        assert_eq!(model.dimension().unwrap(), 512);
+    }
+
+    #[tokio::test]
+    async fn test_find_similar_needs_an_index() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = SemanticConfig {
+            cache_path: temp_dir.path().to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        let engine = SemanticSearchEngine::new(config).unwrap();
+
+        let repo = create_test_repo("user/logging-lib", "A logging library for Rust");
+        let err = engine.find_similar(&repo, None, 10).await.unwrap_err();
+        assert!(matches!(err, SemanticError::EmptyIndex));
+        assert!(err.to_string().contains("semantic-index rebuild"));
+    }
+
+    #[tokio::test]
+    async fn test_reindexing_unchanged_repo_skips_embedding() {
+        let temp_dir = TempDir::new().unwrap();
+        let config = SemanticConfig {
+            cache_path: temp_dir.path().to_string_lossy().to_string(),
+            ..Default::default()
+        };
+        let engine = SemanticSearchEngine::new(config).unwrap();
+        let repo = create_test_repo("user/logging-lib", "A logging library for Rust");
+
+        // Stand in for a previous indexing run, so no model is needed
+        let entry = crate::models::EmbeddingEntry::new(
+            "GitHub:user/logging-lib".to_string(),
+            vec![0.5; engine.embedder.dimension()],
+            preprocess_repository(&repo, None),
+        );
+        engine.index.write().await.add(entry).unwrap();
+
+        // The model was never initialized, so any embedding would have to load it
+        let indexed = engine
+            .index_repositories(vec![(repo.clone(), None), (repo, None)])
+            .await
+            .unwrap();
+        assert_eq!(indexed, 2);
+
+        let stats = engine.stats().await;
+        assert_eq!(stats.newly_embedded, 0);
+        assert_eq!(stats.skipped_unchanged, 2);
+        assert!(engine.is_indexed("GitHub:user/logging-lib").await);
+    }
 }
@@ -1,35 +1,27 @@
 use reposcout_core::models::{Platform, Repository};
-use reposcout_semantic::{EmbeddingGenerator, SemanticConfig, SemanticSearchEngine, VectorIndex};
+use reposcout_semantic::{
+    EmbeddingGenerator, EmbeddingModel, SemanticConfig, SemanticSearchEngine, VectorIndex,
+};
 use tempfile::TempDir;
 
 fn create_test_repo(name: &str, description: &str, language: &str) -> Repository {
     Repository {
-        platform: Platform::GitHub,
-        full_name: name.to_string(),
         description: Some(description.to_string()),
         url: format!("https://github.com/{}", name),
-        homepage_url: None,
-        stars: 100,
         forks: 10,
         watchers: 50,
         open_issues: 5,
         language: Some(language.to_string()),
         topics: vec!["test".to_string()],
         license: Some("MIT".to_string()),
-        created_at: chrono::Utc::now(),
-        updated_at: chrono::Utc::now(),
-        pushed_at: chrono::Utc::now(),
         size: 1024,
-        default_branch: "main".to_string(),
-        is_archived: false,
-        is_private: false,
-        health: None,
+        ..Repository::test_fixture(Platform::GitHub, name, 100)
     }
 }
 
 #[tokio::test]
 async fn test_embedding_generator_initialization() {
-    let generator = EmbeddingGenerator::new("sentence-transformers/all-MiniLM-L6-v2".to_string());
+    let generator = EmbeddingGenerator::new(EmbeddingModel::MiniLmL6).unwrap();
     assert_eq!(generator.dimension(), 384);
 
     // Initialize the model
@@ -39,7 +31,7 @@ async fn test_embedding_generator_initialization() {
 
 #[tokio::test]
 async fn test_embed_text() {
-    let generator = EmbeddingGenerator::new("sentence-transformers/all-MiniLM-L6-v2".to_string());
+    let generator = EmbeddingGenerator::new(EmbeddingModel::MiniLmL6).unwrap();
     generator.initialize().await.unwrap();
 
     let text = "This is a test sentence for embedding generation";
@@ -52,7 +44,7 @@ async fn test_embed_text() {
 
 #[tokio::test]
 async fn test_embed_repository() {
-    let generator = EmbeddingGenerator::new("sentence-transformers/all-MiniLM-L6-v2".to_string());
+    let generator = EmbeddingGenerator::new(EmbeddingModel::MiniLmL6).unwrap();
     generator.initialize().await.unwrap();
 
     let repo = create_test_repo(
@@ -71,7 +63,7 @@ async fn test_embed_repository() {
 
 #[tokio::test]
 async fn test_embed_batch() {
-    let generator = EmbeddingGenerator::new("sentence-transformers/all-MiniLM-L6-v2".to_string());
+    let generator = EmbeddingGenerator::new(EmbeddingModel::MiniLmL6).unwrap();
     generator.initialize().await.unwrap();
 
     let texts = vec![
@@ -95,7 +87,7 @@ async fn test_vector_index_basic() {
 
     let mut index = VectorIndex::new(384, "test-model".to_string(), index_path.clone()).unwrap();
 
-    let generator = EmbeddingGenerator::new("sentence-transformers/all-MiniLM-L6-v2".to_string());
+    let generator = EmbeddingGenerator::new(EmbeddingModel::MiniLmL6).unwrap();
     generator.initialize().await.unwrap();
 
     // Create and add some test repositories
@@ -120,7 +112,7 @@ async fn test_vector_search() {
 
     let mut index = VectorIndex::new(384, "test-model".to_string(), index_path.clone()).unwrap();
 
-    let generator = EmbeddingGenerator::new("sentence-transformers/all-MiniLM-L6-v2".to_string());
+    let generator = EmbeddingGenerator::new(EmbeddingModel::MiniLmL6).unwrap();
     generator.initialize().await.unwrap();
 
     // Create test repositories
@@ -156,7 +148,7 @@ async fn test_index_persistence() {
     let temp_dir = TempDir::new().unwrap();
     let index_path = temp_dir.path().to_path_buf();
 
-    let generator = EmbeddingGenerator::new("sentence-transformers/all-MiniLM-L6-v2".to_string());
+    let generator = EmbeddingGenerator::new(EmbeddingModel::MiniLmL6).unwrap();
     generator.initialize().await.unwrap();
 
     // Create and save index
@@ -273,7 +265,7 @@ async fn test_index_update() {
     let temp_dir = TempDir::new().unwrap();
     let index_path = temp_dir.path().to_path_buf();
 
-    let generator = EmbeddingGenerator::new("sentence-transformers/all-MiniLM-L6-v2".to_string());
+    let generator = EmbeddingGenerator::new(EmbeddingModel::MiniLmL6).unwrap();
     generator.initialize().await.unwrap();
 
     let mut index = VectorIndex::new(384, "test-model".to_string(), index_path.clone()).unwrap();
@@ -307,7 +299,7 @@ async fn test_index_removal() {
     let temp_dir = TempDir::new().unwrap();
     let index_path = temp_dir.path().to_path_buf();
 
-    let generator = EmbeddingGenerator::new("sentence-transformers/all-MiniLM-L6-v2".to_string());
+    let generator = EmbeddingGenerator::new(EmbeddingModel::MiniLmL6).unwrap();
     generator.initialize().await.unwrap();
 
     let mut index = VectorIndex::new(384, "test-model".to_string(), index_path.clone()).unwrap();
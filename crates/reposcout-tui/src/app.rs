@@ -1,7 +1,12 @@
 // TUI application state and event handling
+use crate::command::Command;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
 use ratatui::widgets::ListState;
+use reposcout_api::RateLimitState;
 use reposcout_cache::SearchHistoryEntry;
-use reposcout_core::models::{CodeSearchResult, Repository};
+use reposcout_core::models::{CodeSearchResult, Platform, Repository};
+use reposcout_core::GroupBy;
 use reposcout_deps::DependencyInfo;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,17 +30,85 @@ pub enum InputMode {
     HistoryPopup,  // Browsing search history
     Settings,      // Settings/token management popup
     TokenInput,    // Entering API token
+    BookmarkEdit,  // Editing a bookmark's tags or notes
+    Command,       // Typing a `:` command
+}
+
+/// Which bookmark field the edit popup is changing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookmarkField {
+    Tags,
+    Notes,
+}
+
+impl BookmarkField {
+    pub fn label(&self) -> &'static str {
+        match self {
+            BookmarkField::Tags => "Tags",
+            BookmarkField::Notes => "Notes",
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PreviewMode {
     Stats,        // Show repository statistics
     Readme,       // Show README content
+    Changelog,    // Show CHANGELOG or latest release notes
     Activity,     // Show repository activity/commits
+    Languages,    // Show per-language code breakdown
     Dependencies, // Show dependency analysis
     Package,      // Show package manager info and install commands
 }
 
+/// Pause after the last keystroke before a live search runs
+pub const LIVE_SEARCH_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(400);
+
+/// Smallest terminal (width, height) the layout is drawn in by default
+pub const MIN_TERMINAL_SIZE: (u16, u16) = (40, 10);
+
+/// A row of the results list: a group header or a result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResultRow {
+    Header {
+        name: String,
+        count: usize,
+    },
+    /// Index into `App::results`
+    Result(usize),
+}
+
+/// Repository field that produced a fuzzy filter match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuzzyField {
+    Name,
+    Description,
+    Language,
+    Topics,
+    /// Only matched across several fields together
+    Combined,
+}
+
+impl FuzzyField {
+    /// Short tag shown next to a result
+    pub fn label(&self) -> &'static str {
+        match self {
+            FuzzyField::Name => "name",
+            FuzzyField::Description => "desc",
+            FuzzyField::Language => "lang",
+            FuzzyField::Topics => "topic",
+            FuzzyField::Combined => "multi",
+        }
+    }
+
+    fn weight(&self) -> i64 {
+        match self {
+            FuzzyField::Name => 2,
+            _ => 1,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CodePreviewMode {
     Code,     // Show highlighted code with context
@@ -50,6 +123,12 @@ pub struct SearchFilters {
     pub max_stars: Option<u32>,
     pub pushed: Option<String>,
     pub sort_by: String,
+    /// Lowest health score shown; repositories without a score stay
+    pub min_health: Option<u8>,
+    pub exclude_forks: bool,
+    pub exclude_archived: bool,
+    /// Leave out repositories marked as reviewed
+    pub hide_reviewed: bool,
 }
 
 impl Default for SearchFilters {
@@ -60,41 +139,55 @@ impl Default for SearchFilters {
             max_stars: None,
             pushed: None,
             sort_by: "stars".to_string(),
+            min_health: None,
+            exclude_forks: false,
+            exclude_archived: false,
+            hide_reviewed: false,
         }
     }
 }
 
 impl SearchFilters {
+    /// The query with these filters added as search qualifiers. GitHub
+    /// applies fork/archived server-side, other platforms go through
+    /// retain_matching.
     pub fn build_query(&self, base_query: &str) -> String {
-        let mut parts = vec![base_query.to_string()];
-
-        if let Some(lang) = &self.language {
-            if !lang.is_empty() {
-                parts.push(format!("language:{}", lang));
-            }
-        }
-
-        match (self.min_stars, self.max_stars) {
-            (Some(min), Some(max)) => parts.push(format!("stars:{}..{}", min, max)),
-            (Some(min), None) => parts.push(format!("stars:>={}", min)),
-            (None, Some(max)) => parts.push(format!("stars:<={}", max)),
-            (None, None) => {}
-        }
+        reposcout_core::build_github_query(
+            base_query,
+            &reposcout_core::QueryFilters {
+                language: self.language.clone(),
+                min_stars: self.min_stars,
+                max_stars: self.max_stars,
+                pushed: self.pushed.clone(),
+                exclude_forks: self.exclude_forks,
+                exclude_archived: self.exclude_archived,
+                ..Default::default()
+            },
+        )
+    }
 
-        if let Some(pushed) = &self.pushed {
-            if !pushed.is_empty() {
-                parts.push(format!("pushed:{}", pushed));
-            }
-        }
+    /// Whether a result survives the fork/archived exclusions and health floor
+    pub fn allows(&self, repo: &Repository) -> bool {
+        let unhealthy = self
+            .min_health
+            .is_some_and(|min| repo.health_score().is_some_and(|score| score < min));
+        let excluded = (self.exclude_forks && repo.is_fork)
+            || (self.exclude_archived && repo.is_archived)
+            || unhealthy;
+        !excluded
+    }
 
-        parts.join(" ")
+    /// Drop forks/archived/unhealthy repositories when excluded
+    pub fn retain_matching(&self, results: &mut Vec<Repository>) {
+        results.retain(|repo| self.allows(repo));
     }
 
     pub fn sort_results(&self, results: &mut [Repository]) {
         match self.sort_by.as_str() {
-            "stars" => results.sort_by(|a, b| b.stars.cmp(&a.stars)),
-            "forks" => results.sort_by(|a, b| b.forks.cmp(&a.forks)),
-            "updated" => results.sort_by(|a, b| b.updated_at.cmp(&a.updated_at)),
+            "stars" => results.sort_by_key(|r| std::cmp::Reverse(r.stars)),
+            "forks" => results.sort_by_key(|r| std::cmp::Reverse(r.forks)),
+            "updated" => results.sort_by_key(|r| std::cmp::Reverse(r.updated_at)),
+            "trending-score" => reposcout_core::sort_by_trending_score(results),
             _ => {}
         }
     }
@@ -172,6 +265,8 @@ pub struct TrendingFilters {
     pub min_stars: u32,
     pub topic: Option<String>,
     pub sort_by_velocity: bool,
+    /// Include repos where `language` is at least this % of the code (0 = primary language only)
+    pub min_language_percent: u8,
 }
 
 impl Default for TrendingFilters {
@@ -182,6 +277,7 @@ impl Default for TrendingFilters {
             min_stars: 100,
             topic: None,
             sort_by_velocity: false,
+            min_language_percent: 0,
         }
     }
 }
@@ -191,10 +287,15 @@ pub struct App {
     pub input_mode: InputMode,
     pub search_mode: SearchMode,
     pub search_input: String,
+    /// Text of the last repository search, before filters were added, so
+    /// `r` in the filter panel can re-run it with the current filters
+    pub last_query: String,
     pub results: Vec<Repository>,
     pub selected_index: usize,
     pub scroll_offset: usize,
     pub loading: bool,
+    /// Loading spinner frame, advanced once per redraw while `loading`
+    pub spinner_frame: usize,
     pub error_message: Option<String>,
     pub error_timestamp: Option<std::time::SystemTime>,
     pub filters: SearchFilters,
@@ -207,22 +308,83 @@ pub struct App {
     pub readme_loading: bool,
     // Cache README content per repository to avoid re-fetching
     pub readme_cache: std::collections::HashMap<String, String>,
-    // Scroll position for README view
+    // Scroll position for README and changelog views
     pub readme_scroll: u16,
+    // README footnote typed so far, waiting for a possible second digit
+    pub pending_footnote: Option<(usize, std::time::Instant)>,
+    // Cache changelog content (or release notes) per repository
+    pub changelog_cache: std::collections::HashMap<String, String>,
+    pub changelog_loading: bool,
+    // Language byte breakdowns per (platform, full_name), None when the platform has no data
+    pub languages_cache:
+        std::collections::HashMap<String, Option<std::collections::HashMap<String, u64>>>,
+    pub languages_loading: bool,
+    // Contributor and commit stats per (platform, full_name), once fetched
+    pub activity_cache: std::collections::HashMap<String, crate::tasks::ActivityStats>,
+    pub activity_loading: bool,
+    /// Star counts recorded in the cache, loaded when the Activity tab shows
+    pub star_history_cache: std::collections::HashMap<String, Vec<reposcout_cache::StarSnapshot>>,
+    // Background fetches (README, changelog, dependencies, notifications)
+    pub tasks: crate::tasks::TaskManager,
+    // READMEs fetched ahead for the selection and its neighbours
+    pub readme_prefetch: crate::tasks::ReadmePrefetcher,
     // Track bookmarked repositories (platform + full_name)
     pub bookmarked: std::collections::HashSet<String>,
+    // Repositories already looked at while triaging (platform + full_name)
+    pub reviewed: std::collections::HashSet<String>,
     // Show bookmarks only
     pub show_bookmarks_only: bool,
+    // Full-text filter applied to the bookmarks view (empty shows all)
+    pub bookmark_query: String,
+    // Tags and notes per bookmark key
+    pub bookmark_tags: std::collections::HashMap<String, Vec<String>>,
+    pub bookmark_notes: std::collections::HashMap<String, String>,
+    // Bookmark tag/notes edit popup
+    pub bookmark_edit_field: BookmarkField,
+    pub bookmark_edit_buffer: String,
+    // `:` command line
+    pub command_input: String,
+    /// Most results shown, set with `:limit`
+    pub result_limit: Option<usize>,
+    /// Results shown in sections under header rows
+    pub group_by: Option<GroupBy>,
+    /// Search as you type, toggled with `:live`
+    pub live_search: bool,
+    /// Search box text and when it last changed, while a live search waits
+    live_search_typed: Option<(String, std::time::Instant)>,
+    /// Query text the last live search ran for
+    live_search_query: String,
+    /// Below this (width, height) only a "terminal too small" notice is drawn
+    pub min_terminal_size: (u16, u16),
+    /// The search behind the results can serve another page
+    pub more_results: bool,
+    /// Scrolling reached the last result, so the next page should be fetched
+    pub loading_more: bool,
     // Fuzzy search state
     pub fuzzy_input: String,
     pub all_results: Vec<Repository>, // Store original results before fuzzy filtering
+    /// Everything the current search returned, before filters and `:limit`
+    pub fetched_results: Vec<Repository>,
+    // (platform, full_name) keys of loaded results, used to de-duplicate appended pages
+    pub seen_results: std::collections::HashSet<String>,
     pub fuzzy_match_count: usize,
+    // Field each result matched on while fuzzy filtering, keyed like seen_results
+    pub fuzzy_matched_fields: std::collections::HashMap<String, FuzzyField>,
     // Dependency analysis state
     pub dependencies_cache: std::collections::HashMap<String, Option<DependencyInfo>>,
     pub dependencies_loading: bool,
+    // Declared license per runtime dependency, keyed like dependencies_cache
+    pub dependency_licenses_cache:
+        std::collections::HashMap<String, std::collections::HashMap<String, Option<String>>>,
+    pub dependency_licenses_loading: bool,
+    /// Whether an OSV vulnerability check is running
+    pub dependency_vulns_loading: bool,
     // Package manager integration
     pub package_info_cache: std::collections::HashMap<String, Vec<reposcout_core::PackageInfo>>,
     pub package_loading: bool,
+    // crates.io usage stats per crate name, None when they couldn't be fetched
+    pub crate_usage_cache: std::collections::HashMap<String, Option<reposcout_core::CrateUsage>>,
+    pub crate_usage_loading: bool,
     // Code search state
     pub code_results: Vec<CodeSearchResult>,
     pub code_filters: CodeSearchFilters,
@@ -233,8 +395,12 @@ pub struct App {
     pub code_filter_cursor: usize,
     pub code_filter_edit_buffer: String,
     pub code_match_index: usize, // Which match within a file to highlight
-    // Full file content cache for code preview
+    // Full file content cache for code preview, keyed by code_file_key.
+    // Files that couldn't be fetched are cached empty
     pub code_content_cache: std::collections::HashMap<String, String>,
+    pub code_content_loading: bool,
+    // Lines of surrounding code shown around each match
+    pub code_context_lines: usize,
     // Platform status tracking
     pub platform_status: PlatformStatus,
     // Search history popup state
@@ -244,6 +410,8 @@ pub struct App {
     pub trending_filters: TrendingFilters,
     pub show_trending_options: bool,
     pub trending_option_cursor: usize,
+    // Language breakdowns (language -> % of code) per repository, for trending language share
+    pub language_cache: std::collections::HashMap<String, std::collections::HashMap<String, f64>>,
     // Settings/Token management state
     pub show_settings: bool,
     pub settings_cursor: usize,
@@ -256,8 +424,12 @@ pub struct App {
     pub notifications_loading: bool,
     pub notifications_show_all: bool, // false = unread only, true = all
     pub notifications_participating: bool, // filter to participating only
+    pub notifications_reason: Option<reposcout_core::NotificationReason>,
+    pub notifications_type: Option<&'static str>,
     // Theme state
     pub current_theme: reposcout_core::Theme,
+    /// syntect theme for code previews
+    pub code_theme_name: String,
     pub show_theme_selector: bool,
     pub theme_selector_index: usize,
     // Portfolio/Watchlist state
@@ -268,10 +440,24 @@ pub struct App {
     // Discovery state
     pub discovery_category: DiscoveryCategory,
     pub discovery_cursor: usize,
+    pub topic_suggestions: crate::topics::TopicSuggestions,
     // Keybindings help popup
     pub show_keybindings_help: bool,
+    pub help_filter: String,
+    pub help_scroll: u16,
+    // Screen area of the results list from the last draw, for mouse hit-testing
+    pub results_area: Option<ratatui::layout::Rect>,
+    // Wrapped line count and visible height of the document preview from the
+    // last draw, for jumping to the bottom and half-page scrolling
+    pub document_rows: u16,
+    pub preview_height: u16,
+    // Time and result index of the last left click, for double-click detection
+    pub last_click: Option<(std::time::Instant, usize)>,
 }
 
+/// Terminal rows taken by one entry in the results list
+pub const RESULT_ROWS: u16 = 3;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DiscoveryCategory {
     NewAndNotable, // Recently created repos gaining traction
@@ -285,6 +471,9 @@ pub struct PlatformStatus {
     pub github_configured: bool,
     pub gitlab_configured: bool,
     pub bitbucket_configured: bool,
+    /// Rate limits last reported by the APIs (Bitbucket and SourceHut don't report one)
+    pub github_rate_limit: Option<RateLimitState>,
+    pub gitlab_rate_limit: Option<RateLimitState>,
 }
 
 impl App {
@@ -297,10 +486,12 @@ impl App {
             input_mode: InputMode::Searching,
             search_mode: SearchMode::Repository,
             search_input: String::new(),
+            last_query: String::new(),
             results: Vec::new(),
             selected_index: 0,
             scroll_offset: 0,
             loading: false,
+            spinner_frame: 0,
             error_message: None,
             error_timestamp: None,
             filters: SearchFilters::default(),
@@ -313,15 +504,48 @@ impl App {
             readme_loading: false,
             readme_cache: std::collections::HashMap::new(),
             readme_scroll: 0,
+            pending_footnote: None,
+            changelog_cache: std::collections::HashMap::new(),
+            changelog_loading: false,
+            languages_cache: std::collections::HashMap::new(),
+            languages_loading: false,
+            activity_cache: std::collections::HashMap::new(),
+            activity_loading: false,
+            star_history_cache: std::collections::HashMap::new(),
+            tasks: crate::tasks::TaskManager::new(),
+            readme_prefetch: crate::tasks::ReadmePrefetcher::new(),
             bookmarked: std::collections::HashSet::new(),
+            reviewed: std::collections::HashSet::new(),
             show_bookmarks_only: false,
+            bookmark_query: String::new(),
+            bookmark_tags: std::collections::HashMap::new(),
+            bookmark_notes: std::collections::HashMap::new(),
+            bookmark_edit_field: BookmarkField::Tags,
+            bookmark_edit_buffer: String::new(),
+            command_input: String::new(),
+            result_limit: None,
+            group_by: None,
+            live_search: false,
+            live_search_typed: None,
+            live_search_query: String::new(),
+            min_terminal_size: MIN_TERMINAL_SIZE,
+            more_results: false,
+            loading_more: false,
             fuzzy_input: String::new(),
             all_results: Vec::new(),
+            fetched_results: Vec::new(),
+            seen_results: std::collections::HashSet::new(),
             fuzzy_match_count: 0,
+            fuzzy_matched_fields: std::collections::HashMap::new(),
             dependencies_cache: std::collections::HashMap::new(),
             dependencies_loading: false,
+            dependency_licenses_cache: std::collections::HashMap::new(),
+            dependency_licenses_loading: false,
+            dependency_vulns_loading: false,
             package_info_cache: std::collections::HashMap::new(),
             package_loading: false,
+            crate_usage_cache: std::collections::HashMap::new(),
+            crate_usage_loading: false,
             code_results: Vec::new(),
             code_filters: CodeSearchFilters::default(),
             code_selected_index: 0,
@@ -332,16 +556,21 @@ impl App {
             code_filter_edit_buffer: String::new(),
             code_match_index: 0,
             code_content_cache: std::collections::HashMap::new(),
+            code_content_loading: false,
+            code_context_lines: reposcout_core::DEFAULT_CONTEXT_LINES,
             platform_status: PlatformStatus {
                 github_configured: true, // Always available (public repos don't need auth)
                 gitlab_configured: true, // Always available (public repos don't need auth)
                 bitbucket_configured: false,
+                github_rate_limit: None,
+                gitlab_rate_limit: None,
             },
             search_history: Vec::new(),
             history_selected_index: 0,
             trending_filters: TrendingFilters::default(),
             show_trending_options: false,
             trending_option_cursor: 0,
+            language_cache: std::collections::HashMap::new(),
             show_settings: false,
             settings_cursor: 0,
             token_input_buffer: String::new(),
@@ -352,7 +581,10 @@ impl App {
             notifications_loading: false,
             notifications_show_all: false,
             notifications_participating: true,
+            notifications_reason: None,
+            notifications_type: None,
             current_theme: reposcout_core::Theme::default(),
+            code_theme_name: crate::syntax::DEFAULT_CODE_THEME.to_string(),
             show_theme_selector: false,
             theme_selector_index: 0,
             portfolio_manager: reposcout_core::PortfolioManager::new(),
@@ -361,16 +593,109 @@ impl App {
             portfolio_cursor: 0,
             discovery_category: DiscoveryCategory::NewAndNotable,
             discovery_cursor: 0,
+            topic_suggestions: Default::default(),
             show_keybindings_help: false,
+            help_filter: String::new(),
+            help_scroll: 0,
+            results_area: None,
+            document_rows: 0,
+            preview_height: 0,
+            last_click: None,
         }
     }
 
     pub fn set_platform_status(&mut self, github: bool, gitlab: bool, bitbucket: bool) {
-        self.platform_status = PlatformStatus {
-            github_configured: github,
-            gitlab_configured: gitlab,
-            bitbucket_configured: bitbucket,
-        };
+        self.platform_status.github_configured = github;
+        self.platform_status.gitlab_configured = gitlab;
+        self.platform_status.bitbucket_configured = bitbucket;
+    }
+
+    /// Open the `:` command line
+    pub fn enter_command_mode(&mut self) {
+        self.input_mode = InputMode::Command;
+        self.command_input.clear();
+    }
+
+    pub fn cancel_command(&mut self) {
+        self.command_input.clear();
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Run the typed command and close the command line. Failures show in
+    /// the status bar; the command is returned when it ran, so the caller
+    /// can do the parts that need more than the app state (saving a theme).
+    pub fn submit_command(&mut self) -> Option<Command> {
+        self.input_mode = InputMode::Normal;
+        let input = std::mem::take(&mut self.command_input);
+        let result = Command::parse(&input).and_then(|command| {
+            self.run_command(&command)?;
+            Ok(command)
+        });
+        match result {
+            Ok(command) => Some(command),
+            Err(e) => {
+                self.set_temp_error(e);
+                None
+            }
+        }
+    }
+
+    fn run_command(&mut self, command: &Command) -> Result<(), String> {
+        match command {
+            Command::Sort(order) => {
+                self.filters.sort_by = order.clone();
+                self.filters.sort_results(&mut self.results);
+                self.selected_index = 0;
+                self.scroll_offset = 0;
+                self.list_state.select(Some(0));
+                self.apply_grouping();
+            }
+            Command::Group(group_by) => self.set_grouping(*group_by),
+            Command::Live(enabled) => {
+                self.live_search = enabled.unwrap_or(!self.live_search);
+                self.set_temp_error(format!(
+                    "Search as you type {}",
+                    if self.live_search { "on" } else { "off" }
+                ));
+            }
+            Command::Language(language) => {
+                self.filters.language = language.clone();
+                self.set_temp_error(match language {
+                    Some(language) => format!("Language set to {} for the next search", language),
+                    None => "Language filter cleared".to_string(),
+                });
+            }
+            Command::Limit(limit) => {
+                self.result_limit = *limit;
+                self.refresh_results();
+                if self.selected_index >= self.results.len() {
+                    self.selected_index = self.results.len().saturating_sub(1);
+                    self.list_state.select(Some(self.selected_index));
+                }
+            }
+            Command::Export(path) => {
+                reposcout_core::Exporter::export_search_results(
+                    &self.results,
+                    path,
+                    &self.search_input,
+                    None,
+                    None,
+                )
+                .map_err(|e| format!("Export failed: {}", e))?;
+                self.set_temp_error(format!(
+                    "Exported {} repositories to {}",
+                    self.results.len(),
+                    path
+                ));
+            }
+            Command::Theme(name) => {
+                let theme = reposcout_core::Theme::by_name(name)
+                    .ok_or_else(|| format!("Unknown theme '{}'", name))?;
+                self.set_theme(theme);
+            }
+            Command::Quit => self.quit(),
+        }
+        Ok(())
     }
 
     /// Enter fuzzy search mode
@@ -386,6 +711,7 @@ impl App {
     pub fn exit_fuzzy_mode(&mut self) {
         self.input_mode = InputMode::Normal;
         self.fuzzy_input.clear();
+        self.fuzzy_matched_fields.clear();
         // Restore all results
         if !self.all_results.is_empty() {
             self.results = self.all_results.clone();
@@ -397,8 +723,7 @@ impl App {
 
     /// Apply fuzzy filter to results
     pub fn apply_fuzzy_filter(&mut self) {
-        use fuzzy_matcher::skim::SkimMatcherV2;
-        use fuzzy_matcher::FuzzyMatcher;
+        self.fuzzy_matched_fields.clear();
 
         if self.fuzzy_input.is_empty() {
             // No filter, show all results
@@ -409,33 +734,81 @@ impl App {
             let query = self.fuzzy_input.to_lowercase();
 
             // Filter and score results
-            let mut scored_results: Vec<(Repository, i64)> = self
+            let mut scored_results: Vec<(Repository, i64, FuzzyField)> = self
                 .all_results
                 .iter()
                 .filter_map(|repo| {
-                    // Match against repo name and description
-                    let name_score = matcher.fuzzy_match(&repo.full_name.to_lowercase(), &query);
-                    let desc_score = repo
-                        .description
-                        .as_ref()
-                        .and_then(|d| matcher.fuzzy_match(&d.to_lowercase(), &query));
-
-                    // Take the best score
-                    let score = name_score.or(desc_score)?;
-                    Some((repo.clone(), score))
+                    let (field, score) = Self::fuzzy_match(&matcher, &query, repo)?;
+                    Some((repo.clone(), score, field))
                 })
                 .collect();
 
-            // Sort by score (highest first)
-            scored_results.sort_by(|a, b| b.1.cmp(&a.1));
+            // Sort by score (highest first); the sort is stable so ties keep the original order
+            scored_results.sort_by_key(|r| std::cmp::Reverse(r.1));
 
-            self.results = scored_results.into_iter().map(|(repo, _)| repo).collect();
+            self.results = scored_results
+                .into_iter()
+                .map(|(repo, _, field)| {
+                    self.fuzzy_matched_fields
+                        .insert(Self::result_key(&repo), field);
+                    repo
+                })
+                .collect();
             self.fuzzy_match_count = self.results.len();
         }
 
         // Reset selection
         self.selected_index = 0;
         self.list_state.select(Some(0));
+        self.apply_grouping();
+    }
+
+    /// How well a repository matches a lower-cased fuzzy query, and on
+    /// which field. None when it doesn't match.
+    fn fuzzy_match(
+        matcher: &SkimMatcherV2,
+        query: &str,
+        repo: &Repository,
+    ) -> Option<(FuzzyField, i64)> {
+        let score = |text: &str| matcher.fuzzy_match(&text.to_lowercase(), query);
+        let topics = repo.topics.join(" ");
+        let fields = [
+            (FuzzyField::Name, score(&repo.full_name)),
+            (
+                FuzzyField::Description,
+                repo.description.as_deref().and_then(score),
+            ),
+            (
+                FuzzyField::Language,
+                repo.language.as_deref().and_then(score),
+            ),
+            (FuzzyField::Topics, score(&topics)),
+        ];
+
+        // Best single field wins, weighted so name matches rank first
+        let best = fields
+            .into_iter()
+            .filter_map(|(field, s)| s.map(|s| (field, s * field.weight())))
+            .max_by_key(|&(_, s)| s);
+
+        // Queries spanning fields ("rust terminal") only match the combined text
+        best.or_else(|| {
+            let combined = format!(
+                "{} {} {} {}",
+                repo.full_name,
+                repo.description.as_deref().unwrap_or(""),
+                repo.language.as_deref().unwrap_or(""),
+                topics
+            );
+            score(&combined).map(|s| (FuzzyField::Combined, s))
+        })
+    }
+
+    /// Which field a result matched the fuzzy filter on, if filtering
+    pub fn fuzzy_matched_field(&self, repo: &Repository) -> Option<FuzzyField> {
+        self.fuzzy_matched_fields
+            .get(&Self::result_key(repo))
+            .copied()
     }
 
     /// Get bookmark key for a repository
@@ -460,6 +833,8 @@ impl App {
             let key =
                 Self::bookmark_key(&repo.platform.to_string().to_lowercase(), &repo.full_name);
             if self.bookmarked.contains(&key) {
+                self.bookmark_tags.remove(&key);
+                self.bookmark_notes.remove(&key);
                 self.bookmarked.remove(&key);
             } else {
                 self.bookmarked.insert(key);
@@ -467,16 +842,123 @@ impl App {
         }
     }
 
+    /// Whether a repository has been marked as reviewed
+    pub fn is_reviewed(&self, repo: &Repository) -> bool {
+        self.reviewed.contains(&Self::result_key(repo))
+    }
+
+    /// Mark or unmark the selected repository as reviewed, returning whether
+    /// it is reviewed now. With reviewed repositories hidden it leaves the
+    /// list straight away.
+    pub fn toggle_current_reviewed(&mut self) -> Option<bool> {
+        let key = Self::result_key(self.selected_repository()?);
+        if self.reviewed.remove(&key) {
+            return Some(false);
+        }
+        self.reviewed.insert(key.clone());
+
+        if self.filters.hide_reviewed {
+            self.results.retain(|repo| Self::result_key(repo) != key);
+            self.all_results
+                .retain(|repo| Self::result_key(repo) != key);
+            if self.selected_index >= self.results.len() {
+                self.selected_index = self.results.len().saturating_sub(1);
+            }
+            self.list_state.select(Some(self.selected_index));
+            self.apply_grouping();
+        }
+        Some(true)
+    }
+
+    /// Remember a bookmark's tags (comma-separated) and notes
+    pub fn set_bookmark_metadata(&mut self, key: String, tags: Option<&str>, notes: Option<&str>) {
+        let tags = parse_tags(tags.unwrap_or(""));
+        if tags.is_empty() {
+            self.bookmark_tags.remove(&key);
+        } else {
+            self.bookmark_tags.insert(key.clone(), tags);
+        }
+        match notes.map(str::trim).filter(|n| !n.is_empty()) {
+            Some(notes) => self.bookmark_notes.insert(key, notes.to_string()),
+            None => self.bookmark_notes.remove(&key),
+        };
+    }
+
+    /// Open the tag/notes editor for the selected repository, pre-filled
+    /// with its current value. Does nothing unless it's bookmarked
+    pub fn start_bookmark_edit(&mut self, field: BookmarkField) {
+        if !self.is_current_bookmarked() {
+            return;
+        }
+        let Some(repo) = self.selected_repository() else {
+            return;
+        };
+        let key = Self::bookmark_key(&repo.platform.to_string().to_lowercase(), &repo.full_name);
+        self.bookmark_edit_buffer = match field {
+            BookmarkField::Tags => self
+                .bookmark_tags
+                .get(&key)
+                .map(|tags| tags.join(", "))
+                .unwrap_or_default(),
+            BookmarkField::Notes => self.bookmark_notes.get(&key).cloned().unwrap_or_default(),
+        };
+        self.bookmark_edit_field = field;
+        self.input_mode = InputMode::BookmarkEdit;
+    }
+
+    /// Apply the edit locally and close the popup. Returns the platform,
+    /// full name and new value (None when cleared) to persist
+    pub fn finish_bookmark_edit(&mut self) -> Option<(String, String, Option<String>)> {
+        self.input_mode = InputMode::Normal;
+        let buffer = std::mem::take(&mut self.bookmark_edit_buffer);
+        let repo = self.selected_repository()?;
+        let platform = repo.platform.to_string().to_lowercase();
+        let full_name = repo.full_name.clone();
+        let key = Self::bookmark_key(&platform, &full_name);
+
+        let value = match self.bookmark_edit_field {
+            BookmarkField::Tags => {
+                let tags = parse_tags(&buffer);
+                let value = (!tags.is_empty()).then(|| tags.join(","));
+                let notes = self.bookmark_notes.get(&key).cloned();
+                self.set_bookmark_metadata(key, value.as_deref(), notes.as_deref());
+                value
+            }
+            BookmarkField::Notes => {
+                let value = Some(buffer.trim().to_string()).filter(|n| !n.is_empty());
+                let tags = self.bookmark_tags.get(&key).map(|tags| tags.join(","));
+                self.set_bookmark_metadata(key, tags.as_deref(), value.as_deref());
+                value
+            }
+        };
+        Some((platform, full_name, value))
+    }
+
+    pub fn cancel_bookmark_edit(&mut self) {
+        self.bookmark_edit_buffer.clear();
+        self.input_mode = InputMode::Normal;
+    }
+
+    /// Tags of a repository's bookmark, if any
+    pub fn tags_for(&self, repo: &Repository) -> &[String] {
+        let key = Self::bookmark_key(&repo.platform.to_string().to_lowercase(), &repo.full_name);
+        self.bookmark_tags
+            .get(&key)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
     /// Toggle showing bookmarks only
     pub fn toggle_bookmarks_view(&mut self) {
         self.show_bookmarks_only = !self.show_bookmarks_only;
+        self.bookmark_query.clear();
     }
 
     pub fn toggle_preview_mode(&mut self) {
         self.preview_mode = match self.preview_mode {
             PreviewMode::Stats => PreviewMode::Readme,
-            PreviewMode::Readme => PreviewMode::Activity,
-            PreviewMode::Activity => PreviewMode::Dependencies,
+            PreviewMode::Readme | PreviewMode::Changelog => PreviewMode::Activity,
+            PreviewMode::Activity | PreviewMode::Languages => PreviewMode::Dependencies,
             PreviewMode::Dependencies => PreviewMode::Package,
             PreviewMode::Package => PreviewMode::Stats,
         };
@@ -485,8 +967,10 @@ impl App {
     pub fn next_preview_tab(&mut self) {
         self.preview_mode = match self.preview_mode {
             PreviewMode::Stats => PreviewMode::Readme,
-            PreviewMode::Readme => PreviewMode::Activity,
-            PreviewMode::Activity => PreviewMode::Dependencies,
+            PreviewMode::Readme => PreviewMode::Changelog,
+            PreviewMode::Changelog => PreviewMode::Activity,
+            PreviewMode::Activity => PreviewMode::Languages,
+            PreviewMode::Languages => PreviewMode::Dependencies,
             PreviewMode::Dependencies => PreviewMode::Package,
             PreviewMode::Package => PreviewMode::Stats,
         };
@@ -502,8 +986,10 @@ impl App {
         self.preview_mode = match self.preview_mode {
             PreviewMode::Stats => PreviewMode::Package,
             PreviewMode::Package => PreviewMode::Dependencies,
-            PreviewMode::Dependencies => PreviewMode::Activity,
-            PreviewMode::Activity => PreviewMode::Readme,
+            PreviewMode::Dependencies => PreviewMode::Languages,
+            PreviewMode::Languages => PreviewMode::Activity,
+            PreviewMode::Activity => PreviewMode::Changelog,
+            PreviewMode::Changelog => PreviewMode::Readme,
             PreviewMode::Readme => PreviewMode::Stats,
         };
         self.reset_readme_scroll();
@@ -512,11 +998,13 @@ impl App {
     pub fn set_readme(&mut self, content: String) {
         self.readme_content = Some(content);
         self.readme_loading = false;
+        self.pending_footnote = None;
     }
 
     pub fn clear_readme(&mut self) {
         self.readme_content = None;
         self.readme_loading = false;
+        self.pending_footnote = None;
     }
 
     /// Check if README is cached for the currently selected repository
@@ -539,6 +1027,22 @@ impl App {
         self.readme_content = None;
     }
 
+    /// Repositories whose READMEs are worth prefetching: the selection first,
+    /// then neighbours up to `radius` rows away, skipping cached ones
+    pub fn readme_prefetch_candidates(&self, radius: usize) -> Vec<String> {
+        let start = self.selected_index.saturating_sub(radius);
+        let end = (self.selected_index + radius + 1).min(self.results.len());
+        let mut candidates: Vec<(usize, String)> = (start..end)
+            .map(|i| {
+                let distance = i.abs_diff(self.selected_index);
+                (distance, self.results[i].full_name.clone())
+            })
+            .filter(|(_, name)| !self.readme_cache.contains_key(name))
+            .collect();
+        candidates.sort_by_key(|(distance, _)| *distance);
+        candidates.into_iter().map(|(_, name)| name).collect()
+    }
+
     /// Set README from cache or fetched content
     pub fn load_readme_for_current(&mut self) {
         if let Some(repo) = self.selected_repository() {
@@ -552,6 +1056,118 @@ impl App {
         }
     }
 
+    /// Check if the changelog is cached for the currently selected repository
+    pub fn get_cached_changelog(&self) -> Option<&String> {
+        self.selected_repository()
+            .and_then(|repo| self.changelog_cache.get(&repo.full_name))
+    }
+
+    /// Cache changelog content for a repository
+    pub fn cache_changelog(&mut self, repo_name: String, content: String) {
+        self.changelog_cache.insert(repo_name, content);
+        self.changelog_loading = false;
+    }
+
+    /// Language breakdown for the selected repository, if fetched.
+    ///
+    /// Outer None means not fetched yet, inner None means the platform has no data.
+    pub fn get_cached_languages(&self) -> Option<Option<&std::collections::HashMap<String, u64>>> {
+        let repo = self.selected_repository()?;
+        if let Some(languages) = &repo.languages {
+            return Some(Some(languages));
+        }
+        self.languages_cache
+            .get(&Self::result_key(repo))
+            .map(|languages| languages.as_ref())
+    }
+
+    /// Cache a language breakdown and attach it to the matching loaded results
+    pub fn cache_languages(
+        &mut self,
+        platform: Platform,
+        repo_name: &str,
+        languages: Option<std::collections::HashMap<String, u64>>,
+    ) {
+        for repo in self
+            .results
+            .iter_mut()
+            .chain(self.all_results.iter_mut())
+            .chain(self.fetched_results.iter_mut())
+        {
+            if repo.platform == platform && repo.full_name == repo_name {
+                repo.languages = languages.clone();
+            }
+        }
+        let key = Self::bookmark_key(&platform.to_string().to_lowercase(), repo_name);
+        self.languages_cache.insert(key, languages);
+        self.languages_loading = false;
+    }
+
+    /// Activity stats for the selected repository, if fetched
+    pub fn get_cached_activity(&self) -> Option<crate::tasks::ActivityStats> {
+        let repo = self.selected_repository()?;
+        if let Some(stats) = self.activity_cache.get(&Self::result_key(repo)) {
+            return Some(stats.clone());
+        }
+        (repo.contributors.is_some() || repo.commit_activity.is_some()).then(|| {
+            crate::tasks::ActivityStats {
+                contributors: repo.contributors,
+                commit_activity: repo.commit_activity.clone(),
+                stats_pending: false,
+            }
+        })
+    }
+
+    /// Cache activity stats and attach them to the matching loaded results
+    pub fn cache_activity(
+        &mut self,
+        platform: Platform,
+        repo_name: &str,
+        stats: crate::tasks::ActivityStats,
+    ) {
+        for repo in self
+            .results
+            .iter_mut()
+            .chain(self.all_results.iter_mut())
+            .chain(self.fetched_results.iter_mut())
+        {
+            if repo.platform == platform && repo.full_name == repo_name {
+                repo.contributors = stats.contributors;
+                repo.commit_activity = stats.commit_activity.clone();
+            }
+        }
+        let key = Self::bookmark_key(&platform.to_string().to_lowercase(), repo_name);
+        self.activity_cache.insert(key, stats);
+        self.activity_loading = false;
+    }
+
+    /// Recorded star counts for the selected repository, if loaded
+    pub fn get_star_history(&self) -> Option<&[reposcout_cache::StarSnapshot]> {
+        let repo = self.selected_repository()?;
+        self.star_history_cache
+            .get(&Self::result_key(repo))
+            .map(Vec::as_slice)
+    }
+
+    /// Keep a repository's recorded star counts for the Activity tab
+    pub fn cache_star_history(
+        &mut self,
+        platform: Platform,
+        repo_name: &str,
+        history: Vec<reposcout_cache::StarSnapshot>,
+    ) {
+        let key = Self::bookmark_key(&platform.to_string().to_lowercase(), repo_name);
+        self.star_history_cache.insert(key, history);
+    }
+
+    /// Whether the current preview is a scrollable markdown document
+    pub fn is_document_preview(&self) -> bool {
+        matches!(
+            self.preview_mode,
+            PreviewMode::Readme | PreviewMode::Changelog
+        )
+    }
+
     /// Scroll README down
     pub fn scroll_readme_down(&mut self) {
         self.readme_scroll = self.readme_scroll.saturating_add(1);
@@ -567,6 +1183,141 @@ impl App {
         self.readme_scroll = 0;
     }
 
+    /// Type a digit of a README footnote number. Returns the URL to open once
+    /// the number is complete, i.e. no further digit could name a footnote.
+    pub fn push_footnote_digit(&mut self, digit: u32, now: std::time::Instant) -> Option<String> {
+        let urls = self.readme_footnote_urls();
+        let number = match self.pending_footnote.take() {
+            Some((pending, _)) => pending * 10 + digit as usize,
+            None => digit as usize,
+        };
+
+        if number == 0 || number > urls.len() {
+            self.set_temp_error(match urls.len() {
+                0 => "This README has no links".to_string(),
+                n => format!("No link [{}], this README has {}", number, n),
+            });
+            return None;
+        }
+
+        if number * 10 > urls.len() {
+            return urls.into_iter().nth(number - 1);
+        }
+
+        self.pending_footnote = Some((number, now));
+        self.set_temp_error(format!("Link [{}…]", number));
+        None
+    }
+
+    /// URL of a typed footnote once no second digit came in time
+    pub fn take_due_footnote(&mut self, now: std::time::Instant) -> Option<String> {
+        let (number, typed_at) = self.pending_footnote?;
+        if now.duration_since(typed_at) < crate::readme_links::FOOTNOTE_DIGIT_TIMEOUT {
+            return None;
+        }
+        self.pending_footnote = None;
+        self.readme_footnote_urls().into_iter().nth(number - 1)
+    }
+
+    /// Follow typing in the repository search box when live search is on.
+    /// Returns the query to run once typing has paused for
+    /// `LIVE_SEARCH_DEBOUNCE` on text that hasn't been searched yet.
+    pub fn poll_live_search(&mut self, now: std::time::Instant) -> Option<String> {
+        let typing = self.live_search
+            && self.input_mode == InputMode::Searching
+            && self.search_mode == SearchMode::Repository
+            && !self.show_bookmarks_only;
+        let input = self.search_input.trim().to_string();
+        if !typing || input.is_empty() || input == self.live_search_query {
+            self.live_search_typed = None;
+            return None;
+        }
+
+        match &self.live_search_typed {
+            Some((typed, since)) if *typed == input => {
+                if now.duration_since(*since) < LIVE_SEARCH_DEBOUNCE {
+                    return None;
+                }
+                self.live_search_typed = None;
+                self.live_search_query = input;
+                Some(self.get_search_query())
+            }
+            _ => {
+                self.live_search_typed = Some((input, now));
+                None
+            }
+        }
+    }
+
+    /// Whether a live search is waiting for typing to pause
+    pub fn live_search_pending(&self) -> bool {
+        self.live_search_typed.is_some()
+    }
+
+    /// Link targets of the shown README, footnote 1 first
+    fn readme_footnote_urls(&self) -> Vec<String> {
+        self.readme_content
+            .as_deref()
+            .map(crate::readme_links::footnote_urls)
+            .unwrap_or_default()
+    }
+
+    /// Furthest the README can scroll while still filling the preview
+    fn max_readme_scroll(&self) -> u16 {
+        self.document_rows.saturating_sub(self.preview_height)
+    }
+
+    /// Jump to the first result, or the top of the README/changelog
+    pub fn jump_to_top(&mut self) {
+        if self.is_document_preview() {
+            self.reset_readme_scroll();
+        } else {
+            self.select_result(0);
+        }
+    }
+
+    /// Jump to the last result, or the bottom of the README/changelog
+    pub fn jump_to_bottom(&mut self) {
+        if self.is_document_preview() {
+            self.readme_scroll = self.max_readme_scroll();
+        } else if !self.results.is_empty() {
+            self.select_result(self.results.len() - 1);
+        }
+    }
+
+    /// Move half a page down through the results or README/changelog
+    pub fn half_page_down(&mut self) {
+        if self.is_document_preview() {
+            let target = self.readme_scroll.saturating_add(self.half_page_rows());
+            self.readme_scroll = target.min(self.max_readme_scroll().max(self.readme_scroll));
+        } else if !self.results.is_empty() {
+            let target = self.selected_index + self.half_page_results();
+            self.select_result(target.min(self.results.len() - 1));
+        }
+    }
+
+    /// Move half a page up through the results or README/changelog
+    pub fn half_page_up(&mut self) {
+        if self.is_document_preview() {
+            self.readme_scroll = self.readme_scroll.saturating_sub(self.half_page_rows());
+        } else {
+            self.select_result(self.selected_index.saturating_sub(self.half_page_results()));
+        }
+    }
+
+    fn half_page_rows(&self) -> u16 {
+        (self.preview_height / 2).max(1)
+    }
+
+    /// Half the number of results visible in the list (at least one)
+    fn half_page_results(&self) -> usize {
+        let visible = self
+            .results_area
+            .map(|area| area.height.saturating_sub(2) / RESULT_ROWS)
+            .unwrap_or(10);
+        (visible as usize / 2).max(1)
+    }
+
     pub fn quit(&mut self) {
         self.should_quit = true;
     }
@@ -600,10 +1351,29 @@ impl App {
                 .unwrap_or_default(),
             3 => self.filters.pushed.clone().unwrap_or_default(),
             4 => self.filters.sort_by.clone(),
+            5 => self
+                .filters
+                .min_health
+                .map(|s| s.to_string())
+                .unwrap_or_default(),
             _ => String::new(),
         };
     }
 
+    /// Whether the filter under the cursor is an on/off toggle rather than a text field
+    pub fn is_toggle_filter(&self) -> bool {
+        self.filter_cursor >= 6
+    }
+
+    pub fn toggle_current_filter(&mut self) {
+        match self.filter_cursor {
+            6 => self.filters.exclude_forks = !self.filters.exclude_forks,
+            7 => self.filters.exclude_archived = !self.filters.exclude_archived,
+            8 => self.filters.hide_reviewed = !self.filters.hide_reviewed,
+            _ => {}
+        }
+    }
+
     pub fn save_filter_edit(&mut self) {
         // Save the edit buffer to the actual filter
         match self.filter_cursor {
@@ -627,10 +1397,15 @@ impl App {
                     Some(self.filter_edit_buffer.clone())
                 };
             }
-            4 => {
-                if !self.filter_edit_buffer.is_empty() {
-                    self.filters.sort_by = self.filter_edit_buffer.clone();
-                }
+            4 if !self.filter_edit_buffer.is_empty() => {
+                self.filters.sort_by = self.filter_edit_buffer.clone();
+            }
+            5 => {
+                self.filters.min_health = self
+                    .filter_edit_buffer
+                    .parse::<u8>()
+                    .ok()
+                    .map(|score| score.min(100));
             }
             _ => {}
         }
@@ -648,7 +1423,7 @@ impl App {
     }
 
     pub fn next_filter(&mut self) {
-        self.filter_cursor = (self.filter_cursor + 1).min(4); // 5 filter fields
+        self.filter_cursor = (self.filter_cursor + 1).min(8); // 9 filter fields
     }
 
     pub fn previous_filter(&mut self) {
@@ -661,6 +1436,7 @@ impl App {
         self.filters.sort_by = match self.filters.sort_by.as_str() {
             "stars" => "forks".to_string(),
             "forks" => "updated".to_string(),
+            "updated" => "trending-score".to_string(),
             _ => "stars".to_string(),
         };
     }
@@ -672,6 +1448,10 @@ impl App {
             2 => self.filters.max_stars = None,
             3 => self.filters.pushed = None,
             4 => self.filters.sort_by = "stars".to_string(),
+            5 => self.filters.min_health = None,
+            6 => self.filters.exclude_forks = false,
+            7 => self.filters.exclude_archived = false,
+            8 => self.filters.hide_reviewed = false,
             _ => {}
         }
     }
@@ -680,6 +1460,24 @@ impl App {
         if !self.results.is_empty() {
             self.selected_index = (self.selected_index + 1).min(self.results.len() - 1);
             self.list_state.select(Some(self.selected_index));
+            self.request_more_at_end();
+        }
+    }
+
+    /// Ask for the next page of search results once the last one is selected
+    fn request_more_at_end(&mut self) {
+        let at_end = self.selected_index + 1 >= self.results.len();
+        if at_end
+            && self.more_results
+            && !self.loading
+            && self.search_mode == SearchMode::Repository
+            && !self.show_bookmarks_only
+            && self.input_mode != InputMode::FuzzySearch
+            && !self
+                .result_limit
+                .is_some_and(|limit| self.results.len() >= limit)
+        {
+            self.loading_more = true;
         }
     }
 
@@ -690,17 +1488,263 @@ impl App {
         }
     }
 
+    pub fn select_result(&mut self, index: usize) {
+        if index < self.results.len() {
+            self.selected_index = index;
+            self.list_state.select(Some(index));
+        }
+    }
+
+    /// Result index under a screen position, if it's on a results list row
+    pub fn result_index_at(&self, column: u16, row: u16) -> Option<usize> {
+        let area = self.results_area?;
+        // Skip the list's border
+        let inner_top = area.y + 1;
+        let inner_bottom = area.y + area.height.saturating_sub(1);
+        if column <= area.x
+            || column >= area.x + area.width.saturating_sub(1)
+            || row < inner_top
+            || row >= inner_bottom
+        {
+            return None;
+        }
+
+        if self.group_by.is_none() {
+            let index = self.list_state.offset() + ((row - inner_top) / RESULT_ROWS) as usize;
+            return (index < self.results.len()).then_some(index);
+        }
+
+        // Header rows are one line tall; the list offset counts rows
+        let mut top = inner_top;
+        for list_row in self
+            .result_rows()
+            .into_iter()
+            .skip(self.list_state.offset())
+        {
+            let height = match list_row {
+                ResultRow::Header { .. } => 1,
+                ResultRow::Result(_) => RESULT_ROWS,
+            };
+            if row < top + height {
+                return match list_row {
+                    ResultRow::Result(index) => Some(index),
+                    ResultRow::Header { .. } => None,
+                };
+            }
+            top += height;
+        }
+        None
+    }
+
+    /// Whether a terminal this size is big enough for the normal layout
+    pub fn terminal_fits(&self, width: u16, height: u16) -> bool {
+        let (min_width, min_height) = self.min_terminal_size;
+        width >= min_width && height >= min_height
+    }
+
+    /// Whether a popup is covering the main view
+    pub fn popup_open(&self) -> bool {
+        self.show_filters
+            || self.show_code_filters
+            || self.show_trending_options
+            || self.show_settings
+            || self.show_theme_selector
+            || self.show_portfolio_manager
+            || self.show_keybindings_help
+    }
+
     pub fn selected_repository(&self) -> Option<&Repository> {
         self.results.get(self.selected_index)
     }
 
     pub fn set_results(&mut self, mut results: Vec<Repository>) {
-        // Apply sorting based on filters
-        self.filters.sort_results(&mut results);
-        self.results = results;
+        // Bookmarks and trending don't come through the search engine, so
+        // work out health here once rather than on every draw
+        for repo in &mut results {
+            repo.ensure_health();
+        }
+        self.fetched_results = results;
+        self.refresh_results();
+        // Fetching the results recorded new star counts, so reload them
+        self.star_history_cache.clear();
+
         self.selected_index = 0;
         self.scroll_offset = 0;
         self.list_state.select(Some(0));
+        self.more_results = false;
+        self.loading_more = false;
+    }
+
+    /// Show the results of an engine search, which can serve further pages
+    /// once scrolling reaches the end
+    pub fn set_search_results(&mut self, results: Vec<Repository>) {
+        self.set_results(results);
+        self.more_results = true;
+    }
+
+    /// Rebuild the shown results from everything fetched: filters, sorting,
+    /// de-duplication, then the result limit. Leaves the selection alone.
+    fn refresh_results(&mut self) {
+        let mut results = self.fetched_results.clone();
+        self.filters.retain_matching(&mut results);
+        if self.filters.hide_reviewed {
+            results.retain(|repo| !self.is_reviewed(repo));
+        }
+        self.filters.sort_results(&mut results);
+
+        self.seen_results.clear();
+        let seen = &mut self.seen_results;
+        results.retain(|repo| seen.insert(Self::result_key(repo)));
+        if let Some(limit) = self.result_limit {
+            results.truncate(limit);
+        }
+
+        self.results = results;
+        self.apply_grouping();
+    }
+
+    /// Append another page of results, skipping repositories already loaded.
+    ///
+    /// The API's ordering can shift between page requests, so the same repo may
+    /// come back twice. The first occurrence wins and the current selection is
+    /// left untouched. Returns how many new repositories were added.
+    pub fn append_results(&mut self, page: Vec<Repository>) -> usize {
+        let mut added = 0;
+        let matcher = SkimMatcherV2::default();
+        let query = self.fuzzy_input.to_lowercase();
+        self.star_history_cache.clear();
+
+        for mut repo in page {
+            repo.ensure_health();
+            self.fetched_results.push(repo.clone());
+            if self
+                .result_limit
+                .is_some_and(|limit| self.results.len() >= limit)
+            {
+                continue;
+            }
+            let hidden = self.filters.hide_reviewed && self.is_reviewed(&repo);
+            if hidden
+                || !self.filters.allows(&repo)
+                || !self.seen_results.insert(Self::result_key(&repo))
+            {
+                continue;
+            }
+
+            added += 1;
+            // Keep the unfiltered list in sync while fuzzy filtering is
+            // active, and only show what matches the query. New matches go
+            // to the end so the selection stays put.
+            if self.input_mode == InputMode::FuzzySearch {
+                self.all_results.push(repo.clone());
+                if !query.is_empty() {
+                    let Some((field, _)) = Self::fuzzy_match(&matcher, &query, &repo) else {
+                        continue;
+                    };
+                    self.fuzzy_matched_fields
+                        .insert(Self::result_key(&repo), field);
+                }
+                self.fuzzy_match_count += 1;
+            }
+            self.results.push(repo);
+        }
+
+        if added > 0 {
+            self.apply_grouping();
+        }
+        added
+    }
+
+    /// Append a page fetched on reaching the end of the list. An empty page
+    /// means the search has nothing more to give.
+    pub fn append_more_results(&mut self, page: Vec<Repository>) -> usize {
+        self.loading_more = false;
+        if page.is_empty() {
+            self.more_results = false;
+        }
+        self.append_results(page)
+    }
+
+    /// Cycle the results grouping: none, by platform, by language
+    pub fn cycle_grouping(&mut self) {
+        self.set_grouping(match self.group_by {
+            None => Some(GroupBy::Platform),
+            Some(GroupBy::Platform) => Some(GroupBy::Language),
+            Some(GroupBy::Language) => None,
+        });
+    }
+
+    pub fn set_grouping(&mut self, group_by: Option<GroupBy>) {
+        self.group_by = group_by;
+        // The list scrolls by rows, which header rows change
+        *self.list_state.offset_mut() = 0;
+        match group_by {
+            Some(group_by) => {
+                self.apply_grouping();
+                self.set_temp_error(format!("Results grouped by {}", group_by.label()));
+            }
+            None => {
+                // Groups kept the sort order inside them; sorting again interleaves them
+                let selected = self.selected_repository().map(Self::result_key);
+                self.filters.sort_results(&mut self.results);
+                self.reselect(selected);
+                self.set_temp_error("Results no longer grouped".to_string());
+            }
+        }
+    }
+
+    /// Make each group's results contiguous so moving through the list
+    /// goes group by group, keeping the selected repository selected
+    fn apply_grouping(&mut self) {
+        let Some(group_by) = self.group_by else {
+            return;
+        };
+        let selected = self.selected_repository().map(Self::result_key);
+        let order: Vec<usize> = reposcout_core::group_repositories(&self.results, group_by)
+            .into_iter()
+            .flat_map(|group| group.indices)
+            .collect();
+        let mut results: Vec<Option<Repository>> = std::mem::take(&mut self.results)
+            .into_iter()
+            .map(Some)
+            .collect();
+        self.results = order
+            .into_iter()
+            .filter_map(|i| results[i].take())
+            .collect();
+        self.reselect(selected);
+    }
+
+    fn reselect(&mut self, key: Option<String>) {
+        if let Some(index) = key.and_then(|key| {
+            self.results
+                .iter()
+                .position(|repo| Self::result_key(repo) == key)
+        }) {
+            self.selected_index = index;
+            self.list_state.select(Some(index));
+        }
+    }
+
+    /// The results list as displayed, with a header row before each group
+    pub fn result_rows(&self) -> Vec<ResultRow> {
+        let Some(group_by) = self.group_by else {
+            return (0..self.results.len()).map(ResultRow::Result).collect();
+        };
+        let mut rows = Vec::new();
+        for group in reposcout_core::group_repositories(&self.results, group_by) {
+            rows.push(ResultRow::Header {
+                name: group.name,
+                count: group.indices.len(),
+            });
+            rows.extend(group.indices.into_iter().map(ResultRow::Result));
+        }
+        rows
+    }
+
+    /// De-duplication key for a result (platform + full_name)
+    fn result_key(repo: &Repository) -> String {
+        Self::bookmark_key(&repo.platform.to_string().to_lowercase(), &repo.full_name)
     }
 
     pub fn clear_error(&mut self) {
@@ -708,6 +1752,21 @@ impl App {
         self.error_timestamp = None;
     }
 
+    /// Step the loading spinner once per redraw; it rewinds when loading stops
+    pub fn tick_spinner(&mut self) {
+        if self.loading || self.loading_more {
+            self.spinner_frame = self.spinner_frame.wrapping_add(1);
+        } else {
+            self.spinner_frame = 0;
+        }
+    }
+
+    /// Current loading spinner frame
+    pub fn spinner(&self) -> &'static str {
+        let frames = &crate::tasks::SPINNER_FRAMES;
+        frames[self.spinner_frame % frames.len()]
+    }
+
     /// Set a temporary error message that will auto-clear after 5 seconds
     pub fn set_temp_error(&mut self, message: String) {
         self.error_message = Some(message);
@@ -735,6 +1794,16 @@ impl App {
         self.filters.build_query(&self.search_input)
     }
 
+    /// The last search's text with the current filters applied, also put
+    /// back in the search input. None before the first search.
+    pub fn rerun_query(&mut self) -> Option<String> {
+        if self.last_query.is_empty() {
+            return None;
+        }
+        self.search_input = self.last_query.clone();
+        Some(self.get_search_query())
+    }
+
     /// Get cached dependencies for current repository
     pub fn get_cached_dependencies(&self) -> Option<&Option<DependencyInfo>> {
         if let Some(repo) = self.selected_repository() {
@@ -749,6 +1818,24 @@ impl App {
         self.dependencies_cache.insert(repo_name, deps);
     }
 
+    /// Dependency licenses for the current repository, if fetched
+    pub fn get_cached_dependency_licenses(
+        &self,
+    ) -> Option<&std::collections::HashMap<String, Option<String>>> {
+        let repo = self.selected_repository()?;
+        self.dependency_licenses_cache.get(&repo.full_name)
+    }
+
+    /// Cache dependency licenses for a repository
+    pub fn cache_dependency_licenses(
+        &mut self,
+        repo_name: String,
+        licenses: std::collections::HashMap<String, Option<String>>,
+    ) {
+        self.dependency_licenses_cache.insert(repo_name, licenses);
+        self.dependency_licenses_loading = false;
+    }
+
     /// Start dependency loading
     pub fn start_dependencies_loading(&mut self) {
         self.dependencies_loading = true;
@@ -777,6 +1864,36 @@ impl App {
         self.package_info_cache.insert(repo_name, packages);
     }
 
+    /// Crate published from the selected repository, if any
+    pub fn selected_crate_name(&self) -> Option<&str> {
+        self.get_cached_package_info()?
+            .iter()
+            .find(|pkg| pkg.manager == reposcout_core::PackageManager::Cargo)
+            .map(|pkg| pkg.name.as_str())
+    }
+
+    /// crates.io usage stats for a crate.
+    ///
+    /// Outer None means not fetched yet, inner None means the fetch failed.
+    pub fn get_cached_crate_usage(
+        &self,
+        crate_name: &str,
+    ) -> Option<Option<&reposcout_core::CrateUsage>> {
+        self.crate_usage_cache
+            .get(crate_name)
+            .map(|usage| usage.as_ref())
+    }
+
+    /// Cache crates.io usage stats for a crate
+    pub fn cache_crate_usage(
+        &mut self,
+        crate_name: String,
+        usage: Option<reposcout_core::CrateUsage>,
+    ) {
+        self.crate_usage_cache.insert(crate_name, usage);
+        self.crate_usage_loading = false;
+    }
+
     /// Start package loading
     pub fn start_package_loading(&mut self) {
         self.package_loading = true;
@@ -812,15 +1929,7 @@ impl App {
     pub fn copy_package_install_command(&mut self) -> Result<(), String> {
         if let Some(packages) = self.get_cached_package_info() {
             if let Some(first_pkg) = packages.first() {
-                match arboard::Clipboard::new() {
-                    Ok(mut clipboard) => {
-                        if let Err(e) = clipboard.set_text(&first_pkg.install_command) {
-                            return Err(format!("Failed to copy to clipboard: {}", e));
-                        }
-                        Ok(())
-                    }
-                    Err(e) => Err(format!("Failed to access clipboard: {}", e)),
-                }
+                copy_to_clipboard(&first_pkg.install_command)
             } else {
                 Err("No package detected".to_string())
             }
@@ -829,6 +1938,26 @@ impl App {
         }
     }
 
+    /// URL of whatever is selected in the current mode: the file for code
+    /// results, the repository for notifications, otherwise the repository
+    pub fn selected_url(&self) -> Option<String> {
+        match self.search_mode {
+            SearchMode::Code => self.selected_code_result().map(|r| r.file_url.clone()),
+            SearchMode::Notifications => self
+                .get_selected_notification()
+                .map(|n| n.repository.html_url.clone()),
+            _ => self.selected_repository().map(|r| r.url.clone()),
+        }
+    }
+
+    /// Copy the selected item's URL to the system clipboard
+    pub fn copy_selected_url(&self) -> Result<(), String> {
+        let url = self
+            .selected_url()
+            .ok_or_else(|| "Nothing selected".to_string())?;
+        copy_to_clipboard(&url)
+    }
+
     /// Open package registry in browser
     pub fn open_package_registry(&self) -> Result<(), String> {
         if let Some(packages) = self.get_cached_package_info() {
@@ -913,6 +2042,56 @@ impl App {
     pub fn set_code_results(&mut self, results: Vec<CodeSearchResult>) {
         self.code_results = results;
         self.code_selected_index = 0;
+
+        // Files already fetched for an earlier search give context straight away
+        for i in 0..self.code_results.len() {
+            let key = Self::code_file_key(&self.code_results[i]);
+            if let Some(file) = self.code_content_cache.get(&key).cloned() {
+                self.apply_code_file(i, &file);
+            }
+        }
+    }
+
+    /// Cache key for a code result's file (platform + repository + path)
+    pub fn code_file_key(result: &CodeSearchResult) -> String {
+        format!(
+            "{}:{}:{}",
+            result.platform.to_string().to_lowercase(),
+            result.repository,
+            result.file_path
+        )
+    }
+
+    /// The selected code result's file when it still has to be fetched
+    pub fn code_file_to_fetch(&self) -> Option<&CodeSearchResult> {
+        let result = self.selected_code_result()?;
+        let cached = self
+            .code_content_cache
+            .contains_key(&Self::code_file_key(result));
+        (!cached && !self.code_content_loading && self.code_context_lines > 0).then_some(result)
+    }
+
+    /// Cache a fetched file (`None` if it couldn't be fetched) and fill in
+    /// the context around the matches of every result from it
+    pub fn cache_code_file(&mut self, key: String, file: Option<String>) {
+        let file = file.unwrap_or_default();
+        for i in 0..self.code_results.len() {
+            if Self::code_file_key(&self.code_results[i]) == key {
+                self.apply_code_file(i, &file);
+            }
+        }
+        self.code_content_cache.insert(key, file);
+        self.code_content_loading = false;
+    }
+
+    /// Locate the matches of one code result in its file
+    fn apply_code_file(&mut self, index: usize, file: &str) {
+        let query = self.search_input.clone();
+        let terms = reposcout_core::query_terms(&query);
+        let context = self.code_context_lines;
+        for code_match in &mut self.code_results[index].matches {
+            reposcout_core::apply_file_context(code_match, file, &terms, context);
+        }
     }
 
     /// Scroll code preview down
@@ -1060,8 +2239,8 @@ impl App {
 
     /// Navigate trending options
     pub fn next_trending_option(&mut self) {
-        // Options: 0=Period, 1=Language, 2=MinStars, 3=Topic, 4=SortByVelocity
-        self.trending_option_cursor = (self.trending_option_cursor + 1).min(4);
+        // Options: 0=Period, 1=Language, 2=MinStars, 3=Topic, 4=SortByVelocity, 5=MinLanguagePercent
+        self.trending_option_cursor = (self.trending_option_cursor + 1).min(5);
     }
 
     pub fn previous_trending_option(&mut self) {
@@ -1089,6 +2268,17 @@ impl App {
         self.trending_filters.min_stars = self.trending_filters.min_stars.saturating_sub(50);
     }
 
+    /// Adjust the secondary-language share threshold for trending
+    pub fn increase_trending_language_percent(&mut self) {
+        self.trending_filters.min_language_percent =
+            (self.trending_filters.min_language_percent + 5).min(100);
+    }
+
+    pub fn decrease_trending_language_percent(&mut self) {
+        self.trending_filters.min_language_percent =
+            self.trending_filters.min_language_percent.saturating_sub(5);
+    }
+
     // Settings/Token management methods
 
     /// Toggle settings popup
@@ -1142,6 +2332,29 @@ impl App {
     }
 
     /// Cancel token input
+    /// Append pasted text to whichever input is being typed in. Line breaks
+    /// become spaces; tokens drop whitespace entirely.
+    pub fn paste(&mut self, text: &str) {
+        let text = single_line(text);
+        match self.input_mode {
+            InputMode::Searching => self.search_input.push_str(&text),
+            InputMode::EditingFilter => self.filter_edit_buffer.push_str(&text),
+            InputMode::Command => self.command_input.push_str(&text),
+            InputMode::TokenInput => self
+                .token_input_buffer
+                .extend(text.chars().filter(|c| !c.is_whitespace())),
+            _ => {}
+        }
+    }
+
+    /// Paste the clipboard contents into the active input (Ctrl+V)
+    pub fn paste_from_clipboard(&mut self) {
+        match read_clipboard() {
+            Ok(text) => self.paste(&text),
+            Err(e) => self.set_temp_error(e),
+        }
+    }
+
     pub fn cancel_token_input(&mut self) {
         self.token_input_buffer.clear();
         self.token_input_platform.clear();
@@ -1185,23 +2398,70 @@ impl App {
 
     /// Navigate to next notification
     pub fn next_notification(&mut self) {
-        if !self.notifications.is_empty() {
-            self.notifications_selected_index =
-                (self.notifications_selected_index + 1) % self.notifications.len();
+        let count = self.visible_notifications().len();
+        if count > 0 {
+            self.notifications_selected_index = (self.notifications_selected_index + 1) % count;
         }
     }
 
     /// Navigate to previous notification
     pub fn previous_notification(&mut self) {
-        if !self.notifications.is_empty() {
+        let count = self.visible_notifications().len();
+        if count > 0 {
             if self.notifications_selected_index > 0 {
                 self.notifications_selected_index -= 1;
             } else {
-                self.notifications_selected_index = self.notifications.len() - 1;
+                self.notifications_selected_index = count - 1;
             }
         }
     }
 
+    /// The reason and subject type filters, applied to the fetched list
+    pub fn notification_filters(&self) -> reposcout_core::NotificationFilters {
+        reposcout_core::NotificationFilters {
+            reason: self.notifications_reason,
+            subject_type: self.notifications_type.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    /// Fetched notifications that pass the reason and type filters
+    pub fn visible_notifications(&self) -> Vec<&reposcout_core::Notification> {
+        let filters = self.notification_filters();
+        self.notifications
+            .iter()
+            .filter(|n| filters.matches(n))
+            .collect()
+    }
+
+    /// Cycle the reason filter: off, then each reason in turn
+    pub fn cycle_notification_reason(&mut self) {
+        let reasons = reposcout_core::NotificationReason::ALL;
+        self.notifications_reason = match self.notifications_reason {
+            None => Some(reasons[0]),
+            Some(current) => reasons
+                .iter()
+                .position(|r| *r == current)
+                .and_then(|i| reasons.get(i + 1))
+                .copied(),
+        };
+        self.notifications_selected_index = 0;
+    }
+
+    /// Cycle the subject type filter: off, then each type in turn
+    pub fn cycle_notification_type(&mut self) {
+        let types = reposcout_core::NOTIFICATION_SUBJECT_TYPES;
+        self.notifications_type = match self.notifications_type {
+            None => Some(types[0]),
+            Some(current) => types
+                .iter()
+                .position(|t| *t == current)
+                .and_then(|i| types.get(i + 1))
+                .copied(),
+        };
+        self.notifications_selected_index = 0;
+    }
+
     /// Toggle showing all vs unread-only notifications
     pub fn toggle_notification_filter(&mut self) {
         self.notifications_show_all = !self.notifications_show_all;
@@ -1214,7 +2474,9 @@ impl App {
 
     /// Get currently selected notification
     pub fn get_selected_notification(&self) -> Option<&reposcout_core::Notification> {
-        self.notifications.get(self.notifications_selected_index)
+        self.visible_notifications()
+            .get(self.notifications_selected_index)
+            .copied()
     }
 
     // Theme management methods
@@ -1224,11 +2486,53 @@ impl App {
         self.current_theme = theme;
     }
 
+    /// Switch to a theme by name. An unknown name falls back to the default
+    /// theme and shows a warning instead; returns whether the name matched.
+    pub fn set_theme_by_name(&mut self, name: &str) -> bool {
+        match reposcout_core::Theme::by_name(name) {
+            Some(theme) => {
+                self.set_theme(theme);
+                true
+            }
+            None => {
+                let fallback = reposcout_core::Theme::default();
+                self.set_temp_error(format!("Unknown theme '{}', using {}", name, fallback.name));
+                self.set_theme(fallback);
+                false
+            }
+        }
+    }
+
     /// Get the current theme
     pub fn get_theme(&self) -> &reposcout_core::Theme {
         &self.current_theme
     }
 
+    /// Switch the code preview to a syntax theme by name. An unknown name
+    /// falls back to the default and shows a warning; returns whether the
+    /// name matched.
+    pub fn set_code_theme_by_name(&mut self, name: &str) -> bool {
+        if crate::syntax::code_theme_names().contains(&name) {
+            self.code_theme_name = name.to_string();
+            true
+        } else {
+            let fallback = crate::syntax::DEFAULT_CODE_THEME;
+            self.set_temp_error(format!("Unknown code theme '{}', using {}", name, fallback));
+            self.code_theme_name = fallback.to_string();
+            false
+        }
+    }
+
+    /// Cycle the code preview to the next syntax theme
+    pub fn next_code_theme(&mut self) {
+        let names = crate::syntax::code_theme_names();
+        let next = names
+            .iter()
+            .position(|name| *name == self.code_theme_name)
+            .map_or(0, |i| (i + 1) % names.len());
+        self.code_theme_name = names[next].to_string();
+    }
+
     /// Cycle to next theme
     pub fn next_theme(&mut self) {
         let themes = reposcout_core::Theme::all_themes();
@@ -1257,6 +2561,27 @@ impl App {
         }
     }
 
+    // Keybindings help methods
+
+    /// Open or close the keybindings help, starting from an empty search
+    pub fn toggle_keybindings_help(&mut self) {
+        self.show_keybindings_help = !self.show_keybindings_help;
+        self.help_filter.clear();
+        self.help_scroll = 0;
+    }
+
+    /// Append a character to the help search and jump back to the top
+    pub fn push_help_filter(&mut self, c: char) {
+        self.help_filter.push(c);
+        self.help_scroll = 0;
+    }
+
+    /// Remove the last character from the help search
+    pub fn pop_help_filter(&mut self) {
+        self.help_filter.pop();
+        self.help_scroll = 0;
+    }
+
     // Portfolio/Watchlist management methods
 
     /// Add current repository to a portfolio
@@ -1328,3 +2653,738 @@ impl Default for App {
         Self::new()
     }
 }
+
+/// Put text on the system clipboard
+fn copy_to_clipboard(text: &str) -> Result<(), String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| format!("Failed to access clipboard: {}", e))?;
+    clipboard
+        .set_text(text)
+        .map_err(|e| format!("Failed to copy to clipboard: {}", e))
+}
+
+/// Read text from the system clipboard
+fn read_clipboard() -> Result<String, String> {
+    let mut clipboard =
+        arboard::Clipboard::new().map_err(|e| format!("Failed to access clipboard: {}", e))?;
+    clipboard
+        .get_text()
+        .map_err(|e| format!("Failed to paste from clipboard: {}", e))
+}
+
+/// Pasted text as a single line, so a multi-line paste can't submit the input
+fn single_line(text: &str) -> String {
+    text.split(['\r', '\n'])
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Split comma-separated tags, dropping blanks and duplicates
+fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        if !tags.iter().any(|t| t == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo(platform: Platform, name: &str) -> Repository {
+        Repository::test_fixture(platform, name, 0)
+    }
+
+    #[test]
+    fn test_terminal_fits_minimum_size() {
+        let mut app = App::new();
+        assert!(app.terminal_fits(40, 10));
+        assert!(!app.terminal_fits(39, 10));
+        assert!(!app.terminal_fits(80, 9));
+
+        app.min_terminal_size = (100, 30);
+        assert!(!app.terminal_fits(80, 24));
+    }
+
+    #[test]
+    fn test_scrolling_to_the_end_loads_more() {
+        let mut app = App::new();
+        app.set_search_results(vec![
+            repo(Platform::GitHub, "a/one"),
+            repo(Platform::GitHub, "a/two"),
+        ]);
+
+        app.next_result();
+        assert!(app.loading_more);
+        assert_eq!(
+            app.append_more_results(vec![
+                repo(Platform::GitHub, "a/two"),
+                repo(Platform::GitHub, "a/three"),
+            ]),
+            1
+        );
+        assert!(!app.loading_more);
+        assert_eq!(app.selected_index, 1);
+
+        app.next_result();
+        assert!(app.loading_more);
+        app.append_more_results(Vec::new());
+        assert!(!app.more_results);
+        app.next_result();
+        assert!(!app.loading_more);
+
+        // Results that didn't come from a search have no pages to load
+        app.set_search_results(vec![repo(Platform::GitHub, "b/one")]);
+        assert!(app.more_results);
+        app.set_results(vec![repo(Platform::GitHub, "b/one")]);
+        assert!(!app.more_results);
+    }
+
+    #[test]
+    fn test_new_results_reload_star_history() {
+        let mut app = App::new();
+        app.set_results(vec![repo(Platform::GitHub, "a/one")]);
+        let snapshot = reposcout_cache::StarSnapshot {
+            recorded_at: 0,
+            stars: 10,
+        };
+        app.cache_star_history(Platform::GitHub, "a/one", vec![snapshot]);
+        assert!(app.get_star_history().is_some());
+
+        // Searching again recorded a fresh count, so the old one goes
+        app.set_results(vec![repo(Platform::GitHub, "a/one")]);
+        assert!(app.get_star_history().is_none());
+    }
+
+    #[test]
+    fn test_append_results_skips_duplicates() {
+        let mut app = App::new();
+        app.set_results(vec![
+            repo(Platform::GitHub, "a/one"),
+            repo(Platform::GitHub, "a/two"),
+        ]);
+        app.next_result();
+
+        let added = app.append_results(vec![
+            repo(Platform::GitHub, "a/two"),
+            repo(Platform::GitLab, "a/two"),
+            repo(Platform::GitHub, "a/three"),
+            repo(Platform::GitHub, "a/three"),
+        ]);
+
+        assert_eq!(added, 2);
+        let names: Vec<_> = app
+            .results
+            .iter()
+            .map(|r| (r.platform, r.full_name.as_str()))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                (Platform::GitHub, "a/one"),
+                (Platform::GitHub, "a/two"),
+                (Platform::GitLab, "a/two"),
+                (Platform::GitHub, "a/three"),
+            ]
+        );
+        // Selection stays on the same repository
+        assert_eq!(app.selected_repository().unwrap().full_name, "a/two");
+    }
+
+    #[test]
+    fn test_result_index_at() {
+        let mut app = App::new();
+        app.set_results(vec![
+            repo(Platform::GitHub, "a/one"),
+            repo(Platform::GitHub, "a/two"),
+        ]);
+        app.results_area = Some(ratatui::layout::Rect::new(0, 5, 40, 20));
+
+        // Rows 6-8 are the first entry, 9-11 the second, below that is empty
+        assert_eq!(app.result_index_at(3, 6), Some(0));
+        assert_eq!(app.result_index_at(3, 8), Some(0));
+        assert_eq!(app.result_index_at(3, 9), Some(1));
+        assert_eq!(app.result_index_at(3, 12), None);
+        // Borders and outside the list
+        assert_eq!(app.result_index_at(3, 5), None);
+        assert_eq!(app.result_index_at(0, 6), None);
+        assert_eq!(app.result_index_at(45, 6), None);
+    }
+
+    #[test]
+    fn test_cache_languages_is_per_platform() {
+        let mut app = App::new();
+        app.set_results(vec![
+            repo(Platform::GitHub, "a/one"),
+            repo(Platform::GitLab, "a/one"),
+        ]);
+        assert!(app.get_cached_languages().is_none());
+
+        let bytes = std::collections::HashMap::from([("Rust".to_string(), 100)]);
+        app.cache_languages(Platform::GitHub, "a/one", Some(bytes));
+        assert_eq!(app.get_cached_languages().unwrap().unwrap()["Rust"], 100);
+        assert!(app.results[1].languages.is_none());
+
+        app.next_result();
+        assert!(app.get_cached_languages().is_none());
+        app.cache_languages(Platform::GitLab, "a/one", None);
+        assert_eq!(app.get_cached_languages(), Some(None));
+    }
+
+    #[test]
+    fn test_reviewed_dims_or_hides() {
+        let mut app = App::new();
+        app.set_results(vec![
+            repo(Platform::GitHub, "a/one"),
+            repo(Platform::GitHub, "a/two"),
+        ]);
+        assert_eq!(app.toggle_current_reviewed(), Some(true));
+        assert!(app.is_reviewed(&app.results[0]));
+        assert_eq!(app.results.len(), 2);
+        assert_eq!(app.toggle_current_reviewed(), Some(false));
+        assert!(!app.is_reviewed(&app.results[0]));
+
+        // Hidden reviewed repositories leave the list and stay out of new results
+        app.filters.hide_reviewed = true;
+        app.next_result();
+        assert_eq!(app.toggle_current_reviewed(), Some(true));
+        assert_eq!(app.results.len(), 1);
+        assert_eq!(app.selected_index, 0);
+        app.set_results(vec![
+            repo(Platform::GitHub, "a/one"),
+            repo(Platform::GitHub, "a/two"),
+        ]);
+        assert_eq!(app.results.len(), 1);
+        assert_eq!(app.append_results(vec![repo(Platform::GitHub, "a/two")]), 0);
+    }
+
+    #[test]
+    fn test_cache_activity_attaches_to_results() {
+        let mut app = App::new();
+        app.set_results(vec![
+            repo(Platform::GitHub, "a/one"),
+            repo(Platform::GitHub, "a/two"),
+        ]);
+        assert!(app.get_cached_activity().is_none());
+
+        app.activity_loading = true;
+        let stats = crate::tasks::ActivityStats {
+            contributors: Some(42),
+            commit_activity: Some(vec![[1, 2, 3, 4, 5, 6, 7]]),
+            stats_pending: false,
+        };
+        app.cache_activity(Platform::GitHub, "a/one", stats);
+        assert!(!app.activity_loading);
+        assert_eq!(app.results[0].contributors, Some(42));
+        assert_eq!(app.get_cached_activity().unwrap().contributors, Some(42));
+
+        app.next_result();
+        assert!(app.get_cached_activity().is_none());
+    }
+
+    #[test]
+    fn test_command_line() {
+        let mut app = App::new();
+        let mut forked = repo(Platform::GitHub, "a/forked");
+        forked.forks = 10;
+        app.set_results(vec![
+            repo(Platform::GitHub, "a/one"),
+            forked,
+            repo(Platform::GitHub, "a/two"),
+        ]);
+
+        app.enter_command_mode();
+        app.command_input = "sort forks".to_string();
+        assert_eq!(
+            app.submit_command(),
+            Some(Command::Sort("forks".to_string()))
+        );
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.results[0].full_name, "a/forked");
+
+        app.command_input = "limit 2".to_string();
+        app.submit_command();
+        assert_eq!(app.results.len(), 2);
+        app.append_results(vec![repo(Platform::GitLab, "a/three")]);
+        assert_eq!(app.results.len(), 2);
+        // Raising the limit brings back rows the lower one hid
+        app.command_input = "limit 50".to_string();
+        app.submit_command();
+        assert_eq!(app.results.len(), 4);
+        assert_eq!(app.results[0].full_name, "a/forked");
+
+        app.command_input = "theme no-such-theme".to_string();
+        assert_eq!(app.submit_command(), None);
+        assert_eq!(
+            app.error_message.as_deref(),
+            Some("Unknown theme 'no-such-theme'")
+        );
+    }
+
+    #[test]
+    fn test_code_theme() {
+        let mut app = App::new();
+        assert_eq!(app.code_theme_name, crate::syntax::DEFAULT_CODE_THEME);
+
+        let names = crate::syntax::code_theme_names();
+        for _ in 0..names.len() {
+            let before = app.code_theme_name.clone();
+            app.next_code_theme();
+            assert_ne!(app.code_theme_name, before);
+        }
+        // A full cycle comes back around
+        assert_eq!(app.code_theme_name, crate::syntax::DEFAULT_CODE_THEME);
+
+        assert!(app.set_code_theme_by_name("Solarized (dark)"));
+        assert_eq!(app.code_theme_name, "Solarized (dark)");
+        assert!(!app.set_code_theme_by_name("no-such-theme"));
+        assert_eq!(app.code_theme_name, crate::syntax::DEFAULT_CODE_THEME);
+    }
+
+    #[test]
+    fn test_grouped_results() {
+        let mut app = App::new();
+        let starred = |platform, name: &str, stars| Repository {
+            stars,
+            ..repo(platform, name)
+        };
+        app.set_results(vec![
+            starred(Platform::GitHub, "a/one", 30),
+            starred(Platform::GitLab, "b/two", 20),
+            starred(Platform::GitHub, "c/three", 10),
+        ]);
+        app.select_result(1);
+
+        app.cycle_grouping();
+        assert_eq!(app.group_by, Some(GroupBy::Platform));
+        let names: Vec<_> = app.results.iter().map(|r| r.full_name.as_str()).collect();
+        assert_eq!(names, ["a/one", "c/three", "b/two"]);
+        assert_eq!(app.selected_repository().unwrap().full_name, "b/two");
+        assert_eq!(
+            app.result_rows(),
+            [
+                ResultRow::Header {
+                    name: "GitHub".to_string(),
+                    count: 2
+                },
+                ResultRow::Result(0),
+                ResultRow::Result(1),
+                ResultRow::Header {
+                    name: "GitLab".to_string(),
+                    count: 1
+                },
+                ResultRow::Result(2),
+            ]
+        );
+
+        // Later pages join their group
+        app.append_results(vec![starred(Platform::GitHub, "d/four", 5)]);
+        assert_eq!(app.results[2].full_name, "d/four");
+        assert_eq!(app.selected_repository().unwrap().full_name, "b/two");
+
+        app.set_grouping(None);
+        assert_eq!(app.results[1].full_name, "b/two");
+        assert_eq!(app.selected_index, 1);
+    }
+
+    #[test]
+    fn test_rerun_query_applies_current_filters() {
+        let mut app = App::new();
+        assert_eq!(app.rerun_query(), None);
+
+        app.last_query = "terminal ui".to_string();
+        app.search_input = "edited but not run".to_string();
+        app.filters.language = Some("rust".to_string());
+        app.filters.min_stars = Some(500);
+        assert_eq!(
+            app.rerun_query().as_deref(),
+            Some("terminal ui language:rust stars:>=500")
+        );
+        assert_eq!(app.search_input, "terminal ui");
+    }
+
+    #[test]
+    fn test_exclude_forks_and_archived() {
+        let mut app = App::new();
+        app.filters.exclude_forks = true;
+        app.filters.exclude_archived = true;
+        assert_eq!(
+            app.filters.build_query("tui"),
+            "tui fork:false archived:false"
+        );
+
+        let mut fork = repo(Platform::GitLab, "a/fork");
+        fork.is_fork = true;
+        let mut archived = repo(Platform::Bitbucket, "a/old");
+        archived.is_archived = true;
+        app.set_results(vec![repo(Platform::GitLab, "a/one"), fork.clone()]);
+        app.append_results(vec![archived, fork, repo(Platform::GitHub, "a/two")]);
+
+        let names: Vec<_> = app.results.iter().map(|r| r.full_name.as_str()).collect();
+        assert_eq!(names, vec!["a/one", "a/two"]);
+    }
+
+    #[test]
+    fn test_paste_goes_to_active_input_on_one_line() {
+        let mut app = App::new();
+        app.input_mode = InputMode::Searching;
+        app.search_input = "rust ".to_string();
+        app.paste("terminal\r\nui\n");
+        assert_eq!(app.search_input, "rust terminal ui");
+
+        app.input_mode = InputMode::TokenInput;
+        app.paste(" ghp_abc\n123 \n");
+        assert_eq!(app.token_input_buffer, "ghp_abc123");
+
+        app.input_mode = InputMode::Normal;
+        app.paste("ignored");
+        assert_eq!(app.search_input, "rust terminal ui");
+    }
+
+    #[test]
+    fn test_min_health_keeps_unscored_repositories() {
+        let mut app = App::new();
+        app.filters.min_health = Some(101);
+
+        let scored = repo(Platform::GitHub, "a/scored");
+        let unscored = repo(Platform::SourceHut, "~a/unscored");
+        assert!(!app.filters.allows(&scored));
+        assert!(app.filters.allows(&unscored));
+
+        app.filter_cursor = 5;
+        app.filter_edit_buffer = "250".to_string();
+        app.save_filter_edit();
+        assert_eq!(app.filters.min_health, Some(100));
+        app.filter_edit_buffer = "70".to_string();
+        app.save_filter_edit();
+        assert_eq!(app.filters.min_health, Some(70));
+        assert!(!app.is_toggle_filter());
+    }
+
+    #[test]
+    fn test_fuzzy_filter_matches_description_and_language() {
+        let mut app = App::new();
+        let mut by_desc = repo(Platform::GitHub, "a/widgets");
+        by_desc.description = Some("A terminal toolkit".to_string());
+        let mut by_lang = repo(Platform::GitHub, "b/widgets");
+        by_lang.language = Some("Rust".to_string());
+        let by_name = repo(Platform::GitHub, "c/rusty-terminal");
+        app.set_results(vec![by_desc, by_lang, by_name]);
+        app.enter_fuzzy_mode();
+
+        app.fuzzy_input = "terminal".to_string();
+        app.apply_fuzzy_filter();
+        let names: Vec<_> = app.results.iter().map(|r| r.full_name.as_str()).collect();
+        // Name matches are weighted above description matches
+        assert_eq!(names, vec!["c/rusty-terminal", "a/widgets"]);
+        assert_eq!(
+            app.fuzzy_matched_field(&app.results[1]),
+            Some(FuzzyField::Description)
+        );
+
+        app.fuzzy_input = "rust".to_string();
+        app.apply_fuzzy_filter();
+        assert_eq!(app.fuzzy_match_count, 2);
+        assert_eq!(
+            app.fuzzy_matched_field(&app.results[1]),
+            Some(FuzzyField::Language)
+        );
+
+        // Another page only shows what matches the query
+        app.append_results(vec![
+            repo(Platform::GitHub, "d/trusty"),
+            repo(Platform::GitHub, "e/gadgets"),
+        ]);
+        let names: Vec<_> = app.results.iter().map(|r| r.full_name.as_str()).collect();
+        assert_eq!(names, vec!["c/rusty-terminal", "b/widgets", "d/trusty"]);
+        assert_eq!(app.fuzzy_match_count, 3);
+        assert_eq!(app.all_results.len(), 5);
+    }
+
+    #[test]
+    fn test_bookmark_tag_edit() {
+        let mut app = App::new();
+        app.set_results(vec![repo(Platform::GitHub, "a/one")]);
+        app.enter_normal_mode();
+
+        // Not bookmarked: the editor doesn't open
+        app.start_bookmark_edit(BookmarkField::Tags);
+        assert_eq!(app.input_mode, InputMode::Normal);
+
+        app.toggle_current_bookmark();
+        app.set_bookmark_metadata("github:a/one".to_string(), Some("cli,rust"), Some("later"));
+        app.start_bookmark_edit(BookmarkField::Tags);
+        assert_eq!(app.input_mode, InputMode::BookmarkEdit);
+        assert_eq!(app.bookmark_edit_buffer, "cli, rust");
+
+        app.bookmark_edit_buffer = " tui, cli,,tui ".to_string();
+        let saved = app.finish_bookmark_edit();
+        assert_eq!(
+            saved,
+            Some((
+                "github".to_string(),
+                "a/one".to_string(),
+                Some("tui,cli".to_string())
+            ))
+        );
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert_eq!(app.tags_for(&app.results[0]), ["tui", "cli"]);
+        assert_eq!(
+            app.bookmark_notes.get("github:a/one").map(String::as_str),
+            Some("later")
+        );
+
+        app.start_bookmark_edit(BookmarkField::Notes);
+        assert_eq!(app.bookmark_edit_buffer, "later");
+        app.bookmark_edit_buffer.clear();
+        let saved = app.finish_bookmark_edit();
+        assert_eq!(saved.unwrap().2, None);
+        assert!(app.bookmark_notes.is_empty());
+        assert_eq!(app.tags_for(&app.results[0]).len(), 2);
+    }
+
+    #[test]
+    fn test_live_search_debounce() {
+        use std::time::{Duration, Instant};
+
+        let mut app = App::new();
+        app.enter_search_mode();
+        app.search_input = "ratatui".to_string();
+        let start = Instant::now();
+        assert_eq!(app.poll_live_search(start), None, "live search is opt-in");
+
+        app.live_search = true;
+        assert_eq!(app.poll_live_search(start), None);
+        assert!(app.live_search_pending());
+        assert_eq!(
+            app.poll_live_search(start + Duration::from_millis(200)),
+            None
+        );
+
+        // Another keystroke restarts the wait
+        app.search_input.push('s');
+        let typed = start + Duration::from_millis(300);
+        assert_eq!(app.poll_live_search(typed), None);
+        assert_eq!(
+            app.poll_live_search(typed + Duration::from_millis(350)),
+            None
+        );
+        assert_eq!(
+            app.poll_live_search(typed + LIVE_SEARCH_DEBOUNCE),
+            Some(app.get_search_query())
+        );
+
+        // Runs once per text, so a cancelled search isn't started again
+        let later = typed + Duration::from_secs(5);
+        assert_eq!(app.poll_live_search(later), None);
+        assert!(!app.live_search_pending());
+    }
+
+    #[test]
+    fn test_readme_footnote_digits() {
+        use std::time::{Duration, Instant};
+
+        let mut app = App::new();
+        let links: String = (1..=12)
+            .map(|n| format!("[link {0}](https://example.com/{0})\n", n))
+            .collect();
+        app.set_readme(links);
+        let now = Instant::now();
+
+        // "2" can't start a two-digit footnote out of 12, so it opens at once
+        assert_eq!(
+            app.push_footnote_digit(2, now).as_deref(),
+            Some("https://example.com/2")
+        );
+
+        // "1" waits for a second digit
+        assert_eq!(app.push_footnote_digit(1, now), None);
+        assert_eq!(
+            app.push_footnote_digit(2, now).as_deref(),
+            Some("https://example.com/12")
+        );
+
+        // ... and opens on its own after the timeout
+        assert_eq!(app.push_footnote_digit(1, now), None);
+        assert_eq!(app.take_due_footnote(now), None);
+        assert_eq!(
+            app.take_due_footnote(now + Duration::from_secs(1))
+                .as_deref(),
+            Some("https://example.com/1")
+        );
+
+        assert_eq!(app.push_footnote_digit(0, now), None);
+        assert!(app.pending_footnote.is_none());
+    }
+
+    #[test]
+    fn test_readme_prefetch_candidates() {
+        let mut app = App::new();
+        app.set_results(vec![
+            repo(Platform::GitHub, "a/one"),
+            repo(Platform::GitHub, "a/two"),
+            repo(Platform::GitHub, "a/three"),
+            repo(Platform::GitHub, "a/four"),
+        ]);
+        app.select_result(2);
+        assert_eq!(
+            app.readme_prefetch_candidates(1),
+            vec!["a/three", "a/two", "a/four"]
+        );
+
+        app.cache_readme("a/two".to_string(), String::new());
+        app.select_result(0);
+        assert_eq!(app.readme_prefetch_candidates(1), vec!["a/one"]);
+    }
+
+    #[test]
+    fn test_jump_and_half_page_navigation() {
+        let mut app = App::new();
+        app.set_results(
+            (0..30)
+                .map(|i| repo(Platform::GitHub, &format!("owner/repo{}", i)))
+                .collect(),
+        );
+        // 18 rows inside the border fit 6 results, so half a page is 3
+        app.results_area = Some(ratatui::layout::Rect::new(0, 0, 40, 20));
+
+        app.jump_to_bottom();
+        assert_eq!(app.selected_index, 29);
+        assert_eq!(app.list_state.selected(), Some(29));
+        app.half_page_down();
+        assert_eq!(app.selected_index, 29);
+        app.half_page_up();
+        assert_eq!(app.selected_index, 26);
+        app.jump_to_top();
+        assert_eq!(app.selected_index, 0);
+        app.half_page_up();
+        assert_eq!(app.selected_index, 0);
+
+        // In README preview the same keys scroll the document instead
+        app.preview_mode = PreviewMode::Readme;
+        app.document_rows = 100;
+        app.preview_height = 20;
+        app.half_page_down();
+        assert_eq!(app.readme_scroll, 10);
+        app.jump_to_bottom();
+        assert_eq!(app.readme_scroll, 80);
+        app.half_page_down();
+        assert_eq!(app.readme_scroll, 80);
+        app.jump_to_top();
+        assert_eq!(app.readme_scroll, 0);
+        assert_eq!(app.selected_index, 0);
+    }
+
+    #[test]
+    fn test_selected_url_follows_search_mode() {
+        let mut app = App::new();
+        assert_eq!(app.selected_url(), None);
+
+        let mut selected = repo(Platform::GitHub, "a/one");
+        selected.url = "https://github.com/a/one".to_string();
+        app.set_results(vec![selected]);
+        assert_eq!(
+            app.selected_url().as_deref(),
+            Some("https://github.com/a/one")
+        );
+
+        app.search_mode = SearchMode::Code;
+        app.code_results = vec![CodeSearchResult {
+            platform: Platform::GitHub,
+            repository: "a/one".to_string(),
+            file_path: "src/main.rs".to_string(),
+            language: None,
+            file_url: "https://github.com/a/one/blob/main/src/main.rs".to_string(),
+            repository_url: "https://github.com/a/one".to_string(),
+            matches: Vec::new(),
+            repository_stars: 0,
+        }];
+        assert_eq!(
+            app.selected_url().as_deref(),
+            Some("https://github.com/a/one/blob/main/src/main.rs")
+        );
+    }
+
+    #[test]
+    fn test_code_file_fills_match_context() {
+        use reposcout_core::models::CodeMatch;
+
+        let mut app = App::new();
+        app.search_input = "parse".to_string();
+        app.code_context_lines = 1;
+        let result = CodeSearchResult {
+            platform: Platform::GitHub,
+            repository: "a/one".to_string(),
+            file_path: "src/main.rs".to_string(),
+            language: None,
+            file_url: String::new(),
+            repository_url: String::new(),
+            matches: vec![CodeMatch {
+                content: "let x = parse();".to_string(),
+                line_number: 1,
+                context_before: vec![],
+                context_after: vec![],
+            }],
+            repository_stars: 0,
+        };
+        app.set_code_results(vec![result.clone()]);
+        let key = App::code_file_key(app.code_file_to_fetch().unwrap());
+
+        app.code_content_loading = true;
+        app.cache_code_file(
+            key,
+            Some("fn main() {\n    let x = parse();\n}".to_string()),
+        );
+        assert!(app.code_file_to_fetch().is_none());
+        let code_match = &app.code_results[0].matches[0];
+        assert_eq!(code_match.line_number, 2);
+        assert_eq!(code_match.context_before, vec!["fn main() {"]);
+        assert_eq!(code_match.context_after, vec!["}"]);
+
+        // A new search over the same file uses the cached copy
+        app.set_code_results(vec![result]);
+        assert!(app.code_file_to_fetch().is_none());
+        assert_eq!(app.code_results[0].matches[0].line_number, 2);
+    }
+
+    #[test]
+    fn test_spinner_advances_only_while_loading() {
+        let mut app = App::new();
+        app.tick_spinner();
+        assert_eq!(app.spinner_frame, 0);
+
+        app.loading = true;
+        let first = app.spinner();
+        app.tick_spinner();
+        assert_ne!(app.spinner(), first);
+        app.tick_spinner();
+        assert_eq!(app.spinner_frame, 2);
+
+        app.loading = false;
+        app.tick_spinner();
+        assert_eq!(app.spinner_frame, 0);
+        assert_eq!(app.spinner(), first);
+    }
+
+    #[test]
+    fn test_unknown_theme_falls_back_to_default() {
+        let mut app = App::new();
+        assert!(app.set_theme_by_name("nord"));
+        assert_eq!(app.current_theme.name, "Nord");
+        assert!(app.error_message.is_none());
+
+        assert!(!app.set_theme_by_name("No Such Theme"));
+        assert_eq!(
+            app.current_theme.name,
+            reposcout_core::Theme::default().name
+        );
+        assert!(app
+            .error_message
+            .as_deref()
+            .is_some_and(|e| e.contains("No Such Theme")));
+    }
+}
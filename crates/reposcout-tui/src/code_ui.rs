@@ -1,5 +1,5 @@
 // Enhanced UI rendering for code search
-use crate::{App, CodePreviewMode, InputMode};
+use crate::{syntax, App, CodePreviewMode, InputMode};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -8,8 +8,7 @@ use ratatui::{
     Frame,
 };
 use syntect::easy::HighlightLines;
-use syntect::highlighting::{Style as SyntectStyle, ThemeSet};
-use syntect::parsing::SyntaxSet;
+use syntect::highlighting::Style as SyntectStyle;
 use syntect::util::LinesWithEndings;
 
 /// Format large numbers with commas
@@ -130,6 +129,8 @@ pub fn render_code_results_list(frame: &mut Frame, app: &App, area: Rect) {
                 reposcout_core::models::Platform::GitHub => Color::Rgb(255, 165, 0), // Orange
                 reposcout_core::models::Platform::GitLab => Color::Rgb(252, 109, 38), // GitLab orange
                 reposcout_core::models::Platform::Bitbucket => Color::Rgb(33, 136, 255), // Blue
+                reposcout_core::models::Platform::SourceHut => Color::Rgb(170, 170, 170), // Gray
+                reposcout_core::models::Platform::AzureDevOps => Color::Rgb(0, 120, 212), // Azure blue
             };
 
             // Line 1: Index + File path (with icon)
@@ -523,11 +524,26 @@ fn render_code_tab(
         ]));
         preview_lines.push(Line::from(""));
 
-        // Syntax-highlighted code with line numbers
+        // Syntax-highlighted code with line numbers, inside its context lines
+        // once the file has been fetched
+        let first_line = code_match
+            .line_number
+            .saturating_sub(code_match.context_before.len());
+        let matched_lines = code_match.content.lines().count().max(1);
+        let code = code_match
+            .context_before
+            .iter()
+            .map(String::as_str)
+            .chain(code_match.content.lines())
+            .chain(code_match.context_after.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join("\n");
         let highlighted = highlight_code_with_line_numbers(
-            &code_match.content,
+            &code,
             result.language.as_deref(),
-            code_match.line_number,
+            &app.code_theme_name,
+            first_line,
+            code_match.line_number..code_match.line_number + matched_lines,
         );
         preview_lines.extend(highlighted);
         preview_lines.push(Line::from(""));
@@ -538,7 +554,11 @@ fn render_code_tab(
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title(" Code (Syntax Highlighted) ")
+                .title(if app.code_content_loading {
+                    " Code (Syntax Highlighted, fetching context...) "
+                } else {
+                    " Code (Syntax Highlighted) "
+                })
                 .title_style(
                     Style::default()
                         .fg(Color::Green)
@@ -746,38 +766,36 @@ fn render_file_info_tab(
     frame.render_widget(paragraph, area);
 }
 
-/// Syntax highlight code with line numbers
+/// Syntax highlight code with line numbers, picking out the `matched` ones
 fn highlight_code_with_line_numbers(
     code: &str,
     language: Option<&str>,
+    theme_name: &str,
     start_line: usize,
+    matched: std::ops::Range<usize>,
 ) -> Vec<Line<'static>> {
-    let ps = SyntaxSet::load_defaults_newlines();
-    let ts = ThemeSet::load_defaults();
-    let theme = &ts.themes["base16-ocean.dark"];
-
-    let syntax = if let Some(lang) = language {
-        ps.find_syntax_by_name(lang)
-            .or_else(|| ps.find_syntax_by_extension(lang))
-            .unwrap_or_else(|| ps.find_syntax_plain_text())
-    } else {
-        ps.find_syntax_plain_text()
-    };
-
-    let mut highlighter = HighlightLines::new(syntax, theme);
+    let ps = syntax::syntax_set();
+    let mut highlighter = HighlightLines::new(
+        syntax::find_syntax(language),
+        syntax::code_theme(theme_name),
+    );
     let mut result_lines = Vec::new();
 
     for (line_idx, line) in LinesWithEndings::from(code).enumerate() {
         let line_number = start_line + line_idx;
         let ranges: Vec<(SyntectStyle, &str)> =
-            highlighter.highlight_line(line, &ps).unwrap_or_default();
+            highlighter.highlight_line(line, ps).unwrap_or_default();
 
+        let number_style = if matched.contains(&line_number) {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
         let mut spans = vec![
             // Line number
-            Span::styled(
-                format!("{:>4} │ ", line_number),
-                Style::default().fg(Color::DarkGray),
-            ),
+            Span::styled(format!("{:>4} │ ", line_number), number_style),
         ];
 
         // Highlighted code
@@ -0,0 +1,139 @@
+// `:` command line
+//
+// Vim-style commands typed at the bottom of the screen. Each one maps onto
+// something a keybinding or popup can already do, so power users can reach
+// it without remembering the key.
+
+use reposcout_core::GroupBy;
+
+/// Sort orders `:sort` accepts, same as the filter panel
+pub const SORT_ORDERS: &[&str] = &["stars", "forks", "updated", "trending-score"];
+
+/// A parsed command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    /// `:sort <order>`, re-sorts the current results
+    Sort(String),
+    /// `:lang [language]`, language filter for the next search (none clears it)
+    Language(Option<String>),
+    /// `:limit [n]`, show at most n results (none or 0 removes the limit)
+    Limit(Option<usize>),
+    /// `:export <path>`, write the current results (format from the extension)
+    Export(String),
+    /// `:theme <name>`
+    Theme(String),
+    /// `:group platform|language|off`, show results in sections
+    Group(Option<GroupBy>),
+    /// `:live [on|off]`, search as you type (no argument toggles)
+    Live(Option<bool>),
+    /// `:q` / `:quit`
+    Quit,
+}
+
+/// Names for the help overlay and error messages
+pub const COMMAND_NAMES: &[&str] = &[
+    "sort", "lang", "limit", "export", "theme", "group", "live", "quit",
+];
+
+impl Command {
+    /// Parse a command line, without the leading `:`
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let input = input.trim().trim_start_matches(':');
+        let (name, arg) = match input.split_once(char::is_whitespace) {
+            Some((name, arg)) => (name, arg.trim()),
+            None => (input, ""),
+        };
+        let arg = (!arg.is_empty()).then(|| arg.to_string());
+
+        match name {
+            "sort" => {
+                let order = arg.ok_or("Usage: :sort stars|forks|updated|trending-score")?;
+                let order = order.to_lowercase();
+                if SORT_ORDERS.contains(&order.as_str()) {
+                    Ok(Command::Sort(order))
+                } else {
+                    Err(format!(
+                        "Unknown sort '{}', use one of: {}",
+                        order,
+                        SORT_ORDERS.join(", ")
+                    ))
+                }
+            }
+            "lang" | "language" => Ok(Command::Language(arg)),
+            "limit" => match arg {
+                None => Ok(Command::Limit(None)),
+                Some(n) => match n.parse::<usize>() {
+                    Ok(0) => Ok(Command::Limit(None)),
+                    Ok(n) => Ok(Command::Limit(Some(n))),
+                    Err(_) => Err(format!("Not a number: '{}'", n)),
+                },
+            },
+            "export" => arg
+                .map(Command::Export)
+                .ok_or_else(|| "Usage: :export <file.json|csv|md>".to_string()),
+            "theme" => arg
+                .map(Command::Theme)
+                .ok_or_else(|| "Usage: :theme <name>".to_string()),
+            "group" => match arg.as_deref() {
+                None | Some("off") | Some("none") => Ok(Command::Group(None)),
+                Some(by) => by.parse().map(|by| Command::Group(Some(by))),
+            },
+            "live" => match arg.as_deref() {
+                None => Ok(Command::Live(None)),
+                Some("on") => Ok(Command::Live(Some(true))),
+                Some("off") => Ok(Command::Live(Some(false))),
+                Some(other) => Err(format!("Usage: :live [on|off], not '{}'", other)),
+            },
+            "q" | "quit" => Ok(Command::Quit),
+            "" => Err("Empty command".to_string()),
+            other => Err(format!(
+                "Unknown command ':{}' (try {})",
+                other,
+                COMMAND_NAMES.join(", ")
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(
+            Command::parse("sort Forks"),
+            Ok(Command::Sort("forks".to_string()))
+        );
+        assert_eq!(
+            Command::parse(":lang rust"),
+            Ok(Command::Language(Some("rust".to_string())))
+        );
+        assert_eq!(Command::parse("lang"), Ok(Command::Language(None)));
+        assert_eq!(Command::parse("limit 50"), Ok(Command::Limit(Some(50))));
+        assert_eq!(Command::parse("limit 0"), Ok(Command::Limit(None)));
+        assert_eq!(
+            Command::parse("export  my results.md "),
+            Ok(Command::Export("my results.md".to_string()))
+        );
+        assert_eq!(
+            Command::parse("theme Nord"),
+            Ok(Command::Theme("Nord".to_string()))
+        );
+        assert_eq!(
+            Command::parse("group language"),
+            Ok(Command::Group(Some(GroupBy::Language)))
+        );
+        assert_eq!(Command::parse("group off"), Ok(Command::Group(None)));
+        assert_eq!(Command::parse("live on"), Ok(Command::Live(Some(true))));
+        assert_eq!(Command::parse("q"), Ok(Command::Quit));
+
+        assert!(Command::parse("sort size").is_err());
+        assert!(Command::parse("limit many").is_err());
+        assert!(Command::parse("export").is_err());
+        assert!(Command::parse("group owner").is_err());
+        assert!(Command::parse("frobnicate")
+            .unwrap_err()
+            .contains("Unknown command ':frobnicate'"));
+    }
+}
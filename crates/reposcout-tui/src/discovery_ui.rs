@@ -211,18 +211,23 @@ fn render_hidden_gems(frame: &mut Frame, _app: &App, area: Rect) {
 }
 
 fn render_topics(frame: &mut Frame, app: &App, area: Rect) {
-    let topics = reposcout_core::discovery::popular_topics();
+    let topics = app.topic_suggestions.entries();
+    let heading = if app.topic_suggestions.is_active() {
+        format!("🏷️  Topics related to '{}'", app.topic_suggestions.seed)
+    } else {
+        "🏷️  Popular Topics".to_string()
+    };
 
     let mut items: Vec<ListItem> = vec![ListItem::new(vec![
         Line::from(vec![Span::styled(
-            "🏷️  Popular Topics",
+            heading,
             Style::default()
                 .fg(Color::Cyan)
                 .add_modifier(Modifier::BOLD),
         )]),
         Line::from(""),
         Line::from(vec![Span::styled(
-            "Navigate with j/k, press ENTER to explore",
+            "Navigate with j/k, press ENTER to explore, / to type a topic seed",
             Style::default().fg(Color::Gray),
         )]),
         Line::from(""),
@@ -240,11 +245,16 @@ fn render_topics(frame: &mut Frame, app: &App, area: Rect) {
         };
 
         let indicator = if is_selected { "▶ " } else { "  " };
+        // Suggested topics are their own names, so show how common they are instead
+        let detail = match app.topic_suggestions.topics.get(i) {
+            Some(suggestion) => format!("({} repos)", suggestion.repos),
+            None => format!("({})", topic),
+        };
 
         items.push(ListItem::new(vec![Line::from(vec![
             Span::styled(format!("{}{}", indicator, name), style),
             Span::raw(" "),
-            Span::styled(format!("({})", topic), Style::default().fg(Color::DarkGray)),
+            Span::styled(detail, Style::default().fg(Color::DarkGray)),
         ])]));
     }
 
@@ -1,4 +1,6 @@
-use crate::App;
+use crate::{App, SearchMode};
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -7,8 +9,250 @@ use ratatui::{
     Frame,
 };
 
+/// Where a group of keybindings applies
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpContext {
+    Always,           // Works everywhere
+    Mode(SearchMode), // Only in a specific search mode
+    Popup,            // Inside a popup or edit mode
+}
+
+/// A single keybinding entry
+#[derive(Debug, Clone, Copy)]
+pub struct KeyBinding {
+    pub keys: &'static str,
+    pub description: &'static str,
+}
+
+/// A titled group of keybindings for one mode or context
+#[derive(Debug, Clone)]
+pub struct HelpSection {
+    pub title: &'static str,
+    pub context: HelpContext,
+    pub bindings: Vec<KeyBinding>,
+}
+
+impl HelpSection {
+    fn new(
+        title: &'static str,
+        context: HelpContext,
+        bindings: &[(&'static str, &'static str)],
+    ) -> Self {
+        Self {
+            title,
+            context,
+            bindings: bindings
+                .iter()
+                .map(|&(keys, description)| KeyBinding { keys, description })
+                .collect(),
+        }
+    }
+
+    /// Whether these bindings apply in the given search mode
+    pub fn is_active(&self, mode: SearchMode) -> bool {
+        match self.context {
+            HelpContext::Always => true,
+            HelpContext::Mode(m) => m == mode,
+            HelpContext::Popup => false,
+        }
+    }
+}
+
+/// All keybindings, grouped by mode/context
+pub fn keybinding_sections() -> Vec<HelpSection> {
+    use HelpContext::*;
+
+    vec![
+        HelpSection::new(
+            "Global",
+            Always,
+            &[
+                ("q", "Quit application"),
+                ("?", "Toggle this help"),
+                ("M", "Cycle search mode (Repository > Code > Trending > Notifications > Semantic > Portfolio > Discovery)"),
+                ("T", "Open theme selector"),
+                ("Ctrl+R", "Open search history"),
+                ("Ctrl+S", "Open settings/token manager"),
+                ("Ctrl+V", "Paste into the search, filter, token or command input"),
+                (":", "Command line: sort <order>, lang [name], limit [n], export <file>, theme <name>, group <by>, live, q"),
+                ("ESC", "Close popup / Clear error / Exit mode"),
+            ],
+        ),
+        HelpSection::new(
+            "Navigation",
+            Always,
+            &[
+                ("j / Down", "Navigate down / Scroll down"),
+                ("k / Up", "Navigate up / Scroll up"),
+                ("g / G", "Jump to top / bottom"),
+                ("Ctrl+D / Ctrl+U", "Half-page down / up"),
+                ("TAB", "Cycle preview tabs / Next option"),
+                ("Shift+TAB", "Previous preview tab"),
+                ("ENTER", "Confirm / Open in browser / Execute"),
+                ("y", "Copy selected URL to clipboard"),
+                ("i / P", "Open issues / pull (merge) requests in browser"),
+            ],
+        ),
+        HelpSection::new(
+            "Repository Search",
+            Mode(SearchMode::Repository),
+            &[
+                ("/", "Enter search mode"),
+                ("f", "Toggle fuzzy search filter"),
+                ("F", "Toggle filter panel"),
+                ("b", "Bookmark current repository"),
+                ("B", "Toggle bookmarks-only view"),
+                ("x", "Mark current repository as reviewed"),
+                ("z", "Group results: by platform, by language, off"),
+                ("s", "Show repositories similar to the selected one"),
+                ("/ (in bookmarks)", "Search bookmark names, tags and notes"),
+                ("t (in bookmarks)", "Edit the selected bookmark's tags"),
+                ("e (in bookmarks)", "Edit the selected bookmark's notes"),
+                ("r / R", "Fetch and display README"),
+                ("1-9 (in README)", "Open the README link with that footnote number"),
+                ("C", "Fetch changelog (or latest release notes)"),
+                ("d", "Fetch dependency information"),
+                ("v (Dependencies tab)", "Check dependencies for known vulnerabilities (OSV)"),
+                ("ESC", "Cancel running fetches"),
+                ("Mouse", "Scroll to move/scroll, click to select, double-click to open"),
+                ("c", "Copy package install command (Package tab)"),
+                ("N", "Create new portfolio"),
+                ("+", "Add repository to portfolio"),
+                ("-", "Remove repository from portfolio"),
+            ],
+        ),
+        HelpSection::new(
+            "Code Search",
+            Mode(SearchMode::Code),
+            &[
+                ("/", "Enter search mode"),
+                ("F", "Toggle code filters"),
+                ("n", "Navigate to next match in file"),
+                ("N", "Navigate to previous match in file"),
+                ("TAB", "Toggle Code/Raw preview modes"),
+                ("t", "Cycle syntax highlighting theme"),
+            ],
+        ),
+        HelpSection::new(
+            "Trending",
+            Mode(SearchMode::Trending),
+            &[
+                ("o / O", "Toggle trending options panel"),
+                ("Space", "Toggle period/velocity option"),
+                ("+ / =", "Increase minimum stars"),
+                ("- / _", "Decrease minimum stars"),
+                ("ENTER", "Execute trending search"),
+            ],
+        ),
+        HelpSection::new(
+            "Notifications",
+            Mode(SearchMode::Notifications),
+            &[
+                ("m", "Mark selected notification as read"),
+                ("a", "Mark all notifications as read"),
+                ("f", "Toggle all/unread filter"),
+                ("p", "Toggle participating filter"),
+                ("t", "Cycle subject type filter"),
+                ("w", "Cycle reason filter"),
+            ],
+        ),
+        HelpSection::new(
+            "Discovery",
+            Mode(SearchMode::Discovery),
+            &[
+                ("TAB / l", "Next discovery category"),
+                ("h", "Previous discovery category"),
+                ("/", "Topics: type a seed to suggest related topics"),
+                ("1", "Quick search: New & Notable (7 days)"),
+                ("2", "Quick search: New & Notable (30 days)"),
+                ("3", "Quick search: New & Notable (90 days)"),
+                ("D", "Switch to Discovery mode"),
+                ("Backspace", "Return to Discovery mode"),
+            ],
+        ),
+        HelpSection::new(
+            "Portfolio",
+            Mode(SearchMode::Portfolio),
+            &[
+                ("N", "Create new portfolio"),
+                ("+", "Add repository to selected portfolio"),
+                ("-", "Remove repository from selected portfolio"),
+            ],
+        ),
+        HelpSection::new(
+            "Filter & Edit Modes",
+            Popup,
+            &[
+                ("ENTER", "Save/confirm value"),
+                ("ESC", "Cancel/exit mode"),
+                ("DEL / d", "Clear current filter"),
+                ("s", "Cycle sort options (in filter mode)"),
+                (
+                    "r",
+                    "Re-run the last search with the current filters (ENTER edits the row instead)",
+                ),
+                ("Backspace", "Delete character"),
+            ],
+        ),
+        HelpSection::new(
+            "Theme Selector",
+            Popup,
+            &[
+                ("j / k", "Navigate themes"),
+                ("ENTER", "Apply selected theme"),
+                ("ESC", "Close without applying"),
+            ],
+        ),
+        HelpSection::new(
+            "History Popup",
+            Popup,
+            &[
+                ("j / k", "Navigate history entries"),
+                ("ENTER", "Execute selected query"),
+                ("ESC", "Close popup"),
+            ],
+        ),
+        HelpSection::new(
+            "Settings",
+            Popup,
+            &[
+                ("j / k", "Navigate settings"),
+                ("ENTER", "Select platform to configure"),
+                ("ESC", "Close settings"),
+            ],
+        ),
+    ]
+}
+
+/// Narrow sections down to bindings whose description or keys fuzzy-match the query.
+/// Sections left without any matching binding are dropped.
+pub fn filter_sections(sections: Vec<HelpSection>, query: &str) -> Vec<HelpSection> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return sections;
+    }
+
+    let matcher = SkimMatcherV2::default();
+    sections
+        .into_iter()
+        .filter_map(|mut section| {
+            section.bindings.retain(|binding| {
+                matcher
+                    .fuzzy_match(&binding.description.to_lowercase(), &query)
+                    .or_else(|| matcher.fuzzy_match(&binding.keys.to_lowercase(), &query))
+                    .is_some()
+            });
+            if section.bindings.is_empty() {
+                None
+            } else {
+                Some(section)
+            }
+        })
+        .collect()
+}
+
 /// Render keybindings help popup
-pub fn render_keybindings_help(frame: &mut Frame, app: &App, area: Rect) {
+pub fn render_keybindings_help(frame: &mut Frame, app: &mut App, area: Rect) {
     // Create centered popup (80% width, 85% height)
     let popup_area = centered_rect(80, 85, area);
 
@@ -42,27 +286,70 @@ pub fn render_keybindings_help(frame: &mut Frame, app: &App, area: Rect) {
         app.current_theme.colors.muted.b,
     );
 
-    let keybindings = get_keybindings_content(primary_color, accent_color, fg_color, muted_color);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Keybindings Help ")
+        .title_alignment(Alignment::Center)
+        .border_style(Style::default().fg(primary_color))
+        .style(Style::default().bg(bg_color));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // Search input
+            Constraint::Length(1), // Spacer
+            Constraint::Min(0),    // Keybindings
+        ])
+        .split(inner);
+
+    // Search input
+    let sections = filter_sections(keybinding_sections(), &app.help_filter);
+    let match_count: usize = sections.iter().map(|s| s.bindings.len()).sum();
+    let search_line = Paragraph::new(Line::from(vec![
+        Span::styled(
+            " Search: ",
+            Style::default()
+                .fg(primary_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(app.help_filter.clone(), Style::default().fg(accent_color)),
+        Span::styled("█", Style::default().fg(accent_color)),
+        Span::styled(
+            format!("  ({} bindings)", match_count),
+            Style::default().fg(muted_color),
+        ),
+    ]))
+    .style(Style::default().bg(bg_color));
+    frame.render_widget(search_line, chunks[0]);
+
+    let keybindings = get_keybindings_content(
+        &sections,
+        app.search_mode,
+        primary_color,
+        accent_color,
+        fg_color,
+        muted_color,
+    );
+    let total_lines = keybindings.len();
+    let max_scroll = total_lines.saturating_sub(chunks[2].height as usize) as u16;
+    // Keep scroll within bounds so scrolling back up responds immediately
+    app.help_scroll = app.help_scroll.min(max_scroll);
+    let scroll = app.help_scroll;
 
     let help_text = Paragraph::new(keybindings)
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(" Keybindings Help ")
-                .title_alignment(Alignment::Center)
-                .border_style(Style::default().fg(primary_color))
-                .style(Style::default().bg(bg_color)),
-        )
         .style(Style::default().fg(fg_color).bg(bg_color))
-        .alignment(Alignment::Left);
+        .alignment(Alignment::Left)
+        .scroll((scroll, 0));
 
-    frame.render_widget(help_text, popup_area);
+    frame.render_widget(help_text, chunks[2]);
 
-    // Scrollbar (visual indicator)
+    // Scrollbar
     let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
         .begin_symbol(Some("↑"))
         .end_symbol(Some("↓"));
-    let mut scrollbar_state = ScrollbarState::new(100).position(0);
+    let mut scrollbar_state = ScrollbarState::new(max_scroll as usize).position(scroll as usize);
 
     let scrollbar_area = Rect {
         x: popup_area.x + popup_area.width - 1,
@@ -81,7 +368,14 @@ pub fn render_keybindings_help(frame: &mut Frame, app: &App, area: Rect) {
     };
 
     let footer = Paragraph::new(Line::from(vec![
-        Span::styled("Press ", Style::default().fg(muted_color)),
+        Span::styled("Type to search | ", Style::default().fg(muted_color)),
+        Span::styled(
+            "↑/↓ ",
+            Style::default()
+                .fg(accent_color)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("scroll | ", Style::default().fg(muted_color)),
         Span::styled(
             "? ",
             Style::default()
@@ -103,8 +397,10 @@ pub fn render_keybindings_help(frame: &mut Frame, app: &App, area: Rect) {
     frame.render_widget(footer, help_area);
 }
 
-/// Get all keybindings content as styled lines
+/// Build styled lines for the given keybinding sections
 fn get_keybindings_content(
+    sections: &[HelpSection],
+    mode: SearchMode,
     primary: Color,
     accent: Color,
     fg: Color,
@@ -112,148 +408,46 @@ fn get_keybindings_content(
 ) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
 
-    // Helper to create a section header
-    let section = |title: &str| -> Line<'static> {
-        Line::from(vec![Span::styled(
-            format!(" {} ", title),
+    if sections.is_empty() {
+        lines.push(Line::from(vec![Span::styled(
+            "  No keybindings match your search",
+            Style::default().fg(muted).add_modifier(Modifier::ITALIC),
+        )]));
+        return lines;
+    }
+
+    for section in sections {
+        let active = section.is_active(mode);
+
+        // Section header, highlighted when it applies to the current mode
+        let mut header = vec![Span::styled(
+            format!(" {} ", section.title),
             Style::default()
                 .fg(Color::Black)
-                .bg(primary)
+                .bg(if active { primary } else { muted })
                 .add_modifier(Modifier::BOLD),
-        )])
-    };
-
-    // Helper to create a keybinding line
-    let key = |k: &str, desc: &str| -> Line<'static> {
-        Line::from(vec![
-            Span::styled(
-                format!("  {:12}", k),
-                Style::default().fg(accent).add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(desc.to_string(), Style::default().fg(fg)),
-        ])
-    };
-
-    // Global Keybindings
-    lines.push(section("Global"));
-    lines.push(Line::from(""));
-    lines.push(key("q", "Quit application"));
-    lines.push(key("?", "Toggle this help"));
-    lines.push(key("M", "Cycle search mode (Repository > Code > Trending > Notifications > Semantic > Portfolio > Discovery)"));
-    lines.push(key("T", "Open theme selector"));
-    lines.push(key("Ctrl+R", "Open search history"));
-    lines.push(key("Ctrl+S", "Open settings/token manager"));
-    lines.push(key("ESC", "Close popup / Clear error / Exit mode"));
-    lines.push(Line::from(""));
-
-    // Navigation
-    lines.push(section("Navigation"));
-    lines.push(Line::from(""));
-    lines.push(key("j / Down", "Navigate down / Scroll down"));
-    lines.push(key("k / Up", "Navigate up / Scroll up"));
-    lines.push(key("TAB", "Cycle preview tabs / Next option"));
-    lines.push(key("Shift+TAB", "Previous preview tab"));
-    lines.push(key("ENTER", "Confirm / Open in browser / Execute"));
-    lines.push(Line::from(""));
-
-    // Repository Search Mode
-    lines.push(section("Repository Search"));
-    lines.push(Line::from(""));
-    lines.push(key("/", "Enter search mode"));
-    lines.push(key("f", "Toggle fuzzy search filter"));
-    lines.push(key("F", "Toggle filter panel"));
-    lines.push(key("b", "Bookmark current repository"));
-    lines.push(key("B", "Toggle bookmarks-only view"));
-    lines.push(key("r / R", "Fetch and display README"));
-    lines.push(key("d", "Fetch dependency information"));
-    lines.push(key("c", "Copy package install command (Package tab)"));
-    lines.push(key("N", "Create new portfolio"));
-    lines.push(key("+", "Add repository to portfolio"));
-    lines.push(key("-", "Remove repository from portfolio"));
-    lines.push(Line::from(""));
-
-    // Code Search Mode
-    lines.push(section("Code Search"));
-    lines.push(Line::from(""));
-    lines.push(key("/", "Enter search mode"));
-    lines.push(key("F", "Toggle code filters"));
-    lines.push(key("n", "Navigate to next match in file"));
-    lines.push(key("N", "Navigate to previous match in file"));
-    lines.push(key("TAB", "Toggle Code/Raw preview modes"));
-    lines.push(Line::from(""));
-
-    // Trending Mode
-    lines.push(section("Trending"));
-    lines.push(Line::from(""));
-    lines.push(key("o / O", "Toggle trending options panel"));
-    lines.push(key("Space", "Toggle period/velocity option"));
-    lines.push(key("+ / =", "Increase minimum stars"));
-    lines.push(key("- / _", "Decrease minimum stars"));
-    lines.push(key("ENTER", "Execute trending search"));
-    lines.push(Line::from(""));
-
-    // Notifications Mode
-    lines.push(section("Notifications"));
-    lines.push(Line::from(""));
-    lines.push(key("m", "Mark selected notification as read"));
-    lines.push(key("a", "Mark all notifications as read"));
-    lines.push(key("f", "Toggle all/unread filter"));
-    lines.push(key("p", "Toggle participating filter"));
-    lines.push(Line::from(""));
-
-    // Discovery Mode
-    lines.push(section("Discovery"));
-    lines.push(Line::from(""));
-    lines.push(key("TAB / l", "Next discovery category"));
-    lines.push(key("h", "Previous discovery category"));
-    lines.push(key("1", "Quick search: New & Notable (7 days)"));
-    lines.push(key("2", "Quick search: New & Notable (30 days)"));
-    lines.push(key("3", "Quick search: New & Notable (90 days)"));
-    lines.push(key("D", "Switch to Discovery mode"));
-    lines.push(key("Backspace", "Return to Discovery mode"));
-    lines.push(Line::from(""));
-
-    // Portfolio Mode
-    lines.push(section("Portfolio"));
-    lines.push(Line::from(""));
-    lines.push(key("N", "Create new portfolio"));
-    lines.push(key("+", "Add repository to selected portfolio"));
-    lines.push(key("-", "Remove repository from selected portfolio"));
-    lines.push(Line::from(""));
-
-    // Filter/Edit Modes
-    lines.push(section("Filter & Edit Modes"));
-    lines.push(Line::from(""));
-    lines.push(key("ENTER", "Save/confirm value"));
-    lines.push(key("ESC", "Cancel/exit mode"));
-    lines.push(key("DEL / d", "Clear current filter"));
-    lines.push(key("s", "Cycle sort options (in filter mode)"));
-    lines.push(key("Backspace", "Delete character"));
-    lines.push(Line::from(""));
+        )];
+        if active {
+            header.push(Span::styled(
+                "  ● active",
+                Style::default().fg(accent).add_modifier(Modifier::ITALIC),
+            ));
+        }
+        lines.push(Line::from(header));
+        lines.push(Line::from(""));
 
-    // Theme Selector
-    lines.push(section("Theme Selector"));
-    lines.push(Line::from(""));
-    lines.push(key("j / k", "Navigate themes"));
-    lines.push(key("ENTER", "Apply selected theme"));
-    lines.push(key("ESC", "Close without applying"));
-    lines.push(Line::from(""));
-
-    // History Popup
-    lines.push(section("History Popup"));
-    lines.push(Line::from(""));
-    lines.push(key("j / k", "Navigate history entries"));
-    lines.push(key("ENTER", "Execute selected query"));
-    lines.push(key("ESC", "Close popup"));
-    lines.push(Line::from(""));
-
-    // Settings
-    lines.push(section("Settings"));
-    lines.push(Line::from(""));
-    lines.push(key("j / k", "Navigate settings"));
-    lines.push(key("ENTER", "Select platform to configure"));
-    lines.push(key("ESC", "Close settings"));
-    lines.push(Line::from(""));
+        let desc_color = if active { fg } else { muted };
+        for binding in &section.bindings {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("  {:12}", binding.keys),
+                    Style::default().fg(accent).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(binding.description, Style::default().fg(desc_color)),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
 
     // Footer note
     lines.push(Line::from(vec![Span::styled(
@@ -285,3 +479,38 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_filter_keeps_everything() {
+        let all = keybinding_sections();
+        let filtered = filter_sections(keybinding_sections(), "  ");
+        assert_eq!(all.len(), filtered.len());
+    }
+
+    #[test]
+    fn test_filter_matches_descriptions() {
+        let filtered = filter_sections(keybinding_sections(), "bookmark");
+        assert!(!filtered.is_empty());
+        assert!(filtered
+            .iter()
+            .flat_map(|s| &s.bindings)
+            .all(|b| b.description.to_lowercase().contains("bookmark")));
+        assert!(filtered.iter().any(|s| s.title == "Repository Search"));
+        assert!(!filtered.iter().any(|s| s.title == "Settings"));
+    }
+
+    #[test]
+    fn test_active_sections_follow_search_mode() {
+        let sections = keybinding_sections();
+        let active: Vec<_> = sections
+            .iter()
+            .filter(|s| s.is_active(SearchMode::Trending))
+            .map(|s| s.title)
+            .collect();
+        assert_eq!(active, vec!["Global", "Navigation", "Trending"]);
+    }
+}
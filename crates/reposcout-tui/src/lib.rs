@@ -3,15 +3,21 @@
 
 pub mod app;
 pub mod code_ui;
+pub mod command;
 pub mod discovery_ui;
 pub mod help_ui;
 pub mod portfolio_ui;
+pub mod readme_links;
 pub mod runner;
 pub mod sparkline;
+pub mod syntax;
+pub mod tasks;
 pub mod theme_ui;
+pub mod topics;
 pub mod ui;
 
 pub use app::{
-    App, CodePreviewMode, DiscoveryCategory, InputMode, PlatformStatus, PreviewMode, SearchMode,
+    App, BookmarkField, CodePreviewMode, DiscoveryCategory, FuzzyField, InputMode, PlatformStatus,
+    PreviewMode, ResultRow, SearchMode, MIN_TERMINAL_SIZE,
 };
 pub use runner::run_tui;
@@ -0,0 +1,265 @@
+// Markdown links in the README preview
+//
+// Inline (`[text](url)`) and reference-style (`[text][ref]` with a
+// `[ref]: url` definition) links are shown as their text plus a footnote
+// number, and the URLs are listed under the README so they can be opened
+// by number. Images collapse to their alt text and relative links stay
+// plain text, since neither has anything to open.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// How long to wait for a second digit before opening a footnote
+pub const FOOTNOTE_DIGIT_TIMEOUT: Duration = Duration::from_millis(700);
+
+/// Part of a README line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Piece {
+    Text(String),
+    /// Link text and its footnote number, None for links that can't be opened
+    Link {
+        text: String,
+        footnote: Option<usize>,
+    },
+}
+
+/// Footnote numbering for one README
+pub struct Footnotes {
+    urls: Vec<String>,
+    references: HashMap<String, String>,
+}
+
+impl Footnotes {
+    /// Collect the reference definitions of `markdown`
+    pub fn new(markdown: &str) -> Self {
+        let references = markdown_lines(markdown)
+            .filter_map(reference_definition)
+            .map(|(label, url)| (label.to_lowercase(), url.to_string()))
+            .collect();
+        Self {
+            urls: Vec::new(),
+            references,
+        }
+    }
+
+    /// Footnote URLs in the order they were numbered (footnote 1 first)
+    pub fn urls(&self) -> &[String] {
+        &self.urls
+    }
+
+    /// Split a line into text and links, numbering new URLs as they appear
+    pub fn split_line(&mut self, line: &str) -> Vec<Piece> {
+        let mut pieces = Vec::new();
+        let mut text = String::new();
+        let mut rest = line;
+
+        while let Some(start) = rest.find('[') {
+            let is_image = rest[..start].ends_with('!');
+            match self.parse_link(&rest[start..]) {
+                Some((label, target, len)) => {
+                    let before = &rest[..start - usize::from(is_image)];
+                    text.push_str(before);
+                    if is_image {
+                        // Alt text only, an image has nothing to open
+                        text.push_str(&label);
+                    } else {
+                        if !text.is_empty() {
+                            pieces.push(Piece::Text(std::mem::take(&mut text)));
+                        }
+                        let footnote = if is_openable(&target) {
+                            Some(self.number(target))
+                        } else {
+                            None
+                        };
+                        pieces.push(Piece::Link {
+                            text: label,
+                            footnote,
+                        });
+                    }
+                    rest = &rest[start + len..];
+                }
+                None => {
+                    text.push_str(&rest[..=start]);
+                    rest = &rest[start + 1..];
+                }
+            }
+        }
+
+        text.push_str(rest);
+        if !text.is_empty() {
+            pieces.push(Piece::Text(text));
+        }
+        pieces
+    }
+
+    /// Parse a link at the start of `s` (which begins with `[`). Returns the
+    /// rendered label, the link target and the number of bytes consumed.
+    fn parse_link(&self, s: &str) -> Option<(String, String, usize)> {
+        let label_end = matching(s, '[', ']')?;
+        let raw_label = &s[1..label_end];
+        let after = &s[label_end + 1..];
+
+        let (target, len) = if after.starts_with('(') {
+            let close = matching(after, '(', ')')?;
+            // Drop an optional title: [text](url "title")
+            let url = after[1..close].split_whitespace().next().unwrap_or("");
+            (url.trim_matches(['<', '>']).to_string(), close + 1)
+        } else if after.starts_with('[') {
+            let close = after.find(']')?;
+            let reference = match &after[1..close] {
+                "" => raw_label,
+                reference => reference,
+            };
+            (self.resolve(reference)?, close + 1)
+        } else {
+            // Shortcut reference, only when defined so `[x]` stays text
+            (self.resolve(raw_label)?, 0)
+        };
+
+        // Nested links and images (badges) inside the label keep their text only
+        let label = Footnotes {
+            urls: Vec::new(),
+            references: HashMap::new(),
+        }
+        .split_line(raw_label)
+        .into_iter()
+        .map(|piece| match piece {
+            Piece::Text(text) | Piece::Link { text, .. } => text,
+        })
+        .collect();
+
+        Some((label, target, label_end + 1 + len))
+    }
+
+    fn resolve(&self, reference: &str) -> Option<String> {
+        self.references.get(&reference.to_lowercase()).cloned()
+    }
+
+    /// Footnote number for `url`, reusing the number of an earlier identical URL
+    fn number(&mut self, url: String) -> usize {
+        match self.urls.iter().position(|u| *u == url) {
+            Some(i) => i + 1,
+            None => {
+                self.urls.push(url);
+                self.urls.len()
+            }
+        }
+    }
+}
+
+/// All footnote URLs of a README, numbered the way the preview shows them
+pub fn footnote_urls(markdown: &str) -> Vec<String> {
+    let mut footnotes = Footnotes::new(markdown);
+    for line in markdown_lines(markdown) {
+        if reference_definition(line).is_none() {
+            footnotes.split_line(line);
+        }
+    }
+    footnotes.urls
+}
+
+/// A `[label]: url` definition line
+pub fn reference_definition(line: &str) -> Option<(&str, &str)> {
+    let line = line.trim_start();
+    let rest = line.strip_prefix('[')?;
+    let (label, rest) = rest.split_once("]:")?;
+    let url = rest.split_whitespace().next()?;
+    (!label.is_empty()).then_some((label, url.trim_matches(['<', '>'])))
+}
+
+/// Lines outside fenced code blocks
+fn markdown_lines(markdown: &str) -> impl Iterator<Item = &str> {
+    let mut in_code = false;
+    markdown.lines().filter(move |line| {
+        if line.starts_with("```") {
+            in_code = !in_code;
+            return false;
+        }
+        !in_code
+    })
+}
+
+/// Index of the bracket closing the one `s` starts with
+fn matching(s: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+fn is_openable(url: &str) -> bool {
+    url.starts_with("https://") || url.starts_with("http://") || url.starts_with("mailto:")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_links_become_footnotes() {
+        let readme = "\
+[![CI](https://ci.example/badge.svg)](https://ci.example/build)
+See the [guide](https://example.com/guide \"Guide\") and [API docs][api].
+Also the [guide](https://example.com/guide), [Contributing] and [local](docs/a.md).
+- [x] done
+```
+[not](https://a.link)
+```
+[api]: https://docs.rs/crate
+[contributing]: <https://example.com/contributing>
+";
+        let mut footnotes = Footnotes::new(readme);
+
+        assert_eq!(
+            footnotes.split_line("[![CI](https://ci.example/badge.svg)](https://ci.example/build)"),
+            vec![Piece::Link {
+                text: "CI".to_string(),
+                footnote: Some(1)
+            }]
+        );
+        assert_eq!(
+            footnotes.split_line(
+                "See the [guide](https://example.com/guide \"Guide\") and [API docs][api]."
+            ),
+            vec![
+                Piece::Text("See the ".to_string()),
+                Piece::Link {
+                    text: "guide".to_string(),
+                    footnote: Some(2)
+                },
+                Piece::Text(" and ".to_string()),
+                Piece::Link {
+                    text: "API docs".to_string(),
+                    footnote: Some(3)
+                },
+                Piece::Text(".".to_string()),
+            ]
+        );
+        assert_eq!(
+            footnotes.split_line("- [x] done"),
+            vec![Piece::Text("- [x] done".to_string())]
+        );
+
+        assert_eq!(
+            footnote_urls(readme),
+            [
+                "https://ci.example/build",
+                "https://example.com/guide",
+                "https://docs.rs/crate",
+                "https://example.com/contributing",
+            ]
+        );
+        assert_eq!(
+            reference_definition("[api]: https://docs.rs/crate"),
+            Some(("api", "https://docs.rs/crate"))
+        );
+    }
+}
@@ -1,66 +1,257 @@
 // TUI event loop and terminal management
+use crate::tasks::{PlatformClients, TaskKind, TaskOutput};
 use crate::{App, InputMode, SearchMode};
 use crossterm::{
     event::{
-        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+        Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
     },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use reposcout_api::{BitbucketClient, GitHubClient, GitLabClient};
+use reposcout_api::{
+    AzureDevOpsClient, BitbucketClient, GitHubClient, GitLabClient, SourceHutClient,
+};
 use reposcout_cache::CacheManager;
+use reposcout_core::models::Repository;
+use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::io;
 
-pub async fn run_tui<F>(
+#[allow(clippy::too_many_arguments)]
+pub async fn run_tui<F, G>(
     mut app: App,
     mut on_search: F,
+    mut on_load_more: G,
     github_client: GitHubClient,
     gitlab_client: GitLabClient,
     bitbucket_client: BitbucketClient,
+    sourcehut_client: SourceHutClient,
+    azure_devops_client: AzureDevOpsClient,
     cache: CacheManager,
 ) -> anyhow::Result<()>
 where
-    F: FnMut(
-        &str,
+    F: for<'a> FnMut(
+        &'a str,
+        &'a mut dyn FnMut(&[reposcout_core::models::Repository]),
     ) -> std::pin::Pin<
         Box<
             dyn std::future::Future<
                     Output = anyhow::Result<Vec<reposcout_core::models::Repository>>,
-                > + '_,
+                > + 'a,
+        >,
+    >,
+    G: FnMut() -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<
+                Output = anyhow::Result<Vec<reposcout_core::models::Repository>>,
+            >,
         >,
     >,
 {
+    crate::syntax::preload();
+
     // Load existing bookmarks
-    if let Ok(bookmarks) = cache.get_bookmarks::<reposcout_core::models::Repository>() {
-        for repo in bookmarks {
-            let key = App::bookmark_key(&repo.platform.to_string().to_lowercase(), &repo.full_name);
+    if let Ok(bookmarks) = cache.get_bookmarks_with_metadata() {
+        for entry in bookmarks {
+            let key = App::bookmark_key(&entry.platform, &entry.full_name);
+            app.set_bookmark_metadata(key.clone(), entry.tags.as_deref(), entry.notes.as_deref());
             app.bookmarked.insert(key);
         }
     }
+    if let Ok(reviewed) = cache.get_reviewed() {
+        app.reviewed.extend(
+            reviewed
+                .iter()
+                .map(|entry| App::bookmark_key(&entry.platform, &entry.full_name)),
+        );
+    }
+    match reposcout_core::PortfolioManager::load() {
+        Ok(manager) => app.portfolio_manager = manager,
+        Err(e) => tracing::warn!("Failed to load portfolios: {}", e),
+    }
+    // Clients cloned into background tasks
+    let clients = PlatformClients {
+        github: github_client.clone(),
+        gitlab: gitlab_client.clone(),
+        bitbucket: bitbucket_client.clone(),
+        sourcehut: sourcehut_client,
+        azure_devops: azure_devops_client,
+        osv: reposcout_deps::OsvClient::new(),
+    };
+
     // Setup terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    // Bracketed paste delivers a paste as one event instead of a burst of keys
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
+    // Input that arrived while a live search ran, handled once it finishes
+    let mut deferred_events = VecDeque::new();
+
     // Main loop
     loop {
         // Clear expired temporary errors
         app.clear_expired_error();
 
+        // Apply results of background tasks that finished since the last tick
+        for output in app.tasks.take_finished() {
+            apply_task_output(&mut app, output);
+        }
+        for (repo_name, result) in app.readme_prefetch.take_finished() {
+            apply_prefetched_readme(&mut app, repo_name, result);
+        }
+        prefetch_readmes_if_needed(&mut app, &clients);
+        fetch_languages_if_needed(&mut app, &clients);
+        fetch_activity_if_needed(&mut app, &clients);
+        load_star_history_if_needed(&mut app, &cache);
+        fetch_code_file_if_needed(&mut app, &clients);
+        fetch_crate_usage_if_needed(&mut app);
+        fetch_dependency_licenses_if_needed(&mut app);
+        suggest_topics_if_needed(&mut app, &clients);
+        if let Some(url) = app.take_due_footnote(std::time::Instant::now()) {
+            open_footnote(&mut app, &url);
+        }
+        app.platform_status.github_rate_limit = clients.github.rate_limit();
+        app.platform_status.gitlab_rate_limit = clients.gitlab.rate_limit();
+
         // Clear and redraw terminal
+        app.tick_spinner();
         terminal.draw(|f| crate::ui::render(f, &mut app))?;
 
-        // Poll for events with timeout to allow periodic error clearing
-        if event::poll(std::time::Duration::from_millis(500))? {
-            if let Event::Key(key) = event::read()? {
+        // Search as you type once typing pauses. Esc cancels the search; other
+        // input is kept and handled after it, so typing on searches again.
+        if let Some(query) = app.poll_live_search(std::time::Instant::now()) {
+            app.loading = true;
+            let mut first_batch = true;
+            let mut on_batch = |batch: &[reposcout_core::models::Repository]| {
+                // The previous results stay up until the new ones start arriving
+                if std::mem::take(&mut first_batch) {
+                    app.set_results(batch.to_vec());
+                } else {
+                    app.append_results(batch.to_vec());
+                }
+                let _ = terminal.draw(|f| crate::ui::render(f, &mut app));
+            };
+            let search = on_search(&query, &mut on_batch);
+            let cancel = async {
+                loop {
+                    while event::poll(std::time::Duration::ZERO).unwrap_or(false) {
+                        match event::read() {
+                            Ok(Event::Key(key))
+                                if key.code == KeyCode::Esc && key.kind == KeyEventKind::Press =>
+                            {
+                                return;
+                            }
+                            Ok(event) => deferred_events.push_back(event),
+                            Err(_) => return,
+                        }
+                    }
+                    tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+                }
+            };
+            let outcome = tokio::select! {
+                outcome = search => Some(outcome),
+                _ = cancel => None,
+            };
+            app.loading = false;
+            match outcome {
+                Some(Ok(results)) => {
+                    app.set_search_results(results);
+                }
+                Some(Err(e)) => app.set_temp_error(describe_error("Search failed", &e)),
+                None => app.set_temp_error("Search cancelled".to_string()),
+            }
+            continue;
+        }
+
+        // Fetch the next page once scrolling reaches the last result. A failed
+        // page keeps `more_results`, so scrolling down again retries it.
+        if app.loading_more {
+            match with_spinner(&mut app, &mut terminal, None, on_load_more()).await {
+                Ok(page) => {
+                    app.append_more_results(page);
+                }
+                Err(e) => {
+                    app.loading_more = false;
+                    app.set_temp_error(describe_error("Loading more results failed", &e));
+                }
+            }
+            continue;
+        }
+
+        // Poll for events with timeout to allow periodic error clearing.
+        // Tick faster while background tasks run or results load so the spinners
+        // animate, and while a live search waits so it starts on time.
+        let tick_ms = if app.tasks.is_busy() || app.loading || app.live_search_pending() {
+            80
+        } else {
+            500
+        };
+        let event = match deferred_events.pop_front() {
+            Some(event) => Some(event),
+            None if event::poll(std::time::Duration::from_millis(tick_ms))? => Some(event::read()?),
+            None => None,
+        };
+        if let Some(event) = event {
+            if let Event::Mouse(mouse) = event {
+                handle_mouse_event(&mut app, mouse);
+            } else if let Event::Paste(text) = event {
+                app.paste(&text);
+            } else if let Event::Key(key) = event {
                 if key.kind == KeyEventKind::Press {
+                    // Esc cancels running background tasks before anything else
+                    if key.code == KeyCode::Esc
+                        && app.input_mode == InputMode::Normal
+                        && app.tasks.is_busy()
+                    {
+                        cancel_tasks(&mut app);
+                        continue;
+                    }
+
                     match app.input_mode {
-                        InputMode::Searching => match key.code {
-                            KeyCode::Enter => {
-                                if !app.search_input.is_empty() {
+                        InputMode::Searching => {
+                            match key.code {
+                                KeyCode::Enter
+                                    if app.show_bookmarks_only
+                                        && app.search_mode == SearchMode::Repository =>
+                                {
+                                    // In the bookmarks view, search filters bookmarks locally
+                                    let query = app.search_input.trim().to_string();
+                                    match cache.search_bookmarks(&query) {
+                                        Ok(entries) => {
+                                            let bookmarks = entries
+                                                .iter()
+                                                .filter_map(|entry| {
+                                                    serde_json::from_str::<
+                                                        reposcout_core::models::Repository,
+                                                    >(
+                                                        &entry.data
+                                                    )
+                                                    .ok()
+                                                })
+                                                .collect();
+                                            app.set_results(bookmarks);
+                                            app.bookmark_query = query;
+                                        }
+                                        Err(e) => {
+                                            app.set_temp_error(format!(
+                                                "Bookmark search failed: {}",
+                                                e
+                                            ));
+                                        }
+                                    }
+                                    app.enter_normal_mode();
+                                }
+                                KeyCode::Enter if !app.search_input.is_empty() => {
                                     // Clear any stale state from previous searches
                                     app.all_results.clear();
                                     app.fuzzy_input.clear();
@@ -80,8 +271,22 @@ where
                                         SearchMode::Repository | SearchMode::Trending => {
                                             // Perform repository search with filters applied
                                             // (Trending is handled separately via Enter key)
+                                            app.last_query = app.search_input.trim().to_string();
                                             let query = app.get_search_query();
-                                            match on_search(&query).await {
+                                            app.set_results(Vec::new());
+                                            // Show each platform's results as soon as it responds
+                                            let arrived = RefCell::new(Vec::new());
+                                            let mut on_batch = |batch: &[Repository]| {
+                                                arrived.borrow_mut().extend_from_slice(batch)
+                                            };
+                                            let outcome = with_spinner(
+                                                &mut app,
+                                                &mut terminal,
+                                                Some(&arrived),
+                                                on_search(&query, &mut on_batch),
+                                            )
+                                            .await;
+                                            match outcome {
                                                 Ok(results) => {
                                                     // Record search in history
                                                     let result_count = results.len();
@@ -89,27 +294,9 @@ where
                                                     // Auto-index results for semantic search (in background)
                                                     let results_for_indexing = results.clone();
                                                     tokio::spawn(async move {
-                                                        use reposcout_semantic::{
-                                                            SemanticConfig, SemanticSearchEngine,
-                                                        };
-
-                                                        // Get semantic index path (same pattern as CLI)
-                                                        if let Some(cache_dir) =
-                                                            dirs_next::cache_dir()
-                                                        {
-                                                            let cache_path = cache_dir
-                                                                .join("reposcout")
-                                                                .join("reposcout.db");
-                                                            let semantic_path =
-                                                                cache_path.join("semantic");
-
-                                                            let config = SemanticConfig {
-                                                                cache_path: semantic_path
-                                                                    .to_string_lossy()
-                                                                    .to_string(),
-                                                                ..Default::default()
-                                                            };
+                                                        use reposcout_semantic::SemanticSearchEngine;
 
+                                                        if let Some(config) = semantic_config() {
                                                             if let Ok(engine) =
                                                                 SemanticSearchEngine::new(config)
                                                             {
@@ -117,18 +304,23 @@ where
                                                                 {
                                                                     let repos_to_index: Vec<(reposcout_core::models::Repository, Option<String>)> =
                                                                     results_for_indexing.into_iter().map(|r| (r, None)).collect();
-                                                                    let _ = engine
+                                                                    // Persist so the next search can skip unchanged repositories
+                                                                    if engine
                                                                         .index_repositories(
                                                                             repos_to_index,
                                                                         )
-                                                                        .await;
+                                                                        .await
+                                                                        .is_ok()
+                                                                    {
+                                                                        let _ = engine.save().await;
+                                                                    }
                                                                     tracing::debug!("Auto-indexed {} repositories for semantic search", result_count);
                                                                 }
                                                             }
                                                         }
                                                     });
 
-                                                    app.set_results(results);
+                                                    app.set_search_results(results);
                                                     app.loading = false;
                                                     app.error_message = None;
 
@@ -145,19 +337,8 @@ where
                                                     }
                                                 }
                                                 Err(e) => {
-                                                    let error_str = e.to_string();
-                                                    let error_message = if error_str
-                                                        .contains("Network")
-                                                        || error_str.contains("network")
-                                                    {
-                                                        "Network error. Check your connection."
-                                                            .to_string()
-                                                    } else if error_str.len() > 100 {
-                                                        format!("{}...", &error_str[..100])
-                                                    } else {
-                                                        error_str
-                                                    };
-                                                    app.error_message = Some(error_message);
+                                                    app.error_message =
+                                                        Some(describe_error("Search failed", &e));
                                                     app.loading = false;
                                                 }
                                             }
@@ -166,6 +347,10 @@ where
                                             // Notifications don't have a search box - fetched automatically
                                             app.loading = false;
                                         }
+                                        SearchMode::Code if reposcout_core::is_offline() => {
+                                            blocked_offline(&mut app, "Code search");
+                                            app.loading = false;
+                                        }
                                         SearchMode::Code => {
                                             // Perform code search
                                             let query = app.get_code_search_query();
@@ -225,39 +410,24 @@ where
                                                     }
                                                 }
                                                 Err(e) => {
-                                                    let error_str = e.to_string();
-                                                    let error_message = if error_str
-                                                        .contains("Authentication required")
-                                                        || error_str.contains("401")
-                                                        || error_str.contains("Unauthorized")
-                                                    {
-                                                        "Code search requires authentication. Set GITHUB_TOKEN environment variable.".to_string()
-                                                    } else if error_str.contains("Rate limit") {
-                                                        "Rate limit exceeded. Wait a moment and try again.".to_string()
-                                                    } else if error_str.contains("Network")
-                                                        || error_str.contains("network")
-                                                    {
-                                                        "Network error. Check your connection and try again.".to_string()
-                                                    } else if error_str.contains("decode")
-                                                        || error_str.contains("parse")
-                                                    {
-                                                        "API response error. Try again later."
-                                                            .to_string()
-                                                    } else {
-                                                        // Truncate long error messages
-                                                        let short_msg = if error_str.len() > 100 {
-                                                            format!("{}...", &error_str[..100])
-                                                        } else {
-                                                            error_str
-                                                        };
-                                                        format!("Search failed: {}", short_msg)
-                                                    };
-                                                    app.error_message = Some(error_message);
-                                                    app.loading = false;
                                                     tracing::warn!(
                                                         "GitHub code search failed: {}",
                                                         e
                                                     );
+                                                    let error = anyhow::Error::from(
+                                                        reposcout_core::Error::from(e),
+                                                    );
+                                                    app.error_message = Some(
+                                                        if matches!(
+                                                            error.downcast_ref(),
+                                                            Some(reposcout_core::Error::Unauthorized { .. })
+                                                        ) {
+                                                            "Code search requires authentication. Set GITHUB_TOKEN environment variable.".to_string()
+                                                        } else {
+                                                            describe_error("Code search failed", &error)
+                                                        },
+                                                    );
+                                                    app.loading = false;
                                                     // Don't add any results on error
                                                 }
                                             }
@@ -279,7 +449,14 @@ where
                                             let query = app.get_search_query();
 
                                             // First, do keyword search to get candidates
-                                            match on_search(&query).await {
+                                            match with_spinner(
+                                                &mut app,
+                                                &mut terminal,
+                                                None,
+                                                on_search(&query, &mut |_| {}),
+                                            )
+                                            .await
+                                            {
                                                 Ok(keyword_results) => {
                                                     if keyword_results.is_empty() {
                                                         app.error_message = Some("No repositories found. Try a different query.".to_string());
@@ -295,15 +472,12 @@ where
                                                             Ok(engine) => {
                                                                 match engine.initialize().await {
                                                                     Ok(_) => {
-                                                                        // Convert to format expected by hybrid_search
-                                                                        let keyword_pairs: Vec<(reposcout_core::models::Repository, f32)> = keyword_results
-                                                                        .into_iter()
-                                                                        .enumerate()
-                                                                        .map(|(i, repo)| {
-                                                                            let score = 1.0 - (i as f32 / 100.0).min(0.9);
-                                                                            (repo, score)
-                                                                        })
-                                                                        .collect();
+                                                                        // Rank the keyword results for hybrid_search
+                                                                        let keyword_pairs = engine
+                                                                            .score_keyword_results(
+                                                                                &query,
+                                                                                keyword_results,
+                                                                            );
 
                                                                         match engine
                                                                             .hybrid_search(
@@ -354,7 +528,7 @@ where
                                                 }
                                                 Err(e) => {
                                                     app.error_message =
-                                                        Some(format!("Search failed: {}", e));
+                                                        Some(describe_error("Search failed", &e));
                                                     app.loading = false;
                                                 }
                                             }
@@ -369,18 +543,23 @@ where
                                         }
                                     }
                                 }
+                                KeyCode::Char('v')
+                                    if key.modifiers.contains(KeyModifiers::CONTROL) =>
+                                {
+                                    app.paste_from_clipboard();
+                                }
+                                KeyCode::Char(c) => {
+                                    app.search_input.push(c);
+                                }
+                                KeyCode::Backspace => {
+                                    app.search_input.pop();
+                                }
+                                KeyCode::Esc => {
+                                    app.enter_normal_mode();
+                                }
+                                _ => {}
                             }
-                            KeyCode::Char(c) => {
-                                app.search_input.push(c);
-                            }
-                            KeyCode::Backspace => {
-                                app.search_input.pop();
-                            }
-                            KeyCode::Esc => {
-                                app.enter_normal_mode();
-                            }
-                            _ => {}
-                        },
+                        }
                         InputMode::Filtering => match key.code {
                             KeyCode::Esc => {
                                 app.enter_normal_mode();
@@ -394,6 +573,9 @@ where
                             KeyCode::Delete | KeyCode::Char('d') => {
                                 app.clear_current_filter();
                             }
+                            KeyCode::Enter | KeyCode::Char(' ') if app.is_toggle_filter() => {
+                                app.toggle_current_filter();
+                            }
                             KeyCode::Enter => {
                                 // Enter edit mode for this filter
                                 app.enter_editing_filter_mode();
@@ -402,6 +584,51 @@ where
                                 // Cycle sort options with 's' key
                                 app.cycle_sort();
                             }
+                            KeyCode::Char('r') if app.search_mode == SearchMode::Repository => {
+                                // Re-run the last search with the filters as they are now,
+                                // leaving the panel open for further tweaks
+                                let Some(query) = app.rerun_query() else {
+                                    app.set_temp_error("Search for something first".to_string());
+                                    continue;
+                                };
+                                app.all_results.clear();
+                                app.fuzzy_input.clear();
+                                app.show_bookmarks_only = false;
+                                app.set_results(Vec::new());
+                                app.loading = true;
+                                terminal.draw(|f| crate::ui::render(f, &mut app))?;
+
+                                let arrived = RefCell::new(Vec::new());
+                                let mut on_batch = |batch: &[Repository]| {
+                                    arrived.borrow_mut().extend_from_slice(batch)
+                                };
+                                match with_spinner(
+                                    &mut app,
+                                    &mut terminal,
+                                    Some(&arrived),
+                                    on_search(&query, &mut on_batch),
+                                )
+                                .await
+                                {
+                                    Ok(results) => {
+                                        let result_count = results.len();
+                                        app.set_search_results(results);
+                                        app.error_message = None;
+                                        if let Err(e) = cache.add_search_history(
+                                            &app.search_input,
+                                            None,
+                                            Some(result_count as i64),
+                                        ) {
+                                            tracing::warn!("Failed to save search history: {}", e);
+                                        }
+                                    }
+                                    Err(e) => {
+                                        app.error_message =
+                                            Some(describe_error("Search failed", &e));
+                                    }
+                                }
+                                app.loading = false;
+                            }
                             _ => {}
                         },
                         InputMode::EditingFilter => match key.code {
@@ -411,6 +638,9 @@ where
                             KeyCode::Esc => {
                                 app.cancel_filter_edit();
                             }
+                            KeyCode::Char('v') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.paste_from_clipboard();
+                            }
                             KeyCode::Char(c) => {
                                 app.filter_edit_buffer.push(c);
                             }
@@ -463,12 +693,20 @@ where
 
                                     match app.search_mode {
                                         SearchMode::Repository | SearchMode::Trending => {
+                                            app.last_query = app.search_input.trim().to_string();
                                             let query_str = app.get_search_query();
-                                            match on_search(&query_str).await {
+                                            match with_spinner(
+                                                &mut app,
+                                                &mut terminal,
+                                                None,
+                                                on_search(&query_str, &mut |_| {}),
+                                            )
+                                            .await
+                                            {
                                                 Ok(results) => {
                                                     // Record search in history
                                                     let result_count = results.len();
-                                                    app.set_results(results);
+                                                    app.set_search_results(results);
                                                     app.loading = false;
                                                     app.error_message = None;
 
@@ -486,7 +724,7 @@ where
                                                 }
                                                 Err(e) => {
                                                     app.error_message =
-                                                        Some(format!("Search failed: {}", e));
+                                                        Some(describe_error("Search failed", &e));
                                                     app.loading = false;
                                                 }
                                             }
@@ -506,7 +744,14 @@ where
                                             // Hybrid semantic search from history
                                             let query_str = app.get_search_query();
 
-                                            match on_search(&query_str).await {
+                                            match with_spinner(
+                                                &mut app,
+                                                &mut terminal,
+                                                None,
+                                                on_search(&query_str, &mut |_| {}),
+                                            )
+                                            .await
+                                            {
                                                 Ok(keyword_results) => {
                                                     if keyword_results.is_empty() {
                                                         app.error_message = Some(
@@ -523,14 +768,11 @@ where
                                                             Ok(engine) => {
                                                                 match engine.initialize().await {
                                                                     Ok(_) => {
-                                                                        let keyword_pairs: Vec<(reposcout_core::models::Repository, f32)> = keyword_results
-                                                                        .into_iter()
-                                                                        .enumerate()
-                                                                        .map(|(i, repo)| {
-                                                                            let score = 1.0 - (i as f32 / 100.0).min(0.9);
-                                                                            (repo, score)
-                                                                        })
-                                                                        .collect();
+                                                                        let keyword_pairs = engine
+                                                                            .score_keyword_results(
+                                                                                &query_str,
+                                                                                keyword_results,
+                                                                            );
 
                                                                         match engine
                                                                             .hybrid_search(
@@ -574,7 +816,7 @@ where
                                                 }
                                                 Err(e) => {
                                                     app.error_message =
-                                                        Some(format!("Search failed: {}", e));
+                                                        Some(describe_error("Search failed", &e));
                                                     app.loading = false;
                                                 }
                                             }
@@ -605,10 +847,10 @@ where
                                             app.theme_selector_index += 1;
                                         }
                                     }
-                                    KeyCode::Char('k') | KeyCode::Up => {
-                                        if app.theme_selector_index > 0 {
-                                            app.theme_selector_index -= 1;
-                                        }
+                                    KeyCode::Char('k') | KeyCode::Up
+                                        if app.theme_selector_index > 0 =>
+                                    {
+                                        app.theme_selector_index -= 1;
                                     }
                                     KeyCode::Enter => {
                                         // Apply selected theme
@@ -616,6 +858,7 @@ where
                                         if let Some(theme) = themes.get(app.theme_selector_index) {
                                             app.set_theme(theme.clone());
                                             app.show_theme_selector = false;
+                                            save_theme(&mut app);
                                         }
                                     }
                                     _ => {}
@@ -626,8 +869,35 @@ where
                             // Special handling when keybindings help is open
                             if app.show_keybindings_help {
                                 match key.code {
-                                    KeyCode::Esc | KeyCode::Char('?') => {
-                                        app.show_keybindings_help = false;
+                                    KeyCode::Esc => {
+                                        // First ESC clears the search, second closes
+                                        if app.help_filter.is_empty() {
+                                            app.toggle_keybindings_help();
+                                        } else {
+                                            app.help_filter.clear();
+                                            app.help_scroll = 0;
+                                        }
+                                    }
+                                    KeyCode::Char('?') => {
+                                        app.toggle_keybindings_help();
+                                    }
+                                    KeyCode::Down => {
+                                        app.help_scroll = app.help_scroll.saturating_add(1);
+                                    }
+                                    KeyCode::Up => {
+                                        app.help_scroll = app.help_scroll.saturating_sub(1);
+                                    }
+                                    KeyCode::PageDown => {
+                                        app.help_scroll = app.help_scroll.saturating_add(10);
+                                    }
+                                    KeyCode::PageUp => {
+                                        app.help_scroll = app.help_scroll.saturating_sub(10);
+                                    }
+                                    KeyCode::Backspace => {
+                                        app.pop_help_filter();
+                                    }
+                                    KeyCode::Char(c) => {
+                                        app.push_help_filter(c);
                                     }
                                     _ => {}
                                 }
@@ -656,13 +926,17 @@ where
                                         }
                                     }
                                     KeyCode::Char('+') | KeyCode::Char('=') => {
-                                        if app.trending_option_cursor == 2 {
-                                            app.increase_trending_min_stars();
+                                        match app.trending_option_cursor {
+                                            2 => app.increase_trending_min_stars(),
+                                            5 => app.increase_trending_language_percent(),
+                                            _ => {}
                                         }
                                     }
                                     KeyCode::Char('-') | KeyCode::Char('_') => {
-                                        if app.trending_option_cursor == 2 {
-                                            app.decrease_trending_min_stars();
+                                        match app.trending_option_cursor {
+                                            2 => app.decrease_trending_min_stars(),
+                                            5 => app.decrease_trending_language_percent(),
+                                            _ => {}
                                         }
                                     }
                                     KeyCode::Char(c)
@@ -755,12 +1029,44 @@ where
                                 continue;
                             }
 
+                            let uses_results_list = matches!(
+                                app.search_mode,
+                                SearchMode::Repository
+                                    | SearchMode::Trending
+                                    | SearchMode::Semantic
+                                    | SearchMode::Portfolio
+                            );
+
+                            // Ctrl+D / Ctrl+U: half-page down / up
+                            if key.modifiers.contains(KeyModifiers::CONTROL)
+                                && matches!(key.code, KeyCode::Char('d') | KeyCode::Char('u'))
+                            {
+                                if uses_results_list {
+                                    if key.code == KeyCode::Char('d') {
+                                        app.half_page_down();
+                                    } else {
+                                        app.half_page_up();
+                                    }
+                                }
+                                continue;
+                            }
+
+                            // g / G: jump to top / bottom
+                            if uses_results_list
+                                && matches!(key.code, KeyCode::Char('g') | KeyCode::Char('G'))
+                            {
+                                if key.code == KeyCode::Char('g') {
+                                    app.jump_to_top();
+                                } else {
+                                    app.jump_to_bottom();
+                                }
+                                continue;
+                            }
+
                             match key.code {
-                                KeyCode::Esc => {
+                                KeyCode::Esc if app.error_message.is_some() => {
                                     // Clear error message if present
-                                    if app.error_message.is_some() {
-                                        app.clear_error();
-                                    }
+                                    app.clear_error();
                                 }
                                 KeyCode::Char('q') => {
                                     break;
@@ -771,171 +1077,67 @@ where
 
                                     // Fetch notifications when entering notification mode
                                     if app.search_mode == SearchMode::Notifications {
-                                        app.notifications_loading = true;
-                                        terminal.clear()?;
-                                        terminal.draw(|f| crate::ui::render(f, &mut app))?;
-
-                                        match github_client
-                                            .get_notifications(
-                                                app.notifications_show_all,
-                                                app.notifications_participating,
-                                                50,
-                                            )
-                                            .await
-                                        {
-                                            Ok(notifications) => {
-                                                app.notifications = notifications;
-                                                app.notifications_selected_index = 0;
-                                                app.notifications_loading = false;
-                                                app.error_message = None;
-                                            }
-                                            Err(e) => {
-                                                app.error_message = Some(format!(
-                                                    "Failed to fetch notifications: {}",
-                                                    e
-                                                ));
-                                                app.notifications_loading = false;
-                                            }
-                                        }
+                                        spawn_notifications_refresh(&mut app, &clients, None, true);
                                     }
 
                                     // Force full redraw
                                     terminal.clear()?;
                                 }
-                                KeyCode::Char('m') => {
+                                KeyCode::Char('m')
+                                    if app.search_mode == SearchMode::Notifications =>
+                                {
                                     // Mark selected notification as read (only in notification mode)
-                                    if app.search_mode == SearchMode::Notifications {
-                                        if let Some(notif) = app.get_selected_notification() {
-                                            let notif_id = notif.id.clone();
-                                            match github_client
-                                                .mark_notification_read(&notif_id)
-                                                .await
-                                            {
-                                                Ok(_) => {
-                                                    // Refresh notifications
-                                                    app.notifications_loading = true;
-                                                    terminal
-                                                        .draw(|f| crate::ui::render(f, &mut app))?;
-
-                                                    match github_client
-                                                        .get_notifications(
-                                                            app.notifications_show_all,
-                                                            app.notifications_participating,
-                                                            50,
-                                                        )
-                                                        .await
-                                                    {
-                                                        Ok(notifications) => {
-                                                            app.notifications = notifications;
-                                                            app.notifications_loading = false;
-                                                            app.error_message = None;
-                                                        }
-                                                        Err(e) => {
-                                                            app.error_message = Some(format!(
-                                                                "Failed to refresh: {}",
-                                                                e
-                                                            ));
-                                                            app.notifications_loading = false;
-                                                        }
-                                                    }
-                                                }
-                                                Err(e) => {
-                                                    app.error_message = Some(format!(
-                                                        "Failed to mark as read: {}",
-                                                        e
-                                                    ));
-                                                }
-                                            }
-                                        }
+                                    if let Some(notif) = app.get_selected_notification() {
+                                        let notif_id = notif.id.clone();
+                                        spawn_notifications_refresh(
+                                            &mut app,
+                                            &clients,
+                                            Some(MarkRead::One(notif_id)),
+                                            false,
+                                        );
                                     }
                                 }
-                                KeyCode::Char('a') => {
+                                KeyCode::Char('a')
+                                    if app.search_mode == SearchMode::Notifications =>
+                                {
                                     // Mark all notifications as read (only in notification mode)
-                                    if app.search_mode == SearchMode::Notifications {
-                                        match github_client.mark_all_notifications_read().await {
-                                            Ok(_) => {
-                                                // Refresh notifications
-                                                app.notifications_loading = true;
-                                                terminal
-                                                    .draw(|f| crate::ui::render(f, &mut app))?;
-
-                                                match github_client
-                                                    .get_notifications(
-                                                        app.notifications_show_all,
-                                                        app.notifications_participating,
-                                                        50,
-                                                    )
-                                                    .await
-                                                {
-                                                    Ok(notifications) => {
-                                                        app.notifications = notifications;
-                                                        app.notifications_loading = false;
-                                                        app.error_message = None;
-                                                    }
-                                                    Err(e) => {
-                                                        app.error_message = Some(format!(
-                                                            "Failed to refresh: {}",
-                                                            e
-                                                        ));
-                                                        app.notifications_loading = false;
-                                                    }
-                                                }
-                                            }
-                                            Err(e) => {
-                                                app.error_message = Some(format!(
-                                                    "Failed to mark all as read: {}",
-                                                    e
-                                                ));
-                                            }
-                                        }
-                                    }
+                                    spawn_notifications_refresh(
+                                        &mut app,
+                                        &clients,
+                                        Some(MarkRead::All),
+                                        false,
+                                    );
                                 }
-                                KeyCode::Char('p') => {
+                                KeyCode::Char('p')
+                                    if app.search_mode == SearchMode::Notifications =>
+                                {
                                     // Toggle participating filter (only in notification mode)
-                                    if app.search_mode == SearchMode::Notifications {
-                                        app.toggle_participating_filter();
-
-                                        // Refresh notifications with new filter
-                                        app.notifications_loading = true;
-                                        terminal.draw(|f| crate::ui::render(f, &mut app))?;
+                                    app.toggle_participating_filter();
 
-                                        match github_client
-                                            .get_notifications(
-                                                app.notifications_show_all,
-                                                app.notifications_participating,
-                                                50,
-                                            )
-                                            .await
-                                        {
-                                            Ok(notifications) => {
-                                                app.notifications = notifications;
-                                                app.notifications_selected_index = 0;
-                                                app.notifications_loading = false;
-                                                app.error_message = None;
-                                            }
-                                            Err(e) => {
-                                                app.error_message = Some(format!(
-                                                    "Failed to fetch notifications: {}",
-                                                    e
-                                                ));
-                                                app.notifications_loading = false;
-                                            }
-                                        }
-                                    }
+                                    // Refresh notifications with new filter
+                                    spawn_notifications_refresh(&mut app, &clients, None, true);
                                 }
-                                KeyCode::Char('/') => {
-                                    // Enter search mode unless in trending/notification mode
+                                KeyCode::Char('w')
+                                    if app.search_mode == SearchMode::Notifications =>
+                                {
+                                    // Cycle the reason filter (only in notification mode)
+                                    app.cycle_notification_reason();
+                                }
+                                KeyCode::Char(':') => {
+                                    app.enter_command_mode();
+                                }
+                                KeyCode::Char('/')
                                     if app.search_mode != SearchMode::Trending
-                                        && app.search_mode != SearchMode::Notifications
-                                    {
-                                        app.enter_search_mode();
-                                    }
+                                        && app.search_mode != SearchMode::Notifications =>
+                                {
+                                    // Enter search mode unless in trending/notification mode
+                                    app.enter_search_mode();
                                 }
-                                KeyCode::Char('o') | KeyCode::Char('O') => {
+                                KeyCode::Char('o') | KeyCode::Char('O')
+                                    if app.search_mode == SearchMode::Trending =>
+                                {
                                     // Toggle trending options (only in trending mode)
-                                    if app.search_mode == SearchMode::Trending {
-                                        app.toggle_trending_options();
-                                    }
+                                    app.toggle_trending_options();
                                 }
                                 KeyCode::Enter => {
                                     // Trigger trending search when in trending mode
@@ -991,10 +1193,6 @@ where
                                         };
                                         query_parts.push(date_filter);
 
-                                        if let Some(ref lang) = app.trending_filters.language {
-                                            query_parts.push(format!("language:{}", lang));
-                                        }
-
                                         if app.trending_filters.min_stars > 0 {
                                             query_parts.push(format!(
                                                 "stars:>={}",
@@ -1006,29 +1204,79 @@ where
                                             query_parts.push(format!("topic:{}", topic));
                                         }
 
-                                        let query = query_parts.join(" ");
+                                        let base_query = query_parts.join(" ");
+                                        let query = match app.trending_filters.language {
+                                            Some(ref lang) => {
+                                                format!("{} language:{}", base_query, lang)
+                                            }
+                                            None => base_query.clone(),
+                                        };
+
+                                        let mut search_result = with_spinner(
+                                            &mut app,
+                                            &mut terminal,
+                                            None,
+                                            on_search(&query, &mut |_| {}),
+                                        )
+                                        .await;
+
+                                        // Secondary-language matching: also pull repos whose
+                                        // primary language differs but that use the target
+                                        // language for enough of their code
+                                        let min_percent = app.trending_filters.min_language_percent;
+                                        if let (Ok(results), Some(lang), true) = (
+                                            &mut search_result,
+                                            app.trending_filters.language.clone(),
+                                            min_percent > 0,
+                                        ) {
+                                            if let Ok(mut candidates) = with_spinner(
+                                                &mut app,
+                                                &mut terminal,
+                                                None,
+                                                on_search(&base_query, &mut |_| {}),
+                                            )
+                                            .await
+                                            {
+                                                candidates.retain(|c| {
+                                                    !results.iter().any(|r| {
+                                                        r.platform == c.platform
+                                                            && r.full_name == c.full_name
+                                                    })
+                                                });
+                                                // Only GitHub and GitLab report languages
+                                                let github = reposcout_core::providers::GitHubProvider::with_client(
+                                                    github_client.clone(),
+                                                );
+                                                let gitlab = reposcout_core::providers::GitLabProvider::with_client(
+                                                    gitlab_client.clone(),
+                                                );
+                                                let mut finder =
+                                                    reposcout_core::TrendingFinder::new();
+                                                finder.add_provider(&github);
+                                                finder.add_provider(&gitlab);
+                                                let matching = with_spinner(
+                                                    &mut app,
+                                                    &mut terminal,
+                                                    None,
+                                                    finder.retain_language_share(
+                                                        candidates,
+                                                        &lang,
+                                                        f64::from(min_percent),
+                                                    ),
+                                                )
+                                                .await;
+                                                results.extend(matching);
+                                                results.sort_by_key(|r| std::cmp::Reverse(r.stars));
+                                            }
+                                        }
 
-                                        match on_search(&query).await {
+                                        match search_result {
                                             Ok(mut results) => {
                                                 // Sort by velocity if requested
                                                 if app.trending_filters.sort_by_velocity {
-                                                    results.sort_by(|a, b| {
-                                                        let age_a = (chrono::Utc::now()
-                                                            - a.created_at)
-                                                            .num_days()
-                                                            .max(1)
-                                                            as f64;
-                                                        let age_b = (chrono::Utc::now()
-                                                            - b.created_at)
-                                                            .num_days()
-                                                            .max(1)
-                                                            as f64;
-                                                        let velocity_a = a.stars as f64 / age_a;
-                                                        let velocity_b = b.stars as f64 / age_b;
-                                                        velocity_b
-                                                            .partial_cmp(&velocity_a)
-                                                            .unwrap_or(std::cmp::Ordering::Equal)
-                                                    });
+                                                    reposcout_core::sort_by_star_velocity(
+                                                        &mut results,
+                                                    );
                                                 }
 
                                                 app.set_results(results);
@@ -1055,10 +1303,17 @@ where
                                                     query
                                                 ));
 
-                                                match on_search(&query).await {
+                                                match with_spinner(
+                                                    &mut app,
+                                                    &mut terminal,
+                                                    None,
+                                                    on_search(&query, &mut |_| {}),
+                                                )
+                                                .await
+                                                {
                                                     Ok(results) => {
                                                         let count = results.len();
-                                                        app.set_results(results);
+                                                        app.set_search_results(results);
                                                         app.selected_index = 0;
                                                         app.list_state.select(Some(0));
                                                         app.loading = false;
@@ -1068,8 +1323,10 @@ where
                                                         ));
                                                     }
                                                     Err(e) => {
-                                                        app.error_message =
-                                                            Some(format!("Search failed: {}", e));
+                                                        app.error_message = Some(describe_error(
+                                                            "Search failed",
+                                                            &e,
+                                                        ));
                                                         app.loading = false;
                                                     }
                                                 }
@@ -1087,10 +1344,17 @@ where
                                                     query
                                                 ));
 
-                                                match on_search(&query).await {
+                                                match with_spinner(
+                                                    &mut app,
+                                                    &mut terminal,
+                                                    None,
+                                                    on_search(&query, &mut |_| {}),
+                                                )
+                                                .await
+                                                {
                                                     Ok(results) => {
                                                         let count = results.len();
-                                                        app.set_results(results);
+                                                        app.set_search_results(results);
                                                         app.selected_index = 0;
                                                         app.list_state.select(Some(0));
                                                         app.loading = false;
@@ -1100,15 +1364,16 @@ where
                                                         ));
                                                     }
                                                     Err(e) => {
-                                                        app.error_message =
-                                                            Some(format!("Search failed: {}", e));
+                                                        app.error_message = Some(describe_error(
+                                                            "Search failed",
+                                                            &e,
+                                                        ));
                                                         app.loading = false;
                                                     }
                                                 }
                                             }
                                             crate::DiscoveryCategory::Topics => {
-                                                let topics =
-                                                    reposcout_core::discovery::popular_topics();
+                                                let topics = app.topic_suggestions.entries();
                                                 if let Some((topic, name)) =
                                                     topics.get(app.discovery_cursor)
                                                 {
@@ -1124,10 +1389,17 @@ where
                                                         name, query
                                                     ));
 
-                                                    match on_search(&query).await {
+                                                    match with_spinner(
+                                                        &mut app,
+                                                        &mut terminal,
+                                                        None,
+                                                        on_search(&query, &mut |_| {}),
+                                                    )
+                                                    .await
+                                                    {
                                                         Ok(results) => {
                                                             let count = results.len();
-                                                            app.set_results(results);
+                                                            app.set_search_results(results);
                                                             app.selected_index = 0;
                                                             app.list_state.select(Some(0));
                                                             app.loading = false;
@@ -1247,37 +1519,44 @@ where
                                         }
                                     }
                                 }
-                                KeyCode::Char('f') => {
-                                    if app.search_mode == SearchMode::Notifications {
-                                        // Toggle all/unread filter in notification mode
-                                        app.toggle_notification_filter();
-
-                                        // Refresh notifications with new filter
-                                        app.notifications_loading = true;
-                                        terminal.draw(|f| crate::ui::render(f, &mut app))?;
-
-                                        match github_client
-                                            .get_notifications(
-                                                app.notifications_show_all,
-                                                app.notifications_participating,
-                                                50,
-                                            )
-                                            .await
-                                        {
-                                            Ok(notifications) => {
-                                                app.notifications = notifications;
-                                                app.notifications_selected_index = 0;
-                                                app.notifications_loading = false;
-                                                app.error_message = None;
-                                            }
-                                            Err(e) => {
-                                                app.error_message = Some(format!(
-                                                    "Failed to fetch notifications: {}",
-                                                    e
-                                                ));
-                                                app.notifications_loading = false;
-                                            }
+                                KeyCode::Char('i') | KeyCode::Char('P') => {
+                                    // Jump straight to the issues or pull/merge requests page
+                                    let issues = key.code == KeyCode::Char('i');
+                                    if let Some(repo) = app.selected_repository() {
+                                        let (url, page) = if issues {
+                                            (repo.issues_url(), "issues")
+                                        } else {
+                                            (repo.pulls_url(), "pull requests")
+                                        };
+                                        let platform = repo.platform;
+                                        match url {
+                                            Some(url) => match open::that(&url) {
+                                                Ok(()) => {
+                                                    app.set_temp_error(format!("Opening {}", url))
+                                                }
+                                                Err(e) => {
+                                                    app.error_message = Some(format!(
+                                                        "Failed to open browser: {}",
+                                                        e
+                                                    ))
+                                                }
+                                            },
+                                            None => app.set_temp_error(format!(
+                                                "{} has no {} page for this repository",
+                                                platform, page
+                                            )),
                                         }
+                                    } else {
+                                        app.set_temp_error("No repository selected".to_string());
+                                    }
+                                }
+                                KeyCode::Char('f') => {
+                                    if app.search_mode == SearchMode::Notifications {
+                                        // Toggle all/unread filter in notification mode
+                                        app.toggle_notification_filter();
+
+                                        // Refresh notifications with new filter
+                                        spawn_notifications_refresh(&mut app, &clients, None, true);
                                     } else {
                                         // Enter fuzzy search mode in other modes
                                         if !app.results.is_empty() {
@@ -1314,6 +1593,27 @@ where
                                         }
                                     }
                                 }
+                                KeyCode::Char('x') => {
+                                    // Mark the current repository as reviewed, or unmark it
+                                    if let Some(repo) = app.selected_repository() {
+                                        let platform = repo.platform.to_string().to_lowercase();
+                                        let full_name = repo.full_name.clone();
+
+                                        let saved = match app.toggle_current_reviewed() {
+                                            Some(true) => cache.add_reviewed(&platform, &full_name),
+                                            Some(false) => cache
+                                                .remove_reviewed(&platform, &full_name)
+                                                .map(|_| ()),
+                                            None => Ok(()),
+                                        };
+                                        if let Err(e) = saved {
+                                            app.set_temp_error(format!(
+                                                "Failed to save reviewed state: {}",
+                                                e
+                                            ));
+                                        }
+                                    }
+                                }
                                 KeyCode::Char('B') => {
                                     // Toggle bookmarks view
                                     app.toggle_bookmarks_view();
@@ -1327,6 +1627,27 @@ where
                                         }
                                     }
                                 }
+                                KeyCode::Char('t') => {
+                                    if app.search_mode == SearchMode::Notifications {
+                                        // Cycle the subject type filter
+                                        app.cycle_notification_type();
+                                    } else if app.search_mode == SearchMode::Code {
+                                        // Cycle the code preview's syntax theme
+                                        app.next_code_theme();
+                                        save_code_theme(&mut app);
+                                        app.set_temp_error(format!(
+                                            "Code theme: {}",
+                                            app.code_theme_name
+                                        ));
+                                    } else if app.show_bookmarks_only {
+                                        // Edit the selected bookmark's tags
+                                        app.start_bookmark_edit(crate::BookmarkField::Tags);
+                                    }
+                                }
+                                KeyCode::Char('e') if app.show_bookmarks_only => {
+                                    // Edit the selected bookmark's notes
+                                    app.start_bookmark_edit(crate::BookmarkField::Notes);
+                                }
                                 KeyCode::Char('T') => {
                                     // Toggle theme selector
                                     app.show_theme_selector = !app.show_theme_selector;
@@ -1341,7 +1662,7 @@ where
                                 }
                                 KeyCode::Char('?') => {
                                     // Toggle keybindings help
-                                    app.show_keybindings_help = !app.show_keybindings_help;
+                                    app.toggle_keybindings_help();
                                 }
                                 KeyCode::Char('N') => {
                                     if app.search_mode == SearchMode::Code {
@@ -1356,6 +1677,7 @@ where
                                             reposcout_core::PortfolioIcon::Work,
                                         );
                                         app.selected_portfolio_id = Some(portfolio.id.clone());
+                                        save_portfolios(&mut app);
                                         app.set_temp_error(format!(
                                             "Created portfolio: {}",
                                             portfolio.name
@@ -1373,6 +1695,7 @@ where
                                                     app.set_temp_error(
                                                         "Added repository to portfolio".to_string(),
                                                     );
+                                                    save_portfolios(&mut app);
                                                 }
                                                 Err(e) => {
                                                     app.set_temp_error(format!(
@@ -1403,6 +1726,7 @@ where
                                                         "Removed repository from portfolio"
                                                             .to_string(),
                                                     );
+                                                    save_portfolios(&mut app);
                                                 }
                                                 Err(e) => {
                                                     app.set_temp_error(format!(
@@ -1418,23 +1742,34 @@ where
                                         app.set_temp_error("No repository selected".to_string());
                                     }
                                 }
-                                KeyCode::Char('c') => {
-                                    // Copy install command when in Package preview mode
+                                KeyCode::Char('c')
                                     if (app.search_mode == SearchMode::Repository
                                         || app.search_mode == SearchMode::Trending
                                         || app.search_mode == SearchMode::Semantic)
-                                        && app.preview_mode == crate::PreviewMode::Package
-                                    {
-                                        match app.copy_package_install_command() {
-                                            Ok(()) => {
-                                                app.set_temp_error(
-                                                    "Install command copied to clipboard!"
-                                                        .to_string(),
-                                                );
-                                            }
-                                            Err(e) => {
-                                                app.set_temp_error(e);
-                                            }
+                                        && app.preview_mode == crate::PreviewMode::Package =>
+                                {
+                                    // Copy install command when in Package preview mode
+                                    match app.copy_package_install_command() {
+                                        Ok(()) => {
+                                            app.set_temp_error(
+                                                "Install command copied to clipboard!".to_string(),
+                                            );
+                                        }
+                                        Err(e) => {
+                                            app.set_temp_error(e);
+                                        }
+                                    }
+                                }
+                                KeyCode::Char('y') => {
+                                    // Yank the selected URL
+                                    match app.copy_selected_url() {
+                                        Ok(()) => {
+                                            app.set_temp_error(
+                                                "URL copied to clipboard!".to_string(),
+                                            );
+                                        }
+                                        Err(e) => {
+                                            app.set_temp_error(e);
                                         }
                                     }
                                 }
@@ -1511,53 +1846,32 @@ where
                                             let repo_name = repo.full_name.clone();
                                             let platform = repo.platform;
 
-                                            // Check if already cached
-                                            if !app.readme_cache.contains_key(&repo_name) {
+                                            // Load from cache, or fetch unless offline
+                                            if app.readme_cache.contains_key(&repo_name) {
+                                                app.load_readme_for_current();
+                                                app.toggle_preview_mode();
+                                            } else if !blocked_offline(&mut app, "README") {
                                                 // Mark as loading
                                                 app.start_readme_loading();
                                                 app.toggle_preview_mode();
 
-                                                // Fetch README based on platform
-                                                let readme_result: anyhow::Result<String> = match platform {
-                                            reposcout_core::models::Platform::GitHub => {
-                                                let parts: Vec<&str> = repo_name.split('/').collect();
-                                                if parts.len() == 2 {
-                                                    github_client.get_readme(parts[0], parts[1]).await.map_err(|e| anyhow::anyhow!("{}", e))
-                                                } else {
-                                                    Err(anyhow::anyhow!("Invalid repository name format"))
-                                                }
-                                            }
-                                            reposcout_core::models::Platform::GitLab => {
-                                                gitlab_client.get_readme(&repo_name).await.map_err(|e| anyhow::anyhow!("{}", e))
-                                            }
-                                            reposcout_core::models::Platform::Bitbucket => {
-                                                let parts: Vec<&str> = repo_name.split('/').collect();
-                                                if parts.len() == 2 {
-                                                    bitbucket_client.get_readme(parts[0], parts[1]).await.map_err(|e| anyhow::anyhow!("{}", e))
-                                                } else {
-                                                    Err(anyhow::anyhow!("Invalid repository name format"))
-                                                }
-                                            }
-                                        };
-
-                                                match readme_result {
-                                                    Ok(readme) => {
-                                                        app.cache_readme(repo_name, readme.clone());
-                                                        app.set_readme(readme);
-                                                    }
-                                                    Err(e) => {
-                                                        let error_msg = format!("# README Not Available\n\nFailed to fetch README: {}", e);
-                                                        app.cache_readme(
-                                                            repo_name,
-                                                            error_msg.clone(),
-                                                        );
-                                                        app.set_readme(error_msg);
-                                                    }
+                                                // A prefetch already on its way fills this in when it lands
+                                                if !app.readme_prefetch.is_in_flight(&repo_name) {
+                                                    let clients = clients.clone();
+                                                    app.tasks.spawn(
+                                                        TaskKind::Readme,
+                                                        format!(
+                                                            "Fetching README for {}",
+                                                            repo_name
+                                                        ),
+                                                        async move {
+                                                            let result = clients
+                                                                .readme(platform, &repo_name)
+                                                                .await;
+                                                            TaskOutput::Readme { repo_name, result }
+                                                        },
+                                                    );
                                                 }
-                                            } else {
-                                                // Load from cache
-                                                app.load_readme_for_current();
-                                                app.toggle_preview_mode();
                                             }
                                         } else {
                                             app.toggle_preview_mode();
@@ -1567,6 +1881,49 @@ where
                                         app.toggle_preview_mode();
                                     }
                                 }
+                                KeyCode::Char('C') => {
+                                    use crate::PreviewMode;
+
+                                    if app.preview_mode == PreviewMode::Changelog {
+                                        // Toggle back to stats
+                                        app.preview_mode = PreviewMode::Stats;
+                                    } else if let Some(repo) = app.selected_repository() {
+                                        let repo_name = repo.full_name.clone();
+                                        let platform = repo.platform;
+
+                                        app.preview_mode = PreviewMode::Changelog;
+                                        app.reset_readme_scroll();
+
+                                        if !app.changelog_cache.contains_key(&repo_name)
+                                            && !blocked_offline(&mut app, "Changelog")
+                                        {
+                                            app.changelog_loading = true;
+
+                                            let clients = clients.clone();
+                                            app.tasks.spawn(
+                                                TaskKind::Changelog,
+                                                format!("Fetching changelog for {}", repo_name),
+                                                async move {
+                                                    let result = clients
+                                                        .changelog(platform, &repo_name)
+                                                        .await;
+                                                    TaskOutput::Changelog { repo_name, result }
+                                                },
+                                            );
+                                        }
+                                    }
+                                }
+                                KeyCode::Char('v')
+                                    if app.preview_mode == crate::PreviewMode::Dependencies =>
+                                {
+                                    check_vulnerabilities(&mut app, &clients);
+                                }
+                                KeyCode::Char('z') => {
+                                    app.cycle_grouping();
+                                }
+                                KeyCode::Char('s') => {
+                                    find_similar(&mut app);
+                                }
                                 KeyCode::Char('d') | KeyCode::Char('D') => {
                                     use crate::PreviewMode;
 
@@ -1587,280 +1944,166 @@ where
                                         let language = repo.language.clone();
 
                                         // Check if already cached
-                                        if !app.dependencies_cache.contains_key(&repo_name) {
+                                        if !app.dependencies_cache.contains_key(&repo_name)
+                                            && !blocked_offline(&mut app, "Dependency analysis")
+                                        {
                                             // Switch to dependencies view
                                             app.preview_mode = PreviewMode::Dependencies;
                                             app.start_dependencies_loading();
 
-                                            // Determine which dependency file to fetch based on language
-                                            let deps_result: anyhow::Result<Option<reposcout_deps::DependencyInfo>> = match language.as_deref() {
-                                        Some("Rust") => {
-                                            match platform {
-                                                reposcout_core::models::Platform::GitHub => {
-                                                    let parts: Vec<&str> = repo_name.split('/').collect();
-                                                    if parts.len() == 2 {
-                                                        match github_client.get_cargo_toml(parts[0], parts[1]).await {
-                                                            Ok(content) => {
-                                                                reposcout_deps::parse_cargo_toml(&content)
-                                                                    .map(Some)
-                                                                    .map_err(|e| anyhow::anyhow!("{}", e))
-                                                            }
-                                                            Err(_) => Ok(None),
-                                                        }
-                                                    } else {
-                                                        Err(anyhow::anyhow!("Invalid repository name format"))
-                                                    }
-                                                }
-                                                reposcout_core::models::Platform::GitLab => {
-                                                    match gitlab_client.get_cargo_toml(&repo_name).await {
-                                                        Ok(content) => {
-                                                            reposcout_deps::parse_cargo_toml(&content)
-                                                                .map(Some)
-                                                                .map_err(|e| anyhow::anyhow!("{}", e))
-                                                        }
-                                                        Err(_) => Ok(None),
-                                                    }
-                                                }
-                                                reposcout_core::models::Platform::Bitbucket => {
-                                                    let parts: Vec<&str> = repo_name.split('/').collect();
-                                                    if parts.len() == 2 {
-                                                        match bitbucket_client.get_cargo_toml(parts[0], parts[1]).await {
-                                                            Ok(content) => {
-                                                                reposcout_deps::parse_cargo_toml(&content)
-                                                                    .map(Some)
-                                                                    .map_err(|e| anyhow::anyhow!("{}", e))
-                                                            }
-                                                            Err(_) => Ok(None),
-                                                        }
-                                                    } else {
-                                                        Err(anyhow::anyhow!("Invalid repository name format"))
-                                                    }
-                                                }
-                                            }
-                                        }
-                                        Some("JavaScript") | Some("TypeScript") => {
-                                            match platform {
-                                                reposcout_core::models::Platform::GitHub => {
-                                                    let parts: Vec<&str> = repo_name.split('/').collect();
-                                                    if parts.len() == 2 {
-                                                        match github_client.get_package_json(parts[0], parts[1]).await {
-                                                            Ok(content) => {
-                                                                reposcout_deps::parse_package_json(&content)
-                                                                    .map(Some)
-                                                                    .map_err(|e| anyhow::anyhow!("{}", e))
-                                                            }
-                                                            Err(_) => Ok(None),
-                                                        }
-                                                    } else {
-                                                        Err(anyhow::anyhow!("Invalid repository name format"))
-                                                    }
-                                                }
-                                                reposcout_core::models::Platform::GitLab => {
-                                                    match gitlab_client.get_package_json(&repo_name).await {
-                                                        Ok(content) => {
-                                                            reposcout_deps::parse_package_json(&content)
-                                                                .map(Some)
-                                                                .map_err(|e| anyhow::anyhow!("{}", e))
-                                                        }
-                                                        Err(_) => Ok(None),
-                                                    }
-                                                }
-                                                reposcout_core::models::Platform::Bitbucket => {
-                                                    let parts: Vec<&str> = repo_name.split('/').collect();
-                                                    if parts.len() == 2 {
-                                                        match bitbucket_client.get_package_json(parts[0], parts[1]).await {
-                                                            Ok(content) => {
-                                                                reposcout_deps::parse_package_json(&content)
-                                                                    .map(Some)
-                                                                    .map_err(|e| anyhow::anyhow!("{}", e))
-                                                            }
-                                                            Err(_) => Ok(None),
-                                                        }
-                                                    } else {
-                                                        Err(anyhow::anyhow!("Invalid repository name format"))
-                                                    }
-                                                }
-                                            }
-                                        }
-                                        Some("Python") => {
-                                            match platform {
-                                                reposcout_core::models::Platform::GitHub => {
-                                                    let parts: Vec<&str> = repo_name.split('/').collect();
-                                                    if parts.len() == 2 {
-                                                        match github_client.get_requirements_txt(parts[0], parts[1]).await {
-                                                            Ok(content) => {
-                                                                reposcout_deps::parse_requirements_txt(&content)
-                                                                    .map(Some)
-                                                                    .map_err(|e| anyhow::anyhow!("{}", e))
-                                                            }
-                                                            Err(_) => Ok(None),
-                                                        }
-                                                    } else {
-                                                        Err(anyhow::anyhow!("Invalid repository name format"))
-                                                    }
-                                                }
-                                                reposcout_core::models::Platform::GitLab => {
-                                                    match gitlab_client.get_requirements_txt(&repo_name).await {
-                                                        Ok(content) => {
-                                                            reposcout_deps::parse_requirements_txt(&content)
-                                                                .map(Some)
-                                                                .map_err(|e| anyhow::anyhow!("{}", e))
-                                                        }
-                                                        Err(_) => Ok(None),
-                                                    }
-                                                }
-                                                reposcout_core::models::Platform::Bitbucket => {
-                                                    let parts: Vec<&str> = repo_name.split('/').collect();
-                                                    if parts.len() == 2 {
-                                                        match bitbucket_client.get_requirements_txt(parts[0], parts[1]).await {
-                                                            Ok(content) => {
-                                                                reposcout_deps::parse_requirements_txt(&content)
-                                                                    .map(Some)
-                                                                    .map_err(|e| anyhow::anyhow!("{}", e))
-                                                            }
-                                                            Err(_) => Ok(None),
-                                                        }
-                                                    } else {
-                                                        Err(anyhow::anyhow!("Invalid repository name format"))
-                                                    }
-                                                }
-                                            }
-                                        }
-                                        _ => Ok(None),
-                                    };
-
-                                            match deps_result {
-                                                Ok(deps) => {
-                                                    app.cache_dependencies(repo_name, deps);
-                                                }
-                                                Err(e) => {
-                                                    app.error_message = Some(format!(
-                                                        "Failed to fetch dependencies: {}",
-                                                        e
-                                                    ));
-                                                    app.cache_dependencies(repo_name, None);
-                                                }
-                                            }
-
-                                            app.stop_dependencies_loading();
+                                            // Fetch the manifest matching the repository's language
+                                            let clients = clients.clone();
+                                            app.tasks.spawn(
+                                                TaskKind::Dependencies,
+                                                format!("Analyzing dependencies of {}", repo_name),
+                                                async move {
+                                                    let result = clients
+                                                        .dependencies(
+                                                            platform,
+                                                            &repo_name,
+                                                            language.as_deref(),
+                                                        )
+                                                        .await;
+                                                    TaskOutput::Dependencies { repo_name, result }
+                                                },
+                                            );
                                         } else {
                                             // Already cached, just switch to dependencies view
                                             app.preview_mode = PreviewMode::Dependencies;
                                         }
                                     }
                                 }
-                                KeyCode::Char('h') => {
+                                KeyCode::Char('h') if app.search_mode == SearchMode::Discovery => {
                                     // In Discovery mode, go to previous category
-                                    if app.search_mode == SearchMode::Discovery {
-                                        app.previous_discovery_category();
-                                        app.discovery_cursor = 0; // Reset cursor when switching categories
-                                    }
+                                    app.previous_discovery_category();
+                                    app.discovery_cursor = 0; // Reset cursor when switching categories
                                 }
-                                KeyCode::Char('l') => {
+                                KeyCode::Char('l') if app.search_mode == SearchMode::Discovery => {
                                     // In Discovery mode, go to next category
-                                    if app.search_mode == SearchMode::Discovery {
-                                        app.next_discovery_category();
-                                        app.discovery_cursor = 0; // Reset cursor when switching categories
-                                    }
+                                    app.next_discovery_category();
+                                    app.discovery_cursor = 0; // Reset cursor when switching categories
                                 }
-                                KeyCode::Backspace => {
+                                KeyCode::Backspace if app.search_mode != SearchMode::Discovery => {
                                     // Quick shortcut to return to Discovery mode
-                                    if app.search_mode != SearchMode::Discovery {
-                                        app.search_mode = SearchMode::Discovery;
-                                        app.results.clear();
-                                        app.error_message = None;
-                                        app.discovery_cursor = 0; // Reset cursor
+                                    app.search_mode = SearchMode::Discovery;
+                                    app.results.clear();
+                                    app.error_message = None;
+                                    app.discovery_cursor = 0; // Reset cursor
+                                }
+                                KeyCode::Char(c)
+                                    if c.is_ascii_digit()
+                                        && app.preview_mode == crate::PreviewMode::Readme
+                                        && app.search_mode != SearchMode::Discovery
+                                        && app.readme_content.is_some() =>
+                                {
+                                    // Open a README link by its footnote number
+                                    let digit = c.to_digit(10).unwrap_or(0);
+                                    if let Some(url) =
+                                        app.push_footnote_digit(digit, std::time::Instant::now())
+                                    {
+                                        open_footnote(&mut app, &url);
                                     }
                                 }
-                                KeyCode::Char('1') => {
-                                    // In New & Notable, search last 7 days
+                                KeyCode::Char('1')
                                     if app.search_mode == SearchMode::Discovery
                                         && app.discovery_category
-                                            == crate::DiscoveryCategory::NewAndNotable
-                                    {
-                                        let query =
-                                            reposcout_core::discovery::new_and_notable_query(
-                                                None, 7,
-                                            );
-                                        app.search_input = query.clone();
-                                        app.search_mode = SearchMode::Repository;
-                                        app.loading = true;
+                                            == crate::DiscoveryCategory::NewAndNotable =>
+                                {
+                                    // In New & Notable, search last 7 days
+                                    let query =
+                                        reposcout_core::discovery::new_and_notable_query(None, 7);
+                                    app.search_input = query.clone();
+                                    app.search_mode = SearchMode::Repository;
+                                    app.loading = true;
 
-                                        match on_search(&query).await {
-                                            Ok(results) => {
-                                                app.set_results(results);
-                                                app.selected_index = 0;
-                                                app.list_state.select(Some(0));
-                                                app.loading = false;
-                                            }
-                                            Err(e) => {
-                                                app.error_message =
-                                                    Some(format!("Search failed: {}", e));
-                                                app.loading = false;
-                                            }
+                                    match with_spinner(
+                                        &mut app,
+                                        &mut terminal,
+                                        None,
+                                        on_search(&query, &mut |_| {}),
+                                    )
+                                    .await
+                                    {
+                                        Ok(results) => {
+                                            app.set_search_results(results);
+                                            app.selected_index = 0;
+                                            app.list_state.select(Some(0));
+                                            app.loading = false;
+                                        }
+                                        Err(e) => {
+                                            app.error_message =
+                                                Some(describe_error("Search failed", &e));
+                                            app.loading = false;
                                         }
                                     }
                                 }
-                                KeyCode::Char('2') => {
-                                    // In New & Notable, search last 30 days
+                                KeyCode::Char('2')
                                     if app.search_mode == SearchMode::Discovery
                                         && app.discovery_category
-                                            == crate::DiscoveryCategory::NewAndNotable
-                                    {
-                                        let query =
-                                            reposcout_core::discovery::new_and_notable_query(
-                                                None, 30,
-                                            );
-                                        app.search_input = query.clone();
-                                        app.search_mode = SearchMode::Repository;
-                                        app.loading = true;
+                                            == crate::DiscoveryCategory::NewAndNotable =>
+                                {
+                                    // In New & Notable, search last 30 days
+                                    let query =
+                                        reposcout_core::discovery::new_and_notable_query(None, 30);
+                                    app.search_input = query.clone();
+                                    app.search_mode = SearchMode::Repository;
+                                    app.loading = true;
 
-                                        match on_search(&query).await {
-                                            Ok(results) => {
-                                                app.set_results(results);
-                                                app.selected_index = 0;
-                                                app.list_state.select(Some(0));
-                                                app.loading = false;
-                                            }
-                                            Err(e) => {
-                                                app.error_message =
-                                                    Some(format!("Search failed: {}", e));
-                                                app.loading = false;
-                                            }
+                                    match with_spinner(
+                                        &mut app,
+                                        &mut terminal,
+                                        None,
+                                        on_search(&query, &mut |_| {}),
+                                    )
+                                    .await
+                                    {
+                                        Ok(results) => {
+                                            app.set_search_results(results);
+                                            app.selected_index = 0;
+                                            app.list_state.select(Some(0));
+                                            app.loading = false;
+                                        }
+                                        Err(e) => {
+                                            app.error_message =
+                                                Some(describe_error("Search failed", &e));
+                                            app.loading = false;
                                         }
                                     }
                                 }
-                                KeyCode::Char('3') => {
-                                    // In New & Notable, search last 90 days
+                                KeyCode::Char('3')
                                     if app.search_mode == SearchMode::Discovery
                                         && app.discovery_category
-                                            == crate::DiscoveryCategory::NewAndNotable
-                                    {
-                                        let query =
-                                            reposcout_core::discovery::new_and_notable_query(
-                                                None, 90,
-                                            );
-                                        app.search_input = query.clone();
-                                        app.search_mode = SearchMode::Repository;
-                                        app.loading = true;
+                                            == crate::DiscoveryCategory::NewAndNotable =>
+                                {
+                                    // In New & Notable, search last 90 days
+                                    let query =
+                                        reposcout_core::discovery::new_and_notable_query(None, 90);
+                                    app.search_input = query.clone();
+                                    app.search_mode = SearchMode::Repository;
+                                    app.loading = true;
 
-                                        match on_search(&query).await {
-                                            Ok(results) => {
-                                                app.set_results(results);
-                                                app.selected_index = 0;
-                                                app.list_state.select(Some(0));
-                                                app.loading = false;
-                                            }
-                                            Err(e) => {
-                                                app.error_message =
-                                                    Some(format!("Search failed: {}", e));
-                                                app.loading = false;
-                                            }
+                                    match with_spinner(
+                                        &mut app,
+                                        &mut terminal,
+                                        None,
+                                        on_search(&query, &mut |_| {}),
+                                    )
+                                    .await
+                                    {
+                                        Ok(results) => {
+                                            app.set_search_results(results);
+                                            app.selected_index = 0;
+                                            app.list_state.select(Some(0));
+                                            app.loading = false;
+                                        }
+                                        Err(e) => {
+                                            app.error_message =
+                                                Some(describe_error("Search failed", &e));
+                                            app.loading = false;
                                         }
                                     }
                                 }
                                 KeyCode::Char('j') | KeyCode::Down => {
-                                    use crate::PreviewMode;
                                     match app.search_mode {
                                         SearchMode::Code => {
                                             // Scroll code preview or navigate results
@@ -1876,8 +2119,8 @@ where
                                         | SearchMode::Trending
                                         | SearchMode::Semantic
                                         | SearchMode::Portfolio => {
-                                            // If in README preview mode, scroll instead of navigating
-                                            if app.preview_mode == PreviewMode::Readme {
+                                            // If in README/changelog preview mode, scroll instead of navigating
+                                            if app.is_document_preview() {
                                                 app.scroll_readme_down();
                                             } else {
                                                 app.next_result();
@@ -1890,9 +2133,7 @@ where
                                             // Navigate within discovery category items
                                             match app.discovery_category {
                                                 crate::DiscoveryCategory::Topics => {
-                                                    let max =
-                                                        reposcout_core::discovery::popular_topics()
-                                                            .len();
+                                                    let max = app.topic_suggestions.entries().len();
                                                     if app.discovery_cursor < max.saturating_sub(1)
                                                     {
                                                         app.discovery_cursor += 1;
@@ -1913,7 +2154,6 @@ where
                                     }
                                 }
                                 KeyCode::Char('k') | KeyCode::Up => {
-                                    use crate::PreviewMode;
                                     match app.search_mode {
                                         SearchMode::Code => {
                                             // Scroll code preview or navigate results
@@ -1929,8 +2169,8 @@ where
                                         | SearchMode::Trending
                                         | SearchMode::Semantic
                                         | SearchMode::Portfolio => {
-                                            // If in README preview mode, scroll instead of navigating
-                                            if app.preview_mode == PreviewMode::Readme {
+                                            // If in README/changelog preview mode, scroll instead of navigating
+                                            if app.is_document_preview() {
                                                 app.scroll_readme_up();
                                             } else {
                                                 app.previous_result();
@@ -1943,21 +2183,19 @@ where
                                             // Navigate within discovery category items
                                             match app.discovery_category {
                                                 crate::DiscoveryCategory::Topics
-                                                | crate::DiscoveryCategory::AwesomeLists => {
-                                                    if app.discovery_cursor > 0 {
-                                                        app.discovery_cursor -= 1;
-                                                    }
+                                                | crate::DiscoveryCategory::AwesomeLists
+                                                    if app.discovery_cursor > 0 =>
+                                                {
+                                                    app.discovery_cursor -= 1;
                                                 }
                                                 _ => {} // New & Notable and Hidden Gems don't have navigation
                                             }
                                         }
                                     }
                                 }
-                                KeyCode::Char('n') => {
+                                KeyCode::Char('n') if app.search_mode == SearchMode::Code => {
                                     // Navigate to next match within current code result
-                                    if app.search_mode == SearchMode::Code {
-                                        app.next_code_match();
-                                    }
+                                    app.next_code_match();
                                 }
                                 _ => {}
                             }
@@ -1994,6 +2232,9 @@ where
                                     app.error_timestamp = Some(std::time::SystemTime::now());
                                 }
                             }
+                            KeyCode::Char('v') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.paste_from_clipboard();
+                            }
                             KeyCode::Char(c) => {
                                 app.token_input_buffer.push(c);
                             }
@@ -2002,6 +2243,70 @@ where
                             }
                             _ => {}
                         },
+                        InputMode::Command => match key.code {
+                            KeyCode::Esc => {
+                                app.cancel_command();
+                            }
+                            KeyCode::Enter => {
+                                if let Some(crate::command::Command::Theme(_)) =
+                                    app.submit_command()
+                                {
+                                    save_theme(&mut app);
+                                }
+                            }
+                            KeyCode::Char('v') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.paste_from_clipboard();
+                            }
+                            KeyCode::Char(c) => {
+                                app.command_input.push(c);
+                            }
+                            // Like Vim, backspacing past the start closes the command line
+                            KeyCode::Backspace if app.command_input.is_empty() => {
+                                app.cancel_command();
+                            }
+                            KeyCode::Backspace => {
+                                app.command_input.pop();
+                            }
+                            _ => {}
+                        },
+                        InputMode::BookmarkEdit => match key.code {
+                            KeyCode::Esc => {
+                                app.cancel_bookmark_edit();
+                            }
+                            KeyCode::Enter => {
+                                let field = app.bookmark_edit_field;
+                                if let Some((platform, full_name, value)) =
+                                    app.finish_bookmark_edit()
+                                {
+                                    let saved = match field {
+                                        crate::BookmarkField::Tags => cache.update_bookmark_tags(
+                                            &platform,
+                                            &full_name,
+                                            value.as_deref(),
+                                        ),
+                                        crate::BookmarkField::Notes => cache.update_bookmark_notes(
+                                            &platform,
+                                            &full_name,
+                                            value.as_deref(),
+                                        ),
+                                    };
+                                    if let Err(e) = saved {
+                                        app.set_temp_error(format!(
+                                            "Failed to save bookmark {}: {}",
+                                            field.label().to_lowercase(),
+                                            e
+                                        ));
+                                    }
+                                }
+                            }
+                            KeyCode::Char(c) => {
+                                app.bookmark_edit_buffer.push(c);
+                            }
+                            KeyCode::Backspace => {
+                                app.bookmark_edit_buffer.pop();
+                            }
+                            _ => {}
+                        },
                     }
                 }
             }
@@ -2017,9 +2322,835 @@ where
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
     Ok(())
 }
+
+/// Awaits a search started from a key handler. The event loop is stuck until
+/// it finishes, so this ticks the spinner and redraws in its place. Results
+/// streamed into `arrived` are shown on the next tick.
+async fn with_spinner<T>(
+    app: &mut App,
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    arrived: Option<&RefCell<Vec<Repository>>>,
+    search: impl std::future::Future<Output = T>,
+) -> T {
+    tokio::pin!(search);
+    let mut ticker = tokio::time::interval(std::time::Duration::from_millis(80));
+    loop {
+        tokio::select! {
+            outcome = &mut search => return outcome,
+            _ = ticker.tick() => {
+                if let Some(arrived) = arrived {
+                    let batch = std::mem::take(&mut *arrived.borrow_mut());
+                    if !batch.is_empty() {
+                        app.append_results(batch);
+                    }
+                }
+                app.tick_spinner();
+                let _ = terminal.draw(|f| crate::ui::render(f, app));
+            }
+        }
+    }
+}
+
+/// Which notifications to mark as read before refreshing the list
+enum MarkRead {
+    One(String),
+    All,
+}
+
+/// Refresh notifications in the background, optionally marking some read first
+fn spawn_notifications_refresh(
+    app: &mut App,
+    clients: &PlatformClients,
+    mark_read: Option<MarkRead>,
+    reset_selection: bool,
+) {
+    if blocked_offline(app, "Notifications") {
+        return;
+    }
+    app.notifications_loading = true;
+
+    let client = clients.github.clone();
+    let show_all = app.notifications_show_all;
+    let participating = app.notifications_participating;
+    app.tasks.spawn(
+        TaskKind::Notifications,
+        "Loading notifications",
+        async move {
+            let result = async {
+                match mark_read {
+                    Some(MarkRead::One(id)) => client
+                        .mark_notification_read(&id)
+                        .await
+                        .map_err(|e| anyhow::anyhow!("Failed to mark as read: {}", e))?,
+                    Some(MarkRead::All) => client
+                        .mark_all_notifications_read()
+                        .await
+                        .map_err(|e| anyhow::anyhow!("Failed to mark all as read: {}", e))?,
+                    None => {}
+                }
+
+                client
+                    .get_notifications(show_all, participating, 50)
+                    .await
+                    .map_err(|e| anyhow::anyhow!("Failed to fetch notifications: {}", e))
+            }
+            .await;
+
+            TaskOutput::Notifications {
+                reset_selection,
+                result,
+            }
+        },
+    );
+}
+
+/// Open a README link in the browser
+fn open_footnote(app: &mut App, url: &str) {
+    match open::that(url) {
+        Ok(()) => app.set_temp_error(format!("Opening {}", url)),
+        Err(e) => app.error_message = Some(format!("Failed to open browser: {}", e)),
+    }
+}
+
+/// Neighbours on each side of the selection whose READMEs are prefetched
+const README_PREFETCH_RADIUS: usize = 1;
+
+/// Prefetches further than this from the selection are abandoned
+const README_PREFETCH_KEEP_RADIUS: usize = 3;
+
+/// Start fetching READMEs for the selected repository and its neighbours in
+/// the background, so pressing `R` shows them without waiting
+fn prefetch_readmes_if_needed(app: &mut App, clients: &PlatformClients) {
+    let shows_repo_preview = matches!(
+        app.search_mode,
+        SearchMode::Repository | SearchMode::Trending | SearchMode::Semantic
+    );
+    if !shows_repo_preview || reposcout_core::is_offline() {
+        app.readme_prefetch.retain(&[]);
+        return;
+    }
+
+    // The selection moved on; drop prefetches that fell too far behind
+    let keep = app.readme_prefetch_candidates(README_PREFETCH_KEEP_RADIUS);
+    app.readme_prefetch.retain(&keep);
+
+    for repo_name in app.readme_prefetch_candidates(README_PREFETCH_RADIUS) {
+        if app.readme_prefetch.is_full() {
+            break;
+        }
+        // The foreground fetch started by `R` already covers the selection
+        if app.readme_loading && app.tasks.is_running(TaskKind::Readme) {
+            let selected = app.selected_repository().map(|r| r.full_name.as_str());
+            if selected == Some(repo_name.as_str()) {
+                continue;
+            }
+        }
+        let Some(platform) = app
+            .results
+            .iter()
+            .find(|r| r.full_name == repo_name)
+            .map(|r| r.platform)
+        else {
+            continue;
+        };
+
+        let clients = clients.clone();
+        let name = repo_name.clone();
+        app.readme_prefetch.spawn(
+            repo_name,
+            async move { clients.readme(platform, &name).await },
+        );
+    }
+}
+
+/// Cache a prefetched README, and show it if `R` is waiting on it
+fn apply_prefetched_readme(app: &mut App, repo_name: String, result: anyhow::Result<String>) {
+    let readme = readme_or_error(result);
+    app.cache_readme(repo_name.clone(), readme.clone());
+    let waiting = app.readme_loading
+        && !app.tasks.is_running(TaskKind::Readme)
+        && app
+            .selected_repository()
+            .is_some_and(|repo| repo.full_name == repo_name);
+    if waiting {
+        app.set_readme(readme);
+    }
+}
+
+/// README text to show, or a note explaining why there is none
+fn readme_or_error(result: anyhow::Result<String>) -> String {
+    result.unwrap_or_else(|e| format!("# README Not Available\n\nFailed to fetch README: {}", e))
+}
+
+/// Persist portfolios after a change so `reposcout portfolio export` sees them
+fn save_portfolios(app: &mut App) {
+    if let Err(e) = app.portfolio_manager.save() {
+        app.set_temp_error(format!("Failed to save portfolios: {}", e));
+    }
+}
+
+/// Refresh the Discovery topic list from the seed typed in Topics mode.
+/// Suggestions come from GitHub search, so offline or without a token the
+/// built-in list stays.
+fn suggest_topics_if_needed(app: &mut App, clients: &PlatformClients) {
+    let typing_seed = app.search_mode == SearchMode::Discovery
+        && app.discovery_category == crate::DiscoveryCategory::Topics
+        && app.input_mode == InputMode::Searching;
+    if !typing_seed || reposcout_core::is_offline() || !clients.github.has_token() {
+        return;
+    }
+
+    let shown = app.topic_suggestions.seed.clone();
+    let fetch = app
+        .topic_suggestions
+        .poll(&app.search_input, std::time::Instant::now());
+    if app.topic_suggestions.seed != shown {
+        app.discovery_cursor = 0;
+    }
+    let Some(seed) = fetch else {
+        return;
+    };
+
+    let github = clients.github.clone();
+    app.tasks.spawn(
+        TaskKind::TopicSuggestions,
+        format!("Suggesting topics for '{}'", seed),
+        async move {
+            let result = reposcout_core::discovery::suggest_topics(&github, &seed)
+                .await
+                .map_err(Into::into);
+            TaskOutput::TopicSuggestions { seed, result }
+        },
+    );
+}
+
+/// Remember the applied theme in the config file so the next launch uses it
+fn save_theme(app: &mut App) {
+    let result = reposcout_core::Config::load().and_then(|mut config| {
+        config.ui.theme = app.current_theme.name.clone();
+        config.save()
+    });
+    if let Err(e) = result {
+        app.set_temp_error(format!("Failed to save theme: {}", e));
+    }
+}
+
+/// Remember the code preview's syntax theme for the next launch
+fn save_code_theme(app: &mut App) {
+    let result = reposcout_core::Config::load().and_then(|mut config| {
+        config.ui.code_theme = app.code_theme_name.clone();
+        config.save()
+    });
+    if let Err(e) = result {
+        app.set_temp_error(format!("Failed to save code theme: {}", e));
+    }
+}
+
+/// "context: error", with what to do about it for timeouts, network,
+/// rate limit, credential and malformed response errors
+fn describe_error(context: &str, error: &anyhow::Error) -> String {
+    use reposcout_core::Error;
+
+    // Search errors reach here as core errors, task errors as API client errors
+    let core_error = error.chain().find_map(|e| e.downcast_ref::<Error>());
+    if matches!(core_error, Some(Error::Timeout)) || reposcout_api::http::is_timeout(error.as_ref())
+    {
+        return format!(
+            "{}: the request timed out. Check your connection, or raise [network] timeout_secs",
+            context
+        );
+    }
+    match core_error {
+        Some(Error::Network(_)) => format!("{}: network error. Check your connection", context),
+        Some(Error::RateLimited { retry_after }) => match retry_after {
+            Some(wait) => format!(
+                "{}: rate limited. Try again in {}s",
+                context,
+                wait.as_secs().max(1)
+            ),
+            None => format!("{}: rate limited. Wait a moment and try again", context),
+        },
+        Some(Error::Unauthorized { platform, .. }) => format!(
+            "{}: {} rejected the credentials. Check the token (reposcout whoami)",
+            context, platform
+        ),
+        Some(Error::Parse(_)) => format!(
+            "{}: unexpected response from the API. Try again later",
+            context
+        ),
+        _ => format!("{}: {}", context, error),
+    }
+}
+
+/// Show a transient error and report true when `feature` can't run offline
+fn blocked_offline(app: &mut App, feature: &str) -> bool {
+    match reposcout_core::ensure_online(feature) {
+        Ok(()) => false,
+        Err(e) => {
+            app.set_temp_error(e.to_string());
+            true
+        }
+    }
+}
+
+/// Start fetching the selected repository's language breakdown while the
+/// Languages tab is showing and nothing is cached for it yet
+fn fetch_languages_if_needed(app: &mut App, clients: &PlatformClients) {
+    if reposcout_core::is_offline() {
+        return;
+    }
+    let shows_repo_preview = matches!(
+        app.search_mode,
+        SearchMode::Repository | SearchMode::Trending | SearchMode::Semantic
+    );
+    if !shows_repo_preview
+        || app.preview_mode != crate::PreviewMode::Languages
+        || app.languages_loading
+        || app.get_cached_languages().is_some()
+    {
+        return;
+    }
+    let Some(repo) = app.selected_repository() else {
+        return;
+    };
+    let repo_name = repo.full_name.clone();
+    let platform = repo.platform;
+
+    app.languages_loading = true;
+    let clients = clients.clone();
+    app.tasks.spawn(
+        TaskKind::Languages,
+        format!("Fetching languages for {}", repo_name),
+        async move {
+            let result = clients.languages(platform, &repo_name).await;
+            TaskOutput::Languages {
+                platform,
+                repo_name,
+                result,
+            }
+        },
+    );
+}
+
+/// Fetch contributor and commit stats for the selected repository once the
+/// Activity tab is showing. Only GitHub has them.
+fn fetch_activity_if_needed(app: &mut App, clients: &PlatformClients) {
+    if reposcout_core::is_offline() {
+        return;
+    }
+    let shows_repo_preview = matches!(
+        app.search_mode,
+        SearchMode::Repository | SearchMode::Trending | SearchMode::Semantic
+    );
+    if !shows_repo_preview
+        || app.preview_mode != crate::PreviewMode::Activity
+        || app.activity_loading
+        || app.get_cached_activity().is_some()
+    {
+        return;
+    }
+    let Some(repo) = app.selected_repository() else {
+        return;
+    };
+    if repo.platform != reposcout_core::models::Platform::GitHub {
+        return;
+    }
+    let repo_name = repo.full_name.clone();
+    let platform = repo.platform;
+
+    app.activity_loading = true;
+    let clients = clients.clone();
+    app.tasks.spawn(
+        TaskKind::Activity,
+        format!("Fetching activity for {}", repo_name),
+        async move {
+            let result = clients.activity(platform, &repo_name).await;
+            TaskOutput::Activity {
+                platform,
+                repo_name,
+                result,
+            }
+        },
+    );
+}
+
+/// Read the selected repository's recorded star counts from the cache once
+/// the Activity tab is showing
+fn load_star_history_if_needed(app: &mut App, cache: &CacheManager) {
+    let shows_repo_preview = matches!(
+        app.search_mode,
+        SearchMode::Repository | SearchMode::Trending | SearchMode::Semantic
+    );
+    if !shows_repo_preview
+        || app.preview_mode != crate::PreviewMode::Activity
+        || app.get_star_history().is_some()
+    {
+        return;
+    }
+    let Some(repo) = app.selected_repository() else {
+        return;
+    };
+    let (platform, repo_name) = (repo.platform, repo.full_name.clone());
+    let history = cache
+        .get_star_history(&platform.to_string(), &repo_name)
+        .unwrap_or_else(|e| {
+            tracing::warn!("Failed to load star history for {}: {}", repo_name, e);
+            Vec::new()
+        });
+    app.cache_star_history(platform, &repo_name, history);
+}
+
+/// Fetch the selected code result's file so its matches show surrounding code
+fn fetch_code_file_if_needed(app: &mut App, clients: &PlatformClients) {
+    if reposcout_core::is_offline() || app.search_mode != SearchMode::Code {
+        return;
+    }
+    let Some(result) = app.code_file_to_fetch() else {
+        return;
+    };
+    let key = App::code_file_key(result);
+    let (platform, repository, file_path) = (
+        result.platform,
+        result.repository.clone(),
+        result.file_path.clone(),
+    );
+
+    app.code_content_loading = true;
+    let clients = clients.clone();
+    app.tasks.spawn(
+        TaskKind::CodeFile,
+        format!("Fetching {}", file_path),
+        async move {
+            let file = clients
+                .file_content(platform, &repository, &file_path)
+                .await
+                .ok()
+                .flatten();
+            TaskOutput::CodeFile { key, file }
+        },
+    );
+}
+
+/// Runtime dependencies whose licenses are looked up, the same ones the preview lists
+const LICENSE_LOOKUP_LIMIT: usize = 20;
+
+/// Registry to look dependency licenses up in, for ecosystems we can query
+fn dependency_registry(ecosystem: &str) -> Option<reposcout_core::PackageManager> {
+    match ecosystem {
+        "Rust" => Some(reposcout_core::PackageManager::Cargo),
+        "Node.js" => Some(reposcout_core::PackageManager::Npm),
+        e if e.starts_with("Python") => Some(reposcout_core::PackageManager::PyPI),
+        _ => None,
+    }
+}
+
+/// Start looking up runtime dependency licenses once the Dependencies tab
+/// shows a parsed manifest whose licenses haven't been fetched yet
+fn fetch_dependency_licenses_if_needed(app: &mut App) {
+    if reposcout_core::is_offline() {
+        return;
+    }
+    if app.preview_mode != crate::PreviewMode::Dependencies
+        || app.dependency_licenses_loading
+        || app.get_cached_dependency_licenses().is_some()
+    {
+        return;
+    }
+    let Some(repo) = app.selected_repository() else {
+        return;
+    };
+    let Some(Some(deps)) = app.get_cached_dependencies() else {
+        return;
+    };
+    let Some(manager) = dependency_registry(&deps.ecosystem) else {
+        return;
+    };
+    let repo_name = repo.full_name.clone();
+    let names: Vec<String> = deps
+        .dependencies
+        .iter()
+        .filter(|d| d.dep_type == reposcout_deps::DependencyType::Runtime)
+        .take(LICENSE_LOOKUP_LIMIT)
+        .map(|d| d.name.clone())
+        .collect();
+
+    app.dependency_licenses_loading = true;
+    app.tasks.spawn(
+        TaskKind::DependencyLicenses,
+        format!("Checking dependency licenses of {}", repo_name),
+        async move {
+            let licenses = reposcout_core::RegistryClient::new()
+                .fetch_licenses(manager, &names)
+                .await;
+            TaskOutput::DependencyLicenses {
+                repo_name,
+                licenses,
+            }
+        },
+    );
+}
+
+/// Check the selected repository's parsed dependencies against OSV. Opt-in
+/// (the `v` key) since it sends every dependency name to osv.dev.
+fn check_vulnerabilities(app: &mut App, clients: &PlatformClients) {
+    if app.dependency_vulns_loading || blocked_offline(app, "Vulnerability check") {
+        return;
+    }
+    let Some(repo) = app.selected_repository() else {
+        return;
+    };
+    let Some(Some(deps)) = app.get_cached_dependencies() else {
+        app.set_temp_error("No dependencies to check, press d to analyze them first".to_string());
+        return;
+    };
+    let repo_name = repo.full_name.clone();
+    let mut deps = deps.clone();
+
+    app.dependency_vulns_loading = true;
+    let osv = clients.osv.clone();
+    app.tasks.spawn(
+        TaskKind::Vulnerabilities,
+        format!("Checking {} for known vulnerabilities", repo_name),
+        async move {
+            let result = osv.check(&mut deps).await.map(|_| deps);
+            TaskOutput::Vulnerabilities { repo_name, result }
+        },
+    );
+}
+
+/// The cache database the CLI opens, which the semantic index sits under
+fn cache_db_path() -> Option<std::path::PathBuf> {
+    Some(
+        dirs_next::cache_dir()?
+            .join("reposcout")
+            .join("reposcout.db"),
+    )
+}
+
+/// Config for the semantic index the CLI's `semantic-index` command builds,
+/// using the model it was built with
+fn semantic_config() -> Option<reposcout_semantic::SemanticConfig> {
+    let cache_path = cache_db_path()?;
+    let config = reposcout_semantic::SemanticConfig {
+        cache_path: cache_path.join("semantic").to_string_lossy().to_string(),
+        ..Default::default()
+    };
+    Some(config.with_model(None))
+}
+
+/// Replace the results with the selected repository's nearest neighbors in
+/// the semantic index
+fn find_similar(app: &mut App) {
+    use reposcout_core::models::Repository;
+    use reposcout_semantic::SemanticSearchEngine;
+
+    if app.tasks.is_running(TaskKind::SimilarRepositories) {
+        return;
+    }
+    let Some(repo) = app.selected_repository().cloned() else {
+        return;
+    };
+    let (Some(config), Some(db_path)) = (semantic_config(), cache_db_path()) else {
+        app.set_temp_error("Could not find the cache directory".to_string());
+        return;
+    };
+
+    let repo_name = repo.full_name.clone();
+    app.tasks.spawn(
+        TaskKind::SimilarRepositories,
+        format!("Finding repositories similar to {}", repo_name),
+        async move {
+            let result = async {
+                // The index only stores vectors; results are looked up in the
+                // cache, through a connection of our own (reads ignore the TTL)
+                let cache = CacheManager::new(&db_path.to_string_lossy(), 0)?;
+                let known: Vec<Repository> = cache.get_all(cache.stats()?.total_entries)?;
+                let engine = SemanticSearchEngine::new(config)?;
+                engine.initialize().await?;
+                engine.register_repositories(known).await;
+                let similar = engine.find_similar(&repo, None, 30).await?;
+                anyhow::Ok(similar.into_iter().map(|r| r.repository).collect())
+            }
+            .await;
+            TaskOutput::SimilarRepositories { repo_name, result }
+        },
+    );
+}
+
+/// Start fetching crates.io usage stats when the Package tab is showing a
+/// crate that hasn't been looked up yet
+fn fetch_crate_usage_if_needed(app: &mut App) {
+    if reposcout_core::is_offline() {
+        return;
+    }
+    let shows_repo_preview = matches!(
+        app.search_mode,
+        SearchMode::Repository | SearchMode::Trending | SearchMode::Semantic
+    );
+    if !shows_repo_preview
+        || app.preview_mode != crate::PreviewMode::Package
+        || app.crate_usage_loading
+    {
+        return;
+    }
+    let Some(crate_name) = app.selected_crate_name().map(str::to_string) else {
+        return;
+    };
+    if app.get_cached_crate_usage(&crate_name).is_some() {
+        return;
+    }
+
+    app.crate_usage_loading = true;
+    app.tasks.spawn(
+        TaskKind::CrateUsage,
+        format!("Fetching crates.io usage for {}", crate_name),
+        async move {
+            let package = reposcout_core::PackageInfo::new(
+                reposcout_core::PackageManager::Cargo,
+                crate_name.clone(),
+            );
+            let result = reposcout_core::RegistryClient::new()
+                .fetch_crate_usage(&package)
+                .await
+                .map_err(anyhow::Error::msg);
+            TaskOutput::CrateUsage { crate_name, result }
+        },
+    );
+}
+
+/// Apply a finished background task's result to the app state
+fn apply_task_output(app: &mut App, output: TaskOutput) {
+    match output {
+        TaskOutput::Readme { repo_name, result } => {
+            let readme = readme_or_error(result);
+            app.cache_readme(repo_name.clone(), readme.clone());
+            app.readme_loading = false;
+            // Only show it if the user is still looking at that repository
+            if app
+                .selected_repository()
+                .is_some_and(|repo| repo.full_name == repo_name)
+            {
+                app.set_readme(readme);
+            }
+        }
+        TaskOutput::Changelog { repo_name, result } => {
+            let changelog = result.unwrap_or_else(|e| {
+                format!(
+                    "# Changelog Not Available\n\nNo changelog file or release notes found: {}",
+                    e
+                )
+            });
+            app.cache_changelog(repo_name, changelog);
+        }
+        TaskOutput::Languages {
+            platform,
+            repo_name,
+            result,
+        } => {
+            // Failures are cached as "not available" so the tab doesn't refetch every tick
+            let languages = result.unwrap_or_else(|e| {
+                app.set_temp_error(describe_error("Failed to fetch languages", &e));
+                None
+            });
+            app.cache_languages(platform, &repo_name, languages);
+        }
+        TaskOutput::Activity {
+            platform,
+            repo_name,
+            result,
+        } => {
+            // Cached even on failure so the tab falls back to estimates instead of refetching
+            let stats = result.unwrap_or_else(|e| {
+                app.set_temp_error(describe_error("Failed to fetch activity", &e));
+                Default::default()
+            });
+            app.cache_activity(platform, &repo_name, stats);
+        }
+        TaskOutput::CodeFile { key, file } => app.cache_code_file(key, file),
+        TaskOutput::Dependencies { repo_name, result } => {
+            match result {
+                Ok(deps) => app.cache_dependencies(repo_name, deps),
+                Err(e) => {
+                    app.error_message = Some(describe_error("Failed to fetch dependencies", &e));
+                    app.cache_dependencies(repo_name, None);
+                }
+            }
+            app.stop_dependencies_loading();
+        }
+        TaskOutput::DependencyLicenses {
+            repo_name,
+            licenses,
+        } => app.cache_dependency_licenses(repo_name, licenses),
+        TaskOutput::Vulnerabilities { repo_name, result } => {
+            match result {
+                Ok(deps) => app.cache_dependencies(repo_name, Some(deps)),
+                Err(e) => app.set_temp_error(describe_error("Vulnerability check failed", &e)),
+            }
+            app.dependency_vulns_loading = false;
+        }
+        TaskOutput::CrateUsage { crate_name, result } => {
+            // Failures are cached too so switching tabs doesn't refetch
+            let usage = result.unwrap_or_else(|e| {
+                app.set_temp_error(describe_error("Failed to fetch crate usage", &e));
+                None
+            });
+            app.cache_crate_usage(crate_name, usage);
+        }
+        TaskOutput::Notifications {
+            reset_selection,
+            result,
+        } => {
+            match result {
+                Ok(notifications) => {
+                    app.notifications = notifications;
+                    if reset_selection {
+                        app.notifications_selected_index = 0;
+                    }
+                    app.error_message = None;
+                }
+                Err(e) => app.error_message = Some(e.to_string()),
+            }
+            app.notifications_loading = false;
+        }
+        TaskOutput::TopicSuggestions { seed, result } => {
+            // Failures keep the built-in topic list
+            let topics = result
+                .map_err(|e| tracing::warn!("Topic suggestions failed: {}", e))
+                .ok();
+            app.topic_suggestions
+                .finish(seed, topics, std::time::Instant::now());
+            app.discovery_cursor = 0;
+        }
+        TaskOutput::SimilarRepositories { repo_name, result } => match result {
+            Ok(repos) if repos.is_empty() => app.set_temp_error(format!(
+                "No repositories in the semantic index are similar to {}",
+                repo_name
+            )),
+            Ok(repos) => {
+                let count = repos.len();
+                app.set_results(repos);
+                app.set_temp_error(format!("{} repositories similar to {}", count, repo_name));
+            }
+            Err(e) => app.set_temp_error(describe_error("Similar repositories", &e)),
+        },
+    }
+}
+
+/// Abort all background tasks and reset their loading states
+fn cancel_tasks(app: &mut App) {
+    for kind in app.tasks.cancel_all() {
+        match kind {
+            TaskKind::Readme => app.clear_readme(),
+            TaskKind::Changelog => app.changelog_loading = false,
+            TaskKind::Languages => {
+                // Mark it unavailable, otherwise the open tab would start fetching again
+                if let Some(repo) = app.selected_repository() {
+                    let (platform, repo_name) = (repo.platform, repo.full_name.clone());
+                    app.cache_languages(platform, &repo_name, None);
+                }
+                app.languages_loading = false;
+            }
+            TaskKind::Activity => {
+                if let Some(repo) = app.selected_repository() {
+                    let (platform, repo_name) = (repo.platform, repo.full_name.clone());
+                    app.cache_activity(platform, &repo_name, Default::default());
+                }
+                app.activity_loading = false;
+            }
+            TaskKind::CodeFile => {
+                // Cache it as unavailable so the preview doesn't start fetching again
+                if let Some(result) = app.selected_code_result() {
+                    let key = App::code_file_key(result);
+                    app.cache_code_file(key, None);
+                }
+                app.code_content_loading = false;
+            }
+            TaskKind::Dependencies => app.stop_dependencies_loading(),
+            TaskKind::Vulnerabilities => app.dependency_vulns_loading = false,
+            TaskKind::DependencyLicenses => {
+                // Cache an empty lookup so the open tab doesn't start over
+                if let Some(repo) = app.selected_repository() {
+                    let repo_name = repo.full_name.clone();
+                    app.cache_dependency_licenses(repo_name, Default::default());
+                }
+                app.dependency_licenses_loading = false;
+            }
+            TaskKind::CrateUsage => {
+                if let Some(crate_name) = app.selected_crate_name().map(str::to_string) {
+                    app.cache_crate_usage(crate_name, None);
+                }
+                app.crate_usage_loading = false;
+            }
+            TaskKind::Notifications => app.notifications_loading = false,
+            // The built-in topic list stays until the seed changes, and
+            // similar repositories only replace the results once found
+            TaskKind::TopicSuggestions | TaskKind::SimilarRepositories => {}
+        }
+    }
+    app.set_temp_error("Cancelled".to_string());
+}
+
+/// Max delay between two clicks on the same row to count as a double-click
+const DOUBLE_CLICK_MS: u128 = 400;
+
+/// Scroll wheel and clicks on the results list. Keyboard handling is unaffected.
+fn handle_mouse_event(app: &mut App, mouse: MouseEvent) {
+    let uses_results_list = matches!(
+        app.search_mode,
+        SearchMode::Repository | SearchMode::Trending | SearchMode::Semantic
+    );
+    if app.input_mode != InputMode::Normal || app.popup_open() || !uses_results_list {
+        return;
+    }
+
+    let over_results = app
+        .results_area
+        .is_some_and(|area| area.contains(ratatui::layout::Position::new(mouse.column, mouse.row)));
+
+    match mouse.kind {
+        MouseEventKind::ScrollDown => {
+            if app.is_document_preview() && !over_results {
+                app.scroll_readme_down();
+            } else {
+                app.next_result();
+            }
+        }
+        MouseEventKind::ScrollUp => {
+            if app.is_document_preview() && !over_results {
+                app.scroll_readme_up();
+            } else {
+                app.previous_result();
+            }
+        }
+        MouseEventKind::Down(MouseButton::Left) => {
+            let Some(index) = app.result_index_at(mouse.column, mouse.row) else {
+                return;
+            };
+            let now = std::time::Instant::now();
+            let double_click = app.last_click.is_some_and(|(at, last)| {
+                last == index && now.duration_since(at).as_millis() <= DOUBLE_CLICK_MS
+            });
+
+            app.select_result(index);
+            if double_click {
+                app.last_click = None;
+                if let Some(repo) = app.selected_repository() {
+                    let url = repo.url.clone();
+                    if let Err(e) = open::that(&url) {
+                        app.error_message = Some(format!("Failed to open browser: {}", e));
+                    }
+                }
+            } else {
+                app.last_click = Some((now, index));
+            }
+        }
+        _ => {}
+    }
+}
@@ -1,5 +1,12 @@
 // Sparkline rendering utilities
 use chrono::{DateTime, Utc};
+use reposcout_cache::StarSnapshot;
+
+/// Fewest star snapshots worth drawing a real growth trend from
+pub const MIN_STAR_SNAPSHOTS: usize = 3;
+
+/// Snapshots closer together than this say nothing about a trend
+const MIN_STAR_HISTORY_SECS: i64 = 24 * 60 * 60;
 
 /// Generate a sparkline visualization using Unicode block characters
 /// Characters: ▁ ▂ ▃ ▄ ▅ ▆ ▇ █
@@ -88,8 +95,76 @@ pub fn generate_activity_sparkline(
     render_sparkline(&activity_data)
 }
 
-/// Generate star velocity sparkline showing growth rate over time
-pub fn generate_star_velocity_sparkline(created_at: DateTime<Utc>, stars: u32) -> String {
+/// Sparkline of real commit counts (weeks of daily counts, oldest first),
+/// summed into 12 periods like the estimated trend
+pub fn commit_activity_sparkline(weeks: &[[u32; 7]]) -> String {
+    let periods = 12.min(weeks.len());
+    let totals: Vec<f64> = (0..periods)
+        .map(|i| {
+            let range = i * weeks.len() / periods..(i + 1) * weeks.len() / periods;
+            weeks[range]
+                .iter()
+                .map(|week| week.iter().sum::<u32>() as f64)
+                .sum()
+        })
+        .collect();
+    render_sparkline(&totals)
+}
+
+/// Whether there are enough snapshots, far enough apart, for a real trend
+pub fn has_star_trend(history: &[StarSnapshot]) -> bool {
+    match (history.first(), history.last()) {
+        (Some(first), Some(last)) => {
+            history.len() >= MIN_STAR_SNAPSHOTS
+                && last.recorded_at - first.recorded_at >= MIN_STAR_HISTORY_SECS
+        }
+        _ => false,
+    }
+}
+
+/// Stars gained per period between the first and last cached snapshot
+/// (oldest first), reading counts between snapshots off a straight line
+fn star_history_sparkline(history: &[StarSnapshot]) -> String {
+    let first = history[0].recorded_at as f64;
+    let span = (history[history.len() - 1].recorded_at as f64 - first).max(1.0);
+    let periods = 12.min(history.len() - 1);
+    let at = |i: usize| first + span * i as f64 / periods as f64;
+
+    let gains: Vec<f64> = (0..periods)
+        .map(|i| (stars_at(history, at(i + 1)) - stars_at(history, at(i))).max(0.0))
+        .collect();
+    render_sparkline(&gains)
+}
+
+/// Star count at `time`, interpolated between the snapshots either side
+fn stars_at(history: &[StarSnapshot], time: f64) -> f64 {
+    let after = history
+        .iter()
+        .position(|s| s.recorded_at as f64 >= time)
+        .unwrap_or(history.len() - 1);
+    if after == 0 {
+        return history[0].stars as f64;
+    }
+    let (a, b) = (&history[after - 1], &history[after]);
+    let span = (b.recorded_at - a.recorded_at) as f64;
+    if span <= 0.0 {
+        return b.stars as f64;
+    }
+    a.stars as f64 + (b.stars as f64 - a.stars as f64) * (time - a.recorded_at as f64) / span
+}
+
+/// Generate star velocity sparkline showing growth rate over time. Uses the
+/// cached star `history` once it has enough snapshots, and an estimate from
+/// age and current stars until then.
+pub fn generate_star_velocity_sparkline(
+    created_at: DateTime<Utc>,
+    stars: u32,
+    history: &[StarSnapshot],
+) -> String {
+    if has_star_trend(history) {
+        return star_history_sparkline(history);
+    }
+
     let now = Utc::now();
     let age_weeks = (now - created_at).num_weeks().max(1);
 
@@ -211,6 +286,50 @@ mod tests {
         assert!(sparkline.contains('█')); // Should have max char
     }
 
+    #[test]
+    fn test_commit_activity_sparkline() {
+        // 52 weeks: quiet first half, busy second half
+        let mut weeks = vec![[0; 7]; 26];
+        weeks.extend(vec![[1, 2, 3, 4, 5, 6, 7]; 26]);
+        let sparkline = commit_activity_sparkline(&weeks);
+        assert_eq!(sparkline.chars().count(), 12);
+        assert!(sparkline.starts_with("▁▁▁▁▁▁"));
+        assert!(sparkline.ends_with('█'));
+
+        assert_eq!(commit_activity_sparkline(&[[1; 7]; 3]), "███");
+        assert_eq!(commit_activity_sparkline(&[]), "");
+    }
+
+    #[test]
+    fn test_star_velocity_from_history() {
+        const DAY: i64 = 24 * 60 * 60;
+        let snapshot = |day: i64, stars: u32| StarSnapshot {
+            recorded_at: day * DAY,
+            stars,
+        };
+        let created = Utc::now() - chrono::Duration::days(365);
+
+        // Flat for two days, then a jump
+        let history = [
+            snapshot(0, 100),
+            snapshot(1, 100),
+            snapshot(2, 100),
+            snapshot(3, 400),
+        ];
+        assert!(has_star_trend(&history));
+        assert_eq!(
+            generate_star_velocity_sparkline(created, 400, &history),
+            "▁▁█"
+        );
+
+        // Too few snapshots, or all within a day: estimated curve
+        let estimated = generate_star_velocity_sparkline(created, 400, &[]);
+        assert_eq!(estimated.chars().count(), 12);
+        let same_day = [snapshot(0, 1), snapshot(0, 2), snapshot(0, 3)];
+        assert!(!has_star_trend(&same_day));
+        assert!(!has_star_trend(&history[..2]));
+    }
+
     #[test]
     fn test_empty_sparkline() {
         let data: Vec<f64> = vec![];
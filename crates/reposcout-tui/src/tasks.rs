@@ -0,0 +1,580 @@
+// Background tasks for slow network operations
+//
+// Fetches used to be awaited inline in the event loop, which froze the UI until
+// they returned. Tasks now run on tokio::spawn; the loop keeps drawing (with an
+// animated spinner) and picks up finished results each tick. Esc aborts them.
+
+use reposcout_api::{
+    AzureDevOpsClient, BitbucketClient, GitHubClient, GitLabClient, Notification, SourceHutClient,
+};
+use reposcout_core::discovery::TopicSuggestion;
+use reposcout_core::models::{Platform, Repository};
+use reposcout_core::{missing_as_none, CrateUsage};
+use reposcout_deps::DependencyInfo;
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Instant;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinHandle;
+
+pub(crate) const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const SPINNER_FRAME_MS: u128 = 80;
+
+/// Most README prefetches running at once
+pub const MAX_PREFETCHES: usize = 3;
+
+/// Times commit statistics GitHub is still computing are asked for again,
+/// and the wait before each try
+const STATS_PENDING_RETRIES: u32 = 3;
+const STATS_PENDING_DELAY: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// What a background task is doing. Only one task per kind runs at a time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskKind {
+    Readme,
+    Changelog,
+    Languages,
+    Activity,
+    CodeFile,
+    Dependencies,
+    DependencyLicenses,
+    Vulnerabilities,
+    CrateUsage,
+    Notifications,
+    TopicSuggestions,
+    SimilarRepositories,
+}
+
+/// Result of a finished task, applied to the app state on the UI thread
+pub enum TaskOutput {
+    Readme {
+        repo_name: String,
+        result: anyhow::Result<String>,
+    },
+    Changelog {
+        repo_name: String,
+        result: anyhow::Result<String>,
+    },
+    Languages {
+        platform: Platform,
+        repo_name: String,
+        result: anyhow::Result<Option<HashMap<String, u64>>>,
+    },
+    Activity {
+        platform: Platform,
+        repo_name: String,
+        result: anyhow::Result<ActivityStats>,
+    },
+    CodeFile {
+        /// `App::code_file_key` of the file
+        key: String,
+        file: Option<String>,
+    },
+    Dependencies {
+        repo_name: String,
+        result: anyhow::Result<Option<DependencyInfo>>,
+    },
+    DependencyLicenses {
+        repo_name: String,
+        licenses: HashMap<String, Option<String>>,
+    },
+    /// The dependencies annotated with OSV advisories
+    Vulnerabilities {
+        repo_name: String,
+        result: anyhow::Result<DependencyInfo>,
+    },
+    CrateUsage {
+        crate_name: String,
+        result: anyhow::Result<Option<CrateUsage>>,
+    },
+    Notifications {
+        reset_selection: bool,
+        result: anyhow::Result<Vec<Notification>>,
+    },
+    TopicSuggestions {
+        seed: String,
+        result: anyhow::Result<Vec<TopicSuggestion>>,
+    },
+    /// Nearest neighbors of a repository in the semantic index
+    SimilarRepositories {
+        repo_name: String,
+        result: anyhow::Result<Vec<Repository>>,
+    },
+}
+
+struct RunningTask {
+    id: u64,
+    kind: TaskKind,
+    label: String,
+    started: Instant,
+    handle: JoinHandle<()>,
+}
+
+/// Tracks spawned tasks and collects their results
+pub struct TaskManager {
+    next_id: u64,
+    running: Vec<RunningTask>,
+    tx: UnboundedSender<(u64, TaskOutput)>,
+    rx: UnboundedReceiver<(u64, TaskOutput)>,
+}
+
+impl TaskManager {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded_channel();
+        Self {
+            next_id: 0,
+            running: Vec::new(),
+            tx,
+            rx,
+        }
+    }
+
+    /// Run `future` in the background, replacing any running task of the same kind
+    pub fn spawn<F>(&mut self, kind: TaskKind, label: impl Into<String>, future: F)
+    where
+        F: Future<Output = TaskOutput> + Send + 'static,
+    {
+        self.cancel(kind);
+
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let tx = self.tx.clone();
+        let handle = tokio::spawn(async move {
+            let output = future.await;
+            // The receiver only goes away when the app quits
+            let _ = tx.send((id, output));
+        });
+
+        self.running.push(RunningTask {
+            id,
+            kind,
+            label: label.into(),
+            started: Instant::now(),
+            handle,
+        });
+    }
+
+    pub fn is_running(&self, kind: TaskKind) -> bool {
+        self.running.iter().any(|t| t.kind == kind)
+    }
+
+    pub fn is_busy(&self) -> bool {
+        !self.running.is_empty()
+    }
+
+    /// Abort the running task of this kind, if any
+    pub fn cancel(&mut self, kind: TaskKind) -> bool {
+        let before = self.running.len();
+        self.running.retain(|t| {
+            if t.kind == kind {
+                t.handle.abort();
+                false
+            } else {
+                true
+            }
+        });
+        self.running.len() != before
+    }
+
+    /// Abort every running task and return the kinds that were cancelled
+    pub fn cancel_all(&mut self) -> Vec<TaskKind> {
+        self.running
+            .drain(..)
+            .map(|t| {
+                t.handle.abort();
+                t.kind
+            })
+            .collect()
+    }
+
+    /// Collect outputs of tasks that finished since the last call.
+    ///
+    /// Results from cancelled or superseded tasks are dropped.
+    pub fn take_finished(&mut self) -> Vec<TaskOutput> {
+        let mut finished = Vec::new();
+        while let Ok((id, output)) = self.rx.try_recv() {
+            if let Some(pos) = self.running.iter().position(|t| t.id == id) {
+                self.running.remove(pos);
+                finished.push(output);
+            }
+        }
+        finished
+    }
+
+    /// Current spinner frame, animated by wall-clock time
+    pub fn spinner(&self) -> &'static str {
+        let started = self
+            .running
+            .iter()
+            .map(|t| t.started)
+            .min()
+            .unwrap_or_else(Instant::now);
+        let frame = started.elapsed().as_millis() / SPINNER_FRAME_MS;
+        SPINNER_FRAMES[frame as usize % SPINNER_FRAMES.len()]
+    }
+
+    /// One-line description of what's running, for the status bar
+    pub fn status(&self) -> Option<String> {
+        let oldest = self.running.iter().min_by_key(|t| t.started)?;
+        let others = match self.running.len() - 1 {
+            0 => String::new(),
+            n => format!(" (+{} more)", n),
+        };
+        Some(format!(
+            "{} {}{} {}s | ESC: cancel",
+            self.spinner(),
+            oldest.label,
+            others,
+            oldest.started.elapsed().as_secs()
+        ))
+    }
+}
+
+impl Default for TaskManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Fetches READMEs ahead of time for the selected result and its neighbours,
+/// so opening the README tab is instant. Runs silently beside the regular
+/// tasks, keyed by repository rather than kind, with a few fetches in flight.
+pub struct ReadmePrefetcher {
+    in_flight: HashMap<String, JoinHandle<()>>,
+    tx: UnboundedSender<(String, anyhow::Result<String>)>,
+    rx: UnboundedReceiver<(String, anyhow::Result<String>)>,
+}
+
+impl ReadmePrefetcher {
+    pub fn new() -> Self {
+        let (tx, rx) = unbounded_channel();
+        Self {
+            in_flight: HashMap::new(),
+            tx,
+            rx,
+        }
+    }
+
+    pub fn is_in_flight(&self, repo_name: &str) -> bool {
+        self.in_flight.contains_key(repo_name)
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.in_flight.len() >= MAX_PREFETCHES
+    }
+
+    /// Start prefetching a README unless it's already in flight or the limit is reached
+    pub fn spawn<F>(&mut self, repo_name: String, future: F) -> bool
+    where
+        F: Future<Output = anyhow::Result<String>> + Send + 'static,
+    {
+        if self.is_full() || self.is_in_flight(&repo_name) {
+            return false;
+        }
+
+        let tx = self.tx.clone();
+        let name = repo_name.clone();
+        let handle = tokio::spawn(async move {
+            let result = future.await;
+            let _ = tx.send((name, result));
+        });
+        self.in_flight.insert(repo_name, handle);
+        true
+    }
+
+    /// Abort prefetches for repositories that are no longer near the selection
+    pub fn retain(&mut self, keep: &[String]) {
+        self.in_flight.retain(|name, handle| {
+            let keep = keep.contains(name);
+            if !keep {
+                handle.abort();
+            }
+            keep
+        });
+    }
+
+    /// Collect READMEs that finished since the last call.
+    /// Results of aborted prefetches are dropped.
+    pub fn take_finished(&mut self) -> Vec<(String, anyhow::Result<String>)> {
+        let mut finished = Vec::new();
+        while let Ok((repo_name, result)) = self.rx.try_recv() {
+            if self.in_flight.remove(&repo_name).is_some() {
+                finished.push((repo_name, result));
+            }
+        }
+        finished
+    }
+}
+
+impl Default for ReadmePrefetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Real activity numbers for the Activity tab. Each part is None when the
+/// platform couldn't provide it; the tab estimates from push dates instead.
+#[derive(Debug, Clone, Default)]
+pub struct ActivityStats {
+    pub contributors: Option<u32>,
+    /// Commits per day, oldest week first, each week starting on Sunday
+    pub commit_activity: Option<Vec<[u32; 7]>>,
+    /// GitHub was still computing the commit statistics
+    pub stats_pending: bool,
+}
+
+/// API clients for all platforms, cheap to clone into spawned tasks
+#[derive(Clone)]
+pub struct PlatformClients {
+    pub github: GitHubClient,
+    pub gitlab: GitLabClient,
+    pub bitbucket: BitbucketClient,
+    pub sourcehut: SourceHutClient,
+    pub azure_devops: AzureDevOpsClient,
+    /// Vulnerability lookups, cached per package version for the session
+    pub osv: reposcout_deps::OsvClient,
+}
+
+/// Split "owner/repo" for platforms that take the parts separately
+fn split_repo_name(repo_name: &str) -> anyhow::Result<(&str, &str)> {
+    match repo_name.split('/').collect::<Vec<_>>().as_slice() {
+        [owner, repo] => Ok((owner, repo)),
+        _ => Err(anyhow::anyhow!("Invalid repository name format")),
+    }
+}
+
+impl PlatformClients {
+    pub async fn readme(&self, platform: Platform, repo_name: &str) -> anyhow::Result<String> {
+        match platform {
+            Platform::GitHub => {
+                let (owner, repo) = split_repo_name(repo_name)?;
+                Ok(self.github.get_readme(owner, repo).await?)
+            }
+            Platform::GitLab => Ok(self.gitlab.get_readme(repo_name).await?),
+            Platform::Bitbucket => {
+                let (owner, repo) = split_repo_name(repo_name)?;
+                Ok(self.bitbucket.get_readme(owner, repo).await?)
+            }
+            Platform::SourceHut => {
+                let (owner, repo) = split_repo_name(repo_name)?;
+                Ok(self.sourcehut.get_readme(owner, repo).await?)
+            }
+            Platform::AzureDevOps => {
+                let (project, repo) = split_repo_name(repo_name)?;
+                Ok(self.azure_devops.get_readme(project, repo).await?)
+            }
+        }
+    }
+
+    pub async fn changelog(&self, platform: Platform, repo_name: &str) -> anyhow::Result<String> {
+        match platform {
+            Platform::GitHub => {
+                let (owner, repo) = split_repo_name(repo_name)?;
+                Ok(self.github.get_changelog(owner, repo).await?)
+            }
+            Platform::GitLab => Ok(self.gitlab.get_changelog(repo_name).await?),
+            Platform::Bitbucket => {
+                let (owner, repo) = split_repo_name(repo_name)?;
+                Ok(self.bitbucket.get_changelog(owner, repo).await?)
+            }
+            Platform::SourceHut => {
+                let (owner, repo) = split_repo_name(repo_name)?;
+                Ok(self.sourcehut.get_changelog(owner, repo).await?)
+            }
+            Platform::AzureDevOps => {
+                let (project, repo) = split_repo_name(repo_name)?;
+                Ok(self.azure_devops.get_changelog(project, repo).await?)
+            }
+        }
+    }
+
+    /// Bytes of code per language. Only GitHub exposes a byte breakdown, other
+    /// platforms return None.
+    pub async fn languages(
+        &self,
+        platform: Platform,
+        repo_name: &str,
+    ) -> anyhow::Result<Option<HashMap<String, u64>>> {
+        match platform {
+            Platform::GitHub => {
+                let (owner, repo) = split_repo_name(repo_name)?;
+                Ok(Some(self.github.get_languages(owner, repo).await?))
+            }
+            Platform::GitLab
+            | Platform::Bitbucket
+            | Platform::SourceHut
+            | Platform::AzureDevOps => Ok(None),
+        }
+    }
+
+    /// Contributor count and a year of commit activity. Only GitHub exposes
+    /// them; other platforms get empty stats.
+    pub async fn activity(
+        &self,
+        platform: Platform,
+        repo_name: &str,
+    ) -> anyhow::Result<ActivityStats> {
+        if platform != Platform::GitHub {
+            return Ok(ActivityStats::default());
+        }
+        let (owner, repo) = split_repo_name(repo_name)?;
+        let (contributors, mut commit_activity) = tokio::join!(
+            self.github.get_contributor_count(owner, repo),
+            self.github.get_commit_activity(owner, repo),
+        );
+        // A 202 means GitHub has started computing them; they're usually
+        // ready within seconds
+        for _ in 0..STATS_PENDING_RETRIES {
+            if !matches!(commit_activity, Ok(None)) {
+                break;
+            }
+            tokio::time::sleep(STATS_PENDING_DELAY).await;
+            commit_activity = self.github.get_commit_activity(owner, repo).await;
+        }
+        // Either half is useful without the other
+        let (contributors, commit_activity) = match (contributors, commit_activity) {
+            (Err(e), Err(_)) => return Err(e.into()),
+            (contributors, commit_activity) => (contributors.ok(), commit_activity.ok()),
+        };
+
+        Ok(ActivityStats {
+            contributors,
+            stats_pending: matches!(commit_activity, Some(None)),
+            commit_activity: commit_activity
+                .flatten()
+                .filter(|weeks| !weeks.is_empty())
+                .map(|weeks| weeks.into_iter().map(|week| week.days).collect()),
+        })
+    }
+
+    /// Fetch and parse the dependency manifest for the repository's language.
+    ///
+    /// Manifests are tried in order; one that is missing or has no dependency
+    /// table falls through to the next. Finding none is not an error, it just
+    /// means no dependency info, but failing to fetch one is.
+    pub async fn dependencies(
+        &self,
+        platform: Platform,
+        repo_name: &str,
+        language: Option<&str>,
+    ) -> anyhow::Result<Option<DependencyInfo>> {
+        reposcout_deps::analyze_dependencies(language, |manifest| {
+            self.file_content(platform, repo_name, manifest)
+        })
+        .await
+    }
+
+    /// Fetch a file from the repository, `None` if it doesn't exist
+    pub async fn file_content(
+        &self,
+        platform: Platform,
+        repo_name: &str,
+        path: &str,
+    ) -> anyhow::Result<Option<String>> {
+        let file = match platform {
+            Platform::GitHub => {
+                let (owner, repo) = split_repo_name(repo_name)?;
+                missing_as_none(self.github.get_file_content(owner, repo, path).await)
+            }
+            Platform::GitLab => {
+                missing_as_none(self.gitlab.get_file_content(repo_name, path).await)
+            }
+            Platform::Bitbucket => {
+                let (owner, repo) = split_repo_name(repo_name)?;
+                missing_as_none(self.bitbucket.get_file_content(owner, repo, path).await)
+            }
+            Platform::SourceHut => {
+                let (owner, repo) = split_repo_name(repo_name)?;
+                missing_as_none(self.sourcehut.get_file_content(owner, repo, path).await)
+            }
+            Platform::AzureDevOps => {
+                let (project, repo) = split_repo_name(repo_name)?;
+                missing_as_none(
+                    self.azure_devops
+                        .get_file_content(project, repo, path)
+                        .await,
+                )
+            }
+        };
+        Ok(file?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn readme(repo_name: &str) -> TaskOutput {
+        TaskOutput::Readme {
+            repo_name: repo_name.to_string(),
+            result: Ok(String::new()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_task_results_are_collected() {
+        let mut tasks = TaskManager::new();
+        tasks.spawn(TaskKind::Readme, "Fetching README", async { readme("a/b") });
+        assert!(tasks.is_running(TaskKind::Readme));
+
+        let mut finished = Vec::new();
+        while finished.is_empty() {
+            tokio::task::yield_now().await;
+            finished = tasks.take_finished();
+        }
+
+        assert!(matches!(
+            finished.as_slice(),
+            [TaskOutput::Readme { repo_name, .. }] if repo_name == "a/b"
+        ));
+        assert!(!tasks.is_busy());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_and_superseded_results_are_dropped() {
+        let mut tasks = TaskManager::new();
+        tasks.spawn(TaskKind::Readme, "old", async { readme("old/repo") });
+        tasks.spawn(TaskKind::Readme, "new", async { readme("new/repo") });
+        tasks.spawn(TaskKind::Changelog, "slow", async {
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+            readme("never/sent")
+        });
+
+        assert_eq!(tasks.cancel_all().len(), 2);
+        assert!(!tasks.is_busy());
+
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        assert!(tasks.take_finished().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prefetches_are_bounded_and_cancellable() {
+        let mut prefetch = ReadmePrefetcher::new();
+        let slow = || async {
+            tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+            Ok(String::new())
+        };
+
+        assert!(prefetch.spawn("a/one".to_string(), async { Ok("# One".to_string()) }));
+        assert!(!prefetch.spawn("a/one".to_string(), slow()));
+        assert!(prefetch.spawn("a/two".to_string(), slow()));
+        assert!(prefetch.spawn("a/three".to_string(), slow()));
+        assert!(!prefetch.spawn("a/four".to_string(), slow()));
+
+        let mut finished = Vec::new();
+        while finished.is_empty() {
+            tokio::task::yield_now().await;
+            finished = prefetch.take_finished();
+        }
+        assert!(matches!(
+            finished.as_slice(),
+            [(name, Ok(readme))] if name == "a/one" && readme == "# One"
+        ));
+
+        prefetch.retain(&["a/three".to_string()]);
+        assert!(!prefetch.is_in_flight("a/two"));
+        assert!(prefetch.is_in_flight("a/three"));
+        assert!(prefetch.spawn("a/four".to_string(), slow()));
+    }
+}
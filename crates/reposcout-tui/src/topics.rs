@@ -0,0 +1,163 @@
+// Topic suggestions for the Discovery Topics list
+//
+// Typing a seed in Topics mode swaps the built-in topic list for topics found
+// on repositories matching the seed. Fetches wait for typing to pause and
+// results are cached for a while, so each keystroke doesn't hit the API.
+
+use reposcout_core::discovery::{popular_topics, TopicSuggestion};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How long the seed must stay unchanged before suggestions are fetched
+const DEBOUNCE: Duration = Duration::from_millis(400);
+
+/// How long fetched suggestions are reused for the same seed
+const CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Suggestions for the current seed plus a short-lived cache of earlier ones
+#[derive(Default)]
+pub struct TopicSuggestions {
+    /// Seed the shown suggestions belong to, empty for the built-in list
+    pub seed: String,
+    pub topics: Vec<TopicSuggestion>,
+    /// Seed being typed and when it last changed
+    pending: Option<(String, Instant)>,
+    cache: HashMap<String, (Instant, Vec<TopicSuggestion>)>,
+}
+
+impl TopicSuggestions {
+    /// Follow the seed being typed. Cached seeds apply right away; returns
+    /// the seed to fetch once typing has paused and nothing is cached for it.
+    pub fn poll(&mut self, input: &str, now: Instant) -> Option<String> {
+        let seed = input.trim().to_lowercase();
+        if seed == self.seed {
+            self.pending = None;
+            return None;
+        }
+        if seed.is_empty() {
+            self.seed.clear();
+            self.topics.clear();
+            self.pending = None;
+            return None;
+        }
+        if let Some((fetched, topics)) = self.cache.get(&seed) {
+            if now.duration_since(*fetched) < CACHE_TTL {
+                self.topics = topics.clone();
+                self.seed = seed;
+                self.pending = None;
+                return None;
+            }
+        }
+
+        match &self.pending {
+            Some((pending, since)) if *pending == seed => {
+                if now.duration_since(*since) < DEBOUNCE {
+                    return None;
+                }
+                self.pending = None;
+                // Mark it current so the fetch only starts once
+                self.seed = seed.clone();
+                Some(seed)
+            }
+            _ => {
+                self.pending = Some((seed, now));
+                None
+            }
+        }
+    }
+
+    /// Store fetched suggestions. A failed fetch leaves the built-in list
+    /// and isn't cached, so typing the seed again retries it.
+    pub fn finish(&mut self, seed: String, topics: Option<Vec<TopicSuggestion>>, now: Instant) {
+        let topics = match topics {
+            Some(topics) => {
+                self.cache.insert(seed.clone(), (now, topics.clone()));
+                topics
+            }
+            None => Vec::new(),
+        };
+        if seed == self.seed {
+            self.topics = topics;
+        }
+    }
+
+    /// Whether the suggestions replace the built-in list
+    pub fn is_active(&self) -> bool {
+        !self.topics.is_empty()
+    }
+
+    /// Topics to list as (topic, display name) pairs
+    pub fn entries(&self) -> Vec<(String, String)> {
+        if self.is_active() {
+            self.topics
+                .iter()
+                .map(|s| (s.topic.clone(), s.topic.clone()))
+                .collect()
+        } else {
+            popular_topics()
+                .into_iter()
+                .map(|(topic, name)| (topic.to_string(), name.to_string()))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn suggestion(topic: &str) -> TopicSuggestion {
+        TopicSuggestion {
+            topic: topic.to_string(),
+            repos: 3,
+        }
+    }
+
+    #[test]
+    fn test_fetches_after_typing_pauses_and_reuses_cache() {
+        let mut suggestions = TopicSuggestions::default();
+        let start = Instant::now();
+
+        assert_eq!(suggestions.poll("ru", start), None);
+        assert_eq!(suggestions.poll("rust", start + DEBOUNCE / 2), None);
+        // Still typing "rust", not long enough since it last changed
+        assert_eq!(suggestions.poll("rust", start + DEBOUNCE), None);
+        let fetch_at = start + DEBOUNCE / 2 + DEBOUNCE;
+        assert_eq!(
+            suggestions.poll("Rust ", fetch_at),
+            Some("rust".to_string())
+        );
+        assert_eq!(suggestions.poll("rust", fetch_at), None);
+
+        suggestions.finish("rust".to_string(), Some(vec![suggestion("cli")]), fetch_at);
+        assert_eq!(suggestions.entries()[0].0, "cli");
+
+        // Clearing the seed brings back the built-in list, retyping hits the cache
+        suggestions.poll("", fetch_at);
+        assert!(!suggestions.is_active());
+        assert_eq!(suggestions.poll("rust", fetch_at), None);
+        assert!(suggestions.is_active());
+    }
+
+    #[test]
+    fn test_failed_fetch_is_retried() {
+        let mut suggestions = TopicSuggestions::default();
+        let start = Instant::now();
+        suggestions.poll("go", start);
+        assert_eq!(
+            suggestions.poll("go", start + DEBOUNCE),
+            Some("go".to_string())
+        );
+        suggestions.finish("go".to_string(), None, start + DEBOUNCE);
+        assert!(!suggestions.is_active());
+
+        // Typing the seed again fetches it rather than reusing the failure
+        let later = start + DEBOUNCE * 2;
+        suggestions.poll("", later);
+        suggestions.poll("go", later);
+        assert_eq!(
+            suggestions.poll("go", later + DEBOUNCE),
+            Some("go".to_string())
+        );
+    }
+}
@@ -1,6 +1,6 @@
 // UI rendering logic
 use crate::code_ui;
-use crate::{App, InputMode, SearchMode};
+use crate::{App, InputMode, ResultRow, SearchMode};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -9,8 +9,7 @@ use ratatui::{
     Frame,
 };
 use syntect::easy::HighlightLines;
-use syntect::highlighting::{Style as SyntectStyle, ThemeSet};
-use syntect::parsing::SyntaxSet;
+use syntect::highlighting::Style as SyntectStyle;
 use syntect::util::LinesWithEndings;
 
 /// Helper function to convert theme color to ratatui color
@@ -18,6 +17,16 @@ fn theme_color(color: &reposcout_core::Color) -> Color {
     Color::Rgb(color.r, color.g, color.b)
 }
 
+/// Colour for a health status, from the colour name the health badges use
+fn health_color(status: &reposcout_core::HealthStatus) -> Color {
+    match status.color_code() {
+        "green" => Color::Green,
+        "yellow" => Color::Yellow,
+        "orange" => Color::Rgb(255, 165, 0),
+        _ => Color::Red,
+    }
+}
+
 /// Helper function to create base style with theme background and foreground
 fn base_style(app: &App) -> Style {
     Style::default()
@@ -25,16 +34,50 @@ fn base_style(app: &App) -> Style {
         .fg(theme_color(&app.current_theme.colors.foreground))
 }
 
+/// Centered notice shown instead of the layout on undersized terminals
+fn render_terminal_too_small(frame: &mut Frame, app: &App, area: Rect) {
+    let (min_width, min_height) = app.min_terminal_size;
+    let message = format!("Terminal too small (need {}x{})", min_width, min_height);
+    // Roughly center the (possibly wrapped) message vertically
+    let width = area.width.max(1);
+    let lines = (message.chars().count() as u16 + width - 1) / width;
+    let mut text = vec![Line::from(""); area.height.saturating_sub(lines) as usize / 2];
+    text.push(Line::from(Span::styled(
+        message,
+        Style::default()
+            .fg(theme_color(&app.current_theme.colors.warning))
+            .add_modifier(Modifier::BOLD),
+    )));
+
+    let paragraph = Paragraph::new(text)
+        .style(base_style(app))
+        .alignment(ratatui::layout::Alignment::Center)
+        .wrap(Wrap { trim: true });
+    frame.render_widget(paragraph, area);
+}
+
 /// Helper function to create border style
 fn border_style(app: &App) -> Style {
     Style::default().fg(theme_color(&app.current_theme.colors.border))
 }
 
 pub fn render(frame: &mut Frame, app: &mut App) {
+    // Set again below if this frame shows the results list
+    app.results_area = None;
+
     // Apply theme background to entire terminal
     let background = Block::default().style(base_style(app));
     frame.render_widget(background, frame.area());
 
+    // The adaptive layout below splits the screen into regions too small to
+    // draw in (or subtracts past zero), so ask for more room instead. Every
+    // resize redraws, so the normal layout returns once the window grows.
+    let area = frame.area();
+    if !app.terminal_fits(area.width, area.height) {
+        render_terminal_too_small(frame, app, area);
+        return;
+    }
+
     let screen_height = frame.area().height;
 
     // Dynamic header height: 4 if Bitbucket not configured (extra line for warning), else 3
@@ -51,7 +94,7 @@ pub fn render(frame: &mut Frame, app: &mut App) {
             vec![
                 Constraint::Length(header_height.min(screen_height / 6)), // Header (dynamic)
                 Constraint::Length(3.min(screen_height / 8)),             // Search input
-                Constraint::Length(9.min(screen_height / 4)),             // Filters panel
+                Constraint::Length(13.min(screen_height / 3)),            // Filters panel
                 Constraint::Min(5),    // Main content (minimum 5 lines)
                 Constraint::Length(1), // Status bar
             ]
@@ -180,6 +223,10 @@ pub fn render(frame: &mut Frame, app: &mut App) {
         }
     }
 
+    if app.input_mode == InputMode::BookmarkEdit {
+        render_bookmark_edit_popup(app, frame, frame.area());
+    }
+
     // Render theme selector if active
     if app.show_theme_selector {
         crate::theme_ui::render_theme_selector(frame, app, frame.area());
@@ -194,6 +241,26 @@ pub fn render(frame: &mut Frame, app: &mut App) {
     render_status_bar(frame, app, status_area);
 }
 
+/// Remaining API requests next to a platform badge, red once the quota runs low
+fn rate_limit_span(
+    app: &App,
+    rate_limit: Option<reposcout_api::RateLimitState>,
+    compact: bool,
+) -> Option<Span<'static>> {
+    let state = rate_limit?;
+    let text = if compact {
+        format!(" {}", state.remaining)
+    } else {
+        format!(" {}/{}", state.remaining, state.limit)
+    };
+    let color = if state.is_low() {
+        theme_color(&app.current_theme.colors.error)
+    } else {
+        Color::DarkGray
+    };
+    Some(Span::styled(text, Style::default().fg(color)))
+}
+
 fn render_header(frame: &mut Frame, app: &App, area: Rect) {
     let screen_width = area.width;
 
@@ -296,6 +363,11 @@ fn render_header(frame: &mut Frame, app: &App, area: Rect) {
                 .bg(theme_color(&app.current_theme.colors.success))
                 .add_modifier(Modifier::BOLD),
         ));
+        platform_spans.extend(rate_limit_span(
+            app,
+            app.platform_status.github_rate_limit,
+            true,
+        ));
         platform_spans.push(Span::styled(
             " GL✓ ",
             Style::default()
@@ -303,6 +375,11 @@ fn render_header(frame: &mut Frame, app: &App, area: Rect) {
                 .bg(theme_color(&app.current_theme.colors.accent))
                 .add_modifier(Modifier::BOLD),
         ));
+        platform_spans.extend(rate_limit_span(
+            app,
+            app.platform_status.gitlab_rate_limit,
+            true,
+        ));
         if app.platform_status.bitbucket_configured {
             platform_spans.push(Span::styled(
                 " BB✓ ",
@@ -329,6 +406,11 @@ fn render_header(frame: &mut Frame, app: &App, area: Rect) {
                 .bg(theme_color(&app.current_theme.colors.success))
                 .add_modifier(Modifier::BOLD),
         ));
+        platform_spans.extend(rate_limit_span(
+            app,
+            app.platform_status.github_rate_limit,
+            false,
+        ));
         platform_spans.push(Span::raw(" "));
         platform_spans.push(Span::styled(
             " GitLab ✓ ",
@@ -337,6 +419,11 @@ fn render_header(frame: &mut Frame, app: &App, area: Rect) {
                 .bg(theme_color(&app.current_theme.colors.accent))
                 .add_modifier(Modifier::BOLD),
         ));
+        platform_spans.extend(rate_limit_span(
+            app,
+            app.platform_status.gitlab_rate_limit,
+            false,
+        ));
         platform_spans.push(Span::raw(" "));
         if app.platform_status.bitbucket_configured {
             platform_spans.push(Span::styled(
@@ -357,6 +444,17 @@ fn render_header(frame: &mut Frame, app: &App, area: Rect) {
         }
     }
 
+    if reposcout_core::is_offline() {
+        platform_spans.push(Span::raw(" "));
+        platform_spans.push(Span::styled(
+            " OFFLINE ",
+            Style::default()
+                .fg(Color::Black)
+                .bg(theme_color(&app.current_theme.colors.warning))
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
     let mut platform_lines = vec![Line::from(platform_spans)];
 
     // Add Bitbucket warning on separate line (adaptive text)
@@ -438,7 +536,9 @@ fn render_search_input(frame: &mut Frame, app: &App, area: Rect) {
         | InputMode::FuzzySearch
         | InputMode::HistoryPopup
         | InputMode::Settings
-        | InputMode::TokenInput => Style::default(),
+        | InputMode::TokenInput
+        | InputMode::BookmarkEdit
+        | InputMode::Command => Style::default(),
     };
 
     // Different title and content based on search mode
@@ -464,6 +564,15 @@ fn render_search_input(frame: &mut Frame, app: &App, area: Rect) {
                 )
             }
         }
+        // Outside of typing, show the query as it will run with the filters
+        SearchMode::Repository if app.input_mode != InputMode::Searching => (
+            if app.show_filters {
+                "Search (r to re-run with these filters)"
+            } else {
+                "Search (ESC to navigate, / to search)"
+            },
+            app.get_search_query().trim().to_string(),
+        ),
         SearchMode::Repository => (
             "Search (ESC to navigate, / to search)",
             app.search_input.as_str().to_string(),
@@ -485,7 +594,12 @@ fn render_search_input(frame: &mut Frame, app: &App, area: Rect) {
             };
             (
                 "📬 Notifications",
-                format!("{}{}", filter_info, participating_info),
+                format!(
+                    "{}{}{}",
+                    filter_info,
+                    participating_info,
+                    notification_filter_info(app)
+                ),
             )
         }
         SearchMode::Semantic => (
@@ -539,6 +653,8 @@ fn render_search_input(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_results_list(frame: &mut Frame, app: &mut App, area: Rect) {
+    app.results_area = Some(area);
+
     // Calculate adaptive description length based on area width
     let available_width = area.width.saturating_sub(10); // Account for borders and padding
     let desc_max_length = if available_width < 50 {
@@ -551,13 +667,13 @@ fn render_results_list(frame: &mut Frame, app: &mut App, area: Rect) {
         80 // Wide
     };
 
-    // Show loading message if loading
-    if app.loading {
+    // Show loading message until the first platform responds
+    if app.loading && app.results.is_empty() {
         let loading_text = vec![
             Line::from(""),
             Line::from(""),
             Line::from(vec![Span::styled(
-                "  🔄 Searching...",
+                format!("  {} Searching...", app.spinner()),
                 Style::default()
                     .fg(theme_color(&app.current_theme.colors.info))
                     .add_modifier(Modifier::BOLD),
@@ -583,139 +699,200 @@ fn render_results_list(frame: &mut Frame, app: &mut App, area: Rect) {
         return;
     }
 
-    let items: Vec<ListItem> = app
-        .results
-        .iter()
-        .enumerate()
-        .map(|(i, repo)| {
-            let is_selected = i == app.selected_index;
-
-            // Check if this repo is bookmarked
-            let bookmark_key =
-                App::bookmark_key(&repo.platform.to_string().to_lowercase(), &repo.full_name);
-            let is_bookmarked = app.bookmarked.contains(&bookmark_key);
-
-            // Platform color for background
-            let platform_bg_color = match repo.platform {
-                reposcout_core::models::Platform::GitHub => Color::Rgb(255, 165, 0), // Orange for GitHub
-                reposcout_core::models::Platform::GitLab => Color::Rgb(252, 109, 38), // GitLab orange
-                reposcout_core::models::Platform::Bitbucket => Color::Rgb(33, 136, 255), // Bitbucket blue
-            };
+    let results = &app.results;
+    let repo_item = |i: usize| {
+        let repo = &results[i];
+        let is_selected = i == app.selected_index;
+
+        // Check if this repo is bookmarked
+        let bookmark_key =
+            App::bookmark_key(&repo.platform.to_string().to_lowercase(), &repo.full_name);
+        let is_bookmarked = app.bookmarked.contains(&bookmark_key);
+        let is_reviewed = app.reviewed.contains(&bookmark_key);
+
+        // Platform color for background
+        let platform_bg_color = match repo.platform {
+            reposcout_core::models::Platform::GitHub => Color::Rgb(255, 165, 0), // Orange for GitHub
+            reposcout_core::models::Platform::GitLab => Color::Rgb(252, 109, 38), // GitLab orange
+            reposcout_core::models::Platform::Bitbucket => Color::Rgb(33, 136, 255), // Bitbucket blue
+            reposcout_core::models::Platform::SourceHut => Color::Rgb(170, 170, 170), // SourceHut gray
+            reposcout_core::models::Platform::AzureDevOps => Color::Rgb(0, 120, 212), // Azure blue
+        };
 
-            // Line 1: Bookmark + Stats + Name (BRIGHT and DISTINCTIVE)
-            let name_style = if is_selected {
-                Style::default()
-                    .fg(theme_color(&app.current_theme.colors.selected))
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default()
-                    .fg(theme_color(&app.current_theme.colors.primary))
-                    .add_modifier(Modifier::BOLD)
-            };
+        // Line 1: Bookmark + Stats + Name (BRIGHT and DISTINCTIVE)
+        let name_style = if is_selected {
+            Style::default()
+                .fg(theme_color(&app.current_theme.colors.selected))
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default()
+                .fg(theme_color(&app.current_theme.colors.primary))
+                .add_modifier(Modifier::BOLD)
+        };
 
-            let line1 = Line::from(vec![
-                Span::styled(
-                    if is_bookmarked { "📚" } else { "  " },
-                    Style::default().fg(theme_color(&app.current_theme.colors.accent)),
-                ),
-                Span::raw(" "),
-                Span::styled(
-                    format!("⭐{}", format_number(repo.stars)),
-                    Style::default().fg(Color::Rgb(255, 215, 0)), // Gold color for stars
-                ),
-                Span::raw("  "),
-                Span::styled(
-                    format!("🍴{}", format_number(repo.forks)),
-                    Style::default().fg(Color::Rgb(100, 149, 237)), // Cornflower blue for forks
-                ),
-                Span::raw("  "),
-                Span::styled(&repo.full_name, name_style),
-            ]);
+        let mut line1_spans = vec![
+            Span::styled(
+                if is_bookmarked { "📚" } else { "  " },
+                Style::default().fg(theme_color(&app.current_theme.colors.accent)),
+            ),
+            Span::raw(" "),
+            Span::styled(
+                format!("⭐{}", format_number(repo.stars)),
+                Style::default().fg(Color::Rgb(255, 215, 0)), // Gold color for stars
+            ),
+            Span::raw("  "),
+            Span::styled(
+                format!("🍴{}", format_number(repo.forks)),
+                Style::default().fg(Color::Rgb(100, 149, 237)), // Cornflower blue for forks
+            ),
+            Span::raw("  "),
+            Span::styled(&repo.full_name, name_style),
+        ];
+        // Bookmark tags as chips, like topics in the stats preview
+        for tag in app.tags_for(repo) {
+            line1_spans.push(Span::raw(" "));
+            line1_spans.push(Span::styled(
+                format!(" {} ", tag),
+                Style::default().fg(Color::Black).bg(Color::Magenta),
+            ));
+        }
+        if is_reviewed {
+            line1_spans.push(Span::styled(
+                " ✓ reviewed",
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        let line1 = Line::from(line1_spans);
+
+        // Line 2: Language + Platform + Updated + Health (MUTED secondary info)
+        let lang_display = repo.language.as_deref().unwrap_or("Unknown");
+        let days_ago = (chrono::Utc::now() - repo.updated_at).num_days();
+        let updated_display = if !repo.has_dates() {
+            "unknown".to_string()
+        } else if days_ago == 0 {
+            "today".to_string()
+        } else if days_ago == 1 {
+            "1d ago".to_string()
+        } else if days_ago < 30 {
+            format!("{}d ago", days_ago)
+        } else if days_ago < 365 {
+            format!("{}mo ago", days_ago / 30)
+        } else {
+            format!("{}y ago", days_ago / 365)
+        };
 
-            // Line 2: Language + Platform + Updated + Health (MUTED secondary info)
-            let lang_display = repo.language.as_deref().unwrap_or("Unknown");
-            let days_ago = (chrono::Utc::now() - repo.updated_at).num_days();
-            let updated_display = if days_ago == 0 {
-                "today".to_string()
-            } else if days_ago == 1 {
-                "1d ago".to_string()
-            } else if days_ago < 30 {
-                format!("{}d ago", days_ago)
-            } else if days_ago < 365 {
-                format!("{}mo ago", days_ago / 30)
-            } else {
-                format!("{}y ago", days_ago / 365)
-            };
+        let mut line2_spans = vec![
+            Span::raw("     "),                                                // Indent
+            Span::styled("●", Style::default().fg(Color::Rgb(147, 112, 219))), // Medium purple
+            Span::raw(" "),
+            Span::styled(lang_display, Style::default().fg(Color::Rgb(147, 112, 219))),
+            Span::raw("  •  "),
+            Span::styled(
+                format!(" {} ", repo.platform),
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(platform_bg_color)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw("  •  "),
+            Span::styled(
+                updated_display,
+                Style::default().fg(Color::Rgb(128, 128, 128)),
+            ), // Medium gray
+        ];
 
-            let mut line2_spans = vec![
-                Span::raw("     "),                                                // Indent
-                Span::styled("●", Style::default().fg(Color::Rgb(147, 112, 219))), // Medium purple
-                Span::raw(" "),
-                Span::styled(lang_display, Style::default().fg(Color::Rgb(147, 112, 219))),
-                Span::raw("  •  "),
-                Span::styled(
-                    format!(" {} ", repo.platform),
-                    Style::default()
-                        .fg(Color::Black)
-                        .bg(platform_bg_color)
-                        .add_modifier(Modifier::BOLD),
-                ),
-                Span::raw("  •  "),
-                Span::styled(
-                    updated_display,
-                    Style::default().fg(Color::Rgb(128, 128, 128)),
-                ), // Medium gray
-            ];
+        // Add health indicator if available
+        if let Some(health) = &repo.health {
+            let status_color = health_color(&health.status);
 
-            // Add health indicator if available
-            if let Some(health) = &repo.health {
-                let health_color = match health.status {
-                    reposcout_core::HealthStatus::Healthy => Color::Green,
-                    reposcout_core::HealthStatus::Moderate => Color::Yellow,
-                    reposcout_core::HealthStatus::Warning => Color::Rgb(255, 165, 0), // Orange
-                    reposcout_core::HealthStatus::Critical => Color::Red,
-                };
+            line2_spans.push(Span::raw("  •  "));
+            line2_spans.push(Span::styled(
+                format!("{} {}", health.status.emoji(), health.maintenance.label()),
+                Style::default().fg(status_color),
+            ));
+        }
 
-                line2_spans.push(Span::raw("  •  "));
+        // Explain why a result survived the fuzzy filter
+        if app.input_mode == InputMode::FuzzySearch {
+            if let Some(field) = app.fuzzy_matched_field(repo) {
+                line2_spans.push(Span::raw("  "));
                 line2_spans.push(Span::styled(
-                    format!("{} {}", health.status.emoji(), health.maintenance.label()),
-                    Style::default().fg(health_color),
+                    format!("[{}]", field.label()),
+                    Style::default().fg(Color::Magenta),
                 ));
             }
+        }
 
-            let line2 = Line::from(line2_spans);
+        let line2 = Line::from(line2_spans);
 
-            // Line 3: Description (VERY MUTED so it doesn't compete with name)
-            // Use char_indices() to safely truncate at character boundaries - adaptive
-            let description = if let Some(desc) = &repo.description {
-                let char_count = desc.chars().count();
-                if char_count > desc_max_length as usize {
-                    let truncated: String =
-                        desc.chars().take(desc_max_length as usize - 3).collect();
-                    format!("     {}...", truncated)
-                } else {
-                    format!("     {}", desc)
-                }
+        // Line 3: Description (VERY MUTED so it doesn't compete with name)
+        // Use char_indices() to safely truncate at character boundaries - adaptive
+        let description = if let Some(desc) = &repo.description {
+            let char_count = desc.chars().count();
+            if char_count > desc_max_length as usize {
+                let truncated: String = desc.chars().take(desc_max_length as usize - 3).collect();
+                format!("     {}...", truncated)
             } else {
-                "     No description".to_string()
-            };
+                format!("     {}", desc)
+            }
+        } else {
+            "     No description".to_string()
+        };
 
-            let line3 = Line::from(vec![
-                Span::styled(description, Style::default().fg(Color::Rgb(105, 105, 105))), // Dim gray - very muted
-            ]);
+        let line3 = Line::from(vec![
+            Span::styled(description, Style::default().fg(Color::Rgb(105, 105, 105))), // Dim gray - very muted
+        ]);
 
-            let content = vec![line1, line2, line3];
+        let content = vec![line1, line2, line3];
 
+        // Reviewed repositories fade into the background
+        if is_reviewed {
+            ListItem::new(content).style(Style::default().add_modifier(Modifier::DIM))
+        } else {
             ListItem::new(content)
+        }
+    };
+
+    let rows = app.result_rows();
+    let items: Vec<ListItem> = rows
+        .iter()
+        .map(|row| match row {
+            ResultRow::Header { name, count } => ListItem::new(Line::from(Span::styled(
+                format!("── {} ({}) ──", name, count),
+                Style::default()
+                    .fg(theme_color(&app.current_theme.colors.accent))
+                    .add_modifier(Modifier::BOLD),
+            ))),
+            ResultRow::Result(i) => repo_item(*i),
         })
+        .chain(app.loading_more.then(|| {
+            ListItem::new(Line::from(Span::styled(
+                format!("  {} Loading more...", app.spinner()),
+                Style::default().fg(theme_color(&app.current_theme.colors.info)),
+            )))
+        }))
         .collect();
 
     let title = if app.loading {
-        "Results (Loading...)"
+        format!(
+            "Results ({}, {} loading...)",
+            app.results.len(),
+            app.spinner()
+        )
+    } else if app.show_bookmarks_only && !app.bookmark_query.is_empty() {
+        format!(
+            "📚 Bookmarks matching '{}' ({})",
+            app.bookmark_query,
+            app.results.len()
+        )
     } else if app.show_bookmarks_only {
-        &format!("📚 Bookmarks ({})", app.results.len())
+        format!("📚 Bookmarks ({})", app.results.len())
     } else {
-        &format!("Results ({})", app.results.len())
+        format!("Results ({})", app.results.len())
+    };
+    let title = match app.group_by {
+        Some(group_by) => format!("{} by {}", title, group_by.label()),
+        None => title,
     };
 
     let list = List::new(items)
@@ -734,11 +911,21 @@ fn render_results_list(frame: &mut Frame, app: &mut App, area: Rect) {
         )
         .highlight_symbol(">> ");
 
-    // Use stateful rendering for proper scrolling
-    frame.render_stateful_widget(list, area, &mut app.list_state);
+    // Use stateful rendering for proper scrolling. The list state holds the
+    // result index; with header rows the selected row is further down.
+    let selected_row = rows
+        .iter()
+        .position(|row| *row == ResultRow::Result(app.selected_index));
+    if app.group_by.is_some() {
+        app.list_state.select(selected_row);
+        frame.render_stateful_widget(list, area, &mut app.list_state);
+        app.list_state.select(Some(app.selected_index));
+    } else {
+        frame.render_stateful_widget(list, area, &mut app.list_state);
+    }
 }
 
-fn render_preview(frame: &mut Frame, app: &App, area: Rect) {
+fn render_preview(frame: &mut Frame, app: &mut App, area: Rect) {
     use crate::PreviewMode;
 
     // Split area to show tabs at the top
@@ -757,11 +944,23 @@ fn render_preview(frame: &mut Frame, app: &App, area: Rect) {
     let (content, scroll_offset) = match app.preview_mode {
         PreviewMode::Stats => (render_stats_preview(app), 0),
         PreviewMode::Readme => (render_readme_preview(app), app.readme_scroll),
+        PreviewMode::Changelog => (render_changelog_preview(app), app.readme_scroll),
         PreviewMode::Activity => (render_activity_preview(app), 0),
+        PreviewMode::Languages => (
+            render_languages_preview(app, chunks[1].width.saturating_sub(2)),
+            0,
+        ),
         PreviewMode::Dependencies => (render_dependencies_preview(app), 0),
         PreviewMode::Package => (render_package_preview(app), 0),
     };
 
+    // Approximate wrapped height so `G` and Ctrl+D know where the bottom is
+    let inner_width = chunks[1].width.saturating_sub(2).max(1) as usize;
+    let document_rows: usize = content
+        .iter()
+        .map(|line| line.width().max(1).saturating_sub(1) / inner_width + 1)
+        .sum();
+
     let paragraph = Paragraph::new(content)
         .block(
             Block::default()
@@ -774,6 +973,9 @@ fn render_preview(frame: &mut Frame, app: &App, area: Rect) {
         .scroll((scroll_offset, 0));
 
     frame.render_widget(paragraph, chunks[1]);
+
+    app.document_rows = document_rows.min(u16::MAX as usize) as u16;
+    app.preview_height = chunks[1].height.saturating_sub(2);
 }
 
 fn render_preview_tabs(frame: &mut Frame, app: &App, area: Rect) {
@@ -782,7 +984,9 @@ fn render_preview_tabs(frame: &mut Frame, app: &App, area: Rect) {
     let tabs = [
         ("Stats", PreviewMode::Stats),
         ("README", PreviewMode::Readme),
+        ("Changelog", PreviewMode::Changelog),
         ("Activity", PreviewMode::Activity),
+        ("Languages", PreviewMode::Languages),
         ("Dependencies", PreviewMode::Dependencies),
         ("Package", PreviewMode::Package),
     ];
@@ -877,6 +1081,20 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
             ),
         ]));
 
+        if let Some(mirrors) = repo.mirrors_label() {
+            lines.push(Line::from(vec![
+                Span::raw("🪞 "),
+                Span::styled(mirrors, Style::default().fg(Color::Gray)),
+            ]));
+        }
+
+        if let Some(mrs) = repo.open_merge_requests {
+            lines.push(Line::from(vec![
+                Span::raw("🔀 Open MRs:  "),
+                Span::styled(format_number(mrs), Style::default().fg(Color::Magenta)),
+            ]));
+        }
+
         lines.push(Line::from(""));
 
         if let Some(lang) = &repo.language {
@@ -924,7 +1142,7 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
         lines.push(Line::from(vec![
             Span::raw("📅 Created:   "),
             Span::styled(
-                repo.created_at.format("%Y-%m-%d").to_string(),
+                reposcout_core::models::format_day(repo.created_at),
                 Style::default().fg(Color::Gray),
             ),
         ]));
@@ -932,7 +1150,7 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
         lines.push(Line::from(vec![
             Span::raw("🔄 Updated:   "),
             Span::styled(
-                repo.updated_at.format("%Y-%m-%d").to_string(),
+                reposcout_core::models::format_day(repo.updated_at),
                 Style::default().fg(Color::Gray),
             ),
         ]));
@@ -940,7 +1158,7 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
         lines.push(Line::from(vec![
             Span::raw("📌 Pushed:    "),
             Span::styled(
-                repo.pushed_at.format("%Y-%m-%d").to_string(),
+                reposcout_core::models::format_day(repo.pushed_at),
                 Style::default().fg(Color::Gray),
             ),
         ]));
@@ -957,12 +1175,7 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
             lines.push(Line::from(""));
 
             // Overall health score
-            let health_color = match health.status {
-                reposcout_core::HealthStatus::Healthy => Color::Green,
-                reposcout_core::HealthStatus::Moderate => Color::Yellow,
-                reposcout_core::HealthStatus::Warning => Color::Rgb(255, 165, 0),
-                reposcout_core::HealthStatus::Critical => Color::Red,
-            };
+            let status_color = health_color(&health.status);
 
             lines.push(Line::from(vec![
                 Span::raw("💚 Health:    "),
@@ -974,7 +1187,7 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
                         health.score
                     ),
                     Style::default()
-                        .fg(health_color)
+                        .fg(status_color)
                         .add_modifier(Modifier::BOLD),
                 ),
             ]));
@@ -987,7 +1200,7 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
                         health.maintenance.emoji(),
                         health.maintenance.label()
                     ),
-                    Style::default().fg(health_color),
+                    Style::default().fg(status_color),
                 ),
             ]));
 
@@ -1006,7 +1219,10 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
             lines.push(Line::from(vec![
                 Span::raw("  Activity:      "),
                 Span::styled(
-                    format!("{}/30", health.metrics.activity_score),
+                    format!(
+                        "{}/{}",
+                        health.metrics.activity_score, health.weights.activity
+                    ),
                     Style::default().fg(Color::Cyan),
                 ),
             ]));
@@ -1014,7 +1230,10 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
             lines.push(Line::from(vec![
                 Span::raw("  Community:     "),
                 Span::styled(
-                    format!("{}/25", health.metrics.community_score),
+                    format!(
+                        "{}/{}",
+                        health.metrics.community_score, health.weights.community
+                    ),
                     Style::default().fg(Color::Cyan),
                 ),
             ]));
@@ -1022,7 +1241,10 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
             lines.push(Line::from(vec![
                 Span::raw("  Responsiveness:"),
                 Span::styled(
-                    format!("{}/20", health.metrics.responsiveness_score),
+                    format!(
+                        "{}/{}",
+                        health.metrics.responsiveness_score, health.weights.responsiveness
+                    ),
                     Style::default().fg(Color::Cyan),
                 ),
             ]));
@@ -1030,7 +1252,10 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
             lines.push(Line::from(vec![
                 Span::raw("  Maturity:      "),
                 Span::styled(
-                    format!("{}/15", health.metrics.maturity_score),
+                    format!(
+                        "{}/{}",
+                        health.metrics.maturity_score, health.weights.maturity
+                    ),
                     Style::default().fg(Color::Cyan),
                 ),
             ]));
@@ -1038,10 +1263,32 @@ fn render_stats_preview(app: &App) -> Vec<Line<'_>> {
             lines.push(Line::from(vec![
                 Span::raw("  Documentation: "),
                 Span::styled(
-                    format!("{}/10", health.metrics.documentation_score),
+                    format!(
+                        "{}/{}",
+                        health.metrics.documentation_score, health.weights.documentation
+                    ),
                     Style::default().fg(Color::Cyan),
                 ),
             ]));
+
+            if !health.signals.is_empty() {
+                lines.push(Line::from(""));
+                lines.push(Line::from(vec![Span::styled(
+                    "Why:",
+                    Style::default().fg(Color::Gray),
+                )]));
+                for signal in &health.signals {
+                    let (bullet, color) = if signal.is_positive() {
+                        ("+", Color::Green)
+                    } else {
+                        ("-", Color::Red)
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled(format!("  {} ", bullet), Style::default().fg(color)),
+                        Span::styled(signal.to_string(), Style::default().fg(color)),
+                    ]));
+                }
+            }
         }
 
         lines.push(Line::from(""));
@@ -1090,44 +1337,7 @@ fn render_readme_preview(app: &App) -> Vec<Line<'_>> {
     }
 
     if let Some(readme) = &app.readme_content {
-        // Simple markdown-to-text conversion
-        readme
-            .lines()
-            .map(|line| {
-                // Basic markdown styling
-                if line.starts_with("# ") {
-                    Line::from(Span::styled(
-                        line.trim_start_matches("# "),
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    ))
-                } else if line.starts_with("## ") {
-                    Line::from(Span::styled(
-                        line.trim_start_matches("## "),
-                        Style::default()
-                            .fg(Color::Yellow)
-                            .add_modifier(Modifier::BOLD),
-                    ))
-                } else if line.starts_with("### ") {
-                    Line::from(Span::styled(
-                        line.trim_start_matches("### "),
-                        Style::default()
-                            .fg(Color::Green)
-                            .add_modifier(Modifier::BOLD),
-                    ))
-                } else if line.starts_with("```") {
-                    Line::from(Span::styled(
-                        line,
-                        Style::default().fg(Color::DarkGray).bg(Color::Black),
-                    ))
-                } else if line.starts_with("- ") || line.starts_with("* ") {
-                    Line::from(Span::styled(line, Style::default().fg(Color::Blue)))
-                } else {
-                    Line::from(line)
-                }
-            })
-            .collect()
+        render_readme_markdown(readme)
     } else {
         vec![
             Line::from(""),
@@ -1139,8 +1349,241 @@ fn render_readme_preview(app: &App) -> Vec<Line<'_>> {
     }
 }
 
+fn render_changelog_preview(app: &App) -> Vec<Line<'_>> {
+    if app.changelog_loading {
+        return vec![
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                "⏳ Loading changelog...",
+                Style::default().fg(Color::Yellow),
+            )]),
+        ];
+    }
+
+    if let Some(changelog) = app.get_cached_changelog() {
+        render_markdown_lines(changelog)
+    } else {
+        vec![
+            Line::from(""),
+            Line::from(vec![Span::styled(
+                "Press 'C' to fetch the changelog (falls back to latest release notes)",
+                Style::default().fg(Color::Gray),
+            )]),
+        ]
+    }
+}
+
+/// Simple markdown-to-text conversion shared by the README and changelog views
+fn render_markdown_lines(text: &str) -> Vec<Line<'_>> {
+    text.lines()
+        .map(|line| {
+            let (content, style) = markdown_line_style(line);
+            Line::from(Span::styled(content, style))
+        })
+        .collect()
+}
+
+/// Basic markdown styling: a line's text without heading markers, and its style
+fn markdown_line_style(line: &str) -> (&str, Style) {
+    if line.starts_with("# ") {
+        (
+            line.trim_start_matches("# "),
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else if line.starts_with("## ") {
+        (
+            line.trim_start_matches("## "),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else if line.starts_with("### ") {
+        (
+            line.trim_start_matches("### "),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )
+    } else if line.starts_with("```") {
+        (line, Style::default().fg(Color::DarkGray).bg(Color::Black))
+    } else if line.starts_with("- ") || line.starts_with("* ") {
+        (line, Style::default().fg(Color::Blue))
+    } else {
+        (line, Style::default())
+    }
+}
+
+/// README markdown with links numbered as footnotes, listed at the bottom
+fn render_readme_markdown(text: &str) -> Vec<Line<'static>> {
+    use crate::readme_links::{reference_definition, Footnotes, Piece};
+
+    let mut footnotes = Footnotes::new(text);
+    let mut in_code = false;
+    let mut lines = Vec::new();
+
+    for line in text.lines() {
+        if line.starts_with("```") {
+            in_code = !in_code;
+        }
+        if in_code || line.starts_with("```") {
+            // No link parsing inside code blocks
+            let (content, style) = markdown_line_style(line);
+            lines.push(Line::from(Span::styled(content.to_string(), style)));
+            continue;
+        }
+        if reference_definition(line).is_some() {
+            // Listed with the footnotes instead
+            continue;
+        }
+
+        let (content, style) = markdown_line_style(line);
+
+        let spans: Vec<Span> = footnotes
+            .split_line(content)
+            .into_iter()
+            .flat_map(|piece| match piece {
+                Piece::Text(text) => vec![Span::styled(text, style)],
+                Piece::Link { text, footnote } => {
+                    let link = style.fg(Color::Cyan).add_modifier(Modifier::UNDERLINED);
+                    let mut spans = vec![Span::styled(text, link)];
+                    if let Some(n) = footnote {
+                        spans.push(Span::styled(
+                            format!("[{}]", n),
+                            Style::default().fg(Color::DarkGray),
+                        ));
+                    }
+                    spans
+                }
+            })
+            .collect();
+        lines.push(Line::from(spans));
+    }
+
+    if !footnotes.urls().is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Links (press the number to open)",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for (i, url) in footnotes.urls().iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(format!("[{}] ", i + 1), Style::default().fg(Color::Yellow)),
+                Span::styled(url.clone(), Style::default().fg(Color::Blue)),
+            ]));
+        }
+    }
+
+    lines
+}
+
+/// Bar color for a language, roughly following GitHub's linguist colors
+fn language_color(language: &str) -> Color {
+    match language {
+        "Rust" => Color::Rgb(222, 165, 132),
+        "Python" => Color::Rgb(53, 114, 165),
+        "JavaScript" => Color::Rgb(241, 224, 90),
+        "TypeScript" => Color::Rgb(49, 120, 198),
+        "Go" => Color::Rgb(0, 173, 216),
+        "Java" => Color::Rgb(176, 114, 25),
+        "C" => Color::Rgb(85, 85, 85),
+        "C++" => Color::Rgb(243, 75, 125),
+        "C#" => Color::Rgb(23, 134, 0),
+        "Ruby" => Color::Rgb(112, 21, 22),
+        "PHP" => Color::Rgb(79, 93, 149),
+        "Shell" => Color::Rgb(137, 224, 81),
+        "HTML" => Color::Rgb(227, 76, 38),
+        "CSS" => Color::Rgb(86, 61, 124),
+        "Kotlin" => Color::Rgb(169, 123, 255),
+        "Swift" => Color::Rgb(240, 81, 56),
+        _ => Color::Magenta,
+    }
+}
+
+fn render_languages_preview(app: &App, width: u16) -> Vec<Line<'_>> {
+    if app.selected_repository().is_none() {
+        return vec![Line::from("No repository selected")];
+    }
+
+    let languages = match app.get_cached_languages() {
+        Some(Some(languages)) if !languages.is_empty() => languages,
+        Some(_) => {
+            return vec![
+                Line::from(""),
+                Line::from(vec![Span::styled(
+                    "Language breakdown not available.",
+                    Style::default().fg(Color::Gray),
+                )]),
+            ];
+        }
+        None => {
+            return vec![
+                Line::from(""),
+                Line::from(vec![Span::styled(
+                    "⏳ Loading languages...",
+                    Style::default().fg(Color::Yellow),
+                )]),
+            ];
+        }
+    };
+
+    let total: u64 = languages.values().sum();
+    let mut sorted: Vec<_> = languages.iter().collect();
+    sorted.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+
+    let name_width = sorted
+        .iter()
+        .map(|(name, _)| name.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(16);
+    // name, space, "100.0%", space, bar
+    let bar_width = (width as usize).saturating_sub(name_width + 9).max(10);
+
+    let mut lines = vec![
+        Line::from(vec![Span::styled(
+            "Language Breakdown",
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+        )]),
+        Line::from(""),
+    ];
+
+    for (name, bytes) in sorted {
+        let percent = *bytes as f64 * 100.0 / total.max(1) as f64;
+        let filled = ((percent / 100.0) * bar_width as f64).round() as usize;
+        let label: String = name.chars().take(name_width).collect();
+
+        lines.push(Line::from(vec![
+            Span::raw(format!("{:<width$} ", label, width = name_width)),
+            Span::styled(
+                format!("{:>5.1}% ", percent),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(
+                "█".repeat(filled),
+                Style::default().fg(language_color(name)),
+            ),
+            Span::styled(
+                "░".repeat(bar_width - filled),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+
+    lines
+}
+
 fn render_activity_preview(app: &App) -> Vec<Line<'_>> {
     if let Some(repo) = app.selected_repository() {
+        let activity = app.get_cached_activity();
+        let commits = activity
+            .as_ref()
+            .and_then(|stats| stats.commit_activity.as_deref());
         let mut lines = vec![
             Line::from(vec![Span::styled(
                 "Repository Activity",
@@ -1151,6 +1594,24 @@ fn render_activity_preview(app: &App) -> Vec<Line<'_>> {
             Line::from(""),
         ];
 
+        // Contributors (fetched when the tab opens, GitHub only)
+        let contributors = match activity.as_ref().and_then(|stats| stats.contributors) {
+            Some(count) => Some(format_number(count)),
+            None if app.activity_loading => Some(format!("{} loading...", app.spinner())),
+            None => None,
+        };
+        if let Some(contributors) = contributors {
+            lines.push(Line::from(vec![
+                Span::raw("👥 Contributors: "),
+                Span::styled(
+                    contributors,
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            ]));
+        }
+
         // Size information
         lines.push(Line::from(vec![
             Span::raw("📦 Size:        "),
@@ -1232,8 +1693,21 @@ fn render_activity_preview(app: &App) -> Vec<Line<'_>> {
         lines.push(Line::from(""));
 
         // Generate activity heatmap
-        let heatmap_lines = generate_activity_heatmap(repo);
+        let heatmap_lines = generate_activity_heatmap(repo, commits);
         lines.extend(heatmap_lines);
+        let source = if commits.is_some() {
+            "     Commits per day, from GitHub"
+        } else if activity.as_ref().is_some_and(|stats| stats.stats_pending) {
+            "     GitHub is still computing commit statistics; estimated from push dates"
+        } else {
+            "     Estimated from push dates"
+        };
+        lines.push(Line::from(Span::styled(
+            source,
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        )));
 
         // Activity metrics
         lines.push(Line::from(""));
@@ -1259,15 +1733,22 @@ fn render_activity_preview(app: &App) -> Vec<Line<'_>> {
         lines.push(Line::from(""));
 
         // Generate sparklines using repo data
-        let activity_sparkline = crate::sparkline::generate_activity_sparkline(
+        let activity_sparkline = match commits {
+            Some(weeks) => crate::sparkline::commit_activity_sparkline(weeks),
+            None => crate::sparkline::generate_activity_sparkline(
+                repo.created_at,
+                repo.pushed_at,
+                repo.stars,
+            ),
+        };
+
+        let star_history = app.get_star_history().unwrap_or_default();
+        let velocity_sparkline = crate::sparkline::generate_star_velocity_sparkline(
             repo.created_at,
-            repo.pushed_at,
             repo.stars,
+            star_history,
         );
 
-        let velocity_sparkline =
-            crate::sparkline::generate_star_velocity_sparkline(repo.created_at, repo.stars);
-
         let issue_sparkline = crate::sparkline::generate_issue_activity_sparkline(
             repo.open_issues,
             repo.stars,
@@ -1301,6 +1782,24 @@ fn render_activity_preview(app: &App) -> Vec<Line<'_>> {
         }
 
         lines.push(Line::from(""));
+        let velocity_source = if crate::sparkline::has_star_trend(star_history) {
+            let since = chrono::DateTime::from_timestamp(star_history[0].recorded_at, 0)
+                .map(|t| t.format("%Y-%m-%d").to_string())
+                .unwrap_or_default();
+            format!(
+                "  Star velocity from {} star counts seen since {}",
+                star_history.len(),
+                since
+            )
+        } else {
+            "  Star velocity estimated from age; real growth shows once the repository has been seen on a few days".to_string()
+        };
+        lines.push(Line::from(vec![Span::styled(
+            velocity_source,
+            Style::default()
+                .fg(Color::DarkGray)
+                .add_modifier(Modifier::ITALIC),
+        )]));
         lines.push(Line::from(vec![Span::styled(
             "  Each bar represents a time period (12 total)",
             Style::default()
@@ -1327,6 +1826,8 @@ fn render_activity_preview(app: &App) -> Vec<Line<'_>> {
             reposcout_core::models::Platform::GitHub => Color::Yellow,
             reposcout_core::models::Platform::GitLab => Color::Magenta,
             reposcout_core::models::Platform::Bitbucket => Color::Blue,
+            reposcout_core::models::Platform::SourceHut => Color::Gray,
+            reposcout_core::models::Platform::AzureDevOps => Color::LightBlue,
         };
 
         lines.push(Line::from(vec![
@@ -1399,6 +1900,148 @@ fn render_dependencies_preview(app: &App) -> Vec<Line<'_>> {
                 Span::styled(deps.dev_count.to_string(), Style::default().fg(Color::Blue)),
             ]));
 
+            // Runtime dependency licenses against the repository's own license
+            let project_license = app
+                .selected_repository()
+                .and_then(|repo| repo.license.as_deref())
+                .map(reposcout_core::License::parse_license);
+            let licenses = app.get_cached_dependency_licenses();
+            let compatibility = |name: &str| -> Option<reposcout_core::LicenseCompatibility> {
+                let license = licenses?.get(name)?.as_deref()?;
+                let project = project_license?;
+                Some(
+                    reposcout_core::License::parse_license(license)
+                        .dependency_compatibility(&project),
+                )
+            };
+
+            if app.dependency_licenses_loading {
+                lines.push(Line::from(vec![Span::styled(
+                    "Checking dependency licenses...",
+                    Style::default().fg(Color::DarkGray),
+                )]));
+            } else if licenses.is_some() && project_license.is_some() {
+                let flagged = deps
+                    .dependencies
+                    .iter()
+                    .filter(|d| {
+                        matches!(
+                            compatibility(&d.name),
+                            Some(reposcout_core::LicenseCompatibility::Warning)
+                                | Some(reposcout_core::LicenseCompatibility::Incompatible)
+                        )
+                    })
+                    .count();
+                if flagged > 0 {
+                    lines.push(Line::from(vec![Span::styled(
+                        format!(
+                            "⚠ {} {} may be license-incompatible.",
+                            flagged,
+                            if flagged == 1 {
+                                "dependency"
+                            } else {
+                                "dependencies"
+                            }
+                        ),
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD),
+                    )]));
+                } else {
+                    lines.push(Line::from(vec![Span::styled(
+                        "✓ No license conflicts found",
+                        Style::default().fg(Color::Green),
+                    )]));
+                }
+            }
+
+            // Known vulnerabilities, only looked up on request
+            if app.dependency_vulns_loading {
+                lines.push(Line::from(vec![Span::styled(
+                    "Checking for known vulnerabilities...",
+                    Style::default().fg(Color::DarkGray),
+                )]));
+            } else if deps.vulnerabilities_checked {
+                let vulnerable: Vec<_> = deps.vulnerable().collect();
+                if vulnerable.is_empty() {
+                    lines.push(Line::from(vec![Span::styled(
+                        "✓ No known vulnerabilities",
+                        Style::default().fg(Color::Green),
+                    )]));
+                } else {
+                    let advisories: usize =
+                        vulnerable.iter().map(|d| d.vulnerabilities.len()).sum();
+                    lines.push(Line::from(vec![Span::styled(
+                        format!(
+                            "⚠ {} vulnerable {} ({} {})",
+                            vulnerable.len(),
+                            if vulnerable.len() == 1 {
+                                "dependency"
+                            } else {
+                                "dependencies"
+                            },
+                            advisories,
+                            if advisories == 1 {
+                                "advisory"
+                            } else {
+                                "advisories"
+                            }
+                        ),
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    )]));
+                    for dep in vulnerable.iter().take(10) {
+                        for advisory in &dep.vulnerabilities {
+                            let mut spans = vec![
+                                Span::raw("  "),
+                                Span::styled(dep.name.clone(), Style::default().fg(Color::White)),
+                                Span::raw(" "),
+                                Span::styled(
+                                    advisory.display_id().to_string(),
+                                    Style::default().fg(Color::Red),
+                                ),
+                            ];
+                            if let Some(severity) = &advisory.severity {
+                                spans.push(Span::styled(
+                                    format!(" ({})", severity),
+                                    Style::default().fg(Color::Red),
+                                ));
+                            }
+                            if !advisory.summary.is_empty() {
+                                spans.push(Span::styled(
+                                    format!(": {}", advisory.summary),
+                                    Style::default().fg(Color::Gray),
+                                ));
+                            }
+                            lines.push(Line::from(spans));
+                        }
+                    }
+                    if vulnerable.len() > 10 {
+                        lines.push(Line::from(vec![
+                            Span::raw("  "),
+                            Span::styled(
+                                format!("... and {} more", vulnerable.len() - 10),
+                                Style::default()
+                                    .fg(Color::DarkGray)
+                                    .add_modifier(Modifier::ITALIC),
+                            ),
+                        ]));
+                    }
+                }
+            } else if reposcout_deps::osv::osv_ecosystem(&deps.ecosystem).is_some() {
+                lines.push(Line::from(vec![Span::styled(
+                    "Press v to check for known vulnerabilities (OSV)",
+                    Style::default().fg(Color::DarkGray),
+                )]));
+            }
+            let vuln_marker = |dep: &reposcout_deps::Dependency| -> Option<Span<'static>> {
+                (!dep.vulnerabilities.is_empty()).then(|| {
+                    Span::styled(
+                        format!(" ⚠ {}", dep.vulnerabilities.len()),
+                        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    )
+                })
+            };
+
             lines.push(Line::from(""));
             lines.push(Line::from(vec![Span::styled(
                 "Dependencies List",
@@ -1424,6 +2067,11 @@ fn render_dependencies_preview(app: &App) -> Vec<Line<'_>> {
                 .iter()
                 .filter(|d| matches!(d.dep_type, reposcout_deps::DependencyType::Build))
                 .collect();
+            let indirect_deps: Vec<_> = deps
+                .dependencies
+                .iter()
+                .filter(|d| matches!(d.dep_type, reposcout_deps::DependencyType::Indirect))
+                .collect();
 
             // Runtime dependencies
             if !runtime_deps.is_empty() {
@@ -1434,15 +2082,31 @@ fn render_dependencies_preview(app: &App) -> Vec<Line<'_>> {
                         .add_modifier(Modifier::BOLD),
                 )]));
                 for dep in runtime_deps.iter().take(20) {
-                    lines.push(Line::from(vec![
-                        Span::raw("  • "),
+                    // Green compatible, yellow needs review, red incompatible
+                    let marker_color = match compatibility(&dep.name) {
+                        Some(reposcout_core::LicenseCompatibility::Compatible) => Color::Green,
+                        Some(reposcout_core::LicenseCompatibility::Warning) => Color::Yellow,
+                        Some(reposcout_core::LicenseCompatibility::Incompatible) => Color::Red,
+                        _ => Color::DarkGray,
+                    };
+                    let mut spans = vec![
+                        Span::raw("  "),
+                        Span::styled("● ", Style::default().fg(marker_color)),
                         Span::styled(dep.name.clone(), Style::default().fg(Color::White)),
                         Span::raw(" "),
                         Span::styled(
                             format!("({})", dep.version),
                             Style::default().fg(Color::DarkGray),
                         ),
-                    ]));
+                    ];
+                    if let Some(Some(license)) = licenses.and_then(|l| l.get(&dep.name)) {
+                        spans.push(Span::styled(
+                            format!(" [{}]", license),
+                            Style::default().fg(marker_color),
+                        ));
+                    }
+                    spans.extend(vuln_marker(dep));
+                    lines.push(Line::from(spans));
                 }
                 if runtime_deps.len() > 20 {
                     lines.push(Line::from(vec![
@@ -1467,7 +2131,7 @@ fn render_dependencies_preview(app: &App) -> Vec<Line<'_>> {
                         .add_modifier(Modifier::BOLD),
                 )]));
                 for dep in dev_deps.iter().take(15) {
-                    lines.push(Line::from(vec![
+                    let mut spans = vec![
                         Span::raw("  • "),
                         Span::styled(dep.name.clone(), Style::default().fg(Color::White)),
                         Span::raw(" "),
@@ -1475,7 +2139,9 @@ fn render_dependencies_preview(app: &App) -> Vec<Line<'_>> {
                             format!("({})", dep.version),
                             Style::default().fg(Color::DarkGray),
                         ),
-                    ]));
+                    ];
+                    spans.extend(vuln_marker(dep));
+                    lines.push(Line::from(spans));
                 }
                 if dev_deps.len() > 15 {
                     lines.push(Line::from(vec![
@@ -1500,7 +2166,7 @@ fn render_dependencies_preview(app: &App) -> Vec<Line<'_>> {
                         .add_modifier(Modifier::BOLD),
                 )]));
                 for dep in build_deps.iter().take(10) {
-                    lines.push(Line::from(vec![
+                    let mut spans = vec![
                         Span::raw("  • "),
                         Span::styled(dep.name.clone(), Style::default().fg(Color::White)),
                         Span::raw(" "),
@@ -1508,7 +2174,9 @@ fn render_dependencies_preview(app: &App) -> Vec<Line<'_>> {
                             format!("({})", dep.version),
                             Style::default().fg(Color::DarkGray),
                         ),
-                    ]));
+                    ];
+                    spans.extend(vuln_marker(dep));
+                    lines.push(Line::from(spans));
                 }
                 if build_deps.len() > 10 {
                     lines.push(Line::from(vec![
@@ -1521,6 +2189,41 @@ fn render_dependencies_preview(app: &App) -> Vec<Line<'_>> {
                         ),
                     ]));
                 }
+                lines.push(Line::from(""));
+            }
+
+            // Indirect dependencies (e.g. Go `// indirect`)
+            if !indirect_deps.is_empty() {
+                lines.push(Line::from(vec![Span::styled(
+                    "Indirect:",
+                    Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD),
+                )]));
+                for dep in indirect_deps.iter().take(10) {
+                    let mut spans = vec![
+                        Span::raw("  • "),
+                        Span::styled(dep.name.clone(), Style::default().fg(Color::Gray)),
+                        Span::raw(" "),
+                        Span::styled(
+                            format!("({})", dep.version),
+                            Style::default().fg(Color::DarkGray),
+                        ),
+                    ];
+                    spans.extend(vuln_marker(dep));
+                    lines.push(Line::from(spans));
+                }
+                if indirect_deps.len() > 10 {
+                    lines.push(Line::from(vec![
+                        Span::raw("  "),
+                        Span::styled(
+                            format!("... and {} more", indirect_deps.len() - 10),
+                            Style::default()
+                                .fg(Color::DarkGray)
+                                .add_modifier(Modifier::ITALIC),
+                        ),
+                    ]));
+                }
             }
 
             lines
@@ -1538,7 +2241,10 @@ fn render_dependencies_preview(app: &App) -> Vec<Line<'_>> {
                 )]),
                 Line::from(vec![Span::raw("  • Cargo.toml (Rust)")]),
                 Line::from(vec![Span::raw("  • package.json (Node.js)")]),
-                Line::from(vec![Span::raw("  • requirements.txt (Python)")]),
+                Line::from(vec![Span::raw(
+                    "  • pyproject.toml / requirements.txt (Python)",
+                )]),
+                Line::from(vec![Span::raw("  • go.mod (Go)")]),
             ]
         }
     } else if let Some(repo) = app.selected_repository() {
@@ -1583,6 +2289,30 @@ fn render_filters_panel(frame: &mut Frame, app: &App, area: Rect) {
         }
     };
 
+    let toggle_line = |label: &'static str, on: bool, field_idx: usize| -> Line<'static> {
+        let selected = cursor == field_idx && is_active;
+        Line::from(vec![
+            Span::styled(
+                label,
+                if selected {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Gray)
+                },
+            ),
+            Span::styled(
+                if on { " [x]" } else { " [ ]" },
+                if selected {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default()
+                },
+            ),
+        ])
+    };
+
     // Create filter display lines
     let lines = vec![
         Line::from(vec![
@@ -1697,9 +2427,38 @@ fn render_filters_panel(frame: &mut Frame, app: &App, area: Rect) {
                 },
             ),
         ]),
+        Line::from(vec![
+            Span::styled(
+                "Min Health: ",
+                if cursor == 5 && is_active {
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Gray)
+                },
+            ),
+            Span::styled(
+                get_display_value(
+                    5,
+                    &filters
+                        .min_health
+                        .map(|s| s.to_string())
+                        .unwrap_or_else(|| "<none>".to_string()),
+                ),
+                if cursor == 5 && is_active {
+                    Style::default().fg(Color::Cyan)
+                } else {
+                    Style::default()
+                },
+            ),
+        ]),
+        toggle_line("No Forks:   ", filters.exclude_forks, 6),
+        toggle_line("No Archived:", filters.exclude_archived, 7),
+        toggle_line("No Reviewed:", filters.hide_reviewed, 8),
         Line::from(""),
         Line::from(Span::styled(
-            "TAB/arrows: navigate | ENTER: edit | DEL: clear | ESC: close",
+            "TAB/arrows: navigate | ENTER: edit/toggle | DEL: clear | r: re-run search | ESC: close",
             Style::default().fg(Color::DarkGray),
         )),
     ];
@@ -1717,11 +2476,23 @@ fn render_filters_panel(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
-    let status = if let Some(error) = &app.error_message {
+    let status = if app.input_mode == InputMode::Command {
+        let style = Style::default().fg(theme_color(&app.current_theme.colors.accent));
+        vec![
+            Span::styled(":", style.add_modifier(Modifier::BOLD)),
+            Span::styled(&app.command_input, style),
+            Span::styled("█", style),
+        ]
+    } else if let Some(error) = &app.error_message {
         vec![Span::styled(
             error,
             Style::default().fg(theme_color(&app.current_theme.colors.error)),
         )]
+    } else if let Some(task_status) = app.tasks.status() {
+        vec![Span::styled(
+            task_status,
+            Style::default().fg(theme_color(&app.current_theme.colors.warning)),
+        )]
     } else {
         vec![match app.input_mode {
             InputMode::Searching => Span::styled(
@@ -1752,14 +2523,18 @@ fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
                 "TOKEN INPUT | Type token | ENTER: save | ESC: cancel",
                 Style::default().fg(theme_color(&app.current_theme.colors.warning)),
             ),
+            InputMode::BookmarkEdit => Span::styled(
+                "EDIT BOOKMARK | Type value | ENTER: save | ESC: cancel",
+                Style::default().fg(theme_color(&app.current_theme.colors.success)),
+            ),
+            InputMode::Command => Span::raw(""), // Drawn as the command line above
             InputMode::Normal => {
-                use crate::PreviewMode;
                 match app.search_mode {
                     SearchMode::Code => {
                         Span::styled("j/k: navigate | F: filters | TAB: tabs | n/N: matches | /: search | M: mode | ?: help | q: quit", Style::default().fg(Color::Green))
                     }
                     SearchMode::Repository => {
-                        if app.preview_mode == PreviewMode::Readme {
+                        if app.is_document_preview() {
                             Span::styled("README | j/k: scroll | TAB: tab | D: discovery | M: mode | ?: help | q: quit", Style::default().fg(Color::Cyan))
                         } else {
                             Span::raw("j/k: navigate | /: search | f: fuzzy | F: filters | M: mode | ?: help | q: quit")
@@ -1772,7 +2547,7 @@ fn render_status_bar(frame: &mut Frame, app: &App, area: Rect) {
                         Span::styled("j/k: navigate | m: mark read | a: mark all | f: filter | p: participating | ENTER: open | M: mode | q: quit", Style::default().fg(Color::Yellow))
                     }
                     SearchMode::Semantic => {
-                        if app.preview_mode == PreviewMode::Readme {
+                        if app.is_document_preview() {
                             Span::styled("README | j/k: scroll | TAB: next tab | Ctrl+R: history | Ctrl+S: settings | M: switch mode | q: quit", Style::default().fg(Color::LightBlue))
                         } else {
                             Span::styled("j/k: navigate | /: search | Ctrl+R: history | Ctrl+S: settings | f: fuzzy | M: mode | TAB: tabs | b: bookmark | q: quit", Style::default().fg(Color::LightBlue))
@@ -1803,7 +2578,7 @@ fn render_fuzzy_search_overlay(frame: &mut Frame, app: &App, area: Rect) {
     };
 
     // Fuzzy search input box
-    let fuzzy_text = vec![Line::from(vec![
+    let mut fuzzy_text = vec![Line::from(vec![
         Span::styled(
             "🔍 Fuzzy Filter: ",
             Style::default()
@@ -1814,6 +2589,17 @@ fn render_fuzzy_search_overlay(frame: &mut Frame, app: &App, area: Rect) {
         Span::styled("█", Style::default().fg(Color::Yellow)), // Cursor
     ])];
 
+    // Tell the user why the highlighted result matched
+    if let Some(field) = app
+        .selected_repository()
+        .and_then(|repo| app.fuzzy_matched_field(repo))
+    {
+        fuzzy_text[0].spans.push(Span::styled(
+            format!("  matched on {}", field.label()),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
     let match_info = if app.fuzzy_input.is_empty() {
         format!("{} results", app.all_results.len())
     } else {
@@ -1848,7 +2634,7 @@ fn render_code_results_list(frame: &mut Frame, app: &App, area: Rect) {
             Line::from(""),
             Line::from(""),
             Line::from(vec![Span::styled(
-                "  🔄 Searching code...",
+                format!("  {} Searching code...", app.spinner()),
                 Style::default()
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
@@ -1884,6 +2670,8 @@ fn render_code_results_list(frame: &mut Frame, app: &App, area: Rect) {
                 reposcout_core::models::Platform::GitHub => Color::Yellow,
                 reposcout_core::models::Platform::GitLab => Color::Magenta,
                 reposcout_core::models::Platform::Bitbucket => Color::Rgb(33, 136, 255),
+                reposcout_core::models::Platform::SourceHut => Color::Rgb(170, 170, 170),
+                reposcout_core::models::Platform::AzureDevOps => Color::Rgb(0, 120, 212),
             };
 
             // Line 1: File path (highlighted if selected)
@@ -2028,7 +2816,11 @@ fn render_code_preview(frame: &mut Frame, app: &App, area: Rect) {
             preview_lines.push(Line::from(""));
 
             // Syntax-highlighted code
-            let highlighted = highlight_code(&code_match.content, result.language.as_deref());
+            let highlighted = highlight_code(
+                &code_match.content,
+                result.language.as_deref(),
+                &app.code_theme_name,
+            );
             preview_lines.extend(highlighted);
         }
 
@@ -2079,29 +2871,17 @@ fn render_code_preview(frame: &mut Frame, app: &App, area: Rect) {
 
 /// Syntax highlight code using syntect
 #[allow(dead_code)]
-fn highlight_code(code: &str, language: Option<&str>) -> Vec<Line<'static>> {
-    // Load syntax definitions and themes
-    let ps = SyntaxSet::load_defaults_newlines();
-    let ts = ThemeSet::load_defaults();
-
-    // Use a dark theme
-    let theme = &ts.themes["base16-ocean.dark"];
-
-    // Detect syntax
-    let syntax = if let Some(lang) = language {
-        ps.find_syntax_by_name(lang)
-            .or_else(|| ps.find_syntax_by_extension(lang))
-            .unwrap_or_else(|| ps.find_syntax_plain_text())
-    } else {
-        ps.find_syntax_plain_text()
-    };
-
-    let mut highlighter = HighlightLines::new(syntax, theme);
+fn highlight_code(code: &str, language: Option<&str>, theme_name: &str) -> Vec<Line<'static>> {
+    let ps = crate::syntax::syntax_set();
+    let mut highlighter = HighlightLines::new(
+        crate::syntax::find_syntax(language),
+        crate::syntax::code_theme(theme_name),
+    );
     let mut result_lines = Vec::new();
 
     for line in LinesWithEndings::from(code) {
         let ranges: Vec<(SyntectStyle, &str)> =
-            highlighter.highlight_line(line, &ps).unwrap_or_default();
+            highlighter.highlight_line(line, ps).unwrap_or_default();
 
         let mut spans = Vec::new();
         for (style, text) in ranges {
@@ -2324,7 +3104,13 @@ fn render_history_popup(frame: &mut Frame, app: &App, area: Rect) {
 }
 
 /// Generate GitHub-style contribution heatmap (52 weeks x 7 days)
-fn generate_activity_heatmap(repo: &reposcout_core::models::Repository) -> Vec<Line<'_>> {
+///
+/// Real daily commit counts (oldest week first, weeks starting on Sunday) are
+/// drawn when given; otherwise activity is estimated from the push dates.
+fn generate_activity_heatmap<'a>(
+    repo: &'a reposcout_core::models::Repository,
+    commits: Option<&[[u32; 7]]>,
+) -> Vec<Line<'a>> {
     use chrono::{Datelike, Duration, Utc};
 
     let now = Utc::now();
@@ -2344,6 +3130,13 @@ fn generate_activity_heatmap(repo: &reposcout_core::models::Repository) -> Vec<L
         }
     };
 
+    let max_daily_commits = commits
+        .into_iter()
+        .flatten()
+        .flat_map(|days| days.iter().copied())
+        .max()
+        .unwrap_or(0);
+
     let mut lines = vec![];
 
     // Month labels (show every ~4 weeks)
@@ -2384,12 +3177,23 @@ fn generate_activity_heatmap(repo: &reposcout_core::models::Repository) -> Vec<L
             let days_ago = (52 - week) * 7 + (6 - day);
 
             // Calculate activity level for this day
-            let activity_level = calculate_activity_level(
-                days_ago as i64,
-                days_since_pushed,
-                days_since_created,
-                activity_score,
-            );
+            let activity_level = match commits {
+                Some(weeks) => {
+                    // The most recent week is the last column; rows start on
+                    // Monday, GitHub's weeks on Sunday
+                    let count = (week + weeks.len())
+                        .checked_sub(52)
+                        .and_then(|i| weeks.get(i))
+                        .map_or(0, |days| days[(day + 1) % 7]);
+                    commit_level(count, max_daily_commits)
+                }
+                None => calculate_activity_level(
+                    days_ago as i64,
+                    days_since_pushed,
+                    days_since_created,
+                    activity_score,
+                ),
+            };
 
             let color = get_activity_color(activity_level);
             row_spans.push(Span::styled("█", Style::default().fg(color)));
@@ -2418,6 +3222,14 @@ fn generate_activity_heatmap(repo: &reposcout_core::models::Repository) -> Vec<L
     lines
 }
 
+/// Heatmap level (0-4) of a day's commits relative to the busiest day
+fn commit_level(count: u32, max: u32) -> u8 {
+    if count == 0 || max == 0 {
+        return 0;
+    }
+    ((count * 4 + max - 1) / max).clamp(1, 4) as u8
+}
+
 /// Calculate activity level for a specific day based on repository metrics
 fn calculate_activity_level(
     days_ago: i64,
@@ -2474,6 +3286,16 @@ fn get_activity_color(level: u8) -> Color {
 fn generate_activity_summary(repo: &reposcout_core::models::Repository) -> Vec<Line<'_>> {
     use chrono::Utc;
 
+    if !repo.has_dates() {
+        return vec![Line::from(Span::styled(
+            format!(
+                "{} didn't report when this repository changed",
+                repo.platform
+            ),
+            Style::default().fg(Color::DarkGray),
+        ))];
+    }
+
     let now = Utc::now();
     let days_since_created = (now - repo.created_at).num_days();
     let days_since_updated = (now - repo.updated_at).num_days();
@@ -2544,7 +3366,7 @@ fn render_trending_options(frame: &mut Frame, app: &App, area: Rect) {
 
     // Create centered popup
     let popup_width = 60.min(area.width - 4);
-    let popup_height = 18.min(area.height - 4);
+    let popup_height = 21.min(area.height - 4);
 
     let popup_area = Rect {
         x: (area.width.saturating_sub(popup_width)) / 2,
@@ -2668,6 +3490,28 @@ fn render_trending_options(frame: &mut Frame, app: &App, area: Rect) {
         Span::styled(" (Space to toggle)", Style::default().fg(Color::DarkGray)),
     ]));
 
+    // Secondary language share
+    let share_style = if app.trending_option_cursor == 5 {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("  Min Language Share: ", Style::default().fg(Color::Cyan)),
+        Span::styled(
+            if filters.min_language_percent == 0 {
+                "Primary only".to_string()
+            } else {
+                format!("≥{}% of code", filters.min_language_percent)
+            },
+            share_style,
+        ),
+        Span::styled(" (+/- to adjust)", Style::default().fg(Color::DarkGray)),
+    ]));
+
     lines.push(Line::from(""));
     lines.push(Line::from(vec![Span::styled(
         "  Velocity = stars/day (finds fastest growing repos)",
@@ -2915,6 +3759,49 @@ fn render_token_input_popup(app: &App, frame: &mut Frame, area: Rect) {
     frame.render_widget(help, chunks[2]);
 }
 
+/// Render the bookmark tags/notes edit popup
+fn render_bookmark_edit_popup(app: &App, frame: &mut Frame, area: Rect) {
+    let popup_area = centered_rect(60, 20, area);
+    frame.render_widget(Clear, popup_area);
+
+    let repo_name = app
+        .selected_repository()
+        .map(|repo| repo.full_name.as_str())
+        .unwrap_or("");
+    let hint = match app.bookmark_edit_field {
+        crate::BookmarkField::Tags => "Comma-separated, e.g. async, cli",
+        crate::BookmarkField::Notes => "Free text",
+    };
+
+    let input = Paragraph::new(vec![
+        Line::from(Span::styled(hint, Style::default().fg(Color::Gray))),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled(
+                app.bookmark_edit_buffer.as_str(),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("_", Style::default().fg(Color::Yellow)),
+        ]),
+    ])
+    .wrap(Wrap { trim: false })
+    .block(
+        Block::default()
+            .title(format!(
+                " {} for {} ",
+                app.bookmark_edit_field.label(),
+                repo_name
+            ))
+            .title_bottom(" Enter: Save | Esc: Cancel ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow))
+            .style(Style::default().bg(Color::Black)),
+    );
+    frame.render_widget(input, popup_area);
+}
+
 // Helper function to create centered rect
 fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
     let popup_layout = Layout::default()
@@ -2936,6 +3823,18 @@ fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
         .split(popup_layout[1])[1]
 }
 
+/// The reason and subject type filters, e.g. " | PullRequest | Mention"
+fn notification_filter_info(app: &App) -> String {
+    let mut info = String::new();
+    if let Some(subject_type) = app.notifications_type {
+        info.push_str(&format!(" | {}", subject_type));
+    }
+    if let Some(reason) = app.notifications_reason {
+        info.push_str(&format!(" | {}", reason));
+    }
+    info
+}
+
 // Render notifications list
 fn render_notifications_list(frame: &mut Frame, app: &App, area: Rect) {
     let filter_text = if app.notifications_show_all {
@@ -2949,15 +3848,21 @@ fn render_notifications_list(frame: &mut Frame, app: &App, area: Rect) {
         ""
     };
 
+    let notifications = app.visible_notifications();
+    let count = if notifications.len() == app.notifications.len() {
+        notifications.len().to_string()
+    } else {
+        format!("{} of {}", notifications.len(), app.notifications.len())
+    };
     let title = format!(
-        " Notifications ({}) - {} {} | m: Mark Read | a: Mark All | f: Filter | p: Toggle Participating ",
-        app.notifications.len(),
+        " Notifications ({}) - {} {}{} | m: Mark Read | a: Mark All | f: Filter | p: Toggle Participating | t: Type | w: Reason ",
+        count,
         filter_text,
-        participating_text
+        participating_text,
+        notification_filter_info(app)
     );
 
-    let items: Vec<ListItem> = app
-        .notifications
+    let items: Vec<ListItem> = notifications
         .iter()
         .enumerate()
         .map(|(i, notif)| {
@@ -3226,6 +4131,38 @@ fn render_package_preview(app: &App) -> Vec<Line<'_>> {
                         ]));
                     }
 
+                    // crates.io usage, only shown for crates
+                    if pkg.manager == reposcout_core::PackageManager::Cargo {
+                        match app.get_cached_crate_usage(&pkg.name) {
+                            Some(Some(usage)) => {
+                                lines.push(Line::from(vec![
+                                    Span::styled("Used by:   ", Style::default().fg(Color::Cyan)),
+                                    Span::styled(
+                                        format!("{} crates", usage.reverse_dependencies),
+                                        Style::default().fg(Color::Magenta),
+                                    ),
+                                ]));
+                                if let Some(recent) = usage.recent_downloads {
+                                    lines.push(Line::from(vec![
+                                        Span::styled(
+                                            "Downloads (last 90 days): ",
+                                            Style::default().fg(Color::Cyan),
+                                        ),
+                                        Span::styled(
+                                            format_downloads(recent),
+                                            Style::default().fg(Color::Magenta),
+                                        ),
+                                    ]));
+                                }
+                            }
+                            Some(None) => {}
+                            None => lines.push(Line::from(vec![Span::styled(
+                                "Loading crates.io usage...",
+                                Style::default().fg(Color::DarkGray),
+                            )])),
+                        }
+                    }
+
                     // License
                     if let Some(license) = &pkg.license {
                         let license_obj = reposcout_core::License::parse_license(license);